@@ -0,0 +1,282 @@
+use std::fs::File;
+
+use shared::domain::bus::command::{Command, CommandError, CommandHandler};
+use time::OffsetDateTime;
+
+use super::service::DonaAttachmentAdder;
+
+pub const ADD_DONA_ATTACHMENT_COMMAND_TYPE: &str = "dona.add_dona_attachment.command";
+
+#[derive(Debug)]
+pub struct AddDonaAttachmentCommand {
+    pub id: String,
+    pub dona_id: String,
+    pub uploader_id: String,
+    pub filename: String,
+    pub content_type: String,
+    pub file: File,
+    pub created_at: OffsetDateTime,
+}
+
+impl Command for AddDonaAttachmentCommand {
+    fn command_type(&self) -> &'static str {
+        ADD_DONA_ATTACHMENT_COMMAND_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct AddDonaAttachmentCommandHandler {
+    service: DonaAttachmentAdder,
+}
+
+impl AddDonaAttachmentCommandHandler {
+    pub fn new(service: DonaAttachmentAdder) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler for AddDonaAttachmentCommandHandler {
+    async fn handle(&self, command: Box<dyn Command>) -> Result<(), CommandError> {
+        let command = command
+            .as_any()
+            .downcast_ref::<AddDonaAttachmentCommand>()
+            .ok_or_else(|| CommandError::new("Invalid command".to_string()))?;
+
+        let file = command
+            .file
+            .try_clone()
+            .map_err(|e| CommandError::new(e.to_string()))?;
+
+        self.service
+            .execute(
+                command.id.to_owned(),
+                command.dona_id.to_owned(),
+                command.uploader_id.to_owned(),
+                command.filename.to_owned(),
+                command.content_type.to_owned(),
+                file,
+                command.created_at,
+            )
+            .await
+            .map_err(CommandError::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use std::sync::Arc;
+
+    use mockall::predicate;
+    use shared::{
+        domain::{
+            bus::event::tests::MockEventBus, storage::tests::MockFileStorageRepository,
+            utils::new_uuid,
+        },
+        DONA_STORAGE_MODEL,
+    };
+    use time::Duration;
+
+    use super::*;
+
+    use crate::attachment::domain::dona_attachment::tests::DonaAttachmentMother;
+    use crate::attachment::domain::dona_attachment::{
+        ERR_DONA_NOT_ACCEPTING_ATTACHMENTS, ERR_TOO_MANY_DONA_ATTACHMENTS, MAX_DONA_ATTACHMENTS,
+    };
+    use crate::attachment::domain::dona_attachment_added_event::DONA_ATTACHMENT_ADDED_EVENT_TYPE;
+    use crate::attachment::domain::dona_attachment_repository::tests::MockDonaAttachmentRepository;
+    use crate::dona::domain::dona::tests::DonaMother;
+    use crate::dona::domain::dona::{Dona, ERR_DONA_NOT_FOUND};
+    use crate::dona::domain::dona_repository::tests::MockDonaRepository;
+
+    fn dona_with(status: &str, method: &str) -> Dona {
+        DonaMother::create(
+            None,
+            None,
+            None,
+            None,
+            Some(status.to_string()),
+            Some(method.to_string()),
+            None,
+            Some(new_uuid()),
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn command_from(dona: &Dona, uploader_id: String) -> AddDonaAttachmentCommand {
+        let mut file = tempfile::tempfile().unwrap();
+        file.write_all(b"%PDF-1.4").unwrap();
+
+        AddDonaAttachmentCommand {
+            id: new_uuid(),
+            dona_id: dona.id(),
+            uploader_id,
+            filename: "transfer.pdf".to_string(),
+            content_type: "application/pdf".to_string(),
+            file,
+            created_at: OffsetDateTime::now_utc() - Duration::seconds(1),
+        }
+    }
+
+    fn handler(
+        dona: Dona,
+        attachment_repository: MockDonaAttachmentRepository,
+        storage_repository: MockFileStorageRepository,
+        event_bus: MockEventBus,
+    ) -> AddDonaAttachmentCommandHandler {
+        let mut dona_repository = MockDonaRepository::new();
+        dona_repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(dona));
+
+        AddDonaAttachmentCommandHandler::new(DonaAttachmentAdder::new(
+            Arc::new(attachment_repository),
+            Arc::new(dona_repository),
+            Arc::new(storage_repository),
+            Arc::new(event_bus),
+        ))
+    }
+
+    #[tokio::test]
+    async fn it_should_store_the_file_and_publish_the_attachment() {
+        let dona = dona_with("pending", "MANUAL");
+        let command = command_from(&dona, dona.sender_id().unwrap());
+        let stored_filename = format!("{}.pdf", command.id);
+
+        let mut attachment_repository = MockDonaAttachmentRepository::new();
+        attachment_repository
+            .expect_find_by_dona_id()
+            .times(1)
+            .return_const(Ok(vec![]));
+        let expected_dona_id = dona.id();
+        attachment_repository
+            .expect_save()
+            .withf(move |attachment| {
+                attachment.dona_id() == expected_dona_id && attachment.size() == 8
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut storage_repository = MockFileStorageRepository::new();
+        storage_repository
+            .expect_save()
+            .with(
+                predicate::eq(DONA_STORAGE_MODEL.to_string()),
+                predicate::eq(dona.id()),
+                predicate::eq(stored_filename),
+                predicate::always(),
+            )
+            .times(1)
+            .returning(|_, _, filename, _| Ok(filename));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus
+            .expect_publish()
+            .withf(|events| {
+                events.len() == 1 && events[0].event_type() == DONA_ATTACHMENT_ADDED_EVENT_TYPE
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let result = handler(dona, attachment_repository, storage_repository, event_bus)
+            .handle(Box::new(command))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_hide_the_dona_from_other_users() {
+        let dona = dona_with("pending", "MANUAL");
+
+        let mut attachment_repository = MockDonaAttachmentRepository::new();
+        attachment_repository.expect_save().times(0);
+        let mut storage_repository = MockFileStorageRepository::new();
+        storage_repository.expect_save().times(0);
+
+        let command = command_from(&dona, new_uuid());
+        let result = handler(
+            dona,
+            attachment_repository,
+            storage_repository,
+            MockEventBus::new(),
+        )
+        .handle(Box::new(command))
+        .await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_DONA_NOT_FOUND.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_only_accept_pending_manual_donas() {
+        for (status, method) in [("confirmed", "MANUAL"), ("pending", "PAYPAL")] {
+            let dona = dona_with(status, method);
+
+            let mut attachment_repository = MockDonaAttachmentRepository::new();
+            attachment_repository.expect_save().times(0);
+            let mut storage_repository = MockFileStorageRepository::new();
+            storage_repository.expect_save().times(0);
+
+            let command = command_from(&dona, dona.sender_id().unwrap());
+            let result = handler(
+                dona,
+                attachment_repository,
+                storage_repository,
+                MockEventBus::new(),
+            )
+            .handle(Box::new(command))
+            .await;
+
+            assert_eq!(
+                result,
+                Err(CommandError::new(
+                    ERR_DONA_NOT_ACCEPTING_ATTACHMENTS.to_string()
+                ))
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_limit_the_attachments_per_dona() {
+        let dona = dona_with("pending", "MANUAL");
+        let attachments = (0..MAX_DONA_ATTACHMENTS)
+            .map(|_| DonaAttachmentMother::create(None, Some(dona.id()), None, None))
+            .collect::<Vec<_>>();
+
+        let mut attachment_repository = MockDonaAttachmentRepository::new();
+        attachment_repository
+            .expect_find_by_dona_id()
+            .times(1)
+            .return_const(Ok(attachments));
+        attachment_repository.expect_save().times(0);
+        let mut storage_repository = MockFileStorageRepository::new();
+        storage_repository.expect_save().times(0);
+
+        let command = command_from(&dona, dona.sender_id().unwrap());
+        let result = handler(
+            dona,
+            attachment_repository,
+            storage_repository,
+            MockEventBus::new(),
+        )
+        .handle(Box::new(command))
+        .await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_TOO_MANY_DONA_ATTACHMENTS.to_string()))
+        );
+    }
+}
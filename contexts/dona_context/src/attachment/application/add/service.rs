@@ -0,0 +1,100 @@
+use std::{fs::File, sync::Arc};
+
+use shared::{
+    domain::{
+        base_errors::BaseRepositoryError, bus::event::EventBus, storage::FileStorageRepository,
+    },
+    DONA_STORAGE_MODEL,
+};
+use time::OffsetDateTime;
+
+use crate::{
+    attachment::domain::{
+        dona_attachment::{DonaAttachment, ERR_TOO_MANY_DONA_ATTACHMENTS, MAX_DONA_ATTACHMENTS},
+        dona_attachment_repository::DonaAttachmentRepository,
+    },
+    dona::domain::{
+        dona::{DonaId, ERR_DONA_NOT_FOUND},
+        dona_repository::DonaRepository,
+    },
+};
+
+#[derive(Clone)]
+pub struct DonaAttachmentAdder {
+    attachment_repository: Arc<dyn DonaAttachmentRepository>,
+    dona_repository: Arc<dyn DonaRepository>,
+    storage_repository: Arc<dyn FileStorageRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl DonaAttachmentAdder {
+    pub fn new(
+        attachment_repository: Arc<dyn DonaAttachmentRepository>,
+        dona_repository: Arc<dyn DonaRepository>,
+        storage_repository: Arc<dyn FileStorageRepository>,
+        event_bus: Arc<dyn EventBus>,
+    ) -> Self {
+        Self {
+            attachment_repository,
+            dona_repository,
+            storage_repository,
+            event_bus,
+        }
+    }
+
+    /// Only the donor who sent the dona may attach files to it, for anyone else the dona
+    /// does not exist.
+    pub async fn execute(
+        &self,
+        id: String,
+        dona_id: String,
+        uploader_id: String,
+        filename: String,
+        content_type: String,
+        file: File,
+        created_at: OffsetDateTime,
+    ) -> Result<(), String> {
+        let dona_id = DonaId::new(dona_id)?;
+        let dona = self
+            .dona_repository
+            .find_by_id(dona_id.clone())
+            .await
+            .map_err(|e| match e {
+                BaseRepositoryError::NotFound => ERR_DONA_NOT_FOUND.to_string(),
+                e => e.to_string(),
+            })?;
+        if dona.sender_id().as_deref() != Some(uploader_id.as_str()) {
+            return Err(ERR_DONA_NOT_FOUND.to_string());
+        }
+
+        let size = file.metadata().map_err(|e| e.to_string())?.len();
+        let mut attachment = DonaAttachment::create(
+            id,
+            &dona,
+            uploader_id,
+            filename,
+            content_type,
+            size,
+            created_at,
+        )?;
+
+        let attachments = self.attachment_repository.find_by_dona_id(dona_id).await?;
+        if attachments.len() >= MAX_DONA_ATTACHMENTS {
+            return Err(ERR_TOO_MANY_DONA_ATTACHMENTS.to_string());
+        }
+
+        self.storage_repository
+            .save(
+                DONA_STORAGE_MODEL.to_string(),
+                attachment.dona_id(),
+                attachment.stored_filename(),
+                file,
+            )
+            .await?;
+        self.attachment_repository.save(&attachment).await?;
+
+        self.event_bus.publish(attachment.pull_events()).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+use shared::domain::bus::query::{Query, QueryError, QueryHandler, Response};
+
+use super::service::DonaAttachmentDownloader;
+
+pub const DOWNLOAD_DONA_ATTACHMENT_QUERY_TYPE: &str = "dona.download_dona_attachment.query";
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DownloadDonaAttachmentQuery {
+    pub id: String,
+    pub requester_id: String,
+    pub is_admin: bool,
+}
+
+impl Query for DownloadDonaAttachmentQuery {
+    fn query_type(&self) -> &'static str {
+        DOWNLOAD_DONA_ATTACHMENT_QUERY_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct DownloadDonaAttachmentQueryHandler {
+    service: DonaAttachmentDownloader,
+}
+
+impl DownloadDonaAttachmentQueryHandler {
+    pub fn new(service: DonaAttachmentDownloader) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler for DownloadDonaAttachmentQueryHandler {
+    async fn handle(&self, query: Box<dyn Query>) -> Result<Box<dyn Response>, QueryError> {
+        let query = query
+            .as_any()
+            .downcast_ref::<DownloadDonaAttachmentQuery>()
+            .ok_or_else(|| QueryError::new("Invalid query".to_string()))?;
+
+        let file = self
+            .service
+            .execute(
+                query.id.to_owned(),
+                query.requester_id.to_owned(),
+                query.is_admin,
+            )
+            .await
+            .map_err(QueryError::new)?;
+
+        Ok(Box::new(file))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Seek, SeekFrom, Write};
+    use std::sync::Arc;
+
+    use mockall::predicate;
+    use shared::{
+        domain::{
+            base_errors::BaseRepositoryError, storage::tests::MockFileStorageRepository,
+            utils::new_uuid,
+        },
+        DONA_STORAGE_MODEL,
+    };
+
+    use super::*;
+
+    use crate::attachment::application::response::DonaAttachmentFileResponse;
+    use crate::attachment::domain::dona_attachment::tests::DonaAttachmentMother;
+    use crate::attachment::domain::dona_attachment::{
+        DonaAttachment, ERR_DONA_ATTACHMENT_FORBIDDEN, ERR_DONA_ATTACHMENT_NOT_FOUND,
+    };
+    use crate::attachment::domain::dona_attachment_repository::tests::MockDonaAttachmentRepository;
+
+    fn handler(
+        attachment: Option<DonaAttachment>,
+        storage_repository: MockFileStorageRepository,
+    ) -> DownloadDonaAttachmentQueryHandler {
+        let mut attachment_repository = MockDonaAttachmentRepository::new();
+        attachment_repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(attachment.ok_or(BaseRepositoryError::NotFound));
+
+        DownloadDonaAttachmentQueryHandler::new(DonaAttachmentDownloader::new(
+            Arc::new(attachment_repository),
+            Arc::new(storage_repository),
+        ))
+    }
+
+    #[tokio::test]
+    async fn it_should_let_recipient_and_admin_download() {
+        let attachment = DonaAttachmentMother::random();
+
+        for (requester_id, is_admin) in [(attachment.user_id(), false), (new_uuid(), true)] {
+            let mut storage_repository = MockFileStorageRepository::new();
+            storage_repository
+                .expect_get()
+                .with(
+                    predicate::eq(DONA_STORAGE_MODEL.to_string()),
+                    predicate::eq(attachment.dona_id()),
+                    predicate::eq(attachment.stored_filename()),
+                )
+                .times(1)
+                .returning(|_, _, _| {
+                    let mut file = tempfile::tempfile().unwrap();
+                    file.write_all(b"%PDF-1.4").unwrap();
+                    file.seek(SeekFrom::Start(0)).unwrap();
+                    Ok(file)
+                });
+
+            let response = handler(Some(attachment.clone()), storage_repository)
+                .handle(Box::new(DownloadDonaAttachmentQuery {
+                    id: attachment.id(),
+                    requester_id,
+                    is_admin,
+                }))
+                .await
+                .unwrap();
+            let response = response
+                .as_any()
+                .downcast_ref::<DonaAttachmentFileResponse>()
+                .unwrap()
+                .to_owned();
+
+            assert_eq!(
+                response,
+                DonaAttachmentFileResponse {
+                    filename: "transfer.pdf".to_string(),
+                    content_type: "application/pdf".to_string(),
+                    content: b"%PDF-1.4".to_vec(),
+                }
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_forbid_the_uploader_and_other_users() {
+        let attachment = DonaAttachmentMother::random();
+
+        for requester_id in [attachment.uploader_id(), new_uuid()] {
+            let mut storage_repository = MockFileStorageRepository::new();
+            storage_repository.expect_get().times(0);
+
+            let result = handler(Some(attachment.clone()), storage_repository)
+                .handle(Box::new(DownloadDonaAttachmentQuery {
+                    id: attachment.id(),
+                    requester_id,
+                    is_admin: false,
+                }))
+                .await;
+
+            assert_eq!(
+                result.err(),
+                Some(QueryError::new(ERR_DONA_ATTACHMENT_FORBIDDEN.to_string()))
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_attachment_does_not_exist() {
+        let mut storage_repository = MockFileStorageRepository::new();
+        storage_repository.expect_get().times(0);
+
+        let result = handler(None, storage_repository)
+            .handle(Box::new(DownloadDonaAttachmentQuery {
+                id: new_uuid(),
+                requester_id: new_uuid(),
+                is_admin: true,
+            }))
+            .await;
+
+        assert_eq!(
+            result.err(),
+            Some(QueryError::new(ERR_DONA_ATTACHMENT_NOT_FOUND.to_string()))
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use std::{io::Read, sync::Arc};
+
+use shared::{domain::storage::FileStorageRepository, DONA_STORAGE_MODEL};
+
+use crate::attachment::{
+    application::response::DonaAttachmentFileResponse,
+    domain::{
+        dona_attachment::{
+            DonaAttachmentId, ERR_DONA_ATTACHMENT_FORBIDDEN, ERR_DONA_ATTACHMENT_NOT_FOUND,
+        },
+        dona_attachment_repository::DonaAttachmentRepository,
+    },
+};
+
+#[derive(Clone)]
+pub struct DonaAttachmentDownloader {
+    attachment_repository: Arc<dyn DonaAttachmentRepository>,
+    storage_repository: Arc<dyn FileStorageRepository>,
+}
+
+impl DonaAttachmentDownloader {
+    pub fn new(
+        attachment_repository: Arc<dyn DonaAttachmentRepository>,
+        storage_repository: Arc<dyn FileStorageRepository>,
+    ) -> Self {
+        Self {
+            attachment_repository,
+            storage_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        id: String,
+        requester_id: String,
+        is_admin: bool,
+    ) -> Result<DonaAttachmentFileResponse, String> {
+        let attachment = self
+            .attachment_repository
+            .find_by_id(DonaAttachmentId::new(id)?)
+            .await
+            .map_err(|_| ERR_DONA_ATTACHMENT_NOT_FOUND.to_string())?;
+
+        if !is_admin && !attachment.can_be_read_by(&requester_id) {
+            return Err(ERR_DONA_ATTACHMENT_FORBIDDEN.to_string());
+        }
+
+        let mut file = self
+            .storage_repository
+            .get(
+                DONA_STORAGE_MODEL.to_string(),
+                attachment.dona_id(),
+                attachment.stored_filename(),
+            )
+            .await?;
+        let mut content = Vec::new();
+        file.read_to_end(&mut content).map_err(|e| e.to_string())?;
+
+        Ok(DonaAttachmentFileResponse {
+            filename: attachment.filename(),
+            content_type: attachment.content_type(),
+            content,
+        })
+    }
+}
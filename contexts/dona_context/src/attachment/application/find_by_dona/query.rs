@@ -0,0 +1,151 @@
+use shared::domain::bus::query::{Query, QueryError, QueryHandler, Response};
+
+use super::service::DonaAttachmentsFinder;
+
+pub const FIND_DONA_ATTACHMENTS_QUERY_TYPE: &str = "dona.find_dona_attachments.query";
+
+/// `is_admin` is resolved by the caller through the security context, admins may read the
+/// attachments of any dona.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FindDonaAttachmentsQuery {
+    pub dona_id: String,
+    pub requester_id: String,
+    pub is_admin: bool,
+}
+
+impl Query for FindDonaAttachmentsQuery {
+    fn query_type(&self) -> &'static str {
+        FIND_DONA_ATTACHMENTS_QUERY_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct FindDonaAttachmentsQueryHandler {
+    service: DonaAttachmentsFinder,
+}
+
+impl FindDonaAttachmentsQueryHandler {
+    pub fn new(service: DonaAttachmentsFinder) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler for FindDonaAttachmentsQueryHandler {
+    async fn handle(&self, query: Box<dyn Query>) -> Result<Box<dyn Response>, QueryError> {
+        let query = query
+            .as_any()
+            .downcast_ref::<FindDonaAttachmentsQuery>()
+            .ok_or_else(|| QueryError::new("Invalid query".to_string()))?;
+
+        let attachments = self
+            .service
+            .execute(
+                query.dona_id.to_owned(),
+                query.requester_id.to_owned(),
+                query.is_admin,
+            )
+            .await
+            .map_err(QueryError::new)?;
+
+        Ok(Box::new(attachments))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockall::predicate;
+    use shared::domain::utils::new_uuid;
+
+    use super::*;
+
+    use crate::attachment::application::response::{
+        DonaAttachmentResponse, DonaAttachmentsResponse,
+    };
+    use crate::attachment::domain::dona_attachment::tests::DonaAttachmentMother;
+    use crate::attachment::domain::dona_attachment::ERR_DONA_ATTACHMENT_FORBIDDEN;
+    use crate::attachment::domain::dona_attachment_repository::tests::MockDonaAttachmentRepository;
+    use crate::dona::domain::dona::tests::DonaMother;
+    use crate::dona::domain::dona::{Dona, DonaId};
+    use crate::dona::domain::dona_repository::tests::MockDonaRepository;
+
+    fn handler(
+        dona: Dona,
+        attachment_repository: MockDonaAttachmentRepository,
+    ) -> FindDonaAttachmentsQueryHandler {
+        let mut dona_repository = MockDonaRepository::new();
+        dona_repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(dona));
+
+        FindDonaAttachmentsQueryHandler::new(DonaAttachmentsFinder::new(
+            Arc::new(attachment_repository),
+            Arc::new(dona_repository),
+        ))
+    }
+
+    #[tokio::test]
+    async fn it_should_list_attachments_to_recipient_and_admin() {
+        let dona = DonaMother::random();
+        let attachment =
+            DonaAttachmentMother::create(None, Some(dona.id()), Some(dona.user_id()), None);
+
+        for (requester_id, is_admin) in [(dona.user_id(), false), (new_uuid(), true)] {
+            let mut attachment_repository = MockDonaAttachmentRepository::new();
+            attachment_repository
+                .expect_find_by_dona_id()
+                .with(predicate::eq(DonaId::new(dona.id()).unwrap()))
+                .times(1)
+                .return_const(Ok(vec![attachment.clone()]));
+
+            let response = handler(dona.clone(), attachment_repository)
+                .handle(Box::new(FindDonaAttachmentsQuery {
+                    dona_id: dona.id(),
+                    requester_id,
+                    is_admin,
+                }))
+                .await
+                .unwrap();
+            let response = response
+                .as_any()
+                .downcast_ref::<DonaAttachmentsResponse>()
+                .unwrap()
+                .to_owned();
+
+            assert_eq!(
+                response.attachments,
+                vec![DonaAttachmentResponse::from(attachment.clone())]
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_forbid_the_sender_and_other_users() {
+        let dona = DonaMother::random();
+
+        for requester_id in [dona.sender_id().unwrap(), new_uuid()] {
+            let mut attachment_repository = MockDonaAttachmentRepository::new();
+            attachment_repository.expect_find_by_dona_id().times(0);
+
+            let result = handler(dona.clone(), attachment_repository)
+                .handle(Box::new(FindDonaAttachmentsQuery {
+                    dona_id: dona.id(),
+                    requester_id,
+                    is_admin: false,
+                }))
+                .await;
+
+            assert_eq!(
+                result.err(),
+                Some(QueryError::new(ERR_DONA_ATTACHMENT_FORBIDDEN.to_string()))
+            );
+        }
+    }
+}
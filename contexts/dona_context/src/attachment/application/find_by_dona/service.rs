@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use shared::domain::base_errors::BaseRepositoryError;
+
+use crate::{
+    attachment::{
+        application::response::{DonaAttachmentResponse, DonaAttachmentsResponse},
+        domain::{
+            dona_attachment::ERR_DONA_ATTACHMENT_FORBIDDEN,
+            dona_attachment_repository::DonaAttachmentRepository,
+        },
+    },
+    dona::domain::{
+        dona::{DonaId, ERR_DONA_NOT_FOUND},
+        dona_repository::DonaRepository,
+    },
+};
+
+#[derive(Clone)]
+pub struct DonaAttachmentsFinder {
+    attachment_repository: Arc<dyn DonaAttachmentRepository>,
+    dona_repository: Arc<dyn DonaRepository>,
+}
+
+impl DonaAttachmentsFinder {
+    pub fn new(
+        attachment_repository: Arc<dyn DonaAttachmentRepository>,
+        dona_repository: Arc<dyn DonaRepository>,
+    ) -> Self {
+        Self {
+            attachment_repository,
+            dona_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        dona_id: String,
+        requester_id: String,
+        is_admin: bool,
+    ) -> Result<DonaAttachmentsResponse, String> {
+        let dona_id = DonaId::new(dona_id)?;
+        let dona = self
+            .dona_repository
+            .find_by_id(dona_id.clone())
+            .await
+            .map_err(|e| match e {
+                BaseRepositoryError::NotFound => ERR_DONA_NOT_FOUND.to_string(),
+                e => e.to_string(),
+            })?;
+        if !is_admin && dona.user_id() != requester_id {
+            return Err(ERR_DONA_ATTACHMENT_FORBIDDEN.to_string());
+        }
+
+        let attachments = self.attachment_repository.find_by_dona_id(dona_id).await?;
+
+        Ok(DonaAttachmentsResponse {
+            attachments: attachments
+                .into_iter()
+                .map(DonaAttachmentResponse::from)
+                .collect(),
+        })
+    }
+}
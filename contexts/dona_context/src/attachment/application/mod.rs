@@ -0,0 +1,5 @@
+pub mod add;
+pub mod download;
+pub mod find_by_dona;
+pub mod remove_on_dona_deleted;
+pub mod response;
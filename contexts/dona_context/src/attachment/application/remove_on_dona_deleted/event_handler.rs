@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use shared::domain::bus::event::{Event, EventError, EventHandler};
+
+use crate::dona::domain::dona_deleted_event::{DonaDeletedEvent, DONA_DELETED_EVENT_TYPE};
+
+use super::service::DonaAttachmentsRemover;
+
+#[derive(Clone)]
+pub struct RemoveDonaAttachmentsOnDonaDeleted {
+    service: DonaAttachmentsRemover,
+}
+
+impl RemoveDonaAttachmentsOnDonaDeleted {
+    pub fn new(service: DonaAttachmentsRemover) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl EventHandler for RemoveDonaAttachmentsOnDonaDeleted {
+    async fn handle(&self, event: Arc<dyn Event>) -> Result<(), EventError> {
+        let event = event
+            .as_any()
+            .downcast_ref::<DonaDeletedEvent>()
+            .ok_or_else(|| EventError::new("Invalid event".to_string()))?;
+
+        self.service
+            .execute(event.dona_id().to_string())
+            .await
+            .map_err(EventError::new)
+    }
+
+    fn subscribed_to(&self) -> Vec<&'static str> {
+        vec![DONA_DELETED_EVENT_TYPE]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate;
+    use shared::{
+        domain::{storage::tests::MockFileStorageRepository, utils::new_uuid},
+        DONA_STORAGE_MODEL,
+    };
+
+    use super::*;
+
+    use crate::attachment::domain::dona_attachment::tests::DonaAttachmentMother;
+    use crate::attachment::domain::dona_attachment_repository::tests::MockDonaAttachmentRepository;
+    use crate::dona::domain::dona::DonaId;
+
+    fn event(dona_id: String) -> Arc<dyn Event> {
+        Arc::new(DonaDeletedEvent::new(
+            dona_id,
+            new_uuid(),
+            "2024-04-09T10:00:00Z".to_string(),
+        ))
+    }
+
+    #[tokio::test]
+    async fn it_should_remove_the_rows_and_files_of_the_dona() {
+        let dona_id = new_uuid();
+        let attachments = vec![
+            DonaAttachmentMother::create(None, Some(dona_id.clone()), None, None),
+            DonaAttachmentMother::create(None, Some(dona_id.clone()), None, None),
+        ];
+
+        let mut attachment_repository = MockDonaAttachmentRepository::new();
+        attachment_repository
+            .expect_find_by_dona_id()
+            .times(1)
+            .return_const(Ok(attachments.clone()));
+        attachment_repository
+            .expect_delete_by_dona_id()
+            .with(predicate::eq(DonaId::new(dona_id.clone()).unwrap()))
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut storage_repository = MockFileStorageRepository::new();
+        for attachment in attachments {
+            storage_repository
+                .expect_delete()
+                .with(
+                    predicate::eq(DONA_STORAGE_MODEL.to_string()),
+                    predicate::eq(dona_id.clone()),
+                    predicate::eq(attachment.stored_filename()),
+                )
+                .times(1)
+                .return_const(Ok(()));
+        }
+
+        let handler = RemoveDonaAttachmentsOnDonaDeleted::new(DonaAttachmentsRemover::new(
+            Arc::new(attachment_repository),
+            Arc::new(storage_repository),
+        ));
+
+        let result = handler.handle(event(dona_id)).await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_do_nothing_without_attachments() {
+        let mut attachment_repository = MockDonaAttachmentRepository::new();
+        attachment_repository
+            .expect_find_by_dona_id()
+            .times(1)
+            .return_const(Ok(vec![]));
+        attachment_repository.expect_delete_by_dona_id().times(0);
+
+        let mut storage_repository = MockFileStorageRepository::new();
+        storage_repository.expect_delete().times(0);
+
+        let handler = RemoveDonaAttachmentsOnDonaDeleted::new(DonaAttachmentsRemover::new(
+            Arc::new(attachment_repository),
+            Arc::new(storage_repository),
+        ));
+
+        let result = handler.handle(event(new_uuid())).await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+}
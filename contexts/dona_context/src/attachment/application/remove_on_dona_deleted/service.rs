@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use shared::{domain::storage::FileStorageRepository, DONA_STORAGE_MODEL};
+
+use crate::{
+    attachment::domain::dona_attachment_repository::DonaAttachmentRepository,
+    dona::domain::dona::DonaId,
+};
+
+#[derive(Clone)]
+pub struct DonaAttachmentsRemover {
+    attachment_repository: Arc<dyn DonaAttachmentRepository>,
+    storage_repository: Arc<dyn FileStorageRepository>,
+}
+
+impl DonaAttachmentsRemover {
+    pub fn new(
+        attachment_repository: Arc<dyn DonaAttachmentRepository>,
+        storage_repository: Arc<dyn FileStorageRepository>,
+    ) -> Self {
+        Self {
+            attachment_repository,
+            storage_repository,
+        }
+    }
+
+    /// The rows go first, so delivering the event again finds nothing left to remove instead
+    /// of failing on the files already gone.
+    pub async fn execute(&self, dona_id: String) -> Result<(), String> {
+        let dona_id = DonaId::new(dona_id)?;
+        let attachments = self
+            .attachment_repository
+            .find_by_dona_id(dona_id.clone())
+            .await?;
+        if attachments.is_empty() {
+            return Ok(());
+        }
+
+        self.attachment_repository
+            .delete_by_dona_id(dona_id)
+            .await?;
+
+        for attachment in attachments {
+            self.storage_repository
+                .delete(
+                    DONA_STORAGE_MODEL.to_string(),
+                    attachment.dona_id(),
+                    attachment.stored_filename(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use shared::domain::bus::query::Response;
+use time::OffsetDateTime;
+
+use crate::attachment::domain::dona_attachment::DonaAttachment;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DonaAttachmentResponse {
+    pub id: String,
+    pub dona_id: String,
+    pub filename: String,
+    pub content_type: String,
+    pub size: u64,
+    pub created_at: OffsetDateTime,
+}
+
+impl From<DonaAttachment> for DonaAttachmentResponse {
+    fn from(attachment: DonaAttachment) -> Self {
+        Self {
+            id: attachment.id(),
+            dona_id: attachment.dona_id(),
+            filename: attachment.filename(),
+            content_type: attachment.content_type(),
+            size: attachment.size(),
+            created_at: attachment.created_at(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DonaAttachmentsResponse {
+    pub attachments: Vec<DonaAttachmentResponse>,
+}
+
+impl Response for DonaAttachmentsResponse {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DonaAttachmentFileResponse {
+    pub filename: String,
+    pub content_type: String,
+    pub content: Vec<u8>,
+}
+
+impl Response for DonaAttachmentFileResponse {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
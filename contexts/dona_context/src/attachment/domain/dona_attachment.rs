@@ -0,0 +1,357 @@
+use std::fmt::Display;
+use std::sync::Arc;
+
+use shared::domain::{bus::event::Event, utils::is_uuid, value_objects::user_id::UserId};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+use crate::dona::domain::dona::{Dona, DonaId, DonaStatus};
+use crate::shared::domain::dona::DonaOptionMethod;
+
+use super::dona_attachment_added_event::DonaAttachmentAddedEvent;
+
+/// Proofs of payment a donor can attach to a single dona.
+pub const MAX_DONA_ATTACHMENTS: usize = 5;
+
+pub const ERR_DONA_ATTACHMENT_NOT_FOUND: &str = "Dona Attachment not found";
+pub const ERR_DONA_ATTACHMENT_FORBIDDEN: &str =
+    "Dona Attachments can only be read by the recipient of the Dona";
+pub const ERR_DONA_NOT_ACCEPTING_ATTACHMENTS: &str =
+    "Attachments can only be added to pending manual Donas";
+pub const ERR_TOO_MANY_DONA_ATTACHMENTS: &str = "Dona has too many attachments";
+
+pub const ERR_INVALID_DONA_ATTACHMENT_ID: &str = "Invalid Dona Attachment ID";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct DonaAttachmentId(String);
+
+impl DonaAttachmentId {
+    pub fn new(value: String) -> Result<Self, String> {
+        if is_uuid(&value) {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_DONA_ATTACHMENT_ID.to_string())
+        }
+    }
+}
+
+impl Display for DonaAttachmentId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub const ERR_INVALID_DONA_ATTACHMENT_FILENAME: &str = "Invalid Dona Attachment Filename";
+
+/// Name of the file as uploaded by the donor, only shown back to the recipient. The file is
+/// stored under its id so the name never reaches the storage path.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct DonaAttachmentFilename(String);
+
+impl DonaAttachmentFilename {
+    pub fn new(value: String) -> Result<Self, String> {
+        let value = value.trim().to_string();
+
+        if value.is_empty()
+            || value.chars().count() > 255
+            || value.contains(['/', '\\'])
+            || value.chars().any(char::is_control)
+        {
+            return Err(ERR_INVALID_DONA_ATTACHMENT_FILENAME.to_string());
+        }
+
+        Ok(Self(value))
+    }
+}
+
+impl Display for DonaAttachmentFilename {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub const ERR_INVALID_DONA_ATTACHMENT_CONTENT_TYPE: &str = "Invalid Dona Attachment Content Type";
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum DonaAttachmentContentType {
+    Jpeg,
+    Png,
+    Gif,
+    Webp,
+    Pdf,
+}
+
+impl DonaAttachmentContentType {
+    pub fn new(value: String) -> Result<Self, String> {
+        match value.as_str() {
+            "image/jpeg" => Ok(Self::Jpeg),
+            "image/png" => Ok(Self::Png),
+            "image/gif" => Ok(Self::Gif),
+            "image/webp" => Ok(Self::Webp),
+            "application/pdf" => Ok(Self::Pdf),
+            _ => Err(ERR_INVALID_DONA_ATTACHMENT_CONTENT_TYPE.to_string()),
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Jpeg => "jpg",
+            Self::Png => "png",
+            Self::Gif => "gif",
+            Self::Webp => "webp",
+            Self::Pdf => "pdf",
+        }
+    }
+}
+
+impl Display for DonaAttachmentContentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Jpeg => write!(f, "image/jpeg"),
+            Self::Png => write!(f, "image/png"),
+            Self::Gif => write!(f, "image/gif"),
+            Self::Webp => write!(f, "image/webp"),
+            Self::Pdf => write!(f, "application/pdf"),
+        }
+    }
+}
+
+pub const ERR_INVALID_DONA_ATTACHMENT_SIZE: &str = "Invalid Dona Attachment Size";
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct DonaAttachmentSize(u64);
+
+impl DonaAttachmentSize {
+    pub fn new(value: u64) -> Result<Self, String> {
+        if value > 0 {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_DONA_ATTACHMENT_SIZE.to_string())
+        }
+    }
+
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+pub const ERR_INVALID_DONA_ATTACHMENT_CREATED_AT: &str = "Invalid Dona Attachment Created At";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct DonaAttachmentCreatedAt(OffsetDateTime);
+
+impl DonaAttachmentCreatedAt {
+    pub fn new(value: OffsetDateTime) -> Result<Self, String> {
+        if value < OffsetDateTime::now_utc() {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_DONA_ATTACHMENT_CREATED_AT.to_string())
+        }
+    }
+}
+
+impl Display for DonaAttachmentCreatedAt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.format(&Rfc3339).unwrap())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DonaAttachment {
+    id: DonaAttachmentId,
+    dona_id: DonaId,
+    user_id: UserId,
+    uploader_id: UserId,
+    filename: DonaAttachmentFilename,
+    content_type: DonaAttachmentContentType,
+    size: DonaAttachmentSize,
+    created_at: DonaAttachmentCreatedAt,
+
+    events: Vec<Arc<dyn Event>>,
+}
+
+impl PartialEq for DonaAttachment {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.dona_id == other.dona_id
+            && self.user_id == other.user_id
+            && self.uploader_id == other.uploader_id
+            && self.filename == other.filename
+            && self.content_type == other.content_type
+            && self.size == other.size
+            && self.created_at == other.created_at
+    }
+}
+
+impl Eq for DonaAttachment {}
+
+impl DonaAttachment {
+    pub(crate) fn new(
+        id: String,
+        dona_id: String,
+        user_id: String,
+        uploader_id: String,
+        filename: String,
+        content_type: String,
+        size: u64,
+        created_at: OffsetDateTime,
+    ) -> Result<Self, String> {
+        Ok(Self {
+            id: DonaAttachmentId::new(id)?,
+            dona_id: DonaId::new(dona_id)?,
+            user_id: UserId::new(user_id)?,
+            uploader_id: UserId::new(uploader_id)?,
+            filename: DonaAttachmentFilename::new(filename)?,
+            content_type: DonaAttachmentContentType::new(content_type)?,
+            size: DonaAttachmentSize::new(size)?,
+            created_at: DonaAttachmentCreatedAt::new(created_at)?,
+            events: vec![],
+        })
+    }
+
+    /// Only manual donas wait for the recipient to check the payment, so they are the only
+    /// ones a proof makes sense for, and only while they are still pending.
+    pub fn create(
+        id: String,
+        dona: &Dona,
+        uploader_id: String,
+        filename: String,
+        content_type: String,
+        size: u64,
+        created_at: OffsetDateTime,
+    ) -> Result<Self, String> {
+        if dona.status() != DonaStatus::Pending.to_string()
+            || dona.method() != DonaOptionMethod::Manual.to_string()
+        {
+            return Err(ERR_DONA_NOT_ACCEPTING_ATTACHMENTS.to_string());
+        }
+
+        let mut attachment = Self::new(
+            id,
+            dona.id(),
+            dona.user_id(),
+            uploader_id,
+            filename,
+            content_type,
+            size,
+            created_at,
+        )?;
+
+        let event = DonaAttachmentAddedEvent::new(
+            attachment.id(),
+            attachment.dona_id(),
+            attachment.filename(),
+            attachment.content_type(),
+            attachment.created_at.to_string(),
+        );
+        attachment.record(Arc::new(event));
+
+        Ok(attachment)
+    }
+
+    pub fn can_be_read_by(&self, user_id: &str) -> bool {
+        self.user_id.to_string() == user_id
+    }
+
+    /// Name of the file in the storage, next to the other attachments of the dona.
+    pub fn stored_filename(&self) -> String {
+        format!("{}.{}", self.id, self.content_type.extension())
+    }
+
+    pub fn record(&mut self, event: Arc<dyn Event>) {
+        self.events.push(event);
+    }
+
+    pub fn pull_events(&mut self) -> Vec<Arc<dyn Event>> {
+        let events = self.events.clone();
+        self.events = vec![];
+        events
+    }
+
+    pub fn id(&self) -> String {
+        self.id.to_string()
+    }
+
+    pub fn dona_id(&self) -> String {
+        self.dona_id.to_string()
+    }
+
+    pub fn user_id(&self) -> String {
+        self.user_id.to_string()
+    }
+
+    pub fn uploader_id(&self) -> String {
+        self.uploader_id.to_string()
+    }
+
+    pub fn filename(&self) -> String {
+        self.filename.to_string()
+    }
+
+    pub fn content_type(&self) -> String {
+        self.content_type.to_string()
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size.value()
+    }
+
+    pub fn created_at(&self) -> OffsetDateTime {
+        self.created_at.0
+    }
+}
+
+pub mod tests {
+    use shared::domain::{utils::new_uuid, value_objects::user_id::tests::UserIdMother};
+    use time::Duration;
+
+    use crate::dona::domain::dona::tests::DonaIdMother;
+
+    use super::*;
+
+    pub struct DonaAttachmentIdMother;
+
+    impl DonaAttachmentIdMother {
+        pub fn random() -> DonaAttachmentId {
+            DonaAttachmentId::new(new_uuid()).unwrap()
+        }
+
+        pub fn create(value: Option<String>) -> DonaAttachmentId {
+            match value {
+                Some(value) => DonaAttachmentId::new(value).unwrap(),
+                None => Self::random(),
+            }
+        }
+    }
+
+    pub struct DonaAttachmentMother;
+
+    impl DonaAttachmentMother {
+        pub fn random() -> DonaAttachment {
+            Self::create(None, None, None, None)
+        }
+
+        /// A PDF uploaded a minute ago unless told otherwise.
+        pub fn create(
+            id: Option<String>,
+            dona_id: Option<String>,
+            user_id: Option<String>,
+            uploader_id: Option<String>,
+        ) -> DonaAttachment {
+            DonaAttachment {
+                id: DonaAttachmentIdMother::create(id),
+                dona_id: DonaIdMother::create(dona_id),
+                user_id: UserIdMother::create(user_id),
+                uploader_id: UserIdMother::create(uploader_id),
+                filename: DonaAttachmentFilename::new("transfer.pdf".to_string()).unwrap(),
+                content_type: DonaAttachmentContentType::Pdf,
+                size: DonaAttachmentSize::new(1024).unwrap(),
+                created_at: DonaAttachmentCreatedAt::new(
+                    OffsetDateTime::now_utc() - Duration::minutes(1),
+                )
+                .unwrap(),
+
+                events: vec![],
+            }
+        }
+    }
+}
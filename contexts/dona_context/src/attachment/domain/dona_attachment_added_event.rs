@@ -0,0 +1,121 @@
+use shared::domain::bus::event::{BaseEvent, Event, EventDeserializeError, EventSerialized};
+
+pub const DONA_ATTACHMENT_ADDED_EVENT_TYPE: &str = "dona.dona_attachment_added";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct DonaAttachmentAddedEvent {
+    id: String,
+    dona_id: String,
+    filename: String,
+    content_type: String,
+    created_at: String,
+
+    base_event: BaseEvent,
+}
+
+impl DonaAttachmentAddedEvent {
+    pub fn new(
+        id: String,
+        dona_id: String,
+        filename: String,
+        content_type: String,
+        created_at: String,
+    ) -> Self {
+        Self {
+            id: id.clone(),
+            dona_id,
+            filename,
+            content_type,
+            created_at,
+            base_event: BaseEvent::new(id),
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn dona_id(&self) -> &str {
+        &self.dona_id
+    }
+
+    pub fn filename(&self) -> &str {
+        &self.filename
+    }
+
+    pub fn content_type(&self) -> &str {
+        &self.content_type
+    }
+
+    pub fn created_at(&self) -> &str {
+        &self.created_at
+    }
+}
+
+impl Event for DonaAttachmentAddedEvent {
+    fn event_type(&self) -> &'static str {
+        DONA_ATTACHMENT_ADDED_EVENT_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn from_primitives(
+        &self,
+        primitives: EventSerialized,
+    ) -> Result<Box<dyn Event>, EventDeserializeError> {
+        let data = primitives.data();
+        let base_event = BaseEvent::from_primitives(
+            primitives.event_id().to_string(),
+            primitives.aggregate_id().to_string(),
+            primitives.occurred_at().to_string(),
+        );
+
+        let id = data
+            .get("id")
+            .ok_or(EventDeserializeError::MissingField("id".to_string()))?;
+        let dona_id = data
+            .get("dona_id")
+            .ok_or(EventDeserializeError::MissingField("dona_id".to_string()))?;
+        let filename = data
+            .get("filename")
+            .ok_or(EventDeserializeError::MissingField("filename".to_string()))?;
+        let content_type = data
+            .get("content_type")
+            .ok_or(EventDeserializeError::MissingField(
+                "content_type".to_string(),
+            ))?;
+        let created_at = data
+            .get("created_at")
+            .ok_or(EventDeserializeError::MissingField(
+                "created_at".to_string(),
+            ))?;
+
+        Ok(Box::new(Self {
+            id: id.to_string(),
+            dona_id: dona_id.to_string(),
+            filename: filename.to_string(),
+            content_type: content_type.to_string(),
+            created_at: created_at.to_string(),
+            base_event,
+        }))
+    }
+
+    fn to_primitives(&self) -> EventSerialized {
+        EventSerialized::new(
+            self.base_event.event_id().to_string(),
+            self.base_event.aggregate_id().to_string(),
+            self.base_event.occurred_at().to_string(),
+            vec![
+                ("id".to_string(), self.id.clone()),
+                ("dona_id".to_string(), self.dona_id.clone()),
+                ("filename".to_string(), self.filename.clone()),
+                ("content_type".to_string(), self.content_type.clone()),
+                ("created_at".to_string(), self.created_at.clone()),
+            ]
+            .into_iter()
+            .collect(),
+        )
+    }
+}
@@ -0,0 +1,37 @@
+use shared::domain::base_errors::BaseRepositoryError;
+
+use crate::dona::domain::dona::DonaId;
+
+use super::dona_attachment::{DonaAttachment, DonaAttachmentId};
+
+#[async_trait::async_trait]
+pub trait DonaAttachmentRepository: Send + Sync {
+    async fn find_by_id(&self, id: DonaAttachmentId)
+        -> Result<DonaAttachment, BaseRepositoryError>;
+    /// Oldest first.
+    async fn find_by_dona_id(
+        &self,
+        dona_id: DonaId,
+    ) -> Result<Vec<DonaAttachment>, BaseRepositoryError>;
+    async fn save(&self, attachment: &DonaAttachment) -> Result<(), BaseRepositoryError>;
+    async fn delete_by_dona_id(&self, dona_id: DonaId) -> Result<(), BaseRepositoryError>;
+}
+
+#[cfg(test)]
+pub mod tests {
+    use mockall::mock;
+
+    use super::*;
+
+    mock! {
+        pub DonaAttachmentRepository {}
+
+        #[async_trait::async_trait]
+        impl DonaAttachmentRepository for DonaAttachmentRepository {
+            async fn find_by_id(&self, id: DonaAttachmentId) -> Result<DonaAttachment, BaseRepositoryError>;
+            async fn find_by_dona_id(&self, dona_id: DonaId) -> Result<Vec<DonaAttachment>, BaseRepositoryError>;
+            async fn save(&self, attachment: &DonaAttachment) -> Result<(), BaseRepositoryError>;
+            async fn delete_by_dona_id(&self, dona_id: DonaId) -> Result<(), BaseRepositoryError>;
+        }
+    }
+}
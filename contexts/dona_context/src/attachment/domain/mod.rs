@@ -0,0 +1,3 @@
+pub mod dona_attachment;
+pub mod dona_attachment_added_event;
+pub mod dona_attachment_repository;
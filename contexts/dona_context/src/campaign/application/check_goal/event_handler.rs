@@ -0,0 +1,262 @@
+use std::sync::Arc;
+
+use shared::domain::bus::event::{Event, EventError, EventHandler};
+
+use crate::dona::domain::dona_confirmed_event::{DonaConfirmedEvent, DONA_CONFIRMED_EVENT_TYPE};
+
+use super::service::CampaignGoalChecker;
+
+#[derive(Clone)]
+pub struct CheckCampaignGoalOnDonaConfirmed {
+    service: CampaignGoalChecker,
+}
+
+impl CheckCampaignGoalOnDonaConfirmed {
+    pub fn new(service: CampaignGoalChecker) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl EventHandler for CheckCampaignGoalOnDonaConfirmed {
+    async fn handle(&self, event: Arc<dyn Event>) -> Result<(), EventError> {
+        let event = event
+            .as_any()
+            .downcast_ref::<DonaConfirmedEvent>()
+            .ok_or_else(|| EventError::new("Invalid event".to_string()))?;
+
+        self.service
+            .execute(event.dona_id().to_string())
+            .await
+            .map_err(EventError::new)
+    }
+
+    fn subscribed_to(&self) -> Vec<&'static str> {
+        vec![DONA_CONFIRMED_EVENT_TYPE]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate;
+    use rust_decimal_macros::dec;
+    use shared::domain::bus::event::tests::MockEventBus;
+
+    use super::*;
+
+    use crate::campaign::domain::campaign::tests::CampaignMother;
+    use crate::campaign::domain::campaign::CampaignId;
+    use crate::campaign::domain::campaign_goal_reached_event::{
+        CampaignGoalReachedEvent, CAMPAIGN_GOAL_REACHED_EVENT_TYPE,
+    };
+    use crate::campaign::domain::campaign_repository::tests::MockCampaignRepository;
+    use crate::dona::domain::dona::tests::DonaMother;
+    use crate::dona::domain::dona::Dona;
+    use crate::dona::domain::dona_repository::tests::MockDonaRepository;
+    use crate::dona::domain::dona_repository::DonaTotals;
+
+    fn confirmed_dona(campaign_id: Option<String>) -> Dona {
+        DonaMother::create(
+            None,
+            None,
+            Some(dec!(40.00)),
+            Some("USD".to_string()),
+            Some("confirmed".to_string()),
+            None,
+            None,
+            None,
+            campaign_id,
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn confirmed_event(dona: &Dona) -> Arc<dyn Event> {
+        Arc::new(DonaConfirmedEvent::new(
+            dona.id(),
+            dona.updated_at().to_string(),
+        ))
+    }
+
+    fn handler(
+        campaign_repository: MockCampaignRepository,
+        dona_repository: MockDonaRepository,
+        event_bus: MockEventBus,
+    ) -> CheckCampaignGoalOnDonaConfirmed {
+        CheckCampaignGoalOnDonaConfirmed::new(CampaignGoalChecker::new(
+            Arc::new(campaign_repository),
+            Arc::new(dona_repository),
+            Arc::new(event_bus),
+        ))
+    }
+
+    #[tokio::test]
+    async fn it_should_ignore_donas_without_campaign() {
+        let dona = confirmed_dona(None);
+
+        let mut dona_repository = MockDonaRepository::new();
+        dona_repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(dona.clone()));
+        dona_repository.expect_sum_confirmed_by_campaign().times(0);
+
+        let mut campaign_repository = MockCampaignRepository::new();
+        campaign_repository.expect_find_by_id().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let result = handler(campaign_repository, dona_repository, event_bus)
+            .handle(confirmed_event(&dona))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_not_publish_while_below_target() {
+        let campaign = CampaignMother::create(
+            None,
+            None,
+            None,
+            Some(dec!(100.00)),
+            Some("USD".to_string()),
+            None,
+            None,
+        );
+        let dona = confirmed_dona(Some(campaign.id()));
+
+        let mut dona_repository = MockDonaRepository::new();
+        dona_repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(dona.clone()));
+        dona_repository
+            .expect_sum_confirmed_by_campaign()
+            .times(1)
+            .return_const(Ok(DonaTotals {
+                amount: dec!(99.99),
+                count: 4,
+            }));
+
+        let mut campaign_repository = MockCampaignRepository::new();
+        campaign_repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(campaign));
+        campaign_repository.expect_save().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let result = handler(campaign_repository, dona_repository, event_bus)
+            .handle(confirmed_event(&dona))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_not_publish_twice_once_goal_is_reached() {
+        let campaign = CampaignMother::create(
+            None,
+            None,
+            None,
+            Some(dec!(100.00)),
+            Some("USD".to_string()),
+            None,
+            Some("goal_reached".to_string()),
+        );
+        let dona = confirmed_dona(Some(campaign.id()));
+
+        let mut dona_repository = MockDonaRepository::new();
+        dona_repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(dona.clone()));
+        dona_repository
+            .expect_sum_confirmed_by_campaign()
+            .times(1)
+            .return_const(Ok(DonaTotals {
+                amount: dec!(140.00),
+                count: 5,
+            }));
+
+        let mut campaign_repository = MockCampaignRepository::new();
+        campaign_repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(campaign));
+        campaign_repository.expect_save().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let result = handler(campaign_repository, dona_repository, event_bus)
+            .handle(confirmed_event(&dona))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_publish_goal_reached_when_crossing_target() {
+        let campaign = CampaignMother::create(
+            None,
+            None,
+            None,
+            Some(dec!(100.00)),
+            Some("USD".to_string()),
+            None,
+            None,
+        );
+        let dona = confirmed_dona(Some(campaign.id()));
+
+        let mut dona_repository = MockDonaRepository::new();
+        dona_repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(dona.clone()));
+        dona_repository
+            .expect_sum_confirmed_by_campaign()
+            .with(predicate::eq(CampaignId::new(campaign.id()).unwrap()))
+            .times(1)
+            .return_const(Ok(DonaTotals {
+                amount: dec!(120.00),
+                count: 3,
+            }));
+
+        let mut campaign_repository = MockCampaignRepository::new();
+        campaign_repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(campaign.clone()));
+        campaign_repository
+            .expect_save()
+            .withf(|campaign| campaign.status() == "goal_reached")
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus
+            .expect_publish()
+            .withf(|events| {
+                events.len() == 1
+                    && events[0].event_type() == CAMPAIGN_GOAL_REACHED_EVENT_TYPE
+                    && events[0]
+                        .as_any()
+                        .downcast_ref::<CampaignGoalReachedEvent>()
+                        .is_some_and(|event| event.raised() == "120.00")
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let result = handler(campaign_repository, dona_repository, event_bus)
+            .handle(confirmed_event(&dona))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+}
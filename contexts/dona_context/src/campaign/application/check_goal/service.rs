@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use shared::domain::bus::event::EventBus;
+
+use crate::{
+    campaign::domain::{campaign::CampaignId, campaign_repository::CampaignRepository},
+    dona::domain::{dona::DonaId, dona_repository::DonaRepository},
+};
+
+#[derive(Clone)]
+pub struct CampaignGoalChecker {
+    campaign_repository: Arc<dyn CampaignRepository>,
+    dona_repository: Arc<dyn DonaRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl CampaignGoalChecker {
+    pub fn new(
+        campaign_repository: Arc<dyn CampaignRepository>,
+        dona_repository: Arc<dyn DonaRepository>,
+        event_bus: Arc<dyn EventBus>,
+    ) -> Self {
+        Self {
+            campaign_repository,
+            dona_repository,
+            event_bus,
+        }
+    }
+
+    /// Re-evaluates the campaign of a freshly confirmed dona. Donas outside of a campaign and
+    /// campaigns that already reached their goal or were closed are left untouched.
+    pub async fn execute(&self, dona_id: String) -> Result<(), String> {
+        let dona = self
+            .dona_repository
+            .find_by_id(DonaId::new(dona_id)?)
+            .await?;
+        let Some(campaign_id) = dona.campaign_id() else {
+            return Ok(());
+        };
+
+        let campaign_id = CampaignId::new(campaign_id)?;
+        let mut campaign = self
+            .campaign_repository
+            .find_by_id(campaign_id.clone())
+            .await?;
+        let totals = self
+            .dona_repository
+            .sum_confirmed_by_campaign(campaign_id)
+            .await?;
+
+        campaign.reach_goal(totals.amount, dona.updated_at().max(campaign.updated_at()))?;
+
+        let events = campaign.pull_events();
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        self.campaign_repository.save(&campaign).await?;
+
+        self.event_bus.publish(events).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,142 @@
+use shared::domain::bus::command::{Command, CommandError, CommandHandler};
+use time::OffsetDateTime;
+
+use super::service::CampaignCloser;
+
+pub const CLOSE_CAMPAIGN_COMMAND_TYPE: &str = "dona.close_campaign.command";
+
+#[derive(Debug)]
+pub struct CloseCampaignCommand {
+    pub id: String,
+    pub user_id: String,
+    pub updated_at: OffsetDateTime,
+}
+
+impl Command for CloseCampaignCommand {
+    fn command_type(&self) -> &'static str {
+        CLOSE_CAMPAIGN_COMMAND_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct CloseCampaignCommandHandler {
+    service: CampaignCloser,
+}
+
+impl CloseCampaignCommandHandler {
+    pub fn new(service: CampaignCloser) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler for CloseCampaignCommandHandler {
+    async fn handle(&self, command: Box<dyn Command>) -> Result<(), CommandError> {
+        let command = command
+            .as_any()
+            .downcast_ref::<CloseCampaignCommand>()
+            .ok_or_else(|| CommandError::new("Invalid command".to_string()))?;
+
+        self.service
+            .execute(
+                command.id.to_owned(),
+                command.user_id.to_owned(),
+                command.updated_at,
+            )
+            .await
+            .map_err(CommandError::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockall::predicate;
+    use shared::domain::bus::event::tests::MockEventBus;
+
+    use super::*;
+
+    use crate::campaign::domain::campaign::tests::CampaignMother;
+    use crate::campaign::domain::campaign::{Campaign, ERR_INVALID_CAMPAIGN_STATUS_TRANSITION};
+    use crate::campaign::domain::campaign_repository::tests::MockCampaignRepository;
+
+    fn campaign_with_status(status: &str) -> Campaign {
+        CampaignMother::create(None, None, None, None, None, None, Some(status.to_string()))
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_campaign_is_already_closed() {
+        let campaign = campaign_with_status("closed");
+        let mut repository = MockCampaignRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(campaign.clone()));
+        repository.expect_save().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let handler = CloseCampaignCommandHandler::new(CampaignCloser::new(
+            Arc::new(repository),
+            Arc::new(event_bus),
+        ));
+
+        let result = handler
+            .handle(Box::new(CloseCampaignCommand {
+                id: campaign.id(),
+                user_id: campaign.user_id(),
+                updated_at: campaign.updated_at(),
+            }))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(
+                ERR_INVALID_CAMPAIGN_STATUS_TRANSITION.to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_close_campaign() {
+        let campaign = campaign_with_status("goal_reached");
+        let mut repository = MockCampaignRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(campaign.clone()));
+        repository
+            .expect_save()
+            .withf(|campaign| campaign.status() == "closed")
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus
+            .expect_publish()
+            .with(predicate::function(|events: &Vec<_>| events.len() == 1))
+            .times(1)
+            .return_const(Ok(()));
+
+        let handler = CloseCampaignCommandHandler::new(CampaignCloser::new(
+            Arc::new(repository),
+            Arc::new(event_bus),
+        ));
+
+        let result = handler
+            .handle(Box::new(CloseCampaignCommand {
+                id: campaign.id(),
+                user_id: campaign.user_id(),
+                updated_at: campaign.updated_at(),
+            }))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use shared::domain::{base_errors::BaseRepositoryError, bus::event::EventBus};
+use time::OffsetDateTime;
+
+use crate::campaign::domain::{
+    campaign::{Campaign, CampaignId, ERR_CAMPAIGN_NOT_FOUND},
+    campaign_repository::CampaignRepository,
+};
+
+#[derive(Clone)]
+pub struct CampaignCloser {
+    repository: Arc<dyn CampaignRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl CampaignCloser {
+    pub fn new(repository: Arc<dyn CampaignRepository>, event_bus: Arc<dyn EventBus>) -> Self {
+        Self {
+            repository,
+            event_bus,
+        }
+    }
+
+    async fn campaign_finder(&self, id: String, user_id: String) -> Result<Campaign, String> {
+        let campaign = self
+            .repository
+            .find_by_id(CampaignId::new(id)?)
+            .await
+            .map_err(|e| match e {
+                BaseRepositoryError::NotFound => ERR_CAMPAIGN_NOT_FOUND.to_string(),
+                _ => e.to_string(),
+            })?;
+
+        if campaign.user_id() != user_id {
+            return Err(ERR_CAMPAIGN_NOT_FOUND.to_string());
+        }
+
+        Ok(campaign)
+    }
+
+    pub async fn execute(
+        &self,
+        id: String,
+        user_id: String,
+        updated_at: OffsetDateTime,
+    ) -> Result<(), String> {
+        let mut campaign = self.campaign_finder(id, user_id).await?;
+        campaign.close(updated_at)?;
+
+        self.repository.save(&campaign).await?;
+
+        self.event_bus.publish(campaign.pull_events()).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,190 @@
+use rust_decimal::Decimal;
+use shared::domain::bus::command::{Command, CommandError, CommandHandler};
+use time::OffsetDateTime;
+
+use super::service::CampaignCreator;
+
+pub const CREATE_CAMPAIGN_COMMAND_TYPE: &str = "dona.create_campaign.command";
+
+#[derive(Debug)]
+pub struct CreateCampaignCommand {
+    pub id: String,
+    pub user_id: String,
+    pub title: String,
+    pub target: Decimal,
+    pub currency: String,
+    pub deadline: OffsetDateTime,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl Command for CreateCampaignCommand {
+    fn command_type(&self) -> &'static str {
+        CREATE_CAMPAIGN_COMMAND_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct CreateCampaignCommandHandler {
+    service: CampaignCreator,
+}
+
+impl CreateCampaignCommandHandler {
+    pub fn new(service: CampaignCreator) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler for CreateCampaignCommandHandler {
+    async fn handle(&self, command: Box<dyn Command>) -> Result<(), CommandError> {
+        let command = command
+            .as_any()
+            .downcast_ref::<CreateCampaignCommand>()
+            .ok_or_else(|| CommandError::new("Invalid command".to_string()))?;
+
+        self.service
+            .execute(
+                command.id.to_owned(),
+                command.user_id.to_owned(),
+                command.title.to_owned(),
+                command.target,
+                command.currency.to_owned(),
+                command.deadline,
+                command.created_at,
+                command.updated_at,
+            )
+            .await
+            .map_err(CommandError::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockall::predicate;
+    use rust_decimal_macros::dec;
+    use shared::domain::base_errors::BaseRepositoryError;
+    use shared::domain::bus::event::tests::MockEventBus;
+
+    use super::*;
+
+    use crate::campaign::application::create::service::ERR_CAMPAIGN_ALREADY_EXISTS;
+    use crate::campaign::domain::campaign::tests::CampaignMother;
+    use crate::campaign::domain::campaign::{Campaign, CampaignId, ERR_INVALID_CAMPAIGN_DEADLINE};
+    use crate::campaign::domain::campaign_repository::tests::MockCampaignRepository;
+
+    fn active_campaign() -> Campaign {
+        CampaignMother::create(
+            None,
+            None,
+            None,
+            Some(dec!(500.00)),
+            Some("USD".to_string()),
+            None,
+            None,
+        )
+    }
+
+    fn command_from(campaign: &Campaign) -> CreateCampaignCommand {
+        CreateCampaignCommand {
+            id: campaign.id(),
+            user_id: campaign.user_id(),
+            title: campaign.title(),
+            target: campaign.target(),
+            currency: campaign.currency(),
+            deadline: campaign.deadline(),
+            created_at: campaign.created_at(),
+            updated_at: campaign.updated_at(),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_campaign_exists() {
+        let campaign = active_campaign();
+        let mut repository = MockCampaignRepository::new();
+        repository
+            .expect_find_by_id()
+            .with(predicate::eq(CampaignId::new(campaign.id()).unwrap()))
+            .times(1)
+            .return_const(Ok(campaign.clone()));
+        repository.expect_save().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let handler = CreateCampaignCommandHandler::new(CampaignCreator::new(
+            Arc::new(repository),
+            Arc::new(event_bus),
+        ));
+
+        let result = handler.handle(Box::new(command_from(&campaign))).await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_CAMPAIGN_ALREADY_EXISTS.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_deadline_is_before_creation() {
+        let campaign = active_campaign();
+        let mut repository = MockCampaignRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        repository.expect_save().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let handler = CreateCampaignCommandHandler::new(CampaignCreator::new(
+            Arc::new(repository),
+            Arc::new(event_bus),
+        ));
+
+        let command = CreateCampaignCommand {
+            deadline: campaign.created_at() - time::Duration::days(1),
+            ..command_from(&campaign)
+        };
+        let result = handler.handle(Box::new(command)).await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_INVALID_CAMPAIGN_DEADLINE.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_create_campaign() {
+        let campaign = active_campaign();
+        let mut repository = MockCampaignRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        repository
+            .expect_save()
+            .with(predicate::eq(campaign.clone()))
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(1).return_const(Ok(()));
+
+        let handler = CreateCampaignCommandHandler::new(CampaignCreator::new(
+            Arc::new(repository),
+            Arc::new(event_bus),
+        ));
+
+        let result = handler.handle(Box::new(command_from(&campaign))).await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+}
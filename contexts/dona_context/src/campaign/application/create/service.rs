@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use shared::domain::{base_errors::BaseRepositoryError, bus::event::EventBus};
+use time::OffsetDateTime;
+
+use crate::campaign::domain::{
+    campaign::{Campaign, CampaignId},
+    campaign_repository::CampaignRepository,
+};
+
+pub const ERR_CAMPAIGN_ALREADY_EXISTS: &str = "Campaign already exists";
+
+#[derive(Clone)]
+pub struct CampaignCreator {
+    repository: Arc<dyn CampaignRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl CampaignCreator {
+    pub fn new(repository: Arc<dyn CampaignRepository>, event_bus: Arc<dyn EventBus>) -> Self {
+        Self {
+            repository,
+            event_bus,
+        }
+    }
+
+    async fn campaign_exists(&self, id: String) -> Result<(), String> {
+        let campaign = self.repository.find_by_id(CampaignId::new(id)?).await;
+
+        match campaign {
+            Ok(_) => Err(ERR_CAMPAIGN_ALREADY_EXISTS.to_string()),
+            Err(BaseRepositoryError::NotFound) => Ok(()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        id: String,
+        user_id: String,
+        title: String,
+        target: Decimal,
+        currency: String,
+        deadline: OffsetDateTime,
+        created_at: OffsetDateTime,
+        updated_at: OffsetDateTime,
+    ) -> Result<(), String> {
+        self.campaign_exists(id.clone()).await?;
+
+        let mut campaign = Campaign::create(
+            id, user_id, title, target, currency, deadline, created_at, updated_at,
+        )?;
+
+        self.repository.save(&campaign).await?;
+
+        self.event_bus.publish(campaign.pull_events()).await?;
+
+        Ok(())
+    }
+}
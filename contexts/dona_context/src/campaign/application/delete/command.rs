@@ -0,0 +1,141 @@
+use shared::domain::bus::command::{Command, CommandError, CommandHandler};
+
+use super::service::CampaignDeleter;
+
+pub const DELETE_CAMPAIGN_COMMAND_TYPE: &str = "dona.delete_campaign.command";
+
+#[derive(Debug)]
+pub struct DeleteCampaignCommand {
+    pub id: String,
+    pub user_id: String,
+}
+
+impl Command for DeleteCampaignCommand {
+    fn command_type(&self) -> &'static str {
+        DELETE_CAMPAIGN_COMMAND_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct DeleteCampaignCommandHandler {
+    service: CampaignDeleter,
+}
+
+impl DeleteCampaignCommandHandler {
+    pub fn new(service: CampaignDeleter) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler for DeleteCampaignCommandHandler {
+    async fn handle(&self, command: Box<dyn Command>) -> Result<(), CommandError> {
+        let command = command
+            .as_any()
+            .downcast_ref::<DeleteCampaignCommand>()
+            .ok_or_else(|| CommandError::new("Invalid command".to_string()))?;
+
+        self.service
+            .execute(command.id.to_owned(), command.user_id.to_owned())
+            .await
+            .map_err(CommandError::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockall::predicate;
+    use shared::domain::bus::event::tests::MockEventBus;
+
+    use super::*;
+
+    use crate::campaign::application::delete::service::ERR_CAMPAIGN_HAS_DONAS;
+    use crate::campaign::domain::campaign::tests::CampaignMother;
+    use crate::campaign::domain::campaign::CampaignId;
+    use crate::campaign::domain::campaign_repository::tests::MockCampaignRepository;
+    use crate::dona::domain::dona::tests::DonaMother;
+    use crate::dona::domain::dona_repository::tests::MockDonaRepository;
+
+    #[tokio::test]
+    async fn it_should_fail_when_campaign_has_donas() {
+        let campaign = CampaignMother::random();
+        let mut repository = MockCampaignRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(campaign.clone()));
+        repository.expect_delete().times(0);
+
+        let mut dona_repository = MockDonaRepository::new();
+        dona_repository
+            .expect_find_by_criteria()
+            .times(1)
+            .return_const(Ok(vec![DonaMother::random()]));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let handler = DeleteCampaignCommandHandler::new(CampaignDeleter::new(
+            Arc::new(repository),
+            Arc::new(dona_repository),
+            Arc::new(event_bus),
+        ));
+
+        let result = handler
+            .handle(Box::new(DeleteCampaignCommand {
+                id: campaign.id(),
+                user_id: campaign.user_id(),
+            }))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_CAMPAIGN_HAS_DONAS.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_delete_campaign() {
+        let campaign = CampaignMother::random();
+        let mut repository = MockCampaignRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(campaign.clone()));
+        repository
+            .expect_delete()
+            .with(predicate::eq(CampaignId::new(campaign.id()).unwrap()))
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut dona_repository = MockDonaRepository::new();
+        dona_repository
+            .expect_find_by_criteria()
+            .times(1)
+            .return_const(Ok(vec![]));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(1).return_const(Ok(()));
+
+        let handler = DeleteCampaignCommandHandler::new(CampaignDeleter::new(
+            Arc::new(repository),
+            Arc::new(dona_repository),
+            Arc::new(event_bus),
+        ));
+
+        let result = handler
+            .handle(Box::new(DeleteCampaignCommand {
+                id: campaign.id(),
+                user_id: campaign.user_id(),
+            }))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+}
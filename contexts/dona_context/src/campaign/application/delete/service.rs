@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use shared::domain::{
+    base_errors::BaseRepositoryError,
+    bus::event::EventBus,
+    criteria::{
+        cursor::{Cursor, FirstField},
+        filter::{Filter, FilterField, FilterOperator, FilterValue},
+        Criteria,
+    },
+};
+
+use crate::{
+    campaign::domain::{
+        campaign::{Campaign, CampaignId, ERR_CAMPAIGN_NOT_FOUND},
+        campaign_repository::CampaignRepository,
+    },
+    dona::domain::dona_repository::DonaRepository,
+};
+
+pub const ERR_CAMPAIGN_HAS_DONAS: &str =
+    "A campaign with donas cannot be deleted, close it instead";
+
+#[derive(Clone)]
+pub struct CampaignDeleter {
+    repository: Arc<dyn CampaignRepository>,
+    dona_repository: Arc<dyn DonaRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl CampaignDeleter {
+    pub fn new(
+        repository: Arc<dyn CampaignRepository>,
+        dona_repository: Arc<dyn DonaRepository>,
+        event_bus: Arc<dyn EventBus>,
+    ) -> Self {
+        Self {
+            repository,
+            dona_repository,
+            event_bus,
+        }
+    }
+
+    async fn campaign_finder(&self, id: String, user_id: String) -> Result<Campaign, String> {
+        let campaign = self
+            .repository
+            .find_by_id(CampaignId::new(id)?)
+            .await
+            .map_err(|e| match e {
+                BaseRepositoryError::NotFound => ERR_CAMPAIGN_NOT_FOUND.to_string(),
+                _ => e.to_string(),
+            })?;
+
+        if campaign.user_id() != user_id {
+            return Err(ERR_CAMPAIGN_NOT_FOUND.to_string());
+        }
+
+        Ok(campaign)
+    }
+
+    async fn campaign_has_donas(&self, campaign_id: String) -> Result<bool, String> {
+        let donas = self
+            .dona_repository
+            .find_by_criteria(Criteria::new(
+                vec![Filter::new(
+                    FilterField::try_from("campaign_id".to_string()).unwrap(),
+                    FilterOperator::Equal,
+                    FilterValue::try_from(campaign_id)?,
+                )],
+                None,
+                Some(Cursor::new(
+                    None,
+                    None,
+                    Some(FirstField::new(1).unwrap()),
+                    None,
+                )),
+            ))
+            .await?;
+
+        Ok(!donas.is_empty())
+    }
+
+    pub async fn execute(&self, id: String, user_id: String) -> Result<(), String> {
+        let campaign = self.campaign_finder(id, user_id).await?;
+
+        if self.campaign_has_donas(campaign.id()).await? {
+            return Err(ERR_CAMPAIGN_HAS_DONAS.to_string());
+        }
+
+        self.repository
+            .delete(CampaignId::new(campaign.id())?)
+            .await?;
+
+        self.event_bus.publish(vec![]).await?;
+
+        Ok(())
+    }
+}
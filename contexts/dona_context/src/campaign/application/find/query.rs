@@ -0,0 +1,141 @@
+use shared::domain::bus::query::{Query, QueryError, QueryHandler, Response};
+
+use super::service::CampaignFinder;
+
+pub const FIND_CAMPAIGN_QUERY_TYPE: &str = "dona.find_campaign.query";
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FindCampaignQuery {
+    pub id: String,
+}
+
+impl Query for FindCampaignQuery {
+    fn query_type(&self) -> &'static str {
+        FIND_CAMPAIGN_QUERY_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct FindCampaignQueryHandler {
+    service: CampaignFinder,
+}
+
+impl FindCampaignQueryHandler {
+    pub fn new(service: CampaignFinder) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler for FindCampaignQueryHandler {
+    async fn handle(&self, query: Box<dyn Query>) -> Result<Box<dyn Response>, QueryError> {
+        let query = query
+            .as_any()
+            .downcast_ref::<FindCampaignQuery>()
+            .ok_or_else(|| QueryError::new("Invalid query".to_string()))?;
+
+        let campaign = self
+            .service
+            .execute(query.id.to_owned())
+            .await
+            .map_err(QueryError::new)?;
+
+        Ok(Box::new(campaign))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockall::predicate;
+    use rust_decimal_macros::dec;
+    use shared::domain::base_errors::BaseRepositoryError;
+
+    use super::*;
+
+    use crate::campaign::application::response::CampaignResponse;
+    use crate::campaign::domain::campaign::tests::CampaignMother;
+    use crate::campaign::domain::campaign::{CampaignId, ERR_CAMPAIGN_NOT_FOUND};
+    use crate::campaign::domain::campaign_repository::tests::MockCampaignRepository;
+    use crate::dona::domain::dona_repository::tests::MockDonaRepository;
+    use crate::dona::domain::dona_repository::DonaTotals;
+
+    #[tokio::test]
+    async fn it_should_fail_when_campaign_does_not_exist() {
+        let campaign = CampaignMother::random();
+        let mut campaign_repository = MockCampaignRepository::new();
+        campaign_repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+
+        let mut dona_repository = MockDonaRepository::new();
+        dona_repository.expect_sum_confirmed_by_campaign().times(0);
+
+        let handler = FindCampaignQueryHandler::new(CampaignFinder::new(
+            Arc::new(campaign_repository),
+            Arc::new(dona_repository),
+        ));
+
+        let result = handler
+            .handle(Box::new(FindCampaignQuery { id: campaign.id() }))
+            .await;
+
+        assert_eq!(
+            result.err(),
+            Some(QueryError::new(ERR_CAMPAIGN_NOT_FOUND.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_return_campaign_with_progress() {
+        let campaign = CampaignMother::create(
+            None,
+            None,
+            None,
+            Some(dec!(100.00)),
+            Some("USD".to_string()),
+            None,
+            None,
+        );
+        let totals = DonaTotals {
+            amount: dec!(42.50),
+            count: 3,
+        };
+
+        let mut campaign_repository = MockCampaignRepository::new();
+        campaign_repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(campaign.clone()));
+
+        let mut dona_repository = MockDonaRepository::new();
+        dona_repository
+            .expect_sum_confirmed_by_campaign()
+            .with(predicate::eq(CampaignId::new(campaign.id()).unwrap()))
+            .times(1)
+            .return_const(Ok(totals));
+
+        let handler = FindCampaignQueryHandler::new(CampaignFinder::new(
+            Arc::new(campaign_repository),
+            Arc::new(dona_repository),
+        ));
+
+        let response = handler
+            .handle(Box::new(FindCampaignQuery { id: campaign.id() }))
+            .await
+            .unwrap();
+        let response = response
+            .as_any()
+            .downcast_ref::<CampaignResponse>()
+            .unwrap()
+            .to_owned();
+
+        assert_eq!(response, CampaignResponse::new(campaign, totals));
+    }
+}
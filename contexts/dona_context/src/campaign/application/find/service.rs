@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use crate::{
+    campaign::{
+        application::response::CampaignResponse,
+        domain::{
+            campaign::{CampaignId, ERR_CAMPAIGN_NOT_FOUND},
+            campaign_repository::CampaignRepository,
+        },
+    },
+    dona::domain::dona_repository::DonaRepository,
+};
+
+#[derive(Clone)]
+pub struct CampaignFinder {
+    campaign_repository: Arc<dyn CampaignRepository>,
+    dona_repository: Arc<dyn DonaRepository>,
+}
+
+impl CampaignFinder {
+    pub fn new(
+        campaign_repository: Arc<dyn CampaignRepository>,
+        dona_repository: Arc<dyn DonaRepository>,
+    ) -> Self {
+        Self {
+            campaign_repository,
+            dona_repository,
+        }
+    }
+
+    pub async fn execute(&self, id: String) -> Result<CampaignResponse, String> {
+        let campaign_id = CampaignId::new(id)?;
+        let campaign = self
+            .campaign_repository
+            .find_by_id(campaign_id.clone())
+            .await
+            .map_err(|_| ERR_CAMPAIGN_NOT_FOUND.to_string())?;
+        let totals = self
+            .dona_repository
+            .sum_confirmed_by_campaign(campaign_id)
+            .await?;
+
+        Ok(CampaignResponse::new(campaign, totals))
+    }
+}
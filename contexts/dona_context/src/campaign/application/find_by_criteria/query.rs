@@ -0,0 +1,113 @@
+use shared::domain::{
+    bus::query::{Query, QueryError, QueryHandler, Response},
+    criteria::Criteria,
+};
+
+use super::service::CampaignsFinder;
+
+pub const FIND_CAMPAIGNS_BY_CRITERIA_QUERY_TYPE: &str = "dona.find_campaigns_by_criteria.query";
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FindCampaignsByCriteriaQuery {
+    pub criteria: Criteria,
+}
+
+impl Query for FindCampaignsByCriteriaQuery {
+    fn query_type(&self) -> &'static str {
+        FIND_CAMPAIGNS_BY_CRITERIA_QUERY_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct FindCampaignsByCriteriaQueryHandler {
+    service: CampaignsFinder,
+}
+
+impl FindCampaignsByCriteriaQueryHandler {
+    pub fn new(service: CampaignsFinder) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler for FindCampaignsByCriteriaQueryHandler {
+    async fn handle(&self, query: Box<dyn Query>) -> Result<Box<dyn Response>, QueryError> {
+        let query = query
+            .as_any()
+            .downcast_ref::<FindCampaignsByCriteriaQuery>()
+            .ok_or_else(|| QueryError::new("Invalid query".to_string()))?;
+
+        let campaigns = self
+            .service
+            .execute(query.criteria.to_owned())
+            .await
+            .map_err(QueryError::new)?;
+
+        Ok(Box::new(campaigns))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockall::predicate;
+    use shared::domain::criteria::page::CursorPage;
+
+    use super::*;
+
+    use crate::campaign::application::response::{CampaignResponse, CampaignsResponse};
+    use crate::campaign::domain::campaign::tests::CampaignMother;
+    use crate::campaign::domain::campaign_repository::tests::MockCampaignRepository;
+    use crate::dona::domain::dona_repository::tests::MockDonaRepository;
+    use crate::dona::domain::dona_repository::DonaTotals;
+
+    #[tokio::test]
+    async fn it_should_return_campaigns_with_page_info() {
+        let criteria = Criteria::new(vec![], None, None);
+        let campaigns = vec![CampaignMother::random(), CampaignMother::random()];
+
+        let mut campaign_repository = MockCampaignRepository::new();
+        campaign_repository
+            .expect_find_page_by_criteria()
+            .with(predicate::eq(criteria.clone()))
+            .times(1)
+            .return_const(Ok(CursorPage::new(campaigns.clone(), true, false)));
+
+        let mut dona_repository = MockDonaRepository::new();
+        dona_repository
+            .expect_sum_confirmed_by_campaign()
+            .times(2)
+            .return_const(Ok(DonaTotals::default()));
+
+        let handler = FindCampaignsByCriteriaQueryHandler::new(CampaignsFinder::new(
+            Arc::new(campaign_repository),
+            Arc::new(dona_repository),
+        ));
+
+        let response = handler
+            .handle(Box::new(FindCampaignsByCriteriaQuery { criteria }))
+            .await
+            .unwrap();
+        let response = response
+            .as_any()
+            .downcast_ref::<CampaignsResponse>()
+            .unwrap()
+            .to_owned();
+
+        let expected_response = CampaignsResponse {
+            campaigns: campaigns
+                .into_iter()
+                .map(|campaign| CampaignResponse::new(campaign, DonaTotals::default()))
+                .collect(),
+            has_previous_page: true,
+            has_next_page: false,
+        };
+
+        assert_eq!(response, expected_response);
+    }
+}
@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use shared::domain::criteria::Criteria;
+
+use crate::{
+    campaign::{
+        application::response::{CampaignResponse, CampaignsResponse},
+        domain::{campaign::CampaignId, campaign_repository::CampaignRepository},
+    },
+    dona::domain::dona_repository::DonaRepository,
+};
+
+#[derive(Clone)]
+pub struct CampaignsFinder {
+    campaign_repository: Arc<dyn CampaignRepository>,
+    dona_repository: Arc<dyn DonaRepository>,
+}
+
+impl CampaignsFinder {
+    pub fn new(
+        campaign_repository: Arc<dyn CampaignRepository>,
+        dona_repository: Arc<dyn DonaRepository>,
+    ) -> Self {
+        Self {
+            campaign_repository,
+            dona_repository,
+        }
+    }
+
+    pub async fn execute(&self, criteria: Criteria) -> Result<CampaignsResponse, String> {
+        let page = self
+            .campaign_repository
+            .find_page_by_criteria(criteria)
+            .await?;
+        let has_previous_page = page.has_previous_page();
+        let has_next_page = page.has_next_page();
+
+        let mut campaigns = vec![];
+        for campaign in page.into_items() {
+            let totals = self
+                .dona_repository
+                .sum_confirmed_by_campaign(CampaignId::new(campaign.id())?)
+                .await?;
+            campaigns.push(CampaignResponse::new(campaign, totals));
+        }
+
+        Ok(CampaignsResponse {
+            campaigns,
+            has_previous_page,
+            has_next_page,
+        })
+    }
+}
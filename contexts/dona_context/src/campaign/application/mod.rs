@@ -0,0 +1,8 @@
+pub mod check_goal;
+pub mod close;
+pub mod create;
+pub mod delete;
+pub mod find;
+pub mod find_by_criteria;
+pub mod response;
+pub mod update;
@@ -0,0 +1,58 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use shared::domain::bus::query::Response;
+use time::OffsetDateTime;
+
+use crate::{campaign::domain::campaign::Campaign, dona::domain::dona_repository::DonaTotals};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CampaignResponse {
+    pub id: String,
+    pub user_id: String,
+    pub title: String,
+    pub target: Decimal,
+    pub currency: String,
+    pub deadline: OffsetDateTime,
+    pub status: String,
+    pub raised: Decimal,
+    pub dona_count: u64,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl CampaignResponse {
+    pub fn new(campaign: Campaign, totals: DonaTotals) -> Self {
+        Self {
+            id: campaign.id(),
+            user_id: campaign.user_id(),
+            title: campaign.title(),
+            target: campaign.target(),
+            currency: campaign.currency(),
+            deadline: campaign.deadline(),
+            status: campaign.status(),
+            raised: totals.amount,
+            dona_count: totals.count,
+            created_at: campaign.created_at(),
+            updated_at: campaign.updated_at(),
+        }
+    }
+}
+
+impl Response for CampaignResponse {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CampaignsResponse {
+    pub campaigns: Vec<CampaignResponse>,
+    pub has_previous_page: bool,
+    pub has_next_page: bool,
+}
+
+impl Response for CampaignsResponse {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
@@ -0,0 +1,162 @@
+use rust_decimal::Decimal;
+use shared::domain::bus::command::{Command, CommandError, CommandHandler};
+use time::OffsetDateTime;
+
+use super::service::CampaignUpdater;
+
+pub const UPDATE_CAMPAIGN_COMMAND_TYPE: &str = "dona.update_campaign.command";
+
+#[derive(Debug)]
+pub struct UpdateCampaignCommand {
+    pub id: String,
+    pub user_id: String,
+    pub title: String,
+    pub target: Decimal,
+    pub deadline: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl Command for UpdateCampaignCommand {
+    fn command_type(&self) -> &'static str {
+        UPDATE_CAMPAIGN_COMMAND_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct UpdateCampaignCommandHandler {
+    service: CampaignUpdater,
+}
+
+impl UpdateCampaignCommandHandler {
+    pub fn new(service: CampaignUpdater) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler for UpdateCampaignCommandHandler {
+    async fn handle(&self, command: Box<dyn Command>) -> Result<(), CommandError> {
+        let command = command
+            .as_any()
+            .downcast_ref::<UpdateCampaignCommand>()
+            .ok_or_else(|| CommandError::new("Invalid command".to_string()))?;
+
+        self.service
+            .execute(
+                command.id.to_owned(),
+                command.user_id.to_owned(),
+                command.title.to_owned(),
+                command.target,
+                command.deadline,
+                command.updated_at,
+            )
+            .await
+            .map_err(CommandError::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use rust_decimal_macros::dec;
+    use shared::domain::bus::event::tests::MockEventBus;
+    use shared::domain::value_objects::user_id::tests::UserIdMother;
+
+    use super::*;
+
+    use crate::campaign::domain::campaign::tests::CampaignMother;
+    use crate::campaign::domain::campaign::{
+        Campaign, ERR_CAMPAIGN_CLOSED, ERR_CAMPAIGN_NOT_FOUND,
+    };
+    use crate::campaign::domain::campaign_repository::tests::MockCampaignRepository;
+
+    fn campaign_with_status(status: &str) -> Campaign {
+        CampaignMother::create(
+            None,
+            None,
+            None,
+            Some(dec!(500.00)),
+            Some("USD".to_string()),
+            None,
+            Some(status.to_string()),
+        )
+    }
+
+    fn handler_for(campaign: &Campaign, saves: usize) -> UpdateCampaignCommandHandler {
+        let mut repository = MockCampaignRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(campaign.clone()));
+        repository.expect_save().times(saves).return_const(Ok(()));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(saves).return_const(Ok(()));
+
+        UpdateCampaignCommandHandler::new(CampaignUpdater::new(
+            Arc::new(repository),
+            Arc::new(event_bus),
+        ))
+    }
+
+    fn command_for(campaign: &Campaign, user_id: String) -> UpdateCampaignCommand {
+        UpdateCampaignCommand {
+            id: campaign.id(),
+            user_id,
+            title: "New title".to_string(),
+            target: dec!(750.00),
+            deadline: campaign.deadline() + time::Duration::days(7),
+            updated_at: campaign.updated_at(),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_user_does_not_own_campaign() {
+        let campaign = campaign_with_status("active");
+        let handler = handler_for(&campaign, 0);
+
+        let result = handler
+            .handle(Box::new(command_for(
+                &campaign,
+                UserIdMother::random().to_string(),
+            )))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_CAMPAIGN_NOT_FOUND.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_campaign_is_closed() {
+        let campaign = campaign_with_status("closed");
+        let handler = handler_for(&campaign, 0);
+
+        let result = handler
+            .handle(Box::new(command_for(&campaign, campaign.user_id())))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_CAMPAIGN_CLOSED.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_update_campaign() {
+        let campaign = campaign_with_status("active");
+        let handler = handler_for(&campaign, 1);
+
+        let result = handler
+            .handle(Box::new(command_for(&campaign, campaign.user_id())))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+}
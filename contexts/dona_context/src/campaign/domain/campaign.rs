@@ -0,0 +1,518 @@
+use std::fmt::Display;
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use shared::domain::{
+    bus::event::Event,
+    utils::is_uuid,
+    value_objects::{
+        money::{Currency, Money},
+        user_id::UserId,
+    },
+};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+use super::{
+    campaign_closed_event::CampaignClosedEvent, campaign_created_event::CampaignCreatedEvent,
+    campaign_goal_reached_event::CampaignGoalReachedEvent,
+    campaign_updated_event::CampaignUpdatedEvent,
+};
+
+pub const ERR_CAMPAIGN_NOT_FOUND: &str = "Campaign not found";
+
+pub const ERR_INVALID_CAMPAIGN_ID: &str = "Invalid Campaign ID";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct CampaignId(String);
+
+impl CampaignId {
+    pub fn new(value: String) -> Result<Self, String> {
+        if is_uuid(&value) {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_CAMPAIGN_ID.to_string())
+        }
+    }
+}
+
+impl Display for CampaignId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub const CAMPAIGN_TITLE_MAX_LENGTH: usize = 150;
+pub const ERR_INVALID_CAMPAIGN_TITLE: &str = "Invalid Campaign Title";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct CampaignTitle(String);
+
+impl CampaignTitle {
+    pub fn new(value: String) -> Result<Self, String> {
+        if !value.trim().is_empty() && value.chars().count() <= CAMPAIGN_TITLE_MAX_LENGTH {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_CAMPAIGN_TITLE.to_string())
+        }
+    }
+}
+
+impl Display for CampaignTitle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub const ERR_INVALID_CAMPAIGN_TARGET: &str = "Invalid Campaign Target";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct CampaignTarget(Money);
+
+impl CampaignTarget {
+    pub fn new(value: Money) -> Result<Self, String> {
+        if value.amount() > Decimal::ZERO {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_CAMPAIGN_TARGET.to_string())
+        }
+    }
+}
+
+impl Display for CampaignTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub const ERR_INVALID_CAMPAIGN_DEADLINE: &str = "Campaign deadline must be after its creation";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct CampaignDeadline(OffsetDateTime);
+
+impl CampaignDeadline {
+    pub fn new(value: OffsetDateTime, created_at: &CampaignCreatedAt) -> Result<Self, String> {
+        if value > created_at.0 {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_CAMPAIGN_DEADLINE.to_string())
+        }
+    }
+}
+
+impl Display for CampaignDeadline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.format(&Rfc3339).unwrap())
+    }
+}
+
+pub const ERR_INVALID_CAMPAIGN_STATUS: &str = "Invalid Campaign Status";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum CampaignStatus {
+    Active,
+    GoalReached,
+    Closed,
+}
+
+impl CampaignStatus {
+    pub fn new(value: String) -> Result<Self, String> {
+        match value.as_str() {
+            "active" => Ok(Self::Active),
+            "goal_reached" => Ok(Self::GoalReached),
+            "closed" => Ok(Self::Closed),
+            _ => Err(ERR_INVALID_CAMPAIGN_STATUS.to_string()),
+        }
+    }
+
+    pub fn can_transition_to(&self, next: &CampaignStatus) -> bool {
+        matches!(
+            (self, next),
+            (Self::Active, Self::GoalReached)
+                | (Self::Active, Self::Closed)
+                | (Self::GoalReached, Self::Closed)
+        )
+    }
+}
+
+impl Display for CampaignStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Active => write!(f, "active"),
+            Self::GoalReached => write!(f, "goal_reached"),
+            Self::Closed => write!(f, "closed"),
+        }
+    }
+}
+
+pub const ERR_INVALID_CAMPAIGN_STATUS_TRANSITION: &str = "Invalid Campaign Status Transition";
+pub const ERR_CAMPAIGN_CLOSED: &str = "Campaign is closed";
+
+pub const ERR_INVALID_CAMPAIGN_CREATED_AT: &str = "Invalid Campaign Created At";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct CampaignCreatedAt(OffsetDateTime);
+
+impl CampaignCreatedAt {
+    pub fn new(value: OffsetDateTime) -> Result<Self, String> {
+        if value < OffsetDateTime::now_utc() {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_CAMPAIGN_CREATED_AT.to_string())
+        }
+    }
+}
+
+impl Display for CampaignCreatedAt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.format(&Rfc3339).unwrap())
+    }
+}
+
+pub const ERR_INVALID_CAMPAIGN_UPDATED_AT: &str = "Invalid Campaign Updated At";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct CampaignUpdatedAt(OffsetDateTime);
+
+impl CampaignUpdatedAt {
+    pub fn new(value: OffsetDateTime) -> Result<Self, String> {
+        if value < OffsetDateTime::now_utc() {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_CAMPAIGN_UPDATED_AT.to_string())
+        }
+    }
+}
+
+impl Display for CampaignUpdatedAt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.format(&Rfc3339).unwrap())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Campaign {
+    id: CampaignId,
+    user_id: UserId,
+    title: CampaignTitle,
+    target: CampaignTarget,
+    deadline: CampaignDeadline,
+    status: CampaignStatus,
+    created_at: CampaignCreatedAt,
+    updated_at: CampaignUpdatedAt,
+
+    events: Vec<Arc<dyn Event>>,
+}
+
+impl PartialEq for Campaign {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.user_id == other.user_id
+            && self.title == other.title
+            && self.target == other.target
+            && self.deadline == other.deadline
+            && self.status == other.status
+            && self.created_at == other.created_at
+            && self.updated_at == other.updated_at
+    }
+}
+
+impl Eq for Campaign {}
+
+impl Campaign {
+    pub(crate) fn new(
+        id: String,
+        user_id: String,
+        title: String,
+        target: Decimal,
+        currency: String,
+        deadline: OffsetDateTime,
+        status: String,
+        created_at: OffsetDateTime,
+        updated_at: OffsetDateTime,
+    ) -> Result<Self, String> {
+        let created_at = CampaignCreatedAt::new(created_at)?;
+
+        Ok(Self {
+            id: CampaignId::new(id)?,
+            user_id: UserId::new(user_id)?,
+            title: CampaignTitle::new(title)?,
+            target: CampaignTarget::new(Money::new(target, currency)?)?,
+            deadline: CampaignDeadline::new(deadline, &created_at)?,
+            status: CampaignStatus::new(status)?,
+            created_at,
+            updated_at: CampaignUpdatedAt::new(updated_at)?,
+            events: vec![],
+        })
+    }
+
+    pub fn create(
+        id: String,
+        user_id: String,
+        title: String,
+        target: Decimal,
+        currency: String,
+        deadline: OffsetDateTime,
+        created_at: OffsetDateTime,
+        updated_at: OffsetDateTime,
+    ) -> Result<Self, String> {
+        let mut campaign = Self::new(
+            id,
+            user_id,
+            title,
+            target,
+            currency,
+            deadline,
+            CampaignStatus::Active.to_string(),
+            created_at,
+            updated_at,
+        )?;
+
+        let event = CampaignCreatedEvent::new(
+            campaign.id(),
+            campaign.user_id(),
+            campaign.title(),
+            campaign.target().to_string(),
+            campaign.currency(),
+            campaign.deadline.to_string(),
+            campaign.created_at.to_string(),
+            campaign.updated_at.to_string(),
+        );
+        campaign.record(Arc::new(event));
+
+        Ok(campaign)
+    }
+
+    /// The currency is fixed on creation, since the donas already linked to the campaign are
+    /// denominated in it.
+    pub fn update(
+        &mut self,
+        title: String,
+        target: Decimal,
+        deadline: OffsetDateTime,
+        updated_at: OffsetDateTime,
+    ) -> Result<(), String> {
+        if self.status == CampaignStatus::Closed {
+            return Err(ERR_CAMPAIGN_CLOSED.to_string());
+        }
+
+        self.title = CampaignTitle::new(title)?;
+        self.target = CampaignTarget::new(Money::new(target, self.currency())?)?;
+        self.deadline = CampaignDeadline::new(deadline, &self.created_at)?;
+        self.updated_at = CampaignUpdatedAt::new(updated_at)?;
+
+        let event = CampaignUpdatedEvent::new(
+            self.id(),
+            self.title(),
+            self.target().to_string(),
+            self.deadline.to_string(),
+            self.updated_at.to_string(),
+        );
+        self.record(Arc::new(event));
+
+        Ok(())
+    }
+
+    fn transition(
+        &mut self,
+        next: CampaignStatus,
+        ocurred_at: OffsetDateTime,
+    ) -> Result<(), String> {
+        if !self.status.can_transition_to(&next) {
+            return Err(ERR_INVALID_CAMPAIGN_STATUS_TRANSITION.to_string());
+        }
+
+        self.updated_at = CampaignUpdatedAt::new(ocurred_at)?;
+        self.status = next;
+
+        Ok(())
+    }
+
+    pub fn close(&mut self, ocurred_at: OffsetDateTime) -> Result<(), String> {
+        self.transition(CampaignStatus::Closed, ocurred_at)?;
+
+        let event = CampaignClosedEvent::new(self.id(), self.updated_at.to_string());
+        self.record(Arc::new(event));
+
+        Ok(())
+    }
+
+    /// Marks the goal as reached once the confirmed donas add up to `raised`. Does nothing
+    /// when `raised` is still below the target or the goal was already reached.
+    pub fn reach_goal(
+        &mut self,
+        raised: Decimal,
+        ocurred_at: OffsetDateTime,
+    ) -> Result<(), String> {
+        if self.status != CampaignStatus::Active || raised < self.target() {
+            return Ok(());
+        }
+
+        self.transition(CampaignStatus::GoalReached, ocurred_at)?;
+
+        let event = CampaignGoalReachedEvent::new(
+            self.id(),
+            self.user_id(),
+            raised.to_string(),
+            self.target().to_string(),
+            self.currency(),
+            self.updated_at.to_string(),
+        );
+        self.record(Arc::new(event));
+
+        Ok(())
+    }
+
+    pub fn accepts_donas(&self, at: OffsetDateTime) -> bool {
+        self.status != CampaignStatus::Closed && at <= self.deadline.0
+    }
+
+    pub fn accepts_currency(&self, currency: &Currency) -> bool {
+        self.target.0.currency() == currency
+    }
+
+    pub fn record(&mut self, event: Arc<dyn Event>) {
+        self.events.push(event);
+    }
+
+    pub fn pull_events(&mut self) -> Vec<Arc<dyn Event>> {
+        let events = self.events.clone();
+        self.events = vec![];
+        events
+    }
+
+    pub fn id(&self) -> String {
+        self.id.to_string()
+    }
+
+    pub fn user_id(&self) -> String {
+        self.user_id.to_string()
+    }
+
+    pub fn title(&self) -> String {
+        self.title.to_string()
+    }
+
+    pub fn target(&self) -> Decimal {
+        self.target.0.amount()
+    }
+
+    pub fn currency(&self) -> String {
+        self.target.0.currency().to_string()
+    }
+
+    pub fn deadline(&self) -> OffsetDateTime {
+        self.deadline.0
+    }
+
+    pub fn status(&self) -> String {
+        self.status.to_string()
+    }
+
+    pub fn created_at(&self) -> OffsetDateTime {
+        self.created_at.0
+    }
+
+    pub fn updated_at(&self) -> OffsetDateTime {
+        self.updated_at.0
+    }
+}
+
+pub mod tests {
+    use fake::{faker::lorem::en::Sentence, Fake};
+    use shared::domain::{
+        utils::new_uuid,
+        value_objects::{money::tests::MoneyMother, user_id::tests::UserIdMother},
+    };
+    use time::Duration;
+
+    use super::*;
+
+    pub struct CampaignIdMother;
+
+    impl CampaignIdMother {
+        pub fn random() -> CampaignId {
+            CampaignId::new(new_uuid()).unwrap()
+        }
+
+        pub fn create(value: Option<String>) -> CampaignId {
+            match value {
+                Some(value) => CampaignId::new(value).unwrap(),
+                None => Self::random(),
+            }
+        }
+    }
+
+    pub struct CampaignTitleMother;
+
+    impl CampaignTitleMother {
+        pub fn random() -> CampaignTitle {
+            CampaignTitle::new(Sentence(1..5).fake()).unwrap()
+        }
+
+        pub fn create(value: Option<String>) -> CampaignTitle {
+            match value {
+                Some(value) => CampaignTitle::new(value).unwrap(),
+                None => Self::random(),
+            }
+        }
+    }
+
+    pub struct CampaignTargetMother;
+
+    impl CampaignTargetMother {
+        pub fn create(amount: Option<Decimal>, currency: Option<String>) -> CampaignTarget {
+            CampaignTarget::new(MoneyMother::create(amount, currency)).unwrap()
+        }
+
+        pub fn random() -> CampaignTarget {
+            Self::create(None, None)
+        }
+    }
+
+    pub struct CampaignStatusMother;
+
+    impl CampaignStatusMother {
+        pub fn create(value: Option<String>) -> CampaignStatus {
+            match value {
+                Some(value) => CampaignStatus::new(value).unwrap(),
+                None => CampaignStatus::Active,
+            }
+        }
+    }
+
+    pub struct CampaignMother;
+
+    impl CampaignMother {
+        pub fn random() -> Campaign {
+            Self::create(None, None, None, None, None, None, None)
+        }
+
+        /// Campaigns are created a day ago and stay open for a month unless a deadline is given.
+        pub fn create(
+            id: Option<String>,
+            user_id: Option<String>,
+            title: Option<String>,
+            target: Option<Decimal>,
+            currency: Option<String>,
+            deadline: Option<OffsetDateTime>,
+            status: Option<String>,
+        ) -> Campaign {
+            let created_at =
+                CampaignCreatedAt::new(OffsetDateTime::now_utc() - Duration::days(1)).unwrap();
+            let deadline = deadline.unwrap_or(created_at.0 + Duration::days(30));
+
+            Campaign {
+                id: CampaignIdMother::create(id),
+                user_id: UserIdMother::create(user_id),
+                title: CampaignTitleMother::create(title),
+                target: CampaignTargetMother::create(target, currency),
+                deadline: CampaignDeadline::new(deadline, &created_at).unwrap(),
+                status: CampaignStatusMother::create(status),
+                created_at: created_at.clone(),
+                updated_at: CampaignUpdatedAt::new(created_at.0).unwrap(),
+
+                events: vec![],
+            }
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use shared::domain::bus::event::{BaseEvent, Event, EventDeserializeError, EventSerialized};
+
+pub const CAMPAIGN_CLOSED_EVENT_TYPE: &str = "dona.campaign_closed";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct CampaignClosedEvent {
+    id: String,
+    updated_at: String,
+
+    base_event: BaseEvent,
+}
+
+impl CampaignClosedEvent {
+    pub fn new(id: String, updated_at: String) -> Self {
+        Self {
+            id: id.clone(),
+            updated_at,
+            base_event: BaseEvent::new(id),
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn updated_at(&self) -> &str {
+        &self.updated_at
+    }
+}
+
+impl Event for CampaignClosedEvent {
+    fn event_type(&self) -> &'static str {
+        CAMPAIGN_CLOSED_EVENT_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn from_primitives(
+        &self,
+        primitives: EventSerialized,
+    ) -> Result<Box<dyn Event>, EventDeserializeError> {
+        let data = primitives.data();
+        let base_event = BaseEvent::from_primitives(
+            primitives.event_id().to_string(),
+            primitives.aggregate_id().to_string(),
+            primitives.occurred_at().to_string(),
+        );
+
+        let id = data
+            .get("id")
+            .ok_or(EventDeserializeError::MissingField("id".to_string()))?;
+        let updated_at = data
+            .get("updated_at")
+            .ok_or(EventDeserializeError::MissingField(
+                "updated_at".to_string(),
+            ))?;
+
+        Ok(Box::new(Self {
+            id: id.to_string(),
+            updated_at: updated_at.to_string(),
+            base_event,
+        }))
+    }
+
+    fn to_primitives(&self) -> EventSerialized {
+        EventSerialized::new(
+            self.base_event.event_id().to_string(),
+            self.base_event.aggregate_id().to_string(),
+            self.base_event.occurred_at().to_string(),
+            vec![
+                ("id".to_string(), self.id.clone()),
+                ("updated_at".to_string(), self.updated_at.clone()),
+            ]
+            .into_iter()
+            .collect(),
+        )
+    }
+}
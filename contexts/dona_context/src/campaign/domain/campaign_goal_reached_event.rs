@@ -0,0 +1,133 @@
+use shared::domain::bus::event::{BaseEvent, Event, EventDeserializeError, EventSerialized};
+
+pub const CAMPAIGN_GOAL_REACHED_EVENT_TYPE: &str = "dona.campaign_goal_reached";
+
+/// Published once per campaign, by the confirmation that takes the raised total to the target.
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct CampaignGoalReachedEvent {
+    id: String,
+    user_id: String,
+    raised: String,
+    target: String,
+    currency: String,
+    updated_at: String,
+
+    base_event: BaseEvent,
+}
+
+impl CampaignGoalReachedEvent {
+    pub fn new(
+        id: String,
+        user_id: String,
+        raised: String,
+        target: String,
+        currency: String,
+        updated_at: String,
+    ) -> Self {
+        Self {
+            id: id.clone(),
+            user_id,
+            raised,
+            target,
+            currency,
+            updated_at,
+            base_event: BaseEvent::new(id),
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    pub fn raised(&self) -> &str {
+        &self.raised
+    }
+
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    pub fn updated_at(&self) -> &str {
+        &self.updated_at
+    }
+}
+
+impl Event for CampaignGoalReachedEvent {
+    fn event_type(&self) -> &'static str {
+        CAMPAIGN_GOAL_REACHED_EVENT_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn from_primitives(
+        &self,
+        primitives: EventSerialized,
+    ) -> Result<Box<dyn Event>, EventDeserializeError> {
+        let data = primitives.data();
+        let base_event = BaseEvent::from_primitives(
+            primitives.event_id().to_string(),
+            primitives.aggregate_id().to_string(),
+            primitives.occurred_at().to_string(),
+        );
+
+        let id = data
+            .get("id")
+            .ok_or(EventDeserializeError::MissingField("id".to_string()))?;
+        let user_id = data
+            .get("user_id")
+            .ok_or(EventDeserializeError::MissingField("user_id".to_string()))?;
+        let raised = data
+            .get("raised")
+            .ok_or(EventDeserializeError::MissingField("raised".to_string()))?;
+        let target = data
+            .get("target")
+            .ok_or(EventDeserializeError::MissingField("target".to_string()))?;
+        let currency = data
+            .get("currency")
+            .ok_or(EventDeserializeError::MissingField("currency".to_string()))?;
+        let updated_at = data
+            .get("updated_at")
+            .ok_or(EventDeserializeError::MissingField(
+                "updated_at".to_string(),
+            ))?;
+
+        Ok(Box::new(Self {
+            id: id.to_string(),
+            user_id: user_id.to_string(),
+            raised: raised.to_string(),
+            target: target.to_string(),
+            currency: currency.to_string(),
+            updated_at: updated_at.to_string(),
+            base_event,
+        }))
+    }
+
+    fn to_primitives(&self) -> EventSerialized {
+        EventSerialized::new(
+            self.base_event.event_id().to_string(),
+            self.base_event.aggregate_id().to_string(),
+            self.base_event.occurred_at().to_string(),
+            vec![
+                ("id".to_string(), self.id.clone()),
+                ("user_id".to_string(), self.user_id.clone()),
+                ("raised".to_string(), self.raised.clone()),
+                ("target".to_string(), self.target.clone()),
+                ("currency".to_string(), self.currency.clone()),
+                ("updated_at".to_string(), self.updated_at.clone()),
+            ]
+            .into_iter()
+            .collect(),
+        )
+    }
+}
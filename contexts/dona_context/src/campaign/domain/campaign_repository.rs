@@ -0,0 +1,41 @@
+use shared::domain::{
+    base_errors::BaseRepositoryError,
+    criteria::{page::CursorPage, Criteria},
+};
+
+use super::campaign::{Campaign, CampaignId};
+
+#[async_trait::async_trait]
+pub trait CampaignRepository: Send + Sync {
+    async fn find_by_id(&self, id: CampaignId) -> Result<Campaign, BaseRepositoryError>;
+    async fn find_by_criteria(
+        &self,
+        criteria: Criteria,
+    ) -> Result<Vec<Campaign>, BaseRepositoryError>;
+    async fn find_page_by_criteria(
+        &self,
+        criteria: Criteria,
+    ) -> Result<CursorPage<Campaign>, BaseRepositoryError>;
+    async fn save(&self, campaign: &Campaign) -> Result<(), BaseRepositoryError>;
+    async fn delete(&self, id: CampaignId) -> Result<(), BaseRepositoryError>;
+}
+
+#[cfg(test)]
+pub mod tests {
+    use mockall::mock;
+
+    use super::*;
+
+    mock! {
+        pub CampaignRepository {}
+
+        #[async_trait::async_trait]
+        impl CampaignRepository for CampaignRepository {
+            async fn find_by_id(&self, id: CampaignId) -> Result<Campaign, BaseRepositoryError>;
+            async fn find_by_criteria(&self, criteria: Criteria) -> Result<Vec<Campaign>, BaseRepositoryError>;
+            async fn find_page_by_criteria(&self, criteria: Criteria) -> Result<CursorPage<Campaign>, BaseRepositoryError>;
+            async fn save(&self, campaign: &Campaign) -> Result<(), BaseRepositoryError>;
+            async fn delete(&self, id: CampaignId) -> Result<(), BaseRepositoryError>;
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use shared::domain::bus::event::{BaseEvent, Event, EventDeserializeError, EventSerialized};
+
+pub const CAMPAIGN_UPDATED_EVENT_TYPE: &str = "dona.campaign_updated";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct CampaignUpdatedEvent {
+    id: String,
+    title: String,
+    target: String,
+    deadline: String,
+    updated_at: String,
+
+    base_event: BaseEvent,
+}
+
+impl CampaignUpdatedEvent {
+    pub fn new(
+        id: String,
+        title: String,
+        target: String,
+        deadline: String,
+        updated_at: String,
+    ) -> Self {
+        Self {
+            id: id.clone(),
+            title,
+            target,
+            deadline,
+            updated_at,
+            base_event: BaseEvent::new(id),
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    pub fn deadline(&self) -> &str {
+        &self.deadline
+    }
+
+    pub fn updated_at(&self) -> &str {
+        &self.updated_at
+    }
+}
+
+impl Event for CampaignUpdatedEvent {
+    fn event_type(&self) -> &'static str {
+        CAMPAIGN_UPDATED_EVENT_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn from_primitives(
+        &self,
+        primitives: EventSerialized,
+    ) -> Result<Box<dyn Event>, EventDeserializeError> {
+        let data = primitives.data();
+        let base_event = BaseEvent::from_primitives(
+            primitives.event_id().to_string(),
+            primitives.aggregate_id().to_string(),
+            primitives.occurred_at().to_string(),
+        );
+
+        let id = data
+            .get("id")
+            .ok_or(EventDeserializeError::MissingField("id".to_string()))?;
+        let title = data
+            .get("title")
+            .ok_or(EventDeserializeError::MissingField("title".to_string()))?;
+        let target = data
+            .get("target")
+            .ok_or(EventDeserializeError::MissingField("target".to_string()))?;
+        let deadline = data
+            .get("deadline")
+            .ok_or(EventDeserializeError::MissingField("deadline".to_string()))?;
+        let updated_at = data
+            .get("updated_at")
+            .ok_or(EventDeserializeError::MissingField(
+                "updated_at".to_string(),
+            ))?;
+
+        Ok(Box::new(Self {
+            id: id.to_string(),
+            title: title.to_string(),
+            target: target.to_string(),
+            deadline: deadline.to_string(),
+            updated_at: updated_at.to_string(),
+            base_event,
+        }))
+    }
+
+    fn to_primitives(&self) -> EventSerialized {
+        EventSerialized::new(
+            self.base_event.event_id().to_string(),
+            self.base_event.aggregate_id().to_string(),
+            self.base_event.occurred_at().to_string(),
+            vec![
+                ("id".to_string(), self.id.clone()),
+                ("title".to_string(), self.title.clone()),
+                ("target".to_string(), self.target.clone()),
+                ("deadline".to_string(), self.deadline.clone()),
+                ("updated_at".to_string(), self.updated_at.clone()),
+            ]
+            .into_iter()
+            .collect(),
+        )
+    }
+}
@@ -0,0 +1,6 @@
+pub mod campaign;
+pub mod campaign_closed_event;
+pub mod campaign_created_event;
+pub mod campaign_goal_reached_event;
+pub mod campaign_repository;
+pub mod campaign_updated_event;
@@ -0,0 +1 @@
+pub mod sea_campaign_repo;
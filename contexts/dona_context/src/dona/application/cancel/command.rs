@@ -0,0 +1,172 @@
+use shared::domain::bus::command::{Command, CommandError, CommandHandler};
+use time::OffsetDateTime;
+
+use super::service::DonaCanceller;
+
+pub const CANCEL_DONA_COMMAND_TYPE: &str = "dona.cancel_dona.command";
+
+#[derive(Debug)]
+pub struct CancelDonaCommand {
+    pub id: String,
+    pub sender_id: String,
+    pub updated_at: OffsetDateTime,
+}
+
+impl Command for CancelDonaCommand {
+    fn command_type(&self) -> &'static str {
+        CANCEL_DONA_COMMAND_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct CancelDonaCommandHandler {
+    service: DonaCanceller,
+}
+
+impl CancelDonaCommandHandler {
+    pub fn new(service: DonaCanceller) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler for CancelDonaCommandHandler {
+    async fn handle(&self, command: Box<dyn Command>) -> Result<(), CommandError> {
+        let command = command
+            .as_any()
+            .downcast_ref::<CancelDonaCommand>()
+            .ok_or_else(|| CommandError::new("Invalid command".to_string()))?;
+
+        self.service
+            .execute(
+                command.id.to_owned(),
+                command.sender_id.to_owned(),
+                command.updated_at,
+            )
+            .await
+            .map_err(CommandError::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use rust_decimal_macros::dec;
+    use shared::domain::bus::event::tests::MockEventBus;
+    use time::Duration;
+
+    use super::*;
+
+    use crate::dona::domain::dona::tests::DonaMother;
+    use crate::dona::domain::dona::{Dona, ERR_DONA_NOT_FOUND, ERR_INVALID_DONA_STATUS_TRANSITION};
+    use crate::dona::domain::dona_repository::tests::MockDonaRepository;
+
+    fn dona_with_status(status: &str) -> Dona {
+        DonaMother::create(
+            None,
+            None,
+            Some(dec!(10.00)),
+            None,
+            Some(status.to_string()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn command_from(dona: &Dona) -> CancelDonaCommand {
+        CancelDonaCommand {
+            id: dona.id(),
+            sender_id: dona.sender_id().unwrap(),
+            updated_at: OffsetDateTime::now_utc() - Duration::seconds(1),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_dona_not_found() {
+        let dona = dona_with_status("pending");
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_find_by_criteria()
+            .times(1)
+            .return_const(Ok(vec![]));
+        repository.expect_save().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let service = DonaCanceller::new(Arc::new(repository), Arc::new(event_bus));
+        let handler = CancelDonaCommandHandler::new(service);
+
+        let result = handler.handle(Box::new(command_from(&dona))).await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_DONA_NOT_FOUND.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_dona_is_not_pending() {
+        let dona = dona_with_status("confirmed");
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_find_by_criteria()
+            .times(1)
+            .return_const(Ok(vec![dona.clone()]));
+        repository.expect_save().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let service = DonaCanceller::new(Arc::new(repository), Arc::new(event_bus));
+        let handler = CancelDonaCommandHandler::new(service);
+
+        let result = handler.handle(Box::new(command_from(&dona))).await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(
+                ERR_INVALID_DONA_STATUS_TRANSITION.to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_cancel_dona() {
+        let dona = dona_with_status("pending");
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_find_by_criteria()
+            .times(1)
+            .return_const(Ok(vec![dona.clone()]));
+        repository
+            .expect_save()
+            .withf(|dona| dona.status() == "cancelled")
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus
+            .expect_publish()
+            .withf(|events| events.len() == 1)
+            .times(1)
+            .return_const(Ok(()));
+
+        let service = DonaCanceller::new(Arc::new(repository), Arc::new(event_bus));
+        let handler = CancelDonaCommandHandler::new(service);
+
+        let result = handler.handle(Box::new(command_from(&dona))).await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+}
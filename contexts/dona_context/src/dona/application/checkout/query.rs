@@ -0,0 +1,188 @@
+use shared::domain::bus::query::{Query, QueryError, QueryHandler, Response};
+
+use super::service::DonaCheckoutStarter;
+
+pub const START_DONA_CHECKOUT_QUERY_TYPE: &str = "dona.start_dona_checkout.query";
+
+/// `requester_id` is the session user, if any.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StartDonaCheckoutQuery {
+    pub dona_id: String,
+    pub requester_id: Option<String>,
+}
+
+impl Query for StartDonaCheckoutQuery {
+    fn query_type(&self) -> &'static str {
+        START_DONA_CHECKOUT_QUERY_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct StartDonaCheckoutQueryHandler {
+    service: DonaCheckoutStarter,
+}
+
+impl StartDonaCheckoutQueryHandler {
+    pub fn new(service: DonaCheckoutStarter) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler for StartDonaCheckoutQueryHandler {
+    async fn handle(&self, query: Box<dyn Query>) -> Result<Box<dyn Response>, QueryError> {
+        let query = query
+            .as_any()
+            .downcast_ref::<StartDonaCheckoutQuery>()
+            .ok_or_else(|| QueryError::new("Invalid query".to_string()))?;
+
+        let checkout = self
+            .service
+            .execute(query.dona_id.to_owned(), query.requester_id.to_owned())
+            .await
+            .map_err(QueryError::new)?;
+
+        Ok(Box::new(checkout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use shared::domain::utils::new_uuid;
+
+    use super::*;
+
+    use crate::dona::application::response::DonaCheckoutResponse;
+    use crate::dona::domain::dona::tests::DonaMother;
+    use crate::dona::domain::dona::{Dona, NewDona, ERR_DONA_NOT_FOUND};
+    use crate::dona::domain::dona_repository::tests::MockDonaRepository;
+    use crate::dona::domain::payment_gateway::{
+        PaymentGatewayRegistry, ERR_DONA_NOT_PAYABLE, ERR_PAYMENT_GATEWAY_NOT_FOUND,
+    };
+    use crate::dona::infrastructure::payment::fake_payment_gateway::{
+        FakePaymentGateway, FAKE_CHECKOUT_URL,
+    };
+    use crate::shared::domain::dona::DonaOptionMethod;
+
+    fn dona_with(status: &str, method: &str) -> Dona {
+        DonaMother::create(
+            None,
+            None,
+            None,
+            None,
+            Some(status.to_string()),
+            Some(method.to_string()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn handler(dona: Dona) -> StartDonaCheckoutQueryHandler {
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(dona));
+
+        StartDonaCheckoutQueryHandler::new(DonaCheckoutStarter::new(
+            Arc::new(repository),
+            PaymentGatewayRegistry::new(vec![Arc::new(FakePaymentGateway::new(
+                DonaOptionMethod::Paypal,
+            ))]),
+        ))
+    }
+
+    #[tokio::test]
+    async fn it_should_start_a_checkout_for_the_sender() {
+        let dona = dona_with("pending", "PAYPAL");
+
+        let response = handler(dona.clone())
+            .handle(Box::new(StartDonaCheckoutQuery {
+                dona_id: dona.id(),
+                requester_id: dona.sender_id(),
+            }))
+            .await
+            .unwrap();
+        let response = response
+            .as_any()
+            .downcast_ref::<DonaCheckoutResponse>()
+            .unwrap()
+            .to_owned();
+
+        assert_eq!(
+            response.url,
+            format!("{}/{}", FAKE_CHECKOUT_URL, response.reference)
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_let_anyone_pay_a_guest_dona() {
+        let guest = DonaMother::guest(None, None, None, false);
+        let dona = Dona::new(NewDona {
+            id: guest.id(),
+            msg: guest.msg(),
+            amount: guest.amount(),
+            currency: guest.currency(),
+            status: "pending".to_string(),
+            method: "PAYPAL".to_string(),
+            user_id: guest.user_id(),
+            sender_id: None,
+            guest_name: guest.guest_name(),
+            guest_email: guest.guest_email(),
+            is_anonymous: false,
+            campaign_id: None,
+            pledge_id: None,
+            tx_hash: None,
+            created_at: guest.created_at(),
+            updated_at: guest.updated_at(),
+        })
+        .unwrap();
+
+        let result = handler(dona.clone())
+            .handle(Box::new(StartDonaCheckoutQuery {
+                dona_id: dona.id(),
+                requester_id: None,
+            }))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_the_dona_cannot_be_paid() {
+        for (dona, requester_id, error) in [
+            (
+                dona_with("pending", "PAYPAL"),
+                Some(new_uuid()),
+                ERR_DONA_NOT_FOUND,
+            ),
+            (dona_with("confirmed", "PAYPAL"), None, ERR_DONA_NOT_PAYABLE),
+            (
+                dona_with("pending", "MANUAL"),
+                None,
+                ERR_PAYMENT_GATEWAY_NOT_FOUND,
+            ),
+        ] {
+            let requester_id = requester_id.or(dona.sender_id());
+
+            let result = handler(dona.clone())
+                .handle(Box::new(StartDonaCheckoutQuery {
+                    dona_id: dona.id(),
+                    requester_id,
+                }))
+                .await;
+
+            assert_eq!(result.err(), Some(QueryError::new(error.to_string())));
+        }
+    }
+}
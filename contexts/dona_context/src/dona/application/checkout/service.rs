@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use shared::domain::base_errors::BaseRepositoryError;
+
+use crate::{
+    dona::{
+        application::response::DonaCheckoutResponse,
+        domain::{
+            dona::{DonaId, DonaStatus, ERR_DONA_NOT_FOUND},
+            dona_repository::DonaRepository,
+            payment_gateway::{PaymentGatewayRegistry, ERR_DONA_NOT_PAYABLE},
+        },
+    },
+    shared::domain::dona::DonaOptionMethod,
+};
+
+#[derive(Clone)]
+pub struct DonaCheckoutStarter {
+    repository: Arc<dyn DonaRepository>,
+    gateways: PaymentGatewayRegistry,
+}
+
+impl DonaCheckoutStarter {
+    pub fn new(repository: Arc<dyn DonaRepository>, gateways: PaymentGatewayRegistry) -> Self {
+        Self {
+            repository,
+            gateways,
+        }
+    }
+
+    /// Guests have no account, the id of their dona is enough to pay it. Donas of registered
+    /// users can only be paid by their sender.
+    pub async fn execute(
+        &self,
+        dona_id: String,
+        requester_id: Option<String>,
+    ) -> Result<DonaCheckoutResponse, String> {
+        let dona = self
+            .repository
+            .find_by_id(DonaId::new(dona_id)?)
+            .await
+            .map_err(|e| match e {
+                BaseRepositoryError::NotFound => ERR_DONA_NOT_FOUND.to_string(),
+                e => e.to_string(),
+            })?;
+        if dona
+            .sender_id()
+            .is_some_and(|sender_id| Some(sender_id) != requester_id)
+        {
+            return Err(ERR_DONA_NOT_FOUND.to_string());
+        }
+        if dona.status() != DonaStatus::Pending.to_string() {
+            return Err(ERR_DONA_NOT_PAYABLE.to_string());
+        }
+
+        let checkout = self
+            .gateways
+            .get(&DonaOptionMethod::new(dona.method())?)?
+            .create_checkout(&dona)
+            .await?;
+
+        Ok(checkout.into())
+    }
+}
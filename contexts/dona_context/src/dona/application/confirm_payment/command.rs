@@ -0,0 +1,449 @@
+use shared::domain::bus::command::{Command, CommandError, CommandHandler};
+use time::OffsetDateTime;
+
+use super::service::DonaPaymentConfirmer;
+
+pub const CONFIRM_DONA_PAYMENT_COMMAND_TYPE: &str = "dona.confirm_dona_payment.command";
+
+#[derive(Debug)]
+pub struct ConfirmDonaPaymentCommand {
+    /// Method whose gateway sent the callback.
+    pub method: String,
+    /// Body of the callback as received, gateways may verify it byte for byte.
+    pub payload: String,
+    pub received_at: OffsetDateTime,
+}
+
+impl Command for ConfirmDonaPaymentCommand {
+    fn command_type(&self) -> &'static str {
+        CONFIRM_DONA_PAYMENT_COMMAND_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct ConfirmDonaPaymentCommandHandler {
+    service: DonaPaymentConfirmer,
+}
+
+impl ConfirmDonaPaymentCommandHandler {
+    pub fn new(service: DonaPaymentConfirmer) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler for ConfirmDonaPaymentCommandHandler {
+    async fn handle(&self, command: Box<dyn Command>) -> Result<(), CommandError> {
+        let command = command
+            .as_any()
+            .downcast_ref::<ConfirmDonaPaymentCommand>()
+            .ok_or_else(|| CommandError::new("Invalid command".to_string()))?;
+
+        self.service
+            .execute(
+                command.method.to_owned(),
+                command.payload.to_owned(),
+                command.received_at,
+            )
+            .await
+            .map_err(CommandError::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use rust_decimal_macros::dec;
+    use shared::domain::{
+        bus::{event::tests::MockEventBus, query::QueryHandler},
+        utils::new_uuid,
+    };
+    use time::Duration;
+
+    use super::*;
+
+    use crate::dona::application::checkout::{
+        query::{StartDonaCheckoutQuery, StartDonaCheckoutQueryHandler},
+        service::DonaCheckoutStarter,
+    };
+    use crate::dona::application::response::DonaCheckoutResponse;
+    use crate::dona::domain::dona::tests::DonaMother;
+    use crate::dona::domain::dona::{Dona, DonaId};
+    use crate::dona::domain::dona_confirmed_event::DONA_CONFIRMED_EVENT_TYPE;
+    use crate::dona::domain::dona_repository::tests::MockDonaRepository;
+    use crate::dona::domain::payment_gateway::tests::MockPaymentGateway;
+    use crate::dona::domain::payment_gateway::{
+        GatewayPayment, PaymentGatewayRegistry, PaymentStatus, ERR_PAYMENT_GATEWAY_NOT_FOUND,
+        ERR_PAYMENT_MISMATCH,
+    };
+    use crate::dona::infrastructure::payment::fake_payment_gateway::FakePaymentGateway;
+    use crate::shared::domain::dona::DonaOptionMethod;
+    use crate::user_payment_method::domain::user_payment_method::tests::UserPaymentMethodMother;
+    use crate::user_payment_method::domain::user_payment_method::UserPaymentMethod;
+    use crate::user_payment_method::domain::user_payment_method_repository::tests::MockUserPaymentMethodRepository;
+
+    fn paypal_dona(status: &str) -> Dona {
+        DonaMother::create(
+            None,
+            None,
+            Some(dec!(10.00)),
+            Some("USD".to_string()),
+            Some(status.to_string()),
+            Some("PAYPAL".to_string()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn payment(dona: &Dona, status: PaymentStatus) -> GatewayPayment {
+        GatewayPayment {
+            dona_id: DonaId::new(dona.id()).unwrap(),
+            reference: "ref-1".to_string(),
+            status,
+            amount: dona.amount(),
+            currency: dona.currency(),
+            receiver: None,
+        }
+    }
+
+    fn gateway(payment: GatewayPayment) -> PaymentGatewayRegistry {
+        let mut gateway = MockPaymentGateway::new();
+        gateway
+            .expect_method()
+            .return_const(DonaOptionMethod::Paypal);
+        gateway.expect_parse_callback().return_const(Ok(payment));
+
+        PaymentGatewayRegistry::new(vec![Arc::new(gateway)])
+    }
+
+    fn command(method: &str, payload: String) -> Box<ConfirmDonaPaymentCommand> {
+        Box::new(ConfirmDonaPaymentCommand {
+            method: method.to_string(),
+            payload,
+            received_at: OffsetDateTime::now_utc() - Duration::seconds(1),
+        })
+    }
+
+    /// Saved donas are kept in `stored`, so a later lookup sees them as they were left.
+    fn repository(stored: Arc<Mutex<Dona>>) -> MockDonaRepository {
+        let mut repository = MockDonaRepository::new();
+        let found = stored.clone();
+        repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(found.lock().unwrap().clone()));
+        repository.expect_save().returning(move |dona| {
+            *stored.lock().unwrap() = dona.clone();
+            Ok(())
+        });
+        repository
+    }
+
+    #[tokio::test]
+    async fn it_should_confirm_a_dona_paid_through_the_fake_gateway() {
+        let stored = Arc::new(Mutex::new(paypal_dona("pending")));
+        let (dona_id, sender_id) = {
+            let dona = stored.lock().unwrap();
+            (dona.id(), dona.sender_id())
+        };
+        let fake_gateway = Arc::new(FakePaymentGateway::new(DonaOptionMethod::Paypal));
+        let gateways = PaymentGatewayRegistry::new(vec![fake_gateway.clone()]);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus
+            .expect_publish()
+            .withf(|events| {
+                events.len() == 1 && events[0].event_type() == DONA_CONFIRMED_EVENT_TYPE
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let checkout_handler = StartDonaCheckoutQueryHandler::new(DonaCheckoutStarter::new(
+            Arc::new(repository(stored.clone())),
+            gateways.clone(),
+        ));
+        let confirm_handler = ConfirmDonaPaymentCommandHandler::new(DonaPaymentConfirmer::new(
+            Arc::new(repository(stored.clone())),
+            Arc::new(MockUserPaymentMethodRepository::new()),
+            gateways,
+            Arc::new(event_bus),
+        ));
+
+        let checkout = checkout_handler
+            .handle(Box::new(StartDonaCheckoutQuery {
+                dona_id,
+                requester_id: sender_id,
+            }))
+            .await
+            .unwrap();
+        let checkout = checkout
+            .as_any()
+            .downcast_ref::<DonaCheckoutResponse>()
+            .unwrap()
+            .to_owned();
+
+        let callback = fake_gateway.complete(&checkout.reference).unwrap();
+        let result = confirm_handler
+            .handle(command("PAYPAL", callback.clone()))
+            .await;
+        assert!(result.is_ok(), "Result should be Ok");
+        assert!(stored.lock().unwrap().is_confirmed());
+
+        let duplicate = confirm_handler.handle(command("PAYPAL", callback)).await;
+        assert!(duplicate.is_ok(), "Duplicate should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_ignore_payments_not_completed() {
+        let dona = paypal_dona("pending");
+
+        for status in [PaymentStatus::Pending, PaymentStatus::Failed] {
+            let mut repository = MockDonaRepository::new();
+            repository.expect_find_by_id().times(0);
+            repository.expect_save().times(0);
+
+            let handler = ConfirmDonaPaymentCommandHandler::new(DonaPaymentConfirmer::new(
+                Arc::new(repository),
+                Arc::new(MockUserPaymentMethodRepository::new()),
+                gateway(payment(&dona, status)),
+                Arc::new(MockEventBus::new()),
+            ));
+
+            let result = handler.handle(command("PAYPAL", String::new())).await;
+
+            assert!(result.is_ok(), "Result should be Ok");
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_reject_a_payment_that_does_not_match_the_dona() {
+        let dona = paypal_dona("pending");
+        let mut other_amount = payment(&dona, PaymentStatus::Completed);
+        other_amount.amount = dec!(1.00);
+        let mut other_currency = payment(&dona, PaymentStatus::Completed);
+        other_currency.currency = "EUR".to_string();
+
+        let other_dona = payment(&paypal_dona("pending"), PaymentStatus::Completed);
+
+        for reported in [other_amount, other_currency, other_dona] {
+            let mut repository = MockDonaRepository::new();
+            repository
+                .expect_find_by_id()
+                .times(1)
+                .return_const(Ok(dona.clone()));
+            repository.expect_save().times(0);
+
+            let handler = ConfirmDonaPaymentCommandHandler::new(DonaPaymentConfirmer::new(
+                Arc::new(repository),
+                Arc::new(MockUserPaymentMethodRepository::new()),
+                gateway(reported),
+                Arc::new(MockEventBus::new()),
+            ));
+
+            let result = handler.handle(command("PAYPAL", String::new())).await;
+
+            assert_eq!(
+                result,
+                Err(CommandError::new(ERR_PAYMENT_MISMATCH.to_string()))
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_only_confirm_payments_sent_to_the_recipient_account() {
+        let dona = paypal_dona("pending");
+        let account = UserPaymentMethodMother::paypal(
+            Some(dona.user_id()),
+            Some("recipient@example.com".to_string()),
+        );
+
+        for (receiver, confirmed) in [
+            ("Recipient@Example.com", true),
+            (" recipient@example.com ", true),
+            ("someone@example.com", false),
+            ("example.com", false),
+            ("", false),
+        ] {
+            let mut reported = payment(&dona, PaymentStatus::Completed);
+            reported.receiver = Some(receiver.to_string());
+
+            let mut repository = MockDonaRepository::new();
+            repository
+                .expect_find_by_id()
+                .times(1)
+                .return_const(Ok(dona.clone()));
+            repository
+                .expect_save()
+                .times(confirmed as usize)
+                .return_const(Ok(()));
+
+            let mut user_payment_method_repository = MockUserPaymentMethodRepository::new();
+            user_payment_method_repository
+                .expect_find_by_criteria()
+                .times(1)
+                .return_const(Ok(vec![account.clone()]));
+
+            let mut event_bus = MockEventBus::new();
+            event_bus
+                .expect_publish()
+                .times(confirmed as usize)
+                .return_const(Ok(()));
+
+            let handler = ConfirmDonaPaymentCommandHandler::new(DonaPaymentConfirmer::new(
+                Arc::new(repository),
+                Arc::new(user_payment_method_repository),
+                gateway(reported),
+                Arc::new(event_bus),
+            ));
+
+            let result = handler.handle(command("PAYPAL", String::new())).await;
+
+            if confirmed {
+                assert!(result.is_ok(), "Result should be Ok");
+            } else {
+                assert_eq!(
+                    result,
+                    Err(CommandError::new(ERR_PAYMENT_MISMATCH.to_string()))
+                );
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_refuse_payments_to_a_paypal_method_without_its_account_email() {
+        let dona = paypal_dona("pending");
+        // Saved before the account email was asked for, it only shows in the instructions
+        let account = UserPaymentMethod::new(
+            new_uuid(),
+            dona.user_id(),
+            "PAYPAL".to_string(),
+            "Send your dona to recipient@example.com, thanks!".to_string(),
+            None,
+            None,
+            None,
+            vec!["USD".to_string()],
+            dona.created_at(),
+            dona.updated_at(),
+        )
+        .unwrap();
+        let mut reported = payment(&dona, PaymentStatus::Completed);
+        reported.receiver = Some("recipient@example.com".to_string());
+
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(dona.clone()));
+        repository.expect_save().times(0);
+
+        let mut user_payment_method_repository = MockUserPaymentMethodRepository::new();
+        user_payment_method_repository
+            .expect_find_by_criteria()
+            .times(1)
+            .return_const(Ok(vec![account]));
+
+        let handler = ConfirmDonaPaymentCommandHandler::new(DonaPaymentConfirmer::new(
+            Arc::new(repository),
+            Arc::new(user_payment_method_repository),
+            gateway(reported),
+            Arc::new(MockEventBus::new()),
+        ));
+
+        let result = handler.handle(command("PAYPAL", String::new())).await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_PAYMENT_MISMATCH.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_accept_a_duplicate_payment_of_a_dona_no_longer_pending() {
+        for status in ["confirmed", "refunded"] {
+            let dona = paypal_dona(status);
+            let mut reported = payment(&dona, PaymentStatus::Completed);
+            reported.receiver = Some("recipient@example.com".to_string());
+
+            let mut repository = MockDonaRepository::new();
+            repository
+                .expect_find_by_id()
+                .times(1)
+                .return_const(Ok(dona.clone()));
+            repository.expect_save().times(0);
+
+            let mut event_bus = MockEventBus::new();
+            event_bus.expect_publish().times(0);
+
+            let handler = ConfirmDonaPaymentCommandHandler::new(DonaPaymentConfirmer::new(
+                Arc::new(repository),
+                Arc::new(MockUserPaymentMethodRepository::new()),
+                gateway(reported),
+                Arc::new(event_bus),
+            ));
+
+            let result = handler.handle(command("PAYPAL", String::new())).await;
+
+            assert!(result.is_ok(), "A {} dona should be left as it is", status);
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_reject_payments_when_the_recipient_has_no_paypal_account() {
+        let dona = paypal_dona("pending");
+        let mut reported = payment(&dona, PaymentStatus::Completed);
+        reported.receiver = Some("recipient@example.com".to_string());
+
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(dona.clone()));
+        repository.expect_save().times(0);
+
+        let mut user_payment_method_repository = MockUserPaymentMethodRepository::new();
+        user_payment_method_repository
+            .expect_find_by_criteria()
+            .times(1)
+            .return_const(Ok(vec![]));
+
+        let handler = ConfirmDonaPaymentCommandHandler::new(DonaPaymentConfirmer::new(
+            Arc::new(repository),
+            Arc::new(user_payment_method_repository),
+            gateway(reported),
+            Arc::new(MockEventBus::new()),
+        ));
+
+        let result = handler.handle(command("PAYPAL", String::new())).await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_PAYMENT_MISMATCH.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_for_methods_without_gateway() {
+        let dona = paypal_dona("pending");
+        let handler = ConfirmDonaPaymentCommandHandler::new(DonaPaymentConfirmer::new(
+            Arc::new(MockDonaRepository::new()),
+            Arc::new(MockUserPaymentMethodRepository::new()),
+            gateway(payment(&dona, PaymentStatus::Completed)),
+            Arc::new(MockEventBus::new()),
+        ));
+
+        let result = handler.handle(command("MANUAL", String::new())).await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_PAYMENT_GATEWAY_NOT_FOUND.to_string()))
+        );
+    }
+}
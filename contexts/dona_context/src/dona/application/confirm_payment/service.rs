@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use shared::domain::{base_errors::BaseRepositoryError, bus::event::EventBus};
+use time::OffsetDateTime;
+
+use crate::{
+    dona::{
+        application::create::service::{
+            recipient_accepts_method, ERR_RECIPIENT_CURRENCY_NOT_ACCEPTED,
+            ERR_RECIPIENT_PAYMENT_METHOD_NOT_FOUND,
+        },
+        domain::{
+            dona::{Dona, ERR_DONA_NOT_FOUND},
+            dona_repository::DonaRepository,
+            payment_gateway::{
+                GatewayPayment, PaymentGatewayRegistry, PaymentStatus, ERR_PAYMENT_MISMATCH,
+            },
+        },
+    },
+    shared::domain::dona::DonaOptionMethod,
+    user_payment_method::domain::user_payment_method_repository::UserPaymentMethodRepository,
+};
+
+#[derive(Clone)]
+pub struct DonaPaymentConfirmer {
+    repository: Arc<dyn DonaRepository>,
+    user_payment_method_repository: Arc<dyn UserPaymentMethodRepository>,
+    gateways: PaymentGatewayRegistry,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl DonaPaymentConfirmer {
+    pub fn new(
+        repository: Arc<dyn DonaRepository>,
+        user_payment_method_repository: Arc<dyn UserPaymentMethodRepository>,
+        gateways: PaymentGatewayRegistry,
+        event_bus: Arc<dyn EventBus>,
+    ) -> Self {
+        Self {
+            repository,
+            user_payment_method_repository,
+            gateways,
+            event_bus,
+        }
+    }
+
+    /// The payment must have gone to the account the recipient set up for the method of the
+    /// dona, otherwise paying someone else would confirm it. A method without an account to
+    /// compare with, such as a PayPal one saved before its email was asked for, is refused.
+    async fn check_receiver(&self, dona: &Dona, receiver: &str) -> Result<(), String> {
+        let method = recipient_accepts_method(
+            self.user_payment_method_repository.as_ref(),
+            dona.user_id(),
+            dona.method(),
+            dona.currency(),
+        )
+        .await
+        .map_err(|e| match e.as_str() {
+            ERR_RECIPIENT_PAYMENT_METHOD_NOT_FOUND | ERR_RECIPIENT_CURRENCY_NOT_ACCEPTED => {
+                ERR_PAYMENT_MISMATCH.to_string()
+            }
+            _ => e,
+        })?;
+
+        if method.receives_at(receiver) {
+            Ok(())
+        } else {
+            Err(ERR_PAYMENT_MISMATCH.to_string())
+        }
+    }
+
+    /// Confirms the dona of a completed payment. Providers deliver the same payment again
+    /// until they get an answer, so a dona that is no longer pending, such as one confirmed or
+    /// refunded since, is left as it is.
+    pub async fn confirm(
+        &self,
+        method: &DonaOptionMethod,
+        payment: GatewayPayment,
+        confirmed_at: OffsetDateTime,
+    ) -> Result<(), String> {
+        if payment.status != PaymentStatus::Completed {
+            return Ok(());
+        }
+
+        let mut dona = self
+            .repository
+            .find_by_id(payment.dona_id.clone())
+            .await
+            .map_err(|e| match e {
+                BaseRepositoryError::NotFound => ERR_DONA_NOT_FOUND.to_string(),
+                e => e.to_string(),
+            })?;
+        if dona.method() != method.to_string() || !payment.matches(&dona) {
+            return Err(ERR_PAYMENT_MISMATCH.to_string());
+        }
+        if !dona.is_pending() {
+            return Ok(());
+        }
+        if let Some(receiver) = &payment.receiver {
+            self.check_receiver(&dona, receiver).await?;
+        }
+
+        dona.confirm(confirmed_at)?;
+
+        self.repository.save(&dona).await?;
+
+        self.event_bus.publish(dona.pull_events()).await?;
+
+        Ok(())
+    }
+
+    /// `payload` is the callback exactly as the provider posted it.
+    pub async fn execute(
+        &self,
+        method: String,
+        payload: String,
+        received_at: OffsetDateTime,
+    ) -> Result<(), String> {
+        let method = DonaOptionMethod::new(method)?;
+        let payment = self.gateways.get(&method)?.parse_callback(&payload).await?;
+
+        self.confirm(&method, payment, received_at).await
+    }
+}
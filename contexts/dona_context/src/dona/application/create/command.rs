@@ -0,0 +1,1320 @@
+use rust_decimal::Decimal;
+use shared::domain::bus::command::{Command, CommandError, CommandHandler};
+use time::OffsetDateTime;
+
+use crate::dona::domain::dona::{DonaStatus, NewDona};
+
+use super::service::DonaCreator;
+
+pub const CREATE_DONA_COMMAND_TYPE: &str = "dona.create_dona.command";
+
+#[derive(Debug)]
+pub struct CreateDonaCommand {
+    pub id: String,
+    pub msg: String,
+    pub amount: Decimal,
+    pub currency: String,
+    pub method: String,
+    pub user_id: String,
+    /// `None` for guest donas, which carry `guest_name` instead.
+    pub sender_id: Option<String>,
+    pub guest_name: Option<String>,
+    pub guest_email: Option<String>,
+    pub is_anonymous: bool,
+    pub campaign_id: Option<String>,
+    /// Required for crypto donas, rejected for the others.
+    pub tx_hash: Option<String>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl Command for CreateDonaCommand {
+    fn command_type(&self) -> &'static str {
+        CREATE_DONA_COMMAND_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct CreateDonaCommandHandler {
+    service: DonaCreator,
+}
+
+impl CreateDonaCommandHandler {
+    pub fn new(service: DonaCreator) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler for CreateDonaCommandHandler {
+    async fn handle(&self, command: Box<dyn Command>) -> Result<(), CommandError> {
+        let command = command
+            .as_any()
+            .downcast_ref::<CreateDonaCommand>()
+            .ok_or_else(|| CommandError::new("Invalid command".to_string()))?;
+
+        self.service
+            .execute(NewDona {
+                id: command.id.to_owned(),
+                msg: command.msg.to_owned(),
+                amount: command.amount,
+                currency: command.currency.to_owned(),
+                status: DonaStatus::Pending.to_string(),
+                method: command.method.to_owned(),
+                user_id: command.user_id.to_owned(),
+                sender_id: command.sender_id.to_owned(),
+                guest_name: command.guest_name.to_owned(),
+                guest_email: command.guest_email.to_owned(),
+                is_anonymous: command.is_anonymous,
+                campaign_id: command.campaign_id.to_owned(),
+                pledge_id: None,
+                tx_hash: command.tx_hash.to_owned(),
+                created_at: command.created_at,
+                updated_at: command.updated_at,
+            })
+            .await
+            .map_err(CommandError::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockall::predicate;
+    use rust_decimal_macros::dec;
+    use shared::domain::base_errors::BaseRepositoryError;
+    use shared::domain::bus::event::tests::MockEventBus;
+    use shared::domain::bus::event::Event;
+    use shared::domain::utils::new_uuid;
+    use shared::domain::value_objects::money::ERR_INVALID_MONEY_PRECISION;
+    use shared::domain::value_objects::user_id::tests::UserIdMother;
+    use shared::domain::value_objects::user_id::UserId;
+
+    use super::*;
+
+    use crate::campaign::domain::campaign::tests::CampaignMother;
+    use crate::campaign::domain::campaign::{Campaign, CampaignId, ERR_CAMPAIGN_NOT_FOUND};
+    use crate::campaign::domain::campaign_repository::tests::MockCampaignRepository;
+    use crate::dona::application::create::service::{
+        ERR_CAMPAIGN_CURRENCY_MISMATCH, ERR_CAMPAIGN_NOT_ACCEPTING_DONAS, ERR_DONA_ALREADY_EXISTS,
+        ERR_DONA_TX_HASH_NETWORK_MISMATCH, ERR_DONA_TX_HASH_REQUIRED,
+        ERR_RECIPIENT_CURRENCY_NOT_ACCEPTED, ERR_RECIPIENT_PAYMENT_METHOD_NOT_FOUND,
+    };
+    use crate::dona::domain::dona::tests::DonaMother;
+    use crate::dona::domain::dona::{
+        Dona, DonaId, ERR_DONA_TX_HASH_NOT_ALLOWED, ERR_INVALID_DONA_GUEST_EMAIL,
+        ERR_INVALID_DONA_SENDER, REDACTED_DONA_MSG,
+    };
+    use crate::dona::domain::dona_created_event::DonaCreatedEvent;
+    use crate::dona::domain::dona_msg_policy::{
+        DonaMsgLinkPolicy, DonaMsgPolicy, ERR_DONA_MSG_LINKS_NOT_ALLOWED, ERR_DONA_MSG_TOO_LONG,
+    };
+    use crate::dona::domain::dona_repository::tests::MockDonaRepository;
+    use crate::exchange_rate::application::convert::service::CurrencyConverter;
+    use crate::exchange_rate::domain::exchange_rate::tests::ExchangeRateMother;
+    use crate::exchange_rate::domain::exchange_rate_repository::tests::MockExchangeRateRepository;
+    use crate::recipient::domain::recipient_settings::tests::RecipientSettingsMother;
+    use crate::recipient::domain::recipient_settings::{
+        RecipientSettings, ERR_DONA_AMOUNT_ABOVE_RECIPIENT_MAXIMUM,
+        ERR_DONA_AMOUNT_BELOW_RECIPIENT_MINIMUM, ERR_RECIPIENT_METHOD_NOT_ACTIVE,
+    };
+    use crate::recipient::domain::recipient_settings_repository::tests::MockRecipientSettingsRepository;
+    use crate::user_payment_method::domain::user_payment_method::tests::UserPaymentMethodMother;
+    use crate::user_payment_method::domain::user_payment_method::UserPaymentMethod;
+    use crate::user_payment_method::domain::user_payment_method_repository::tests::MockUserPaymentMethodRepository;
+
+    fn pending_dona() -> Dona {
+        DonaMother::create(
+            None,
+            None,
+            Some(dec!(25.50)),
+            Some("USD".to_string()),
+            Some("pending".to_string()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn command_from(dona: &Dona) -> CreateDonaCommand {
+        CreateDonaCommand {
+            id: dona.id(),
+            msg: dona.msg(),
+            amount: dona.amount(),
+            currency: dona.currency(),
+            method: dona.method(),
+            user_id: dona.user_id(),
+            sender_id: dona.sender_id(),
+            guest_name: dona.guest_name(),
+            guest_email: dona.guest_email(),
+            is_anonymous: dona.is_anonymous(),
+            campaign_id: dona.campaign_id(),
+            tx_hash: dona.tx_hash(),
+            created_at: dona.created_at(),
+            updated_at: dona.updated_at(),
+        }
+    }
+
+    fn no_recipient_settings() -> MockRecipientSettingsRepository {
+        let mut repository = MockRecipientSettingsRepository::new();
+        repository
+            .expect_find_by_user_id()
+            .return_const(Err(BaseRepositoryError::NotFound));
+        repository
+    }
+
+    fn converter() -> CurrencyConverter {
+        CurrencyConverter::new(Arc::new(MockExchangeRateRepository::new()))
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_dona_exists() {
+        let dona = pending_dona();
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_find_by_id()
+            .with(predicate::eq(DonaId::new(dona.id()).unwrap()))
+            .times(1)
+            .return_const(Ok(dona.clone()));
+        repository.expect_save().times(0);
+
+        let mut method_repository = MockUserPaymentMethodRepository::new();
+        method_repository.expect_find_by_criteria().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let service = DonaCreator::new(
+            Arc::new(repository),
+            Arc::new(method_repository),
+            Arc::new(MockCampaignRepository::new()),
+            Arc::new(no_recipient_settings()),
+            converter(),
+            DonaMsgPolicy::default(),
+            Arc::new(event_bus),
+        );
+        let handler = CreateDonaCommandHandler::new(service);
+
+        let result = handler.handle(Box::new(command_from(&dona))).await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_DONA_ALREADY_EXISTS.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_recipient_has_no_payment_method() {
+        let dona = pending_dona();
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        repository.expect_save().times(0);
+
+        let mut method_repository = MockUserPaymentMethodRepository::new();
+        method_repository
+            .expect_find_by_criteria()
+            .times(1)
+            .return_const(Ok(vec![]));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let service = DonaCreator::new(
+            Arc::new(repository),
+            Arc::new(method_repository),
+            Arc::new(MockCampaignRepository::new()),
+            Arc::new(no_recipient_settings()),
+            converter(),
+            DonaMsgPolicy::default(),
+            Arc::new(event_bus),
+        );
+        let handler = CreateDonaCommandHandler::new(service);
+
+        let result = handler.handle(Box::new(command_from(&dona))).await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(
+                ERR_RECIPIENT_PAYMENT_METHOD_NOT_FOUND.to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_recipient_does_not_accept_currency() {
+        let dona = pending_dona();
+        let method = UserPaymentMethodMother::create(
+            None,
+            Some(dona.user_id()),
+            Some(dona.method()),
+            None,
+            Some(vec!["EUR".to_string()]),
+            None,
+            None,
+        );
+
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        repository.expect_save().times(0);
+
+        let mut method_repository = MockUserPaymentMethodRepository::new();
+        method_repository
+            .expect_find_by_criteria()
+            .times(1)
+            .return_const(Ok(vec![method]));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let service = DonaCreator::new(
+            Arc::new(repository),
+            Arc::new(method_repository),
+            Arc::new(MockCampaignRepository::new()),
+            Arc::new(no_recipient_settings()),
+            converter(),
+            DonaMsgPolicy::default(),
+            Arc::new(event_bus),
+        );
+        let handler = CreateDonaCommandHandler::new(service);
+
+        let result = handler.handle(Box::new(command_from(&dona))).await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(
+                ERR_RECIPIENT_CURRENCY_NOT_ACCEPTED.to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_amount_exceeds_currency_precision() {
+        let dona = pending_dona();
+        let method = UserPaymentMethodMother::create(
+            None,
+            Some(dona.user_id()),
+            Some(dona.method()),
+            None,
+            Some(vec!["JPY".to_string()]),
+            None,
+            None,
+        );
+
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        repository.expect_save().times(0);
+
+        let mut method_repository = MockUserPaymentMethodRepository::new();
+        method_repository
+            .expect_find_by_criteria()
+            .times(1)
+            .return_const(Ok(vec![method]));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let service = DonaCreator::new(
+            Arc::new(repository),
+            Arc::new(method_repository),
+            Arc::new(MockCampaignRepository::new()),
+            Arc::new(no_recipient_settings()),
+            converter(),
+            DonaMsgPolicy::default(),
+            Arc::new(event_bus),
+        );
+        let handler = CreateDonaCommandHandler::new(service);
+
+        let command = CreateDonaCommand {
+            currency: "JPY".to_string(),
+            ..command_from(&dona)
+        };
+        let result = handler.handle(Box::new(command)).await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_INVALID_MONEY_PRECISION.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_repository_fails() {
+        let dona = pending_dona();
+        let method = UserPaymentMethodMother::create(
+            None,
+            Some(dona.user_id()),
+            Some(dona.method()),
+            None,
+            Some(vec!["USD".to_string(), "EUR".to_string()]),
+            None,
+            None,
+        );
+
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        repository
+            .expect_save()
+            .with(predicate::eq(dona.clone()))
+            .times(1)
+            .return_const(Err(BaseRepositoryError::UnexpectedError(
+                "error".to_string(),
+            )));
+
+        let mut method_repository = MockUserPaymentMethodRepository::new();
+        method_repository
+            .expect_find_by_criteria()
+            .times(1)
+            .return_const(Ok(vec![method]));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let service = DonaCreator::new(
+            Arc::new(repository),
+            Arc::new(method_repository),
+            Arc::new(MockCampaignRepository::new()),
+            Arc::new(no_recipient_settings()),
+            converter(),
+            DonaMsgPolicy::default(),
+            Arc::new(event_bus),
+        );
+        let handler = CreateDonaCommandHandler::new(service);
+
+        let result = handler.handle(Box::new(command_from(&dona))).await;
+
+        assert!(result.is_err(), "Result should be an error");
+    }
+
+    #[tokio::test]
+    async fn it_should_create_dona() {
+        let dona = pending_dona();
+        let method = UserPaymentMethodMother::create(
+            None,
+            Some(dona.user_id()),
+            Some(dona.method()),
+            None,
+            Some(vec!["USD".to_string(), "EUR".to_string()]),
+            None,
+            None,
+        );
+
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        repository
+            .expect_save()
+            .with(predicate::eq(dona.clone()))
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut method_repository = MockUserPaymentMethodRepository::new();
+        method_repository
+            .expect_find_by_criteria()
+            .times(1)
+            .return_const(Ok(vec![method]));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(1).return_const(Ok(()));
+
+        let service = DonaCreator::new(
+            Arc::new(repository),
+            Arc::new(method_repository),
+            Arc::new(MockCampaignRepository::new()),
+            Arc::new(no_recipient_settings()),
+            converter(),
+            DonaMsgPolicy::default(),
+            Arc::new(event_bus),
+        );
+        let handler = CreateDonaCommandHandler::new(service);
+
+        let result = handler.handle(Box::new(command_from(&dona))).await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    fn campaign_dona(campaign_id: &str, user_id: &str) -> Dona {
+        DonaMother::create(
+            None,
+            None,
+            Some(dec!(25.50)),
+            Some("USD".to_string()),
+            Some("pending".to_string()),
+            None,
+            Some(user_id.to_string()),
+            None,
+            Some(campaign_id.to_string()),
+            None,
+            None,
+            None,
+        )
+    }
+
+    async fn handle_campaign_dona(
+        dona: &Dona,
+        campaign: Campaign,
+        saves: usize,
+    ) -> Result<(), CommandError> {
+        let method = UserPaymentMethodMother::create(
+            None,
+            Some(dona.user_id()),
+            Some(dona.method()),
+            None,
+            Some(vec!["USD".to_string()]),
+            None,
+            None,
+        );
+
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        repository
+            .expect_save()
+            .with(predicate::eq(dona.clone()))
+            .times(saves)
+            .return_const(Ok(()));
+
+        let mut method_repository = MockUserPaymentMethodRepository::new();
+        method_repository
+            .expect_find_by_criteria()
+            .times(1)
+            .return_const(Ok(vec![method]));
+
+        let mut campaign_repository = MockCampaignRepository::new();
+        campaign_repository
+            .expect_find_by_id()
+            .with(predicate::eq(CampaignId::new(campaign.id()).unwrap()))
+            .times(1)
+            .return_const(Ok(campaign));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(saves).return_const(Ok(()));
+
+        let service = DonaCreator::new(
+            Arc::new(repository),
+            Arc::new(method_repository),
+            Arc::new(campaign_repository),
+            Arc::new(no_recipient_settings()),
+            converter(),
+            DonaMsgPolicy::default(),
+            Arc::new(event_bus),
+        );
+        let handler = CreateDonaCommandHandler::new(service);
+
+        handler.handle(Box::new(command_from(dona))).await
+    }
+
+    #[tokio::test]
+    async fn it_should_create_dona_for_campaign() {
+        let campaign = CampaignMother::create(
+            None,
+            None,
+            None,
+            Some(dec!(100.00)),
+            Some("USD".to_string()),
+            None,
+            None,
+        );
+        let dona = campaign_dona(&campaign.id(), &campaign.user_id());
+
+        let result = handle_campaign_dona(&dona, campaign, 1).await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_campaign_is_closed() {
+        let campaign = CampaignMother::create(
+            None,
+            None,
+            None,
+            Some(dec!(100.00)),
+            Some("USD".to_string()),
+            None,
+            Some("closed".to_string()),
+        );
+        let dona = campaign_dona(&campaign.id(), &campaign.user_id());
+
+        let result = handle_campaign_dona(&dona, campaign, 0).await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(
+                ERR_CAMPAIGN_NOT_ACCEPTING_DONAS.to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_campaign_currency_differs() {
+        let campaign = CampaignMother::create(
+            None,
+            None,
+            None,
+            Some(dec!(100.00)),
+            Some("EUR".to_string()),
+            None,
+            None,
+        );
+        let dona = campaign_dona(&campaign.id(), &campaign.user_id());
+
+        let result = handle_campaign_dona(&dona, campaign, 0).await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(
+                ERR_CAMPAIGN_CURRENCY_MISMATCH.to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_campaign_belongs_to_another_user() {
+        let campaign = CampaignMother::create(
+            None,
+            None,
+            None,
+            Some(dec!(100.00)),
+            Some("USD".to_string()),
+            None,
+            None,
+        );
+        let dona = campaign_dona(&campaign.id(), &UserIdMother::random().to_string());
+
+        let result = handle_campaign_dona(&dona, campaign, 0).await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_CAMPAIGN_NOT_FOUND.to_string()))
+        );
+    }
+
+    async fn handle_guest_dona(
+        dona: &Dona,
+        command: CreateDonaCommand,
+        saves: usize,
+    ) -> Result<(), CommandError> {
+        let method = UserPaymentMethodMother::create(
+            None,
+            Some(dona.user_id()),
+            Some(dona.method()),
+            None,
+            Some(vec!["USD".to_string()]),
+            None,
+            None,
+        );
+
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        repository
+            .expect_save()
+            .with(predicate::eq(dona.clone()))
+            .times(saves)
+            .return_const(Ok(()));
+
+        let mut method_repository = MockUserPaymentMethodRepository::new();
+        method_repository
+            .expect_find_by_criteria()
+            .times(1)
+            .return_const(Ok(vec![method]));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(saves).return_const(Ok(()));
+
+        let service = DonaCreator::new(
+            Arc::new(repository),
+            Arc::new(method_repository),
+            Arc::new(MockCampaignRepository::new()),
+            Arc::new(no_recipient_settings()),
+            converter(),
+            DonaMsgPolicy::default(),
+            Arc::new(event_bus),
+        );
+        let handler = CreateDonaCommandHandler::new(service);
+
+        handler.handle(Box::new(command)).await
+    }
+
+    #[tokio::test]
+    async fn it_should_create_anonymous_guest_dona() {
+        let dona = DonaMother::guest(
+            None,
+            Some("Jane".to_string()),
+            Some("jane@example.com".to_string()),
+            true,
+        );
+
+        let result = handle_guest_dona(&dona, command_from(&dona), 1).await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_dona_has_both_sender_and_guest() {
+        let dona = DonaMother::guest(None, Some("Jane".to_string()), None, false);
+        let command = CreateDonaCommand {
+            sender_id: Some(UserIdMother::random().to_string()),
+            ..command_from(&dona)
+        };
+
+        let result = handle_guest_dona(&dona, command, 0).await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_INVALID_DONA_SENDER.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_guest_email_is_invalid() {
+        let dona = DonaMother::guest(None, Some("Jane".to_string()), None, false);
+        let command = CreateDonaCommand {
+            guest_email: Some("not-an-email".to_string()),
+            ..command_from(&dona)
+        };
+
+        let result = handle_guest_dona(&dona, command, 0).await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_INVALID_DONA_GUEST_EMAIL.to_string()))
+        );
+    }
+
+    const BTC_TX_HASH: &str = "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b";
+
+    fn crypto_dona(network: &str, address: &str, tx_hash: &str) -> (Dona, UserPaymentMethod) {
+        let dona = DonaMother::create(
+            None,
+            None,
+            Some(dec!(25.50)),
+            Some("USD".to_string()),
+            Some("pending".to_string()),
+            Some("CRYPTO".to_string()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let dona = Dona::new(NewDona {
+            id: dona.id(),
+            msg: dona.msg(),
+            amount: dona.amount(),
+            currency: dona.currency(),
+            status: dona.status(),
+            method: dona.method(),
+            user_id: dona.user_id(),
+            sender_id: dona.sender_id(),
+            guest_name: None,
+            guest_email: None,
+            is_anonymous: false,
+            campaign_id: None,
+            pledge_id: None,
+            tx_hash: Some(tx_hash.to_string()),
+            created_at: dona.created_at(),
+            updated_at: dona.updated_at(),
+        })
+        .unwrap();
+        let method = UserPaymentMethod::new(
+            new_uuid(),
+            dona.user_id(),
+            "CRYPTO".to_string(),
+            "Send it to my wallet".to_string(),
+            Some(network.to_string()),
+            Some(address.to_string()),
+            None,
+            vec!["USD".to_string()],
+            dona.created_at(),
+            dona.updated_at(),
+        )
+        .unwrap();
+
+        (dona, method)
+    }
+
+    async fn handle_crypto_dona(
+        dona: &Dona,
+        method: UserPaymentMethod,
+        command: CreateDonaCommand,
+        saves: usize,
+    ) -> Result<(), CommandError> {
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        repository
+            .expect_save()
+            .with(predicate::eq(dona.clone()))
+            .times(saves)
+            .return_const(Ok(()));
+
+        let mut method_repository = MockUserPaymentMethodRepository::new();
+        method_repository
+            .expect_find_by_criteria()
+            .times(1)
+            .return_const(Ok(vec![method]));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(saves).return_const(Ok(()));
+
+        let service = DonaCreator::new(
+            Arc::new(repository),
+            Arc::new(method_repository),
+            Arc::new(MockCampaignRepository::new()),
+            Arc::new(no_recipient_settings()),
+            converter(),
+            DonaMsgPolicy::default(),
+            Arc::new(event_bus),
+        );
+        let handler = CreateDonaCommandHandler::new(service);
+
+        handler.handle(Box::new(command)).await
+    }
+
+    #[tokio::test]
+    async fn it_should_create_crypto_dona_with_its_tx_hash() {
+        for (network, address, tx_hash) in [
+            (
+                "BTC",
+                "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa",
+                BTC_TX_HASH.to_string(),
+            ),
+            (
+                "ETH",
+                "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+                format!("0x{}", BTC_TX_HASH),
+            ),
+        ] {
+            let (dona, method) = crypto_dona(network, address, &tx_hash);
+
+            let result = handle_crypto_dona(&dona, method, command_from(&dona), 1).await;
+
+            assert!(result.is_ok(), "Result should be Ok");
+            assert_eq!(dona.tx_hash(), Some(tx_hash));
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_crypto_dona_has_no_valid_tx_hash() {
+        let (dona, method) = crypto_dona("TRX", "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t", BTC_TX_HASH);
+
+        for (tx_hash, error) in [
+            (None, ERR_DONA_TX_HASH_REQUIRED),
+            (
+                Some(format!("0x{}", BTC_TX_HASH)),
+                ERR_DONA_TX_HASH_NETWORK_MISMATCH,
+            ),
+            (
+                Some("abc123".to_string()),
+                ERR_DONA_TX_HASH_NETWORK_MISMATCH,
+            ),
+        ] {
+            let command = CreateDonaCommand {
+                tx_hash,
+                ..command_from(&dona)
+            };
+
+            let result = handle_crypto_dona(&dona, method.clone(), command, 0).await;
+
+            assert_eq!(result, Err(CommandError::new(error.to_string())));
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_other_donas_have_a_tx_hash() {
+        let dona = DonaMother::create(
+            None,
+            None,
+            Some(dec!(25.50)),
+            Some("USD".to_string()),
+            Some("pending".to_string()),
+            Some("PAYPAL".to_string()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let command = CreateDonaCommand {
+            tx_hash: Some(BTC_TX_HASH.to_string()),
+            ..command_from(&dona)
+        };
+
+        let result = handle_guest_dona(&dona, command, 0).await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_DONA_TX_HASH_NOT_ALLOWED.to_string()))
+        );
+    }
+
+    /// Expects `dona` to be saved with the message, status and flags in `expected`, or not to
+    /// be saved at all when it is `None`.
+    /// The dona is announced with its message as it was saved.
+    fn created_event_has(events: &[Arc<dyn Event>], msg: &str, msg_status: &str) -> bool {
+        events[0]
+            .as_any()
+            .downcast_ref::<DonaCreatedEvent>()
+            .is_some_and(|event| event.msg() == msg && event.msg_status() == msg_status)
+    }
+
+    fn moderation_handler(
+        dona: &Dona,
+        policy: DonaMsgPolicy,
+        expected: Option<(&str, &str, Vec<&str>)>,
+    ) -> CreateDonaCommandHandler {
+        let method = UserPaymentMethodMother::create(
+            None,
+            Some(dona.user_id()),
+            Some(dona.method()),
+            None,
+            Some(vec![dona.currency()]),
+            None,
+            None,
+        );
+        let saves = usize::from(expected.is_some());
+
+        let mut repository = MockDonaRepository::new();
+        let mut event_bus = MockEventBus::new();
+        repository
+            .expect_find_by_id()
+            .times(saves)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        if let Some((msg, msg_status, msg_flags)) = expected {
+            let (msg, msg_status) = (msg.to_string(), msg_status.to_string());
+            let msg_flags = msg_flags
+                .into_iter()
+                .map(str::to_string)
+                .collect::<Vec<_>>();
+            let (event_msg, event_msg_status) = (msg.clone(), msg_status.clone());
+            repository
+                .expect_save()
+                .withf(move |saved| {
+                    saved.msg() == msg
+                        && saved.msg_status() == msg_status
+                        && saved.msg_flags() == msg_flags
+                })
+                .times(1)
+                .return_const(Ok(()));
+            event_bus
+                .expect_publish()
+                .withf(move |events| created_event_has(events, &event_msg, &event_msg_status))
+                .times(1)
+                .return_const(Ok(()));
+        } else {
+            repository.expect_save().times(0);
+            event_bus.expect_publish().times(0);
+        }
+
+        let mut method_repository = MockUserPaymentMethodRepository::new();
+        method_repository
+            .expect_find_by_criteria()
+            .times(saves)
+            .return_const(Ok(vec![method]));
+
+        CreateDonaCommandHandler::new(DonaCreator::new(
+            Arc::new(repository),
+            Arc::new(method_repository),
+            Arc::new(MockCampaignRepository::new()),
+            Arc::new(no_recipient_settings()),
+            converter(),
+            policy,
+            Arc::new(event_bus),
+        ))
+    }
+
+    fn moderation_command(dona: &Dona, msg: &str) -> Box<CreateDonaCommand> {
+        let mut command = command_from(dona);
+        command.msg = msg.to_string();
+        Box::new(command)
+    }
+
+    #[tokio::test]
+    async fn it_should_publish_clean_messages_without_invisible_chars() {
+        let dona = pending_dona();
+        let policy = DonaMsgPolicy::new(500, vec!["ass".to_string()], DonaMsgLinkPolicy::Review);
+
+        let result = moderation_handler(
+            &dona,
+            policy,
+            Some(("Great \"class\" today: keep it up", "published", vec![])),
+        )
+        .handle(moderation_command(
+            &dona,
+            "Great \"class\"\u{200B} today: keep it up\r\n",
+        ))
+        .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_send_flagged_messages_to_review() {
+        let words = vec!["scam".to_string(), "buy followers".to_string()];
+
+        for (msg, stored, flags) in [
+            (
+                "Nice stream, check twitch.tv/someone",
+                "Nice stream, check twitch.tv/someone",
+                vec!["link"],
+            ),
+            (
+                "More at https://example.org/free",
+                "More at https://example.org/free",
+                vec!["link"],
+            ),
+            ("This is a SCAM!", "This is a SCAM!", vec!["banned_word"]),
+            (
+                "This is a sc\u{2060}am",
+                "This is a scam",
+                vec!["banned_word"],
+            ),
+            (
+                "This is a SC\u{00AD}AM!",
+                "This is a SCAM!",
+                vec!["banned_word"],
+            ),
+            (
+                "Buy  followers at www.spam.example",
+                "Buy  followers at www.spam.example",
+                vec!["link", "banned_word"],
+            ),
+        ] {
+            let dona = pending_dona();
+            let policy = DonaMsgPolicy::new(500, words.clone(), DonaMsgLinkPolicy::Review);
+
+            let result = moderation_handler(&dona, policy, Some((stored, "in_review", flags)))
+                .handle(moderation_command(&dona, msg))
+                .await;
+
+            assert!(result.is_ok(), "{} should be sent to review", msg);
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_let_links_through_when_allowed() {
+        let dona = pending_dona();
+        let policy = DonaMsgPolicy::new(500, vec![], DonaMsgLinkPolicy::Allow);
+
+        let result = moderation_handler(
+            &dona,
+            policy,
+            Some(("Follow me on https://example.com", "published", vec![])),
+        )
+        .handle(moderation_command(
+            &dona,
+            "Follow me on https://example.com",
+        ))
+        .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_links_are_rejected() {
+        for msg in [
+            "Free gifts at https://example.com",
+            "Free gifts at evil\u{200B}.com",
+            "Free gifts at https:\u{2060}//example.com",
+        ] {
+            let dona = pending_dona();
+            let policy = DonaMsgPolicy::new(500, vec![], DonaMsgLinkPolicy::Reject);
+
+            let result = moderation_handler(&dona, policy, None)
+                .handle(moderation_command(&dona, msg))
+                .await;
+
+            assert_eq!(
+                result,
+                Err(CommandError::new(
+                    ERR_DONA_MSG_LINKS_NOT_ALLOWED.to_string()
+                )),
+                "{:?} should be rejected",
+                msg
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_msg_is_too_long() {
+        for (policy, msg) in [
+            (
+                DonaMsgPolicy::new(20, vec![], DonaMsgLinkPolicy::Review),
+                "a".repeat(21),
+            ),
+            // The configured length cannot go over the one of the messages.
+            (
+                DonaMsgPolicy::new(1000, vec![], DonaMsgLinkPolicy::Review),
+                "a".repeat(501),
+            ),
+        ] {
+            let dona = pending_dona();
+
+            let result = moderation_handler(&dona, policy, None)
+                .handle(moderation_command(&dona, &msg))
+                .await;
+
+            assert_eq!(
+                result,
+                Err(CommandError::new(ERR_DONA_MSG_TOO_LONG.to_string()))
+            );
+        }
+    }
+
+    fn settings_handler(
+        dona: &Dona,
+        settings: RecipientSettings,
+        exchange_rate_repository: MockExchangeRateRepository,
+        saves: usize,
+    ) -> CreateDonaCommandHandler {
+        let method = UserPaymentMethodMother::create(
+            None,
+            Some(dona.user_id()),
+            Some(dona.method()),
+            None,
+            Some(vec![dona.currency()]),
+            None,
+            None,
+        );
+
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        repository.expect_save().times(saves).return_const(Ok(()));
+
+        let mut method_repository = MockUserPaymentMethodRepository::new();
+        method_repository
+            .expect_find_by_criteria()
+            .times(saves)
+            .return_const(Ok(vec![method]));
+
+        let mut settings_repository = MockRecipientSettingsRepository::new();
+        settings_repository
+            .expect_find_by_user_id()
+            .with(predicate::eq(UserId::new(dona.user_id()).unwrap()))
+            .times(1)
+            .return_const(Ok(settings));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(saves).return_const(Ok(()));
+
+        CreateDonaCommandHandler::new(DonaCreator::new(
+            Arc::new(repository),
+            Arc::new(method_repository),
+            Arc::new(MockCampaignRepository::new()),
+            Arc::new(settings_repository),
+            CurrencyConverter::new(Arc::new(exchange_rate_repository)),
+            DonaMsgPolicy::default(),
+            Arc::new(event_bus),
+        ))
+    }
+
+    fn limited_settings(
+        dona: &Dona,
+        min_amount: Option<Decimal>,
+        max_amount: Option<Decimal>,
+        currency: &str,
+    ) -> RecipientSettings {
+        RecipientSettingsMother::create(
+            Some(dona.user_id()),
+            min_amount,
+            max_amount,
+            Some(currency),
+            vec!["MANUAL", "PAYPAL", "CRYPTO"],
+            true,
+        )
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_amount_is_outside_recipient_limits() {
+        let dona = pending_dona();
+
+        for (min_amount, max_amount, error) in [
+            (
+                Some(dec!(30.00)),
+                None,
+                ERR_DONA_AMOUNT_BELOW_RECIPIENT_MINIMUM,
+            ),
+            (
+                Some(dec!(1.00)),
+                Some(dec!(20.00)),
+                ERR_DONA_AMOUNT_ABOVE_RECIPIENT_MAXIMUM,
+            ),
+        ] {
+            let settings = limited_settings(&dona, min_amount, max_amount, "USD");
+
+            let result = settings_handler(&dona, settings, MockExchangeRateRepository::new(), 0)
+                .handle(Box::new(command_from(&dona)))
+                .await;
+
+            assert_eq!(result, Err(CommandError::new(error.to_string())));
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_convert_amount_to_the_currency_of_recipient_limits() {
+        let dona = pending_dona();
+
+        for (min_amount, saves) in [(dec!(20.00), 1), (dec!(25.00), 0)] {
+            let settings = limited_settings(&dona, Some(min_amount), None, "EUR");
+
+            let mut exchange_rate_repository = MockExchangeRateRepository::new();
+            exchange_rate_repository
+                .expect_find_effective()
+                .times(1)
+                .return_const(Ok(ExchangeRateMother::create(
+                    None,
+                    Some("USD".to_string()),
+                    Some("EUR".to_string()),
+                    Some(dec!(0.90)),
+                    None,
+                )));
+
+            // 25.50 USD are 22.95 EUR.
+            let result = settings_handler(&dona, settings, exchange_rate_repository, saves)
+                .handle(Box::new(command_from(&dona)))
+                .await;
+
+            if saves == 1 {
+                assert!(result.is_ok(), "Result should be Ok");
+            } else {
+                assert_eq!(
+                    result,
+                    Err(CommandError::new(
+                        ERR_DONA_AMOUNT_BELOW_RECIPIENT_MINIMUM.to_string()
+                    ))
+                );
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_method_is_not_active() {
+        let dona = pending_dona();
+        let active_methods = ["MANUAL", "PAYPAL", "CRYPTO"]
+            .into_iter()
+            .filter(|method| *method != dona.method())
+            .collect();
+        let settings = RecipientSettingsMother::create(
+            Some(dona.user_id()),
+            None,
+            None,
+            None,
+            active_methods,
+            true,
+        );
+
+        let result = settings_handler(&dona, settings, MockExchangeRateRepository::new(), 0)
+            .handle(Box::new(command_from(&dona)))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(
+                ERR_RECIPIENT_METHOD_NOT_ACTIVE.to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_withhold_msg_when_recipient_does_not_take_messages() {
+        let dona = pending_dona();
+        let settings = RecipientSettingsMother::create(
+            Some(dona.user_id()),
+            None,
+            None,
+            None,
+            vec!["MANUAL", "PAYPAL", "CRYPTO"],
+            false,
+        );
+
+        let method = UserPaymentMethodMother::create(
+            None,
+            Some(dona.user_id()),
+            Some(dona.method()),
+            None,
+            Some(vec![dona.currency()]),
+            None,
+            None,
+        );
+
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        repository
+            .expect_save()
+            .withf(|saved| saved.msg() == REDACTED_DONA_MSG && saved.msg_status() == "redacted")
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut method_repository = MockUserPaymentMethodRepository::new();
+        method_repository
+            .expect_find_by_criteria()
+            .times(1)
+            .return_const(Ok(vec![method]));
+
+        let mut settings_repository = MockRecipientSettingsRepository::new();
+        settings_repository
+            .expect_find_by_user_id()
+            .times(1)
+            .return_const(Ok(settings));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus
+            .expect_publish()
+            .withf(|events| created_event_has(events, REDACTED_DONA_MSG, "redacted"))
+            .times(1)
+            .return_const(Ok(()));
+
+        let handler = CreateDonaCommandHandler::new(DonaCreator::new(
+            Arc::new(repository),
+            Arc::new(method_repository),
+            Arc::new(MockCampaignRepository::new()),
+            Arc::new(settings_repository),
+            converter(),
+            DonaMsgPolicy::new(500, vec![], DonaMsgLinkPolicy::Reject),
+            Arc::new(event_bus),
+        ));
+
+        // The message is dropped before moderation, so its link does not matter.
+        let result = handler
+            .handle(moderation_command(&dona, "Check https://example.com"))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+}
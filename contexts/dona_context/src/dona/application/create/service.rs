@@ -0,0 +1,289 @@
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use shared::domain::{
+    base_errors::BaseRepositoryError,
+    bus::event::EventBus,
+    criteria::{
+        cursor::{Cursor, FirstField},
+        filter::{Filter, FilterField, FilterOperator, FilterValue},
+        Criteria,
+    },
+    value_objects::{money::Currency, user_id::UserId},
+};
+use time::OffsetDateTime;
+
+use crate::{
+    campaign::domain::{
+        campaign::{CampaignId, ERR_CAMPAIGN_NOT_FOUND},
+        campaign_repository::CampaignRepository,
+    },
+    dona::domain::{
+        dona::{Dona, DonaId, DonaMsgModeration, DonaStatus, NewDona},
+        dona_msg_policy::DonaMsgPolicy,
+        dona_repository::DonaRepository,
+    },
+    exchange_rate::application::convert::service::CurrencyConverter,
+    recipient::domain::{
+        recipient_settings::RecipientSettings,
+        recipient_settings_repository::RecipientSettingsRepository,
+    },
+    shared::domain::dona::DonaOptionMethod,
+    user_payment_method::domain::{
+        user_payment_method::UserPaymentMethod,
+        user_payment_method_repository::UserPaymentMethodRepository,
+    },
+};
+
+pub const ERR_DONA_ALREADY_EXISTS: &str = "Dona already exists";
+pub const ERR_RECIPIENT_PAYMENT_METHOD_NOT_FOUND: &str =
+    "The recipient does not accept this payment method";
+pub const ERR_RECIPIENT_CURRENCY_NOT_ACCEPTED: &str =
+    "The recipient does not accept this currency for the payment method";
+pub const ERR_CAMPAIGN_NOT_ACCEPTING_DONAS: &str = "The campaign is no longer accepting donas";
+pub const ERR_CAMPAIGN_CURRENCY_MISMATCH: &str =
+    "The dona currency does not match the campaign currency";
+pub const ERR_DONA_TX_HASH_REQUIRED: &str =
+    "Crypto donas need the hash of the transaction that paid them";
+pub const ERR_DONA_TX_HASH_NETWORK_MISMATCH: &str =
+    "The transaction hash is not valid on the network of the recipient wallet";
+
+/// Checks that the recipient has set up `method` and takes `currency` through it.
+pub(crate) async fn recipient_accepts_method(
+    user_payment_method_repository: &dyn UserPaymentMethodRepository,
+    user_id: String,
+    method: String,
+    currency: String,
+) -> Result<UserPaymentMethod, String> {
+    let user_id = UserId::new(user_id)?;
+    let method = DonaOptionMethod::new(method)?;
+    let currency = Currency::new(currency)?;
+
+    let methods = user_payment_method_repository
+        .find_by_criteria(Criteria::new(
+            vec![
+                Filter::new(
+                    FilterField::try_from("user_id".to_string()).unwrap(),
+                    FilterOperator::Equal,
+                    FilterValue::try_from(user_id.to_string())?,
+                ),
+                Filter::new(
+                    FilterField::try_from("payment_method".to_string()).unwrap(),
+                    FilterOperator::Equal,
+                    FilterValue::try_from(method.to_string())?,
+                ),
+            ],
+            None,
+            Some(Cursor::new(
+                None,
+                None,
+                Some(FirstField::new(1).unwrap()),
+                None,
+            )),
+        ))
+        .await?;
+
+    let method = methods
+        .into_iter()
+        .next()
+        .ok_or(ERR_RECIPIENT_PAYMENT_METHOD_NOT_FOUND.to_string())?;
+
+    if !method.accepts_currency(&currency) {
+        return Err(ERR_RECIPIENT_CURRENCY_NOT_ACCEPTED.to_string());
+    }
+
+    Ok(method)
+}
+
+/// Empty when the recipient never saved settings, so the defaults apply.
+pub(crate) async fn recipient_settings(
+    recipient_settings_repository: &dyn RecipientSettingsRepository,
+    user_id: &str,
+) -> Result<Option<RecipientSettings>, String> {
+    match recipient_settings_repository
+        .find_by_user_id(UserId::new(user_id.to_string())?)
+        .await
+    {
+        Ok(settings) => Ok(Some(settings)),
+        Err(BaseRepositoryError::NotFound) => Ok(None),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Checks the dona against the active methods and limits of the recipient. Limits in another
+/// currency are compared with the rate of the day the dona is created.
+pub(crate) async fn recipient_accepts_dona(
+    converter: &CurrencyConverter,
+    settings: &RecipientSettings,
+    method: &str,
+    amount: Decimal,
+    currency: &str,
+    created_at: OffsetDateTime,
+) -> Result<(), String> {
+    settings.check_method(&DonaOptionMethod::new(method.to_string())?)?;
+
+    if let Some(limits) = settings.limits() {
+        let amount = converter
+            .convert(
+                amount,
+                &Currency::new(currency.to_string())?,
+                limits.currency(),
+                created_at,
+            )
+            .await?;
+        limits.check(amount)?;
+    }
+
+    Ok(())
+}
+
+/// The donor pays a crypto dona before creating it, so it must come with the hash of a
+/// transaction that could have been sent to the recipient wallet.
+fn check_tx_hash(method: &UserPaymentMethod, tx_hash: Option<&str>) -> Result<(), String> {
+    let Some(wallet) = method.crypto_wallet() else {
+        return Ok(());
+    };
+    let tx_hash = tx_hash.ok_or(ERR_DONA_TX_HASH_REQUIRED.to_string())?;
+
+    if wallet.network().is_valid_tx_hash(tx_hash.trim()) {
+        Ok(())
+    } else {
+        Err(ERR_DONA_TX_HASH_NETWORK_MISMATCH.to_string())
+    }
+}
+
+#[derive(Clone)]
+pub struct DonaCreator {
+    repository: Arc<dyn DonaRepository>,
+    user_payment_method_repository: Arc<dyn UserPaymentMethodRepository>,
+    campaign_repository: Arc<dyn CampaignRepository>,
+    recipient_settings_repository: Arc<dyn RecipientSettingsRepository>,
+    converter: CurrencyConverter,
+    msg_policy: DonaMsgPolicy,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl DonaCreator {
+    pub fn new(
+        repository: Arc<dyn DonaRepository>,
+        user_payment_method_repository: Arc<dyn UserPaymentMethodRepository>,
+        campaign_repository: Arc<dyn CampaignRepository>,
+        recipient_settings_repository: Arc<dyn RecipientSettingsRepository>,
+        converter: CurrencyConverter,
+        msg_policy: DonaMsgPolicy,
+        event_bus: Arc<dyn EventBus>,
+    ) -> Self {
+        Self {
+            repository,
+            user_payment_method_repository,
+            campaign_repository,
+            recipient_settings_repository,
+            converter,
+            msg_policy,
+            event_bus,
+        }
+    }
+
+    async fn dona_exists(&self, id: String) -> Result<(), String> {
+        let dona = self.repository.find_by_id(DonaId::new(id)?).await;
+
+        match dona {
+            Ok(_) => Err(ERR_DONA_ALREADY_EXISTS.to_string()),
+            Err(BaseRepositoryError::NotFound) => Ok(()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// A campaign only takes donas made to its owner, in its currency, while it is still open.
+    async fn campaign_accepts_dona(
+        &self,
+        campaign_id: String,
+        user_id: &str,
+        currency: String,
+        created_at: OffsetDateTime,
+    ) -> Result<(), String> {
+        let campaign = self
+            .campaign_repository
+            .find_by_id(CampaignId::new(campaign_id)?)
+            .await
+            .map_err(|e| match e {
+                BaseRepositoryError::NotFound => ERR_CAMPAIGN_NOT_FOUND.to_string(),
+                _ => e.to_string(),
+            })?;
+
+        if campaign.user_id() != user_id {
+            return Err(ERR_CAMPAIGN_NOT_FOUND.to_string());
+        }
+        if !campaign.accepts_donas(created_at) {
+            return Err(ERR_CAMPAIGN_NOT_ACCEPTING_DONAS.to_string());
+        }
+        if !campaign.accepts_currency(&Currency::new(currency)?) {
+            return Err(ERR_CAMPAIGN_CURRENCY_MISMATCH.to_string());
+        }
+
+        Ok(())
+    }
+
+    /// The dona always starts pending and outside any pledge, whatever `new_dona` says.
+    pub async fn execute(&self, new_dona: NewDona) -> Result<(), String> {
+        let settings = recipient_settings(
+            self.recipient_settings_repository.as_ref(),
+            &new_dona.user_id,
+        )
+        .await?;
+        let messages_allowed = settings
+            .as_ref()
+            .is_none_or(|settings| settings.messages_allowed());
+        let (msg, msg_moderation) = if messages_allowed {
+            let (msg, flags) = self.msg_policy.moderate(&new_dona.msg)?;
+            (msg, DonaMsgModeration::Checked(flags))
+        } else {
+            (new_dona.msg.clone(), DonaMsgModeration::Withheld)
+        };
+        self.dona_exists(new_dona.id.clone()).await?;
+        if let Some(settings) = &settings {
+            recipient_accepts_dona(
+                &self.converter,
+                settings,
+                &new_dona.method,
+                new_dona.amount,
+                &new_dona.currency,
+                new_dona.created_at,
+            )
+            .await?;
+        }
+        let user_payment_method = recipient_accepts_method(
+            self.user_payment_method_repository.as_ref(),
+            new_dona.user_id.clone(),
+            new_dona.method.clone(),
+            new_dona.currency.clone(),
+        )
+        .await?;
+        check_tx_hash(&user_payment_method, new_dona.tx_hash.as_deref())?;
+        if let Some(campaign_id) = new_dona.campaign_id.clone() {
+            self.campaign_accepts_dona(
+                campaign_id,
+                &new_dona.user_id,
+                new_dona.currency.clone(),
+                new_dona.created_at,
+            )
+            .await?;
+        }
+
+        let mut dona = Dona::create(
+            NewDona {
+                msg,
+                status: DonaStatus::Pending.to_string(),
+                pledge_id: None,
+                ..new_dona
+            },
+            msg_moderation,
+        )?;
+
+        self.repository.save(&dona).await?;
+
+        self.event_bus.publish(dona.pull_events()).await?;
+
+        Ok(())
+    }
+}
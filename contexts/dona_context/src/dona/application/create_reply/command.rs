@@ -0,0 +1,195 @@
+use shared::domain::bus::command::{Command, CommandError, CommandHandler};
+use time::OffsetDateTime;
+
+use super::service::DonaReplyCreator;
+
+pub const CREATE_DONA_REPLY_COMMAND_TYPE: &str = "dona.create_dona_reply.command";
+
+#[derive(Debug)]
+pub struct CreateDonaReplyCommand {
+    pub id: String,
+    pub dona_id: String,
+    pub user_id: String,
+    pub msg: String,
+    /// `PRIVATE` or `PUBLIC`.
+    pub visibility: String,
+    pub created_at: OffsetDateTime,
+}
+
+impl Command for CreateDonaReplyCommand {
+    fn command_type(&self) -> &'static str {
+        CREATE_DONA_REPLY_COMMAND_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct CreateDonaReplyCommandHandler {
+    service: DonaReplyCreator,
+}
+
+impl CreateDonaReplyCommandHandler {
+    pub fn new(service: DonaReplyCreator) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler for CreateDonaReplyCommandHandler {
+    async fn handle(&self, command: Box<dyn Command>) -> Result<(), CommandError> {
+        let command = command
+            .as_any()
+            .downcast_ref::<CreateDonaReplyCommand>()
+            .ok_or_else(|| CommandError::new("Invalid command".to_string()))?;
+
+        self.service
+            .execute(
+                command.id.to_owned(),
+                command.dona_id.to_owned(),
+                command.user_id.to_owned(),
+                command.msg.to_owned(),
+                command.visibility.to_owned(),
+                command.created_at,
+            )
+            .await
+            .map_err(CommandError::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use shared::domain::{bus::event::tests::MockEventBus, utils::new_uuid};
+    use time::Duration;
+
+    use super::*;
+
+    use crate::dona::domain::dona::tests::DonaMother;
+    use crate::dona::domain::dona::{Dona, ERR_DONA_NOT_FOUND};
+    use crate::dona::domain::dona_reply::tests::DonaReplyMother;
+    use crate::dona::domain::dona_reply::{
+        DonaReply, ERR_DONA_NOT_ACCEPTING_REPLIES, ERR_DONA_REPLY_ALREADY_EXISTS,
+    };
+    use crate::dona::domain::dona_reply_repository::tests::MockDonaReplyRepository;
+    use crate::dona::domain::dona_repository::tests::MockDonaRepository;
+
+    fn dona(status: &str) -> Dona {
+        DonaMother::create(
+            None,
+            None,
+            None,
+            None,
+            Some(status.to_string()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn command_from(dona: &Dona) -> Box<CreateDonaReplyCommand> {
+        Box::new(CreateDonaReplyCommand {
+            id: new_uuid(),
+            dona_id: dona.id(),
+            user_id: dona.user_id(),
+            msg: "Thank you so much!".to_string(),
+            visibility: "PUBLIC".to_string(),
+            created_at: OffsetDateTime::now_utc() - Duration::seconds(1),
+        })
+    }
+
+    fn handler(
+        found: Vec<Dona>,
+        replies: Vec<DonaReply>,
+        saves: usize,
+    ) -> CreateDonaReplyCommandHandler {
+        let mut dona_repository = MockDonaRepository::new();
+        dona_repository
+            .expect_find_by_criteria()
+            .return_const(Ok(found));
+
+        let mut reply_repository = MockDonaReplyRepository::new();
+        reply_repository
+            .expect_find_by_dona_ids()
+            .return_const(Ok(replies));
+        reply_repository
+            .expect_save()
+            .withf(|reply| reply.visibility() == "PUBLIC")
+            .times(saves)
+            .return_const(Ok(()));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus
+            .expect_publish()
+            .withf(|events| events.len() == 1)
+            .times(saves)
+            .return_const(Ok(()));
+
+        CreateDonaReplyCommandHandler::new(DonaReplyCreator::new(
+            Arc::new(dona_repository),
+            Arc::new(reply_repository),
+            Arc::new(event_bus),
+        ))
+    }
+
+    #[tokio::test]
+    async fn it_should_reply_to_a_confirmed_dona() {
+        let dona = dona("confirmed");
+
+        let result = handler(vec![dona.clone()], vec![], 1)
+            .handle(command_from(&dona))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_dona_is_not_of_the_user() {
+        let dona = dona("confirmed");
+
+        let result = handler(vec![], vec![], 0).handle(command_from(&dona)).await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_DONA_NOT_FOUND.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_dona_is_not_confirmed() {
+        let dona = dona("pending");
+
+        let result = handler(vec![dona.clone()], vec![], 0)
+            .handle(command_from(&dona))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(
+                ERR_DONA_NOT_ACCEPTING_REPLIES.to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_dona_already_has_a_reply() {
+        let dona = dona("confirmed");
+        let reply = DonaReplyMother::create(Some(dona.id()), Some(dona.user_id()), None);
+
+        let result = handler(vec![dona.clone()], vec![reply], 0)
+            .handle(command_from(&dona))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_DONA_REPLY_ALREADY_EXISTS.to_string()))
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use shared::domain::{
+    bus::event::EventBus,
+    criteria::{
+        filter::{Filter, FilterField, FilterOperator, FilterValue},
+        Criteria,
+    },
+};
+use time::OffsetDateTime;
+
+use crate::dona::domain::{
+    dona::{Dona, DonaId, ERR_DONA_NOT_FOUND},
+    dona_reply::{DonaReply, ERR_DONA_REPLY_ALREADY_EXISTS},
+    dona_reply_repository::DonaReplyRepository,
+    dona_repository::DonaRepository,
+};
+
+#[derive(Clone)]
+pub struct DonaReplyCreator {
+    dona_repository: Arc<dyn DonaRepository>,
+    reply_repository: Arc<dyn DonaReplyRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl DonaReplyCreator {
+    pub fn new(
+        dona_repository: Arc<dyn DonaRepository>,
+        reply_repository: Arc<dyn DonaReplyRepository>,
+        event_bus: Arc<dyn EventBus>,
+    ) -> Self {
+        Self {
+            dona_repository,
+            reply_repository,
+            event_bus,
+        }
+    }
+
+    async fn dona_finder(&self, dona_id: String, user_id: String) -> Result<Dona, String> {
+        let dona_id = DonaId::new(dona_id)?;
+
+        self.dona_repository
+            .find_by_criteria(Criteria::new(
+                vec![
+                    Filter::new(
+                        FilterField::try_from("id".to_string()).unwrap(),
+                        FilterOperator::Equal,
+                        FilterValue::try_from(dona_id.to_string())?,
+                    ),
+                    Filter::new(
+                        FilterField::try_from("user_id".to_string()).unwrap(),
+                        FilterOperator::Equal,
+                        FilterValue::try_from(user_id)?,
+                    ),
+                ],
+                None,
+                None,
+            ))
+            .await?
+            .pop()
+            .ok_or_else(|| ERR_DONA_NOT_FOUND.to_string())
+    }
+
+    /// `user_id` is the recipient of the dona, admins reply on their behalf.
+    pub async fn execute(
+        &self,
+        id: String,
+        dona_id: String,
+        user_id: String,
+        msg: String,
+        visibility: String,
+        created_at: OffsetDateTime,
+    ) -> Result<(), String> {
+        let dona = self.dona_finder(dona_id, user_id).await?;
+
+        let replies = self
+            .reply_repository
+            .find_by_dona_ids(vec![DonaId::new(dona.id())?])
+            .await?;
+        if !replies.is_empty() {
+            return Err(ERR_DONA_REPLY_ALREADY_EXISTS.to_string());
+        }
+
+        let mut reply = DonaReply::create(id, &dona, msg, visibility, created_at)?;
+
+        self.reply_repository.save(&reply).await?;
+
+        self.event_bus.publish(reply.pull_events()).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+use shared::domain::bus::command::{Command, CommandError, CommandHandler};
+use time::OffsetDateTime;
+
+use super::service::DonaDeleter;
+
+pub const DELETE_DONA_COMMAND_TYPE: &str = "dona.delete_dona.command";
+
+#[derive(Debug)]
+pub struct DeleteDonaCommand {
+    pub id: String,
+    pub deleted_at: OffsetDateTime,
+}
+
+impl Command for DeleteDonaCommand {
+    fn command_type(&self) -> &'static str {
+        DELETE_DONA_COMMAND_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct DeleteDonaCommandHandler {
+    service: DonaDeleter,
+}
+
+impl DeleteDonaCommandHandler {
+    pub fn new(service: DonaDeleter) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler for DeleteDonaCommandHandler {
+    async fn handle(&self, command: Box<dyn Command>) -> Result<(), CommandError> {
+        let command = command
+            .as_any()
+            .downcast_ref::<DeleteDonaCommand>()
+            .ok_or_else(|| CommandError::new("Invalid command".to_string()))?;
+
+        self.service
+            .execute(command.id.to_owned(), command.deleted_at)
+            .await
+            .map_err(CommandError::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockall::predicate;
+    use shared::domain::{base_errors::BaseRepositoryError, bus::event::tests::MockEventBus};
+    use time::Duration;
+
+    use super::*;
+
+    use crate::dona::domain::dona::tests::DonaMother;
+    use crate::dona::domain::dona::{Dona, DonaId, ERR_DONA_NOT_FOUND};
+    use crate::dona::domain::dona_deleted_event::{DonaDeletedEvent, DONA_DELETED_EVENT_TYPE};
+    use crate::dona::domain::dona_repository::tests::MockDonaRepository;
+
+    fn command_from(dona: &Dona) -> DeleteDonaCommand {
+        DeleteDonaCommand {
+            id: dona.id(),
+            deleted_at: OffsetDateTime::now_utc() - Duration::seconds(1),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_dona_not_found() {
+        let dona = DonaMother::random();
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        repository.expect_delete().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let service = DonaDeleter::new(Arc::new(repository), Arc::new(event_bus));
+        let handler = DeleteDonaCommandHandler::new(service);
+
+        let result = handler.handle(Box::new(command_from(&dona))).await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_DONA_NOT_FOUND.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_delete_dona_and_publish_it() {
+        let dona = DonaMother::random();
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(dona.clone()));
+        repository
+            .expect_delete()
+            .with(predicate::eq(DonaId::new(dona.id()).unwrap()))
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut event_bus = MockEventBus::new();
+        let expected_dona = dona.clone();
+        event_bus
+            .expect_publish()
+            .withf(move |events| {
+                events.len() == 1
+                    && events[0].event_type() == DONA_DELETED_EVENT_TYPE
+                    && events[0]
+                        .as_any()
+                        .downcast_ref::<DonaDeletedEvent>()
+                        .is_some_and(|event| {
+                            event.dona_id() == expected_dona.id()
+                                && event.user_id() == expected_dona.user_id()
+                        })
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let service = DonaDeleter::new(Arc::new(repository), Arc::new(event_bus));
+        let handler = DeleteDonaCommandHandler::new(service);
+
+        let result = handler.handle(Box::new(command_from(&dona))).await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+}
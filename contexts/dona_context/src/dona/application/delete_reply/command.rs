@@ -0,0 +1,143 @@
+use shared::domain::bus::command::{Command, CommandError, CommandHandler};
+use time::OffsetDateTime;
+
+use super::service::DonaReplyDeleter;
+
+pub const DELETE_DONA_REPLY_COMMAND_TYPE: &str = "dona.delete_dona_reply.command";
+
+#[derive(Debug)]
+pub struct DeleteDonaReplyCommand {
+    pub id: String,
+    pub user_id: String,
+    pub deleted_at: OffsetDateTime,
+}
+
+impl Command for DeleteDonaReplyCommand {
+    fn command_type(&self) -> &'static str {
+        DELETE_DONA_REPLY_COMMAND_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct DeleteDonaReplyCommandHandler {
+    service: DonaReplyDeleter,
+}
+
+impl DeleteDonaReplyCommandHandler {
+    pub fn new(service: DonaReplyDeleter) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler for DeleteDonaReplyCommandHandler {
+    async fn handle(&self, command: Box<dyn Command>) -> Result<(), CommandError> {
+        let command = command
+            .as_any()
+            .downcast_ref::<DeleteDonaReplyCommand>()
+            .ok_or_else(|| CommandError::new("Invalid command".to_string()))?;
+
+        self.service
+            .execute(
+                command.id.to_owned(),
+                command.user_id.to_owned(),
+                command.deleted_at,
+            )
+            .await
+            .map_err(CommandError::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockall::predicate;
+    use shared::domain::{
+        base_errors::BaseRepositoryError, bus::event::tests::MockEventBus, utils::new_uuid,
+    };
+    use time::Duration;
+
+    use super::*;
+
+    use crate::dona::domain::dona_reply::tests::{DonaReplyIdMother, DonaReplyMother};
+    use crate::dona::domain::dona_reply::{DonaReply, ERR_DONA_REPLY_NOT_FOUND};
+    use crate::dona::domain::dona_reply_repository::tests::MockDonaReplyRepository;
+
+    fn command_from(reply: &DonaReply, user_id: String) -> Box<DeleteDonaReplyCommand> {
+        Box::new(DeleteDonaReplyCommand {
+            id: reply.id(),
+            user_id,
+            deleted_at: OffsetDateTime::now_utc() - Duration::seconds(1),
+        })
+    }
+
+    fn handler(
+        reply: &DonaReply,
+        found: Result<DonaReply, BaseRepositoryError>,
+        deletes: usize,
+    ) -> DeleteDonaReplyCommandHandler {
+        let mut repository = MockDonaReplyRepository::new();
+        repository.expect_find_by_id().return_const(found);
+        repository
+            .expect_delete()
+            .with(predicate::eq(DonaReplyIdMother::create(Some(reply.id()))))
+            .times(deletes)
+            .return_const(Ok(()));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus
+            .expect_publish()
+            .withf(|events| events.len() == 1)
+            .times(deletes)
+            .return_const(Ok(()));
+
+        DeleteDonaReplyCommandHandler::new(DonaReplyDeleter::new(
+            Arc::new(repository),
+            Arc::new(event_bus),
+        ))
+    }
+
+    #[tokio::test]
+    async fn it_should_delete_the_reply() {
+        let reply = DonaReplyMother::random();
+
+        let result = handler(&reply, Ok(reply.clone()), 1)
+            .handle(command_from(&reply, reply.user_id()))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_reply_is_not_of_the_user() {
+        let reply = DonaReplyMother::random();
+
+        let result = handler(&reply, Ok(reply.clone()), 0)
+            .handle(command_from(&reply, new_uuid()))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_DONA_REPLY_NOT_FOUND.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_reply_does_not_exist() {
+        let reply = DonaReplyMother::random();
+
+        let result = handler(&reply, Err(BaseRepositoryError::NotFound), 0)
+            .handle(command_from(&reply, reply.user_id()))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_DONA_REPLY_NOT_FOUND.to_string()))
+        );
+    }
+}
@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use shared::domain::{base_errors::BaseRepositoryError, bus::event::EventBus};
+use time::OffsetDateTime;
+
+use crate::dona::domain::{
+    dona_reply::{DonaReplyId, ERR_DONA_REPLY_NOT_FOUND},
+    dona_reply_repository::DonaReplyRepository,
+};
+
+#[derive(Clone)]
+pub struct DonaReplyDeleter {
+    repository: Arc<dyn DonaReplyRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl DonaReplyDeleter {
+    pub fn new(repository: Arc<dyn DonaReplyRepository>, event_bus: Arc<dyn EventBus>) -> Self {
+        Self {
+            repository,
+            event_bus,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        id: String,
+        user_id: String,
+        deleted_at: OffsetDateTime,
+    ) -> Result<(), String> {
+        let mut reply = self
+            .repository
+            .find_by_id(DonaReplyId::new(id)?)
+            .await
+            .map_err(|e| match e {
+                BaseRepositoryError::NotFound => ERR_DONA_REPLY_NOT_FOUND.to_string(),
+                e => e.to_string(),
+            })?;
+        if !reply.belongs_to(&user_id) {
+            return Err(ERR_DONA_REPLY_NOT_FOUND.to_string());
+        }
+        reply.delete(deleted_at)?;
+
+        self.repository
+            .delete(DonaReplyId::new(reply.id())?)
+            .await?;
+
+        self.event_bus.publish(reply.pull_events()).await?;
+
+        Ok(())
+    }
+}
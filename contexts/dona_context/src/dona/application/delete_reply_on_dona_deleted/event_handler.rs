@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use shared::domain::bus::event::{Event, EventError, EventHandler};
+
+use crate::dona::domain::dona_deleted_event::{DonaDeletedEvent, DONA_DELETED_EVENT_TYPE};
+
+use super::service::DonaReplyRemover;
+
+#[derive(Clone)]
+pub struct DeleteDonaReplyOnDonaDeleted {
+    service: DonaReplyRemover,
+}
+
+impl DeleteDonaReplyOnDonaDeleted {
+    pub fn new(service: DonaReplyRemover) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl EventHandler for DeleteDonaReplyOnDonaDeleted {
+    async fn handle(&self, event: Arc<dyn Event>) -> Result<(), EventError> {
+        let event = event
+            .as_any()
+            .downcast_ref::<DonaDeletedEvent>()
+            .ok_or_else(|| EventError::new("Invalid event".to_string()))?;
+
+        self.service
+            .execute(event.dona_id().to_string())
+            .await
+            .map_err(EventError::new)
+    }
+
+    fn subscribed_to(&self) -> Vec<&'static str> {
+        vec![DONA_DELETED_EVENT_TYPE]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate;
+    use shared::domain::{base_errors::BaseRepositoryError, utils::new_uuid};
+
+    use super::*;
+
+    use crate::dona::domain::dona::DonaId;
+    use crate::dona::domain::dona_reply_repository::tests::MockDonaReplyRepository;
+
+    fn event(dona_id: String) -> Arc<dyn Event> {
+        Arc::new(DonaDeletedEvent::new(
+            dona_id,
+            new_uuid(),
+            "2024-04-14T10:00:00Z".to_string(),
+        ))
+    }
+
+    #[tokio::test]
+    async fn it_should_delete_the_reply_of_the_dona() {
+        let dona_id = new_uuid();
+
+        let mut repository = MockDonaReplyRepository::new();
+        repository
+            .expect_delete_by_dona_id()
+            .with(predicate::eq(DonaId::new(dona_id.clone()).unwrap()))
+            .times(1)
+            .return_const(Ok(()));
+
+        let handler =
+            DeleteDonaReplyOnDonaDeleted::new(DonaReplyRemover::new(Arc::new(repository)));
+
+        let result = handler.handle(event(dona_id)).await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_repository_fails() {
+        let mut repository = MockDonaReplyRepository::new();
+        repository
+            .expect_delete_by_dona_id()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::ConnectionError));
+
+        let handler =
+            DeleteDonaReplyOnDonaDeleted::new(DonaReplyRemover::new(Arc::new(repository)));
+
+        let result = handler.handle(event(new_uuid())).await;
+
+        assert!(result.is_err(), "Result should be Err");
+    }
+}
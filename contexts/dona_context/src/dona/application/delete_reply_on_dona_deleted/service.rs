@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use crate::dona::domain::{dona::DonaId, dona_reply_repository::DonaReplyRepository};
+
+#[derive(Clone)]
+pub struct DonaReplyRemover {
+    repository: Arc<dyn DonaReplyRepository>,
+}
+
+impl DonaReplyRemover {
+    pub fn new(repository: Arc<dyn DonaReplyRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Deleting nothing is not an error, so delivering the event again is harmless.
+    pub async fn execute(&self, dona_id: String) -> Result<(), String> {
+        self.repository
+            .delete_by_dona_id(DonaId::new(dona_id)?)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use shared::domain::{base_errors::BaseRepositoryError, bus::event::EventBus};
+use time::OffsetDateTime;
+
+use crate::dona::domain::{
+    dona::{DonaId, ERR_DONA_NOT_FOUND},
+    dona_repository::DonaRepository,
+};
+
+#[derive(Clone)]
+pub struct DonaExpirer {
+    repository: Arc<dyn DonaRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl DonaExpirer {
+    pub fn new(repository: Arc<dyn DonaRepository>, event_bus: Arc<dyn EventBus>) -> Self {
+        Self {
+            repository,
+            event_bus,
+        }
+    }
+
+    pub async fn execute(&self, id: String, updated_at: OffsetDateTime) -> Result<(), String> {
+        let mut dona = self
+            .repository
+            .find_by_id(DonaId::new(id)?)
+            .await
+            .map_err(|e| match e {
+                BaseRepositoryError::NotFound => ERR_DONA_NOT_FOUND.to_string(),
+                e => e.to_string(),
+            })?;
+        dona.expire(updated_at)?;
+
+        self.repository.save(&dona).await?;
+
+        self.event_bus.publish(dona.pull_events()).await?;
+
+        Ok(())
+    }
+}
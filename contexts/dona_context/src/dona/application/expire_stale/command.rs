@@ -0,0 +1,160 @@
+use shared::domain::bus::command::{Command, CommandError, CommandHandler};
+use time::OffsetDateTime;
+
+use super::service::StaleDonasExpirer;
+
+pub const EXPIRE_STALE_DONAS_COMMAND_TYPE: &str = "dona.expire_stale_donas.command";
+
+#[derive(Debug)]
+pub struct ExpireStaleDonasCommand {
+    pub older_than: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl Command for ExpireStaleDonasCommand {
+    fn command_type(&self) -> &'static str {
+        EXPIRE_STALE_DONAS_COMMAND_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct ExpireStaleDonasCommandHandler {
+    service: StaleDonasExpirer,
+}
+
+impl ExpireStaleDonasCommandHandler {
+    pub fn new(service: StaleDonasExpirer) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler for ExpireStaleDonasCommandHandler {
+    async fn handle(&self, command: Box<dyn Command>) -> Result<(), CommandError> {
+        let command = command
+            .as_any()
+            .downcast_ref::<ExpireStaleDonasCommand>()
+            .ok_or_else(|| CommandError::new("Invalid command".to_string()))?;
+
+        self.service
+            .execute(command.older_than, command.updated_at)
+            .await
+            .map_err(CommandError::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use rust_decimal_macros::dec;
+    use shared::domain::{base_errors::BaseRepositoryError, bus::event::tests::MockEventBus};
+    use time::Duration;
+
+    use super::*;
+
+    use crate::dona::domain::dona::tests::DonaMother;
+    use crate::dona::domain::dona::Dona;
+    use crate::dona::domain::dona_repository::tests::MockDonaRepository;
+
+    fn pending_dona() -> Dona {
+        DonaMother::create(
+            None,
+            None,
+            Some(dec!(3.00)),
+            None,
+            Some("pending".to_string()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn command() -> ExpireStaleDonasCommand {
+        let now = OffsetDateTime::now_utc() - Duration::seconds(1);
+
+        ExpireStaleDonasCommand {
+            older_than: now - Duration::days(7),
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_repository_fails() {
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_find_by_criteria()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::UnexpectedError(
+                "error".to_string(),
+            )));
+        repository.expect_save().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let service = StaleDonasExpirer::new(Arc::new(repository), Arc::new(event_bus));
+        let handler = ExpireStaleDonasCommandHandler::new(service);
+
+        let result = handler.handle(Box::new(command())).await;
+
+        assert!(result.is_err(), "Result should be an error");
+    }
+
+    #[tokio::test]
+    async fn it_should_do_nothing_when_no_donas_are_stale() {
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_find_by_criteria()
+            .times(1)
+            .return_const(Ok(vec![]));
+        repository.expect_save().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let service = StaleDonasExpirer::new(Arc::new(repository), Arc::new(event_bus));
+        let handler = ExpireStaleDonasCommandHandler::new(service);
+
+        let result = handler.handle(Box::new(command())).await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_expire_stale_donas() {
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_find_by_criteria()
+            .withf(|criteria| criteria.filters().len() == 2)
+            .times(1)
+            .return_const(Ok(vec![pending_dona(), pending_dona()]));
+        repository
+            .expect_save()
+            .withf(|dona| dona.status() == "expired")
+            .times(2)
+            .return_const(Ok(()));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus
+            .expect_publish()
+            .withf(|events| events.len() == 1)
+            .times(2)
+            .return_const(Ok(()));
+
+        let service = StaleDonasExpirer::new(Arc::new(repository), Arc::new(event_bus));
+        let handler = ExpireStaleDonasCommandHandler::new(service);
+
+        let result = handler.handle(Box::new(command())).await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+}
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use shared::domain::{
+    bus::event::EventBus,
+    criteria::{
+        cursor::{Cursor, FirstField},
+        filter::{Filter, FilterField, FilterOperator, FilterValue},
+        Criteria,
+    },
+};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+use crate::dona::domain::{dona::DonaStatus, dona_repository::DonaRepository};
+
+pub const STALE_DONAS_BATCH_SIZE: usize = 100;
+
+#[derive(Clone)]
+pub struct StaleDonasExpirer {
+    repository: Arc<dyn DonaRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl StaleDonasExpirer {
+    pub fn new(repository: Arc<dyn DonaRepository>, event_bus: Arc<dyn EventBus>) -> Self {
+        Self {
+            repository,
+            event_bus,
+        }
+    }
+
+    fn stale_criteria(older_than: OffsetDateTime) -> Result<Criteria, String> {
+        Ok(Criteria::new(
+            vec![
+                Filter::new(
+                    FilterField::try_from("status".to_string())?,
+                    FilterOperator::Equal,
+                    FilterValue::try_from(DonaStatus::Pending.to_string())?,
+                ),
+                Filter::new(
+                    FilterField::try_from("created_at".to_string())?,
+                    FilterOperator::LessThan,
+                    FilterValue::try_from(older_than.format(&Rfc3339).map_err(|e| e.to_string())?)?,
+                ),
+            ],
+            None,
+            Some(Cursor::new(
+                None,
+                None,
+                Some(FirstField::new(STALE_DONAS_BATCH_SIZE)?),
+                None,
+            )),
+        ))
+    }
+
+    /// Expires every pending dona created before `older_than`, one batch at a time.
+    /// Expired donas no longer match the criteria, so each batch picks up where the last one
+    /// left off.
+    pub async fn execute(
+        &self,
+        older_than: OffsetDateTime,
+        updated_at: OffsetDateTime,
+    ) -> Result<(), String> {
+        let criteria = Self::stale_criteria(older_than)?;
+
+        loop {
+            let donas = self.repository.find_by_criteria(criteria.clone()).await?;
+            let is_last_batch = donas.len() < STALE_DONAS_BATCH_SIZE;
+
+            for mut dona in donas {
+                dona.expire(updated_at)?;
+
+                self.repository.save(&dona).await?;
+
+                self.event_bus.publish(dona.pull_events()).await?;
+            }
+
+            if is_last_batch {
+                return Ok(());
+            }
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use shared::domain::bus::query::{Query, QueryError, QueryHandler, Response};
+
+use super::service::DonaFinder;
+
+pub const FIND_DONA_QUERY_TYPE: &str = "dona.find_dona.query";
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FindDonaQuery {
+    pub id: String,
+}
+
+impl Query for FindDonaQuery {
+    fn query_type(&self) -> &'static str {
+        FIND_DONA_QUERY_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct FindDonaQueryHandler {
+    service: DonaFinder,
+}
+
+impl FindDonaQueryHandler {
+    pub fn new(service: DonaFinder) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler for FindDonaQueryHandler {
+    async fn handle(&self, query: Box<dyn Query>) -> Result<Box<dyn Response>, QueryError> {
+        let query = query
+            .as_any()
+            .downcast_ref::<FindDonaQuery>()
+            .ok_or_else(|| QueryError::new("Invalid query".to_string()))?;
+
+        let dona = self
+            .service
+            .execute(query.id.to_owned())
+            .await
+            .map_err(QueryError::new)?;
+
+        Ok(Box::new(dona))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockall::predicate;
+    use rust_decimal_macros::dec;
+    use shared::domain::base_errors::BaseRepositoryError;
+
+    use super::*;
+
+    use crate::dona::application::response::DonaResponse;
+    use crate::dona::domain::dona::tests::DonaMother;
+    use crate::dona::domain::dona::DonaId;
+    use crate::dona::domain::dona_repository::tests::MockDonaRepository;
+
+    #[tokio::test]
+    async fn it_should_return_error_when_dona_not_found() {
+        let dona = DonaMother::create(
+            None,
+            None,
+            Some(dec!(5.00)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        let mut dona_repository = MockDonaRepository::new();
+        dona_repository
+            .expect_find_by_id()
+            .with(predicate::eq(DonaId::new(dona.id()).unwrap()))
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+
+        let handler = FindDonaQueryHandler::new(DonaFinder::new(Arc::new(dona_repository)));
+
+        let response = handler
+            .handle(Box::new(FindDonaQuery { id: dona.id() }))
+            .await;
+
+        assert!(response.is_err(), "Expected error response");
+    }
+
+    #[tokio::test]
+    async fn it_should_return_dona() {
+        let dona = DonaMother::create(
+            None,
+            None,
+            Some(dec!(5.00)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        let mut dona_repository = MockDonaRepository::new();
+        dona_repository
+            .expect_find_by_id()
+            .with(predicate::eq(DonaId::new(dona.id()).unwrap()))
+            .times(1)
+            .return_const(Ok(dona.clone()));
+
+        let handler = FindDonaQueryHandler::new(DonaFinder::new(Arc::new(dona_repository)));
+
+        let response = handler
+            .handle(Box::new(FindDonaQuery { id: dona.id() }))
+            .await
+            .unwrap();
+        let response = response
+            .as_any()
+            .downcast_ref::<DonaResponse>()
+            .unwrap()
+            .to_owned();
+
+        assert_eq!(response, DonaResponse::from(dona));
+    }
+}
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use crate::dona::{
+    application::response::DonaResponse,
+    domain::{dona::DonaId, dona_repository::DonaRepository},
+};
+
+#[derive(Clone)]
+pub struct DonaFinder {
+    dona_repository: Arc<dyn DonaRepository>,
+}
+
+impl DonaFinder {
+    pub fn new(dona_repository: Arc<dyn DonaRepository>) -> Self {
+        Self { dona_repository }
+    }
+
+    pub async fn execute(&self, id: String) -> Result<DonaResponse, String> {
+        let dona = self
+            .dona_repository
+            .find_by_id(DonaId::new(id)?)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(dona.into())
+    }
+}
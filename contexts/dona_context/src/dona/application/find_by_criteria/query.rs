@@ -0,0 +1,187 @@
+use shared::domain::{
+    bus::query::{Query, QueryError, QueryHandler, Response},
+    criteria::Criteria,
+};
+
+use super::service::DonasFinder;
+
+pub const FIND_DONAS_BY_CRITERIA_QUERY_TYPE: &str = "dona.find_donas_by_criteria.query";
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FindDonasByCriteriaQuery {
+    pub criteria: Criteria,
+}
+
+impl Query for FindDonasByCriteriaQuery {
+    fn query_type(&self) -> &'static str {
+        FIND_DONAS_BY_CRITERIA_QUERY_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct FindDonasByCriteriaQueryHandler {
+    service: DonasFinder,
+}
+
+impl FindDonasByCriteriaQueryHandler {
+    pub fn new(service: DonasFinder) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler for FindDonasByCriteriaQueryHandler {
+    async fn handle(&self, query: Box<dyn Query>) -> Result<Box<dyn Response>, QueryError> {
+        let query = query
+            .as_any()
+            .downcast_ref::<FindDonasByCriteriaQuery>()
+            .ok_or_else(|| QueryError::new("Invalid query".to_string()))?;
+
+        let donas = self
+            .service
+            .execute(query.criteria.to_owned())
+            .await
+            .map_err(QueryError::new)?;
+
+        Ok(Box::new(donas))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockall::predicate;
+    use rust_decimal_macros::dec;
+    use shared::domain::{base_errors::BaseRepositoryError, criteria::page::CursorPage};
+
+    use super::*;
+
+    use crate::dona::application::response::{DonaReplyResponse, DonaResponse, DonasResponse};
+    use crate::dona::domain::dona::tests::DonaMother;
+    use crate::dona::domain::dona_reply::{tests::DonaReplyMother, DonaReply};
+    use crate::dona::domain::dona_reply_repository::tests::MockDonaReplyRepository;
+    use crate::dona::domain::dona_repository::tests::MockDonaRepository;
+
+    fn mock_criteria() -> Criteria {
+        Criteria::new(vec![], None, None)
+    }
+
+    fn handler(
+        dona_repository: MockDonaRepository,
+        replies: Vec<DonaReply>,
+    ) -> FindDonasByCriteriaQueryHandler {
+        let mut reply_repository = MockDonaReplyRepository::new();
+        reply_repository
+            .expect_find_by_dona_ids()
+            .return_const(Ok(replies));
+
+        FindDonasByCriteriaQueryHandler::new(DonasFinder::new(
+            Arc::new(dona_repository),
+            Arc::new(reply_repository),
+        ))
+    }
+
+    #[tokio::test]
+    async fn it_should_return_error_when_dona_repo_fails() {
+        let criteria = mock_criteria();
+
+        let mut dona_repository = MockDonaRepository::new();
+        dona_repository
+            .expect_find_page_by_criteria()
+            .with(predicate::eq(criteria.clone()))
+            .return_const(Err(BaseRepositoryError::UnexpectedError(
+                "Error".to_string(),
+            )));
+
+        let handler = handler(dona_repository, vec![]);
+
+        let response = handler
+            .handle(Box::new(FindDonasByCriteriaQuery { criteria }))
+            .await;
+
+        assert!(response.is_err(), "Expected error response");
+    }
+
+    #[tokio::test]
+    async fn it_should_return_donas_with_page_info() {
+        let criteria = mock_criteria();
+        let donas = vec![DonaMother::create(
+            None,
+            None,
+            Some(dec!(12.00)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )];
+
+        let mut dona_repository = MockDonaRepository::new();
+        dona_repository
+            .expect_find_page_by_criteria()
+            .with(predicate::eq(criteria.clone()))
+            .return_const(Ok(CursorPage::new(donas.clone(), false, true)));
+
+        let handler = handler(dona_repository, vec![]);
+
+        let response = handler
+            .handle(Box::new(FindDonasByCriteriaQuery { criteria }))
+            .await
+            .unwrap();
+        let response = response
+            .as_any()
+            .downcast_ref::<DonasResponse>()
+            .unwrap()
+            .to_owned();
+
+        let expected_response = DonasResponse {
+            donas: donas.into_iter().map(DonaResponse::from).collect(),
+            has_previous_page: false,
+            has_next_page: true,
+        };
+
+        assert_eq!(response, expected_response);
+    }
+
+    #[tokio::test]
+    async fn it_should_return_donas_with_their_reply() {
+        let criteria = mock_criteria();
+        let replied = DonaMother::random();
+        let not_replied = DonaMother::random();
+        let reply = DonaReplyMother::create(Some(replied.id()), Some(replied.user_id()), None);
+
+        let mut dona_repository = MockDonaRepository::new();
+        dona_repository
+            .expect_find_page_by_criteria()
+            .return_const(Ok(CursorPage::new(
+                vec![replied.clone(), not_replied.clone()],
+                false,
+                false,
+            )));
+
+        let response = handler(dona_repository, vec![reply.clone()])
+            .handle(Box::new(FindDonasByCriteriaQuery { criteria }))
+            .await
+            .unwrap();
+        let response = response
+            .as_any()
+            .downcast_ref::<DonasResponse>()
+            .unwrap()
+            .to_owned();
+
+        assert_eq!(
+            response.donas[0].reply,
+            Some(DonaReplyResponse::from(reply))
+        );
+        assert_eq!(response.donas[1].reply, None);
+    }
+}
@@ -0,0 +1,63 @@
+use std::{collections::HashMap, sync::Arc};
+
+use shared::domain::criteria::Criteria;
+
+use crate::dona::{
+    application::response::{DonaReplyResponse, DonaResponse, DonasResponse},
+    domain::{
+        dona::DonaId, dona_reply::DonaReply, dona_reply_repository::DonaReplyRepository,
+        dona_repository::DonaRepository,
+    },
+};
+
+#[derive(Clone)]
+pub struct DonasFinder {
+    dona_repository: Arc<dyn DonaRepository>,
+    reply_repository: Arc<dyn DonaReplyRepository>,
+}
+
+impl DonasFinder {
+    pub fn new(
+        dona_repository: Arc<dyn DonaRepository>,
+        reply_repository: Arc<dyn DonaReplyRepository>,
+    ) -> Self {
+        Self {
+            dona_repository,
+            reply_repository,
+        }
+    }
+
+    /// Each dona comes with the reply of its recipient, so donors get to read the thanks
+    /// when they list the donas they sent.
+    pub async fn execute(&self, criteria: Criteria) -> Result<DonasResponse, String> {
+        let page = self.dona_repository.find_page_by_criteria(criteria).await?;
+
+        let dona_ids = page
+            .items()
+            .iter()
+            .map(|dona| DonaId::new(dona.id()))
+            .collect::<Result<Vec<DonaId>, String>>()?;
+        let mut replies: HashMap<String, DonaReply> = self
+            .reply_repository
+            .find_by_dona_ids(dona_ids)
+            .await?
+            .into_iter()
+            .map(|reply| (reply.dona_id(), reply))
+            .collect();
+
+        Ok(DonasResponse {
+            has_previous_page: page.has_previous_page(),
+            has_next_page: page.has_next_page(),
+            donas: page
+                .map(|dona| {
+                    let reply = replies.remove(&dona.id()).map(DonaReplyResponse::from);
+
+                    DonaResponse {
+                        reply,
+                        ..DonaResponse::from(dona)
+                    }
+                })
+                .into_items(),
+        })
+    }
+}
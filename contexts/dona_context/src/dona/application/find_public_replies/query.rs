@@ -0,0 +1,153 @@
+use shared::domain::{
+    bus::query::{Query, QueryError, QueryHandler, Response},
+    criteria::Criteria,
+};
+
+use super::service::PublicDonaRepliesFinder;
+
+pub const FIND_PUBLIC_DONA_REPLIES_QUERY_TYPE: &str = "dona.find_public_dona_replies.query";
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FindPublicDonaRepliesQuery {
+    pub user_id: String,
+    pub criteria: Criteria,
+}
+
+impl Query for FindPublicDonaRepliesQuery {
+    fn query_type(&self) -> &'static str {
+        FIND_PUBLIC_DONA_REPLIES_QUERY_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct FindPublicDonaRepliesQueryHandler {
+    service: PublicDonaRepliesFinder,
+}
+
+impl FindPublicDonaRepliesQueryHandler {
+    pub fn new(service: PublicDonaRepliesFinder) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler for FindPublicDonaRepliesQueryHandler {
+    async fn handle(&self, query: Box<dyn Query>) -> Result<Box<dyn Response>, QueryError> {
+        let query = query
+            .as_any()
+            .downcast_ref::<FindPublicDonaRepliesQuery>()
+            .ok_or_else(|| QueryError::new("Invalid query".to_string()))?;
+
+        let replies = self
+            .service
+            .execute(query.user_id.to_owned(), query.criteria.to_owned())
+            .await
+            .map_err(QueryError::new)?;
+
+        Ok(Box::new(replies))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use shared::domain::{
+        criteria::{
+            filter::{Filter, FilterField, FilterOperator, FilterValue},
+            page::CursorPage,
+        },
+        value_objects::user_id::{tests::UserIdMother, ERR_INVALID_USER_ID},
+    };
+
+    use super::*;
+
+    use crate::dona::application::response::{DonaRepliesResponse, DonaReplyResponse};
+    use crate::dona::domain::dona_reply::{tests::DonaReplyMother, DonaReplyVisibility};
+    use crate::dona::domain::dona_reply_repository::tests::MockDonaReplyRepository;
+
+    #[tokio::test]
+    async fn it_should_only_find_public_replies_of_the_recipient() {
+        let user_id = UserIdMother::random().to_string();
+        let reply = DonaReplyMother::create(
+            None,
+            Some(user_id.clone()),
+            Some(DonaReplyVisibility::Public),
+        );
+        // A donor asking for private replies still gets public ones only.
+        let visibility = Filter::new(
+            FilterField::try_from("visibility".to_string()).unwrap(),
+            FilterOperator::Equal,
+            FilterValue::try_from("PRIVATE".to_string()).unwrap(),
+        );
+
+        let expected_user_id = user_id.clone();
+        let mut reply_repository = MockDonaReplyRepository::new();
+        reply_repository
+            .expect_find_page_by_criteria()
+            .withf(move |criteria| {
+                let filters = criteria
+                    .filters()
+                    .iter()
+                    .map(|filter| (filter.field().to_string(), filter.value().to_string()))
+                    .collect::<Vec<_>>();
+
+                filters.contains(&("user_id".to_string(), expected_user_id.clone()))
+                    && filters.contains(&("visibility".to_string(), "PUBLIC".to_string()))
+            })
+            .times(1)
+            .return_const(Ok(CursorPage::new(vec![reply.clone()], false, true)));
+
+        let handler = FindPublicDonaRepliesQueryHandler::new(PublicDonaRepliesFinder::new(
+            Arc::new(reply_repository),
+        ));
+
+        let response = handler
+            .handle(Box::new(FindPublicDonaRepliesQuery {
+                user_id,
+                criteria: Criteria::new(vec![visibility], None, None),
+            }))
+            .await
+            .unwrap();
+        let response = response
+            .as_any()
+            .downcast_ref::<DonaRepliesResponse>()
+            .unwrap()
+            .to_owned();
+
+        assert_eq!(
+            response,
+            DonaRepliesResponse {
+                replies: vec![DonaReplyResponse::from(reply)],
+                has_previous_page: false,
+                has_next_page: true,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_user_id_is_invalid() {
+        let mut reply_repository = MockDonaReplyRepository::new();
+        reply_repository.expect_find_page_by_criteria().times(0);
+
+        let handler = FindPublicDonaRepliesQueryHandler::new(PublicDonaRepliesFinder::new(
+            Arc::new(reply_repository),
+        ));
+
+        let result = handler
+            .handle(Box::new(FindPublicDonaRepliesQuery {
+                user_id: "invalid".to_string(),
+                criteria: Criteria::new(vec![], None, None),
+            }))
+            .await;
+
+        assert_eq!(
+            result.err(),
+            Some(QueryError::new(ERR_INVALID_USER_ID.to_string()))
+        );
+    }
+}
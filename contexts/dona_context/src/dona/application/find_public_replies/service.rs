@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use shared::domain::{
+    criteria::{
+        filter::{Filter, FilterField, FilterOperator, FilterValue},
+        Criteria,
+    },
+    value_objects::user_id::UserId,
+};
+
+use crate::dona::{
+    application::response::{DonaRepliesResponse, DonaReplyResponse},
+    domain::{dona_reply::DonaReplyVisibility, dona_reply_repository::DonaReplyRepository},
+};
+
+#[derive(Clone)]
+pub struct PublicDonaRepliesFinder {
+    reply_repository: Arc<dyn DonaReplyRepository>,
+}
+
+impl PublicDonaRepliesFinder {
+    pub fn new(reply_repository: Arc<dyn DonaReplyRepository>) -> Self {
+        Self { reply_repository }
+    }
+
+    /// Only the replies the recipient made public, whatever the criteria asks for.
+    pub async fn execute(
+        &self,
+        user_id: String,
+        criteria: Criteria,
+    ) -> Result<DonaRepliesResponse, String> {
+        let user_id = UserId::new(user_id)?;
+        let criteria = criteria
+            .with_filter(Filter::new(
+                FilterField::try_from("user_id".to_string())?,
+                FilterOperator::Equal,
+                FilterValue::try_from(user_id.to_string())?,
+            ))
+            .with_filter(Filter::new(
+                FilterField::try_from("visibility".to_string())?,
+                FilterOperator::Equal,
+                FilterValue::try_from(DonaReplyVisibility::Public.to_string())?,
+            ));
+
+        let page = self
+            .reply_repository
+            .find_page_by_criteria(criteria)
+            .await?;
+
+        Ok(DonaRepliesResponse {
+            has_previous_page: page.has_previous_page(),
+            has_next_page: page.has_next_page(),
+            replies: page.map(DonaReplyResponse::from).into_items(),
+        })
+    }
+}
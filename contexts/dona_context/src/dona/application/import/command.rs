@@ -0,0 +1,297 @@
+use shared::domain::bus::command::{Command, CommandError, CommandHandler};
+use time::OffsetDateTime;
+
+use super::service::DonasImporter;
+
+pub const IMPORT_DONAS_COMMAND_TYPE: &str = "dona.import_donas.command";
+
+#[derive(Debug)]
+pub struct ImportDonasCommand {
+    pub user_id: String,
+    pub csv: String,
+    pub imported_at: OffsetDateTime,
+}
+
+impl Command for ImportDonasCommand {
+    fn command_type(&self) -> &'static str {
+        IMPORT_DONAS_COMMAND_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct ImportDonasCommandHandler {
+    service: DonasImporter,
+}
+
+impl ImportDonasCommandHandler {
+    pub fn new(service: DonasImporter) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler for ImportDonasCommandHandler {
+    async fn handle(&self, command: Box<dyn Command>) -> Result<(), CommandError> {
+        let command = command
+            .as_any()
+            .downcast_ref::<ImportDonasCommand>()
+            .ok_or_else(|| CommandError::new("Invalid command".to_string()))?;
+
+        self.service
+            .execute(
+                command.user_id.to_owned(),
+                command.csv.to_owned(),
+                command.imported_at,
+            )
+            .await
+            .map_err(CommandError::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use rust_decimal_macros::dec;
+    use shared::domain::{
+        base_errors::BaseRepositoryError, bus::event::tests::MockEventBus, utils::new_uuid,
+    };
+    use time::{macros::datetime, Duration};
+
+    use super::*;
+
+    use crate::dona::domain::dona::REDACTED_DONA_MSG;
+    use crate::dona::domain::dona_confirmed_event::DONA_CONFIRMED_EVENT_TYPE;
+    use crate::dona::domain::dona_created_event::DONA_CREATED_EVENT_TYPE;
+    use crate::dona::domain::dona_import::ERR_INVALID_DONA_IMPORT_CSV_HEADER;
+    use crate::dona::domain::dona_msg_flagged_event::DONA_MSG_FLAGGED_EVENT_TYPE;
+    use crate::dona::domain::dona_msg_policy::DonaMsgPolicy;
+    use crate::dona::domain::dona_repository::tests::MockDonaRepository;
+    use crate::recipient::domain::recipient_settings::tests::RecipientSettingsMother;
+    use crate::recipient::domain::recipient_settings_repository::tests::MockRecipientSettingsRepository;
+
+    const CSV: &str = "date,amount,currency,donor_name,donor_email,message\n\
+        2023-11-02,25.00,usd,Ana,ana@example.com,Cash at the show\n\
+        2023-11-03,-5,USD,Bob,,Refund?\n\
+        2023-12-24T18:30:00+01:00,10,EUR,\"Doe, Jane\",,\"Bank transfer, thanks\"\n";
+
+    fn handler(
+        repository: MockDonaRepository,
+        event_bus: MockEventBus,
+    ) -> ImportDonasCommandHandler {
+        let mut recipient_settings_repository = MockRecipientSettingsRepository::new();
+        recipient_settings_repository
+            .expect_find_by_user_id()
+            .return_const(Err(BaseRepositoryError::NotFound));
+
+        handler_with(repository, recipient_settings_repository, event_bus)
+    }
+
+    fn handler_with(
+        repository: MockDonaRepository,
+        recipient_settings_repository: MockRecipientSettingsRepository,
+        event_bus: MockEventBus,
+    ) -> ImportDonasCommandHandler {
+        ImportDonasCommandHandler::new(DonasImporter::new(
+            Arc::new(repository),
+            Arc::new(recipient_settings_repository),
+            DonaMsgPolicy::default(),
+            Arc::new(event_bus),
+        ))
+    }
+
+    fn command(user_id: String, csv: &str) -> Box<dyn Command> {
+        Box::new(ImportDonasCommand {
+            user_id,
+            csv: csv.to_string(),
+            imported_at: OffsetDateTime::now_utc() - Duration::seconds(1),
+        })
+    }
+
+    #[tokio::test]
+    async fn it_should_save_the_valid_rows_as_confirmed_manual_donas() {
+        let user_id = new_uuid();
+
+        let mut repository = MockDonaRepository::new();
+        let expected_user_id = user_id.clone();
+        repository
+            .expect_save_all()
+            .withf(move |donas| {
+                let [cash, transfer] = donas else {
+                    return false;
+                };
+
+                donas.iter().all(|dona| {
+                    dona.user_id() == expected_user_id
+                        && dona.status() == "confirmed"
+                        && dona.method() == "MANUAL"
+                        && dona.sender_id().is_none()
+                        && !dona.is_anonymous()
+                }) && cash.amount() == dec!(25.00)
+                    && cash.currency() == "USD"
+                    && cash.guest_name() == Some("Ana".to_string())
+                    && cash.guest_email() == Some("ana@example.com".to_string())
+                    && cash.created_at() == datetime!(2023-11-02 00:00 UTC)
+                    && transfer.guest_name() == Some("Doe, Jane".to_string())
+                    && transfer.guest_email().is_none()
+                    && transfer.msg() == "Bank transfer, thanks"
+                    && transfer.created_at() == datetime!(2023-12-24 17:30 UTC)
+            })
+            .times(1)
+            .return_const(Ok(()));
+        repository.expect_save().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus
+            .expect_publish()
+            .withf(|events| {
+                events.iter().map(|event| event.event_type()).eq([
+                    DONA_CREATED_EVENT_TYPE,
+                    DONA_CONFIRMED_EVENT_TYPE,
+                    DONA_CREATED_EVENT_TYPE,
+                    DONA_CONFIRMED_EVENT_TYPE,
+                ])
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let result = handler(repository, event_bus)
+            .handle(command(user_id, CSV))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_moderate_the_messages_of_the_imported_donas() {
+        let csv = "date,amount,currency,donor_name,donor_email,message\n\
+            2023-11-02,25.00,USD,Ana,,See https://example.com\n";
+
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_save_all()
+            .withf(|donas| {
+                donas.len() == 1
+                    && donas[0].status() == "confirmed"
+                    && donas[0].msg() == "See https://example.com"
+                    && donas[0].msg_status() == "in_review"
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus
+            .expect_publish()
+            .withf(|events| {
+                events
+                    .iter()
+                    .any(|event| event.event_type() == DONA_MSG_FLAGGED_EVENT_TYPE)
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let result = handler(repository, event_bus)
+            .handle(command(new_uuid(), csv))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_withhold_messages_when_recipient_does_not_take_messages() {
+        let user_id = new_uuid();
+
+        let mut recipient_settings_repository = MockRecipientSettingsRepository::new();
+        recipient_settings_repository
+            .expect_find_by_user_id()
+            .times(1)
+            .return_const(Ok(RecipientSettingsMother::create(
+                Some(user_id.clone()),
+                None,
+                None,
+                None,
+                vec!["MANUAL"],
+                false,
+            )));
+
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_save_all()
+            .withf(|donas| {
+                donas.len() == 2
+                    && donas.iter().all(|dona| {
+                        dona.msg() == REDACTED_DONA_MSG && dona.msg_status() == "redacted"
+                    })
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(1).return_const(Ok(()));
+
+        let result = handler_with(repository, recipient_settings_repository, event_bus)
+            .handle(command(user_id, CSV))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_not_save_anything_without_valid_rows() {
+        let mut repository = MockDonaRepository::new();
+        repository.expect_save_all().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let result = handler(repository, event_bus)
+            .handle(command(
+                new_uuid(),
+                "date,amount,currency,donor_name,donor_email,message\n2999-01-01,1,USD,Ana,,Hi\n",
+            ))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_with_an_unexpected_header_or_a_failed_save() {
+        let mut repository = MockDonaRepository::new();
+        repository.expect_save_all().times(0);
+
+        let result = handler(repository, MockEventBus::new())
+            .handle(command(new_uuid(), "date,amount\n2023-11-02,25.00\n"))
+            .await;
+
+        assert_eq!(
+            result.err(),
+            Some(CommandError::new(
+                ERR_INVALID_DONA_IMPORT_CSV_HEADER.to_string()
+            ))
+        );
+
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_save_all()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::ConnectionError));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let result = handler(repository, event_bus)
+            .handle(command(new_uuid(), CSV))
+            .await;
+
+        assert_eq!(
+            result.err(),
+            Some(CommandError::new(
+                BaseRepositoryError::ConnectionError.to_string()
+            ))
+        );
+    }
+}
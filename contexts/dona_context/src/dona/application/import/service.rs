@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use shared::domain::bus::event::EventBus;
+use time::OffsetDateTime;
+
+use crate::{
+    dona::{
+        application::create::service::recipient_settings,
+        domain::{
+            dona_import::DonaImport, dona_msg_policy::DonaMsgPolicy,
+            dona_repository::DonaRepository,
+        },
+    },
+    recipient::domain::recipient_settings_repository::RecipientSettingsRepository,
+};
+
+#[derive(Clone)]
+pub struct DonasImporter {
+    repository: Arc<dyn DonaRepository>,
+    recipient_settings_repository: Arc<dyn RecipientSettingsRepository>,
+    msg_policy: DonaMsgPolicy,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl DonasImporter {
+    pub fn new(
+        repository: Arc<dyn DonaRepository>,
+        recipient_settings_repository: Arc<dyn RecipientSettingsRepository>,
+        msg_policy: DonaMsgPolicy,
+        event_bus: Arc<dyn EventBus>,
+    ) -> Self {
+        Self {
+            repository,
+            recipient_settings_repository,
+            msg_policy,
+            event_bus,
+        }
+    }
+
+    /// Saves the valid rows of the file as donas received by the user and skips the invalid
+    /// ones, `DonaImportValidator` reports them beforehand. The donas are published as created
+    /// and confirmed, so fees and receipts apply to them as well. Limits and payment methods
+    /// of the recipient settings are not checked, the donas were received before the import.
+    pub async fn execute(
+        &self,
+        user_id: String,
+        csv: String,
+        imported_at: OffsetDateTime,
+    ) -> Result<(), String> {
+        let settings =
+            recipient_settings(self.recipient_settings_repository.as_ref(), &user_id).await?;
+        let messages_allowed = settings
+            .as_ref()
+            .is_none_or(|settings| settings.messages_allowed());
+
+        let mut donas = DonaImport::parse(
+            &csv,
+            user_id,
+            imported_at,
+            messages_allowed.then_some(&self.msg_policy),
+        )?
+        .into_donas();
+        if donas.is_empty() {
+            return Ok(());
+        }
+
+        self.repository.save_all(&donas).await?;
+
+        let events = donas
+            .iter_mut()
+            .flat_map(|dona| dona.pull_events())
+            .collect();
+        self.event_bus.publish(events).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+pub mod cancel;
+pub mod checkout;
+pub mod confirm;
+pub mod confirm_payment;
+pub mod create;
+pub mod create_reply;
+pub mod delete;
+pub mod delete_reply;
+pub mod delete_reply_on_dona_deleted;
+pub mod expire;
+pub mod expire_stale;
+pub mod find;
+pub mod find_by_criteria;
+pub mod find_public_replies;
+pub mod import;
+pub mod received_total;
+pub mod refresh_payment;
+pub mod refund;
+pub mod reject;
+pub mod response;
+pub mod review_msg;
+pub mod stats;
+pub mod update_reply;
+pub mod validate_import;
@@ -0,0 +1,226 @@
+use shared::domain::bus::query::{Query, QueryError, QueryHandler, Response};
+
+use super::service::ReceivedTotalCalculator;
+
+pub const GET_RECEIVED_TOTAL_QUERY_TYPE: &str = "dona.get_received_total.query";
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GetReceivedTotalQuery {
+    pub user_id: String,
+    pub currency: String,
+}
+
+impl Query for GetReceivedTotalQuery {
+    fn query_type(&self) -> &'static str {
+        GET_RECEIVED_TOTAL_QUERY_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct GetReceivedTotalQueryHandler {
+    service: ReceivedTotalCalculator,
+}
+
+impl GetReceivedTotalQueryHandler {
+    pub fn new(service: ReceivedTotalCalculator) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler for GetReceivedTotalQueryHandler {
+    async fn handle(&self, query: Box<dyn Query>) -> Result<Box<dyn Response>, QueryError> {
+        let query = query
+            .as_any()
+            .downcast_ref::<GetReceivedTotalQuery>()
+            .ok_or_else(|| QueryError::new("Invalid query".to_string()))?;
+
+        let total = self
+            .service
+            .execute(query.user_id.to_owned(), query.currency.to_owned())
+            .await
+            .map_err(QueryError::new)?;
+
+        Ok(Box::new(total))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+    use shared::domain::base_errors::BaseRepositoryError;
+    use shared::domain::criteria::page::CursorPage;
+    use shared::domain::utils::new_uuid;
+
+    use super::*;
+
+    use crate::dona::application::response::ReceivedTotalResponse;
+    use crate::dona::domain::dona::tests::DonaMother;
+    use crate::dona::domain::dona::Dona;
+    use crate::dona::domain::dona_repository::tests::MockDonaRepository;
+    use crate::exchange_rate::application::convert::service::{
+        CurrencyConverter, ERR_EXCHANGE_RATE_NOT_FOUND,
+    };
+    use crate::exchange_rate::domain::exchange_rate::tests::ExchangeRateMother;
+    use crate::exchange_rate::domain::exchange_rate_repository::tests::MockExchangeRateRepository;
+
+    fn confirmed_dona(user_id: &str, amount: Decimal, currency: &str) -> Dona {
+        DonaMother::create(
+            None,
+            None,
+            Some(amount),
+            Some(currency.to_string()),
+            Some("confirmed".to_string()),
+            None,
+            Some(user_id.to_string()),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn handler(
+        repository: MockDonaRepository,
+        exchange_rate_repository: MockExchangeRateRepository,
+    ) -> GetReceivedTotalQueryHandler {
+        GetReceivedTotalQueryHandler::new(ReceivedTotalCalculator::new(
+            Arc::new(repository),
+            CurrencyConverter::new(Arc::new(exchange_rate_repository)),
+        ))
+    }
+
+    #[tokio::test]
+    async fn it_should_sum_received_donas_in_requested_currency() {
+        let user_id = new_uuid();
+        let donas = vec![
+            confirmed_dona(&user_id, dec!(10.00), "USD"),
+            confirmed_dona(&user_id, dec!(20.00), "EUR"),
+        ];
+
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_find_page_by_criteria()
+            .times(1)
+            .return_const(Ok(CursorPage::new(donas, false, false)));
+
+        let mut exchange_rate_repository = MockExchangeRateRepository::new();
+        exchange_rate_repository
+            .expect_find_effective()
+            .times(1)
+            .return_const(Ok(ExchangeRateMother::create(
+                None,
+                Some("EUR".to_string()),
+                Some("USD".to_string()),
+                Some(dec!(1.10)),
+                None,
+            )));
+
+        let response = handler(repository, exchange_rate_repository)
+            .handle(Box::new(GetReceivedTotalQuery {
+                user_id,
+                currency: "USD".to_string(),
+            }))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response
+                .as_any()
+                .downcast_ref::<ReceivedTotalResponse>()
+                .unwrap(),
+            &ReceivedTotalResponse {
+                total: dec!(32.00),
+                currency: "USD".to_string(),
+                count: 2,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_resume_each_page_after_the_last_dona() {
+        let user_id = new_uuid();
+        let first_page = vec![
+            confirmed_dona(&user_id, dec!(10.00), "USD"),
+            confirmed_dona(&user_id, dec!(20.00), "USD"),
+        ];
+        let last_id = first_page[1].id();
+        let second_page = vec![confirmed_dona(&user_id, dec!(5.00), "USD")];
+
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_find_page_by_criteria()
+            .withf(|criteria| criteria.cursor().unwrap().after().is_none())
+            .times(1)
+            .return_const(Ok(CursorPage::new(first_page, false, true)));
+        repository
+            .expect_find_page_by_criteria()
+            .withf(move |criteria| {
+                criteria
+                    .cursor()
+                    .and_then(|cursor| cursor.after())
+                    .and_then(|after| after.id())
+                    == Some(&last_id)
+            })
+            .times(1)
+            .return_const(Ok(CursorPage::new(second_page, true, false)));
+
+        let response = handler(repository, MockExchangeRateRepository::new())
+            .handle(Box::new(GetReceivedTotalQuery {
+                user_id,
+                currency: "USD".to_string(),
+            }))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response
+                .as_any()
+                .downcast_ref::<ReceivedTotalResponse>()
+                .unwrap(),
+            &ReceivedTotalResponse {
+                total: dec!(35.00),
+                currency: "USD".to_string(),
+                count: 3,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_a_rate_is_missing() {
+        let user_id = new_uuid();
+        let donas = vec![confirmed_dona(&user_id, dec!(20.00), "EUR")];
+
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_find_page_by_criteria()
+            .times(1)
+            .return_const(Ok(CursorPage::new(donas, false, false)));
+
+        let mut exchange_rate_repository = MockExchangeRateRepository::new();
+        exchange_rate_repository
+            .expect_find_effective()
+            .times(2)
+            .returning(|_, _, _| Err(BaseRepositoryError::NotFound));
+
+        let result = handler(repository, exchange_rate_repository)
+            .handle(Box::new(GetReceivedTotalQuery {
+                user_id,
+                currency: "USD".to_string(),
+            }))
+            .await;
+
+        assert_eq!(
+            result.err(),
+            Some(QueryError::new(ERR_EXCHANGE_RATE_NOT_FOUND.to_string()))
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use shared::domain::{
+    criteria::{
+        cursor::{AfterCursor, Cursor, FirstField},
+        filter::{Filter, FilterField, FilterOperator, FilterValue},
+        Criteria,
+    },
+    value_objects::{money::Currency, user_id::UserId},
+};
+
+use crate::{
+    dona::{
+        application::response::ReceivedTotalResponse,
+        domain::{dona::DonaStatus, dona_repository::DonaRepository},
+    },
+    exchange_rate::application::convert::service::CurrencyConverter,
+};
+
+pub const RECEIVED_TOTAL_BATCH_SIZE: usize = 100;
+
+#[derive(Clone)]
+pub struct ReceivedTotalCalculator {
+    repository: Arc<dyn DonaRepository>,
+    converter: CurrencyConverter,
+}
+
+impl ReceivedTotalCalculator {
+    pub fn new(repository: Arc<dyn DonaRepository>, converter: CurrencyConverter) -> Self {
+        Self {
+            repository,
+            converter,
+        }
+    }
+
+    fn confirmed_criteria(
+        user_id: &UserId,
+        after: Option<AfterCursor>,
+    ) -> Result<Criteria, String> {
+        Ok(Criteria::new(
+            vec![
+                Filter::new(
+                    FilterField::try_from("user_id".to_string())?,
+                    FilterOperator::Equal,
+                    FilterValue::try_from(user_id.to_string())?,
+                ),
+                Filter::new(
+                    FilterField::try_from("status".to_string())?,
+                    FilterOperator::Equal,
+                    FilterValue::try_from(DonaStatus::Confirmed.to_string())?,
+                ),
+            ],
+            None,
+            Some(Cursor::new(
+                after,
+                None,
+                Some(FirstField::new(RECEIVED_TOTAL_BATCH_SIZE)?),
+                None,
+            )),
+        ))
+    }
+
+    /// Sums every confirmed dona received by the user in `currency`, converting each one with
+    /// the rate that applied on the day it was created.
+    pub async fn execute(
+        &self,
+        user_id: String,
+        currency: String,
+    ) -> Result<ReceivedTotalResponse, String> {
+        let user_id = UserId::new(user_id)?;
+        let currency = Currency::new(currency)?;
+
+        let mut total = Decimal::ZERO;
+        let mut count = 0;
+        let mut after = None;
+
+        loop {
+            let page = self
+                .repository
+                .find_page_by_criteria(Self::confirmed_criteria(&user_id, after)?)
+                .await?;
+
+            for dona in page.items() {
+                let from = Currency::new(dona.currency())?;
+                total += self
+                    .converter
+                    .convert(dona.amount(), &from, &currency, dona.created_at())
+                    .await?;
+                count += 1;
+            }
+
+            if !page.has_next_page() {
+                break;
+            }
+            after = page
+                .items()
+                .last()
+                .map(|dona| AfterCursor::with_id(dona.created_at(), dona.id()))
+                .transpose()?;
+        }
+
+        Ok(ReceivedTotalResponse {
+            total: total.round_dp(currency.precision()),
+            currency: currency.to_string(),
+            count,
+        })
+    }
+}
@@ -0,0 +1,184 @@
+use shared::domain::bus::command::{Command, CommandError, CommandHandler};
+use time::OffsetDateTime;
+
+use super::service::DonaPaymentRefresher;
+
+pub const REFRESH_DONA_PAYMENT_COMMAND_TYPE: &str = "dona.refresh_dona_payment.command";
+
+#[derive(Debug)]
+pub struct RefreshDonaPaymentCommand {
+    pub method: String,
+    /// Reference of the payment at the provider, as returned by the checkout.
+    pub reference: String,
+    pub refreshed_at: OffsetDateTime,
+}
+
+impl Command for RefreshDonaPaymentCommand {
+    fn command_type(&self) -> &'static str {
+        REFRESH_DONA_PAYMENT_COMMAND_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct RefreshDonaPaymentCommandHandler {
+    service: DonaPaymentRefresher,
+}
+
+impl RefreshDonaPaymentCommandHandler {
+    pub fn new(service: DonaPaymentRefresher) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler for RefreshDonaPaymentCommandHandler {
+    async fn handle(&self, command: Box<dyn Command>) -> Result<(), CommandError> {
+        let command = command
+            .as_any()
+            .downcast_ref::<RefreshDonaPaymentCommand>()
+            .ok_or_else(|| CommandError::new("Invalid command".to_string()))?;
+
+        self.service
+            .execute(
+                command.method.to_owned(),
+                command.reference.to_owned(),
+                command.refreshed_at,
+            )
+            .await
+            .map_err(CommandError::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use rust_decimal_macros::dec;
+    use shared::domain::bus::event::tests::MockEventBus;
+    use time::Duration;
+
+    use super::*;
+
+    use crate::dona::application::confirm_payment::service::DonaPaymentConfirmer;
+    use crate::dona::domain::dona::tests::DonaMother;
+    use crate::dona::domain::dona::Dona;
+    use crate::dona::domain::dona_confirmed_event::DONA_CONFIRMED_EVENT_TYPE;
+    use crate::dona::domain::dona_repository::tests::MockDonaRepository;
+    use crate::dona::domain::payment_gateway::{PaymentGateway, PaymentGatewayRegistry};
+    use crate::dona::infrastructure::payment::fake_payment_gateway::{
+        FakePaymentGateway, ERR_UNKNOWN_FAKE_PAYMENT,
+    };
+    use crate::shared::domain::dona::DonaOptionMethod;
+    use crate::user_payment_method::domain::user_payment_method_repository::tests::MockUserPaymentMethodRepository;
+
+    fn paypal_dona() -> Dona {
+        DonaMother::create(
+            None,
+            None,
+            Some(dec!(10.00)),
+            Some("USD".to_string()),
+            Some("pending".to_string()),
+            Some("PAYPAL".to_string()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn handler(
+        repository: MockDonaRepository,
+        event_bus: MockEventBus,
+        gateway: Arc<FakePaymentGateway>,
+    ) -> RefreshDonaPaymentCommandHandler {
+        let gateways = PaymentGatewayRegistry::new(vec![gateway]);
+
+        RefreshDonaPaymentCommandHandler::new(DonaPaymentRefresher::new(
+            gateways.clone(),
+            DonaPaymentConfirmer::new(
+                Arc::new(repository),
+                Arc::new(MockUserPaymentMethodRepository::new()),
+                gateways,
+                Arc::new(event_bus),
+            ),
+        ))
+    }
+
+    fn command(reference: String) -> Box<RefreshDonaPaymentCommand> {
+        Box::new(RefreshDonaPaymentCommand {
+            method: "PAYPAL".to_string(),
+            reference,
+            refreshed_at: OffsetDateTime::now_utc() - Duration::seconds(1),
+        })
+    }
+
+    #[tokio::test]
+    async fn it_should_confirm_a_completed_payment_without_callback() {
+        let dona = paypal_dona();
+        let gateway = Arc::new(FakePaymentGateway::new(DonaOptionMethod::Paypal));
+        let checkout = gateway.create_checkout(&dona).await.unwrap();
+        gateway.complete(&checkout.reference).unwrap();
+
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(dona));
+        repository
+            .expect_save()
+            .withf(|dona| dona.is_confirmed())
+            .times(1)
+            .return_const(Ok(()));
+        let mut event_bus = MockEventBus::new();
+        event_bus
+            .expect_publish()
+            .withf(|events| {
+                events.len() == 1 && events[0].event_type() == DONA_CONFIRMED_EVENT_TYPE
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let result = handler(repository, event_bus, gateway)
+            .handle(command(checkout.reference))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_leave_pending_payments_alone() {
+        let dona = paypal_dona();
+        let gateway = Arc::new(FakePaymentGateway::new(DonaOptionMethod::Paypal));
+        let checkout = gateway.create_checkout(&dona).await.unwrap();
+
+        let mut repository = MockDonaRepository::new();
+        repository.expect_find_by_id().times(0);
+        repository.expect_save().times(0);
+
+        let result = handler(repository, MockEventBus::new(), gateway)
+            .handle(command(checkout.reference))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_for_unknown_references() {
+        let gateway = Arc::new(FakePaymentGateway::new(DonaOptionMethod::Paypal));
+
+        let result = handler(MockDonaRepository::new(), MockEventBus::new(), gateway)
+            .handle(command("unknown".to_string()))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_UNKNOWN_FAKE_PAYMENT.to_string()))
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use time::OffsetDateTime;
+
+use crate::{
+    dona::{
+        application::confirm_payment::service::DonaPaymentConfirmer,
+        domain::payment_gateway::PaymentGatewayRegistry,
+    },
+    shared::domain::dona::DonaOptionMethod,
+};
+
+/// Asks the provider for a payment whose callback may have been lost.
+#[derive(Clone)]
+pub struct DonaPaymentRefresher {
+    gateways: PaymentGatewayRegistry,
+    confirmer: DonaPaymentConfirmer,
+}
+
+impl DonaPaymentRefresher {
+    pub fn new(gateways: PaymentGatewayRegistry, confirmer: DonaPaymentConfirmer) -> Self {
+        Self {
+            gateways,
+            confirmer,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        method: String,
+        reference: String,
+        refreshed_at: OffsetDateTime,
+    ) -> Result<(), String> {
+        let method = DonaOptionMethod::new(method)?;
+        let payment = self
+            .gateways
+            .get(&method)?
+            .lookup_payment(&reference)
+            .await?;
+
+        self.confirmer.confirm(&method, payment, refreshed_at).await
+    }
+}
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use shared::domain::{
+    bus::event::EventBus,
+    criteria::{
+        filter::{Filter, FilterField, FilterOperator, FilterValue},
+        Criteria,
+    },
+};
+use time::OffsetDateTime;
+
+use crate::dona::domain::{
+    dona::{Dona, DonaId, ERR_DONA_NOT_FOUND},
+    dona_repository::DonaRepository,
+};
+
+#[derive(Clone)]
+pub struct DonaRefunder {
+    repository: Arc<dyn DonaRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl DonaRefunder {
+    pub fn new(repository: Arc<dyn DonaRepository>, event_bus: Arc<dyn EventBus>) -> Self {
+        Self {
+            repository,
+            event_bus,
+        }
+    }
+
+    async fn dona_finder(&self, id: String, user_id: String) -> Result<Dona, String> {
+        let id = DonaId::new(id)?;
+
+        self.repository
+            .find_by_criteria(Criteria::new(
+                vec![
+                    Filter::new(
+                        FilterField::try_from("id".to_string()).unwrap(),
+                        FilterOperator::Equal,
+                        FilterValue::try_from(id.to_string())?,
+                    ),
+                    Filter::new(
+                        FilterField::try_from("user_id".to_string()).unwrap(),
+                        FilterOperator::Equal,
+                        FilterValue::try_from(user_id)?,
+                    ),
+                ],
+                None,
+                None,
+            ))
+            .await?
+            .pop()
+            .ok_or_else(|| ERR_DONA_NOT_FOUND.to_string())
+    }
+
+    pub async fn execute(
+        &self,
+        id: String,
+        user_id: String,
+        updated_at: OffsetDateTime,
+    ) -> Result<(), String> {
+        let mut dona = self.dona_finder(id, user_id).await?;
+        dona.refund(updated_at)?;
+
+        self.repository.save(&dona).await?;
+
+        self.event_bus.publish(dona.pull_events()).await?;
+
+        Ok(())
+    }
+}
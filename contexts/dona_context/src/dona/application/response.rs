@@ -0,0 +1,259 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use shared::domain::bus::query::Response;
+use time::OffsetDateTime;
+
+use crate::dona::domain::{
+    dona::Dona,
+    dona_import::{DonaImport, DonaImportRowError},
+    dona_reply::DonaReply,
+    dona_stats::{DonaStats, DonaStatsBucket, DonaStatsGranularity, DonaStatsRange},
+    payment_gateway::PaymentCheckout,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DonaResponse {
+    pub id: String,
+    pub msg: String,
+    pub msg_status: String,
+    pub msg_flags: Vec<String>,
+    pub amount: Decimal,
+    pub currency: String,
+    pub fee_amount: Option<Decimal>,
+    pub net_amount: Option<Decimal>,
+    pub status: String,
+    pub method: String,
+    pub user_id: String,
+    pub sender_id: Option<String>,
+    pub guest_name: Option<String>,
+    pub guest_email: Option<String>,
+    pub is_anonymous: bool,
+    pub campaign_id: Option<String>,
+    pub pledge_id: Option<String>,
+    pub tx_hash: Option<String>,
+    /// Only filled in when donas are listed.
+    pub reply: Option<DonaReplyResponse>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl From<Dona> for DonaResponse {
+    fn from(dona: Dona) -> Self {
+        Self {
+            id: dona.id(),
+            msg: dona.msg(),
+            msg_status: dona.msg_status(),
+            msg_flags: dona.msg_flags(),
+            amount: dona.amount(),
+            currency: dona.currency(),
+            fee_amount: dona.fee_amount(),
+            net_amount: dona.net_amount(),
+            status: dona.status(),
+            method: dona.method(),
+            user_id: dona.user_id(),
+            sender_id: dona.sender_id(),
+            guest_name: dona.guest_name(),
+            guest_email: dona.guest_email(),
+            is_anonymous: dona.is_anonymous(),
+            campaign_id: dona.campaign_id(),
+            pledge_id: dona.pledge_id(),
+            tx_hash: dona.tx_hash(),
+            reply: None,
+            created_at: dona.created_at(),
+            updated_at: dona.updated_at(),
+        }
+    }
+}
+
+impl Response for DonaResponse {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DonaReplyResponse {
+    pub id: String,
+    pub msg: String,
+    pub visibility: String,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl From<DonaReply> for DonaReplyResponse {
+    fn from(reply: DonaReply) -> Self {
+        Self {
+            id: reply.id(),
+            msg: reply.msg(),
+            visibility: reply.visibility(),
+            created_at: reply.created_at(),
+            updated_at: reply.updated_at(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DonaRepliesResponse {
+    pub replies: Vec<DonaReplyResponse>,
+    pub has_previous_page: bool,
+    pub has_next_page: bool,
+}
+
+impl Response for DonaRepliesResponse {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DonasResponse {
+    pub donas: Vec<DonaResponse>,
+    pub has_previous_page: bool,
+    pub has_next_page: bool,
+}
+
+impl Response for DonasResponse {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ReceivedTotalResponse {
+    pub total: Decimal,
+    pub currency: String,
+    pub count: u64,
+}
+
+impl Response for ReceivedTotalResponse {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DonaStatsBucketResponse {
+    pub key: String,
+    pub currency: String,
+    pub amount: Decimal,
+    pub fees: Decimal,
+    pub net_amount: Decimal,
+    pub count: u64,
+    pub average: Decimal,
+}
+
+impl From<DonaStatsBucket> for DonaStatsBucketResponse {
+    fn from(value: DonaStatsBucket) -> Self {
+        Self {
+            average: value.average(),
+            key: value.key,
+            currency: value.currency,
+            amount: value.amount,
+            fees: value.fees,
+            net_amount: value.net_amount,
+            count: value.count,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DonaStatsResponse {
+    pub from: OffsetDateTime,
+    pub to: OffsetDateTime,
+    pub granularity: String,
+    pub totals: Vec<DonaStatsBucketResponse>,
+    pub by_period: Vec<DonaStatsBucketResponse>,
+    pub by_method: Vec<DonaStatsBucketResponse>,
+    pub by_status: Vec<DonaStatsBucketResponse>,
+}
+
+impl DonaStatsResponse {
+    pub fn new(range: DonaStatsRange, granularity: DonaStatsGranularity, stats: DonaStats) -> Self {
+        let into_responses = |buckets: Vec<DonaStatsBucket>| {
+            buckets
+                .into_iter()
+                .map(DonaStatsBucketResponse::from)
+                .collect::<Vec<DonaStatsBucketResponse>>()
+        };
+
+        Self {
+            from: range.from(),
+            to: range.to(),
+            granularity: granularity.to_string(),
+            totals: into_responses(stats.totals),
+            by_period: into_responses(stats.by_period),
+            by_method: into_responses(stats.by_method),
+            by_status: into_responses(stats.by_status),
+        }
+    }
+}
+
+impl Response for DonaStatsResponse {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DonaImportRowErrorResponse {
+    pub line: u64,
+    pub error: String,
+}
+
+impl From<DonaImportRowError> for DonaImportRowErrorResponse {
+    fn from(value: DonaImportRowError) -> Self {
+        Self {
+            line: value.line,
+            error: value.error,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DonaImportReportResponse {
+    pub total_rows: u64,
+    pub valid_rows: u64,
+    pub errors: Vec<DonaImportRowErrorResponse>,
+}
+
+impl From<&DonaImport> for DonaImportReportResponse {
+    fn from(value: &DonaImport) -> Self {
+        Self {
+            total_rows: value.total_rows(),
+            valid_rows: value.donas().len() as u64,
+            errors: value
+                .errors()
+                .iter()
+                .cloned()
+                .map(DonaImportRowErrorResponse::from)
+                .collect(),
+        }
+    }
+}
+
+impl Response for DonaImportReportResponse {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DonaCheckoutResponse {
+    pub reference: String,
+    pub url: String,
+}
+
+impl From<PaymentCheckout> for DonaCheckoutResponse {
+    fn from(checkout: PaymentCheckout) -> Self {
+        Self {
+            reference: checkout.reference,
+            url: checkout.url,
+        }
+    }
+}
+
+impl Response for DonaCheckoutResponse {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
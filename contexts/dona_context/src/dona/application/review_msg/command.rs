@@ -0,0 +1,193 @@
+use shared::domain::bus::command::{Command, CommandError, CommandHandler};
+use time::OffsetDateTime;
+
+use super::service::DonaMsgReviewer;
+
+pub const REVIEW_DONA_MSG_COMMAND_TYPE: &str = "dona.review_dona_msg.command";
+
+#[derive(Debug)]
+pub struct ReviewDonaMsgCommand {
+    pub id: String,
+    pub user_id: String,
+    /// `approve` or `redact`.
+    pub decision: String,
+    pub updated_at: OffsetDateTime,
+}
+
+impl Command for ReviewDonaMsgCommand {
+    fn command_type(&self) -> &'static str {
+        REVIEW_DONA_MSG_COMMAND_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct ReviewDonaMsgCommandHandler {
+    service: DonaMsgReviewer,
+}
+
+impl ReviewDonaMsgCommandHandler {
+    pub fn new(service: DonaMsgReviewer) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler for ReviewDonaMsgCommandHandler {
+    async fn handle(&self, command: Box<dyn Command>) -> Result<(), CommandError> {
+        let command = command
+            .as_any()
+            .downcast_ref::<ReviewDonaMsgCommand>()
+            .ok_or_else(|| CommandError::new("Invalid command".to_string()))?;
+
+        self.service
+            .execute(
+                command.id.to_owned(),
+                command.user_id.to_owned(),
+                command.decision.to_owned(),
+                command.updated_at,
+            )
+            .await
+            .map_err(CommandError::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use shared::domain::bus::event::tests::MockEventBus;
+    use time::Duration;
+
+    use super::*;
+
+    use crate::dona::domain::dona::tests::DonaMother;
+    use crate::dona::domain::dona::{
+        Dona, ERR_DONA_MSG_NOT_IN_REVIEW, ERR_DONA_NOT_FOUND, ERR_INVALID_DONA_MSG_REVIEW_DECISION,
+        REDACTED_DONA_MSG,
+    };
+    use crate::dona::domain::dona_repository::tests::MockDonaRepository;
+
+    fn command_from(dona: &Dona, decision: &str) -> Box<ReviewDonaMsgCommand> {
+        Box::new(ReviewDonaMsgCommand {
+            id: dona.id(),
+            user_id: dona.user_id(),
+            decision: decision.to_string(),
+            updated_at: OffsetDateTime::now_utc() - Duration::seconds(1),
+        })
+    }
+
+    fn handler(
+        found: Vec<Dona>,
+        repository: MockDonaRepository,
+        publishes: usize,
+    ) -> ReviewDonaMsgCommandHandler {
+        let mut repository = repository;
+        repository.expect_find_by_criteria().return_const(Ok(found));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus
+            .expect_publish()
+            .withf(|events| events.len() == 1)
+            .times(publishes)
+            .return_const(Ok(()));
+
+        ReviewDonaMsgCommandHandler::new(DonaMsgReviewer::new(
+            Arc::new(repository),
+            Arc::new(event_bus),
+        ))
+    }
+
+    #[tokio::test]
+    async fn it_should_publish_approved_messages() {
+        let dona = DonaMother::in_review(None);
+        let msg = dona.msg();
+
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_save()
+            .withf(move |saved| saved.msg_status() == "published" && saved.msg() == msg)
+            .times(1)
+            .return_const(Ok(()));
+
+        let result = handler(vec![dona.clone()], repository, 1)
+            .handle(command_from(&dona, "approve"))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_replace_redacted_messages() {
+        let dona = DonaMother::in_review(None);
+
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_save()
+            .withf(|saved| saved.msg_status() == "redacted" && saved.msg() == REDACTED_DONA_MSG)
+            .times(1)
+            .return_const(Ok(()));
+
+        let result = handler(vec![dona.clone()], repository, 1)
+            .handle(command_from(&dona, "redact"))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_msg_is_not_in_review() {
+        let dona = DonaMother::random();
+
+        let mut repository = MockDonaRepository::new();
+        repository.expect_save().times(0);
+
+        let result = handler(vec![dona.clone()], repository, 0)
+            .handle(command_from(&dona, "redact"))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_DONA_MSG_NOT_IN_REVIEW.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_dona_is_not_of_the_user() {
+        let dona = DonaMother::in_review(None);
+
+        let mut repository = MockDonaRepository::new();
+        repository.expect_save().times(0);
+
+        let result = handler(vec![], repository, 0)
+            .handle(command_from(&dona, "approve"))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_DONA_NOT_FOUND.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_decision_is_unknown() {
+        let dona = DonaMother::in_review(None);
+
+        let mut repository = MockDonaRepository::new();
+        repository.expect_save().times(0);
+
+        let result = handler(vec![dona.clone()], repository, 0)
+            .handle(command_from(&dona, "IGNORE"))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(
+                ERR_INVALID_DONA_MSG_REVIEW_DECISION.to_string()
+            ))
+        );
+    }
+}
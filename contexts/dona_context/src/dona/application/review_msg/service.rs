@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use shared::domain::{
+    bus::event::EventBus,
+    criteria::{
+        filter::{Filter, FilterField, FilterOperator, FilterValue},
+        Criteria,
+    },
+};
+use time::OffsetDateTime;
+
+use crate::dona::domain::{
+    dona::{Dona, DonaId, DonaMsgReviewDecision, ERR_DONA_NOT_FOUND},
+    dona_repository::DonaRepository,
+};
+
+#[derive(Clone)]
+pub struct DonaMsgReviewer {
+    repository: Arc<dyn DonaRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl DonaMsgReviewer {
+    pub fn new(repository: Arc<dyn DonaRepository>, event_bus: Arc<dyn EventBus>) -> Self {
+        Self {
+            repository,
+            event_bus,
+        }
+    }
+
+    async fn dona_finder(&self, id: String, user_id: String) -> Result<Dona, String> {
+        let id = DonaId::new(id)?;
+
+        self.repository
+            .find_by_criteria(Criteria::new(
+                vec![
+                    Filter::new(
+                        FilterField::try_from("id".to_string()).unwrap(),
+                        FilterOperator::Equal,
+                        FilterValue::try_from(id.to_string())?,
+                    ),
+                    Filter::new(
+                        FilterField::try_from("user_id".to_string()).unwrap(),
+                        FilterOperator::Equal,
+                        FilterValue::try_from(user_id)?,
+                    ),
+                ],
+                None,
+                None,
+            ))
+            .await?
+            .pop()
+            .ok_or_else(|| ERR_DONA_NOT_FOUND.to_string())
+    }
+
+    /// `user_id` is the recipient of the dona, admins review on their behalf.
+    pub async fn execute(
+        &self,
+        id: String,
+        user_id: String,
+        decision: String,
+        updated_at: OffsetDateTime,
+    ) -> Result<(), String> {
+        let decision = DonaMsgReviewDecision::new(decision)?;
+        let mut dona = self.dona_finder(id, user_id).await?;
+        dona.review_msg(decision, updated_at)?;
+
+        self.repository.save(&dona).await?;
+
+        self.event_bus.publish(dona.pull_events()).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,211 @@
+use shared::domain::bus::query::{Query, QueryError, QueryHandler, Response};
+use time::OffsetDateTime;
+
+use super::service::DonaStatsCalculator;
+
+pub const GET_DONA_STATS_QUERY_TYPE: &str = "dona.get_dona_stats.query";
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GetDonaStatsQuery {
+    pub user_id: String,
+    pub from: Option<OffsetDateTime>,
+    pub to: Option<OffsetDateTime>,
+    pub granularity: String,
+}
+
+impl Query for GetDonaStatsQuery {
+    fn query_type(&self) -> &'static str {
+        GET_DONA_STATS_QUERY_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct GetDonaStatsQueryHandler {
+    service: DonaStatsCalculator,
+}
+
+impl GetDonaStatsQueryHandler {
+    pub fn new(service: DonaStatsCalculator) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler for GetDonaStatsQueryHandler {
+    async fn handle(&self, query: Box<dyn Query>) -> Result<Box<dyn Response>, QueryError> {
+        let query = query
+            .as_any()
+            .downcast_ref::<GetDonaStatsQuery>()
+            .ok_or_else(|| QueryError::new("Invalid query".to_string()))?;
+
+        let stats = self
+            .service
+            .execute(
+                query.user_id.to_owned(),
+                query.from,
+                query.to,
+                query.granularity.to_owned(),
+            )
+            .await
+            .map_err(QueryError::new)?;
+
+        Ok(Box::new(stats))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockall::predicate;
+    use rust_decimal_macros::dec;
+    use shared::domain::utils::new_uuid;
+    use shared::domain::value_objects::user_id::UserId;
+    use time::{macros::datetime, Duration};
+
+    use super::*;
+
+    use crate::dona::application::response::{DonaStatsBucketResponse, DonaStatsResponse};
+    use crate::dona::domain::dona_repository::tests::MockDonaRepository;
+    use crate::dona::domain::dona_stats::{
+        DonaStats, DonaStatsBucket, DonaStatsGranularity, DonaStatsRange,
+        ERR_INVALID_DONA_STATS_GRANULARITY, ERR_INVALID_DONA_STATS_RANGE,
+    };
+
+    fn handler(repository: MockDonaRepository) -> GetDonaStatsQueryHandler {
+        GetDonaStatsQueryHandler::new(DonaStatsCalculator::new(Arc::new(repository)))
+    }
+
+    #[tokio::test]
+    async fn it_should_return_stats_with_averages() {
+        let user_id = new_uuid();
+        let from = datetime!(2024-03-01 00:00 UTC);
+        let to = datetime!(2024-04-01 00:00 UTC);
+        let totals = vec![
+            DonaStatsBucket {
+                key: "JPY".to_string(),
+                currency: "JPY".to_string(),
+                amount: dec!(1000),
+                fees: dec!(30),
+                net_amount: dec!(970),
+                count: 3,
+            },
+            DonaStatsBucket {
+                key: "USD".to_string(),
+                currency: "USD".to_string(),
+                amount: dec!(10.00),
+                fees: dec!(0),
+                net_amount: dec!(10.00),
+                count: 3,
+            },
+        ];
+
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_stats()
+            .with(
+                predicate::eq(UserId::new(user_id.clone()).unwrap()),
+                predicate::eq(DonaStatsRange::new(from, to).unwrap()),
+                predicate::eq(DonaStatsGranularity::Month),
+            )
+            .times(1)
+            .return_const(Ok(DonaStats {
+                totals,
+                ..Default::default()
+            }));
+
+        let response = handler(repository)
+            .handle(Box::new(GetDonaStatsQuery {
+                user_id,
+                from: Some(from),
+                to: Some(to),
+                granularity: "month".to_string(),
+            }))
+            .await
+            .unwrap();
+        let response = response
+            .as_any()
+            .downcast_ref::<DonaStatsResponse>()
+            .unwrap()
+            .to_owned();
+
+        assert_eq!(
+            response.totals,
+            vec![
+                DonaStatsBucketResponse {
+                    key: "JPY".to_string(),
+                    currency: "JPY".to_string(),
+                    amount: dec!(1000),
+                    fees: dec!(30),
+                    net_amount: dec!(970),
+                    count: 3,
+                    average: dec!(333),
+                },
+                DonaStatsBucketResponse {
+                    key: "USD".to_string(),
+                    currency: "USD".to_string(),
+                    amount: dec!(10.00),
+                    fees: dec!(0),
+                    net_amount: dec!(10.00),
+                    count: 3,
+                    average: dec!(3.33),
+                },
+            ]
+        );
+        assert_eq!(response.granularity, "month");
+        assert!(response.by_period.is_empty());
+    }
+
+    #[tokio::test]
+    async fn it_should_default_to_the_last_days() {
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_stats()
+            .withf(|_, range, _| range.to() - range.from() == Duration::days(30))
+            .times(1)
+            .return_const(Ok(DonaStats::default()));
+
+        let result = handler(repository)
+            .handle(Box::new(GetDonaStatsQuery {
+                user_id: new_uuid(),
+                from: None,
+                to: None,
+                granularity: "day".to_string(),
+            }))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_with_an_invalid_range_or_granularity() {
+        let from = datetime!(2024-03-01 00:00 UTC);
+
+        for (to, granularity, error) in [
+            (from, "day", ERR_INVALID_DONA_STATS_RANGE),
+            (
+                from + Duration::days(1),
+                "year",
+                ERR_INVALID_DONA_STATS_GRANULARITY,
+            ),
+        ] {
+            let mut repository = MockDonaRepository::new();
+            repository.expect_stats().times(0);
+
+            let result = handler(repository)
+                .handle(Box::new(GetDonaStatsQuery {
+                    user_id: new_uuid(),
+                    from: Some(from),
+                    to: Some(to),
+                    granularity: granularity.to_string(),
+                }))
+                .await;
+
+            assert_eq!(result.err(), Some(QueryError::new(error.to_string())));
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use shared::domain::value_objects::user_id::UserId;
+use time::{Duration, OffsetDateTime};
+
+use crate::dona::{
+    application::response::DonaStatsResponse,
+    domain::{
+        dona_repository::DonaRepository,
+        dona_stats::{DonaStatsGranularity, DonaStatsRange},
+    },
+};
+
+pub const DEFAULT_DONA_STATS_DAYS: i64 = 30;
+
+#[derive(Clone)]
+pub struct DonaStatsCalculator {
+    repository: Arc<dyn DonaRepository>,
+}
+
+impl DonaStatsCalculator {
+    pub fn new(repository: Arc<dyn DonaRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Without a range it covers the last `DEFAULT_DONA_STATS_DAYS` days up to now.
+    pub async fn execute(
+        &self,
+        user_id: String,
+        from: Option<OffsetDateTime>,
+        to: Option<OffsetDateTime>,
+        granularity: String,
+    ) -> Result<DonaStatsResponse, String> {
+        let user_id = UserId::new(user_id)?;
+        let granularity = DonaStatsGranularity::new(granularity)?;
+        let to = to.unwrap_or_else(OffsetDateTime::now_utc);
+        let from = from.unwrap_or(to - Duration::days(DEFAULT_DONA_STATS_DAYS));
+        let range = DonaStatsRange::new(from, to)?;
+
+        let stats = self.repository.stats(user_id, range, granularity).await?;
+
+        Ok(DonaStatsResponse::new(range, granularity, stats))
+    }
+}
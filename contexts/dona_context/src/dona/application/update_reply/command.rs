@@ -0,0 +1,170 @@
+use shared::domain::bus::command::{Command, CommandError, CommandHandler};
+use time::OffsetDateTime;
+
+use super::service::DonaReplyUpdater;
+
+pub const UPDATE_DONA_REPLY_COMMAND_TYPE: &str = "dona.update_dona_reply.command";
+
+#[derive(Debug)]
+pub struct UpdateDonaReplyCommand {
+    pub id: String,
+    pub user_id: String,
+    pub msg: String,
+    /// `PRIVATE` or `PUBLIC`.
+    pub visibility: String,
+    pub updated_at: OffsetDateTime,
+}
+
+impl Command for UpdateDonaReplyCommand {
+    fn command_type(&self) -> &'static str {
+        UPDATE_DONA_REPLY_COMMAND_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct UpdateDonaReplyCommandHandler {
+    service: DonaReplyUpdater,
+}
+
+impl UpdateDonaReplyCommandHandler {
+    pub fn new(service: DonaReplyUpdater) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler for UpdateDonaReplyCommandHandler {
+    async fn handle(&self, command: Box<dyn Command>) -> Result<(), CommandError> {
+        let command = command
+            .as_any()
+            .downcast_ref::<UpdateDonaReplyCommand>()
+            .ok_or_else(|| CommandError::new("Invalid command".to_string()))?;
+
+        self.service
+            .execute(
+                command.id.to_owned(),
+                command.user_id.to_owned(),
+                command.msg.to_owned(),
+                command.visibility.to_owned(),
+                command.updated_at,
+            )
+            .await
+            .map_err(CommandError::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use shared::domain::{
+        base_errors::BaseRepositoryError, bus::event::tests::MockEventBus, utils::new_uuid,
+    };
+    use time::Duration;
+
+    use super::*;
+
+    use crate::dona::domain::dona_reply::tests::DonaReplyMother;
+    use crate::dona::domain::dona_reply::{
+        DonaReply, ERR_DONA_REPLY_NOT_FOUND, ERR_INVALID_DONA_REPLY_VISIBILITY,
+    };
+    use crate::dona::domain::dona_reply_repository::tests::MockDonaReplyRepository;
+
+    fn command_from(
+        reply: &DonaReply,
+        user_id: String,
+        visibility: &str,
+    ) -> Box<UpdateDonaReplyCommand> {
+        Box::new(UpdateDonaReplyCommand {
+            id: reply.id(),
+            user_id,
+            msg: "Thanks again!".to_string(),
+            visibility: visibility.to_string(),
+            updated_at: OffsetDateTime::now_utc() - Duration::seconds(1),
+        })
+    }
+
+    fn handler(
+        found: Result<DonaReply, BaseRepositoryError>,
+        saves: usize,
+    ) -> UpdateDonaReplyCommandHandler {
+        let mut repository = MockDonaReplyRepository::new();
+        repository.expect_find_by_id().return_const(found);
+        repository
+            .expect_save()
+            .withf(|saved| saved.msg() == "Thanks again!" && saved.visibility() == "PUBLIC")
+            .times(saves)
+            .return_const(Ok(()));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus
+            .expect_publish()
+            .withf(|events| events.len() == 1)
+            .times(saves)
+            .return_const(Ok(()));
+
+        UpdateDonaReplyCommandHandler::new(DonaReplyUpdater::new(
+            Arc::new(repository),
+            Arc::new(event_bus),
+        ))
+    }
+
+    #[tokio::test]
+    async fn it_should_update_the_reply() {
+        let reply = DonaReplyMother::random();
+
+        let result = handler(Ok(reply.clone()), 1)
+            .handle(command_from(&reply, reply.user_id(), "PUBLIC"))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_reply_is_not_of_the_user() {
+        let reply = DonaReplyMother::random();
+
+        let result = handler(Ok(reply.clone()), 0)
+            .handle(command_from(&reply, new_uuid(), "PUBLIC"))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_DONA_REPLY_NOT_FOUND.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_reply_does_not_exist() {
+        let reply = DonaReplyMother::random();
+
+        let result = handler(Err(BaseRepositoryError::NotFound), 0)
+            .handle(command_from(&reply, reply.user_id(), "PUBLIC"))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_DONA_REPLY_NOT_FOUND.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_visibility_is_unknown() {
+        let reply = DonaReplyMother::random();
+
+        let result = handler(Ok(reply.clone()), 0)
+            .handle(command_from(&reply, reply.user_id(), "FRIENDS"))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(
+                ERR_INVALID_DONA_REPLY_VISIBILITY.to_string()
+            ))
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use shared::domain::{base_errors::BaseRepositoryError, bus::event::EventBus};
+use time::OffsetDateTime;
+
+use crate::dona::domain::{
+    dona_reply::{DonaReply, DonaReplyId, ERR_DONA_REPLY_NOT_FOUND},
+    dona_reply_repository::DonaReplyRepository,
+};
+
+#[derive(Clone)]
+pub struct DonaReplyUpdater {
+    repository: Arc<dyn DonaReplyRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl DonaReplyUpdater {
+    pub fn new(repository: Arc<dyn DonaReplyRepository>, event_bus: Arc<dyn EventBus>) -> Self {
+        Self {
+            repository,
+            event_bus,
+        }
+    }
+
+    /// Replies of other recipients are reported as missing, not as forbidden.
+    async fn reply_finder(&self, id: String, user_id: String) -> Result<DonaReply, String> {
+        let reply = self
+            .repository
+            .find_by_id(DonaReplyId::new(id)?)
+            .await
+            .map_err(|e| match e {
+                BaseRepositoryError::NotFound => ERR_DONA_REPLY_NOT_FOUND.to_string(),
+                e => e.to_string(),
+            })?;
+
+        if !reply.belongs_to(&user_id) {
+            return Err(ERR_DONA_REPLY_NOT_FOUND.to_string());
+        }
+
+        Ok(reply)
+    }
+
+    pub async fn execute(
+        &self,
+        id: String,
+        user_id: String,
+        msg: String,
+        visibility: String,
+        updated_at: OffsetDateTime,
+    ) -> Result<(), String> {
+        let mut reply = self.reply_finder(id, user_id).await?;
+        reply.update(msg, visibility, updated_at)?;
+
+        self.repository.save(&reply).await?;
+
+        self.event_bus.publish(reply.pull_events()).await?;
+
+        Ok(())
+    }
+}
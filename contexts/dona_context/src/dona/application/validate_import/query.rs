@@ -0,0 +1,149 @@
+use shared::domain::bus::query::{Query, QueryError, QueryHandler, Response};
+
+use super::service::DonaImportValidator;
+
+pub const VALIDATE_DONA_IMPORT_QUERY_TYPE: &str = "dona.validate_dona_import.query";
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ValidateDonaImportQuery {
+    pub user_id: String,
+    pub csv: String,
+}
+
+impl Query for ValidateDonaImportQuery {
+    fn query_type(&self) -> &'static str {
+        VALIDATE_DONA_IMPORT_QUERY_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct ValidateDonaImportQueryHandler {
+    service: DonaImportValidator,
+}
+
+impl ValidateDonaImportQueryHandler {
+    pub fn new(service: DonaImportValidator) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler for ValidateDonaImportQueryHandler {
+    async fn handle(&self, query: Box<dyn Query>) -> Result<Box<dyn Response>, QueryError> {
+        let query = query
+            .as_any()
+            .downcast_ref::<ValidateDonaImportQuery>()
+            .ok_or_else(|| QueryError::new("Invalid query".to_string()))?;
+
+        let report = self
+            .service
+            .execute(query.user_id.to_owned(), query.csv.to_owned())
+            .await
+            .map_err(QueryError::new)?;
+
+        Ok(Box::new(report))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use shared::domain::{utils::new_uuid, value_objects::money::ERR_INVALID_CURRENCY};
+
+    use super::*;
+
+    use crate::dona::application::response::{
+        DonaImportReportResponse, DonaImportRowErrorResponse,
+    };
+    use crate::dona::domain::dona::{
+        ERR_INVALID_DONA_AMOUNT, ERR_INVALID_DONA_CREATED_AT, ERR_INVALID_DONA_GUEST_EMAIL,
+        ERR_INVALID_DONA_GUEST_NAME, ERR_INVALID_DONA_MSG,
+    };
+    use crate::dona::domain::dona_import::{
+        DONA_IMPORT_MAX_ROWS, ERR_DONA_IMPORT_TOO_MANY_ROWS, ERR_EMPTY_DONA_IMPORT_CSV,
+    };
+    use crate::dona::domain::dona_msg_policy::DonaMsgPolicy;
+
+    fn handler() -> ValidateDonaImportQueryHandler {
+        ValidateDonaImportQueryHandler::new(DonaImportValidator::new(DonaMsgPolicy::default()))
+    }
+
+    fn query(csv: String) -> Box<dyn Query> {
+        Box::new(ValidateDonaImportQuery {
+            user_id: new_uuid(),
+            csv,
+        })
+    }
+
+    #[tokio::test]
+    async fn it_should_report_every_invalid_row() {
+        let csv = [
+            "date,amount,currency,donor_name,donor_email,message",
+            // A quoted message spanning two lines moves every row below it one line down
+            "2023-11-02,25.00,USD,Ana,,\"Cash at the show,\nthank you!\"",
+            "2023-11-02,0,USD,Ana,,Nothing",
+            "2023-11-02,abc,USD,Ana,,Not a number",
+            "2023-11-02,5,XXX,Ana,,Unknown currency",
+            "02/11/2023,5,USD,Ana,,Other date format",
+            "2999-01-01,5,USD,Ana,,From the future",
+            "2023-11-02,5,USD, ,,No donor",
+            "2023-11-02,5,USD,Ana,not-an-email,Bad email",
+            "2023-11-02,5,USD,Ana,,",
+            "2023-11-02,5,USD",
+        ]
+        .join("\n");
+
+        let response = handler().handle(query(csv)).await.unwrap();
+        let response = response
+            .as_any()
+            .downcast_ref::<DonaImportReportResponse>()
+            .unwrap()
+            .to_owned();
+
+        let error = |line: u64, error: &str| DonaImportRowErrorResponse {
+            line,
+            error: error.to_string(),
+        };
+        assert_eq!(
+            response,
+            DonaImportReportResponse {
+                total_rows: 10,
+                valid_rows: 1,
+                errors: vec![
+                    error(4, ERR_INVALID_DONA_AMOUNT),
+                    error(5, ERR_INVALID_DONA_AMOUNT),
+                    error(6, ERR_INVALID_CURRENCY),
+                    error(7, ERR_INVALID_DONA_CREATED_AT),
+                    error(8, ERR_INVALID_DONA_CREATED_AT),
+                    error(9, ERR_INVALID_DONA_GUEST_NAME),
+                    error(10, ERR_INVALID_DONA_GUEST_EMAIL),
+                    error(11, ERR_INVALID_DONA_MSG),
+                    error(12, "Expected 6 columns, found 3"),
+                ],
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_the_file_has_no_rows_or_too_many() {
+        let header = "date,amount,currency,donor_name,donor_email,message\n";
+        let too_many = format!(
+            "{}{}",
+            header,
+            "2023-11-02,1,USD,Ana,,Hi\n".repeat(DONA_IMPORT_MAX_ROWS + 1)
+        );
+
+        for (csv, error) in [
+            (String::new(), ERR_EMPTY_DONA_IMPORT_CSV),
+            (header.to_string(), ERR_EMPTY_DONA_IMPORT_CSV),
+            (too_many, ERR_DONA_IMPORT_TOO_MANY_ROWS),
+        ] {
+            let result = handler().handle(query(csv)).await;
+
+            assert_eq!(result.err(), Some(QueryError::new(error.to_string())));
+        }
+    }
+}
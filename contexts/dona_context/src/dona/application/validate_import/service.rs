@@ -0,0 +1,34 @@
+use time::OffsetDateTime;
+
+use crate::dona::{
+    application::response::DonaImportReportResponse,
+    domain::{dona_import::DonaImport, dona_msg_policy::DonaMsgPolicy},
+};
+
+#[derive(Clone)]
+pub struct DonaImportValidator {
+    msg_policy: DonaMsgPolicy,
+}
+
+impl DonaImportValidator {
+    pub fn new(msg_policy: DonaMsgPolicy) -> Self {
+        Self { msg_policy }
+    }
+
+    /// Reads the file like `DonasImporter` does without saving anything. Messages withheld by
+    /// the recipient settings are still imported, so the report always moderates them.
+    pub async fn execute(
+        &self,
+        user_id: String,
+        csv: String,
+    ) -> Result<DonaImportReportResponse, String> {
+        let import = DonaImport::parse(
+            &csv,
+            user_id,
+            OffsetDateTime::now_utc(),
+            Some(&self.msg_policy),
+        )?;
+
+        Ok(DonaImportReportResponse::from(&import))
+    }
+}
@@ -2,16 +2,29 @@ use std::fmt::Display;
 use std::sync::Arc;
 
 use rust_decimal::Decimal;
-use shared::domain::{bus::event::Event, utils::is_uuid, value_objects::user_id::UserId};
+use shared::domain::{
+    bus::event::Event,
+    utils::is_uuid,
+    value_objects::{money::Money, user_id::UserId},
+};
 use time::{format_description::well_known::Rfc3339, OffsetDateTime};
 
-use crate::shared::domain::dona::DonaOptionMethod;
+use crate::{
+    campaign::domain::campaign::CampaignId, pledge::domain::pledge::PledgeId,
+    shared::domain::dona::DonaOptionMethod,
+};
 
 use super::{
-    dona_confirmed_event::DonaConfirmedEvent, dona_created_event::DonaCreatedEvent,
+    dona_cancelled_event::DonaCancelledEvent, dona_confirmed_event::DonaConfirmedEvent,
+    dona_created_event::DonaCreatedEvent, dona_deleted_event::DonaDeletedEvent,
+    dona_expired_event::DonaExpiredEvent, dona_msg_flagged_event::DonaMsgFlaggedEvent,
+    dona_msg_reviewed_event::DonaMsgReviewedEvent, dona_refunded_event::DonaRefundedEvent,
     dona_rejected_event::DonaRejectedEvent,
 };
 
+pub const ERR_DONA_NOT_FOUND: &str = "Dona not found";
+pub const ERR_DONA_MSG_NOT_IN_REVIEW: &str = "The message of the dona is not waiting for review";
+
 pub const ERR_INVALID_DONA_ID: &str = "Invalid Dona ID";
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
@@ -35,6 +48,12 @@ impl Display for DonaId {
 
 pub const ERR_INVALID_DONA_MSG: &str = "Invalid Dona Message";
 
+/// Longest message a donor can send, checked by the message policy when a dona is created so
+/// older and imported donas still load.
+pub const DONA_MSG_MAX_LENGTH: usize = 500;
+/// Replaces the message of a dona whose message was redacted after review.
+pub const REDACTED_DONA_MSG: &str = "[redacted]";
+
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub struct DonaMsg(String);
 
@@ -54,14 +73,216 @@ impl Display for DonaMsg {
     }
 }
 
+pub const ERR_INVALID_DONA_MSG_STATUS: &str = "Invalid Dona Message Status";
+
+/// Flagged messages wait in review and must not be shown until they are published.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum DonaMsgStatus {
+    Published,
+    InReview,
+    Redacted,
+}
+
+impl DonaMsgStatus {
+    pub fn new(value: String) -> Result<Self, String> {
+        match value.as_str() {
+            "published" => Ok(Self::Published),
+            "in_review" => Ok(Self::InReview),
+            "redacted" => Ok(Self::Redacted),
+            _ => Err(ERR_INVALID_DONA_MSG_STATUS.to_string()),
+        }
+    }
+}
+
+impl Display for DonaMsgStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Published => write!(f, "published"),
+            Self::InReview => write!(f, "in_review"),
+            Self::Redacted => write!(f, "redacted"),
+        }
+    }
+}
+
+pub const ERR_INVALID_DONA_MSG_FLAG: &str = "Invalid Dona Message Flag";
+
+/// Why a message was sent to review.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum DonaMsgFlag {
+    BannedWord,
+    Link,
+}
+
+impl DonaMsgFlag {
+    pub fn new(value: String) -> Result<Self, String> {
+        match value.as_str() {
+            "banned_word" => Ok(Self::BannedWord),
+            "link" => Ok(Self::Link),
+            _ => Err(ERR_INVALID_DONA_MSG_FLAG.to_string()),
+        }
+    }
+}
+
+impl Display for DonaMsgFlag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BannedWord => write!(f, "banned_word"),
+            Self::Link => write!(f, "link"),
+        }
+    }
+}
+
+pub const ERR_INVALID_DONA_MSG_REVIEW_DECISION: &str = "Invalid Dona Message Review Decision";
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum DonaMsgReviewDecision {
+    Approve,
+    Redact,
+}
+
+impl DonaMsgReviewDecision {
+    pub fn new(value: String) -> Result<Self, String> {
+        match value.as_str() {
+            "approve" => Ok(Self::Approve),
+            "redact" => Ok(Self::Redact),
+            _ => Err(ERR_INVALID_DONA_MSG_REVIEW_DECISION.to_string()),
+        }
+    }
+}
+
+/// What became of the message of a new dona, once it went through the
+/// [`DonaMsgPolicy`](super::dona_msg_policy::DonaMsgPolicy) or the recipient settings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DonaMsgModeration {
+    /// Published as written, or held in review when there are flags.
+    Checked(Vec<DonaMsgFlag>),
+    /// Dropped, because the recipient does not take messages.
+    Withheld,
+}
+
+pub const ERR_INVALID_DONA_GUEST_NAME: &str = "Invalid Dona Guest Name";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct DonaGuestName(String);
+
+impl DonaGuestName {
+    pub fn new(value: String) -> Result<Self, String> {
+        let value = value.trim().to_string();
+
+        if !value.is_empty() && value.chars().count() <= 100 {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_DONA_GUEST_NAME.to_string())
+        }
+    }
+}
+
+impl Display for DonaGuestName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub const ERR_INVALID_DONA_GUEST_EMAIL: &str = "Invalid Dona Guest Email";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct DonaGuestEmail(String);
+
+impl DonaGuestEmail {
+    pub fn new(value: String) -> Result<Self, String> {
+        let is_valid = match value.split_once('@') {
+            Some((local, domain)) => {
+                !local.is_empty()
+                    && domain.contains('.')
+                    && !domain.starts_with('.')
+                    && !domain.ends_with('.')
+                    && !value.contains(char::is_whitespace)
+            }
+            None => false,
+        };
+
+        if is_valid {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_DONA_GUEST_EMAIL.to_string())
+        }
+    }
+}
+
+impl Display for DonaGuestEmail {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub const ERR_INVALID_DONA_SENDER: &str =
+    "A dona must come from either a registered user or a guest donor";
+
+/// Who made the dona: a registered user, or a guest who only left a display name and,
+/// optionally, an email.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum DonaSender {
+    User(UserId),
+    Guest {
+        name: DonaGuestName,
+        email: Option<DonaGuestEmail>,
+    },
+}
+
+impl DonaSender {
+    pub fn new(
+        sender_id: Option<String>,
+        guest_name: Option<String>,
+        guest_email: Option<String>,
+    ) -> Result<Self, String> {
+        match (sender_id, guest_name, guest_email) {
+            (Some(sender_id), None, None) => Ok(Self::User(UserId::new(sender_id)?)),
+            (None, Some(name), email) => Ok(Self::Guest {
+                name: DonaGuestName::new(name)?,
+                email: email.map(DonaGuestEmail::new).transpose()?,
+            }),
+            _ => Err(ERR_INVALID_DONA_SENDER.to_string()),
+        }
+    }
+}
+
+pub const ERR_INVALID_DONA_TX_HASH: &str = "Invalid Dona Transaction Hash";
+pub const ERR_DONA_TX_HASH_NOT_ALLOWED: &str = "Only crypto Donas have a transaction hash";
+
+/// Hash of the transaction a donor sent a crypto dona with. The format depends on the network,
+/// which the dona does not know, so it is checked against the recipient wallet on creation.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct DonaTxHash(String);
+
+impl DonaTxHash {
+    pub fn new(value: String) -> Result<Self, String> {
+        let value = value.trim().to_string();
+
+        if !value.is_empty()
+            && value.len() <= 100
+            && value.chars().all(|c| c.is_ascii_alphanumeric())
+        {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_DONA_TX_HASH.to_string())
+        }
+    }
+}
+
+impl Display for DonaTxHash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 pub const ERR_INVALID_DONA_AMOUNT: &str = "Invalid Dona Amount";
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
-pub struct DonaAmount(Decimal);
+pub struct DonaAmount(Money);
 
 impl DonaAmount {
-    pub fn new(value: Decimal) -> Result<Self, String> {
-        if value > Decimal::ZERO {
+    pub fn new(value: Money) -> Result<Self, String> {
+        if value.amount() > Decimal::ZERO {
             Ok(Self(value))
         } else {
             Err(ERR_INVALID_DONA_AMOUNT.to_string())
@@ -75,6 +296,33 @@ impl Display for DonaAmount {
     }
 }
 
+pub const ERR_INVALID_DONA_FEE: &str = "Invalid Dona Fee";
+pub const ERR_DONA_FEE_ALREADY_APPLIED: &str = "The fee of the dona was already applied";
+pub const ERR_DONA_FEE_NOT_ALLOWED: &str = "Only confirmed donas have a fee";
+
+/// What the platform keeps from a dona, in the currency of the dona and never above it.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct DonaFee(Money);
+
+impl DonaFee {
+    pub fn new(value: Money, amount: &DonaAmount) -> Result<Self, String> {
+        if value.amount() >= Decimal::ZERO
+            && value.currency() == amount.0.currency()
+            && value.amount() <= amount.0.amount()
+        {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_DONA_FEE.to_string())
+        }
+    }
+}
+
+impl Display for DonaFee {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 pub const ERR_INVALID_DONA_STATUS: &str = "Invalid Dona Status";
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
@@ -82,6 +330,9 @@ pub enum DonaStatus {
     Pending,
     Confirmed,
     Rejected,
+    Cancelled,
+    Refunded,
+    Expired,
 }
 
 impl DonaStatus {
@@ -90,9 +341,23 @@ impl DonaStatus {
             "pending" => Ok(Self::Pending),
             "confirmed" => Ok(Self::Confirmed),
             "rejected" => Ok(Self::Rejected),
+            "cancelled" => Ok(Self::Cancelled),
+            "refunded" => Ok(Self::Refunded),
+            "expired" => Ok(Self::Expired),
             _ => Err(ERR_INVALID_DONA_STATUS.to_string()),
         }
     }
+
+    pub fn can_transition_to(&self, next: &DonaStatus) -> bool {
+        matches!(
+            (self, next),
+            (Self::Pending, Self::Confirmed)
+                | (Self::Pending, Self::Rejected)
+                | (Self::Pending, Self::Cancelled)
+                | (Self::Pending, Self::Expired)
+                | (Self::Confirmed, Self::Refunded)
+        )
+    }
 }
 
 impl Display for DonaStatus {
@@ -101,10 +366,15 @@ impl Display for DonaStatus {
             Self::Pending => write!(f, "pending"),
             Self::Confirmed => write!(f, "confirmed"),
             Self::Rejected => write!(f, "rejected"),
+            Self::Cancelled => write!(f, "cancelled"),
+            Self::Refunded => write!(f, "refunded"),
+            Self::Expired => write!(f, "expired"),
         }
     }
 }
 
+pub const ERR_INVALID_DONA_STATUS_TRANSITION: &str = "Invalid Dona Status Transition";
+
 pub const ERR_INVALID_DONA_CREATED_AT: &str = "Invalid Dona Created At";
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
@@ -151,11 +421,18 @@ impl Display for DonaUpdatedAt {
 pub struct Dona {
     id: DonaId,
     msg: DonaMsg,
+    msg_status: DonaMsgStatus,
+    msg_flags: Vec<DonaMsgFlag>,
     amount: DonaAmount,
+    fee: Option<DonaFee>,
     status: DonaStatus,
     method: DonaOptionMethod,
+    tx_hash: Option<DonaTxHash>,
     user_id: UserId,
-    sender_id: UserId,
+    sender: DonaSender,
+    is_anonymous: bool,
+    campaign_id: Option<CampaignId>,
+    pledge_id: Option<PledgeId>,
     created_at: DonaCreatedAt,
     updated_at: DonaUpdatedAt,
 
@@ -166,11 +443,18 @@ impl PartialEq for Dona {
     fn eq(&self, other: &Self) -> bool {
         self.id == other.id
             && self.msg == other.msg
+            && self.msg_status == other.msg_status
+            && self.msg_flags == other.msg_flags
             && self.amount == other.amount
+            && self.fee == other.fee
             && self.status == other.status
             && self.method == other.method
+            && self.tx_hash == other.tx_hash
             && self.user_id == other.user_id
-            && self.sender_id == other.sender_id
+            && self.sender == other.sender
+            && self.is_anonymous == other.is_anonymous
+            && self.campaign_id == other.campaign_id
+            && self.pledge_id == other.pledge_id
             && self.created_at == other.created_at
             && self.updated_at == other.updated_at
     }
@@ -188,85 +472,251 @@ impl Display for Dona {
     }
 }
 
+/// The primitives a [`Dona`] is built from, named so that callers can't swap two of them.
+#[derive(Debug, Clone)]
+pub struct NewDona {
+    pub id: String,
+    pub msg: String,
+    pub amount: Decimal,
+    pub currency: String,
+    pub status: String,
+    pub method: String,
+    pub user_id: String,
+    /// `None` for guest donas, which carry `guest_name` instead.
+    pub sender_id: Option<String>,
+    pub guest_name: Option<String>,
+    pub guest_email: Option<String>,
+    pub is_anonymous: bool,
+    pub campaign_id: Option<String>,
+    pub pledge_id: Option<String>,
+    /// Required for crypto donas, rejected for the others.
+    pub tx_hash: Option<String>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
 impl Dona {
-    pub(crate) fn new(
-        id: String,
-        msg: String,
-        amount: Decimal,
-        status: String,
-        method: String,
-        user_id: String,
-        sender_id: String,
-        created_at: OffsetDateTime,
-        updated_at: OffsetDateTime,
-    ) -> Result<Self, String> {
+    pub(crate) fn new(value: NewDona) -> Result<Self, String> {
+        let method = DonaOptionMethod::new(value.method)?;
+        let tx_hash = value.tx_hash.map(DonaTxHash::new).transpose()?;
+        if tx_hash.is_some() && method != DonaOptionMethod::Crypto {
+            return Err(ERR_DONA_TX_HASH_NOT_ALLOWED.to_string());
+        }
+
         Ok(Self {
-            id: DonaId::new(id)?,
-            msg: DonaMsg::new(msg)?,
-            amount: DonaAmount::new(amount)?,
-            status: DonaStatus::new(status)?,
-            method: DonaOptionMethod::new(method)?,
-            user_id: UserId::new(user_id)?,
-            sender_id: UserId::new(sender_id)?,
-            created_at: DonaCreatedAt::new(created_at)?,
-            updated_at: DonaUpdatedAt::new(updated_at)?,
+            id: DonaId::new(value.id)?,
+            msg: DonaMsg::new(value.msg)?,
+            msg_status: DonaMsgStatus::Published,
+            msg_flags: vec![],
+            amount: DonaAmount::new(Money::new(value.amount, value.currency)?)?,
+            fee: None,
+            status: DonaStatus::new(value.status)?,
+            method,
+            tx_hash,
+            user_id: UserId::new(value.user_id)?,
+            sender: DonaSender::new(value.sender_id, value.guest_name, value.guest_email)?,
+            is_anonymous: value.is_anonymous,
+            campaign_id: value.campaign_id.map(CampaignId::new).transpose()?,
+            pledge_id: value.pledge_id.map(PledgeId::new).transpose()?,
+            created_at: DonaCreatedAt::new(value.created_at)?,
+            updated_at: DonaUpdatedAt::new(value.updated_at)?,
             events: vec![],
         })
     }
 
-    pub fn create(
-        id: String,
-        msg: String,
-        amount: Decimal,
-        status: String,
-        method: String,
-        user_id: String,
-        sender_id: String,
-        created_at: OffsetDateTime,
-        updated_at: OffsetDateTime,
-    ) -> Result<Self, String> {
-        let mut dona = Self::new(
-            id.clone(),
-            msg.clone(),
-            amount,
-            status,
-            method,
-            user_id.clone(),
-            sender_id.clone(),
-            created_at,
-            updated_at,
-        )?;
+    pub fn create(value: NewDona, msg_moderation: DonaMsgModeration) -> Result<Self, String> {
+        let mut dona = Self::new(value)?;
+        let msg_flags = match msg_moderation {
+            DonaMsgModeration::Checked(flags) => flags,
+            DonaMsgModeration::Withheld => {
+                dona.msg = DonaMsg(REDACTED_DONA_MSG.to_string());
+                dona.msg_status = DonaMsgStatus::Redacted;
+                vec![]
+            }
+        };
+        if !msg_flags.is_empty() {
+            dona.msg_status = DonaMsgStatus::InReview;
+            dona.msg_flags = msg_flags;
+        }
 
         let event = DonaCreatedEvent::new(
-            id,
-            msg,
-            amount.to_string(),
-            user_id,
-            sender_id,
-            created_at.to_string(),
-            updated_at.to_string(),
+            dona.id(),
+            dona.msg(),
+            dona.msg_status(),
+            dona.amount().to_string(),
+            dona.currency(),
+            dona.user_id(),
+            dona.sender_id().unwrap_or_default(),
+            dona.guest_name().unwrap_or_default(),
+            dona.guest_email().unwrap_or_default(),
+            dona.is_anonymous().to_string(),
+            dona.campaign_id().unwrap_or_default(),
+            dona.pledge_id().unwrap_or_default(),
+            dona.tx_hash().unwrap_or_default(),
+            dona.created_at().to_string(),
+            dona.updated_at().to_string(),
         );
         dona.record(Arc::new(event));
+        if dona.msg_status == DonaMsgStatus::InReview {
+            let event = DonaMsgFlaggedEvent::new(
+                dona.id(),
+                dona.user_id(),
+                dona.msg_flags(),
+                dona.updated_at.to_string(),
+            );
+            dona.record(Arc::new(event));
+        }
 
         Ok(dona)
     }
 
-    pub fn confirm(&mut self, ocurred_at: OffsetDateTime) {
-        self.status = DonaStatus::Confirmed;
-        self.updated_at = DonaUpdatedAt::new(ocurred_at).unwrap();
+    /// Restores the review state of the message, which `new` leaves as published.
+    pub(crate) fn with_msg_review(
+        mut self,
+        msg_status: String,
+        msg_flags: Vec<String>,
+    ) -> Result<Self, String> {
+        self.msg_status = DonaMsgStatus::new(msg_status)?;
+        self.msg_flags = msg_flags
+            .into_iter()
+            .map(DonaMsgFlag::new)
+            .collect::<Result<_, _>>()?;
+
+        Ok(self)
+    }
+
+    /// Restores the fee of a confirmed dona, which `new` leaves empty.
+    pub(crate) fn with_fee(mut self, fee_amount: Option<Decimal>) -> Result<Self, String> {
+        self.fee = fee_amount
+            .map(|fee| DonaFee::new(Money::new(fee, self.currency())?, &self.amount))
+            .transpose()?;
+
+        Ok(self)
+    }
+
+    /// Splits a confirmed dona into the fee and what the recipient gets. The fee is worked out
+    /// once, so later changes to the fee rules do not touch it.
+    pub fn apply_fee(&mut self, fee_amount: Decimal) -> Result<(), String> {
+        if self.status != DonaStatus::Confirmed {
+            return Err(ERR_DONA_FEE_NOT_ALLOWED.to_string());
+        }
+        if self.fee.is_some() {
+            return Err(ERR_DONA_FEE_ALREADY_APPLIED.to_string());
+        }
+
+        self.fee = Some(DonaFee::new(
+            Money::new(fee_amount, self.currency())?,
+            &self.amount,
+        )?);
+
+        Ok(())
+    }
+
+    /// Publishes the message as it was written, or replaces it with [`REDACTED_DONA_MSG`].
+    pub fn review_msg(
+        &mut self,
+        decision: DonaMsgReviewDecision,
+        ocurred_at: OffsetDateTime,
+    ) -> Result<(), String> {
+        if self.msg_status != DonaMsgStatus::InReview {
+            return Err(ERR_DONA_MSG_NOT_IN_REVIEW.to_string());
+        }
+
+        self.updated_at = DonaUpdatedAt::new(ocurred_at)?;
+        match decision {
+            DonaMsgReviewDecision::Approve => self.msg_status = DonaMsgStatus::Published,
+            DonaMsgReviewDecision::Redact => {
+                self.msg = DonaMsg(REDACTED_DONA_MSG.to_string());
+                self.msg_status = DonaMsgStatus::Redacted;
+            }
+        }
+
+        let event = DonaMsgReviewedEvent::new(
+            self.id.to_string(),
+            self.user_id.to_string(),
+            self.msg_status.to_string(),
+            self.updated_at.to_string(),
+        );
+
+        self.record(Arc::new(event));
+
+        Ok(())
+    }
+
+    fn transition(&mut self, next: DonaStatus, ocurred_at: OffsetDateTime) -> Result<(), String> {
+        if !self.status.can_transition_to(&next) {
+            return Err(ERR_INVALID_DONA_STATUS_TRANSITION.to_string());
+        }
+
+        self.updated_at = DonaUpdatedAt::new(ocurred_at)?;
+        self.status = next;
+
+        Ok(())
+    }
+
+    pub fn confirm(&mut self, ocurred_at: OffsetDateTime) -> Result<(), String> {
+        self.transition(DonaStatus::Confirmed, ocurred_at)?;
 
         let event = DonaConfirmedEvent::new(self.id.to_string(), self.updated_at.to_string());
 
         self.record(Arc::new(event));
+
+        Ok(())
     }
 
-    pub fn reject(&mut self, ocurred_at: OffsetDateTime) {
-        self.status = DonaStatus::Rejected;
-        self.updated_at = DonaUpdatedAt::new(ocurred_at).unwrap();
+    pub fn reject(&mut self, ocurred_at: OffsetDateTime) -> Result<(), String> {
+        self.transition(DonaStatus::Rejected, ocurred_at)?;
 
         let event = DonaRejectedEvent::new(self.id.to_string(), self.updated_at.to_string());
 
         self.record(Arc::new(event));
+
+        Ok(())
+    }
+
+    pub fn cancel(&mut self, ocurred_at: OffsetDateTime) -> Result<(), String> {
+        self.transition(DonaStatus::Cancelled, ocurred_at)?;
+
+        let event = DonaCancelledEvent::new(self.id.to_string(), self.updated_at.to_string());
+
+        self.record(Arc::new(event));
+
+        Ok(())
+    }
+
+    pub fn refund(&mut self, ocurred_at: OffsetDateTime) -> Result<(), String> {
+        self.transition(DonaStatus::Refunded, ocurred_at)?;
+
+        let event = DonaRefundedEvent::new(self.id.to_string(), self.updated_at.to_string());
+
+        self.record(Arc::new(event));
+
+        Ok(())
+    }
+
+    pub fn expire(&mut self, ocurred_at: OffsetDateTime) -> Result<(), String> {
+        self.transition(DonaStatus::Expired, ocurred_at)?;
+
+        let event = DonaExpiredEvent::new(self.id.to_string(), self.updated_at.to_string());
+
+        self.record(Arc::new(event));
+
+        Ok(())
+    }
+
+    /// Records the deletion, so whatever is attached to the dona can be removed with it.
+    pub fn delete(&mut self, ocurred_at: OffsetDateTime) -> Result<(), String> {
+        let deleted_at = DonaUpdatedAt::new(ocurred_at)?;
+
+        let event = DonaDeletedEvent::new(
+            self.id.to_string(),
+            self.user_id.to_string(),
+            deleted_at.to_string(),
+        );
+
+        self.record(Arc::new(event));
+
+        Ok(())
     }
 
     pub fn record(&mut self, event: Arc<dyn Event>) {
@@ -287,8 +737,31 @@ impl Dona {
         self.msg.to_string()
     }
 
+    pub fn msg_status(&self) -> String {
+        self.msg_status.to_string()
+    }
+
+    /// Empty unless the message was sent to review.
+    pub fn msg_flags(&self) -> Vec<String> {
+        self.msg_flags.iter().map(|flag| flag.to_string()).collect()
+    }
+
     pub fn amount(&self) -> Decimal {
-        self.amount.0
+        self.amount.0.amount()
+    }
+
+    pub fn currency(&self) -> String {
+        self.amount.0.currency().to_string()
+    }
+
+    /// `None` until the dona is confirmed and its fee applied.
+    pub fn fee_amount(&self) -> Option<Decimal> {
+        self.fee.as_ref().map(|fee| fee.0.amount())
+    }
+
+    /// The amount without the fee, `None` while the fee is not applied.
+    pub fn net_amount(&self) -> Option<Decimal> {
+        self.fee_amount().map(|fee| self.amount() - fee)
     }
 
     pub fn status(&self) -> String {
@@ -299,16 +772,56 @@ impl Dona {
         self.status == DonaStatus::Confirmed
     }
 
+    pub fn is_pending(&self) -> bool {
+        self.status == DonaStatus::Pending
+    }
+
     pub fn method(&self) -> String {
         self.method.to_string()
     }
 
+    /// Only crypto donas have one.
+    pub fn tx_hash(&self) -> Option<String> {
+        self.tx_hash.as_ref().map(|hash| hash.to_string())
+    }
+
     pub fn user_id(&self) -> String {
         self.user_id.to_string()
     }
 
-    pub fn sender_id(&self) -> String {
-        self.sender_id.to_string()
+    /// `None` for guest donas.
+    pub fn sender_id(&self) -> Option<String> {
+        match &self.sender {
+            DonaSender::User(id) => Some(id.to_string()),
+            DonaSender::Guest { .. } => None,
+        }
+    }
+
+    pub fn guest_name(&self) -> Option<String> {
+        match &self.sender {
+            DonaSender::User(_) => None,
+            DonaSender::Guest { name, .. } => Some(name.to_string()),
+        }
+    }
+
+    pub fn guest_email(&self) -> Option<String> {
+        match &self.sender {
+            DonaSender::User(_) => None,
+            DonaSender::Guest { email, .. } => email.as_ref().map(|email| email.to_string()),
+        }
+    }
+
+    /// Anonymous donas keep their sender, but it must not be shown to the recipient.
+    pub fn is_anonymous(&self) -> bool {
+        self.is_anonymous
+    }
+
+    pub fn campaign_id(&self) -> Option<String> {
+        self.campaign_id.as_ref().map(|id| id.to_string())
+    }
+
+    pub fn pledge_id(&self) -> Option<String> {
+        self.pledge_id.as_ref().map(|id| id.to_string())
     }
 
     pub fn created_at(&self) -> OffsetDateTime {
@@ -321,19 +834,22 @@ impl Dona {
 }
 
 pub mod tests {
-    use crate::shared::domain::dona::tests::DonaOptionMethodMother;
+    use crate::{
+        campaign::domain::campaign::tests::CampaignIdMother,
+        pledge::domain::pledge::tests::PledgeIdMother,
+        shared::domain::dona::tests::DonaOptionMethodMother,
+    };
 
     use super::*;
 
     use fake::{
-        decimal::PositiveDecimal,
-        faker::{lorem::en::Sentence, time::en::DateTimeAfter},
+        faker::{lorem::en::Sentence, name::en::Name, time::en::DateTimeAfter},
         Dummy, Fake,
     };
     use rand::seq::SliceRandom;
     use shared::domain::{
         utils::{new_uuid, MINIMUM_DATE_PERMITTED},
-        value_objects::user_id::tests::UserIdMother,
+        value_objects::{money::tests::MoneyMother, user_id::tests::UserIdMother},
     };
 
     pub struct DonaIdMother;
@@ -369,15 +885,12 @@ pub mod tests {
     pub struct DonaAmountMother;
 
     impl DonaAmountMother {
-        pub fn create(value: Option<Decimal>) -> DonaAmount {
-            match value {
-                Some(value) => DonaAmount::new(value).unwrap(),
-                None => Self::random(),
-            }
+        pub fn create(amount: Option<Decimal>, currency: Option<String>) -> DonaAmount {
+            DonaAmount::new(MoneyMother::create(amount, currency)).unwrap()
         }
 
         pub fn random() -> DonaAmount {
-            DonaAmount::new(PositiveDecimal.fake()).unwrap()
+            Self::create(None, None)
         }
     }
 
@@ -439,6 +952,16 @@ pub mod tests {
         }
     }
 
+    pub struct DonaTxHashMother;
+
+    impl DonaTxHashMother {
+        /// 32 random bytes in hex, valid on every supported network.
+        pub fn random() -> DonaTxHash {
+            let bytes: [u8; 32] = rand::random();
+            DonaTxHash::new(bytes.iter().map(|byte| format!("{:02x}", byte)).collect()).unwrap()
+        }
+    }
+
     pub struct DonaMother;
 
     impl DonaMother {
@@ -446,21 +969,33 @@ pub mod tests {
             id: Option<String>,
             msg: Option<String>,
             amount: Option<Decimal>,
+            currency: Option<String>,
             status: Option<String>,
             method: Option<String>,
             user_id: Option<String>,
             sender_id: Option<String>,
+            campaign_id: Option<String>,
+            pledge_id: Option<String>,
             created_at: Option<OffsetDateTime>,
             updated_at: Option<OffsetDateTime>,
         ) -> Dona {
+            let method = DonaOptionMethodMother::create(method);
+
             Dona {
                 id: DonaIdMother::create(id),
                 msg: DonaMsgMother::create(msg),
-                amount: DonaAmountMother::create(amount),
+                msg_status: DonaMsgStatus::Published,
+                msg_flags: vec![],
+                amount: DonaAmountMother::create(amount, currency),
+                fee: None,
                 status: DonaStatusMother::create(status),
-                method: DonaOptionMethodMother::create(method),
+                tx_hash: (method == DonaOptionMethod::Crypto).then(DonaTxHashMother::random),
+                method,
                 user_id: UserIdMother::create(user_id),
-                sender_id: UserIdMother::create(sender_id),
+                sender: DonaSender::User(UserIdMother::create(sender_id)),
+                is_anonymous: false,
+                campaign_id: campaign_id.map(|id| CampaignIdMother::create(Some(id))),
+                pledge_id: pledge_id.map(|id| PledgeIdMother::create(Some(id))),
                 created_at: DonaCreatedAtMother::create(created_at),
                 updated_at: DonaUpdatedAtMother::create(updated_at),
 
@@ -469,7 +1004,122 @@ pub mod tests {
         }
 
         pub fn random() -> Dona {
-            Self::create(None, None, None, None, None, None, None, None, None)
+            Self::create(
+                None, None, None, None, None, None, None, None, None, None, None, None,
+            )
+        }
+
+        /// A pending dona from a guest donor, without a registered sender.
+        pub fn guest(
+            user_id: Option<String>,
+            guest_name: Option<String>,
+            guest_email: Option<String>,
+            is_anonymous: bool,
+        ) -> Dona {
+            let mut dona = Self::create(
+                None,
+                None,
+                Some(Decimal::new(1000, 2)),
+                Some("USD".to_string()),
+                Some("pending".to_string()),
+                None,
+                user_id,
+                None,
+                None,
+                None,
+                None,
+                None,
+            );
+            dona.sender = DonaSender::Guest {
+                name: DonaGuestName::new(guest_name.unwrap_or(Name().fake())).unwrap(),
+                email: guest_email.map(|email| DonaGuestEmail::new(email).unwrap()),
+            };
+            dona.is_anonymous = is_anonymous;
+
+            dona
+        }
+
+        /// A dona whose message was flagged when it was created.
+        pub fn in_review(user_id: Option<String>) -> Dona {
+            let mut dona = Self::create(
+                None, None, None, None, None, None, user_id, None, None, None, None, None,
+            );
+            dona.msg_status = DonaMsgStatus::InReview;
+            dona.msg_flags = vec![DonaMsgFlag::Link];
+
+            dona
+        }
+    }
+
+    #[test]
+    fn it_should_only_move_pending_donas_forward_and_refund_confirmed_ones() {
+        let allowed = [
+            (DonaStatus::Pending, DonaStatus::Confirmed),
+            (DonaStatus::Pending, DonaStatus::Rejected),
+            (DonaStatus::Pending, DonaStatus::Cancelled),
+            (DonaStatus::Pending, DonaStatus::Expired),
+            (DonaStatus::Confirmed, DonaStatus::Refunded),
+        ];
+        let statuses = [
+            DonaStatus::Pending,
+            DonaStatus::Confirmed,
+            DonaStatus::Rejected,
+            DonaStatus::Cancelled,
+            DonaStatus::Refunded,
+            DonaStatus::Expired,
+        ];
+
+        for from in &statuses {
+            for to in &statuses {
+                assert_eq!(
+                    from.can_transition_to(to),
+                    allowed.contains(&(from.clone(), to.clone())),
+                    "{} -> {}",
+                    from,
+                    to
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn it_should_refuse_to_confirm_an_expired_dona() {
+        let mut dona = DonaMother::random();
+        dona.status = DonaStatus::Expired;
+
+        assert_eq!(
+            dona.confirm(OffsetDateTime::now_utc()),
+            Err(ERR_INVALID_DONA_STATUS_TRANSITION.to_string())
+        );
+        assert_eq!(dona.status(), "expired");
+        assert!(dona.pull_events().is_empty());
+    }
+
+    #[test]
+    fn it_should_refund_a_confirmed_dona() {
+        let mut dona = DonaMother::random();
+        dona.status = DonaStatus::Confirmed;
+
+        dona.refund(OffsetDateTime::now_utc()).unwrap();
+
+        assert_eq!(dona.status(), "refunded");
+        assert_eq!(dona.pull_events().len(), 1);
+    }
+
+    #[test]
+    fn it_should_read_msg_statuses_and_flags_in_lowercase() {
+        for status in ["published", "in_review", "redacted"] {
+            assert_eq!(
+                DonaMsgStatus::new(status.to_string()).unwrap().to_string(),
+                status
+            );
+        }
+        for flag in ["link", "banned_word"] {
+            assert_eq!(
+                DonaMsgFlag::new(flag.to_string()).unwrap().to_string(),
+                flag
+            );
         }
+        assert!(DonaMsgStatus::new("IN_REVIEW".to_string()).is_err());
     }
 }
@@ -6,9 +6,17 @@ pub const DONA_CREATED_EVENT_TYPE: &str = "dona.dona_created";
 pub struct DonaCreatedEvent {
     id: String,
     msg: String,
+    msg_status: String,
     amount: String,
+    currency: String,
     user_id: String,
     sender_id: String,
+    guest_name: String,
+    guest_email: String,
+    is_anonymous: String,
+    campaign_id: String,
+    pledge_id: String,
+    tx_hash: String,
     created_at: String,
     updated_at: String,
 
@@ -19,18 +27,34 @@ impl DonaCreatedEvent {
     pub fn new(
         id: String,
         msg: String,
+        msg_status: String,
         amount: String,
+        currency: String,
         user_id: String,
         sender_id: String,
+        guest_name: String,
+        guest_email: String,
+        is_anonymous: String,
+        campaign_id: String,
+        pledge_id: String,
+        tx_hash: String,
         created_at: String,
         updated_at: String,
     ) -> Self {
         Self {
             id: id.clone(),
             msg,
+            msg_status,
             amount,
+            currency,
             user_id,
             sender_id,
+            guest_name,
+            guest_email,
+            is_anonymous,
+            campaign_id,
+            pledge_id,
+            tx_hash,
             created_at,
             updated_at,
             base_event: BaseEvent::new(id),
@@ -41,22 +65,61 @@ impl DonaCreatedEvent {
         &self.id
     }
 
+    /// [`REDACTED_DONA_MSG`](super::dona::REDACTED_DONA_MSG) for recipients without messages.
     pub fn msg(&self) -> &str {
         &self.msg
     }
 
+    /// Whether the message was published, sent to review or withheld.
+    pub fn msg_status(&self) -> &str {
+        &self.msg_status
+    }
+
     pub fn amount(&self) -> &str {
         &self.amount
     }
 
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
     pub fn user_id(&self) -> &str {
         &self.user_id
     }
 
+    /// Empty for guest donas.
     pub fn sender_id(&self) -> &str {
         &self.sender_id
     }
 
+    /// Empty unless the dona was made by a guest donor.
+    pub fn guest_name(&self) -> &str {
+        &self.guest_name
+    }
+
+    pub fn guest_email(&self) -> &str {
+        &self.guest_email
+    }
+
+    pub fn is_anonymous(&self) -> &str {
+        &self.is_anonymous
+    }
+
+    /// Empty when the dona is not part of a campaign.
+    pub fn campaign_id(&self) -> &str {
+        &self.campaign_id
+    }
+
+    /// Empty unless the dona was generated from a pledge.
+    pub fn pledge_id(&self) -> &str {
+        &self.pledge_id
+    }
+
+    /// Empty unless the dona was paid in crypto.
+    pub fn tx_hash(&self) -> &str {
+        &self.tx_hash
+    }
+
     pub fn created_at(&self) -> &str {
         &self.created_at
     }
@@ -90,15 +153,49 @@ impl Event for DonaCreatedEvent {
         let msg = data
             .get("msg")
             .ok_or(EventDeserializeError::MissingField("msg".to_string()))?;
+        let msg_status = data
+            .get("msg_status")
+            .ok_or(EventDeserializeError::MissingField(
+                "msg_status".to_string(),
+            ))?;
         let amount = data
             .get("amount")
             .ok_or(EventDeserializeError::MissingField("amount".to_string()))?;
+        let currency = data
+            .get("currency")
+            .ok_or(EventDeserializeError::MissingField("currency".to_string()))?;
         let user_id = data
             .get("user_id")
             .ok_or(EventDeserializeError::MissingField("user_id".to_string()))?;
         let sender_id = data
             .get("sender_id")
             .ok_or(EventDeserializeError::MissingField("sender_id".to_string()))?;
+        let guest_name = data
+            .get("guest_name")
+            .ok_or(EventDeserializeError::MissingField(
+                "guest_name".to_string(),
+            ))?;
+        let guest_email = data
+            .get("guest_email")
+            .ok_or(EventDeserializeError::MissingField(
+                "guest_email".to_string(),
+            ))?;
+        let is_anonymous = data
+            .get("is_anonymous")
+            .ok_or(EventDeserializeError::MissingField(
+                "is_anonymous".to_string(),
+            ))?;
+        let campaign_id = data
+            .get("campaign_id")
+            .ok_or(EventDeserializeError::MissingField(
+                "campaign_id".to_string(),
+            ))?;
+        let pledge_id = data
+            .get("pledge_id")
+            .ok_or(EventDeserializeError::MissingField("pledge_id".to_string()))?;
+        let tx_hash = data
+            .get("tx_hash")
+            .ok_or(EventDeserializeError::MissingField("tx_hash".to_string()))?;
         let created_at = data
             .get("created_at")
             .ok_or(EventDeserializeError::MissingField(
@@ -113,9 +210,17 @@ impl Event for DonaCreatedEvent {
         Ok(Box::new(Self {
             id: id.to_string(),
             msg: msg.to_string(),
+            msg_status: msg_status.to_string(),
             amount: amount.to_string(),
+            currency: currency.to_string(),
             user_id: user_id.to_string(),
             sender_id: sender_id.to_string(),
+            guest_name: guest_name.to_string(),
+            guest_email: guest_email.to_string(),
+            is_anonymous: is_anonymous.to_string(),
+            campaign_id: campaign_id.to_string(),
+            pledge_id: pledge_id.to_string(),
+            tx_hash: tx_hash.to_string(),
             created_at: created_at.to_string(),
             updated_at: updated_at.to_string(),
             base_event,
@@ -130,9 +235,17 @@ impl Event for DonaCreatedEvent {
             vec![
                 ("id".to_string(), self.id.clone()),
                 ("msg".to_string(), self.msg.clone()),
+                ("msg_status".to_string(), self.msg_status.clone()),
                 ("amount".to_string(), self.amount.clone()),
+                ("currency".to_string(), self.currency.clone()),
                 ("user_id".to_string(), self.user_id.clone()),
                 ("sender_id".to_string(), self.sender_id.clone()),
+                ("guest_name".to_string(), self.guest_name.clone()),
+                ("guest_email".to_string(), self.guest_email.clone()),
+                ("is_anonymous".to_string(), self.is_anonymous.clone()),
+                ("campaign_id".to_string(), self.campaign_id.clone()),
+                ("pledge_id".to_string(), self.pledge_id.clone()),
+                ("tx_hash".to_string(), self.tx_hash.clone()),
                 ("created_at".to_string(), self.created_at.clone()),
                 ("updated_at".to_string(), self.updated_at.clone()),
             ]
@@ -0,0 +1,171 @@
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+use shared::domain::{csv::parse_csv_records, utils::new_uuid};
+use time::{
+    format_description::well_known::Rfc3339, macros::format_description, Date, OffsetDateTime,
+};
+
+use crate::shared::domain::dona::DonaOptionMethod;
+
+use super::{
+    dona::{
+        Dona, DonaMsgModeration, DonaStatus, NewDona, ERR_INVALID_DONA_AMOUNT,
+        ERR_INVALID_DONA_CREATED_AT,
+    },
+    dona_msg_policy::DonaMsgPolicy,
+};
+
+pub const DONA_IMPORT_CSV_HEADER: [&str; 6] = [
+    "date",
+    "amount",
+    "currency",
+    "donor_name",
+    "donor_email",
+    "message",
+];
+pub const DONA_IMPORT_MAX_ROWS: usize = 5000;
+
+pub const ERR_INVALID_DONA_IMPORT_CSV_HEADER: &str =
+    "Dona import CSV must start with the header date,amount,currency,donor_name,donor_email,message";
+pub const ERR_EMPTY_DONA_IMPORT_CSV: &str = "Dona import CSV has no rows";
+pub const ERR_DONA_IMPORT_TOO_MANY_ROWS: &str = "Dona import CSV has too many rows";
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DonaImportRowError {
+    /// Line of the file the row starts on, the header being line 1.
+    pub line: u64,
+    pub error: String,
+}
+
+/// Donas read from a CSV of offline donations, such as cash or bank transfers received before
+/// joining. Every valid row becomes a manual dona from a guest donor, created and confirmed on
+/// import so it goes through moderation, fees and receipts like any other. The invalid rows are
+/// reported by line without stopping the rest of the file.
+#[derive(Debug, Clone)]
+pub struct DonaImport {
+    donas: Vec<Dona>,
+    errors: Vec<DonaImportRowError>,
+}
+
+impl DonaImport {
+    /// `date` is either a calendar day, taken at midnight UTC, or an RFC 3339 date time.
+    fn parse_date(value: &str) -> Result<OffsetDateTime, String> {
+        if let Ok(date) = Date::parse(value, format_description!("[year]-[month]-[day]")) {
+            return Ok(date.midnight().assume_utc());
+        }
+
+        OffsetDateTime::parse(value, &Rfc3339).map_err(|_| ERR_INVALID_DONA_CREATED_AT.to_string())
+    }
+
+    fn parse_row(
+        fields: &[String],
+        user_id: &str,
+        imported_at: OffsetDateTime,
+        msg_policy: Option<&DonaMsgPolicy>,
+    ) -> Result<Dona, String> {
+        let [date, amount, currency, donor_name, donor_email, message] = fields else {
+            return Err(format!(
+                "Expected {} columns, found {}",
+                DONA_IMPORT_CSV_HEADER.len(),
+                fields.len()
+            ));
+        };
+
+        let amount =
+            Decimal::from_str(amount.trim()).map_err(|_| ERR_INVALID_DONA_AMOUNT.to_string())?;
+        let donor_email = Some(donor_email.trim().to_string()).filter(|email| !email.is_empty());
+        let (msg, msg_moderation) = match msg_policy {
+            Some(msg_policy) => {
+                let (msg, flags) = msg_policy.moderate(message)?;
+                (msg, DonaMsgModeration::Checked(flags))
+            }
+            None => (message.trim().to_string(), DonaMsgModeration::Withheld),
+        };
+
+        let mut dona = Dona::create(
+            NewDona {
+                id: new_uuid(),
+                msg,
+                amount,
+                currency: currency.trim().to_uppercase(),
+                status: DonaStatus::Pending.to_string(),
+                method: DonaOptionMethod::Manual.to_string(),
+                user_id: user_id.to_string(),
+                sender_id: None,
+                guest_name: Some(donor_name.to_string()),
+                guest_email: donor_email,
+                is_anonymous: false,
+                campaign_id: None,
+                pledge_id: None,
+                tx_hash: None,
+                created_at: Self::parse_date(date.trim())?,
+                updated_at: imported_at,
+            },
+            msg_moderation,
+        )?;
+        dona.confirm(imported_at)?;
+
+        Ok(dona)
+    }
+
+    /// Fails as a whole only when the file itself cannot be read: bad quoting, an unexpected
+    /// header, no rows or more than [`DONA_IMPORT_MAX_ROWS`]. Messages are moderated with
+    /// `msg_policy`, or withheld without one when the recipient does not take messages.
+    pub fn parse(
+        csv: &str,
+        user_id: String,
+        imported_at: OffsetDateTime,
+        msg_policy: Option<&DonaMsgPolicy>,
+    ) -> Result<Self, String> {
+        let rows = parse_csv_records(csv)?;
+        let (header, rows) = rows
+            .split_first()
+            .ok_or(ERR_EMPTY_DONA_IMPORT_CSV.to_string())?;
+
+        if header
+            .fields
+            .iter()
+            .map(|h| h.trim())
+            .ne(DONA_IMPORT_CSV_HEADER)
+        {
+            return Err(ERR_INVALID_DONA_IMPORT_CSV_HEADER.to_string());
+        }
+        if rows.is_empty() {
+            return Err(ERR_EMPTY_DONA_IMPORT_CSV.to_string());
+        }
+        if rows.len() > DONA_IMPORT_MAX_ROWS {
+            return Err(ERR_DONA_IMPORT_TOO_MANY_ROWS.to_string());
+        }
+
+        let mut donas = vec![];
+        let mut errors = vec![];
+        for row in rows {
+            match Self::parse_row(&row.fields, &user_id, imported_at, msg_policy) {
+                Ok(dona) => donas.push(dona),
+                Err(error) => errors.push(DonaImportRowError {
+                    line: row.line as u64,
+                    error,
+                }),
+            }
+        }
+
+        Ok(Self { donas, errors })
+    }
+
+    pub fn total_rows(&self) -> u64 {
+        (self.donas.len() + self.errors.len()) as u64
+    }
+
+    pub fn donas(&self) -> &[Dona] {
+        &self.donas
+    }
+
+    pub fn errors(&self) -> &[DonaImportRowError] {
+        &self.errors
+    }
+
+    pub fn into_donas(self) -> Vec<Dona> {
+        self.donas
+    }
+}
@@ -0,0 +1,106 @@
+use shared::domain::bus::event::{BaseEvent, Event, EventDeserializeError, EventSerialized};
+
+pub const DONA_MSG_FLAGGED_EVENT_TYPE: &str = "dona.dona_msg_flagged";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct DonaMsgFlaggedEvent {
+    dona_id: String,
+    user_id: String,
+    /// Comma separated.
+    flags: String,
+    dona_updated_at: String,
+
+    base_event: BaseEvent,
+}
+
+impl DonaMsgFlaggedEvent {
+    pub fn new(
+        dona_id: String,
+        user_id: String,
+        flags: Vec<String>,
+        dona_updated_at: String,
+    ) -> Self {
+        Self {
+            dona_id: dona_id.clone(),
+            user_id,
+            flags: flags.join(","),
+            dona_updated_at,
+            base_event: BaseEvent::new(dona_id),
+        }
+    }
+
+    pub fn dona_id(&self) -> &str {
+        &self.dona_id
+    }
+
+    pub fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    pub fn flags(&self) -> Vec<String> {
+        self.flags.split(',').map(str::to_string).collect()
+    }
+
+    pub fn dona_updated_at(&self) -> &str {
+        &self.dona_updated_at
+    }
+}
+
+impl Event for DonaMsgFlaggedEvent {
+    fn event_type(&self) -> &'static str {
+        DONA_MSG_FLAGGED_EVENT_TYPE
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn from_primitives(
+        &self,
+        primitives: EventSerialized,
+    ) -> Result<Box<dyn Event>, EventDeserializeError> {
+        let data = primitives.data();
+        let base_event = BaseEvent::from_primitives(
+            primitives.event_id().to_string(),
+            primitives.aggregate_id().to_string(),
+            primitives.occurred_at().to_string(),
+        );
+        let dona_id = data
+            .get("dona_id")
+            .ok_or(EventDeserializeError::MissingField("dona_id".to_string()))?;
+        let user_id = data
+            .get("user_id")
+            .ok_or(EventDeserializeError::MissingField("user_id".to_string()))?;
+        let flags = data
+            .get("flags")
+            .ok_or(EventDeserializeError::MissingField("flags".to_string()))?;
+        let updated_at = data
+            .get("dona_updated_at")
+            .ok_or(EventDeserializeError::MissingField(
+                "dona_updated_at".to_string(),
+            ))?;
+
+        Ok(Box::new(Self {
+            dona_id: dona_id.to_string(),
+            user_id: user_id.to_string(),
+            flags: flags.to_string(),
+            dona_updated_at: updated_at.to_string(),
+            base_event,
+        }))
+    }
+
+    fn to_primitives(&self) -> EventSerialized {
+        EventSerialized::new(
+            self.base_event.event_id().to_string(),
+            self.base_event.aggregate_id().to_string(),
+            self.base_event.occurred_at().to_string(),
+            vec![
+                ("dona_id".to_string(), self.dona_id.clone()),
+                ("user_id".to_string(), self.user_id.clone()),
+                ("flags".to_string(), self.flags.clone()),
+                ("dona_updated_at".to_string(), self.dona_updated_at.clone()),
+            ]
+            .into_iter()
+            .collect(),
+        )
+    }
+}
@@ -0,0 +1,143 @@
+use super::dona::{DonaMsgFlag, DONA_MSG_MAX_LENGTH};
+
+pub const ERR_INVALID_DONA_MSG_LINK_POLICY: &str = "Invalid Dona Message Link Policy";
+pub const ERR_DONA_MSG_TOO_LONG: &str = "The message of the dona is too long";
+pub const ERR_DONA_MSG_LINKS_NOT_ALLOWED: &str = "Links are not allowed in dona messages";
+
+/// What happens to messages with links, which are the usual vehicle for spam.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum DonaMsgLinkPolicy {
+    Allow,
+    Review,
+    Reject,
+}
+
+impl DonaMsgLinkPolicy {
+    pub fn new(value: String) -> Result<Self, String> {
+        match value.as_str() {
+            "ALLOW" => Ok(Self::Allow),
+            "REVIEW" => Ok(Self::Review),
+            "REJECT" => Ok(Self::Reject),
+            _ => Err(ERR_INVALID_DONA_MSG_LINK_POLICY.to_string()),
+        }
+    }
+}
+
+/// Top level domains common enough in spam to catch links written without a scheme.
+const LINK_TLDS: [&str; 16] = [
+    "com", "net", "org", "io", "gg", "tv", "ly", "me", "co", "xyz", "ru", "info", "link", "app",
+    "dev", "site",
+];
+
+/// Characters that render as nothing, so they could split a link where nobody sees it.
+const INVISIBLE_CHARS: [char; 17] = [
+    '\u{00AD}', '\u{200B}', '\u{200C}', '\u{200D}', '\u{200E}', '\u{200F}', '\u{202A}', '\u{202B}',
+    '\u{202C}', '\u{202D}', '\u{202E}', '\u{2060}', '\u{2061}', '\u{2062}', '\u{2063}', '\u{2064}',
+    '\u{FEFF}',
+];
+
+fn is_link(word: &str) -> bool {
+    let word = word
+        .trim_matches(|c: char| !c.is_alphanumeric() && c != '/')
+        .to_lowercase();
+    if word.contains("://") || word.starts_with("www.") {
+        return true;
+    }
+
+    let host = word.split('/').next().unwrap_or_default();
+    match host.rsplit_once('.') {
+        Some((name, tld)) => {
+            !name.is_empty()
+                && name
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '-' || c == '.')
+                && LINK_TLDS.contains(&tld)
+        }
+        None => false,
+    }
+}
+
+/// Lowercase words separated by single spaces, with a space at both ends so whole words and
+/// phrases can be found with `contains`.
+fn normalize_words(value: &str) -> String {
+    let words = value
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    format!(" {} ", words)
+}
+
+/// Rules every donor message goes through when a dona is created. Messages over the length or
+/// with links under [`DonaMsgLinkPolicy::Reject`] are refused, while banned words and reviewed
+/// links only flag the message, so the dona still goes through.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DonaMsgPolicy {
+    max_length: usize,
+    banned_words: Vec<String>,
+    link_policy: DonaMsgLinkPolicy,
+}
+
+impl DonaMsgPolicy {
+    /// `max_length` can only lower [`DONA_MSG_MAX_LENGTH`]. Banned words may be phrases and
+    /// are matched as whole words, ignoring case and punctuation.
+    pub fn new(
+        max_length: usize,
+        banned_words: Vec<String>,
+        link_policy: DonaMsgLinkPolicy,
+    ) -> Self {
+        Self {
+            max_length: max_length.min(DONA_MSG_MAX_LENGTH),
+            banned_words: banned_words
+                .iter()
+                .map(|word| normalize_words(word))
+                .filter(|word| !word.trim().is_empty())
+                .collect(),
+            link_policy,
+        }
+    }
+
+    /// Returns the message to store and why it must be reviewed, if it must. Every rule is
+    /// checked against the message as it will be shown: without the invisible characters that
+    /// could hide a link or a banned word, nor control characters other than line breaks.
+    pub fn moderate(&self, msg: &str) -> Result<(String, Vec<DonaMsgFlag>), String> {
+        let mut flags = vec![];
+
+        let msg = msg
+            .chars()
+            .filter(|c| !INVISIBLE_CHARS.contains(c) && (*c == '\n' || !c.is_control()))
+            .collect::<String>()
+            .trim()
+            .to_string();
+        if msg.split_whitespace().any(is_link) {
+            match self.link_policy {
+                DonaMsgLinkPolicy::Allow => {}
+                DonaMsgLinkPolicy::Review => flags.push(DonaMsgFlag::Link),
+                DonaMsgLinkPolicy::Reject => return Err(ERR_DONA_MSG_LINKS_NOT_ALLOWED.to_string()),
+            }
+        }
+
+        if msg.chars().count() > self.max_length {
+            return Err(ERR_DONA_MSG_TOO_LONG.to_string());
+        }
+
+        let words = normalize_words(&msg);
+        if self
+            .banned_words
+            .iter()
+            .any(|banned| words.contains(banned.as_str()))
+        {
+            flags.push(DonaMsgFlag::BannedWord);
+        }
+
+        Ok((msg, flags))
+    }
+}
+
+impl Default for DonaMsgPolicy {
+    fn default() -> Self {
+        Self::new(DONA_MSG_MAX_LENGTH, vec![], DonaMsgLinkPolicy::Review)
+    }
+}
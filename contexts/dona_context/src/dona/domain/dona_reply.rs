@@ -0,0 +1,349 @@
+use std::fmt::Display;
+use std::sync::Arc;
+
+use shared::domain::{bus::event::Event, utils::is_uuid, value_objects::user_id::UserId};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+use super::{
+    dona::{Dona, DonaId, DonaStatus},
+    dona_reply_created_event::DonaReplyCreatedEvent,
+    dona_reply_deleted_event::DonaReplyDeletedEvent,
+    dona_reply_updated_event::DonaReplyUpdatedEvent,
+};
+
+pub const ERR_DONA_REPLY_NOT_FOUND: &str = "Dona Reply not found";
+pub const ERR_DONA_REPLY_ALREADY_EXISTS: &str = "Dona already has a reply";
+pub const ERR_DONA_NOT_ACCEPTING_REPLIES: &str = "Only confirmed Donas can be replied to";
+
+pub const ERR_INVALID_DONA_REPLY_ID: &str = "Invalid Dona Reply ID";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct DonaReplyId(String);
+
+impl DonaReplyId {
+    pub fn new(value: String) -> Result<Self, String> {
+        if is_uuid(&value) {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_DONA_REPLY_ID.to_string())
+        }
+    }
+}
+
+impl Display for DonaReplyId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub const DONA_REPLY_MSG_MAX_LENGTH: usize = 500;
+pub const ERR_INVALID_DONA_REPLY_MSG: &str = "Invalid Dona Reply Message";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct DonaReplyMsg(String);
+
+impl DonaReplyMsg {
+    pub fn new(value: String) -> Result<Self, String> {
+        let value = value.trim().to_string();
+
+        if value.is_empty() || value.chars().count() > DONA_REPLY_MSG_MAX_LENGTH {
+            return Err(ERR_INVALID_DONA_REPLY_MSG.to_string());
+        }
+
+        Ok(Self(value))
+    }
+}
+
+impl Display for DonaReplyMsg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub const ERR_INVALID_DONA_REPLY_VISIBILITY: &str = "Invalid Dona Reply Visibility";
+
+/// Private replies are only shown to the donor, public ones are also listed on the profile of
+/// the recipient.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum DonaReplyVisibility {
+    Private,
+    Public,
+}
+
+impl DonaReplyVisibility {
+    pub fn new(value: String) -> Result<Self, String> {
+        match value.as_str() {
+            "PRIVATE" => Ok(Self::Private),
+            "PUBLIC" => Ok(Self::Public),
+            _ => Err(ERR_INVALID_DONA_REPLY_VISIBILITY.to_string()),
+        }
+    }
+}
+
+impl Display for DonaReplyVisibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Private => write!(f, "PRIVATE"),
+            Self::Public => write!(f, "PUBLIC"),
+        }
+    }
+}
+
+pub const ERR_INVALID_DONA_REPLY_CREATED_AT: &str = "Invalid Dona Reply Created At";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct DonaReplyCreatedAt(OffsetDateTime);
+
+impl DonaReplyCreatedAt {
+    pub fn new(value: OffsetDateTime) -> Result<Self, String> {
+        if value < OffsetDateTime::now_utc() {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_DONA_REPLY_CREATED_AT.to_string())
+        }
+    }
+}
+
+impl Display for DonaReplyCreatedAt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.format(&Rfc3339).unwrap())
+    }
+}
+
+pub const ERR_INVALID_DONA_REPLY_UPDATED_AT: &str = "Invalid Dona Reply Updated At";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct DonaReplyUpdatedAt(OffsetDateTime);
+
+impl DonaReplyUpdatedAt {
+    pub fn new(value: OffsetDateTime) -> Result<Self, String> {
+        if value < OffsetDateTime::now_utc() {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_DONA_REPLY_UPDATED_AT.to_string())
+        }
+    }
+}
+
+impl Display for DonaReplyUpdatedAt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.format(&Rfc3339).unwrap())
+    }
+}
+
+/// Thank-you note the recipient leaves on a dona, one per dona.
+#[derive(Debug, Clone)]
+pub struct DonaReply {
+    id: DonaReplyId,
+    dona_id: DonaId,
+    user_id: UserId,
+    msg: DonaReplyMsg,
+    visibility: DonaReplyVisibility,
+    created_at: DonaReplyCreatedAt,
+    updated_at: DonaReplyUpdatedAt,
+
+    events: Vec<Arc<dyn Event>>,
+}
+
+impl PartialEq for DonaReply {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.dona_id == other.dona_id
+            && self.user_id == other.user_id
+            && self.msg == other.msg
+            && self.visibility == other.visibility
+            && self.created_at == other.created_at
+            && self.updated_at == other.updated_at
+    }
+}
+
+impl Eq for DonaReply {}
+
+impl DonaReply {
+    pub(crate) fn new(
+        id: String,
+        dona_id: String,
+        user_id: String,
+        msg: String,
+        visibility: String,
+        created_at: OffsetDateTime,
+        updated_at: OffsetDateTime,
+    ) -> Result<Self, String> {
+        Ok(Self {
+            id: DonaReplyId::new(id)?,
+            dona_id: DonaId::new(dona_id)?,
+            user_id: UserId::new(user_id)?,
+            msg: DonaReplyMsg::new(msg)?,
+            visibility: DonaReplyVisibility::new(visibility)?,
+            created_at: DonaReplyCreatedAt::new(created_at)?,
+            updated_at: DonaReplyUpdatedAt::new(updated_at)?,
+            events: vec![],
+        })
+    }
+
+    /// Only confirmed donas are replied to, the donor has not given anything to thank for
+    /// until then. The reply belongs to the recipient of the dona.
+    pub fn create(
+        id: String,
+        dona: &Dona,
+        msg: String,
+        visibility: String,
+        created_at: OffsetDateTime,
+    ) -> Result<Self, String> {
+        if dona.status() != DonaStatus::Confirmed.to_string() {
+            return Err(ERR_DONA_NOT_ACCEPTING_REPLIES.to_string());
+        }
+
+        let mut reply = Self::new(
+            id,
+            dona.id(),
+            dona.user_id(),
+            msg,
+            visibility,
+            created_at,
+            created_at,
+        )?;
+
+        let event = DonaReplyCreatedEvent::new(
+            reply.id(),
+            reply.dona_id(),
+            reply.user_id(),
+            reply.visibility(),
+            reply.created_at.to_string(),
+        );
+        reply.record(Arc::new(event));
+
+        Ok(reply)
+    }
+
+    pub fn update(
+        &mut self,
+        msg: String,
+        visibility: String,
+        ocurred_at: OffsetDateTime,
+    ) -> Result<(), String> {
+        self.msg = DonaReplyMsg::new(msg)?;
+        self.visibility = DonaReplyVisibility::new(visibility)?;
+        self.updated_at = DonaReplyUpdatedAt::new(ocurred_at)?;
+
+        let event = DonaReplyUpdatedEvent::new(
+            self.id(),
+            self.dona_id(),
+            self.user_id(),
+            self.visibility(),
+            self.updated_at.to_string(),
+        );
+        self.record(Arc::new(event));
+
+        Ok(())
+    }
+
+    pub fn delete(&mut self, ocurred_at: OffsetDateTime) -> Result<(), String> {
+        let deleted_at = DonaReplyUpdatedAt::new(ocurred_at)?;
+
+        let event = DonaReplyDeletedEvent::new(
+            self.id(),
+            self.dona_id(),
+            self.user_id(),
+            deleted_at.to_string(),
+        );
+        self.record(Arc::new(event));
+
+        Ok(())
+    }
+
+    pub fn belongs_to(&self, user_id: &str) -> bool {
+        self.user_id.to_string() == user_id
+    }
+
+    pub fn record(&mut self, event: Arc<dyn Event>) {
+        self.events.push(event);
+    }
+
+    pub fn pull_events(&mut self) -> Vec<Arc<dyn Event>> {
+        let events = self.events.clone();
+        self.events = vec![];
+        events
+    }
+
+    pub fn id(&self) -> String {
+        self.id.to_string()
+    }
+
+    pub fn dona_id(&self) -> String {
+        self.dona_id.to_string()
+    }
+
+    pub fn user_id(&self) -> String {
+        self.user_id.to_string()
+    }
+
+    pub fn msg(&self) -> String {
+        self.msg.to_string()
+    }
+
+    pub fn visibility(&self) -> String {
+        self.visibility.to_string()
+    }
+
+    pub fn created_at(&self) -> OffsetDateTime {
+        self.created_at.0
+    }
+
+    pub fn updated_at(&self) -> OffsetDateTime {
+        self.updated_at.0
+    }
+}
+
+pub mod tests {
+    use shared::domain::{utils::new_uuid, value_objects::user_id::tests::UserIdMother};
+    use time::Duration;
+
+    use crate::dona::domain::dona::tests::DonaIdMother;
+
+    use super::*;
+
+    pub struct DonaReplyIdMother;
+
+    impl DonaReplyIdMother {
+        pub fn random() -> DonaReplyId {
+            DonaReplyId::new(new_uuid()).unwrap()
+        }
+
+        pub fn create(value: Option<String>) -> DonaReplyId {
+            match value {
+                Some(value) => DonaReplyId::new(value).unwrap(),
+                None => Self::random(),
+            }
+        }
+    }
+
+    pub struct DonaReplyMother;
+
+    impl DonaReplyMother {
+        pub fn random() -> DonaReply {
+            Self::create(None, None, None)
+        }
+
+        /// A private reply written a minute ago unless told otherwise.
+        pub fn create(
+            dona_id: Option<String>,
+            user_id: Option<String>,
+            visibility: Option<DonaReplyVisibility>,
+        ) -> DonaReply {
+            let created_at = OffsetDateTime::now_utc() - Duration::minutes(1);
+
+            DonaReply {
+                id: DonaReplyIdMother::random(),
+                dona_id: DonaIdMother::create(dona_id),
+                user_id: UserIdMother::create(user_id),
+                msg: DonaReplyMsg::new("Thank you so much!".to_string()).unwrap(),
+                visibility: visibility.unwrap_or(DonaReplyVisibility::Private),
+                created_at: DonaReplyCreatedAt::new(created_at).unwrap(),
+                updated_at: DonaReplyUpdatedAt::new(created_at).unwrap(),
+
+                events: vec![],
+            }
+        }
+    }
+}
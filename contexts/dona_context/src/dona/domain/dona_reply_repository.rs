@@ -0,0 +1,48 @@
+use shared::domain::{
+    base_errors::BaseRepositoryError,
+    criteria::{page::CursorPage, Criteria},
+};
+
+use super::{
+    dona::DonaId,
+    dona_reply::{DonaReply, DonaReplyId},
+};
+
+#[async_trait::async_trait]
+pub trait DonaReplyRepository: Send + Sync {
+    async fn find_by_id(&self, id: DonaReplyId) -> Result<DonaReply, BaseRepositoryError>;
+    /// Replies of whichever of the donas have one, in no particular order.
+    async fn find_by_dona_ids(
+        &self,
+        dona_ids: Vec<DonaId>,
+    ) -> Result<Vec<DonaReply>, BaseRepositoryError>;
+    /// Replies are paginated by `created_at`.
+    async fn find_page_by_criteria(
+        &self,
+        criteria: Criteria,
+    ) -> Result<CursorPage<DonaReply>, BaseRepositoryError>;
+    async fn save(&self, reply: &DonaReply) -> Result<(), BaseRepositoryError>;
+    async fn delete(&self, id: DonaReplyId) -> Result<(), BaseRepositoryError>;
+    async fn delete_by_dona_id(&self, dona_id: DonaId) -> Result<(), BaseRepositoryError>;
+}
+
+#[cfg(test)]
+pub mod tests {
+    use mockall::mock;
+
+    use super::*;
+
+    mock! {
+        pub DonaReplyRepository {}
+
+        #[async_trait::async_trait]
+        impl DonaReplyRepository for DonaReplyRepository {
+            async fn find_by_id(&self, id: DonaReplyId) -> Result<DonaReply, BaseRepositoryError>;
+            async fn find_by_dona_ids(&self, dona_ids: Vec<DonaId>) -> Result<Vec<DonaReply>, BaseRepositoryError>;
+            async fn find_page_by_criteria(&self, criteria: Criteria) -> Result<CursorPage<DonaReply>, BaseRepositoryError>;
+            async fn save(&self, reply: &DonaReply) -> Result<(), BaseRepositoryError>;
+            async fn delete(&self, id: DonaReplyId) -> Result<(), BaseRepositoryError>;
+            async fn delete_by_dona_id(&self, dona_id: DonaId) -> Result<(), BaseRepositoryError>;
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use shared::domain::bus::event::{BaseEvent, Event, EventDeserializeError, EventSerialized};
+
+pub const DONA_REPLY_UPDATED_EVENT_TYPE: &str = "dona.dona_reply_updated";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct DonaReplyUpdatedEvent {
+    id: String,
+    dona_id: String,
+    user_id: String,
+    visibility: String,
+    updated_at: String,
+
+    base_event: BaseEvent,
+}
+
+impl DonaReplyUpdatedEvent {
+    pub fn new(
+        id: String,
+        dona_id: String,
+        user_id: String,
+        visibility: String,
+        updated_at: String,
+    ) -> Self {
+        Self {
+            id: id.clone(),
+            dona_id,
+            user_id,
+            visibility,
+            updated_at,
+            base_event: BaseEvent::new(id),
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn dona_id(&self) -> &str {
+        &self.dona_id
+    }
+
+    pub fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    pub fn visibility(&self) -> &str {
+        &self.visibility
+    }
+
+    pub fn updated_at(&self) -> &str {
+        &self.updated_at
+    }
+}
+
+impl Event for DonaReplyUpdatedEvent {
+    fn event_type(&self) -> &'static str {
+        DONA_REPLY_UPDATED_EVENT_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn from_primitives(
+        &self,
+        primitives: EventSerialized,
+    ) -> Result<Box<dyn Event>, EventDeserializeError> {
+        let data = primitives.data();
+        let base_event = BaseEvent::from_primitives(
+            primitives.event_id().to_string(),
+            primitives.aggregate_id().to_string(),
+            primitives.occurred_at().to_string(),
+        );
+
+        let id = data
+            .get("id")
+            .ok_or(EventDeserializeError::MissingField("id".to_string()))?;
+        let dona_id = data
+            .get("dona_id")
+            .ok_or(EventDeserializeError::MissingField("dona_id".to_string()))?;
+        let user_id = data
+            .get("user_id")
+            .ok_or(EventDeserializeError::MissingField("user_id".to_string()))?;
+        let visibility = data
+            .get("visibility")
+            .ok_or(EventDeserializeError::MissingField(
+                "visibility".to_string(),
+            ))?;
+        let updated_at = data
+            .get("updated_at")
+            .ok_or(EventDeserializeError::MissingField(
+                "updated_at".to_string(),
+            ))?;
+
+        Ok(Box::new(Self {
+            id: id.to_string(),
+            dona_id: dona_id.to_string(),
+            user_id: user_id.to_string(),
+            visibility: visibility.to_string(),
+            updated_at: updated_at.to_string(),
+            base_event,
+        }))
+    }
+
+    fn to_primitives(&self) -> EventSerialized {
+        EventSerialized::new(
+            self.base_event.event_id().to_string(),
+            self.base_event.aggregate_id().to_string(),
+            self.base_event.occurred_at().to_string(),
+            vec![
+                ("id".to_string(), self.id.clone()),
+                ("dona_id".to_string(), self.dona_id.clone()),
+                ("user_id".to_string(), self.user_id.clone()),
+                ("visibility".to_string(), self.visibility.clone()),
+                ("updated_at".to_string(), self.updated_at.clone()),
+            ]
+            .into_iter()
+            .collect(),
+        )
+    }
+}
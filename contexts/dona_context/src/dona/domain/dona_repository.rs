@@ -1,13 +1,47 @@
-use shared::domain::{base_errors::BaseRepositoryError, criteria::Criteria};
+use rust_decimal::Decimal;
+use shared::domain::{
+    base_errors::BaseRepositoryError,
+    criteria::{page::CursorPage, Criteria},
+    value_objects::user_id::UserId,
+};
 
-use super::dona::{Dona, DonaId};
+use crate::campaign::domain::campaign::CampaignId;
+
+use super::{
+    dona::{Dona, DonaId},
+    dona_stats::{DonaStats, DonaStatsGranularity, DonaStatsRange},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DonaTotals {
+    pub amount: Decimal,
+    pub count: u64,
+}
 
 #[async_trait::async_trait]
 pub trait DonaRepository: Send + Sync {
     async fn find_by_id(&self, id: DonaId) -> Result<Dona, BaseRepositoryError>;
     async fn find_by_criteria(&self, criteria: Criteria) -> Result<Vec<Dona>, BaseRepositoryError>;
+    async fn find_page_by_criteria(
+        &self,
+        criteria: Criteria,
+    ) -> Result<CursorPage<Dona>, BaseRepositoryError>;
     async fn find_all(&self) -> Result<Vec<Dona>, BaseRepositoryError>;
+    /// Sum and count of the confirmed donas made to a campaign, in the campaign currency.
+    async fn sum_confirmed_by_campaign(
+        &self,
+        campaign_id: CampaignId,
+    ) -> Result<DonaTotals, BaseRepositoryError>;
+    /// Aggregates the donas received by the user within the range, computed by the database.
+    async fn stats(
+        &self,
+        user_id: UserId,
+        range: DonaStatsRange,
+        granularity: DonaStatsGranularity,
+    ) -> Result<DonaStats, BaseRepositoryError>;
     async fn save(&self, dona: &Dona) -> Result<(), BaseRepositoryError>;
+    /// Inserts every dona in a single transaction, either all of them are saved or none.
+    async fn save_all(&self, donas: &[Dona]) -> Result<(), BaseRepositoryError>;
     async fn delete(&self, id: DonaId) -> Result<(), BaseRepositoryError>;
 }
 
@@ -24,8 +58,12 @@ pub mod tests {
         impl DonaRepository for DonaRepository {
             async fn find_by_id(&self, id: DonaId) -> Result<Dona, BaseRepositoryError>;
             async fn find_by_criteria(&self, criteria: Criteria) -> Result<Vec<Dona>, BaseRepositoryError>;
+            async fn find_page_by_criteria(&self, criteria: Criteria) -> Result<CursorPage<Dona>, BaseRepositoryError>;
             async fn find_all(&self) -> Result<Vec<Dona>, BaseRepositoryError>;
+            async fn sum_confirmed_by_campaign(&self, campaign_id: CampaignId) -> Result<DonaTotals, BaseRepositoryError>;
+            async fn stats(&self, user_id: UserId, range: DonaStatsRange, granularity: DonaStatsGranularity) -> Result<DonaStats, BaseRepositoryError>;
             async fn save(&self, dona: &Dona) -> Result<(), BaseRepositoryError>;
+            async fn save_all(&self, donas: &[Dona]) -> Result<(), BaseRepositoryError>;
             async fn delete(&self, id: DonaId) -> Result<(), BaseRepositoryError>;
         }
     }
@@ -0,0 +1,100 @@
+use std::fmt::Display;
+
+use rust_decimal::Decimal;
+use shared::domain::value_objects::money::Currency;
+use time::OffsetDateTime;
+
+pub const ERR_INVALID_DONA_STATS_GRANULARITY: &str = "Invalid Dona Stats Granularity";
+
+/// Size of the periods the donas are grouped in, named after the PostgreSQL `date_trunc` units.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum DonaStatsGranularity {
+    Day,
+    Week,
+    Month,
+}
+
+impl DonaStatsGranularity {
+    pub fn new(value: String) -> Result<Self, String> {
+        match value.as_str() {
+            "day" => Ok(Self::Day),
+            "week" => Ok(Self::Week),
+            "month" => Ok(Self::Month),
+            _ => Err(ERR_INVALID_DONA_STATS_GRANULARITY.to_string()),
+        }
+    }
+}
+
+impl Display for DonaStatsGranularity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Day => write!(f, "day"),
+            Self::Week => write!(f, "week"),
+            Self::Month => write!(f, "month"),
+        }
+    }
+}
+
+pub const ERR_INVALID_DONA_STATS_RANGE: &str = "Invalid Dona Stats Range";
+
+/// Half open range, `from` included and `to` excluded, matched against the creation date.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct DonaStatsRange {
+    from: OffsetDateTime,
+    to: OffsetDateTime,
+}
+
+impl DonaStatsRange {
+    pub fn new(from: OffsetDateTime, to: OffsetDateTime) -> Result<Self, String> {
+        if from < to {
+            Ok(Self { from, to })
+        } else {
+            Err(ERR_INVALID_DONA_STATS_RANGE.to_string())
+        }
+    }
+
+    pub fn from(&self) -> OffsetDateTime {
+        self.from
+    }
+
+    pub fn to(&self) -> OffsetDateTime {
+        self.to
+    }
+}
+
+/// Aggregate of the donas sharing a key. Amounts are never added across currencies, so every
+/// key gets one bucket per currency. `amount` is gross, and `net_amount` is what is left after
+/// `fees`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DonaStatsBucket {
+    pub key: String,
+    pub currency: String,
+    pub amount: Decimal,
+    pub fees: Decimal,
+    pub net_amount: Decimal,
+    pub count: u64,
+}
+
+impl DonaStatsBucket {
+    pub fn average(&self) -> Decimal {
+        if self.count == 0 {
+            return Decimal::ZERO;
+        }
+
+        let precision = Currency::new(self.currency.clone())
+            .map(|currency| currency.precision())
+            .unwrap_or(2);
+
+        (self.amount / Decimal::from(self.count)).round_dp(precision)
+    }
+}
+
+/// `totals`, `by_period` and `by_method` only count confirmed donas, `by_status` counts all of
+/// them. The key of a total is its currency and the key of a period is its first day.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DonaStats {
+    pub totals: Vec<DonaStatsBucket>,
+    pub by_period: Vec<DonaStatsBucket>,
+    pub by_method: Vec<DonaStatsBucket>,
+    pub by_status: Vec<DonaStatsBucket>,
+}
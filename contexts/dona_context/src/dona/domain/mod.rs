@@ -1,5 +1,20 @@
 pub mod dona;
+pub mod dona_cancelled_event;
 pub mod dona_confirmed_event;
 pub mod dona_created_event;
+pub mod dona_deleted_event;
+pub mod dona_expired_event;
+pub mod dona_import;
+pub mod dona_msg_flagged_event;
+pub mod dona_msg_policy;
+pub mod dona_msg_reviewed_event;
+pub mod dona_refunded_event;
 pub mod dona_rejected_event;
+pub mod dona_reply;
+pub mod dona_reply_created_event;
+pub mod dona_reply_deleted_event;
+pub mod dona_reply_repository;
+pub mod dona_reply_updated_event;
 pub mod dona_repository;
+pub mod dona_stats;
+pub mod payment_gateway;
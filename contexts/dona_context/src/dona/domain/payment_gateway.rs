@@ -0,0 +1,103 @@
+use std::{collections::HashMap, sync::Arc};
+
+use rust_decimal::Decimal;
+
+use crate::shared::domain::dona::DonaOptionMethod;
+
+use super::dona::{Dona, DonaId};
+
+pub const ERR_PAYMENT_GATEWAY_NOT_FOUND: &str = "Payment method has no gateway";
+pub const ERR_PAYMENT_GATEWAY_OPERATION_NOT_SUPPORTED: &str =
+    "Payment gateway does not support this operation";
+pub const ERR_PAYMENT_MISMATCH: &str = "Payment does not match the Dona";
+pub const ERR_DONA_NOT_PAYABLE: &str = "Only pending Donas can be paid";
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum PaymentStatus {
+    Pending,
+    Completed,
+    Failed,
+}
+
+/// Where the donor is sent to pay, `reference` identifies the payment at the provider.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct PaymentCheckout {
+    pub reference: String,
+    pub url: String,
+}
+
+/// A payment as the provider reports it, either in a callback or when asked for it.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct GatewayPayment {
+    pub dona_id: DonaId,
+    pub reference: String,
+    pub status: PaymentStatus,
+    pub amount: Decimal,
+    pub currency: String,
+    /// Account the provider says it paid, for providers that report one.
+    pub receiver: Option<String>,
+}
+
+impl GatewayPayment {
+    /// The provider must have charged the exact amount and currency of the dona.
+    pub fn matches(&self, dona: &Dona) -> bool {
+        dona.id() == self.dona_id.to_string()
+            && dona.amount() == self.amount
+            && dona.currency() == self.currency
+    }
+}
+
+/// Behaviour behind a [`DonaOptionMethod`]. Gateways are in charge of authenticating what they
+/// parse, a callback that cannot be trusted must fail instead of returning a payment.
+#[async_trait::async_trait]
+pub trait PaymentGateway: Send + Sync {
+    fn method(&self) -> DonaOptionMethod;
+    async fn create_checkout(&self, dona: &Dona) -> Result<PaymentCheckout, String>;
+    async fn parse_callback(&self, payload: &str) -> Result<GatewayPayment, String>;
+    async fn lookup_payment(&self, reference: &str) -> Result<GatewayPayment, String>;
+}
+
+/// Gateways by the method they handle. Methods without one, like manual donas, are settled by
+/// the recipient.
+#[derive(Clone, Default)]
+pub struct PaymentGatewayRegistry {
+    gateways: HashMap<DonaOptionMethod, Arc<dyn PaymentGateway>>,
+}
+
+impl PaymentGatewayRegistry {
+    /// A later gateway for the same method replaces the earlier one.
+    pub fn new(gateways: Vec<Arc<dyn PaymentGateway>>) -> Self {
+        Self {
+            gateways: gateways
+                .into_iter()
+                .map(|gateway| (gateway.method(), gateway))
+                .collect(),
+        }
+    }
+
+    pub fn get(&self, method: &DonaOptionMethod) -> Result<Arc<dyn PaymentGateway>, String> {
+        self.gateways
+            .get(method)
+            .cloned()
+            .ok_or(ERR_PAYMENT_GATEWAY_NOT_FOUND.to_string())
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use mockall::mock;
+
+    use super::*;
+
+    mock! {
+        pub PaymentGateway {}
+
+        #[async_trait::async_trait]
+        impl PaymentGateway for PaymentGateway {
+            fn method(&self) -> DonaOptionMethod;
+            async fn create_checkout(&self, dona: &Dona) -> Result<PaymentCheckout, String>;
+            async fn parse_callback(&self, payload: &str) -> Result<GatewayPayment, String>;
+            async fn lookup_payment(&self, reference: &str) -> Result<GatewayPayment, String>;
+        }
+    }
+}
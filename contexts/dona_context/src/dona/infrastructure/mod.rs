@@ -1 +1,3 @@
+pub mod payment;
+pub mod paypal;
 pub mod persistence;
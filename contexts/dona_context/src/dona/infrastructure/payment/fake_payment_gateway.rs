@@ -0,0 +1,104 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use serde::{Deserialize, Serialize};
+use shared::domain::utils::new_uuid;
+
+use crate::{
+    dona::domain::{
+        dona::{Dona, DonaId},
+        payment_gateway::{GatewayPayment, PaymentCheckout, PaymentGateway, PaymentStatus},
+    },
+    shared::domain::dona::DonaOptionMethod,
+};
+
+pub const FAKE_CHECKOUT_URL: &str = "https://fake-gateway.invalid/checkout";
+pub const ERR_UNKNOWN_FAKE_PAYMENT: &str = "Unknown fake payment";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct FakeCallback {
+    reference: String,
+}
+
+/// In-process gateway that keeps its payments in memory, for tests and local development.
+/// Payments start pending and are settled with [`FakePaymentGateway::complete`] or
+/// [`FakePaymentGateway::fail`], which return the callback the provider would post.
+pub struct FakePaymentGateway {
+    method: DonaOptionMethod,
+    payments: Mutex<HashMap<String, GatewayPayment>>,
+}
+
+impl FakePaymentGateway {
+    pub fn new(method: DonaOptionMethod) -> Self {
+        Self {
+            method,
+            payments: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn settle(&self, reference: &str, status: PaymentStatus) -> Result<String, String> {
+        let mut payments = self.payments.lock().unwrap();
+        let payment = payments
+            .get_mut(reference)
+            .ok_or(ERR_UNKNOWN_FAKE_PAYMENT.to_string())?;
+        payment.status = status;
+
+        serde_json::to_string(&FakeCallback {
+            reference: reference.to_string(),
+        })
+        .map_err(|e| e.to_string())
+    }
+
+    pub fn complete(&self, reference: &str) -> Result<String, String> {
+        self.settle(reference, PaymentStatus::Completed)
+    }
+
+    pub fn fail(&self, reference: &str) -> Result<String, String> {
+        self.settle(reference, PaymentStatus::Failed)
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentGateway for FakePaymentGateway {
+    fn method(&self) -> DonaOptionMethod {
+        self.method.clone()
+    }
+
+    async fn create_checkout(&self, dona: &Dona) -> Result<PaymentCheckout, String> {
+        let reference = new_uuid();
+        let payment = GatewayPayment {
+            dona_id: DonaId::new(dona.id())?,
+            reference: reference.clone(),
+            status: PaymentStatus::Pending,
+            amount: dona.amount(),
+            currency: dona.currency(),
+            receiver: None,
+        };
+        self.payments
+            .lock()
+            .unwrap()
+            .insert(reference.clone(), payment);
+
+        Ok(PaymentCheckout {
+            url: format!("{}/{}", FAKE_CHECKOUT_URL, reference),
+            reference,
+        })
+    }
+
+    /// Only references handed out by this gateway are accepted, the same way a real provider
+    /// signs its callbacks.
+    async fn parse_callback(&self, payload: &str) -> Result<GatewayPayment, String> {
+        let callback = serde_json::from_str::<FakeCallback>(payload)
+            .map_err(|_| ERR_UNKNOWN_FAKE_PAYMENT.to_string())?;
+
+        self.lookup_payment(&callback.reference).await
+    }
+
+    async fn lookup_payment(&self, reference: &str) -> Result<GatewayPayment, String> {
+        self.payments
+            .lock()
+            .unwrap()
+            .get(reference)
+            .cloned()
+            .ok_or(ERR_UNKNOWN_FAKE_PAYMENT.to_string())
+    }
+}
@@ -0,0 +1,159 @@
+use std::time::Duration;
+
+use super::paypal_notification_verifier::PaypalNotificationVerifier;
+
+/// Live IPN verification endpoint, the sandbox one is
+/// `https://ipnpb.sandbox.paypal.com/cgi-bin/webscr`.
+pub const PAYPAL_IPN_VERIFY_URL: &str = "https://ipnpb.paypal.com/cgi-bin/webscr";
+
+/// PayPal delivers the notification again when it gets no answer, so a slow verification is
+/// better given up than left holding the request.
+const VERIFY_TIMEOUT: Duration = Duration::from_secs(10);
+
+const VERIFIED: &str = "VERIFIED";
+const INVALID: &str = "INVALID";
+
+/// Posts the notification back to PayPal prefixed with `cmd=_notify-validate`, which answers
+/// `VERIFIED` or `INVALID`.
+pub struct HttpPaypalNotificationVerifier {
+    client: reqwest::Client,
+    url: String,
+}
+
+impl HttpPaypalNotificationVerifier {
+    pub fn new(url: String) -> Self {
+        Self::with_timeout(url, VERIFY_TIMEOUT)
+    }
+
+    pub fn with_timeout(url: String, timeout: Duration) -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(timeout)
+                .build()
+                .expect("PayPal verification client should build"),
+            url,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl PaypalNotificationVerifier for HttpPaypalNotificationVerifier {
+    async fn verify(&self, payload: &str) -> Result<bool, String> {
+        let response = self
+            .client
+            .post(&self.url)
+            .header(
+                reqwest::header::CONTENT_TYPE,
+                "application/x-www-form-urlencoded",
+            )
+            .body(format!("cmd=_notify-validate&{}", payload))
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| e.to_string())?;
+        let answer = response.text().await.map_err(|e| e.to_string())?;
+
+        match answer.trim() {
+            VERIFIED => Ok(true),
+            INVALID => Ok(false),
+            answer => Err(format!("Unexpected PayPal verification answer: {}", answer)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+        task::JoinHandle,
+    };
+
+    use super::*;
+
+    /// Answers a single request with `answer` and hands back the body it received.
+    async fn fake_verification_server(answer: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/cgi-bin/webscr", listener.local_addr().unwrap());
+
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0; 1024];
+            let body = loop {
+                let read = stream.read(&mut buffer).await.unwrap();
+                request.extend_from_slice(&buffer[..read]);
+
+                let text = String::from_utf8_lossy(&request).to_string();
+                if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                    let length = head
+                        .lines()
+                        .find_map(|line| {
+                            line.to_lowercase()
+                                .strip_prefix("content-length:")
+                                .map(|value| value.trim().parse::<usize>().unwrap())
+                        })
+                        .unwrap_or_default();
+                    if body.len() >= length {
+                        break body.to_string();
+                    }
+                }
+            };
+
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                answer.len(),
+                answer
+            );
+            stream.write_all(response.as_bytes()).await.unwrap();
+
+            body
+        });
+
+        (url, server)
+    }
+
+    #[tokio::test]
+    async fn it_should_post_the_notification_back_for_validation() {
+        let (url, server) = fake_verification_server("VERIFIED").await;
+        let verifier = HttpPaypalNotificationVerifier::new(url);
+
+        let verified = verifier.verify("txn_id=1&custom=abc").await;
+
+        assert_eq!(verified, Ok(true));
+        assert_eq!(
+            server.await.unwrap(),
+            "cmd=_notify-validate&txn_id=1&custom=abc"
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_not_verify_invalid_notifications() {
+        let (url, _server) = fake_verification_server("INVALID").await;
+        let verifier = HttpPaypalNotificationVerifier::new(url);
+
+        assert_eq!(verifier.verify("txn_id=1").await, Ok(false));
+    }
+
+    #[tokio::test]
+    async fn it_should_give_up_when_paypal_does_not_answer() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/cgi-bin/webscr", listener.local_addr().unwrap());
+        let _server = tokio::spawn(async move {
+            let (_stream, _) = listener.accept().await.unwrap();
+            tokio::time::sleep(Duration::from_secs(60)).await;
+        });
+        let verifier =
+            HttpPaypalNotificationVerifier::with_timeout(url, Duration::from_millis(200));
+
+        assert!(verifier.verify("txn_id=1").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_on_unexpected_answers() {
+        let (url, _server) = fake_verification_server("<html>Maintenance</html>").await;
+        let verifier = HttpPaypalNotificationVerifier::new(url);
+
+        assert!(verifier.verify("txn_id=1").await.is_err());
+    }
+}
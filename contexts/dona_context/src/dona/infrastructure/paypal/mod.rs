@@ -0,0 +1,4 @@
+pub mod http_paypal_notification_verifier;
+pub mod paypal_notification;
+pub mod paypal_notification_verifier;
+pub mod paypal_payment_gateway;
@@ -0,0 +1,129 @@
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+
+use crate::dona::domain::{
+    dona::DonaId,
+    payment_gateway::{GatewayPayment, PaymentStatus},
+};
+
+pub const ERR_INVALID_PAYPAL_NOTIFICATION: &str = "Invalid PayPal notification";
+pub const ERR_PAYPAL_NOTIFICATION_NOT_VERIFIED: &str = "PayPal notification could not be verified";
+
+/// Instant Payment Notification sent by PayPal once a payment changes. The checkout puts the
+/// id of the dona in the `custom` field, which PayPal sends back untouched. Anyone can set
+/// `custom` on their own button, so the receiver must be checked against the recipient.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaypalNotification {
+    txn_id: String,
+    payment_status: String,
+    dona_id: DonaId,
+    gross: Decimal,
+    currency: String,
+    receiver_email: String,
+}
+
+impl PaypalNotification {
+    /// `payload` is the form encoded body exactly as PayPal posted it.
+    pub fn parse(payload: &str) -> Result<Self, String> {
+        let fields = serde_urlencoded::from_str::<Vec<(String, String)>>(payload)
+            .map_err(|_| ERR_INVALID_PAYPAL_NOTIFICATION.to_string())?;
+        let field = |name: &str| {
+            fields
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+                .ok_or(ERR_INVALID_PAYPAL_NOTIFICATION.to_string())
+        };
+
+        Ok(Self {
+            txn_id: field("txn_id")?,
+            payment_status: field("payment_status")?,
+            dona_id: DonaId::new(field("custom")?)
+                .map_err(|_| ERR_INVALID_PAYPAL_NOTIFICATION.to_string())?,
+            gross: Decimal::from_str(&field("mc_gross")?)
+                .map_err(|_| ERR_INVALID_PAYPAL_NOTIFICATION.to_string())?,
+            currency: field("mc_currency")?.to_uppercase(),
+            receiver_email: field("receiver_email")?,
+        })
+    }
+
+    /// PayPal notifies pending, refunded or reversed payments too, those never confirm a dona.
+    pub fn status(&self) -> PaymentStatus {
+        match self.payment_status.as_str() {
+            "Completed" => PaymentStatus::Completed,
+            "Pending" | "In-Progress" | "Processed" => PaymentStatus::Pending,
+            _ => PaymentStatus::Failed,
+        }
+    }
+
+    pub fn txn_id(&self) -> String {
+        self.txn_id.clone()
+    }
+
+    pub fn dona_id(&self) -> DonaId {
+        self.dona_id.clone()
+    }
+
+    pub fn gross(&self) -> Decimal {
+        self.gross
+    }
+
+    pub fn currency(&self) -> String {
+        self.currency.clone()
+    }
+
+    /// Primary email of the PayPal account that received the payment.
+    pub fn receiver_email(&self) -> String {
+        self.receiver_email.clone()
+    }
+}
+
+impl From<PaypalNotification> for GatewayPayment {
+    fn from(notification: PaypalNotification) -> Self {
+        Self {
+            status: notification.status(),
+            dona_id: notification.dona_id,
+            reference: notification.txn_id,
+            amount: notification.gross,
+            currency: notification.currency,
+            receiver: Some(notification.receiver_email),
+        }
+    }
+}
+
+pub mod tests {
+    use rust_decimal::Decimal;
+
+    use crate::dona::domain::dona::Dona;
+
+    /// Account every notification of the mother was paid to.
+    pub const PAYPAL_RECEIVER_EMAIL: &str = "recipient@example.com";
+
+    pub struct PaypalNotificationMother;
+
+    impl PaypalNotificationMother {
+        /// Form encoded body of a notification paying `dona` in full.
+        pub fn payload(dona: &Dona, payment_status: &str) -> String {
+            Self::payload_with(&dona.id(), payment_status, dona.amount(), &dona.currency())
+        }
+
+        pub fn payload_with(
+            dona_id: &str,
+            payment_status: &str,
+            gross: Decimal,
+            currency: &str,
+        ) -> String {
+            serde_urlencoded::to_string([
+                ("txn_id", "61E67681CH3238416"),
+                ("payment_status", payment_status),
+                ("custom", dona_id),
+                ("mc_gross", gross.to_string().as_str()),
+                ("mc_currency", currency),
+                ("receiver_email", PAYPAL_RECEIVER_EMAIL),
+            ])
+            .unwrap()
+        }
+    }
+}
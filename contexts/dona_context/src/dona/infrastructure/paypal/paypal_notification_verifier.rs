@@ -0,0 +1,22 @@
+/// Asks PayPal whether a notification really comes from it, since anyone can post to the
+/// notification endpoint.
+#[async_trait::async_trait]
+pub trait PaypalNotificationVerifier: Send + Sync {
+    async fn verify(&self, payload: &str) -> Result<bool, String>;
+}
+
+#[cfg(test)]
+pub mod tests {
+    use mockall::mock;
+
+    use super::*;
+
+    mock! {
+        pub PaypalNotificationVerifier {}
+
+        #[async_trait::async_trait]
+        impl PaypalNotificationVerifier for PaypalNotificationVerifier {
+            async fn verify(&self, payload: &str) -> Result<bool, String>;
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use std::sync::Arc;
+
+use crate::{
+    dona::domain::{
+        dona::Dona,
+        payment_gateway::{
+            GatewayPayment, PaymentCheckout, PaymentGateway,
+            ERR_PAYMENT_GATEWAY_OPERATION_NOT_SUPPORTED,
+        },
+    },
+    shared::domain::dona::DonaOptionMethod,
+};
+
+use super::{
+    paypal_notification::{PaypalNotification, ERR_PAYPAL_NOTIFICATION_NOT_VERIFIED},
+    paypal_notification_verifier::PaypalNotificationVerifier,
+};
+
+/// PayPal Payments Standard. The client renders the PayPal button itself with the dona id as
+/// `custom`, so only the notifications go through the gateway.
+pub struct PaypalPaymentGateway {
+    verifier: Arc<dyn PaypalNotificationVerifier>,
+}
+
+impl PaypalPaymentGateway {
+    pub fn new(verifier: Arc<dyn PaypalNotificationVerifier>) -> Self {
+        Self { verifier }
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentGateway for PaypalPaymentGateway {
+    fn method(&self) -> DonaOptionMethod {
+        DonaOptionMethod::Paypal
+    }
+
+    async fn create_checkout(&self, _dona: &Dona) -> Result<PaymentCheckout, String> {
+        Err(ERR_PAYMENT_GATEWAY_OPERATION_NOT_SUPPORTED.to_string())
+    }
+
+    async fn parse_callback(&self, payload: &str) -> Result<GatewayPayment, String> {
+        let notification = PaypalNotification::parse(payload)?;
+        if !self.verifier.verify(payload).await? {
+            return Err(ERR_PAYPAL_NOTIFICATION_NOT_VERIFIED.to_string());
+        }
+
+        Ok(notification.into())
+    }
+
+    async fn lookup_payment(&self, _reference: &str) -> Result<GatewayPayment, String> {
+        Err(ERR_PAYMENT_GATEWAY_OPERATION_NOT_SUPPORTED.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate;
+    use rust_decimal_macros::dec;
+    use shared::domain::utils::new_uuid;
+
+    use super::*;
+
+    use crate::dona::domain::dona::DonaId;
+    use crate::dona::domain::payment_gateway::PaymentStatus;
+    use crate::dona::infrastructure::paypal::paypal_notification::tests::{
+        PaypalNotificationMother, PAYPAL_RECEIVER_EMAIL,
+    };
+    use crate::dona::infrastructure::paypal::paypal_notification::ERR_INVALID_PAYPAL_NOTIFICATION;
+    use crate::dona::infrastructure::paypal::paypal_notification_verifier::tests::MockPaypalNotificationVerifier;
+
+    fn gateway(verified: bool) -> PaypalPaymentGateway {
+        let mut verifier = MockPaypalNotificationVerifier::new();
+        verifier.expect_verify().return_const(Ok(verified));
+        PaypalPaymentGateway::new(Arc::new(verifier))
+    }
+
+    #[tokio::test]
+    async fn it_should_parse_verified_notifications() {
+        let dona_id = new_uuid();
+
+        for (payment_status, status) in [
+            ("Completed", PaymentStatus::Completed),
+            ("Pending", PaymentStatus::Pending),
+            ("Reversed", PaymentStatus::Failed),
+        ] {
+            let payload = PaypalNotificationMother::payload_with(
+                &dona_id,
+                payment_status,
+                dec!(10.00),
+                "usd",
+            );
+
+            let mut verifier = MockPaypalNotificationVerifier::new();
+            verifier
+                .expect_verify()
+                .with(predicate::eq(payload.clone()))
+                .times(1)
+                .return_const(Ok(true));
+            let gateway = PaypalPaymentGateway::new(Arc::new(verifier));
+
+            assert_eq!(
+                gateway.parse_callback(&payload).await,
+                Ok(GatewayPayment {
+                    dona_id: DonaId::new(dona_id.clone()).unwrap(),
+                    reference: "61E67681CH3238416".to_string(),
+                    status,
+                    amount: dec!(10.00),
+                    currency: "USD".to_string(),
+                    receiver: Some(PAYPAL_RECEIVER_EMAIL.to_string()),
+                })
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_reject_unverified_or_malformed_notifications() {
+        let payload =
+            PaypalNotificationMother::payload_with(&new_uuid(), "Completed", dec!(10), "USD");
+
+        assert_eq!(
+            gateway(false).parse_callback(&payload).await,
+            Err(ERR_PAYPAL_NOTIFICATION_NOT_VERIFIED.to_string())
+        );
+        assert_eq!(
+            gateway(true)
+                .parse_callback("txn_id=1&payment_status=Completed&custom=not-a-dona")
+                .await,
+            Err(ERR_INVALID_PAYPAL_NOTIFICATION.to_string())
+        );
+    }
+}
@@ -1 +1,2 @@
+pub mod sea_dona_reply_repo;
 pub mod sea_dona_repo;
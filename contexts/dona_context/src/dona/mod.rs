@@ -1,2 +1,3 @@
+pub mod application;
 pub mod domain;
 pub mod infrastructure;
@@ -0,0 +1,177 @@
+use rust_decimal::Decimal;
+use shared::domain::bus::query::{Query, QueryError, QueryHandler, Response};
+use time::OffsetDateTime;
+
+use super::service::CurrencyConverter;
+
+pub const CONVERT_AMOUNT_QUERY_TYPE: &str = "dona.convert_amount.query";
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ConvertAmountQuery {
+    pub amount: Decimal,
+    pub from: String,
+    pub to: String,
+    pub at: OffsetDateTime,
+}
+
+impl Query for ConvertAmountQuery {
+    fn query_type(&self) -> &'static str {
+        CONVERT_AMOUNT_QUERY_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct ConvertAmountQueryHandler {
+    service: CurrencyConverter,
+}
+
+impl ConvertAmountQueryHandler {
+    pub fn new(service: CurrencyConverter) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler for ConvertAmountQueryHandler {
+    async fn handle(&self, query: Box<dyn Query>) -> Result<Box<dyn Response>, QueryError> {
+        let query = query
+            .as_any()
+            .downcast_ref::<ConvertAmountQuery>()
+            .ok_or_else(|| QueryError::new("Invalid query".to_string()))?;
+
+        let converted = self
+            .service
+            .execute(
+                query.amount,
+                query.from.to_owned(),
+                query.to.to_owned(),
+                query.at,
+            )
+            .await
+            .map_err(QueryError::new)?;
+
+        Ok(Box::new(converted))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use rust_decimal_macros::dec;
+    use shared::domain::base_errors::BaseRepositoryError;
+    use time::macros::{date, datetime};
+
+    use super::*;
+
+    use crate::exchange_rate::application::convert::service::ERR_EXCHANGE_RATE_NOT_FOUND;
+    use crate::exchange_rate::application::response::ConvertedAmountResponse;
+    use crate::exchange_rate::domain::exchange_rate::tests::ExchangeRateMother;
+    use crate::exchange_rate::domain::exchange_rate::ExchangeRate;
+    use crate::exchange_rate::domain::exchange_rate_repository::tests::MockExchangeRateRepository;
+
+    fn query(amount: Decimal, from: &str, to: &str) -> ConvertAmountQuery {
+        ConvertAmountQuery {
+            amount,
+            from: from.to_string(),
+            to: to.to_string(),
+            at: datetime!(2024-01-15 12:00 UTC),
+        }
+    }
+
+    fn eur_usd() -> ExchangeRate {
+        ExchangeRateMother::create(
+            None,
+            Some("EUR".to_string()),
+            Some("USD".to_string()),
+            Some(dec!(1.25)),
+            None,
+        )
+    }
+
+    async fn converted(
+        repository: MockExchangeRateRepository,
+        query: ConvertAmountQuery,
+    ) -> Result<ConvertedAmountResponse, QueryError> {
+        let handler = ConvertAmountQueryHandler::new(CurrencyConverter::new(Arc::new(repository)));
+
+        handler.handle(Box::new(query)).await.map(|response| {
+            response
+                .as_any()
+                .downcast_ref::<ConvertedAmountResponse>()
+                .unwrap()
+                .clone()
+        })
+    }
+
+    #[tokio::test]
+    async fn it_should_not_convert_same_currency() {
+        let mut repository = MockExchangeRateRepository::new();
+        repository.expect_find_effective().times(0);
+
+        let result = converted(repository, query(dec!(10.00), "EUR", "EUR")).await;
+
+        assert_eq!(
+            result.unwrap(),
+            ConvertedAmountResponse {
+                amount: dec!(10.00),
+                currency: "EUR".to_string(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_convert_with_direct_rate() {
+        let mut repository = MockExchangeRateRepository::new();
+        repository
+            .expect_find_effective()
+            .withf(|base, quote, date| {
+                base.code() == "EUR" && quote.code() == "USD" && *date == date!(2024 - 01 - 15)
+            })
+            .times(1)
+            .return_const(Ok(eur_usd()));
+
+        let result = converted(repository, query(dec!(10.00), "EUR", "USD")).await;
+
+        assert_eq!(result.unwrap().amount, dec!(12.50));
+    }
+
+    #[tokio::test]
+    async fn it_should_convert_with_inverse_rate() {
+        let mut repository = MockExchangeRateRepository::new();
+        repository
+            .expect_find_effective()
+            .withf(|base, _, _| base.code() == "USD")
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        repository
+            .expect_find_effective()
+            .withf(|base, _, _| base.code() == "EUR")
+            .times(1)
+            .return_const(Ok(eur_usd()));
+
+        let result = converted(repository, query(dec!(10.00), "USD", "EUR")).await;
+
+        assert_eq!(result.unwrap().amount, dec!(8.00));
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_no_rate_exists() {
+        let mut repository = MockExchangeRateRepository::new();
+        repository
+            .expect_find_effective()
+            .times(2)
+            .returning(|_, _, _| Err(BaseRepositoryError::NotFound));
+
+        let result = converted(repository, query(dec!(10.00), "USD", "EUR")).await;
+
+        assert_eq!(
+            result,
+            Err(QueryError::new(ERR_EXCHANGE_RATE_NOT_FOUND.to_string()))
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use shared::domain::{base_errors::BaseRepositoryError, value_objects::money::Currency};
+use time::OffsetDateTime;
+
+use crate::exchange_rate::{
+    application::response::ConvertedAmountResponse,
+    domain::exchange_rate_repository::ExchangeRateRepository,
+};
+
+pub const ERR_EXCHANGE_RATE_NOT_FOUND: &str = "No exchange rate found for the currency pair";
+
+#[derive(Clone)]
+pub struct CurrencyConverter {
+    repository: Arc<dyn ExchangeRateRepository>,
+}
+
+impl CurrencyConverter {
+    pub fn new(repository: Arc<dyn ExchangeRateRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Converts `amount` using the rate in effect on the day of `at`. A rate stored for the
+    /// opposite pair is used inverted. The result is not rounded.
+    pub async fn convert(
+        &self,
+        amount: Decimal,
+        from: &Currency,
+        to: &Currency,
+        at: OffsetDateTime,
+    ) -> Result<Decimal, String> {
+        if from == to {
+            return Ok(amount);
+        }
+
+        let date = at.date();
+        match self
+            .repository
+            .find_effective(from.clone(), to.clone(), date)
+            .await
+        {
+            Ok(rate) => return Ok(rate.convert(amount, false)),
+            Err(BaseRepositoryError::NotFound) => {}
+            Err(e) => return Err(e.to_string()),
+        }
+
+        match self
+            .repository
+            .find_effective(to.clone(), from.clone(), date)
+            .await
+        {
+            Ok(rate) => Ok(rate.convert(amount, true)),
+            Err(BaseRepositoryError::NotFound) => Err(ERR_EXCHANGE_RATE_NOT_FOUND.to_string()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        amount: Decimal,
+        from: String,
+        to: String,
+        at: OffsetDateTime,
+    ) -> Result<ConvertedAmountResponse, String> {
+        let from = Currency::new(from)?;
+        let to = Currency::new(to)?;
+
+        let converted = self.convert(amount, &from, &to, at).await?;
+
+        Ok(ConvertedAmountResponse {
+            amount: converted.round_dp(to.precision()),
+            currency: to.to_string(),
+        })
+    }
+}
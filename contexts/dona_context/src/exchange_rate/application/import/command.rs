@@ -0,0 +1,211 @@
+use shared::domain::bus::command::{Command, CommandError, CommandHandler};
+use time::OffsetDateTime;
+
+use super::service::ExchangeRatesImporter;
+
+pub const IMPORT_EXCHANGE_RATES_COMMAND_TYPE: &str = "dona.import_exchange_rates.command";
+
+#[derive(Debug)]
+pub struct ImportExchangeRatesCommand {
+    pub csv: String,
+    pub updated_at: OffsetDateTime,
+}
+
+impl Command for ImportExchangeRatesCommand {
+    fn command_type(&self) -> &'static str {
+        IMPORT_EXCHANGE_RATES_COMMAND_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct ImportExchangeRatesCommandHandler {
+    service: ExchangeRatesImporter,
+}
+
+impl ImportExchangeRatesCommandHandler {
+    pub fn new(service: ExchangeRatesImporter) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler for ImportExchangeRatesCommandHandler {
+    async fn handle(&self, command: Box<dyn Command>) -> Result<(), CommandError> {
+        let command = command
+            .as_any()
+            .downcast_ref::<ImportExchangeRatesCommand>()
+            .ok_or_else(|| CommandError::new("Invalid command".to_string()))?;
+
+        self.service
+            .execute(command.csv.to_owned(), command.updated_at)
+            .await
+            .map_err(CommandError::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use rust_decimal_macros::dec;
+    use shared::domain::base_errors::BaseRepositoryError;
+    use shared::domain::bus::event::tests::MockEventBus;
+    use shared::domain::value_objects::money::ERR_INVALID_CURRENCY;
+
+    use super::*;
+
+    use crate::exchange_rate::application::import::service::ERR_INVALID_EXCHANGE_RATES_CSV_HEADER;
+    use crate::exchange_rate::application::upsert::service::ExchangeRateUpserter;
+    use crate::exchange_rate::domain::exchange_rate::ERR_INVALID_EXCHANGE_RATE;
+    use crate::exchange_rate::domain::exchange_rate_repository::tests::MockExchangeRateRepository;
+
+    fn handler(
+        repository: MockExchangeRateRepository,
+        event_bus: MockEventBus,
+    ) -> ImportExchangeRatesCommandHandler {
+        let upserter = ExchangeRateUpserter::new(Arc::new(repository), Arc::new(event_bus));
+        ImportExchangeRatesCommandHandler::new(ExchangeRatesImporter::new(upserter))
+    }
+
+    #[tokio::test]
+    async fn it_should_import_every_row() {
+        let mut repository = MockExchangeRateRepository::new();
+        repository
+            .expect_find_by_pair_and_date()
+            .times(2)
+            .returning(|_, _, _| Err(BaseRepositoryError::NotFound));
+        repository.expect_save().times(0);
+        repository
+            .expect_save_all()
+            .withf(|exchange_rates| exchange_rates.len() == 2)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus
+            .expect_publish()
+            .withf(|events| events.len() == 2)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let command = ImportExchangeRatesCommand {
+            csv: "base_currency,quote_currency,rate,date\nEUR,USD,1.0850,2024-01-01\nusd,ars,830.50,2024-01-01\n"
+                .to_string(),
+            updated_at: OffsetDateTime::now_utc(),
+        };
+        let result = handler(repository, event_bus)
+            .handle(Box::new(command))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_keep_the_last_rate_of_a_repeated_pair() {
+        let mut repository = MockExchangeRateRepository::new();
+        repository
+            .expect_find_by_pair_and_date()
+            .times(1)
+            .returning(|_, _, _| Err(BaseRepositoryError::NotFound));
+        repository
+            .expect_save_all()
+            .withf(|exchange_rates| {
+                exchange_rates.len() == 1 && exchange_rates[0].rate() == dec!(1.0900)
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(1).returning(|_| Ok(()));
+
+        let command = ImportExchangeRatesCommand {
+            csv: "base_currency,quote_currency,rate,date\nEUR,USD,1.0850,2024-01-01\nEUR,USD,1.0900,2024-01-01\n"
+                .to_string(),
+            updated_at: OffsetDateTime::now_utc(),
+        };
+        let result = handler(repository, event_bus)
+            .handle(Box::new(command))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_not_publish_when_saving_fails() {
+        let mut repository = MockExchangeRateRepository::new();
+        repository
+            .expect_find_by_pair_and_date()
+            .times(2)
+            .returning(|_, _, _| Err(BaseRepositoryError::NotFound));
+        repository
+            .expect_save_all()
+            .times(1)
+            .returning(|_| Err(BaseRepositoryError::UnexpectedError("error".to_string())));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let command = ImportExchangeRatesCommand {
+            csv: "base_currency,quote_currency,rate,date\nEUR,USD,1.0850,2024-01-01\nusd,ars,830.50,2024-01-01\n"
+                .to_string(),
+            updated_at: OffsetDateTime::now_utc(),
+        };
+        let result = handler(repository, event_bus)
+            .handle(Box::new(command))
+            .await;
+
+        assert!(result.is_err(), "Result should be an error");
+    }
+
+    #[tokio::test]
+    async fn it_should_report_invalid_rows_without_saving() {
+        let mut repository = MockExchangeRateRepository::new();
+        repository.expect_find_by_pair_and_date().times(0);
+        repository.expect_save_all().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let command = ImportExchangeRatesCommand {
+            csv: "base_currency,quote_currency,rate,date\nEUR,USD,1.0850,2024-01-01\n\nXXX,USD,1,2024-01-01\nEUR,USD,-2,2024-01-01\n"
+                .to_string(),
+            updated_at: OffsetDateTime::now_utc(),
+        };
+        let result = handler(repository, event_bus)
+            .handle(Box::new(command))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(format!(
+                "Line 4: {}; Line 5: {}",
+                ERR_INVALID_CURRENCY, ERR_INVALID_EXCHANGE_RATE
+            )))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_header_is_missing() {
+        let mut repository = MockExchangeRateRepository::new();
+        repository.expect_save_all().times(0);
+
+        let command = ImportExchangeRatesCommand {
+            csv: "EUR,USD,1.0850,2024-01-01\n".to_string(),
+            updated_at: OffsetDateTime::now_utc(),
+        };
+        let result = handler(repository, MockEventBus::new())
+            .handle(Box::new(command))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(
+                ERR_INVALID_EXCHANGE_RATES_CSV_HEADER.to_string()
+            ))
+        );
+    }
+}
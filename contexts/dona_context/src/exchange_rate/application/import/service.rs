@@ -0,0 +1,129 @@
+use rust_decimal::Decimal;
+use shared::domain::{csv::parse_csv_records, utils::new_uuid, value_objects::money::Currency};
+use std::{collections::HashMap, str::FromStr};
+use time::{Date, OffsetDateTime};
+
+use crate::exchange_rate::{
+    application::upsert::service::ExchangeRateUpserter,
+    domain::exchange_rate::{ExchangeRateDate, ExchangeRateValue, ERR_INVALID_EXCHANGE_RATE},
+};
+
+pub const EXCHANGE_RATES_CSV_HEADER: [&str; 4] =
+    ["base_currency", "quote_currency", "rate", "date"];
+pub const ERR_INVALID_EXCHANGE_RATES_CSV_HEADER: &str =
+    "Exchange rates CSV must start with the header base_currency,quote_currency,rate,date";
+pub const ERR_EMPTY_EXCHANGE_RATES_CSV: &str = "Exchange rates CSV has no rows";
+
+struct ExchangeRateRow {
+    base_currency: String,
+    quote_currency: String,
+    rate: Decimal,
+    date: Date,
+}
+
+#[derive(Clone)]
+pub struct ExchangeRatesImporter {
+    upserter: ExchangeRateUpserter,
+}
+
+impl ExchangeRatesImporter {
+    pub fn new(upserter: ExchangeRateUpserter) -> Self {
+        Self { upserter }
+    }
+
+    fn parse_row(fields: &[String]) -> Result<ExchangeRateRow, String> {
+        let [base_currency, quote_currency, rate, date] = fields else {
+            return Err(format!(
+                "Expected {} columns, found {}",
+                EXCHANGE_RATES_CSV_HEADER.len(),
+                fields.len()
+            ));
+        };
+
+        let base_currency = Currency::new(base_currency.trim().to_uppercase())?;
+        let quote_currency = Currency::new(quote_currency.trim().to_uppercase())?;
+        let rate = Decimal::from_str(rate.trim()).map_err(|_| ERR_INVALID_EXCHANGE_RATE)?;
+
+        Ok(ExchangeRateRow {
+            base_currency: base_currency.to_string(),
+            quote_currency: quote_currency.to_string(),
+            rate: ExchangeRateValue::new(rate)?.value(),
+            date: ExchangeRateDate::parse(date.trim())?.value(),
+        })
+    }
+
+    /// Every row is validated before anything is written, so a file with a single bad line
+    /// leaves the rates untouched and the error lists all the offending lines. Valid files are
+    /// saved in a single transaction.
+    pub async fn execute(&self, csv: String, updated_at: OffsetDateTime) -> Result<(), String> {
+        let rows = parse_csv_records(&csv)?;
+        let (header, rows) = rows
+            .split_first()
+            .ok_or(ERR_EMPTY_EXCHANGE_RATES_CSV.to_string())?;
+
+        if header
+            .fields
+            .iter()
+            .map(|h| h.trim())
+            .ne(EXCHANGE_RATES_CSV_HEADER)
+        {
+            return Err(ERR_INVALID_EXCHANGE_RATES_CSV_HEADER.to_string());
+        }
+        if rows.is_empty() {
+            return Err(ERR_EMPTY_EXCHANGE_RATES_CSV.to_string());
+        }
+
+        let mut parsed = vec![];
+        let mut errors = vec![];
+        for row in rows {
+            match Self::parse_row(&row.fields) {
+                Ok(parsed_row) => parsed.push(parsed_row),
+                Err(e) => errors.push(format!("Line {}: {}", row.line, e)),
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(errors.join("; "));
+        }
+
+        // A pair repeated for the same day keeps the rate of its last row
+        let mut latest: HashMap<(String, String, Date), usize> = HashMap::new();
+        for (index, row) in parsed.iter().enumerate() {
+            latest.insert(
+                (
+                    row.base_currency.clone(),
+                    row.quote_currency.clone(),
+                    row.date,
+                ),
+                index,
+            );
+        }
+
+        let mut exchange_rates = vec![];
+        for (index, row) in parsed.into_iter().enumerate() {
+            if latest[&(
+                row.base_currency.clone(),
+                row.quote_currency.clone(),
+                row.date,
+            )] != index
+            {
+                continue;
+            }
+
+            exchange_rates.push(
+                self.upserter
+                    .upsert(
+                        new_uuid(),
+                        row.base_currency,
+                        row.quote_currency,
+                        row.rate,
+                        row.date,
+                        updated_at,
+                    )
+                    .await?,
+            );
+        }
+
+        self.upserter.save_all(exchange_rates).await
+    }
+}
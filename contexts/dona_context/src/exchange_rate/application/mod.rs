@@ -0,0 +1,4 @@
+pub mod convert;
+pub mod import;
+pub mod response;
+pub mod upsert;
@@ -0,0 +1,15 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use shared::domain::bus::query::Response;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ConvertedAmountResponse {
+    pub amount: Decimal,
+    pub currency: String,
+}
+
+impl Response for ConvertedAmountResponse {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
@@ -0,0 +1,208 @@
+use rust_decimal::Decimal;
+use shared::domain::bus::command::{Command, CommandError, CommandHandler};
+use time::{Date, OffsetDateTime};
+
+use super::service::ExchangeRateUpserter;
+
+pub const UPSERT_EXCHANGE_RATE_COMMAND_TYPE: &str = "dona.upsert_exchange_rate.command";
+
+#[derive(Debug)]
+pub struct UpsertExchangeRateCommand {
+    pub id: String,
+    pub base_currency: String,
+    pub quote_currency: String,
+    pub rate: Decimal,
+    pub date: Date,
+    pub updated_at: OffsetDateTime,
+}
+
+impl Command for UpsertExchangeRateCommand {
+    fn command_type(&self) -> &'static str {
+        UPSERT_EXCHANGE_RATE_COMMAND_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct UpsertExchangeRateCommandHandler {
+    service: ExchangeRateUpserter,
+}
+
+impl UpsertExchangeRateCommandHandler {
+    pub fn new(service: ExchangeRateUpserter) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler for UpsertExchangeRateCommandHandler {
+    async fn handle(&self, command: Box<dyn Command>) -> Result<(), CommandError> {
+        let command = command
+            .as_any()
+            .downcast_ref::<UpsertExchangeRateCommand>()
+            .ok_or_else(|| CommandError::new("Invalid command".to_string()))?;
+
+        self.service
+            .execute(
+                command.id.to_owned(),
+                command.base_currency.to_owned(),
+                command.quote_currency.to_owned(),
+                command.rate,
+                command.date,
+                command.updated_at,
+            )
+            .await
+            .map_err(CommandError::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockall::predicate;
+    use rust_decimal_macros::dec;
+    use shared::domain::base_errors::BaseRepositoryError;
+    use shared::domain::bus::event::tests::MockEventBus;
+    use shared::domain::utils::new_uuid;
+    use time::macros::date;
+
+    use super::*;
+
+    use crate::exchange_rate::domain::exchange_rate::tests::ExchangeRateMother;
+    use crate::exchange_rate::domain::exchange_rate::ERR_SAME_CURRENCY_EXCHANGE_RATE;
+    use crate::exchange_rate::domain::exchange_rate_repository::tests::MockExchangeRateRepository;
+
+    fn command(rate: Decimal) -> UpsertExchangeRateCommand {
+        UpsertExchangeRateCommand {
+            id: new_uuid(),
+            base_currency: "EUR".to_string(),
+            quote_currency: "USD".to_string(),
+            rate,
+            date: date!(2024 - 01 - 01),
+            updated_at: OffsetDateTime::now_utc(),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_create_missing_exchange_rate() {
+        let command = command(dec!(1.0850));
+        let id = command.id.clone();
+
+        let mut repository = MockExchangeRateRepository::new();
+        repository
+            .expect_find_by_pair_and_date()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        repository
+            .expect_save()
+            .withf(move |exchange_rate| {
+                exchange_rate.id() == id && exchange_rate.rate() == dec!(1.0850)
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(1).return_const(Ok(()));
+
+        let service = ExchangeRateUpserter::new(Arc::new(repository), Arc::new(event_bus));
+        let handler = UpsertExchangeRateCommandHandler::new(service);
+
+        let result = handler.handle(Box::new(command)).await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_update_existing_exchange_rate() {
+        let existing = ExchangeRateMother::create(
+            None,
+            Some("EUR".to_string()),
+            Some("USD".to_string()),
+            Some(dec!(1.0500)),
+            Some(date!(2024 - 01 - 01)),
+        );
+        let existing_id = existing.id();
+
+        let mut repository = MockExchangeRateRepository::new();
+        repository
+            .expect_find_by_pair_and_date()
+            .times(1)
+            .return_const(Ok(existing));
+        repository
+            .expect_save()
+            .withf(move |exchange_rate| {
+                exchange_rate.id() == existing_id && exchange_rate.rate() == dec!(1.0850)
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(1).return_const(Ok(()));
+
+        let service = ExchangeRateUpserter::new(Arc::new(repository), Arc::new(event_bus));
+        let handler = UpsertExchangeRateCommandHandler::new(service);
+
+        let result = handler.handle(Box::new(command(dec!(1.0850)))).await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_currencies_are_equal() {
+        let mut repository = MockExchangeRateRepository::new();
+        repository
+            .expect_find_by_pair_and_date()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        repository.expect_save().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let service = ExchangeRateUpserter::new(Arc::new(repository), Arc::new(event_bus));
+        let handler = UpsertExchangeRateCommandHandler::new(service);
+
+        let command = UpsertExchangeRateCommand {
+            quote_currency: "EUR".to_string(),
+            ..command(dec!(1))
+        };
+        let result = handler.handle(Box::new(command)).await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(
+                ERR_SAME_CURRENCY_EXCHANGE_RATE.to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_repository_fails() {
+        let mut repository = MockExchangeRateRepository::new();
+        repository
+            .expect_find_by_pair_and_date()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        repository
+            .expect_save()
+            .with(predicate::always())
+            .times(1)
+            .return_const(Err(BaseRepositoryError::UnexpectedError(
+                "error".to_string(),
+            )));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let service = ExchangeRateUpserter::new(Arc::new(repository), Arc::new(event_bus));
+        let handler = UpsertExchangeRateCommandHandler::new(service);
+
+        let result = handler.handle(Box::new(command(dec!(1.0850)))).await;
+
+        assert!(result.is_err(), "Result should be an error");
+    }
+}
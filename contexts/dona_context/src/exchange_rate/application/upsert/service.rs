@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use shared::domain::{
+    base_errors::BaseRepositoryError, bus::event::EventBus, value_objects::money::Currency,
+};
+use time::{Date, OffsetDateTime};
+
+use crate::exchange_rate::domain::{
+    exchange_rate::{ExchangeRate, ExchangeRateDate},
+    exchange_rate_repository::ExchangeRateRepository,
+};
+
+#[derive(Clone)]
+pub struct ExchangeRateUpserter {
+    repository: Arc<dyn ExchangeRateRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl ExchangeRateUpserter {
+    pub fn new(repository: Arc<dyn ExchangeRateRepository>, event_bus: Arc<dyn EventBus>) -> Self {
+        Self {
+            repository,
+            event_bus,
+        }
+    }
+
+    async fn rate_finder(
+        &self,
+        base_currency: &str,
+        quote_currency: &str,
+        date: Date,
+    ) -> Result<Option<ExchangeRate>, String> {
+        let base_currency = Currency::new(base_currency.to_string())?;
+        let quote_currency = Currency::new(quote_currency.to_string())?;
+        let date = ExchangeRateDate::new(date)?;
+
+        match self
+            .repository
+            .find_by_pair_and_date(base_currency, quote_currency, date.value())
+            .await
+        {
+            Ok(exchange_rate) => Ok(Some(exchange_rate)),
+            Err(BaseRepositoryError::NotFound) => Ok(None),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Sets the rate of a currency pair for a given day without saving it, creating it when
+    /// the pair has no rate for that day yet. `id` is only used in the latter case.
+    pub(crate) async fn upsert(
+        &self,
+        id: String,
+        base_currency: String,
+        quote_currency: String,
+        rate: Decimal,
+        date: Date,
+        updated_at: OffsetDateTime,
+    ) -> Result<ExchangeRate, String> {
+        let existing = self
+            .rate_finder(&base_currency, &quote_currency, date)
+            .await?;
+
+        let exchange_rate = match existing {
+            Some(mut exchange_rate) => {
+                exchange_rate.update_rate(rate, updated_at)?;
+                exchange_rate
+            }
+            None => ExchangeRate::create(
+                id,
+                base_currency,
+                quote_currency,
+                rate,
+                date,
+                updated_at,
+                updated_at,
+            )?,
+        };
+
+        Ok(exchange_rate)
+    }
+
+    pub async fn execute(
+        &self,
+        id: String,
+        base_currency: String,
+        quote_currency: String,
+        rate: Decimal,
+        date: Date,
+        updated_at: OffsetDateTime,
+    ) -> Result<(), String> {
+        let mut exchange_rate = self
+            .upsert(id, base_currency, quote_currency, rate, date, updated_at)
+            .await?;
+
+        self.repository.save(&exchange_rate).await?;
+
+        self.event_bus.publish(exchange_rate.pull_events()).await?;
+
+        Ok(())
+    }
+
+    /// Saves rates built by `upsert` all at once, events are only published once every rate
+    /// is stored.
+    pub(crate) async fn save_all(
+        &self,
+        mut exchange_rates: Vec<ExchangeRate>,
+    ) -> Result<(), String> {
+        self.repository.save_all(&exchange_rates).await?;
+
+        let events = exchange_rates
+            .iter_mut()
+            .flat_map(|exchange_rate| exchange_rate.pull_events())
+            .collect();
+        self.event_bus.publish(events).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,391 @@
+use std::fmt::Display;
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use shared::domain::{bus::event::Event, utils::is_uuid, value_objects::money::Currency};
+use time::{
+    format_description::well_known::Rfc3339, macros::format_description, Date, OffsetDateTime,
+};
+
+use super::{
+    exchange_rate_created_event::ExchangeRateCreatedEvent,
+    exchange_rate_updated_event::ExchangeRateUpdatedEvent,
+};
+
+pub const ERR_INVALID_EXCHANGE_RATE_ID: &str = "Invalid exchange rate id";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExchangeRateId(String);
+
+impl ExchangeRateId {
+    pub fn new(value: String) -> Result<Self, String> {
+        if is_uuid(&value) {
+            return Ok(Self(value));
+        }
+
+        Err(ERR_INVALID_EXCHANGE_RATE_ID.to_string())
+    }
+}
+
+impl Display for ExchangeRateId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub const ERR_INVALID_EXCHANGE_RATE: &str = "Exchange rate must be greater than zero";
+
+/// How many units of the quote currency one unit of the base currency buys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExchangeRateValue(Decimal);
+
+impl ExchangeRateValue {
+    pub fn new(value: Decimal) -> Result<Self, String> {
+        if value > Decimal::ZERO {
+            return Ok(Self(value));
+        }
+
+        Err(ERR_INVALID_EXCHANGE_RATE.to_string())
+    }
+
+    pub fn value(&self) -> Decimal {
+        self.0
+    }
+}
+
+impl Display for ExchangeRateValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub const ERR_INVALID_EXCHANGE_RATE_DATE: &str = "Invalid exchange rate date";
+
+/// The day from which the rate applies, until a rate with a later date replaces it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ExchangeRateDate(Date);
+
+impl ExchangeRateDate {
+    pub fn new(value: Date) -> Result<Self, String> {
+        if value <= OffsetDateTime::now_utc().date() {
+            return Ok(Self(value));
+        }
+
+        Err(ERR_INVALID_EXCHANGE_RATE_DATE.to_string())
+    }
+
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let date = Date::parse(value, format_description!("[year]-[month]-[day]"))
+            .map_err(|_| ERR_INVALID_EXCHANGE_RATE_DATE.to_string())?;
+
+        Self::new(date)
+    }
+
+    pub fn value(&self) -> Date {
+        self.0
+    }
+}
+
+impl Display for ExchangeRateDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub const ERR_INVALID_EXCHANGE_RATE_CREATED_AT: &str = "Invalid exchange rate created at";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExchangeRateCreatedAt(OffsetDateTime);
+
+impl ExchangeRateCreatedAt {
+    pub fn new(value: OffsetDateTime) -> Result<Self, String> {
+        if value < OffsetDateTime::now_utc() {
+            return Ok(Self(value));
+        }
+
+        Err(ERR_INVALID_EXCHANGE_RATE_CREATED_AT.to_string())
+    }
+
+    pub fn value(&self) -> OffsetDateTime {
+        self.0
+    }
+}
+
+impl Display for ExchangeRateCreatedAt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.format(&Rfc3339).unwrap())
+    }
+}
+
+pub const ERR_INVALID_EXCHANGE_RATE_UPDATED_AT: &str = "Invalid exchange rate updated at";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExchangeRateUpdatedAt(OffsetDateTime);
+
+impl ExchangeRateUpdatedAt {
+    pub fn new(value: OffsetDateTime) -> Result<Self, String> {
+        if value < OffsetDateTime::now_utc() {
+            return Ok(Self(value));
+        }
+
+        Err(ERR_INVALID_EXCHANGE_RATE_UPDATED_AT.to_string())
+    }
+
+    pub fn value(&self) -> OffsetDateTime {
+        self.0
+    }
+}
+
+impl Display for ExchangeRateUpdatedAt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.format(&Rfc3339).unwrap())
+    }
+}
+
+pub const ERR_SAME_CURRENCY_EXCHANGE_RATE: &str =
+    "Base and quote currencies of an exchange rate must differ";
+
+#[derive(Debug, Clone)]
+pub struct ExchangeRate {
+    id: ExchangeRateId,
+    base_currency: Currency,
+    quote_currency: Currency,
+    rate: ExchangeRateValue,
+    date: ExchangeRateDate,
+    created_at: ExchangeRateCreatedAt,
+    updated_at: ExchangeRateUpdatedAt,
+
+    events: Vec<Arc<dyn Event>>,
+}
+
+impl PartialEq for ExchangeRate {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.base_currency == other.base_currency
+            && self.quote_currency == other.quote_currency
+            && self.rate == other.rate
+            && self.date == other.date
+            && self.created_at == other.created_at
+            && self.updated_at == other.updated_at
+    }
+}
+
+impl ExchangeRate {
+    pub(crate) fn new(
+        id: String,
+        base_currency: String,
+        quote_currency: String,
+        rate: Decimal,
+        date: Date,
+        created_at: OffsetDateTime,
+        updated_at: OffsetDateTime,
+    ) -> Result<Self, String> {
+        let base_currency = Currency::new(base_currency)?;
+        let quote_currency = Currency::new(quote_currency)?;
+        if base_currency == quote_currency {
+            return Err(ERR_SAME_CURRENCY_EXCHANGE_RATE.to_string());
+        }
+
+        Ok(Self {
+            id: ExchangeRateId::new(id)?,
+            base_currency,
+            quote_currency,
+            rate: ExchangeRateValue::new(rate)?,
+            date: ExchangeRateDate::new(date)?,
+            created_at: ExchangeRateCreatedAt::new(created_at)?,
+            updated_at: ExchangeRateUpdatedAt::new(updated_at)?,
+            events: vec![],
+        })
+    }
+
+    pub fn create(
+        id: String,
+        base_currency: String,
+        quote_currency: String,
+        rate: Decimal,
+        date: Date,
+        created_at: OffsetDateTime,
+        updated_at: OffsetDateTime,
+    ) -> Result<Self, String> {
+        let mut exchange_rate = Self::new(
+            id,
+            base_currency,
+            quote_currency,
+            rate,
+            date,
+            created_at,
+            updated_at,
+        )?;
+
+        exchange_rate.record(Arc::new(ExchangeRateCreatedEvent::new(
+            exchange_rate.id(),
+            exchange_rate.base_currency(),
+            exchange_rate.quote_currency(),
+            exchange_rate.rate.to_string(),
+            exchange_rate.date.to_string(),
+            exchange_rate.created_at.to_string(),
+            exchange_rate.updated_at.to_string(),
+        )));
+
+        Ok(exchange_rate)
+    }
+
+    pub fn update_rate(&mut self, rate: Decimal, updated_at: OffsetDateTime) -> Result<(), String> {
+        self.rate = ExchangeRateValue::new(rate)?;
+        self.updated_at = ExchangeRateUpdatedAt::new(updated_at)?;
+
+        self.record(Arc::new(ExchangeRateUpdatedEvent::new(
+            self.id(),
+            self.rate.to_string(),
+            self.updated_at.to_string(),
+        )));
+
+        Ok(())
+    }
+
+    /// Converts an amount in the base currency into the quote currency, or the other way round
+    /// when `inverse` is set. The result is not rounded, so callers can sum several conversions
+    /// before rounding to the precision of the target currency.
+    pub fn convert(&self, amount: Decimal, inverse: bool) -> Decimal {
+        if inverse {
+            amount / self.rate.value()
+        } else {
+            amount * self.rate.value()
+        }
+    }
+
+    pub fn record(&mut self, event: Arc<dyn Event>) {
+        self.events.push(event);
+    }
+
+    pub fn pull_events(&mut self) -> Vec<Arc<dyn Event>> {
+        let events = self.events.clone();
+        self.events = vec![];
+        events
+    }
+
+    pub fn id(&self) -> String {
+        self.id.to_string()
+    }
+
+    pub fn base_currency(&self) -> String {
+        self.base_currency.to_string()
+    }
+
+    pub fn quote_currency(&self) -> String {
+        self.quote_currency.to_string()
+    }
+
+    pub fn rate(&self) -> Decimal {
+        self.rate.value()
+    }
+
+    pub fn date(&self) -> Date {
+        self.date.value()
+    }
+
+    pub fn created_at(&self) -> OffsetDateTime {
+        self.created_at.value()
+    }
+
+    pub fn updated_at(&self) -> OffsetDateTime {
+        self.updated_at.value()
+    }
+}
+
+pub mod tests {
+    use fake::{faker::time::en::DateTimeAfter, Fake};
+    use shared::domain::{
+        utils::{new_uuid, MINIMUM_DATE_PERMITTED},
+        value_objects::money::tests::CurrencyMother,
+    };
+
+    use super::*;
+
+    pub struct ExchangeRateIdMother;
+
+    impl ExchangeRateIdMother {
+        pub fn random() -> ExchangeRateId {
+            ExchangeRateId::new(new_uuid()).unwrap()
+        }
+
+        pub fn create(value: Option<String>) -> ExchangeRateId {
+            match value {
+                Some(value) => ExchangeRateId::new(value).unwrap(),
+                None => Self::random(),
+            }
+        }
+    }
+
+    pub struct ExchangeRateValueMother;
+
+    impl ExchangeRateValueMother {
+        pub fn random() -> ExchangeRateValue {
+            let rate = (1..10_000_000i64).fake::<i64>();
+            ExchangeRateValue::new(Decimal::new(rate, 4)).unwrap()
+        }
+
+        pub fn create(value: Option<Decimal>) -> ExchangeRateValue {
+            match value {
+                Some(value) => ExchangeRateValue::new(value).unwrap(),
+                None => Self::random(),
+            }
+        }
+    }
+
+    pub struct ExchangeRateDateMother;
+
+    impl ExchangeRateDateMother {
+        pub fn random() -> ExchangeRateDate {
+            let date: OffsetDateTime = DateTimeAfter(*MINIMUM_DATE_PERMITTED).fake();
+            ExchangeRateDate::new(date.date().min(OffsetDateTime::now_utc().date())).unwrap()
+        }
+
+        pub fn create(value: Option<Date>) -> ExchangeRateDate {
+            match value {
+                Some(value) => ExchangeRateDate::new(value).unwrap(),
+                None => Self::random(),
+            }
+        }
+    }
+
+    pub struct ExchangeRateMother;
+
+    impl ExchangeRateMother {
+        pub fn random() -> ExchangeRate {
+            Self::create(None, None, None, None, None)
+        }
+
+        pub fn create(
+            id: Option<String>,
+            base_currency: Option<String>,
+            quote_currency: Option<String>,
+            rate: Option<Decimal>,
+            date: Option<Date>,
+        ) -> ExchangeRate {
+            let base_currency = CurrencyMother::create(base_currency);
+            let quote_currency = match quote_currency {
+                Some(quote_currency) => CurrencyMother::create(Some(quote_currency)),
+                None => loop {
+                    let currency = CurrencyMother::random();
+                    if currency != base_currency {
+                        break currency;
+                    }
+                },
+            };
+            let created_at = OffsetDateTime::now_utc() - time::Duration::minutes(1);
+
+            ExchangeRate {
+                id: ExchangeRateIdMother::create(id),
+                base_currency,
+                quote_currency,
+                rate: ExchangeRateValueMother::create(rate),
+                date: ExchangeRateDateMother::create(date),
+                created_at: ExchangeRateCreatedAt::new(created_at).unwrap(),
+                updated_at: ExchangeRateUpdatedAt::new(created_at).unwrap(),
+
+                events: vec![],
+            }
+        }
+    }
+}
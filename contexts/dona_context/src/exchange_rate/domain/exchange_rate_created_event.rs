@@ -0,0 +1,149 @@
+use shared::domain::bus::event::{BaseEvent, Event, EventDeserializeError, EventSerialized};
+
+pub const EXCHANGE_RATE_CREATED_EVENT_TYPE: &str = "dona.exchange_rate_created";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct ExchangeRateCreatedEvent {
+    id: String,
+    base_currency: String,
+    quote_currency: String,
+    rate: String,
+    date: String,
+    created_at: String,
+    updated_at: String,
+
+    base_event: BaseEvent,
+}
+
+impl ExchangeRateCreatedEvent {
+    pub fn new(
+        id: String,
+        base_currency: String,
+        quote_currency: String,
+        rate: String,
+        date: String,
+        created_at: String,
+        updated_at: String,
+    ) -> Self {
+        Self {
+            id: id.clone(),
+            base_currency,
+            quote_currency,
+            rate,
+            date,
+            created_at,
+            updated_at,
+            base_event: BaseEvent::new(id),
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn base_currency(&self) -> &str {
+        &self.base_currency
+    }
+
+    pub fn quote_currency(&self) -> &str {
+        &self.quote_currency
+    }
+
+    pub fn rate(&self) -> &str {
+        &self.rate
+    }
+
+    pub fn date(&self) -> &str {
+        &self.date
+    }
+
+    pub fn created_at(&self) -> &str {
+        &self.created_at
+    }
+
+    pub fn updated_at(&self) -> &str {
+        &self.updated_at
+    }
+}
+
+impl Event for ExchangeRateCreatedEvent {
+    fn event_type(&self) -> &'static str {
+        EXCHANGE_RATE_CREATED_EVENT_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn from_primitives(
+        &self,
+        primitives: EventSerialized,
+    ) -> Result<Box<dyn Event>, EventDeserializeError> {
+        let data = primitives.data();
+        let base_event = BaseEvent::from_primitives(
+            primitives.event_id().to_string(),
+            primitives.aggregate_id().to_string(),
+            primitives.occurred_at().to_string(),
+        );
+
+        let id = data
+            .get("id")
+            .ok_or(EventDeserializeError::MissingField("id".to_string()))?;
+        let base_currency =
+            data.get("base_currency")
+                .ok_or(EventDeserializeError::MissingField(
+                    "base_currency".to_string(),
+                ))?;
+        let quote_currency =
+            data.get("quote_currency")
+                .ok_or(EventDeserializeError::MissingField(
+                    "quote_currency".to_string(),
+                ))?;
+        let rate = data
+            .get("rate")
+            .ok_or(EventDeserializeError::MissingField("rate".to_string()))?;
+        let date = data
+            .get("date")
+            .ok_or(EventDeserializeError::MissingField("date".to_string()))?;
+        let created_at = data
+            .get("created_at")
+            .ok_or(EventDeserializeError::MissingField(
+                "created_at".to_string(),
+            ))?;
+        let updated_at = data
+            .get("updated_at")
+            .ok_or(EventDeserializeError::MissingField(
+                "updated_at".to_string(),
+            ))?;
+
+        Ok(Box::new(Self {
+            id: id.to_string(),
+            base_currency: base_currency.to_string(),
+            quote_currency: quote_currency.to_string(),
+            rate: rate.to_string(),
+            date: date.to_string(),
+            created_at: created_at.to_string(),
+            updated_at: updated_at.to_string(),
+            base_event,
+        }))
+    }
+
+    fn to_primitives(&self) -> EventSerialized {
+        EventSerialized::new(
+            self.base_event.event_id().to_string(),
+            self.base_event.aggregate_id().to_string(),
+            self.base_event.occurred_at().to_string(),
+            vec![
+                ("id".to_string(), self.id.clone()),
+                ("base_currency".to_string(), self.base_currency.clone()),
+                ("quote_currency".to_string(), self.quote_currency.clone()),
+                ("rate".to_string(), self.rate.clone()),
+                ("date".to_string(), self.date.clone()),
+                ("created_at".to_string(), self.created_at.clone()),
+                ("updated_at".to_string(), self.updated_at.clone()),
+            ]
+            .into_iter()
+            .collect(),
+        )
+    }
+}
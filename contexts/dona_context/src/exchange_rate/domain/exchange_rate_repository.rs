@@ -0,0 +1,53 @@
+use shared::domain::{
+    base_errors::BaseRepositoryError, criteria::Criteria, value_objects::money::Currency,
+};
+use time::Date;
+
+use super::exchange_rate::{ExchangeRate, ExchangeRateId};
+
+#[async_trait::async_trait]
+pub trait ExchangeRateRepository: Send + Sync {
+    async fn find_by_id(&self, id: ExchangeRateId) -> Result<ExchangeRate, BaseRepositoryError>;
+    async fn find_by_criteria(
+        &self,
+        criteria: Criteria,
+    ) -> Result<Vec<ExchangeRate>, BaseRepositoryError>;
+    /// The most recent rate for the pair dated on or before `date`.
+    async fn find_effective(
+        &self,
+        base_currency: Currency,
+        quote_currency: Currency,
+        date: Date,
+    ) -> Result<ExchangeRate, BaseRepositoryError>;
+    /// The rate for the pair dated exactly on `date`.
+    async fn find_by_pair_and_date(
+        &self,
+        base_currency: Currency,
+        quote_currency: Currency,
+        date: Date,
+    ) -> Result<ExchangeRate, BaseRepositoryError>;
+    async fn save(&self, exchange_rate: &ExchangeRate) -> Result<(), BaseRepositoryError>;
+    /// Saves every rate in a single transaction, so either all of them are stored or none.
+    async fn save_all(&self, exchange_rates: &[ExchangeRate]) -> Result<(), BaseRepositoryError>;
+}
+
+#[cfg(test)]
+pub mod tests {
+    use mockall::mock;
+
+    use super::*;
+
+    mock! {
+        pub ExchangeRateRepository {}
+
+        #[async_trait::async_trait]
+        impl ExchangeRateRepository for ExchangeRateRepository {
+            async fn find_by_id(&self, id: ExchangeRateId) -> Result<ExchangeRate, BaseRepositoryError>;
+            async fn find_by_criteria(&self, criteria: Criteria) -> Result<Vec<ExchangeRate>, BaseRepositoryError>;
+            async fn find_effective(&self, base_currency: Currency, quote_currency: Currency, date: Date) -> Result<ExchangeRate, BaseRepositoryError>;
+            async fn find_by_pair_and_date(&self, base_currency: Currency, quote_currency: Currency, date: Date) -> Result<ExchangeRate, BaseRepositoryError>;
+            async fn save(&self, exchange_rate: &ExchangeRate) -> Result<(), BaseRepositoryError>;
+            async fn save_all(&self, exchange_rates: &[ExchangeRate]) -> Result<(), BaseRepositoryError>;
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod exchange_rate;
+pub mod exchange_rate_created_event;
+pub mod exchange_rate_repository;
+pub mod exchange_rate_updated_event;
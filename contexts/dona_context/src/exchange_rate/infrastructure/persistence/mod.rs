@@ -0,0 +1 @@
+pub mod sea_exchange_rate_repo;
@@ -0,0 +1,169 @@
+use shared::domain::bus::query::{Query, QueryError, QueryHandler, Response};
+
+use super::service::DonaExportDownloader;
+
+pub const DOWNLOAD_DONA_EXPORT_QUERY_TYPE: &str = "dona.download_dona_export.query";
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DownloadDonaExportQuery {
+    pub id: String,
+    pub requester_id: String,
+}
+
+impl Query for DownloadDonaExportQuery {
+    fn query_type(&self) -> &'static str {
+        DOWNLOAD_DONA_EXPORT_QUERY_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct DownloadDonaExportQueryHandler {
+    service: DonaExportDownloader,
+}
+
+impl DownloadDonaExportQueryHandler {
+    pub fn new(service: DonaExportDownloader) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler for DownloadDonaExportQueryHandler {
+    async fn handle(&self, query: Box<dyn Query>) -> Result<Box<dyn Response>, QueryError> {
+        let query = query
+            .as_any()
+            .downcast_ref::<DownloadDonaExportQuery>()
+            .ok_or_else(|| QueryError::new("Invalid query".to_string()))?;
+
+        let export = self
+            .service
+            .execute(query.id.to_owned(), query.requester_id.to_owned())
+            .await
+            .map_err(QueryError::new)?;
+
+        Ok(Box::new(export))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Seek, SeekFrom, Write};
+    use std::sync::Arc;
+
+    use shared::domain::{
+        base_errors::BaseRepositoryError, storage::tests::MockFileStorageRepository,
+        utils::new_uuid,
+    };
+    use shared::DONA_EXPORT_STORAGE_MODEL;
+
+    use super::*;
+
+    use crate::export::application::response::DonaExportFileResponse;
+    use crate::export::domain::dona_export::tests::DonaExportMother;
+    use crate::export::domain::dona_export::{
+        DonaExport, ERR_DONA_EXPORT_NOT_FOUND, ERR_DONA_EXPORT_NOT_READY,
+    };
+    use crate::export::domain::dona_export_repository::tests::MockDonaExportRepository;
+
+    fn handler(
+        export: Option<DonaExport>,
+        storage_repository: MockFileStorageRepository,
+    ) -> DownloadDonaExportQueryHandler {
+        let mut export_repository = MockDonaExportRepository::new();
+        export_repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(export.ok_or(BaseRepositoryError::NotFound));
+
+        DownloadDonaExportQueryHandler::new(DonaExportDownloader::new(
+            Arc::new(export_repository),
+            Arc::new(storage_repository),
+        ))
+    }
+
+    fn export_with_status(status: &str) -> DonaExport {
+        DonaExportMother::create(None, None, None, vec![], vec![], Some(status.to_string()))
+    }
+
+    #[tokio::test]
+    async fn it_should_download_a_completed_export() {
+        let export = export_with_status("completed");
+
+        let mut storage_repository = MockFileStorageRepository::new();
+        let expected_id = export.id();
+        storage_repository
+            .expect_get()
+            .withf(move |model, id, filename| {
+                model == DONA_EXPORT_STORAGE_MODEL
+                    && *id == expected_id
+                    && filename.ends_with(".csv")
+            })
+            .times(1)
+            .returning(|_, _, _| {
+                let mut file = tempfile::tempfile().unwrap();
+                file.write_all(b"Id\r\n").unwrap();
+                file.seek(SeekFrom::Start(0)).unwrap();
+                Ok(file)
+            });
+
+        let response = handler(Some(export.clone()), storage_repository)
+            .handle(Box::new(DownloadDonaExportQuery {
+                id: export.id(),
+                requester_id: export.user_id(),
+            }))
+            .await
+            .unwrap();
+        let response = response
+            .as_any()
+            .downcast_ref::<DonaExportFileResponse>()
+            .unwrap()
+            .to_owned();
+
+        assert_eq!(
+            response,
+            DonaExportFileResponse {
+                filename: export.filename(),
+                content_type: "text/csv; charset=utf-8".to_string(),
+                content: b"Id\r\n".to_vec(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_hide_exports_of_other_users_and_unfinished_ones() {
+        for (export, requester_id, error) in [
+            (
+                Some(export_with_status("completed")),
+                Some(new_uuid()),
+                ERR_DONA_EXPORT_NOT_FOUND,
+            ),
+            (None, None, ERR_DONA_EXPORT_NOT_FOUND),
+            (
+                Some(export_with_status("pending")),
+                None,
+                ERR_DONA_EXPORT_NOT_READY,
+            ),
+        ] {
+            let (id, owner_id) = export
+                .as_ref()
+                .map(|export| (export.id(), export.user_id()))
+                .unwrap_or((new_uuid(), new_uuid()));
+
+            let mut storage_repository = MockFileStorageRepository::new();
+            storage_repository.expect_get().times(0);
+
+            let result = handler(export, storage_repository)
+                .handle(Box::new(DownloadDonaExportQuery {
+                    id,
+                    requester_id: requester_id.unwrap_or(owner_id),
+                }))
+                .await;
+
+            assert_eq!(result.err(), Some(QueryError::new(error.to_string())));
+        }
+    }
+}
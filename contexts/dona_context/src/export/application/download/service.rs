@@ -0,0 +1,67 @@
+use std::{io::Read, sync::Arc};
+
+use shared::{domain::storage::FileStorageRepository, DONA_EXPORT_STORAGE_MODEL};
+
+use crate::export::{
+    application::response::DonaExportFileResponse,
+    domain::{
+        dona_export::{DonaExportId, ERR_DONA_EXPORT_NOT_FOUND, ERR_DONA_EXPORT_NOT_READY},
+        dona_export_repository::DonaExportRepository,
+    },
+};
+
+#[derive(Clone)]
+pub struct DonaExportDownloader {
+    export_repository: Arc<dyn DonaExportRepository>,
+    storage_repository: Arc<dyn FileStorageRepository>,
+}
+
+impl DonaExportDownloader {
+    pub fn new(
+        export_repository: Arc<dyn DonaExportRepository>,
+        storage_repository: Arc<dyn FileStorageRepository>,
+    ) -> Self {
+        Self {
+            export_repository,
+            storage_repository,
+        }
+    }
+
+    /// Exports of other users are reported as not found, so their ids cannot be probed.
+    pub async fn execute(
+        &self,
+        id: String,
+        requester_id: String,
+    ) -> Result<DonaExportFileResponse, String> {
+        let export = self
+            .export_repository
+            .find_by_id(DonaExportId::new(id)?)
+            .await
+            .map_err(|_| ERR_DONA_EXPORT_NOT_FOUND.to_string())?;
+
+        if !export.can_be_downloaded_by(&requester_id) {
+            return Err(ERR_DONA_EXPORT_NOT_FOUND.to_string());
+        }
+        if !export.is_completed() {
+            return Err(ERR_DONA_EXPORT_NOT_READY.to_string());
+        }
+
+        let filename = export.filename();
+        let mut file = self
+            .storage_repository
+            .get(
+                DONA_EXPORT_STORAGE_MODEL.to_string(),
+                export.id(),
+                filename.clone(),
+            )
+            .await?;
+        let mut content = Vec::new();
+        file.read_to_end(&mut content).map_err(|e| e.to_string())?;
+
+        Ok(DonaExportFileResponse {
+            filename,
+            content_type: export.format().content_type().to_string(),
+            content,
+        })
+    }
+}
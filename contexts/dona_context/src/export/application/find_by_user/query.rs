@@ -0,0 +1,109 @@
+use shared::domain::bus::query::{Query, QueryError, QueryHandler, Response};
+
+use super::service::DonaExportsFinder;
+
+pub const FIND_DONA_EXPORTS_QUERY_TYPE: &str = "dona.find_dona_exports.query";
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FindDonaExportsQuery {
+    pub user_id: String,
+}
+
+impl Query for FindDonaExportsQuery {
+    fn query_type(&self) -> &'static str {
+        FIND_DONA_EXPORTS_QUERY_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct FindDonaExportsQueryHandler {
+    service: DonaExportsFinder,
+}
+
+impl FindDonaExportsQueryHandler {
+    pub fn new(service: DonaExportsFinder) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler for FindDonaExportsQueryHandler {
+    async fn handle(&self, query: Box<dyn Query>) -> Result<Box<dyn Response>, QueryError> {
+        let query = query
+            .as_any()
+            .downcast_ref::<FindDonaExportsQuery>()
+            .ok_or_else(|| QueryError::new("Invalid query".to_string()))?;
+
+        let exports = self
+            .service
+            .execute(query.user_id.to_owned())
+            .await
+            .map_err(QueryError::new)?;
+
+        Ok(Box::new(exports))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockall::predicate;
+    use shared::domain::{utils::new_uuid, value_objects::user_id::UserId};
+
+    use super::*;
+
+    use crate::export::application::find_by_user::service::DONA_EXPORTS_HISTORY_LIMIT;
+    use crate::export::application::response::DonaExportsResponse;
+    use crate::export::domain::dona_export::tests::DonaExportMother;
+    use crate::export::domain::dona_export_repository::tests::MockDonaExportRepository;
+
+    #[tokio::test]
+    async fn it_should_list_the_exports_with_their_download_url() {
+        let user_id = new_uuid();
+        let completed = DonaExportMother::create(
+            None,
+            Some(user_id.clone()),
+            Some("xlsx".to_string()),
+            vec![],
+            vec![],
+            Some("completed".to_string()),
+        );
+        let pending =
+            DonaExportMother::create(None, Some(user_id.clone()), None, vec![], vec![], None);
+
+        let mut repository = MockDonaExportRepository::new();
+        repository
+            .expect_find_by_user_id()
+            .with(
+                predicate::eq(UserId::new(user_id.clone()).unwrap()),
+                predicate::eq(DONA_EXPORTS_HISTORY_LIMIT),
+            )
+            .times(1)
+            .return_const(Ok(vec![completed.clone(), pending.clone()]));
+
+        let response =
+            FindDonaExportsQueryHandler::new(DonaExportsFinder::new(Arc::new(repository)))
+                .handle(Box::new(FindDonaExportsQuery { user_id }))
+                .await
+                .unwrap();
+        let response = response
+            .as_any()
+            .downcast_ref::<DonaExportsResponse>()
+            .unwrap()
+            .to_owned();
+
+        assert_eq!(response.exports.len(), 2);
+        assert_eq!(
+            response.exports[0].download_url,
+            Some(completed.download_url())
+        );
+        assert_eq!(response.exports[0].format, "xlsx");
+        assert_eq!(response.exports[1].id, pending.id());
+        assert_eq!(response.exports[1].download_url, None);
+    }
+}
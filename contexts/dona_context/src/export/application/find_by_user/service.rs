@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use shared::domain::value_objects::user_id::UserId;
+
+use crate::export::{
+    application::response::{DonaExportResponse, DonaExportsResponse},
+    domain::dona_export_repository::DonaExportRepository,
+};
+
+/// Exports older than the latest ones are kept in storage but no longer listed.
+pub const DONA_EXPORTS_HISTORY_LIMIT: u64 = 20;
+
+#[derive(Clone)]
+pub struct DonaExportsFinder {
+    repository: Arc<dyn DonaExportRepository>,
+}
+
+impl DonaExportsFinder {
+    pub fn new(repository: Arc<dyn DonaExportRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, user_id: String) -> Result<DonaExportsResponse, String> {
+        let exports = self
+            .repository
+            .find_by_user_id(UserId::new(user_id)?, DONA_EXPORTS_HISTORY_LIMIT)
+            .await?;
+
+        Ok(DonaExportsResponse {
+            exports: exports.into_iter().map(DonaExportResponse::from).collect(),
+        })
+    }
+}
@@ -0,0 +1,5 @@
+pub mod download;
+pub mod find_by_user;
+pub mod process_pending;
+pub mod request;
+pub mod response;
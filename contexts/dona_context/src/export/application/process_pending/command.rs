@@ -0,0 +1,366 @@
+use shared::domain::bus::command::{Command, CommandError, CommandHandler};
+
+use super::service::DonaExportsProcessor;
+
+pub const PROCESS_PENDING_DONA_EXPORTS_COMMAND_TYPE: &str =
+    "dona.process_pending_dona_exports.command";
+
+#[derive(Debug)]
+pub struct ProcessPendingDonaExportsCommand {
+    pub limit: u64,
+}
+
+impl Command for ProcessPendingDonaExportsCommand {
+    fn command_type(&self) -> &'static str {
+        PROCESS_PENDING_DONA_EXPORTS_COMMAND_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct ProcessPendingDonaExportsCommandHandler {
+    service: DonaExportsProcessor,
+}
+
+impl ProcessPendingDonaExportsCommandHandler {
+    pub fn new(service: DonaExportsProcessor) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler for ProcessPendingDonaExportsCommandHandler {
+    async fn handle(&self, command: Box<dyn Command>) -> Result<(), CommandError> {
+        let command = command
+            .as_any()
+            .downcast_ref::<ProcessPendingDonaExportsCommand>()
+            .ok_or_else(|| CommandError::new("Invalid command".to_string()))?;
+
+        self.service
+            .execute(command.limit)
+            .await
+            .map_err(CommandError::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+    use std::sync::Arc;
+
+    use rust_decimal_macros::dec;
+    use shared::domain::{
+        base_errors::BaseRepositoryError, bus::event::tests::MockEventBus,
+        criteria::page::CursorPage, storage::tests::MockFileStorageRepository, utils::new_uuid,
+        value_objects::user_id::UserId,
+    };
+    use shared::DONA_EXPORT_STORAGE_MODEL;
+    use time::{format_description::well_known::Rfc3339, macros::datetime, Duration};
+
+    use super::*;
+
+    use crate::dona::domain::dona::tests::DonaMother;
+    use crate::dona::domain::dona::Dona;
+    use crate::dona::domain::dona_repository::tests::MockDonaRepository;
+    use crate::export::domain::dona_export::tests::DonaExportMother;
+    use crate::export::domain::dona_export::{DonaExport, ERR_INVALID_DONA_EXPORT_FORMAT};
+    use crate::export::domain::dona_export_completed_event::{
+        DonaExportCompletedEvent, DONA_EXPORT_COMPLETED_EVENT_TYPE,
+    };
+    use crate::export::domain::dona_export_failed_event::DONA_EXPORT_FAILED_EVENT_TYPE;
+    use crate::export::domain::dona_export_repository::tests::MockDonaExportRepository;
+    use crate::export::infrastructure::writing::csv_dona_export_writer::CsvDonaExportWriter;
+    use crate::supporter::domain::supporter_profile::tests::MockSupporterProfileRepository;
+    use crate::supporter::domain::supporter_profile::SupporterProfile;
+
+    fn pending_repository(
+        export: &DonaExport,
+        expected_status: &'static str,
+    ) -> MockDonaExportRepository {
+        let mut repository = MockDonaExportRepository::new();
+        repository
+            .expect_find_pending()
+            .times(1)
+            .return_const(Ok(vec![export.clone()]));
+        repository
+            .expect_save()
+            .withf(move |export| export.status() == expected_status)
+            .times(1)
+            .return_const(Ok(()));
+        repository
+    }
+
+    fn handler(
+        export_repository: MockDonaExportRepository,
+        dona_repository: MockDonaRepository,
+        profile_repository: MockSupporterProfileRepository,
+        storage_repository: MockFileStorageRepository,
+        event_bus: MockEventBus,
+    ) -> ProcessPendingDonaExportsCommandHandler {
+        ProcessPendingDonaExportsCommandHandler::new(DonaExportsProcessor::new(
+            Arc::new(export_repository),
+            Arc::new(dona_repository),
+            Arc::new(profile_repository),
+            Arc::new(storage_repository),
+            vec![Arc::new(CsvDonaExportWriter::new())],
+            Arc::new(event_bus),
+            2,
+        ))
+    }
+
+    fn dona(user_id: &str, sender_id: &str, amount: rust_decimal::Decimal, day: i64) -> Dona {
+        let created_at = datetime!(2024-03-01 10:00 UTC) + Duration::days(day);
+
+        DonaMother::create(
+            None,
+            Some(format!("Dona number {}", day)),
+            Some(amount),
+            Some("USD".to_string()),
+            Some("confirmed".to_string()),
+            Some("PAYPAL".to_string()),
+            Some(user_id.to_string()),
+            Some(sender_id.to_string()),
+            None,
+            None,
+            Some(created_at),
+            Some(created_at),
+        )
+    }
+
+    /// Expects the second page to be asked for right after the last dona of the first one.
+    fn paged_dona_repository(first_page: &[Dona], second_page: &[Dona]) -> MockDonaRepository {
+        let mut dona_repository = MockDonaRepository::new();
+        let last = first_page.last().unwrap().clone();
+        dona_repository
+            .expect_find_page_by_criteria()
+            .withf(|criteria| criteria.cursor().is_some_and(|c| c.after().is_none()))
+            .times(1)
+            .return_const(Ok(CursorPage::new(first_page.to_vec(), false, true)));
+        dona_repository
+            .expect_find_page_by_criteria()
+            .withf(move |criteria| {
+                criteria
+                    .cursor()
+                    .and_then(|c| c.after())
+                    .is_some_and(|a| *a.value() == last.created_at() && a.id() == Some(&last.id()))
+            })
+            .times(1)
+            .return_const(Ok(CursorPage::new(second_page.to_vec(), true, false)));
+        dona_repository
+    }
+
+    #[tokio::test]
+    async fn it_should_write_every_page_and_publish_the_completion() {
+        let export = DonaExportMother::create(
+            None,
+            None,
+            None,
+            vec![
+                "date".to_string(),
+                "amount".to_string(),
+                "sender".to_string(),
+            ],
+            vec![],
+            None,
+        );
+        let (known, gone) = (new_uuid(), new_uuid());
+        let first_page = vec![
+            dona(&export.user_id(), &known, dec!(10.00), 1),
+            dona(&export.user_id(), &gone, dec!(5.50), 2),
+        ];
+        let second_page = vec![dona(&export.user_id(), &known, dec!(1.00), 3)];
+
+        let dona_repository = paged_dona_repository(&first_page, &second_page);
+
+        let mut profile_repository = MockSupporterProfileRepository::new();
+        let profile = SupporterProfile {
+            user_id: known.clone(),
+            username: "known".to_string(),
+            full_name: "Known Supporter".to_string(),
+            profile_picture: None,
+        };
+        let expected_ids = vec![
+            UserId::new(known.clone()).unwrap(),
+            UserId::new(gone.clone()).unwrap(),
+        ];
+        profile_repository
+            .expect_find_by_ids()
+            .withf(move |ids| *ids == expected_ids)
+            .times(1)
+            .return_const(Ok(vec![profile.clone()]));
+        profile_repository
+            .expect_find_by_ids()
+            .withf(|ids| ids.len() == 1)
+            .times(1)
+            .return_const(Ok(vec![profile]));
+
+        let date = |dona: &Dona| dona.created_at().format(&Rfc3339).unwrap();
+        let expected_content = format!(
+            "Date,Amount,Sender\r\n{},10.00,Known Supporter\r\n{},5.50,{}\r\n{},1.00,Known Supporter\r\n",
+            date(&first_page[0]),
+            date(&first_page[1]),
+            gone,
+            date(&second_page[0]),
+        );
+        let expected_filename = export.filename();
+        let mut storage_repository = MockFileStorageRepository::new();
+        storage_repository
+            .expect_save()
+            .withf(move |model, _, filename, _| {
+                model == DONA_EXPORT_STORAGE_MODEL && *filename == expected_filename
+            })
+            .times(1)
+            .returning(move |_, _, filename, mut file| {
+                let mut content = String::new();
+                file.read_to_string(&mut content).unwrap();
+                assert_eq!(content, expected_content);
+                Ok(filename)
+            });
+
+        let download_url = export.download_url();
+        let mut event_bus = MockEventBus::new();
+        event_bus
+            .expect_publish()
+            .withf(move |events| {
+                events.len() == 1
+                    && events[0].event_type() == DONA_EXPORT_COMPLETED_EVENT_TYPE
+                    && events[0]
+                        .as_any()
+                        .downcast_ref::<DonaExportCompletedEvent>()
+                        .is_some_and(|event| {
+                            event.download_url() == download_url && event.row_count() == "3"
+                        })
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let result = handler(
+            pending_repository(&export, "completed"),
+            dona_repository,
+            profile_repository,
+            storage_repository,
+            event_bus,
+        )
+        .handle(Box::new(ProcessPendingDonaExportsCommand { limit: 5 }))
+        .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_write_donas_created_at_the_same_instant_once() {
+        let export =
+            DonaExportMother::create(None, None, None, vec!["id".to_string()], vec![], None);
+        let first_page = vec![
+            dona(&export.user_id(), &new_uuid(), dec!(1.00), 1),
+            dona(&export.user_id(), &new_uuid(), dec!(2.00), 1),
+        ];
+        let second_page = vec![dona(&export.user_id(), &new_uuid(), dec!(3.00), 1)];
+
+        let dona_repository = paged_dona_repository(&first_page, &second_page);
+
+        let mut profile_repository = MockSupporterProfileRepository::new();
+        profile_repository
+            .expect_find_by_ids()
+            .times(2)
+            .return_const(Ok(vec![]));
+
+        let expected_content = format!(
+            "ID\r\n{}\r\n{}\r\n{}\r\n",
+            first_page[0].id(),
+            first_page[1].id(),
+            second_page[0].id(),
+        );
+        let mut storage_repository = MockFileStorageRepository::new();
+        storage_repository
+            .expect_save()
+            .times(1)
+            .returning(move |_, _, filename, mut file| {
+                let mut content = String::new();
+                file.read_to_string(&mut content).unwrap();
+                assert_eq!(content, expected_content);
+                Ok(filename)
+            });
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(1).return_const(Ok(()));
+
+        let result = handler(
+            pending_repository(&export, "completed"),
+            dona_repository,
+            profile_repository,
+            storage_repository,
+            event_bus,
+        )
+        .handle(Box::new(ProcessPendingDonaExportsCommand { limit: 5 }))
+        .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_mark_the_export_as_failed() {
+        for (format, dona_result) in [
+            ("xlsx", Ok(vec![])),
+            ("csv", Err(BaseRepositoryError::ConnectionError)),
+        ] {
+            let export = DonaExportMother::create(
+                None,
+                None,
+                Some(format.to_string()),
+                vec![],
+                vec![],
+                None,
+            );
+
+            let mut export_repository = MockDonaExportRepository::new();
+            export_repository
+                .expect_find_pending()
+                .times(1)
+                .return_const(Ok(vec![export.clone()]));
+            let expected_error = match format {
+                "xlsx" => ERR_INVALID_DONA_EXPORT_FORMAT.to_string(),
+                _ => BaseRepositoryError::ConnectionError.to_string(),
+            };
+            export_repository
+                .expect_save()
+                .withf(move |export| {
+                    export.status() == "failed" && export.error() == Some(expected_error.clone())
+                })
+                .times(1)
+                .return_const(Ok(()));
+
+            let mut dona_repository = MockDonaRepository::new();
+            dona_repository
+                .expect_find_page_by_criteria()
+                .return_const(dona_result.map(|donas| CursorPage::new(donas, false, false)));
+
+            let mut storage_repository = MockFileStorageRepository::new();
+            storage_repository.expect_save().times(0);
+
+            let mut event_bus = MockEventBus::new();
+            event_bus
+                .expect_publish()
+                .withf(|events| {
+                    events.len() == 1 && events[0].event_type() == DONA_EXPORT_FAILED_EVENT_TYPE
+                })
+                .times(1)
+                .return_const(Ok(()));
+
+            let result = handler(
+                export_repository,
+                dona_repository,
+                MockSupporterProfileRepository::new(),
+                storage_repository,
+                event_bus,
+            )
+            .handle(Box::new(ProcessPendingDonaExportsCommand { limit: 5 }))
+            .await;
+
+            assert!(result.is_ok(), "Result should be Ok");
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use std::{collections::HashMap, sync::Arc};
+
+use shared::{
+    domain::{
+        bus::event::EventBus, criteria::cursor::AfterCursor, storage::FileStorageRepository,
+        value_objects::user_id::UserId,
+    },
+    DONA_EXPORT_STORAGE_MODEL,
+};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+use crate::{
+    dona::domain::{dona::Dona, dona_repository::DonaRepository},
+    export::domain::{
+        dona_export::{DonaExport, DonaExportColumn, ERR_INVALID_DONA_EXPORT_FORMAT},
+        dona_export_repository::DonaExportRepository,
+        dona_export_writer::{DonaExportCell, DonaExportWriter},
+    },
+    supporter::domain::supporter_profile::SupporterProfileRepository,
+};
+
+pub const DONA_EXPORT_PAGE_SIZE: usize = 500;
+
+/// Name shown for a dona in the sender column. Registered senders are shown by their full
+/// name, or by their id when their user is gone.
+fn sender_name(dona: &Dona, names: &HashMap<String, String>) -> String {
+    if dona.is_anonymous() {
+        return "Anonymous".to_string();
+    }
+
+    match dona.sender_id() {
+        Some(sender_id) => names.get(&sender_id).cloned().unwrap_or(sender_id),
+        None => dona.guest_name().unwrap_or_default(),
+    }
+}
+
+fn cell(column: DonaExportColumn, dona: &Dona, names: &HashMap<String, String>) -> DonaExportCell {
+    match column {
+        DonaExportColumn::Id => DonaExportCell::Text(dona.id()),
+        DonaExportColumn::Date => {
+            DonaExportCell::Text(dona.created_at().format(&Rfc3339).unwrap_or_default())
+        }
+        DonaExportColumn::Amount => DonaExportCell::Number(dona.amount()),
+        DonaExportColumn::Fees => dona
+            .fee_amount()
+            .map_or(DonaExportCell::Text(String::new()), DonaExportCell::Number),
+        DonaExportColumn::NetAmount => dona
+            .net_amount()
+            .map_or(DonaExportCell::Text(String::new()), DonaExportCell::Number),
+        DonaExportColumn::Currency => DonaExportCell::Text(dona.currency()),
+        DonaExportColumn::Method => DonaExportCell::Text(dona.method()),
+        DonaExportColumn::Status => DonaExportCell::Text(dona.status()),
+        DonaExportColumn::Sender => DonaExportCell::Text(sender_name(dona, names)),
+        DonaExportColumn::Message => DonaExportCell::Text(dona.msg()),
+    }
+}
+
+#[derive(Clone)]
+pub struct DonaExportsProcessor {
+    export_repository: Arc<dyn DonaExportRepository>,
+    dona_repository: Arc<dyn DonaRepository>,
+    profile_repository: Arc<dyn SupporterProfileRepository>,
+    storage_repository: Arc<dyn FileStorageRepository>,
+    writers: Vec<Arc<dyn DonaExportWriter>>,
+    event_bus: Arc<dyn EventBus>,
+    page_size: usize,
+}
+
+impl DonaExportsProcessor {
+    pub fn new(
+        export_repository: Arc<dyn DonaExportRepository>,
+        dona_repository: Arc<dyn DonaRepository>,
+        profile_repository: Arc<dyn SupporterProfileRepository>,
+        storage_repository: Arc<dyn FileStorageRepository>,
+        writers: Vec<Arc<dyn DonaExportWriter>>,
+        event_bus: Arc<dyn EventBus>,
+        page_size: usize,
+    ) -> Self {
+        Self {
+            export_repository,
+            dona_repository,
+            profile_repository,
+            storage_repository,
+            writers,
+            event_bus,
+            page_size,
+        }
+    }
+
+    /// Full names of the registered senders of a page, fetched with a single lookup.
+    async fn sender_names(&self, donas: &[Dona]) -> Result<HashMap<String, String>, String> {
+        let mut ids: Vec<UserId> = vec![];
+        for dona in donas.iter().filter(|dona| !dona.is_anonymous()) {
+            if let Some(sender_id) = dona.sender_id() {
+                let sender_id = UserId::new(sender_id)?;
+                if !ids.contains(&sender_id) {
+                    ids.push(sender_id);
+                }
+            }
+        }
+        if ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        Ok(self
+            .profile_repository
+            .find_by_ids(ids)
+            .await?
+            .into_iter()
+            .map(|profile| (profile.user_id, profile.full_name))
+            .collect())
+    }
+
+    /// Writes every matching dona, one page at a time, and stores the document. Returns the
+    /// number of donas written.
+    async fn write(&self, export: &DonaExport) -> Result<u64, String> {
+        let writer = self
+            .writers
+            .iter()
+            .find(|writer| writer.format() == export.format())
+            .ok_or(ERR_INVALID_DONA_EXPORT_FORMAT.to_string())?;
+
+        let mut sheet = writer.start()?;
+        sheet.write_row(
+            &export
+                .columns()
+                .iter()
+                .map(|column| DonaExportCell::Text(column.header().to_string()))
+                .collect::<Vec<DonaExportCell>>(),
+        )?;
+
+        let mut row_count = 0;
+        let mut after = None;
+        loop {
+            let page = self
+                .dona_repository
+                .find_page_by_criteria(export.page_criteria(after, self.page_size)?)
+                .await?;
+            let donas = page.items();
+            let names = self.sender_names(donas).await?;
+
+            for dona in donas {
+                sheet.write_row(
+                    &export
+                        .columns()
+                        .iter()
+                        .map(|column| cell(*column, dona, &names))
+                        .collect::<Vec<DonaExportCell>>(),
+                )?;
+            }
+            row_count += donas.len() as u64;
+
+            if !page.has_next_page() {
+                break;
+            }
+            after = donas
+                .last()
+                .map(|dona| AfterCursor::with_id(dona.created_at(), dona.id()))
+                .transpose()?;
+        }
+
+        self.storage_repository
+            .save(
+                DONA_EXPORT_STORAGE_MODEL.to_string(),
+                export.id(),
+                export.filename(),
+                sheet.finish()?,
+            )
+            .await?;
+
+        Ok(row_count)
+    }
+
+    /// Builds up to `limit` pending exports, oldest first. An export that cannot be built is
+    /// marked as failed with the reason, so it is not retried on every run.
+    pub async fn execute(&self, limit: u64) -> Result<(), String> {
+        let exports = self.export_repository.find_pending(limit).await?;
+
+        for mut export in exports {
+            match self.write(&export).await {
+                Ok(row_count) => export.complete(row_count, OffsetDateTime::now_utc())?,
+                Err(error) => export.fail(error, OffsetDateTime::now_utc())?,
+            }
+
+            self.export_repository.save(&export).await?;
+
+            self.event_bus.publish(export.pull_events()).await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+use shared::domain::{
+    bus::command::{Command, CommandError, CommandHandler},
+    criteria::Criteria,
+};
+use time::OffsetDateTime;
+
+use super::service::DonaExportRequester;
+
+pub const REQUEST_DONA_EXPORT_COMMAND_TYPE: &str = "dona.request_dona_export.command";
+
+/// `columns` are written in the given order, all of them when it is empty.
+#[derive(Debug)]
+pub struct RequestDonaExportCommand {
+    pub id: String,
+    pub user_id: String,
+    pub format: String,
+    pub columns: Vec<String>,
+    pub criteria: Criteria,
+    pub created_at: OffsetDateTime,
+}
+
+impl Command for RequestDonaExportCommand {
+    fn command_type(&self) -> &'static str {
+        REQUEST_DONA_EXPORT_COMMAND_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct RequestDonaExportCommandHandler {
+    service: DonaExportRequester,
+}
+
+impl RequestDonaExportCommandHandler {
+    pub fn new(service: DonaExportRequester) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler for RequestDonaExportCommandHandler {
+    async fn handle(&self, command: Box<dyn Command>) -> Result<(), CommandError> {
+        let command = command
+            .as_any()
+            .downcast_ref::<RequestDonaExportCommand>()
+            .ok_or_else(|| CommandError::new("Invalid command".to_string()))?;
+
+        self.service
+            .execute(
+                command.id.to_owned(),
+                command.user_id.to_owned(),
+                command.format.to_owned(),
+                command.columns.to_owned(),
+                command.criteria.to_owned(),
+                command.created_at,
+            )
+            .await
+            .map_err(CommandError::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use shared::domain::{
+        bus::event::tests::MockEventBus,
+        criteria::filter::{Filter, FilterField, FilterOperator, FilterValue},
+        utils::new_uuid,
+    };
+
+    use super::*;
+
+    use crate::export::domain::dona_export::{
+        DonaExportColumn, ERR_INVALID_DONA_EXPORT_COLUMNS, ERR_INVALID_DONA_EXPORT_FORMAT,
+    };
+    use crate::export::domain::dona_export_repository::tests::MockDonaExportRepository;
+    use crate::export::domain::dona_export_requested_event::DONA_EXPORT_REQUESTED_EVENT_TYPE;
+
+    fn handler(
+        repository: MockDonaExportRepository,
+        event_bus: MockEventBus,
+    ) -> RequestDonaExportCommandHandler {
+        RequestDonaExportCommandHandler::new(DonaExportRequester::new(
+            Arc::new(repository),
+            Arc::new(event_bus),
+        ))
+    }
+
+    fn command(format: &str, columns: Vec<&str>) -> RequestDonaExportCommand {
+        RequestDonaExportCommand {
+            id: new_uuid(),
+            user_id: new_uuid(),
+            format: format.to_string(),
+            columns: columns.into_iter().map(|c| c.to_string()).collect(),
+            criteria: Criteria::new(
+                vec![Filter::new(
+                    FilterField::try_from("status".to_string()).unwrap(),
+                    FilterOperator::Equal,
+                    FilterValue::try_from("confirmed".to_string()).unwrap(),
+                )],
+                None,
+                None,
+            ),
+            created_at: OffsetDateTime::now_utc(),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_queue_a_pending_export() {
+        let command = command("xlsx", vec!["date", "amount", "sender"]);
+        let expected_id = command.id.clone();
+        let expected_filters = command.criteria.filters().to_vec();
+
+        let mut repository = MockDonaExportRepository::new();
+        repository
+            .expect_save()
+            .withf(move |export| {
+                export.id() == expected_id
+                    && export.status() == "pending"
+                    && export.filters() == expected_filters
+                    && export.columns()
+                        == [
+                            DonaExportColumn::Date,
+                            DonaExportColumn::Amount,
+                            DonaExportColumn::Sender,
+                        ]
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus
+            .expect_publish()
+            .withf(|events| {
+                events.len() == 1 && events[0].event_type() == DONA_EXPORT_REQUESTED_EVENT_TYPE
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let result = handler(repository, event_bus)
+            .handle(Box::new(command))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_with_an_invalid_format_or_columns() {
+        for (format, columns, error) in [
+            ("pdf", vec![], ERR_INVALID_DONA_EXPORT_FORMAT),
+            ("csv", vec!["id", "id"], ERR_INVALID_DONA_EXPORT_COLUMNS),
+        ] {
+            let mut repository = MockDonaExportRepository::new();
+            repository.expect_save().times(0);
+
+            let mut event_bus = MockEventBus::new();
+            event_bus.expect_publish().times(0);
+
+            let result = handler(repository, event_bus)
+                .handle(Box::new(command(format, columns)))
+                .await;
+
+            assert_eq!(result, Err(CommandError::new(error.to_string())));
+        }
+    }
+}
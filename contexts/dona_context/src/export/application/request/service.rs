@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use shared::domain::{bus::event::EventBus, criteria::Criteria};
+use time::OffsetDateTime;
+
+use crate::export::domain::{
+    dona_export::DonaExport, dona_export_repository::DonaExportRepository,
+};
+
+#[derive(Clone)]
+pub struct DonaExportRequester {
+    repository: Arc<dyn DonaExportRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl DonaExportRequester {
+    pub fn new(repository: Arc<dyn DonaExportRepository>, event_bus: Arc<dyn EventBus>) -> Self {
+        Self {
+            repository,
+            event_bus,
+        }
+    }
+
+    /// Queues the export for the background worker. Only the filters of the criteria are kept,
+    /// the export always walks the donas from the oldest.
+    pub async fn execute(
+        &self,
+        id: String,
+        user_id: String,
+        format: String,
+        columns: Vec<String>,
+        criteria: Criteria,
+        created_at: OffsetDateTime,
+    ) -> Result<(), String> {
+        let mut export = DonaExport::create(
+            id,
+            user_id,
+            format,
+            columns,
+            criteria.filters().to_vec(),
+            created_at,
+        )?;
+
+        self.repository.save(&export).await?;
+
+        self.event_bus.publish(export.pull_events()).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+use shared::domain::bus::query::Response;
+use time::OffsetDateTime;
+
+use crate::export::domain::dona_export::DonaExport;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DonaExportResponse {
+    pub id: String,
+    pub format: String,
+    pub columns: Vec<String>,
+    pub status: String,
+    pub row_count: u64,
+    pub error: Option<String>,
+    /// Only set once the export is completed.
+    pub download_url: Option<String>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl From<DonaExport> for DonaExportResponse {
+    fn from(export: DonaExport) -> Self {
+        Self {
+            id: export.id(),
+            format: export.format().to_string(),
+            columns: export
+                .columns()
+                .iter()
+                .map(|column| column.to_string())
+                .collect(),
+            status: export.status(),
+            row_count: export.row_count(),
+            error: export.error(),
+            download_url: export.is_completed().then(|| export.download_url()),
+            created_at: export.created_at(),
+            updated_at: export.updated_at(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DonaExportsResponse {
+    pub exports: Vec<DonaExportResponse>,
+}
+
+impl Response for DonaExportsResponse {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DonaExportFileResponse {
+    pub filename: String,
+    pub content_type: String,
+    pub content: Vec<u8>,
+}
+
+impl Response for DonaExportFileResponse {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
@@ -0,0 +1,576 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::Arc;
+
+use shared::domain::{
+    bus::event::Event,
+    criteria::{
+        cursor::{AfterCursor, Cursor, FirstField},
+        filter::{Filter, FilterField, FilterOperator, FilterValue},
+        Criteria,
+    },
+    utils::is_uuid,
+    value_objects::user_id::UserId,
+};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+use super::{
+    dona_export_completed_event::DonaExportCompletedEvent,
+    dona_export_failed_event::DonaExportFailedEvent,
+    dona_export_requested_event::DonaExportRequestedEvent,
+};
+
+/// Route the finished exports are served from, followed by the export id.
+pub const DONA_EXPORT_DOWNLOAD_PATH: &str = "/exports";
+
+pub const ERR_DONA_EXPORT_NOT_FOUND: &str = "Dona Export not found";
+pub const ERR_DONA_EXPORT_NOT_PENDING: &str = "Dona Export is not pending";
+pub const ERR_DONA_EXPORT_NOT_READY: &str = "Dona Export is not ready to be downloaded";
+
+pub const ERR_INVALID_DONA_EXPORT_ID: &str = "Invalid Dona Export ID";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct DonaExportId(String);
+
+impl DonaExportId {
+    pub fn new(value: String) -> Result<Self, String> {
+        if is_uuid(&value) {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_DONA_EXPORT_ID.to_string())
+        }
+    }
+}
+
+impl Display for DonaExportId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub const ERR_INVALID_DONA_EXPORT_FORMAT: &str = "Invalid Dona Export Format";
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum DonaExportFormat {
+    Csv,
+    Xlsx,
+}
+
+impl DonaExportFormat {
+    pub fn new(value: String) -> Result<Self, String> {
+        match value.as_str() {
+            "csv" => Ok(Self::Csv),
+            "xlsx" => Ok(Self::Xlsx),
+            _ => Err(ERR_INVALID_DONA_EXPORT_FORMAT.to_string()),
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::Xlsx => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        }
+    }
+}
+
+impl Display for DonaExportFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Csv => write!(f, "csv"),
+            Self::Xlsx => write!(f, "xlsx"),
+        }
+    }
+}
+
+pub const ERR_INVALID_DONA_EXPORT_COLUMN: &str = "Invalid Dona Export Column";
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum DonaExportColumn {
+    Id,
+    Date,
+    Amount,
+    Fees,
+    NetAmount,
+    Currency,
+    Method,
+    Status,
+    Sender,
+    Message,
+}
+
+impl DonaExportColumn {
+    pub fn new(value: String) -> Result<Self, String> {
+        match value.as_str() {
+            "id" => Ok(Self::Id),
+            "date" => Ok(Self::Date),
+            "amount" => Ok(Self::Amount),
+            "fees" => Ok(Self::Fees),
+            "net_amount" => Ok(Self::NetAmount),
+            "currency" => Ok(Self::Currency),
+            "method" => Ok(Self::Method),
+            "status" => Ok(Self::Status),
+            "sender" => Ok(Self::Sender),
+            "message" => Ok(Self::Message),
+            _ => Err(ERR_INVALID_DONA_EXPORT_COLUMN.to_string()),
+        }
+    }
+
+    /// Title of the column in the header row.
+    pub fn header(&self) -> &'static str {
+        match self {
+            Self::Id => "ID",
+            Self::Date => "Date",
+            Self::Amount => "Amount",
+            Self::Fees => "Fees",
+            Self::NetAmount => "Net amount",
+            Self::Currency => "Currency",
+            Self::Method => "Method",
+            Self::Status => "Status",
+            Self::Sender => "Sender",
+            Self::Message => "Message",
+        }
+    }
+}
+
+impl Display for DonaExportColumn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Id => write!(f, "id"),
+            Self::Date => write!(f, "date"),
+            Self::Amount => write!(f, "amount"),
+            Self::Fees => write!(f, "fees"),
+            Self::NetAmount => write!(f, "net_amount"),
+            Self::Currency => write!(f, "currency"),
+            Self::Method => write!(f, "method"),
+            Self::Status => write!(f, "status"),
+            Self::Sender => write!(f, "sender"),
+            Self::Message => write!(f, "message"),
+        }
+    }
+}
+
+pub const ERR_INVALID_DONA_EXPORT_COLUMNS: &str = "Invalid Dona Export Columns";
+
+/// Columns of the export in the order they are written. An empty list selects all of them.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct DonaExportColumns(Vec<DonaExportColumn>);
+
+impl DonaExportColumns {
+    pub fn new(values: Vec<String>) -> Result<Self, String> {
+        if values.is_empty() {
+            return Ok(Self::all());
+        }
+
+        let mut columns: Vec<DonaExportColumn> = Vec::with_capacity(values.len());
+        for value in values {
+            let column = DonaExportColumn::new(value)?;
+            if columns.contains(&column) {
+                return Err(ERR_INVALID_DONA_EXPORT_COLUMNS.to_string());
+            }
+            columns.push(column);
+        }
+
+        Ok(Self(columns))
+    }
+
+    pub fn all() -> Self {
+        Self(vec![
+            DonaExportColumn::Id,
+            DonaExportColumn::Date,
+            DonaExportColumn::Amount,
+            DonaExportColumn::Fees,
+            DonaExportColumn::NetAmount,
+            DonaExportColumn::Currency,
+            DonaExportColumn::Method,
+            DonaExportColumn::Status,
+            DonaExportColumn::Sender,
+            DonaExportColumn::Message,
+        ])
+    }
+
+    pub fn values(&self) -> &[DonaExportColumn] {
+        &self.0
+    }
+}
+
+impl Display for DonaExportColumns {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let columns = self
+            .0
+            .iter()
+            .map(|column| column.to_string())
+            .collect::<Vec<String>>();
+        write!(f, "{}", columns.join(","))
+    }
+}
+
+pub const ERR_INVALID_DONA_EXPORT_STATUS: &str = "Invalid Dona Export Status";
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum DonaExportStatus {
+    Pending,
+    Completed,
+    Failed,
+}
+
+impl DonaExportStatus {
+    pub fn new(value: String) -> Result<Self, String> {
+        match value.as_str() {
+            "pending" => Ok(Self::Pending),
+            "completed" => Ok(Self::Completed),
+            "failed" => Ok(Self::Failed),
+            _ => Err(ERR_INVALID_DONA_EXPORT_STATUS.to_string()),
+        }
+    }
+}
+
+impl Display for DonaExportStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pending => write!(f, "pending"),
+            Self::Completed => write!(f, "completed"),
+            Self::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+pub const ERR_INVALID_DONA_EXPORT_CREATED_AT: &str = "Invalid Dona Export Created At";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct DonaExportCreatedAt(OffsetDateTime);
+
+impl DonaExportCreatedAt {
+    pub fn new(value: OffsetDateTime) -> Result<Self, String> {
+        if value < OffsetDateTime::now_utc() {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_DONA_EXPORT_CREATED_AT.to_string())
+        }
+    }
+}
+
+impl Display for DonaExportCreatedAt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.format(&Rfc3339).unwrap())
+    }
+}
+
+pub const ERR_INVALID_DONA_EXPORT_UPDATED_AT: &str = "Invalid Dona Export Updated At";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct DonaExportUpdatedAt(OffsetDateTime);
+
+impl DonaExportUpdatedAt {
+    pub fn new(value: OffsetDateTime) -> Result<Self, String> {
+        if value < OffsetDateTime::now_utc() {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_DONA_EXPORT_UPDATED_AT.to_string())
+        }
+    }
+}
+
+impl Display for DonaExportUpdatedAt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.format(&Rfc3339).unwrap())
+    }
+}
+
+/// A spreadsheet of the donas a user received, built in the background. The filters are the
+/// ones of the `Criteria` it was requested with, the recipient filter is always added on top.
+#[derive(Debug, Clone)]
+pub struct DonaExport {
+    id: DonaExportId,
+    user_id: UserId,
+    format: DonaExportFormat,
+    columns: DonaExportColumns,
+    filters: Vec<Filter>,
+    status: DonaExportStatus,
+    row_count: u64,
+    error: Option<String>,
+    created_at: DonaExportCreatedAt,
+    updated_at: DonaExportUpdatedAt,
+
+    events: Vec<Arc<dyn Event>>,
+}
+
+impl PartialEq for DonaExport {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.user_id == other.user_id
+            && self.format == other.format
+            && self.columns == other.columns
+            && self.filters == other.filters
+            && self.status == other.status
+            && self.row_count == other.row_count
+            && self.error == other.error
+            && self.created_at == other.created_at
+            && self.updated_at == other.updated_at
+    }
+}
+
+impl Eq for DonaExport {}
+
+impl DonaExport {
+    pub(crate) fn new(
+        id: String,
+        user_id: String,
+        format: String,
+        columns: Vec<String>,
+        filters: Vec<Filter>,
+        status: String,
+        row_count: u64,
+        error: Option<String>,
+        created_at: OffsetDateTime,
+        updated_at: OffsetDateTime,
+    ) -> Result<Self, String> {
+        Ok(Self {
+            id: DonaExportId::new(id)?,
+            user_id: UserId::new(user_id)?,
+            format: DonaExportFormat::new(format)?,
+            columns: DonaExportColumns::new(columns)?,
+            filters,
+            status: DonaExportStatus::new(status)?,
+            row_count,
+            error,
+            created_at: DonaExportCreatedAt::new(created_at)?,
+            updated_at: DonaExportUpdatedAt::new(updated_at)?,
+            events: vec![],
+        })
+    }
+
+    pub fn create(
+        id: String,
+        user_id: String,
+        format: String,
+        columns: Vec<String>,
+        filters: Vec<Filter>,
+        created_at: OffsetDateTime,
+    ) -> Result<Self, String> {
+        let mut export = Self::new(
+            id,
+            user_id,
+            format,
+            columns,
+            filters,
+            DonaExportStatus::Pending.to_string(),
+            0,
+            None,
+            created_at,
+            created_at,
+        )?;
+
+        let event = DonaExportRequestedEvent::new(
+            export.id(),
+            export.user_id(),
+            export.format.to_string(),
+            export.created_at.to_string(),
+        );
+        export.record(Arc::new(event));
+
+        Ok(export)
+    }
+
+    fn ensure_pending(&self) -> Result<(), String> {
+        if self.status != DonaExportStatus::Pending {
+            return Err(ERR_DONA_EXPORT_NOT_PENDING.to_string());
+        }
+
+        Ok(())
+    }
+
+    pub fn complete(&mut self, row_count: u64, updated_at: OffsetDateTime) -> Result<(), String> {
+        self.ensure_pending()?;
+
+        self.status = DonaExportStatus::Completed;
+        self.row_count = row_count;
+        self.updated_at = DonaExportUpdatedAt::new(updated_at)?;
+
+        let event = DonaExportCompletedEvent::new(
+            self.id(),
+            self.user_id(),
+            self.download_url(),
+            self.row_count.to_string(),
+            self.updated_at.to_string(),
+        );
+        self.record(Arc::new(event));
+
+        Ok(())
+    }
+
+    pub fn fail(&mut self, error: String, updated_at: OffsetDateTime) -> Result<(), String> {
+        self.ensure_pending()?;
+
+        self.status = DonaExportStatus::Failed;
+        self.error = Some(error);
+        self.updated_at = DonaExportUpdatedAt::new(updated_at)?;
+
+        let event = DonaExportFailedEvent::new(
+            self.id(),
+            self.user_id(),
+            self.error.clone().unwrap_or_default(),
+            self.updated_at.to_string(),
+        );
+        self.record(Arc::new(event));
+
+        Ok(())
+    }
+
+    /// Criteria of the page of donas that follows `after`. Pages are walked by creation date
+    /// and id, so the export does not hold every dona in memory at once and donas created at
+    /// the same instant are neither skipped nor written twice.
+    pub fn page_criteria(
+        &self,
+        after: Option<AfterCursor>,
+        size: usize,
+    ) -> Result<Criteria, String> {
+        let criteria = Criteria::new(
+            self.filters.clone(),
+            None,
+            Some(Cursor::new(after, None, Some(FirstField::new(size)?), None)),
+        );
+
+        Ok(criteria.with_filter(Filter::new(
+            FilterField::try_from("user_id".to_string())?,
+            FilterOperator::Equal,
+            FilterValue::try_from(self.user_id())?,
+        )))
+    }
+
+    pub fn can_be_downloaded_by(&self, user_id: &str) -> bool {
+        self.user_id.to_string() == user_id
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.status == DonaExportStatus::Completed
+    }
+
+    pub fn filename(&self) -> String {
+        format!("donas-{}.{}", self.id, self.format)
+    }
+
+    pub fn download_url(&self) -> String {
+        format!("{}/{}", DONA_EXPORT_DOWNLOAD_PATH, self.id)
+    }
+
+    pub fn record(&mut self, event: Arc<dyn Event>) {
+        self.events.push(event);
+    }
+
+    pub fn pull_events(&mut self) -> Vec<Arc<dyn Event>> {
+        let events = self.events.clone();
+        self.events = vec![];
+        events
+    }
+
+    pub fn id(&self) -> String {
+        self.id.to_string()
+    }
+
+    pub fn user_id(&self) -> String {
+        self.user_id.to_string()
+    }
+
+    pub fn format(&self) -> DonaExportFormat {
+        self.format
+    }
+
+    pub fn columns(&self) -> &[DonaExportColumn] {
+        self.columns.values()
+    }
+
+    pub fn filters(&self) -> &[Filter] {
+        &self.filters
+    }
+
+    /// Filters as `field`, `operator` and `value` maps, the shape `Filter::from_values` reads.
+    pub fn filters_to_primitives(&self) -> Vec<HashMap<String, String>> {
+        self.filters
+            .iter()
+            .map(|filter| {
+                HashMap::from([
+                    ("field".to_string(), filter.field().to_string()),
+                    ("operator".to_string(), filter.operator().to_string()),
+                    ("value".to_string(), filter.value().to_string()),
+                ])
+            })
+            .collect()
+    }
+
+    pub fn status(&self) -> String {
+        self.status.to_string()
+    }
+
+    pub fn row_count(&self) -> u64 {
+        self.row_count
+    }
+
+    pub fn error(&self) -> Option<String> {
+        self.error.clone()
+    }
+
+    pub fn created_at(&self) -> OffsetDateTime {
+        self.created_at.0
+    }
+
+    pub fn updated_at(&self) -> OffsetDateTime {
+        self.updated_at.0
+    }
+}
+
+pub mod tests {
+    use shared::domain::{utils::new_uuid, value_objects::user_id::tests::UserIdMother};
+    use time::Duration;
+
+    use super::*;
+
+    pub struct DonaExportIdMother;
+
+    impl DonaExportIdMother {
+        pub fn random() -> DonaExportId {
+            DonaExportId::new(new_uuid()).unwrap()
+        }
+
+        pub fn create(value: Option<String>) -> DonaExportId {
+            match value {
+                Some(value) => DonaExportId::new(value).unwrap(),
+                None => Self::random(),
+            }
+        }
+    }
+
+    pub struct DonaExportMother;
+
+    impl DonaExportMother {
+        /// An export requested an hour ago.
+        pub fn create(
+            id: Option<String>,
+            user_id: Option<String>,
+            format: Option<String>,
+            columns: Vec<String>,
+            filters: Vec<Filter>,
+            status: Option<String>,
+        ) -> DonaExport {
+            let created_at = OffsetDateTime::now_utc() - Duration::hours(1);
+
+            DonaExport {
+                id: DonaExportIdMother::create(id),
+                user_id: UserIdMother::create(user_id),
+                format: DonaExportFormat::new(format.unwrap_or("csv".to_string())).unwrap(),
+                columns: DonaExportColumns::new(columns).unwrap(),
+                filters,
+                status: DonaExportStatus::new(status.unwrap_or("pending".to_string())).unwrap(),
+                row_count: 0,
+                error: None,
+                created_at: DonaExportCreatedAt::new(created_at).unwrap(),
+                updated_at: DonaExportUpdatedAt::new(created_at).unwrap(),
+
+                events: vec![],
+            }
+        }
+
+        pub fn random() -> DonaExport {
+            Self::create(None, None, None, vec![], vec![], None)
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use shared::domain::bus::event::{BaseEvent, Event, EventDeserializeError, EventSerialized};
+
+pub const DONA_EXPORT_COMPLETED_EVENT_TYPE: &str = "dona.dona_export_completed";
+
+/// Published once the export can be downloaded from `download_url`, which the owner is then
+/// notified of.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct DonaExportCompletedEvent {
+    id: String,
+    user_id: String,
+    download_url: String,
+    row_count: String,
+    updated_at: String,
+
+    base_event: BaseEvent,
+}
+
+impl DonaExportCompletedEvent {
+    pub fn new(
+        id: String,
+        user_id: String,
+        download_url: String,
+        row_count: String,
+        updated_at: String,
+    ) -> Self {
+        Self {
+            id: id.clone(),
+            user_id,
+            download_url,
+            row_count,
+            updated_at,
+            base_event: BaseEvent::new(id),
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    pub fn download_url(&self) -> &str {
+        &self.download_url
+    }
+
+    pub fn row_count(&self) -> &str {
+        &self.row_count
+    }
+
+    pub fn updated_at(&self) -> &str {
+        &self.updated_at
+    }
+}
+
+impl Event for DonaExportCompletedEvent {
+    fn event_type(&self) -> &'static str {
+        DONA_EXPORT_COMPLETED_EVENT_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn from_primitives(
+        &self,
+        primitives: EventSerialized,
+    ) -> Result<Box<dyn Event>, EventDeserializeError> {
+        let data = primitives.data();
+        let base_event = BaseEvent::from_primitives(
+            primitives.event_id().to_string(),
+            primitives.aggregate_id().to_string(),
+            primitives.occurred_at().to_string(),
+        );
+
+        let id = data
+            .get("id")
+            .ok_or(EventDeserializeError::MissingField("id".to_string()))?;
+        let user_id = data
+            .get("user_id")
+            .ok_or(EventDeserializeError::MissingField("user_id".to_string()))?;
+        let download_url = data
+            .get("download_url")
+            .ok_or(EventDeserializeError::MissingField(
+                "download_url".to_string(),
+            ))?;
+        let row_count = data
+            .get("row_count")
+            .ok_or(EventDeserializeError::MissingField("row_count".to_string()))?;
+        let updated_at = data
+            .get("updated_at")
+            .ok_or(EventDeserializeError::MissingField(
+                "updated_at".to_string(),
+            ))?;
+
+        Ok(Box::new(Self {
+            id: id.to_string(),
+            user_id: user_id.to_string(),
+            download_url: download_url.to_string(),
+            row_count: row_count.to_string(),
+            updated_at: updated_at.to_string(),
+            base_event,
+        }))
+    }
+
+    fn to_primitives(&self) -> EventSerialized {
+        EventSerialized::new(
+            self.base_event.event_id().to_string(),
+            self.base_event.aggregate_id().to_string(),
+            self.base_event.occurred_at().to_string(),
+            vec![
+                ("id".to_string(), self.id.clone()),
+                ("user_id".to_string(), self.user_id.clone()),
+                ("download_url".to_string(), self.download_url.clone()),
+                ("row_count".to_string(), self.row_count.clone()),
+                ("updated_at".to_string(), self.updated_at.clone()),
+            ]
+            .into_iter()
+            .collect(),
+        )
+    }
+}
@@ -0,0 +1,36 @@
+use shared::domain::{base_errors::BaseRepositoryError, value_objects::user_id::UserId};
+
+use super::dona_export::{DonaExport, DonaExportId};
+
+#[async_trait::async_trait]
+pub trait DonaExportRepository: Send + Sync {
+    async fn find_by_id(&self, id: DonaExportId) -> Result<DonaExport, BaseRepositoryError>;
+    /// Latest exports of the user first, at most `limit` of them.
+    async fn find_by_user_id(
+        &self,
+        user_id: UserId,
+        limit: u64,
+    ) -> Result<Vec<DonaExport>, BaseRepositoryError>;
+    /// Oldest pending exports first, at most `limit` of them.
+    async fn find_pending(&self, limit: u64) -> Result<Vec<DonaExport>, BaseRepositoryError>;
+    async fn save(&self, export: &DonaExport) -> Result<(), BaseRepositoryError>;
+}
+
+#[cfg(test)]
+pub mod tests {
+    use mockall::mock;
+
+    use super::*;
+
+    mock! {
+        pub DonaExportRepository {}
+
+        #[async_trait::async_trait]
+        impl DonaExportRepository for DonaExportRepository {
+            async fn find_by_id(&self, id: DonaExportId) -> Result<DonaExport, BaseRepositoryError>;
+            async fn find_by_user_id(&self, user_id: UserId, limit: u64) -> Result<Vec<DonaExport>, BaseRepositoryError>;
+            async fn find_pending(&self, limit: u64) -> Result<Vec<DonaExport>, BaseRepositoryError>;
+            async fn save(&self, export: &DonaExport) -> Result<(), BaseRepositoryError>;
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use shared::domain::bus::event::{BaseEvent, Event, EventDeserializeError, EventSerialized};
+
+pub const DONA_EXPORT_REQUESTED_EVENT_TYPE: &str = "dona.dona_export_requested";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct DonaExportRequestedEvent {
+    id: String,
+    user_id: String,
+    format: String,
+    created_at: String,
+
+    base_event: BaseEvent,
+}
+
+impl DonaExportRequestedEvent {
+    pub fn new(id: String, user_id: String, format: String, created_at: String) -> Self {
+        Self {
+            id: id.clone(),
+            user_id,
+            format,
+            created_at,
+            base_event: BaseEvent::new(id),
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    pub fn format(&self) -> &str {
+        &self.format
+    }
+
+    pub fn created_at(&self) -> &str {
+        &self.created_at
+    }
+}
+
+impl Event for DonaExportRequestedEvent {
+    fn event_type(&self) -> &'static str {
+        DONA_EXPORT_REQUESTED_EVENT_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn from_primitives(
+        &self,
+        primitives: EventSerialized,
+    ) -> Result<Box<dyn Event>, EventDeserializeError> {
+        let data = primitives.data();
+        let base_event = BaseEvent::from_primitives(
+            primitives.event_id().to_string(),
+            primitives.aggregate_id().to_string(),
+            primitives.occurred_at().to_string(),
+        );
+
+        let id = data
+            .get("id")
+            .ok_or(EventDeserializeError::MissingField("id".to_string()))?;
+        let user_id = data
+            .get("user_id")
+            .ok_or(EventDeserializeError::MissingField("user_id".to_string()))?;
+        let format = data
+            .get("format")
+            .ok_or(EventDeserializeError::MissingField("format".to_string()))?;
+        let created_at = data
+            .get("created_at")
+            .ok_or(EventDeserializeError::MissingField(
+                "created_at".to_string(),
+            ))?;
+
+        Ok(Box::new(Self {
+            id: id.to_string(),
+            user_id: user_id.to_string(),
+            format: format.to_string(),
+            created_at: created_at.to_string(),
+            base_event,
+        }))
+    }
+
+    fn to_primitives(&self) -> EventSerialized {
+        EventSerialized::new(
+            self.base_event.event_id().to_string(),
+            self.base_event.aggregate_id().to_string(),
+            self.base_event.occurred_at().to_string(),
+            vec![
+                ("id".to_string(), self.id.clone()),
+                ("user_id".to_string(), self.user_id.clone()),
+                ("format".to_string(), self.format.clone()),
+                ("created_at".to_string(), self.created_at.clone()),
+            ]
+            .into_iter()
+            .collect(),
+        )
+    }
+}
@@ -0,0 +1,24 @@
+use std::fs::File;
+
+use rust_decimal::Decimal;
+
+use super::dona_export::DonaExportFormat;
+
+/// Value of a cell. Numbers stay numbers in formats that tell them apart from text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DonaExportCell {
+    Text(String),
+    Number(Decimal),
+}
+
+/// A spreadsheet being written row by row, so rows can be dropped once they are written.
+pub trait DonaExportSheet: Send {
+    fn write_row(&mut self, cells: &[DonaExportCell]) -> Result<(), String>;
+    /// Completes the document and returns it rewound, ready to be stored.
+    fn finish(self: Box<Self>) -> Result<File, String>;
+}
+
+pub trait DonaExportWriter: Send + Sync {
+    fn format(&self) -> DonaExportFormat;
+    fn start(&self) -> Result<Box<dyn DonaExportSheet>, String>;
+}
@@ -0,0 +1,6 @@
+pub mod dona_export;
+pub mod dona_export_completed_event;
+pub mod dona_export_failed_event;
+pub mod dona_export_repository;
+pub mod dona_export_requested_event;
+pub mod dona_export_writer;
@@ -0,0 +1 @@
+pub mod sea_dona_export_repo;
@@ -0,0 +1,121 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Seek, SeekFrom, Write},
+};
+
+use crate::export::domain::{
+    dona_export::DonaExportFormat,
+    dona_export_writer::{DonaExportCell, DonaExportSheet, DonaExportWriter},
+};
+
+/// Characters that make spreadsheet applications read a cell as a formula.
+const FORMULA_PREFIXES: [char; 6] = ['=', '+', '-', '@', '\t', '\r'];
+
+/// Quotes the field when needed, as RFC 4180 describes. Text that would be read as a formula
+/// is prefixed with a quote, donors choose what goes in their messages.
+fn escape(cell: &DonaExportCell) -> String {
+    let value = match cell {
+        DonaExportCell::Number(value) => return value.to_string(),
+        DonaExportCell::Text(value) if value.starts_with(FORMULA_PREFIXES) => {
+            format!("'{}", value)
+        }
+        DonaExportCell::Text(value) => value.to_owned(),
+    };
+
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+pub struct CsvDonaExportSheet {
+    writer: BufWriter<File>,
+}
+
+impl DonaExportSheet for CsvDonaExportSheet {
+    fn write_row(&mut self, cells: &[DonaExportCell]) -> Result<(), String> {
+        let row = cells.iter().map(escape).collect::<Vec<String>>().join(",");
+
+        self.writer
+            .write_all(row.as_bytes())
+            .and_then(|_| self.writer.write_all(b"\r\n"))
+            .map_err(|e| e.to_string())
+    }
+
+    fn finish(self: Box<Self>) -> Result<File, String> {
+        let mut file = self.writer.into_inner().map_err(|e| e.to_string())?;
+        file.seek(SeekFrom::Start(0)).map_err(|e| e.to_string())?;
+
+        Ok(file)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CsvDonaExportWriter;
+
+impl CsvDonaExportWriter {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl DonaExportWriter for CsvDonaExportWriter {
+    fn format(&self) -> DonaExportFormat {
+        DonaExportFormat::Csv
+    }
+
+    fn start(&self) -> Result<Box<dyn DonaExportSheet>, String> {
+        let file = tempfile::tempfile().map_err(|e| e.to_string())?;
+
+        Ok(Box::new(CsvDonaExportSheet {
+            writer: BufWriter::new(file),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn it_should_quote_fields_and_neutralize_formulas() {
+        let mut sheet = CsvDonaExportWriter::new().start().unwrap();
+        sheet
+            .write_row(&[
+                DonaExportCell::Text("Message".to_string()),
+                DonaExportCell::Text("Amount".to_string()),
+            ])
+            .unwrap();
+        sheet
+            .write_row(&[
+                DonaExportCell::Text("Thanks, \"great\" stream\nbye".to_string()),
+                DonaExportCell::Number(dec!(-10.50)),
+            ])
+            .unwrap();
+        sheet
+            .write_row(&[
+                DonaExportCell::Text("=HYPERLINK(\"http://x\")".to_string()),
+                DonaExportCell::Number(dec!(3)),
+            ])
+            .unwrap();
+
+        let mut content = String::new();
+        sheet
+            .finish()
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+
+        assert_eq!(
+            content,
+            "Message,Amount\r\n\
+             \"Thanks, \"\"great\"\" stream\nbye\",-10.50\r\n\
+             \"'=HYPERLINK(\"\"http://x\"\")\",3\r\n"
+        );
+    }
+}
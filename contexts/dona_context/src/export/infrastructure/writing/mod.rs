@@ -0,0 +1,2 @@
+pub mod csv_dona_export_writer;
+pub mod xlsx_dona_export_writer;
@@ -0,0 +1,135 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Seek, SeekFrom},
+};
+
+use rust_decimal::prelude::ToPrimitive;
+use rust_xlsxwriter::{ColNum, RowNum, Workbook};
+
+use crate::export::domain::{
+    dona_export::DonaExportFormat,
+    dona_export_writer::{DonaExportCell, DonaExportSheet, DonaExportWriter},
+};
+
+const SHEET_NAME: &str = "Donas";
+
+/// Rows are flushed to a temporary file as they come, rust_xlsxwriter's constant memory mode
+/// only keeps the current one, and the archive is assembled on finish.
+pub struct XlsxDonaExportSheet {
+    workbook: Workbook,
+    rows: RowNum,
+}
+
+impl DonaExportSheet for XlsxDonaExportSheet {
+    fn write_row(&mut self, cells: &[DonaExportCell]) -> Result<(), String> {
+        let sheet = self
+            .workbook
+            .worksheet_from_index(0)
+            .map_err(|e| e.to_string())?;
+
+        for (index, cell) in cells.iter().enumerate() {
+            let column = ColNum::try_from(index).map_err(|e| e.to_string())?;
+            match cell {
+                DonaExportCell::Number(value) => sheet.write_number(
+                    self.rows,
+                    column,
+                    value
+                        .to_f64()
+                        .ok_or("Dona Export amount does not fit a spreadsheet")?,
+                ),
+                DonaExportCell::Text(value) => sheet.write_string(self.rows, column, value),
+            }
+            .map_err(|e| e.to_string())?;
+        }
+        self.rows += 1;
+
+        Ok(())
+    }
+
+    fn finish(mut self: Box<Self>) -> Result<File, String> {
+        let file = tempfile::tempfile().map_err(|e| e.to_string())?;
+        let mut writer = BufWriter::new(file);
+        self.workbook
+            .save_to_writer(&mut writer)
+            .map_err(|e| e.to_string())?;
+
+        let mut file = writer.into_inner().map_err(|e| e.to_string())?;
+        file.seek(SeekFrom::Start(0)).map_err(|e| e.to_string())?;
+
+        Ok(file)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct XlsxDonaExportWriter;
+
+impl XlsxDonaExportWriter {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl DonaExportWriter for XlsxDonaExportWriter {
+    fn format(&self) -> DonaExportFormat {
+        DonaExportFormat::Xlsx
+    }
+
+    fn start(&self) -> Result<Box<dyn DonaExportSheet>, String> {
+        let mut workbook = Workbook::new();
+        workbook
+            .add_worksheet_with_constant_memory()
+            .set_name(SHEET_NAME)
+            .map_err(|e| e.to_string())?;
+
+        Ok(Box::new(XlsxDonaExportSheet { workbook, rows: 0 }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn read_entry(file: File, name: &str) -> String {
+        let mut archive = zip::ZipArchive::new(file).unwrap();
+        let mut content = String::new();
+        archive
+            .by_name(name)
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+
+        content
+    }
+
+    #[test]
+    fn it_should_write_a_workbook_with_typed_cells() {
+        let mut sheet = XlsxDonaExportWriter::new().start().unwrap();
+        sheet
+            .write_row(&[
+                DonaExportCell::Text("Message".to_string()),
+                DonaExportCell::Text("Amount".to_string()),
+            ])
+            .unwrap();
+        sheet
+            .write_row(&[
+                DonaExportCell::Text("<b>Tom & Jerry</b>".to_string()),
+                DonaExportCell::Number(dec!(10.50)),
+            ])
+            .unwrap();
+        let file = sheet.finish().unwrap();
+
+        let workbook = read_entry(file.try_clone().unwrap(), "xl/workbook.xml");
+        assert!(workbook.contains(&format!("<sheet name=\"{}\"", SHEET_NAME)));
+
+        let sheet = read_entry(file, "xl/worksheets/sheet1.xml");
+        assert!(sheet.contains("<c r=\"A1\" t=\"inlineStr\"><is><t>Message</t></is></c>"));
+        assert!(sheet.contains(
+            "<c r=\"A2\" t=\"inlineStr\"><is><t>&lt;b&gt;Tom &amp; Jerry&lt;/b&gt;</t></is></c>"
+        ));
+        assert!(sheet.contains("<c r=\"B2\"><v>10.5</v></c>"));
+    }
+}
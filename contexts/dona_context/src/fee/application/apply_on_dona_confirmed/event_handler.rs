@@ -0,0 +1,322 @@
+use std::sync::Arc;
+
+use shared::domain::bus::event::{Event, EventError, EventHandler};
+
+use crate::dona::domain::dona_confirmed_event::{DonaConfirmedEvent, DONA_CONFIRMED_EVENT_TYPE};
+
+use super::service::DonaFeeApplier;
+
+#[derive(Clone)]
+pub struct ApplyFeeOnDonaConfirmed {
+    service: DonaFeeApplier,
+}
+
+impl ApplyFeeOnDonaConfirmed {
+    pub fn new(service: DonaFeeApplier) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl EventHandler for ApplyFeeOnDonaConfirmed {
+    async fn handle(&self, event: Arc<dyn Event>) -> Result<(), EventError> {
+        let event = event
+            .as_any()
+            .downcast_ref::<DonaConfirmedEvent>()
+            .ok_or_else(|| EventError::new("Invalid event".to_string()))?;
+
+        self.service
+            .execute(event.dona_id().to_string())
+            .await
+            .map_err(EventError::new)
+    }
+
+    fn subscribed_to(&self) -> Vec<&'static str> {
+        vec![DONA_CONFIRMED_EVENT_TYPE]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+    use shared::domain::base_errors::BaseRepositoryError;
+    use time::{macros::datetime, Duration, OffsetDateTime};
+
+    use super::*;
+
+    use crate::dona::domain::dona::tests::DonaMother;
+    use crate::dona::domain::dona::{Dona, DonaId, ERR_DONA_FEE_NOT_ALLOWED};
+    use crate::dona::domain::dona_repository::tests::MockDonaRepository;
+    use crate::exchange_rate::application::convert::service::CurrencyConverter;
+    use crate::exchange_rate::domain::exchange_rate::tests::ExchangeRateMother;
+    use crate::exchange_rate::domain::exchange_rate_repository::tests::MockExchangeRateRepository;
+    use crate::fee::application::calculate::service::FeeCalculator;
+    use crate::fee::domain::fee_rule::tests::FeeRuleMother;
+    use crate::fee::domain::fee_rule_repository::tests::MockFeeRuleRepository;
+    use crate::shared::domain::dona::DonaOptionMethod;
+
+    fn dona(amount: Decimal, currency: &str, status: &str, created_at: OffsetDateTime) -> Dona {
+        DonaMother::create(
+            None,
+            None,
+            Some(amount),
+            Some(currency.to_string()),
+            Some(status.to_string()),
+            Some("PAYPAL".to_string()),
+            None,
+            None,
+            None,
+            None,
+            Some(created_at),
+            Some(created_at + Duration::days(1)),
+        )
+    }
+
+    fn handler(
+        dona_repository: MockDonaRepository,
+        fee_rule_repository: MockFeeRuleRepository,
+        exchange_rate_repository: MockExchangeRateRepository,
+    ) -> ApplyFeeOnDonaConfirmed {
+        ApplyFeeOnDonaConfirmed::new(DonaFeeApplier::new(
+            Arc::new(dona_repository),
+            FeeCalculator::new(
+                Arc::new(fee_rule_repository),
+                CurrencyConverter::new(Arc::new(exchange_rate_repository)),
+            ),
+        ))
+    }
+
+    fn dona_repository(dona: &Dona, fee: Option<Decimal>) -> MockDonaRepository {
+        let mut repository = MockDonaRepository::new();
+        repository
+            .expect_find_by_id()
+            .with(predicate::eq(DonaId::new(dona.id()).unwrap()))
+            .times(1)
+            .return_const(Ok(dona.clone()));
+        match fee {
+            Some(fee) => {
+                let net = dona.amount() - fee;
+                repository
+                    .expect_save()
+                    .withf(move |dona| {
+                        dona.fee_amount() == Some(fee) && dona.net_amount() == Some(net)
+                    })
+                    .times(1)
+                    .return_const(Ok(()));
+            }
+            None => {
+                repository.expect_save().times(0);
+            }
+        }
+        repository
+    }
+
+    async fn handle(handler: ApplyFeeOnDonaConfirmed, dona: &Dona) -> Result<(), EventError> {
+        handler
+            .handle(Arc::new(DonaConfirmedEvent::new(
+                dona.id(),
+                dona.updated_at().to_string(),
+            )))
+            .await
+    }
+
+    #[tokio::test]
+    async fn it_should_apply_the_fee_of_the_rule_effective_when_the_dona_was_created() {
+        let created_at = datetime!(2024-03-10 12:00 UTC);
+        let dona = dona(dec!(50.00), "USD", "confirmed", created_at);
+
+        let mut fee_rule_repository = MockFeeRuleRepository::new();
+        fee_rule_repository
+            .expect_find_effective()
+            .with(
+                predicate::eq(DonaOptionMethod::Paypal),
+                predicate::eq(created_at.date()),
+            )
+            .times(1)
+            .return_const(Ok(FeeRuleMother::create(
+                "PAYPAL",
+                dec!(2.9),
+                Some((dec!(0.30), "USD")),
+                None,
+            )));
+
+        // 2.9% of 50.00 is 1.45, plus the fixed 0.30.
+        let result = handle(
+            handler(
+                dona_repository(&dona, Some(dec!(1.75))),
+                fee_rule_repository,
+                MockExchangeRateRepository::new(),
+            ),
+            &dona,
+        )
+        .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_convert_the_fixed_part_and_round_to_the_dona_currency() {
+        let dona = dona(
+            dec!(1000),
+            "JPY",
+            "confirmed",
+            datetime!(2024-03-10 12:00 UTC),
+        );
+
+        let mut fee_rule_repository = MockFeeRuleRepository::new();
+        fee_rule_repository
+            .expect_find_effective()
+            .times(1)
+            .return_const(Ok(FeeRuleMother::create(
+                "PAYPAL",
+                dec!(3.33),
+                Some((dec!(0.30), "USD")),
+                None,
+            )));
+
+        let mut exchange_rate_repository = MockExchangeRateRepository::new();
+        exchange_rate_repository
+            .expect_find_effective()
+            .times(1)
+            .return_const(Ok(ExchangeRateMother::create(
+                None,
+                Some("USD".to_string()),
+                Some("JPY".to_string()),
+                Some(dec!(150)),
+                None,
+            )));
+
+        // 33.3 JPY plus 45 JPY, rounded to whole yen.
+        let result = handle(
+            handler(
+                dona_repository(&dona, Some(dec!(78))),
+                fee_rule_repository,
+                exchange_rate_repository,
+            ),
+            &dona,
+        )
+        .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_not_take_more_than_the_amount() {
+        let dona = dona(
+            dec!(0.20),
+            "USD",
+            "confirmed",
+            datetime!(2024-03-10 12:00 UTC),
+        );
+
+        let mut fee_rule_repository = MockFeeRuleRepository::new();
+        fee_rule_repository
+            .expect_find_effective()
+            .times(1)
+            .return_const(Ok(FeeRuleMother::create(
+                "PAYPAL",
+                dec!(2.9),
+                Some((dec!(0.30), "USD")),
+                None,
+            )));
+
+        let result = handle(
+            handler(
+                dona_repository(&dona, Some(dec!(0.20))),
+                fee_rule_repository,
+                MockExchangeRateRepository::new(),
+            ),
+            &dona,
+        )
+        .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_apply_no_fee_when_method_has_no_rule() {
+        let dona = dona(
+            dec!(10.00),
+            "EUR",
+            "confirmed",
+            datetime!(2024-03-10 12:00 UTC),
+        );
+
+        let mut fee_rule_repository = MockFeeRuleRepository::new();
+        fee_rule_repository
+            .expect_find_effective()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+
+        let result = handle(
+            handler(
+                dona_repository(&dona, Some(dec!(0))),
+                fee_rule_repository,
+                MockExchangeRateRepository::new(),
+            ),
+            &dona,
+        )
+        .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_skip_donas_with_a_fee() {
+        let mut dona = dona(
+            dec!(10.00),
+            "EUR",
+            "confirmed",
+            datetime!(2024-03-10 12:00 UTC),
+        );
+        dona.apply_fee(dec!(0.50)).unwrap();
+
+        let mut fee_rule_repository = MockFeeRuleRepository::new();
+        fee_rule_repository.expect_find_effective().times(0);
+
+        let result = handle(
+            handler(
+                dona_repository(&dona, None),
+                fee_rule_repository,
+                MockExchangeRateRepository::new(),
+            ),
+            &dona,
+        )
+        .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_dona_is_not_confirmed() {
+        let dona = dona(
+            dec!(10.00),
+            "EUR",
+            "refunded",
+            datetime!(2024-03-10 12:00 UTC),
+        );
+
+        let mut fee_rule_repository = MockFeeRuleRepository::new();
+        fee_rule_repository
+            .expect_find_effective()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+
+        let result = handle(
+            handler(
+                dona_repository(&dona, None),
+                fee_rule_repository,
+                MockExchangeRateRepository::new(),
+            ),
+            &dona,
+        )
+        .await;
+
+        assert_eq!(
+            result,
+            Err(EventError::new(ERR_DONA_FEE_NOT_ALLOWED.to_string()))
+        );
+    }
+}
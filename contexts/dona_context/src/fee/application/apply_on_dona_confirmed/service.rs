@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use shared::domain::value_objects::money::Money;
+
+use crate::{
+    dona::domain::{dona::DonaId, dona_repository::DonaRepository},
+    fee::application::calculate::service::FeeCalculator,
+    shared::domain::dona::DonaOptionMethod,
+};
+
+#[derive(Clone)]
+pub struct DonaFeeApplier {
+    dona_repository: Arc<dyn DonaRepository>,
+    calculator: FeeCalculator,
+}
+
+impl DonaFeeApplier {
+    pub fn new(dona_repository: Arc<dyn DonaRepository>, calculator: FeeCalculator) -> Self {
+        Self {
+            dona_repository,
+            calculator,
+        }
+    }
+
+    /// Applies the fee of the rule in effect on the day the dona was created, so a dona
+    /// confirmed after a rule change keeps the terms it was made under. Donas that already
+    /// have a fee are skipped, so the event can be delivered more than once.
+    pub async fn execute(&self, dona_id: String) -> Result<(), String> {
+        let mut dona = self
+            .dona_repository
+            .find_by_id(DonaId::new(dona_id)?)
+            .await?;
+        if dona.fee_amount().is_some() {
+            return Ok(());
+        }
+
+        let fee = self
+            .calculator
+            .execute(
+                &DonaOptionMethod::new(dona.method())?,
+                &Money::new(dona.amount(), dona.currency())?,
+                dona.created_at(),
+            )
+            .await?;
+        dona.apply_fee(fee)?;
+
+        self.dona_repository.save(&dona).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use shared::domain::{base_errors::BaseRepositoryError, value_objects::money::Money};
+use time::OffsetDateTime;
+
+use crate::{
+    exchange_rate::application::convert::service::CurrencyConverter,
+    fee::domain::fee_rule_repository::FeeRuleRepository, shared::domain::dona::DonaOptionMethod,
+};
+
+#[derive(Clone)]
+pub struct FeeCalculator {
+    repository: Arc<dyn FeeRuleRepository>,
+    converter: CurrencyConverter,
+}
+
+impl FeeCalculator {
+    pub fn new(repository: Arc<dyn FeeRuleRepository>, converter: CurrencyConverter) -> Self {
+        Self {
+            repository,
+            converter,
+        }
+    }
+
+    /// The fee of a dona of `amount` paid through `method`, with the rule in effect on the day
+    /// of `at`. The fixed part is converted with the rate of that day too. Methods without a
+    /// rule are free, and the fee is rounded to the currency and never exceeds the amount.
+    pub async fn execute(
+        &self,
+        method: &DonaOptionMethod,
+        amount: &Money,
+        at: OffsetDateTime,
+    ) -> Result<Decimal, String> {
+        let fee_rule = match self
+            .repository
+            .find_effective(method.clone(), at.date())
+            .await
+        {
+            Ok(fee_rule) => fee_rule,
+            Err(BaseRepositoryError::NotFound) => return Ok(Decimal::ZERO),
+            Err(e) => return Err(e.to_string()),
+        };
+
+        let mut fee = fee_rule.percentage_of(amount.amount());
+        if let Some(fixed) = fee_rule.fixed() {
+            fee += self
+                .converter
+                .convert(fixed.amount(), fixed.currency(), amount.currency(), at)
+                .await?;
+        }
+
+        Ok(fee
+            .round_dp(amount.currency().precision())
+            .min(amount.amount()))
+    }
+}
@@ -0,0 +1,246 @@
+use rust_decimal::Decimal;
+use shared::domain::bus::command::{Command, CommandError, CommandHandler};
+use time::{Date, OffsetDateTime};
+
+use super::service::FeeRuleCreator;
+
+pub const CREATE_FEE_RULE_COMMAND_TYPE: &str = "dona.create_fee_rule.command";
+
+/// `fixed_amount` and `fixed_currency` go together.
+#[derive(Debug)]
+pub struct CreateFeeRuleCommand {
+    pub id: String,
+    pub method: String,
+    pub percentage: Decimal,
+    pub fixed_amount: Option<Decimal>,
+    pub fixed_currency: Option<String>,
+    pub effective_from: Date,
+    pub created_at: OffsetDateTime,
+}
+
+impl Command for CreateFeeRuleCommand {
+    fn command_type(&self) -> &'static str {
+        CREATE_FEE_RULE_COMMAND_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct CreateFeeRuleCommandHandler {
+    service: FeeRuleCreator,
+}
+
+impl CreateFeeRuleCommandHandler {
+    pub fn new(service: FeeRuleCreator) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler for CreateFeeRuleCommandHandler {
+    async fn handle(&self, command: Box<dyn Command>) -> Result<(), CommandError> {
+        let command = command
+            .as_any()
+            .downcast_ref::<CreateFeeRuleCommand>()
+            .ok_or_else(|| CommandError::new("Invalid command".to_string()))?;
+
+        self.service
+            .execute(
+                command.id.to_owned(),
+                command.method.to_owned(),
+                command.percentage,
+                command.fixed_amount,
+                command.fixed_currency.to_owned(),
+                command.effective_from,
+                command.created_at,
+            )
+            .await
+            .map_err(CommandError::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockall::predicate;
+    use rust_decimal_macros::dec;
+    use shared::domain::{
+        base_errors::BaseRepositoryError, bus::event::tests::MockEventBus, utils::new_uuid,
+    };
+    use time::Duration;
+
+    use super::*;
+
+    use crate::fee::domain::fee_rule::tests::FeeRuleMother;
+    use crate::fee::domain::fee_rule::{
+        ERR_FEE_RULE_ALREADY_EXISTS, ERR_FEE_RULE_RETROACTIVE, ERR_INVALID_FEE_FIXED_AMOUNT,
+        ERR_INVALID_FEE_PERCENTAGE,
+    };
+    use crate::fee::domain::fee_rule_created_event::FEE_RULE_CREATED_EVENT_TYPE;
+    use crate::fee::domain::fee_rule_repository::tests::MockFeeRuleRepository;
+    use crate::shared::domain::dona::DonaOptionMethod;
+
+    fn handler(
+        repository: MockFeeRuleRepository,
+        event_bus: MockEventBus,
+    ) -> CreateFeeRuleCommandHandler {
+        CreateFeeRuleCommandHandler::new(FeeRuleCreator::new(
+            Arc::new(repository),
+            Arc::new(event_bus),
+        ))
+    }
+
+    fn command(
+        percentage: Decimal,
+        fixed: Option<(Decimal, &str)>,
+        effective_from: Date,
+    ) -> Box<CreateFeeRuleCommand> {
+        Box::new(CreateFeeRuleCommand {
+            id: new_uuid(),
+            method: "PAYPAL".to_string(),
+            percentage,
+            fixed_amount: fixed.map(|(amount, _)| amount),
+            fixed_currency: fixed.map(|(_, currency)| currency.to_string()),
+            effective_from,
+            created_at: OffsetDateTime::now_utc() - Duration::seconds(1),
+        })
+    }
+
+    fn today() -> Date {
+        OffsetDateTime::now_utc().date()
+    }
+
+    #[tokio::test]
+    async fn it_should_create_fee_rule() {
+        let effective_from = today() + Duration::days(7);
+
+        let mut repository = MockFeeRuleRepository::new();
+        repository
+            .expect_find_by_method()
+            .with(predicate::eq(DonaOptionMethod::Paypal))
+            .times(1)
+            .return_const(Ok(vec![FeeRuleMother::create(
+                "PAYPAL",
+                dec!(3.5),
+                None,
+                None,
+            )]));
+        repository
+            .expect_save()
+            .withf(move |fee_rule| {
+                fee_rule.percentage() == dec!(2.9)
+                    && fee_rule.fixed_amount() == Some(dec!(0.30))
+                    && fee_rule.fixed_currency() == Some("USD".to_string())
+                    && fee_rule.effective_from() == effective_from
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus
+            .expect_publish()
+            .withf(|events| {
+                events.len() == 1 && events[0].event_type() == FEE_RULE_CREATED_EVENT_TYPE
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let result = handler(repository, event_bus)
+            .handle(command(
+                dec!(2.9),
+                Some((dec!(0.30), "USD")),
+                effective_from,
+            ))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_method_has_a_rule_that_day() {
+        let mut repository = MockFeeRuleRepository::new();
+        repository
+            .expect_find_by_method()
+            .times(1)
+            .return_const(Ok(vec![FeeRuleMother::create(
+                "PAYPAL",
+                dec!(3.5),
+                None,
+                Some(today()),
+            )]));
+        repository.expect_save().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let result = handler(repository, event_bus)
+            .handle(command(dec!(2.9), None, today()))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_FEE_RULE_ALREADY_EXISTS.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_fee_rule_is_invalid() {
+        for (percentage, fixed, effective_from, error) in [
+            (dec!(100.1), None, today(), ERR_INVALID_FEE_PERCENTAGE),
+            (dec!(-1), None, today(), ERR_INVALID_FEE_PERCENTAGE),
+            (
+                dec!(2.9),
+                Some((dec!(0), "USD")),
+                today(),
+                ERR_INVALID_FEE_FIXED_AMOUNT,
+            ),
+            (
+                dec!(2.9),
+                None,
+                today() - Duration::days(1),
+                ERR_FEE_RULE_RETROACTIVE,
+            ),
+        ] {
+            let mut repository = MockFeeRuleRepository::new();
+            repository.expect_find_by_method().times(0);
+            repository.expect_save().times(0);
+
+            let mut event_bus = MockEventBus::new();
+            event_bus.expect_publish().times(0);
+
+            let result = handler(repository, event_bus)
+                .handle(command(percentage, fixed, effective_from))
+                .await;
+
+            assert_eq!(result, Err(CommandError::new(error.to_string())));
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_repository_fails() {
+        let mut repository = MockFeeRuleRepository::new();
+        repository
+            .expect_find_by_method()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::ConnectionError));
+        repository.expect_save().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let result = handler(repository, event_bus)
+            .handle(command(dec!(2.9), None, today()))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(
+                BaseRepositoryError::ConnectionError.to_string()
+            ))
+        );
+    }
+}
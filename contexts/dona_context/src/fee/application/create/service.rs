@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use shared::domain::bus::event::EventBus;
+use time::{Date, OffsetDateTime};
+
+use crate::{
+    fee::domain::{
+        fee_rule::{FeeRule, ERR_FEE_RULE_ALREADY_EXISTS},
+        fee_rule_repository::FeeRuleRepository,
+    },
+    shared::domain::dona::DonaOptionMethod,
+};
+
+#[derive(Clone)]
+pub struct FeeRuleCreator {
+    repository: Arc<dyn FeeRuleRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl FeeRuleCreator {
+    pub fn new(repository: Arc<dyn FeeRuleRepository>, event_bus: Arc<dyn EventBus>) -> Self {
+        Self {
+            repository,
+            event_bus,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        id: String,
+        method: String,
+        percentage: Decimal,
+        fixed_amount: Option<Decimal>,
+        fixed_currency: Option<String>,
+        effective_from: Date,
+        created_at: OffsetDateTime,
+    ) -> Result<(), String> {
+        let mut fee_rule = FeeRule::create(
+            id,
+            method.clone(),
+            percentage,
+            fixed_amount,
+            fixed_currency,
+            effective_from,
+            created_at,
+        )?;
+
+        let history = self
+            .repository
+            .find_by_method(DonaOptionMethod::new(method)?)
+            .await?;
+        if history
+            .iter()
+            .any(|rule| rule.effective_from() == fee_rule.effective_from())
+        {
+            return Err(ERR_FEE_RULE_ALREADY_EXISTS.to_string());
+        }
+
+        self.repository.save(&fee_rule).await?;
+
+        self.event_bus.publish(fee_rule.pull_events()).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+use shared::domain::bus::query::{Query, QueryError, QueryHandler, Response};
+
+use super::service::FeeRulesByMethodFinder;
+
+pub const FIND_FEE_RULES_BY_METHOD_QUERY_TYPE: &str = "dona.find_fee_rules_by_method.query";
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FindFeeRulesByMethodQuery {
+    pub method: String,
+}
+
+impl Query for FindFeeRulesByMethodQuery {
+    fn query_type(&self) -> &'static str {
+        FIND_FEE_RULES_BY_METHOD_QUERY_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct FindFeeRulesByMethodQueryHandler {
+    service: FeeRulesByMethodFinder,
+}
+
+impl FindFeeRulesByMethodQueryHandler {
+    pub fn new(service: FeeRulesByMethodFinder) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler for FindFeeRulesByMethodQueryHandler {
+    async fn handle(&self, query: Box<dyn Query>) -> Result<Box<dyn Response>, QueryError> {
+        let query = query
+            .as_any()
+            .downcast_ref::<FindFeeRulesByMethodQuery>()
+            .ok_or_else(|| QueryError::new("Invalid query".to_string()))?;
+
+        let fee_rules = self
+            .service
+            .execute(query.method.to_owned())
+            .await
+            .map_err(QueryError::new)?;
+
+        Ok(Box::new(fee_rules))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockall::predicate;
+    use rust_decimal_macros::dec;
+    use shared::domain::base_errors::BaseRepositoryError;
+    use time::macros::date;
+
+    use super::*;
+
+    use crate::fee::application::response::{FeeRuleResponse, FeeRulesResponse};
+    use crate::fee::domain::fee_rule::tests::FeeRuleMother;
+    use crate::fee::domain::fee_rule_repository::tests::MockFeeRuleRepository;
+    use crate::shared::domain::dona::{DonaOptionMethod, ERR_INVALID_DONA_OPTION_METHOD};
+
+    async fn find(
+        repository: MockFeeRuleRepository,
+        method: &str,
+    ) -> Result<FeeRulesResponse, QueryError> {
+        let handler = FindFeeRulesByMethodQueryHandler::new(FeeRulesByMethodFinder::new(Arc::new(
+            repository,
+        )));
+
+        handler
+            .handle(Box::new(FindFeeRulesByMethodQuery {
+                method: method.to_string(),
+            }))
+            .await
+            .map(|response| {
+                response
+                    .as_any()
+                    .downcast_ref::<FeeRulesResponse>()
+                    .unwrap()
+                    .clone()
+            })
+    }
+
+    #[tokio::test]
+    async fn it_should_find_fee_rules_of_method() {
+        let history = vec![
+            FeeRuleMother::create("CRYPTO", dec!(1), None, Some(date!(2024 - 02 - 01))),
+            FeeRuleMother::create(
+                "CRYPTO",
+                dec!(1.5),
+                Some((dec!(1.00), "USD")),
+                Some(date!(2024 - 01 - 01)),
+            ),
+        ];
+
+        let mut repository = MockFeeRuleRepository::new();
+        repository
+            .expect_find_by_method()
+            .with(predicate::eq(DonaOptionMethod::Crypto))
+            .times(1)
+            .return_const(Ok(history.clone()));
+
+        let result = find(repository, "CRYPTO").await;
+
+        assert_eq!(
+            result,
+            Ok(FeeRulesResponse {
+                fee_rules: history.into_iter().map(FeeRuleResponse::from).collect()
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_method_is_invalid() {
+        let mut repository = MockFeeRuleRepository::new();
+        repository.expect_find_by_method().times(0);
+
+        let result = find(repository, "CASH").await;
+
+        assert_eq!(
+            result,
+            Err(QueryError::new(ERR_INVALID_DONA_OPTION_METHOD.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_repository_fails() {
+        let mut repository = MockFeeRuleRepository::new();
+        repository
+            .expect_find_by_method()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::ConnectionError));
+
+        let result = find(repository, "PAYPAL").await;
+
+        assert_eq!(
+            result,
+            Err(QueryError::new(
+                BaseRepositoryError::ConnectionError.to_string()
+            ))
+        );
+    }
+}
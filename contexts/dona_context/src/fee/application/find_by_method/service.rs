@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use crate::{
+    fee::{
+        application::response::{FeeRuleResponse, FeeRulesResponse},
+        domain::fee_rule_repository::FeeRuleRepository,
+    },
+    shared::domain::dona::DonaOptionMethod,
+};
+
+#[derive(Clone)]
+pub struct FeeRulesByMethodFinder {
+    repository: Arc<dyn FeeRuleRepository>,
+}
+
+impl FeeRulesByMethodFinder {
+    pub fn new(repository: Arc<dyn FeeRuleRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, method: String) -> Result<FeeRulesResponse, String> {
+        let fee_rules = self
+            .repository
+            .find_by_method(DonaOptionMethod::new(method)?)
+            .await?;
+
+        Ok(FeeRulesResponse {
+            fee_rules: fee_rules.into_iter().map(FeeRuleResponse::from).collect(),
+        })
+    }
+}
@@ -0,0 +1,5 @@
+pub mod apply_on_dona_confirmed;
+pub mod calculate;
+pub mod create;
+pub mod find_by_method;
+pub mod response;
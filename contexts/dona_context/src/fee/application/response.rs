@@ -0,0 +1,48 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use shared::domain::bus::query::Response;
+use time::{Date, OffsetDateTime};
+
+use crate::fee::domain::fee_rule::FeeRule;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FeeRuleResponse {
+    pub id: String,
+    pub method: String,
+    pub percentage: Decimal,
+    pub fixed_amount: Option<Decimal>,
+    pub fixed_currency: Option<String>,
+    pub effective_from: Date,
+    pub created_at: OffsetDateTime,
+}
+
+impl From<FeeRule> for FeeRuleResponse {
+    fn from(fee_rule: FeeRule) -> Self {
+        Self {
+            id: fee_rule.id(),
+            method: fee_rule.method(),
+            percentage: fee_rule.percentage(),
+            fixed_amount: fee_rule.fixed_amount(),
+            fixed_currency: fee_rule.fixed_currency(),
+            effective_from: fee_rule.effective_from(),
+            created_at: fee_rule.created_at(),
+        }
+    }
+}
+
+impl Response for FeeRuleResponse {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FeeRulesResponse {
+    pub fee_rules: Vec<FeeRuleResponse>,
+}
+
+impl Response for FeeRulesResponse {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
@@ -0,0 +1,287 @@
+use std::fmt::Display;
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use shared::domain::{bus::event::Event, utils::is_uuid, value_objects::money::Money};
+use time::{format_description::well_known::Rfc3339, Date, OffsetDateTime};
+
+use crate::shared::domain::dona::DonaOptionMethod;
+
+use super::fee_rule_created_event::FeeRuleCreatedEvent;
+
+pub const ERR_FEE_RULE_NOT_FOUND: &str = "Fee rule not found";
+pub const ERR_FEE_RULE_ALREADY_EXISTS: &str =
+    "The method already has a fee rule taking effect that day";
+pub const ERR_FEE_RULE_RETROACTIVE: &str =
+    "A fee rule cannot take effect before the day it is created";
+
+pub const ERR_INVALID_FEE_RULE_ID: &str = "Invalid Fee Rule ID";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct FeeRuleId(String);
+
+impl FeeRuleId {
+    pub fn new(value: String) -> Result<Self, String> {
+        if is_uuid(&value) {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_FEE_RULE_ID.to_string())
+        }
+    }
+}
+
+impl Display for FeeRuleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub const ERR_INVALID_FEE_PERCENTAGE: &str = "Invalid Fee Percentage";
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct FeePercentage(Decimal);
+
+impl FeePercentage {
+    pub fn new(value: Decimal) -> Result<Self, String> {
+        if value >= Decimal::ZERO && value <= Decimal::ONE_HUNDRED {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_FEE_PERCENTAGE.to_string())
+        }
+    }
+}
+
+impl Display for FeePercentage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub const ERR_INVALID_FEE_FIXED_AMOUNT: &str = "Invalid Fee Fixed Amount";
+
+/// Charged on every dona on top of the percentage, in its own currency.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct FeeFixedAmount(Money);
+
+impl FeeFixedAmount {
+    pub fn new(value: Money) -> Result<Self, String> {
+        if value.amount() > Decimal::ZERO {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_FEE_FIXED_AMOUNT.to_string())
+        }
+    }
+}
+
+impl Display for FeeFixedAmount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The day from which the rule applies, until a rule with a later day replaces it.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FeeRuleEffectiveFrom(Date);
+
+impl Display for FeeRuleEffectiveFrom {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub const ERR_INVALID_FEE_RULE_CREATED_AT: &str = "Invalid Fee Rule Created At";
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct FeeRuleCreatedAt(OffsetDateTime);
+
+impl FeeRuleCreatedAt {
+    pub fn new(value: OffsetDateTime) -> Result<Self, String> {
+        if value < OffsetDateTime::now_utc() {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_FEE_RULE_CREATED_AT.to_string())
+        }
+    }
+}
+
+impl Display for FeeRuleCreatedAt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.format(&Rfc3339).unwrap())
+    }
+}
+
+/// What the platform keeps from the donas paid through a method, as a percentage plus an
+/// optional fixed part. Rules are never changed: a new rule with a later effective day
+/// replaces the previous one, so the fees of older donas can always be explained.
+#[derive(Debug, Clone)]
+pub struct FeeRule {
+    id: FeeRuleId,
+    method: DonaOptionMethod,
+    percentage: FeePercentage,
+    fixed_amount: Option<FeeFixedAmount>,
+    effective_from: FeeRuleEffectiveFrom,
+    created_at: FeeRuleCreatedAt,
+
+    events: Vec<Arc<dyn Event>>,
+}
+
+impl PartialEq for FeeRule {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.method == other.method
+            && self.percentage == other.percentage
+            && self.fixed_amount == other.fixed_amount
+            && self.effective_from == other.effective_from
+            && self.created_at == other.created_at
+    }
+}
+
+impl Eq for FeeRule {}
+
+impl FeeRule {
+    pub(crate) fn new(
+        id: String,
+        method: String,
+        percentage: Decimal,
+        fixed_amount: Option<Decimal>,
+        fixed_currency: Option<String>,
+        effective_from: Date,
+        created_at: OffsetDateTime,
+    ) -> Result<Self, String> {
+        let fixed_amount = match (fixed_amount, fixed_currency) {
+            (Some(amount), Some(currency)) => {
+                Some(FeeFixedAmount::new(Money::new(amount, currency)?)?)
+            }
+            (None, None) => None,
+            _ => return Err(ERR_INVALID_FEE_FIXED_AMOUNT.to_string()),
+        };
+
+        Ok(Self {
+            id: FeeRuleId::new(id)?,
+            method: DonaOptionMethod::new(method)?,
+            percentage: FeePercentage::new(percentage)?,
+            fixed_amount,
+            effective_from: FeeRuleEffectiveFrom(effective_from),
+            created_at: FeeRuleCreatedAt::new(created_at)?,
+            events: vec![],
+        })
+    }
+
+    pub fn create(
+        id: String,
+        method: String,
+        percentage: Decimal,
+        fixed_amount: Option<Decimal>,
+        fixed_currency: Option<String>,
+        effective_from: Date,
+        created_at: OffsetDateTime,
+    ) -> Result<Self, String> {
+        if effective_from < created_at.date() {
+            return Err(ERR_FEE_RULE_RETROACTIVE.to_string());
+        }
+
+        let mut fee_rule = Self::new(
+            id,
+            method,
+            percentage,
+            fixed_amount,
+            fixed_currency,
+            effective_from,
+            created_at,
+        )?;
+
+        fee_rule.record(Arc::new(FeeRuleCreatedEvent::new(
+            fee_rule.id(),
+            fee_rule.method(),
+            fee_rule.percentage.to_string(),
+            fee_rule
+                .fixed_amount
+                .as_ref()
+                .map(|fixed| fixed.to_string())
+                .unwrap_or_default(),
+            fee_rule.effective_from.to_string(),
+            fee_rule.created_at.to_string(),
+        )));
+
+        Ok(fee_rule)
+    }
+
+    /// The percentage part of the fee of `amount`, not rounded.
+    pub fn percentage_of(&self, amount: Decimal) -> Decimal {
+        amount * self.percentage.0 / Decimal::ONE_HUNDRED
+    }
+
+    pub fn record(&mut self, event: Arc<dyn Event>) {
+        self.events.push(event);
+    }
+
+    pub fn pull_events(&mut self) -> Vec<Arc<dyn Event>> {
+        let events = self.events.clone();
+        self.events = vec![];
+        events
+    }
+
+    pub fn id(&self) -> String {
+        self.id.to_string()
+    }
+
+    pub fn method(&self) -> String {
+        self.method.to_string()
+    }
+
+    pub fn percentage(&self) -> Decimal {
+        self.percentage.0
+    }
+
+    pub fn fixed(&self) -> Option<&Money> {
+        self.fixed_amount.as_ref().map(|fixed| &fixed.0)
+    }
+
+    pub fn fixed_amount(&self) -> Option<Decimal> {
+        self.fixed().map(Money::amount)
+    }
+
+    pub fn fixed_currency(&self) -> Option<String> {
+        self.fixed().map(|fixed| fixed.currency().to_string())
+    }
+
+    pub fn effective_from(&self) -> Date {
+        self.effective_from.0
+    }
+
+    pub fn created_at(&self) -> OffsetDateTime {
+        self.created_at.0
+    }
+}
+
+pub mod tests {
+    use shared::domain::utils::new_uuid;
+    use time::Duration;
+
+    use super::*;
+
+    pub struct FeeRuleMother;
+
+    impl FeeRuleMother {
+        /// A rule created a minute ago, in effect since `effective_from` or today.
+        pub fn create(
+            method: &str,
+            percentage: Decimal,
+            fixed: Option<(Decimal, &str)>,
+            effective_from: Option<Date>,
+        ) -> FeeRule {
+            let created_at = OffsetDateTime::now_utc() - Duration::minutes(1);
+
+            FeeRule::new(
+                new_uuid(),
+                method.to_string(),
+                percentage,
+                fixed.map(|(amount, _)| amount),
+                fixed.map(|(_, currency)| currency.to_string()),
+                effective_from.unwrap_or(created_at.date()),
+                created_at,
+            )
+            .unwrap()
+        }
+    }
+}
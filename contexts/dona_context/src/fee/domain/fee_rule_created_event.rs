@@ -0,0 +1,138 @@
+use shared::domain::bus::event::{BaseEvent, Event, EventDeserializeError, EventSerialized};
+
+pub const FEE_RULE_CREATED_EVENT_TYPE: &str = "dona.fee_rule_created";
+
+/// The fixed amount is empty when the rule only has a percentage.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct FeeRuleCreatedEvent {
+    id: String,
+    method: String,
+    percentage: String,
+    fixed_amount: String,
+    effective_from: String,
+    created_at: String,
+
+    base_event: BaseEvent,
+}
+
+impl FeeRuleCreatedEvent {
+    pub fn new(
+        id: String,
+        method: String,
+        percentage: String,
+        fixed_amount: String,
+        effective_from: String,
+        created_at: String,
+    ) -> Self {
+        Self {
+            id: id.clone(),
+            method,
+            percentage,
+            fixed_amount,
+            effective_from,
+            created_at,
+            base_event: BaseEvent::new(id),
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn method(&self) -> &str {
+        &self.method
+    }
+
+    pub fn percentage(&self) -> &str {
+        &self.percentage
+    }
+
+    pub fn fixed_amount(&self) -> &str {
+        &self.fixed_amount
+    }
+
+    pub fn effective_from(&self) -> &str {
+        &self.effective_from
+    }
+
+    pub fn created_at(&self) -> &str {
+        &self.created_at
+    }
+}
+
+impl Event for FeeRuleCreatedEvent {
+    fn event_type(&self) -> &'static str {
+        FEE_RULE_CREATED_EVENT_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn from_primitives(
+        &self,
+        primitives: EventSerialized,
+    ) -> Result<Box<dyn Event>, EventDeserializeError> {
+        let data = primitives.data();
+        let base_event = BaseEvent::from_primitives(
+            primitives.event_id().to_string(),
+            primitives.aggregate_id().to_string(),
+            primitives.occurred_at().to_string(),
+        );
+
+        let id = data
+            .get("id")
+            .ok_or(EventDeserializeError::MissingField("id".to_string()))?;
+        let method = data
+            .get("method")
+            .ok_or(EventDeserializeError::MissingField("method".to_string()))?;
+        let percentage = data
+            .get("percentage")
+            .ok_or(EventDeserializeError::MissingField(
+                "percentage".to_string(),
+            ))?;
+        let fixed_amount = data
+            .get("fixed_amount")
+            .ok_or(EventDeserializeError::MissingField(
+                "fixed_amount".to_string(),
+            ))?;
+        let effective_from =
+            data.get("effective_from")
+                .ok_or(EventDeserializeError::MissingField(
+                    "effective_from".to_string(),
+                ))?;
+        let created_at = data
+            .get("created_at")
+            .ok_or(EventDeserializeError::MissingField(
+                "created_at".to_string(),
+            ))?;
+
+        Ok(Box::new(Self {
+            id: id.to_string(),
+            method: method.to_string(),
+            percentage: percentage.to_string(),
+            fixed_amount: fixed_amount.to_string(),
+            effective_from: effective_from.to_string(),
+            created_at: created_at.to_string(),
+            base_event,
+        }))
+    }
+
+    fn to_primitives(&self) -> EventSerialized {
+        EventSerialized::new(
+            self.base_event.event_id().to_string(),
+            self.base_event.aggregate_id().to_string(),
+            self.base_event.occurred_at().to_string(),
+            vec![
+                ("id".to_string(), self.id.clone()),
+                ("method".to_string(), self.method.clone()),
+                ("percentage".to_string(), self.percentage.clone()),
+                ("fixed_amount".to_string(), self.fixed_amount.clone()),
+                ("effective_from".to_string(), self.effective_from.clone()),
+                ("created_at".to_string(), self.created_at.clone()),
+            ]
+            .into_iter()
+            .collect(),
+        )
+    }
+}
@@ -0,0 +1,40 @@
+use shared::domain::base_errors::BaseRepositoryError;
+use time::Date;
+
+use crate::shared::domain::dona::DonaOptionMethod;
+
+use super::fee_rule::FeeRule;
+
+#[async_trait::async_trait]
+pub trait FeeRuleRepository: Send + Sync {
+    /// The rule of the method with the latest effective day not after `date`.
+    async fn find_effective(
+        &self,
+        method: DonaOptionMethod,
+        date: Date,
+    ) -> Result<FeeRule, BaseRepositoryError>;
+    /// Every rule the method has had, newest first.
+    async fn find_by_method(
+        &self,
+        method: DonaOptionMethod,
+    ) -> Result<Vec<FeeRule>, BaseRepositoryError>;
+    async fn save(&self, fee_rule: &FeeRule) -> Result<(), BaseRepositoryError>;
+}
+
+#[cfg(test)]
+pub mod tests {
+    use mockall::mock;
+
+    use super::*;
+
+    mock! {
+        pub FeeRuleRepository {}
+
+        #[async_trait::async_trait]
+        impl FeeRuleRepository for FeeRuleRepository {
+            async fn find_effective(&self, method: DonaOptionMethod, date: Date) -> Result<FeeRule, BaseRepositoryError>;
+            async fn find_by_method(&self, method: DonaOptionMethod) -> Result<Vec<FeeRule>, BaseRepositoryError>;
+            async fn save(&self, fee_rule: &FeeRule) -> Result<(), BaseRepositoryError>;
+        }
+    }
+}
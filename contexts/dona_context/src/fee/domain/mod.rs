@@ -0,0 +1,3 @@
+pub mod fee_rule;
+pub mod fee_rule_created_event;
+pub mod fee_rule_repository;
@@ -0,0 +1 @@
+pub mod sea_fee_rule_repo;
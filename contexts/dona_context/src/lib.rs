@@ -1,6 +1,16 @@
+pub mod attachment;
+pub mod campaign;
 pub mod dona;
+pub mod exchange_rate;
+pub mod export;
+pub mod fee;
+pub mod notification;
+pub mod pledge;
 pub mod posts;
+pub mod receipt;
+pub mod recipient;
 pub(crate) mod shared;
+pub mod supporter;
 pub mod user_payment_method;
 
 #[cfg(test)]
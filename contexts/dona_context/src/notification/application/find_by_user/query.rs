@@ -0,0 +1,95 @@
+use shared::domain::bus::query::{Query, QueryError, QueryHandler, Response};
+
+use super::service::NotificationsFinder;
+
+pub const FIND_NOTIFICATIONS_QUERY_TYPE: &str = "dona.find_notifications.query";
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FindNotificationsQuery {
+    pub user_id: String,
+}
+
+impl Query for FindNotificationsQuery {
+    fn query_type(&self) -> &'static str {
+        FIND_NOTIFICATIONS_QUERY_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct FindNotificationsQueryHandler {
+    service: NotificationsFinder,
+}
+
+impl FindNotificationsQueryHandler {
+    pub fn new(service: NotificationsFinder) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler for FindNotificationsQueryHandler {
+    async fn handle(&self, query: Box<dyn Query>) -> Result<Box<dyn Response>, QueryError> {
+        let query = query
+            .as_any()
+            .downcast_ref::<FindNotificationsQuery>()
+            .ok_or_else(|| QueryError::new("Invalid query".to_string()))?;
+
+        let notifications = self
+            .service
+            .execute(query.user_id.to_owned())
+            .await
+            .map_err(QueryError::new)?;
+
+        Ok(Box::new(notifications))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockall::predicate;
+    use shared::domain::{utils::new_uuid, value_objects::user_id::UserId};
+
+    use super::*;
+
+    use crate::notification::application::find_by_user::service::NOTIFICATIONS_HISTORY_LIMIT;
+    use crate::notification::application::response::NotificationsResponse;
+    use crate::notification::domain::notification::tests::NotificationMother;
+    use crate::notification::domain::notification_repository::tests::MockNotificationRepository;
+
+    #[tokio::test]
+    async fn it_should_list_the_notifications_of_the_user() {
+        let user_id = new_uuid();
+        let notification = NotificationMother::create(Some(user_id.clone()), None);
+
+        let mut repository = MockNotificationRepository::new();
+        repository
+            .expect_find_by_user_id()
+            .with(
+                predicate::eq(UserId::new(user_id.clone()).unwrap()),
+                predicate::eq(NOTIFICATIONS_HISTORY_LIMIT),
+            )
+            .times(1)
+            .return_const(Ok(vec![notification.clone()]));
+
+        let response =
+            FindNotificationsQueryHandler::new(NotificationsFinder::new(Arc::new(repository)))
+                .handle(Box::new(FindNotificationsQuery { user_id }))
+                .await
+                .unwrap();
+        let response = response
+            .as_any()
+            .downcast_ref::<NotificationsResponse>()
+            .unwrap()
+            .to_owned();
+
+        assert_eq!(response.notifications.len(), 1);
+        assert_eq!(response.notifications[0].id, notification.id());
+        assert_eq!(response.notifications[0].link, notification.link());
+    }
+}
@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use shared::domain::value_objects::user_id::UserId;
+
+use crate::notification::{
+    application::response::{NotificationResponse, NotificationsResponse},
+    domain::notification_repository::NotificationRepository,
+};
+
+/// Older notifications are kept but no longer listed.
+pub const NOTIFICATIONS_HISTORY_LIMIT: u64 = 50;
+
+#[derive(Clone)]
+pub struct NotificationsFinder {
+    repository: Arc<dyn NotificationRepository>,
+}
+
+impl NotificationsFinder {
+    pub fn new(repository: Arc<dyn NotificationRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, user_id: String) -> Result<NotificationsResponse, String> {
+        let notifications = self
+            .repository
+            .find_by_user_id(UserId::new(user_id)?, NOTIFICATIONS_HISTORY_LIMIT)
+            .await?;
+
+        Ok(NotificationsResponse {
+            notifications: notifications
+                .into_iter()
+                .map(NotificationResponse::from)
+                .collect(),
+        })
+    }
+}
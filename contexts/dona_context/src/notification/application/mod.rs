@@ -0,0 +1,3 @@
+pub mod find_by_user;
+pub mod notify_on_dona_export_completed;
+pub mod response;
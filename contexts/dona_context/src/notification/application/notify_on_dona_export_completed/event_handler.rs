@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use shared::domain::bus::event::{Event, EventError, EventHandler};
+
+use crate::export::domain::dona_export_completed_event::{
+    DonaExportCompletedEvent, DONA_EXPORT_COMPLETED_EVENT_TYPE,
+};
+
+use super::service::DonaExportCompletedNotifier;
+
+#[derive(Clone)]
+pub struct NotifyOnDonaExportCompleted {
+    service: DonaExportCompletedNotifier,
+}
+
+impl NotifyOnDonaExportCompleted {
+    pub fn new(service: DonaExportCompletedNotifier) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl EventHandler for NotifyOnDonaExportCompleted {
+    async fn handle(&self, event: Arc<dyn Event>) -> Result<(), EventError> {
+        let event = event
+            .as_any()
+            .downcast_ref::<DonaExportCompletedEvent>()
+            .ok_or_else(|| EventError::new("Invalid event".to_string()))?;
+
+        self.service
+            .execute(
+                event.id().to_string(),
+                event.user_id().to_string(),
+                event.download_url().to_string(),
+                event.row_count().to_string(),
+                event.updated_at().to_string(),
+            )
+            .await
+            .map_err(EventError::new)
+    }
+
+    fn subscribed_to(&self) -> Vec<&'static str> {
+        vec![DONA_EXPORT_COMPLETED_EVENT_TYPE]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use shared::domain::{base_errors::BaseRepositoryError, utils::new_uuid};
+
+    use super::*;
+
+    use crate::export::domain::dona_export::DONA_EXPORT_DOWNLOAD_PATH;
+    use crate::notification::domain::notification_repository::tests::MockNotificationRepository;
+
+    fn event(export_id: &str, user_id: &str) -> Arc<dyn Event> {
+        Arc::new(DonaExportCompletedEvent::new(
+            export_id.to_string(),
+            user_id.to_string(),
+            format!("{}/{}", DONA_EXPORT_DOWNLOAD_PATH, export_id),
+            "3".to_string(),
+            "2024-04-09T10:00:00Z".to_string(),
+        ))
+    }
+
+    #[tokio::test]
+    async fn it_should_notify_the_owner_with_the_download_url() {
+        let (export_id, user_id) = (new_uuid(), new_uuid());
+
+        let mut repository = MockNotificationRepository::new();
+        let (expected_export_id, expected_user_id) = (export_id.clone(), user_id.clone());
+        repository
+            .expect_save()
+            .withf(move |notification| {
+                notification.user_id() == expected_user_id
+                    && notification.subject_id() == expected_export_id
+                    && notification.kind() == "dona_export_completed"
+                    && notification.link()
+                        == Some(format!(
+                            "{}/{}",
+                            DONA_EXPORT_DOWNLOAD_PATH, expected_export_id
+                        ))
+                    && notification.msg().contains("3 rows")
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let handler = NotifyOnDonaExportCompleted::new(DonaExportCompletedNotifier::new(Arc::new(
+            repository,
+        )));
+
+        let result = handler.handle(event(&export_id, &user_id)).await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_the_notification_cannot_be_saved() {
+        let mut repository = MockNotificationRepository::new();
+        repository
+            .expect_save()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::UnexpectedError(
+                "connection lost".to_string(),
+            )));
+
+        let handler = NotifyOnDonaExportCompleted::new(DonaExportCompletedNotifier::new(Arc::new(
+            repository,
+        )));
+
+        let result = handler.handle(event(&new_uuid(), &new_uuid())).await;
+
+        assert!(result.is_err(), "Result should be Err");
+    }
+}
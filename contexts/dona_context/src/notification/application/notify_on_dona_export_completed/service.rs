@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use shared::domain::utils::new_uuid;
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+use crate::notification::domain::{
+    notification::Notification, notification_repository::NotificationRepository,
+};
+
+#[derive(Clone)]
+pub struct DonaExportCompletedNotifier {
+    repository: Arc<dyn NotificationRepository>,
+}
+
+impl DonaExportCompletedNotifier {
+    pub fn new(repository: Arc<dyn NotificationRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Takes the event primitives, the notification is dated when the export was completed.
+    pub async fn execute(
+        &self,
+        export_id: String,
+        user_id: String,
+        download_url: String,
+        row_count: String,
+        completed_at: String,
+    ) -> Result<(), String> {
+        let row_count = row_count.parse::<u64>().map_err(|e| e.to_string())?;
+        let completed_at =
+            OffsetDateTime::parse(&completed_at, &Rfc3339).map_err(|e| e.to_string())?;
+
+        let notification = Notification::dona_export_completed(
+            new_uuid(),
+            user_id,
+            export_id,
+            download_url,
+            row_count,
+            completed_at,
+        )?;
+
+        self.repository.save(&notification).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use shared::domain::bus::query::Response;
+use time::OffsetDateTime;
+
+use crate::notification::domain::notification::Notification;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NotificationResponse {
+    pub id: String,
+    pub kind: String,
+    pub msg: String,
+    pub link: Option<String>,
+    pub created_at: OffsetDateTime,
+}
+
+impl From<Notification> for NotificationResponse {
+    fn from(notification: Notification) -> Self {
+        Self {
+            id: notification.id(),
+            kind: notification.kind(),
+            msg: notification.msg(),
+            link: notification.link(),
+            created_at: notification.created_at(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NotificationsResponse {
+    pub notifications: Vec<NotificationResponse>,
+}
+
+impl Response for NotificationsResponse {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
@@ -0,0 +1,257 @@
+use std::fmt::Display;
+
+use shared::domain::{utils::is_uuid, value_objects::user_id::UserId};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+pub const ERR_INVALID_NOTIFICATION_ID: &str = "Invalid Notification ID";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct NotificationId(String);
+
+impl NotificationId {
+    pub fn new(value: String) -> Result<Self, String> {
+        if is_uuid(&value) {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_NOTIFICATION_ID.to_string())
+        }
+    }
+}
+
+impl Display for NotificationId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub const ERR_INVALID_NOTIFICATION_KIND: &str = "Invalid Notification Kind";
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum NotificationKind {
+    DonaExportCompleted,
+}
+
+impl NotificationKind {
+    pub fn new(value: String) -> Result<Self, String> {
+        match value.as_str() {
+            "dona_export_completed" => Ok(Self::DonaExportCompleted),
+            _ => Err(ERR_INVALID_NOTIFICATION_KIND.to_string()),
+        }
+    }
+}
+
+impl Display for NotificationKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DonaExportCompleted => write!(f, "dona_export_completed"),
+        }
+    }
+}
+
+pub const ERR_INVALID_NOTIFICATION_SUBJECT_ID: &str = "Invalid Notification Subject ID";
+
+/// Id of what the notification is about, such as the export that was completed. A subject
+/// is notified once per kind, so delivering the same event again does not repeat it.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct NotificationSubjectId(String);
+
+impl NotificationSubjectId {
+    pub fn new(value: String) -> Result<Self, String> {
+        if is_uuid(&value) {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_NOTIFICATION_SUBJECT_ID.to_string())
+        }
+    }
+}
+
+impl Display for NotificationSubjectId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub const ERR_INVALID_NOTIFICATION_MSG: &str = "Invalid Notification Message";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct NotificationMsg(String);
+
+impl NotificationMsg {
+    pub fn new(value: String) -> Result<Self, String> {
+        if value.trim().is_empty() || value.chars().count() > 500 {
+            return Err(ERR_INVALID_NOTIFICATION_MSG.to_string());
+        }
+
+        Ok(Self(value))
+    }
+}
+
+impl Display for NotificationMsg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub const ERR_INVALID_NOTIFICATION_LINK: &str = "Invalid Notification Link";
+
+/// Path relative to the server, like the download urls it points to.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct NotificationLink(String);
+
+impl NotificationLink {
+    pub fn new(value: String) -> Result<Self, String> {
+        if !value.starts_with('/') || value.chars().any(char::is_whitespace) {
+            return Err(ERR_INVALID_NOTIFICATION_LINK.to_string());
+        }
+
+        Ok(Self(value))
+    }
+}
+
+impl Display for NotificationLink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub const ERR_INVALID_NOTIFICATION_CREATED_AT: &str = "Invalid Notification Created At";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct NotificationCreatedAt(OffsetDateTime);
+
+impl NotificationCreatedAt {
+    pub fn new(value: OffsetDateTime) -> Result<Self, String> {
+        if value < OffsetDateTime::now_utc() {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_NOTIFICATION_CREATED_AT.to_string())
+        }
+    }
+}
+
+impl Display for NotificationCreatedAt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.format(&Rfc3339).unwrap())
+    }
+}
+
+/// Something that happened to a user while they were away, listed in their notifications.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notification {
+    id: NotificationId,
+    user_id: UserId,
+    kind: NotificationKind,
+    subject_id: NotificationSubjectId,
+    msg: NotificationMsg,
+    link: Option<NotificationLink>,
+    created_at: NotificationCreatedAt,
+}
+
+impl Notification {
+    pub(crate) fn new(
+        id: String,
+        user_id: String,
+        kind: String,
+        subject_id: String,
+        msg: String,
+        link: Option<String>,
+        created_at: OffsetDateTime,
+    ) -> Result<Self, String> {
+        Ok(Self {
+            id: NotificationId::new(id)?,
+            user_id: UserId::new(user_id)?,
+            kind: NotificationKind::new(kind)?,
+            subject_id: NotificationSubjectId::new(subject_id)?,
+            msg: NotificationMsg::new(msg)?,
+            link: link.map(NotificationLink::new).transpose()?,
+            created_at: NotificationCreatedAt::new(created_at)?,
+        })
+    }
+
+    /// Tells the owner of an export that it can be downloaded from `download_url`.
+    pub fn dona_export_completed(
+        id: String,
+        user_id: String,
+        export_id: String,
+        download_url: String,
+        row_count: u64,
+        created_at: OffsetDateTime,
+    ) -> Result<Self, String> {
+        Self::new(
+            id,
+            user_id,
+            NotificationKind::DonaExportCompleted.to_string(),
+            export_id,
+            format!("Your dona export is ready, with {} rows", row_count),
+            Some(download_url),
+            created_at,
+        )
+    }
+
+    pub fn id(&self) -> String {
+        self.id.to_string()
+    }
+
+    pub fn user_id(&self) -> String {
+        self.user_id.to_string()
+    }
+
+    pub fn kind(&self) -> String {
+        self.kind.to_string()
+    }
+
+    pub fn subject_id(&self) -> String {
+        self.subject_id.to_string()
+    }
+
+    pub fn msg(&self) -> String {
+        self.msg.to_string()
+    }
+
+    pub fn link(&self) -> Option<String> {
+        self.link.as_ref().map(|link| link.to_string())
+    }
+
+    pub fn created_at(&self) -> OffsetDateTime {
+        self.created_at.0
+    }
+}
+
+pub mod tests {
+    use shared::domain::{utils::new_uuid, value_objects::user_id::tests::UserIdMother};
+    use time::Duration;
+
+    use crate::export::domain::dona_export::DONA_EXPORT_DOWNLOAD_PATH;
+
+    use super::*;
+
+    pub struct NotificationMother;
+
+    impl NotificationMother {
+        pub fn random() -> Notification {
+            Self::create(None, None)
+        }
+
+        /// A completed export notified a minute ago unless told otherwise.
+        pub fn create(user_id: Option<String>, subject_id: Option<String>) -> Notification {
+            let subject_id = subject_id.unwrap_or_else(new_uuid);
+
+            Notification {
+                id: NotificationId::new(new_uuid()).unwrap(),
+                user_id: UserIdMother::create(user_id),
+                kind: NotificationKind::DonaExportCompleted,
+                link: Some(
+                    NotificationLink::new(format!("{}/{}", DONA_EXPORT_DOWNLOAD_PATH, subject_id))
+                        .unwrap(),
+                ),
+                subject_id: NotificationSubjectId::new(subject_id).unwrap(),
+                msg: NotificationMsg::new("Your dona export is ready, with 3 rows".to_string())
+                    .unwrap(),
+                created_at: NotificationCreatedAt::new(
+                    OffsetDateTime::now_utc() - Duration::minutes(1),
+                )
+                .unwrap(),
+            }
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use shared::domain::{base_errors::BaseRepositoryError, value_objects::user_id::UserId};
+
+use super::notification::Notification;
+
+#[async_trait::async_trait]
+pub trait NotificationRepository: Send + Sync {
+    /// Newest first.
+    async fn find_by_user_id(
+        &self,
+        user_id: UserId,
+        limit: u64,
+    ) -> Result<Vec<Notification>, BaseRepositoryError>;
+    /// Does nothing when the subject was already notified with the same kind.
+    async fn save(&self, notification: &Notification) -> Result<(), BaseRepositoryError>;
+}
+
+#[cfg(test)]
+pub mod tests {
+    use mockall::mock;
+
+    use super::*;
+
+    mock! {
+        pub NotificationRepository {}
+
+        #[async_trait::async_trait]
+        impl NotificationRepository for NotificationRepository {
+            async fn find_by_user_id(&self, user_id: UserId, limit: u64) -> Result<Vec<Notification>, BaseRepositoryError>;
+            async fn save(&self, notification: &Notification) -> Result<(), BaseRepositoryError>;
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod sea_notification_repo;
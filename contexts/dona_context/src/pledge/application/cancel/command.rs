@@ -0,0 +1,151 @@
+use shared::domain::bus::command::{Command, CommandError, CommandHandler};
+use time::OffsetDateTime;
+
+use super::service::PledgeCanceller;
+
+pub const CANCEL_PLEDGE_COMMAND_TYPE: &str = "dona.cancel_pledge.command";
+
+#[derive(Debug)]
+pub struct CancelPledgeCommand {
+    pub id: String,
+    pub user_id: String,
+    pub updated_at: OffsetDateTime,
+}
+
+impl Command for CancelPledgeCommand {
+    fn command_type(&self) -> &'static str {
+        CANCEL_PLEDGE_COMMAND_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct CancelPledgeCommandHandler {
+    service: PledgeCanceller,
+}
+
+impl CancelPledgeCommandHandler {
+    pub fn new(service: PledgeCanceller) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler for CancelPledgeCommandHandler {
+    async fn handle(&self, command: Box<dyn Command>) -> Result<(), CommandError> {
+        let command = command
+            .as_any()
+            .downcast_ref::<CancelPledgeCommand>()
+            .ok_or_else(|| CommandError::new("Invalid command".to_string()))?;
+
+        self.service
+            .execute(
+                command.id.to_owned(),
+                command.user_id.to_owned(),
+                command.updated_at,
+            )
+            .await
+            .map_err(CommandError::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockall::predicate;
+    use shared::domain::bus::event::tests::MockEventBus;
+
+    use super::*;
+
+    use crate::pledge::domain::pledge::tests::PledgeMother;
+    use crate::pledge::domain::pledge::{Pledge, ERR_INVALID_PLEDGE_STATUS_TRANSITION};
+    use crate::pledge::domain::pledge_repository::tests::MockPledgeRepository;
+
+    fn pledge_with_status(status: &str) -> Pledge {
+        PledgeMother::create(
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(status.to_string()),
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_pledge_is_cancelled() {
+        let pledge = pledge_with_status("cancelled");
+        let mut repository = MockPledgeRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(pledge.clone()));
+        repository.expect_save().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let handler = CancelPledgeCommandHandler::new(PledgeCanceller::new(
+            Arc::new(repository),
+            Arc::new(event_bus),
+        ));
+
+        let result = handler
+            .handle(Box::new(CancelPledgeCommand {
+                id: pledge.id(),
+                user_id: pledge.sender_id(),
+                updated_at: OffsetDateTime::now_utc(),
+            }))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(
+                ERR_INVALID_PLEDGE_STATUS_TRANSITION.to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_cancel_pledge() {
+        let pledge = pledge_with_status("paused");
+        let mut repository = MockPledgeRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(pledge.clone()));
+        repository
+            .expect_save()
+            .withf(|pledge| pledge.status() == "cancelled")
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus
+            .expect_publish()
+            .with(predicate::function(|events: &Vec<_>| events.len() == 1))
+            .times(1)
+            .return_const(Ok(()));
+
+        let handler = CancelPledgeCommandHandler::new(PledgeCanceller::new(
+            Arc::new(repository),
+            Arc::new(event_bus),
+        ));
+
+        let result = handler
+            .handle(Box::new(CancelPledgeCommand {
+                id: pledge.id(),
+                user_id: pledge.user_id(),
+                updated_at: OffsetDateTime::now_utc(),
+            }))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+}
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use shared::domain::{base_errors::BaseRepositoryError, bus::event::EventBus};
+use time::OffsetDateTime;
+
+use crate::pledge::domain::{
+    pledge::{Pledge, PledgeId, ERR_PLEDGE_NOT_FOUND},
+    pledge_repository::PledgeRepository,
+};
+
+#[derive(Clone)]
+pub struct PledgeCanceller {
+    repository: Arc<dyn PledgeRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl PledgeCanceller {
+    pub fn new(repository: Arc<dyn PledgeRepository>, event_bus: Arc<dyn EventBus>) -> Self {
+        Self {
+            repository,
+            event_bus,
+        }
+    }
+
+    /// Either side of the pledge can cancel it.
+    async fn pledge_finder(&self, id: String, user_id: String) -> Result<Pledge, String> {
+        let pledge = self
+            .repository
+            .find_by_id(PledgeId::new(id)?)
+            .await
+            .map_err(|e| match e {
+                BaseRepositoryError::NotFound => ERR_PLEDGE_NOT_FOUND.to_string(),
+                _ => e.to_string(),
+            })?;
+
+        if pledge.sender_id() != user_id && pledge.user_id() != user_id {
+            return Err(ERR_PLEDGE_NOT_FOUND.to_string());
+        }
+
+        Ok(pledge)
+    }
+
+    pub async fn execute(
+        &self,
+        id: String,
+        user_id: String,
+        updated_at: OffsetDateTime,
+    ) -> Result<(), String> {
+        let mut pledge = self.pledge_finder(id, user_id).await?;
+        pledge.cancel(updated_at)?;
+
+        self.repository.save(&pledge).await?;
+
+        self.event_bus.publish(pledge.pull_events()).await?;
+
+        Ok(())
+    }
+}
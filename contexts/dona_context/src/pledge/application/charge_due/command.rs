@@ -0,0 +1,470 @@
+use shared::domain::bus::command::{Command, CommandError, CommandHandler};
+use time::OffsetDateTime;
+
+use super::service::DuePledgesCharger;
+
+pub const CHARGE_DUE_PLEDGES_COMMAND_TYPE: &str = "dona.charge_due_pledges.command";
+
+#[derive(Debug)]
+pub struct ChargeDuePledgesCommand {
+    pub now: OffsetDateTime,
+}
+
+impl Command for ChargeDuePledgesCommand {
+    fn command_type(&self) -> &'static str {
+        CHARGE_DUE_PLEDGES_COMMAND_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct ChargeDuePledgesCommandHandler {
+    service: DuePledgesCharger,
+}
+
+impl ChargeDuePledgesCommandHandler {
+    pub fn new(service: DuePledgesCharger) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler for ChargeDuePledgesCommandHandler {
+    async fn handle(&self, command: Box<dyn Command>) -> Result<(), CommandError> {
+        let command = command
+            .as_any()
+            .downcast_ref::<ChargeDuePledgesCommand>()
+            .ok_or_else(|| CommandError::new("Invalid command".to_string()))?;
+
+        self.service
+            .execute(command.now)
+            .await
+            .map_err(CommandError::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use rust_decimal_macros::dec;
+    use shared::domain::{
+        base_errors::BaseRepositoryError, bus::event::tests::MockEventBus,
+        criteria::page::CursorPage, utils::new_uuid, value_objects::user_id::tests::UserIdMother,
+    };
+    use time::Duration;
+
+    use super::*;
+
+    use crate::dona::domain::dona::REDACTED_DONA_MSG;
+    use crate::dona::domain::dona_created_event::DonaCreatedEvent;
+    use crate::dona::domain::dona_msg_policy::{DonaMsgLinkPolicy, DonaMsgPolicy};
+    use crate::dona::domain::dona_repository::tests::MockDonaRepository;
+    use crate::exchange_rate::application::convert::service::CurrencyConverter;
+    use crate::exchange_rate::domain::exchange_rate_repository::tests::MockExchangeRateRepository;
+    use crate::pledge::domain::pledge::tests::PledgeMother;
+    use crate::pledge::domain::pledge::Pledge;
+    use crate::pledge::domain::pledge_repository::tests::MockPledgeRepository;
+    use crate::recipient::domain::recipient_settings::tests::RecipientSettingsMother;
+    use crate::recipient::domain::recipient_settings::{
+        RecipientSettings, ERR_DONA_AMOUNT_ABOVE_RECIPIENT_MAXIMUM,
+    };
+    use crate::recipient::domain::recipient_settings_repository::tests::MockRecipientSettingsRepository;
+
+    fn command() -> ChargeDuePledgesCommand {
+        ChargeDuePledgesCommand {
+            now: OffsetDateTime::now_utc() - Duration::seconds(1),
+        }
+    }
+
+    fn no_recipient_settings() -> MockRecipientSettingsRepository {
+        let mut repository = MockRecipientSettingsRepository::new();
+        repository
+            .expect_find_by_user_id()
+            .return_const(Err(BaseRepositoryError::NotFound));
+        repository
+    }
+
+    fn converter() -> CurrencyConverter {
+        CurrencyConverter::new(Arc::new(MockExchangeRateRepository::new()))
+    }
+
+    fn settings_repository_with(settings: RecipientSettings) -> MockRecipientSettingsRepository {
+        let mut repository = MockRecipientSettingsRepository::new();
+        repository
+            .expect_find_by_user_id()
+            .times(1)
+            .return_const(Ok(settings));
+        repository
+    }
+
+    fn monthly_pledge() -> Pledge {
+        PledgeMother::create(
+            None,
+            Some(dec!(10.00)),
+            Some("USD".to_string()),
+            Some("monthly".to_string()),
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_repository_fails() {
+        let mut pledge_repository = MockPledgeRepository::new();
+        pledge_repository
+            .expect_find_page_by_criteria()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::UnexpectedError(
+                "error".to_string(),
+            )));
+        pledge_repository.expect_save().times(0);
+
+        let mut dona_repository = MockDonaRepository::new();
+        dona_repository.expect_save().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let handler = ChargeDuePledgesCommandHandler::new(DuePledgesCharger::new(
+            Arc::new(pledge_repository),
+            Arc::new(dona_repository),
+            Arc::new(no_recipient_settings()),
+            converter(),
+            DonaMsgPolicy::default(),
+            Arc::new(event_bus),
+        ));
+
+        let result = handler.handle(Box::new(command())).await;
+
+        assert!(result.is_err(), "Result should be an error");
+    }
+
+    #[tokio::test]
+    async fn it_should_create_a_pending_dona_linked_to_each_due_pledge() {
+        let pledge = monthly_pledge();
+        let pledge_id = pledge.id();
+        let sender_id = pledge.sender_id();
+
+        let mut pledge_repository = MockPledgeRepository::new();
+        pledge_repository
+            .expect_find_page_by_criteria()
+            .withf(|criteria| criteria.filters().len() == 2)
+            .times(1)
+            .return_const(Ok(CursorPage::new(vec![pledge], false, false)));
+        pledge_repository
+            .expect_save()
+            .withf(|pledge| pledge.next_due_at() > OffsetDateTime::now_utc())
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut dona_repository = MockDonaRepository::new();
+        dona_repository
+            .expect_save()
+            .withf(move |dona| {
+                dona.status() == "pending"
+                    && dona.pledge_id() == Some(pledge_id.clone())
+                    && dona.sender_id() == Some(sender_id.clone())
+                    && dona.amount() == dec!(10.00)
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus
+            .expect_publish()
+            .withf(|events| events.len() == 1)
+            .times(2)
+            .return_const(Ok(()));
+
+        let handler = ChargeDuePledgesCommandHandler::new(DuePledgesCharger::new(
+            Arc::new(pledge_repository),
+            Arc::new(dona_repository),
+            Arc::new(no_recipient_settings()),
+            converter(),
+            DonaMsgPolicy::default(),
+            Arc::new(event_bus),
+        ));
+
+        let result = handler.handle(Box::new(command())).await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_create_one_dona_per_missed_period() {
+        let now = OffsetDateTime::now_utc();
+        let pledge = Pledge::new(
+            new_uuid(),
+            "Weekly coffee".to_string(),
+            dec!(5.00),
+            "USD".to_string(),
+            "weekly".to_string(),
+            "PAYPAL".to_string(),
+            UserIdMother::random().to_string(),
+            UserIdMother::random().to_string(),
+            "active".to_string(),
+            now - Duration::days(15),
+            now - Duration::days(30),
+            now - Duration::days(30),
+        )
+        .unwrap();
+
+        let mut pledge_repository = MockPledgeRepository::new();
+        pledge_repository
+            .expect_find_page_by_criteria()
+            .times(1)
+            .return_const(Ok(CursorPage::new(vec![pledge], false, false)));
+        pledge_repository
+            .expect_save()
+            .times(3)
+            .return_const(Ok(()));
+
+        let mut dona_repository = MockDonaRepository::new();
+        dona_repository.expect_save().times(3).return_const(Ok(()));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(6).return_const(Ok(()));
+
+        let handler = ChargeDuePledgesCommandHandler::new(DuePledgesCharger::new(
+            Arc::new(pledge_repository),
+            Arc::new(dona_repository),
+            Arc::new(no_recipient_settings()),
+            converter(),
+            DonaMsgPolicy::default(),
+            Arc::new(event_bus),
+        ));
+
+        let result = handler.handle(Box::new(command())).await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_keep_charging_other_pledges_when_one_fails() {
+        let monthly = || {
+            PledgeMother::create(
+                None,
+                None,
+                None,
+                Some("monthly".to_string()),
+                None,
+                None,
+                None,
+                None,
+            )
+        };
+        let failing = monthly();
+        let failing_id = failing.id();
+        let charged = monthly();
+        let charged_id = charged.id();
+
+        let mut pledge_repository = MockPledgeRepository::new();
+        pledge_repository
+            .expect_find_page_by_criteria()
+            .times(1)
+            .return_const(Ok(CursorPage::new(vec![failing, charged], false, false)));
+        pledge_repository
+            .expect_save()
+            .withf(move |pledge| pledge.id() == charged_id)
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut dona_repository = MockDonaRepository::new();
+        let failing_dona_pledge_id = failing_id.clone();
+        dona_repository
+            .expect_save()
+            .withf(move |dona| dona.pledge_id() == Some(failing_dona_pledge_id.clone()))
+            .times(1)
+            .return_const(Err(BaseRepositoryError::UnexpectedError(
+                "error".to_string(),
+            )));
+        dona_repository.expect_save().times(1).return_const(Ok(()));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(2).return_const(Ok(()));
+
+        let handler = ChargeDuePledgesCommandHandler::new(DuePledgesCharger::new(
+            Arc::new(pledge_repository),
+            Arc::new(dona_repository),
+            Arc::new(no_recipient_settings()),
+            converter(),
+            DonaMsgPolicy::default(),
+            Arc::new(event_bus),
+        ));
+
+        let result = handler.handle(Box::new(command())).await;
+
+        assert!(
+            result.is_err_and(|e| e.to_string().contains(&failing_id)),
+            "Result should report the failing pledge"
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_not_charge_pledges_the_recipient_settings_no_longer_accept() {
+        let pledge = monthly_pledge();
+        let pledge_id = pledge.id();
+        let settings = RecipientSettingsMother::create(
+            Some(pledge.user_id()),
+            None,
+            Some(dec!(5.00)),
+            Some("USD"),
+            vec!["MANUAL", "PAYPAL", "CRYPTO"],
+            true,
+        );
+
+        let mut pledge_repository = MockPledgeRepository::new();
+        pledge_repository
+            .expect_find_page_by_criteria()
+            .times(1)
+            .return_const(Ok(CursorPage::new(vec![pledge], false, false)));
+        pledge_repository.expect_save().times(0);
+
+        let mut dona_repository = MockDonaRepository::new();
+        dona_repository.expect_save().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let handler = ChargeDuePledgesCommandHandler::new(DuePledgesCharger::new(
+            Arc::new(pledge_repository),
+            Arc::new(dona_repository),
+            Arc::new(settings_repository_with(settings)),
+            converter(),
+            DonaMsgPolicy::default(),
+            Arc::new(event_bus),
+        ));
+
+        let result = handler.handle(Box::new(command())).await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(format!(
+                "Pledge {}: {}",
+                pledge_id, ERR_DONA_AMOUNT_ABOVE_RECIPIENT_MAXIMUM
+            )))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_withhold_msg_when_recipient_does_not_take_messages() {
+        let pledge = monthly_pledge();
+        let settings = RecipientSettingsMother::create(
+            Some(pledge.user_id()),
+            None,
+            None,
+            None,
+            vec!["MANUAL", "PAYPAL", "CRYPTO"],
+            false,
+        );
+
+        let mut pledge_repository = MockPledgeRepository::new();
+        pledge_repository
+            .expect_find_page_by_criteria()
+            .times(1)
+            .return_const(Ok(CursorPage::new(vec![pledge], false, false)));
+        pledge_repository
+            .expect_save()
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut dona_repository = MockDonaRepository::new();
+        dona_repository
+            .expect_save()
+            .withf(|dona| dona.msg() == REDACTED_DONA_MSG && dona.msg_status() == "redacted")
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus
+            .expect_publish()
+            .withf(|events| {
+                events.iter().all(|event| {
+                    event
+                        .as_any()
+                        .downcast_ref::<DonaCreatedEvent>()
+                        .is_none_or(|event| {
+                            event.msg() == REDACTED_DONA_MSG && event.msg_status() == "redacted"
+                        })
+                })
+            })
+            .times(2)
+            .return_const(Ok(()));
+
+        let handler = ChargeDuePledgesCommandHandler::new(DuePledgesCharger::new(
+            Arc::new(pledge_repository),
+            Arc::new(dona_repository),
+            Arc::new(settings_repository_with(settings)),
+            converter(),
+            DonaMsgPolicy::default(),
+            Arc::new(event_bus),
+        ));
+
+        let result = handler.handle(Box::new(command())).await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_send_msgs_flagged_by_the_policy_to_review() {
+        let now = OffsetDateTime::now_utc();
+        let pledge = Pledge::new(
+            new_uuid(),
+            "See you at https://example.com".to_string(),
+            dec!(5.00),
+            "USD".to_string(),
+            "monthly".to_string(),
+            "PAYPAL".to_string(),
+            UserIdMother::random().to_string(),
+            UserIdMother::random().to_string(),
+            "active".to_string(),
+            now - Duration::days(1),
+            now - Duration::days(1),
+            now - Duration::days(1),
+        )
+        .unwrap();
+
+        let mut pledge_repository = MockPledgeRepository::new();
+        pledge_repository
+            .expect_find_page_by_criteria()
+            .times(1)
+            .return_const(Ok(CursorPage::new(vec![pledge], false, false)));
+        pledge_repository
+            .expect_save()
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut dona_repository = MockDonaRepository::new();
+        dona_repository
+            .expect_save()
+            .withf(|dona| {
+                dona.msg() == "See you at https://example.com"
+                    && dona.msg_status() == "in_review"
+                    && dona.msg_flags() == vec!["link".to_string()]
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(2).return_const(Ok(()));
+
+        let handler = ChargeDuePledgesCommandHandler::new(DuePledgesCharger::new(
+            Arc::new(pledge_repository),
+            Arc::new(dona_repository),
+            Arc::new(no_recipient_settings()),
+            converter(),
+            DonaMsgPolicy::new(500, vec![], DonaMsgLinkPolicy::Review),
+            Arc::new(event_bus),
+        ));
+
+        let result = handler.handle(Box::new(command())).await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+}
@@ -0,0 +1,187 @@
+use std::sync::Arc;
+
+use shared::domain::{
+    bus::event::EventBus,
+    criteria::{
+        cursor::{AfterCursor, Cursor, FirstField},
+        filter::{Filter, FilterField, FilterOperator, FilterValue},
+        Criteria,
+    },
+    utils::new_uuid,
+};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+use crate::{
+    dona::{
+        application::create::service::{recipient_accepts_dona, recipient_settings},
+        domain::{
+            dona::{Dona, DonaMsgModeration, DonaStatus, NewDona},
+            dona_msg_policy::DonaMsgPolicy,
+            dona_repository::DonaRepository,
+        },
+    },
+    exchange_rate::application::convert::service::CurrencyConverter,
+    pledge::domain::{
+        pledge::{Pledge, PledgeStatus},
+        pledge_repository::PledgeRepository,
+    },
+    recipient::domain::recipient_settings_repository::RecipientSettingsRepository,
+};
+
+pub const DUE_PLEDGES_BATCH_SIZE: usize = 100;
+
+#[derive(Clone)]
+pub struct DuePledgesCharger {
+    pledge_repository: Arc<dyn PledgeRepository>,
+    dona_repository: Arc<dyn DonaRepository>,
+    recipient_settings_repository: Arc<dyn RecipientSettingsRepository>,
+    converter: CurrencyConverter,
+    msg_policy: DonaMsgPolicy,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl DuePledgesCharger {
+    pub fn new(
+        pledge_repository: Arc<dyn PledgeRepository>,
+        dona_repository: Arc<dyn DonaRepository>,
+        recipient_settings_repository: Arc<dyn RecipientSettingsRepository>,
+        converter: CurrencyConverter,
+        msg_policy: DonaMsgPolicy,
+        event_bus: Arc<dyn EventBus>,
+    ) -> Self {
+        Self {
+            pledge_repository,
+            dona_repository,
+            recipient_settings_repository,
+            converter,
+            msg_policy,
+            event_bus,
+        }
+    }
+
+    fn due_criteria(now: OffsetDateTime, after: Option<AfterCursor>) -> Result<Criteria, String> {
+        Ok(Criteria::new(
+            vec![
+                Filter::new(
+                    FilterField::try_from("status".to_string())?,
+                    FilterOperator::Equal,
+                    FilterValue::try_from(PledgeStatus::Active.to_string())?,
+                ),
+                Filter::new(
+                    FilterField::try_from("next_due_at".to_string())?,
+                    FilterOperator::LessThanOrEqual,
+                    FilterValue::try_from(now.format(&Rfc3339).map_err(|e| e.to_string())?)?,
+                ),
+            ],
+            None,
+            Some(Cursor::new(
+                after,
+                None,
+                Some(FirstField::new(DUE_PLEDGES_BATCH_SIZE)?),
+                None,
+            )),
+        ))
+    }
+
+    /// Generates one pending dona for every period the pledge has fallen due, so a pledge
+    /// that was missed by earlier runs catches up instead of skipping periods. The donas go
+    /// through the current settings of the recipient and the message policy, as if the donor
+    /// made them at `now`.
+    async fn charge(&self, pledge: &mut Pledge, now: OffsetDateTime) -> Result<(), String> {
+        let settings = recipient_settings(
+            self.recipient_settings_repository.as_ref(),
+            &pledge.user_id(),
+        )
+        .await?;
+        if let Some(settings) = &settings {
+            recipient_accepts_dona(
+                &self.converter,
+                settings,
+                &pledge.method(),
+                pledge.amount(),
+                &pledge.currency(),
+                now,
+            )
+            .await?;
+        }
+        let messages_allowed = settings
+            .as_ref()
+            .is_none_or(|settings| settings.messages_allowed());
+        let (msg, msg_moderation) = if messages_allowed {
+            let (msg, flags) = self.msg_policy.moderate(&pledge.msg())?;
+            (msg, DonaMsgModeration::Checked(flags))
+        } else {
+            (pledge.msg(), DonaMsgModeration::Withheld)
+        };
+
+        while pledge.is_due(now) {
+            let mut dona = Dona::create(
+                NewDona {
+                    id: new_uuid(),
+                    msg: msg.clone(),
+                    amount: pledge.amount(),
+                    currency: pledge.currency(),
+                    status: DonaStatus::Pending.to_string(),
+                    method: pledge.method(),
+                    user_id: pledge.user_id(),
+                    sender_id: Some(pledge.sender_id()),
+                    guest_name: None,
+                    guest_email: None,
+                    is_anonymous: false,
+                    campaign_id: None,
+                    pledge_id: Some(pledge.id()),
+                    tx_hash: None,
+                    created_at: now,
+                    updated_at: now,
+                },
+                msg_moderation.clone(),
+            )?;
+            pledge.charge(dona.id(), now)?;
+
+            self.dona_repository.save(&dona).await?;
+            self.pledge_repository.save(pledge).await?;
+
+            self.event_bus.publish(dona.pull_events()).await?;
+            self.event_bus.publish(pledge.pull_events()).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Charges every active pledge due at `now`. A pledge that cannot be charged does not stop
+    /// the others: it stays due for the next run, and the failures are reported once every due
+    /// pledge was tried.
+    pub async fn execute(&self, now: OffsetDateTime) -> Result<(), String> {
+        let mut errors = vec![];
+        let mut after = None;
+
+        loop {
+            let page = self
+                .pledge_repository
+                .find_page_by_criteria(Self::due_criteria(now, after)?)
+                .await?;
+
+            for pledge in page.items() {
+                let mut pledge = pledge.clone();
+                if let Err(e) = self.charge(&mut pledge, now).await {
+                    errors.push(format!("Pledge {}: {}", pledge.id(), e));
+                }
+            }
+
+            if !page.has_next_page() {
+                break;
+            }
+            after = page
+                .items()
+                .last()
+                .map(|pledge| AfterCursor::with_id(pledge.created_at(), pledge.id()))
+                .transpose()?;
+        }
+
+        if !errors.is_empty() {
+            return Err(errors.join("; "));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,440 @@
+use rust_decimal::Decimal;
+use shared::domain::bus::command::{Command, CommandError, CommandHandler};
+use time::OffsetDateTime;
+
+use super::service::PledgeCreator;
+
+pub const CREATE_PLEDGE_COMMAND_TYPE: &str = "dona.create_pledge.command";
+
+#[derive(Debug)]
+pub struct CreatePledgeCommand {
+    pub id: String,
+    pub msg: String,
+    pub amount: Decimal,
+    pub currency: String,
+    pub interval: String,
+    pub method: String,
+    pub user_id: String,
+    pub sender_id: String,
+    pub next_due_at: OffsetDateTime,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl Command for CreatePledgeCommand {
+    fn command_type(&self) -> &'static str {
+        CREATE_PLEDGE_COMMAND_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct CreatePledgeCommandHandler {
+    service: PledgeCreator,
+}
+
+impl CreatePledgeCommandHandler {
+    pub fn new(service: PledgeCreator) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler for CreatePledgeCommandHandler {
+    async fn handle(&self, command: Box<dyn Command>) -> Result<(), CommandError> {
+        let command = command
+            .as_any()
+            .downcast_ref::<CreatePledgeCommand>()
+            .ok_or_else(|| CommandError::new("Invalid command".to_string()))?;
+
+        self.service
+            .execute(
+                command.id.to_owned(),
+                command.msg.to_owned(),
+                command.amount,
+                command.currency.to_owned(),
+                command.interval.to_owned(),
+                command.method.to_owned(),
+                command.user_id.to_owned(),
+                command.sender_id.to_owned(),
+                command.next_due_at,
+                command.created_at,
+                command.updated_at,
+            )
+            .await
+            .map_err(CommandError::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockall::predicate;
+    use rust_decimal_macros::dec;
+    use shared::domain::base_errors::BaseRepositoryError;
+    use shared::domain::bus::event::tests::MockEventBus;
+
+    use super::*;
+
+    use crate::dona::application::create::service::ERR_RECIPIENT_PAYMENT_METHOD_NOT_FOUND;
+    use crate::dona::domain::dona::REDACTED_DONA_MSG;
+    use crate::dona::domain::dona_msg_policy::{
+        DonaMsgLinkPolicy, DonaMsgPolicy, ERR_DONA_MSG_LINKS_NOT_ALLOWED,
+    };
+    use crate::exchange_rate::application::convert::service::CurrencyConverter;
+    use crate::exchange_rate::domain::exchange_rate_repository::tests::MockExchangeRateRepository;
+    use crate::pledge::application::create::service::ERR_PLEDGE_ALREADY_EXISTS;
+    use crate::pledge::domain::pledge::tests::PledgeMother;
+    use crate::pledge::domain::pledge::{Pledge, PledgeId, ERR_INVALID_PLEDGE_INTERVAL};
+    use crate::pledge::domain::pledge_repository::tests::MockPledgeRepository;
+    use crate::recipient::domain::recipient_settings::tests::RecipientSettingsMother;
+    use crate::recipient::domain::recipient_settings::{
+        RecipientSettings, ERR_DONA_AMOUNT_BELOW_RECIPIENT_MINIMUM, ERR_RECIPIENT_METHOD_NOT_ACTIVE,
+    };
+    use crate::recipient::domain::recipient_settings_repository::tests::MockRecipientSettingsRepository;
+    use crate::user_payment_method::domain::user_payment_method::tests::UserPaymentMethodMother;
+    use crate::user_payment_method::domain::user_payment_method_repository::tests::MockUserPaymentMethodRepository;
+
+    fn monthly_pledge() -> Pledge {
+        PledgeMother::create(
+            None,
+            Some(dec!(10.00)),
+            Some("USD".to_string()),
+            Some("monthly".to_string()),
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn command_from(pledge: &Pledge) -> CreatePledgeCommand {
+        CreatePledgeCommand {
+            id: pledge.id(),
+            msg: pledge.msg(),
+            amount: pledge.amount(),
+            currency: pledge.currency(),
+            interval: pledge.interval(),
+            method: pledge.method(),
+            user_id: pledge.user_id(),
+            sender_id: pledge.sender_id(),
+            next_due_at: pledge.next_due_at(),
+            created_at: pledge.created_at(),
+            updated_at: pledge.updated_at(),
+        }
+    }
+
+    fn method_repository_for(pledge: &Pledge) -> MockUserPaymentMethodRepository {
+        let method = UserPaymentMethodMother::create(
+            None,
+            Some(pledge.user_id()),
+            Some(pledge.method()),
+            None,
+            Some(vec!["USD".to_string()]),
+            None,
+            None,
+        );
+
+        let mut method_repository = MockUserPaymentMethodRepository::new();
+        method_repository
+            .expect_find_by_criteria()
+            .times(1)
+            .return_const(Ok(vec![method]));
+        method_repository
+    }
+
+    fn no_recipient_settings() -> MockRecipientSettingsRepository {
+        let mut repository = MockRecipientSettingsRepository::new();
+        repository
+            .expect_find_by_user_id()
+            .return_const(Err(BaseRepositoryError::NotFound));
+        repository
+    }
+
+    fn converter() -> CurrencyConverter {
+        CurrencyConverter::new(Arc::new(MockExchangeRateRepository::new()))
+    }
+
+    fn settings_repository_with(settings: RecipientSettings) -> MockRecipientSettingsRepository {
+        let mut repository = MockRecipientSettingsRepository::new();
+        repository
+            .expect_find_by_user_id()
+            .times(1)
+            .return_const(Ok(settings));
+        repository
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_pledge_exists() {
+        let pledge = monthly_pledge();
+        let mut repository = MockPledgeRepository::new();
+        repository
+            .expect_find_by_id()
+            .with(predicate::eq(PledgeId::new(pledge.id()).unwrap()))
+            .times(1)
+            .return_const(Ok(pledge.clone()));
+        repository.expect_save().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let handler = CreatePledgeCommandHandler::new(PledgeCreator::new(
+            Arc::new(repository),
+            Arc::new(MockUserPaymentMethodRepository::new()),
+            Arc::new(no_recipient_settings()),
+            converter(),
+            DonaMsgPolicy::default(),
+            Arc::new(event_bus),
+        ));
+
+        let result = handler.handle(Box::new(command_from(&pledge))).await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_PLEDGE_ALREADY_EXISTS.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_recipient_has_no_payment_method() {
+        let pledge = monthly_pledge();
+        let mut repository = MockPledgeRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        repository.expect_save().times(0);
+
+        let mut method_repository = MockUserPaymentMethodRepository::new();
+        method_repository
+            .expect_find_by_criteria()
+            .times(1)
+            .return_const(Ok(vec![]));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let handler = CreatePledgeCommandHandler::new(PledgeCreator::new(
+            Arc::new(repository),
+            Arc::new(method_repository),
+            Arc::new(no_recipient_settings()),
+            converter(),
+            DonaMsgPolicy::default(),
+            Arc::new(event_bus),
+        ));
+
+        let result = handler.handle(Box::new(command_from(&pledge))).await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(
+                ERR_RECIPIENT_PAYMENT_METHOD_NOT_FOUND.to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_interval_is_invalid() {
+        let pledge = monthly_pledge();
+        let mut repository = MockPledgeRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        repository.expect_save().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let handler = CreatePledgeCommandHandler::new(PledgeCreator::new(
+            Arc::new(repository),
+            Arc::new(method_repository_for(&pledge)),
+            Arc::new(no_recipient_settings()),
+            converter(),
+            DonaMsgPolicy::default(),
+            Arc::new(event_bus),
+        ));
+
+        let command = CreatePledgeCommand {
+            interval: "daily".to_string(),
+            ..command_from(&pledge)
+        };
+        let result = handler.handle(Box::new(command)).await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_INVALID_PLEDGE_INTERVAL.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_create_pledge() {
+        let pledge = monthly_pledge();
+        let mut repository = MockPledgeRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        repository
+            .expect_save()
+            .with(predicate::eq(pledge.clone()))
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus
+            .expect_publish()
+            .with(predicate::function(|events: &Vec<_>| events.len() == 1))
+            .times(1)
+            .return_const(Ok(()));
+
+        let handler = CreatePledgeCommandHandler::new(PledgeCreator::new(
+            Arc::new(repository),
+            Arc::new(method_repository_for(&pledge)),
+            Arc::new(no_recipient_settings()),
+            converter(),
+            DonaMsgPolicy::default(),
+            Arc::new(event_bus),
+        ));
+
+        let result = handler.handle(Box::new(command_from(&pledge))).await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_msg_policy_refuses_the_msg() {
+        let pledge = monthly_pledge();
+        let mut repository = MockPledgeRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        repository.expect_save().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let handler = CreatePledgeCommandHandler::new(PledgeCreator::new(
+            Arc::new(repository),
+            Arc::new(MockUserPaymentMethodRepository::new()),
+            Arc::new(no_recipient_settings()),
+            converter(),
+            DonaMsgPolicy::new(500, vec![], DonaMsgLinkPolicy::Reject),
+            Arc::new(event_bus),
+        ));
+
+        let command = CreatePledgeCommand {
+            msg: "Monthly gifts at evil\u{200B}.com".to_string(),
+            ..command_from(&pledge)
+        };
+        let result = handler.handle(Box::new(command)).await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(
+                ERR_DONA_MSG_LINKS_NOT_ALLOWED.to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_recipient_settings_do_not_accept_the_pledge() {
+        let pledge = monthly_pledge();
+
+        for (settings, error) in [
+            (
+                RecipientSettingsMother::create(
+                    Some(pledge.user_id()),
+                    Some(dec!(20.00)),
+                    None,
+                    Some("USD"),
+                    vec!["MANUAL", "PAYPAL", "CRYPTO"],
+                    true,
+                ),
+                ERR_DONA_AMOUNT_BELOW_RECIPIENT_MINIMUM,
+            ),
+            (
+                RecipientSettingsMother::create(
+                    Some(pledge.user_id()),
+                    None,
+                    None,
+                    None,
+                    ["MANUAL", "PAYPAL", "CRYPTO"]
+                        .into_iter()
+                        .filter(|method| *method != pledge.method())
+                        .collect(),
+                    true,
+                ),
+                ERR_RECIPIENT_METHOD_NOT_ACTIVE,
+            ),
+        ] {
+            let mut repository = MockPledgeRepository::new();
+            repository
+                .expect_find_by_id()
+                .times(1)
+                .return_const(Err(BaseRepositoryError::NotFound));
+            repository.expect_save().times(0);
+
+            let mut event_bus = MockEventBus::new();
+            event_bus.expect_publish().times(0);
+
+            let handler = CreatePledgeCommandHandler::new(PledgeCreator::new(
+                Arc::new(repository),
+                Arc::new(MockUserPaymentMethodRepository::new()),
+                Arc::new(settings_repository_with(settings)),
+                converter(),
+                DonaMsgPolicy::default(),
+                Arc::new(event_bus),
+            ));
+
+            let result = handler.handle(Box::new(command_from(&pledge))).await;
+
+            assert_eq!(result, Err(CommandError::new(error.to_string())));
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_withhold_msg_when_recipient_does_not_take_messages() {
+        let pledge = monthly_pledge();
+        let settings = RecipientSettingsMother::create(
+            Some(pledge.user_id()),
+            None,
+            None,
+            None,
+            vec!["MANUAL", "PAYPAL", "CRYPTO"],
+            false,
+        );
+
+        let mut repository = MockPledgeRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        repository
+            .expect_save()
+            .withf(|saved| saved.msg() == REDACTED_DONA_MSG)
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(1).return_const(Ok(()));
+
+        let handler = CreatePledgeCommandHandler::new(PledgeCreator::new(
+            Arc::new(repository),
+            Arc::new(method_repository_for(&pledge)),
+            Arc::new(settings_repository_with(settings)),
+            converter(),
+            DonaMsgPolicy::default(),
+            Arc::new(event_bus),
+        ));
+
+        let result = handler.handle(Box::new(command_from(&pledge))).await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+}
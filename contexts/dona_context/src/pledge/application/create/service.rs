@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use shared::domain::{base_errors::BaseRepositoryError, bus::event::EventBus};
+use time::OffsetDateTime;
+
+use crate::{
+    dona::{
+        application::create::service::{
+            recipient_accepts_dona, recipient_accepts_method, recipient_settings,
+        },
+        domain::{dona::REDACTED_DONA_MSG, dona_msg_policy::DonaMsgPolicy},
+    },
+    exchange_rate::application::convert::service::CurrencyConverter,
+    pledge::domain::{
+        pledge::{Pledge, PledgeId},
+        pledge_repository::PledgeRepository,
+    },
+    recipient::domain::recipient_settings_repository::RecipientSettingsRepository,
+    user_payment_method::domain::user_payment_method_repository::UserPaymentMethodRepository,
+};
+
+pub const ERR_PLEDGE_ALREADY_EXISTS: &str = "Pledge already exists";
+
+#[derive(Clone)]
+pub struct PledgeCreator {
+    repository: Arc<dyn PledgeRepository>,
+    user_payment_method_repository: Arc<dyn UserPaymentMethodRepository>,
+    recipient_settings_repository: Arc<dyn RecipientSettingsRepository>,
+    converter: CurrencyConverter,
+    msg_policy: DonaMsgPolicy,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl PledgeCreator {
+    pub fn new(
+        repository: Arc<dyn PledgeRepository>,
+        user_payment_method_repository: Arc<dyn UserPaymentMethodRepository>,
+        recipient_settings_repository: Arc<dyn RecipientSettingsRepository>,
+        converter: CurrencyConverter,
+        msg_policy: DonaMsgPolicy,
+        event_bus: Arc<dyn EventBus>,
+    ) -> Self {
+        Self {
+            repository,
+            user_payment_method_repository,
+            recipient_settings_repository,
+            converter,
+            msg_policy,
+            event_bus,
+        }
+    }
+
+    async fn pledge_exists(&self, id: String) -> Result<(), String> {
+        let pledge = self.repository.find_by_id(PledgeId::new(id)?).await;
+
+        match pledge {
+            Ok(_) => Err(ERR_PLEDGE_ALREADY_EXISTS.to_string()),
+            Err(BaseRepositoryError::NotFound) => Ok(()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// The recipient settings and the message policy are checked like for a single dona, and
+    /// checked again on every charge since they may change during the life of the pledge. The
+    /// message is kept as written, each dona gets it moderated.
+    pub async fn execute(
+        &self,
+        id: String,
+        msg: String,
+        amount: Decimal,
+        currency: String,
+        interval: String,
+        method: String,
+        user_id: String,
+        sender_id: String,
+        next_due_at: OffsetDateTime,
+        created_at: OffsetDateTime,
+        updated_at: OffsetDateTime,
+    ) -> Result<(), String> {
+        self.pledge_exists(id.clone()).await?;
+        let settings =
+            recipient_settings(self.recipient_settings_repository.as_ref(), &user_id).await?;
+        let msg = match &settings {
+            Some(settings) if !settings.messages_allowed() => REDACTED_DONA_MSG.to_string(),
+            _ => {
+                self.msg_policy.moderate(&msg)?;
+                msg
+            }
+        };
+        if let Some(settings) = &settings {
+            recipient_accepts_dona(
+                &self.converter,
+                settings,
+                &method,
+                amount,
+                &currency,
+                created_at,
+            )
+            .await?;
+        }
+        recipient_accepts_method(
+            self.user_payment_method_repository.as_ref(),
+            user_id.clone(),
+            method.clone(),
+            currency.clone(),
+        )
+        .await?;
+
+        let mut pledge = Pledge::create(
+            id,
+            msg,
+            amount,
+            currency,
+            interval,
+            method,
+            user_id,
+            sender_id,
+            next_due_at,
+            created_at,
+            updated_at,
+        )?;
+
+        self.repository.save(&pledge).await?;
+
+        self.event_bus.publish(pledge.pull_events()).await?;
+
+        Ok(())
+    }
+}
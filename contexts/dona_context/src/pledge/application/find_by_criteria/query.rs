@@ -0,0 +1,100 @@
+use shared::domain::{
+    bus::query::{Query, QueryError, QueryHandler, Response},
+    criteria::Criteria,
+};
+
+use super::service::PledgesFinder;
+
+pub const FIND_PLEDGES_BY_CRITERIA_QUERY_TYPE: &str = "dona.find_pledges_by_criteria.query";
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FindPledgesByCriteriaQuery {
+    pub criteria: Criteria,
+}
+
+impl Query for FindPledgesByCriteriaQuery {
+    fn query_type(&self) -> &'static str {
+        FIND_PLEDGES_BY_CRITERIA_QUERY_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct FindPledgesByCriteriaQueryHandler {
+    service: PledgesFinder,
+}
+
+impl FindPledgesByCriteriaQueryHandler {
+    pub fn new(service: PledgesFinder) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler for FindPledgesByCriteriaQueryHandler {
+    async fn handle(&self, query: Box<dyn Query>) -> Result<Box<dyn Response>, QueryError> {
+        let query = query
+            .as_any()
+            .downcast_ref::<FindPledgesByCriteriaQuery>()
+            .ok_or_else(|| QueryError::new("Invalid query".to_string()))?;
+
+        let pledges = self
+            .service
+            .execute(query.criteria.to_owned())
+            .await
+            .map_err(QueryError::new)?;
+
+        Ok(Box::new(pledges))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockall::predicate;
+    use shared::domain::criteria::page::CursorPage;
+
+    use super::*;
+
+    use crate::pledge::application::response::{PledgeResponse, PledgesResponse};
+    use crate::pledge::domain::pledge::tests::PledgeMother;
+    use crate::pledge::domain::pledge_repository::tests::MockPledgeRepository;
+
+    #[tokio::test]
+    async fn it_should_return_pledges_with_page_info() {
+        let criteria = Criteria::new(vec![], None, None);
+        let pledges = vec![PledgeMother::random(), PledgeMother::random()];
+
+        let mut repository = MockPledgeRepository::new();
+        repository
+            .expect_find_page_by_criteria()
+            .with(predicate::eq(criteria.clone()))
+            .times(1)
+            .return_const(Ok(CursorPage::new(pledges.clone(), false, true)));
+
+        let handler =
+            FindPledgesByCriteriaQueryHandler::new(PledgesFinder::new(Arc::new(repository)));
+
+        let response = handler
+            .handle(Box::new(FindPledgesByCriteriaQuery { criteria }))
+            .await
+            .unwrap();
+        let response = response
+            .as_any()
+            .downcast_ref::<PledgesResponse>()
+            .unwrap()
+            .to_owned();
+
+        let expected_response = PledgesResponse {
+            pledges: pledges.into_iter().map(PledgeResponse::from).collect(),
+            has_previous_page: false,
+            has_next_page: true,
+        };
+
+        assert_eq!(response, expected_response);
+    }
+}
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use shared::domain::criteria::Criteria;
+
+use crate::pledge::{
+    application::response::{PledgeResponse, PledgesResponse},
+    domain::pledge_repository::PledgeRepository,
+};
+
+#[derive(Clone)]
+pub struct PledgesFinder {
+    repository: Arc<dyn PledgeRepository>,
+}
+
+impl PledgesFinder {
+    pub fn new(repository: Arc<dyn PledgeRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, criteria: Criteria) -> Result<PledgesResponse, String> {
+        let page = self.repository.find_page_by_criteria(criteria).await?;
+
+        Ok(PledgesResponse {
+            has_previous_page: page.has_previous_page(),
+            has_next_page: page.has_next_page(),
+            pledges: page
+                .into_items()
+                .into_iter()
+                .map(PledgeResponse::from)
+                .collect(),
+        })
+    }
+}
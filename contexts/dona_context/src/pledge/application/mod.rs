@@ -0,0 +1,7 @@
+pub mod cancel;
+pub mod charge_due;
+pub mod create;
+pub mod find_by_criteria;
+pub mod pause;
+pub mod response;
+pub mod resume;
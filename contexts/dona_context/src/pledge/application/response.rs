@@ -0,0 +1,60 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use shared::domain::bus::query::Response;
+use time::OffsetDateTime;
+
+use crate::pledge::domain::pledge::Pledge;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PledgeResponse {
+    pub id: String,
+    pub msg: String,
+    pub amount: Decimal,
+    pub currency: String,
+    pub interval: String,
+    pub method: String,
+    pub user_id: String,
+    pub sender_id: String,
+    pub status: String,
+    pub next_due_at: OffsetDateTime,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl From<Pledge> for PledgeResponse {
+    fn from(pledge: Pledge) -> Self {
+        Self {
+            id: pledge.id(),
+            msg: pledge.msg(),
+            amount: pledge.amount(),
+            currency: pledge.currency(),
+            interval: pledge.interval(),
+            method: pledge.method(),
+            user_id: pledge.user_id(),
+            sender_id: pledge.sender_id(),
+            status: pledge.status(),
+            next_due_at: pledge.next_due_at(),
+            created_at: pledge.created_at(),
+            updated_at: pledge.updated_at(),
+        }
+    }
+}
+
+impl Response for PledgeResponse {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PledgesResponse {
+    pub pledges: Vec<PledgeResponse>,
+    pub has_previous_page: bool,
+    pub has_next_page: bool,
+}
+
+impl Response for PledgesResponse {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
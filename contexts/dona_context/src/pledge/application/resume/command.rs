@@ -0,0 +1,189 @@
+use shared::domain::bus::command::{Command, CommandError, CommandHandler};
+use time::OffsetDateTime;
+
+use super::service::PledgeResumer;
+
+pub const RESUME_PLEDGE_COMMAND_TYPE: &str = "dona.resume_pledge.command";
+
+#[derive(Debug)]
+pub struct ResumePledgeCommand {
+    pub id: String,
+    pub user_id: String,
+    pub updated_at: OffsetDateTime,
+}
+
+impl Command for ResumePledgeCommand {
+    fn command_type(&self) -> &'static str {
+        RESUME_PLEDGE_COMMAND_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct ResumePledgeCommandHandler {
+    service: PledgeResumer,
+}
+
+impl ResumePledgeCommandHandler {
+    pub fn new(service: PledgeResumer) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler for ResumePledgeCommandHandler {
+    async fn handle(&self, command: Box<dyn Command>) -> Result<(), CommandError> {
+        let command = command
+            .as_any()
+            .downcast_ref::<ResumePledgeCommand>()
+            .ok_or_else(|| CommandError::new("Invalid command".to_string()))?;
+
+        self.service
+            .execute(
+                command.id.to_owned(),
+                command.user_id.to_owned(),
+                command.updated_at,
+            )
+            .await
+            .map_err(CommandError::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockall::predicate;
+    use shared::domain::bus::event::tests::MockEventBus;
+    use time::Duration;
+
+    use super::*;
+
+    use crate::pledge::domain::pledge::tests::PledgeMother;
+    use crate::pledge::domain::pledge::{
+        Pledge, ERR_INVALID_PLEDGE_STATUS_TRANSITION, ERR_PLEDGE_NOT_FOUND,
+    };
+    use crate::pledge::domain::pledge_repository::tests::MockPledgeRepository;
+
+    fn pledge_with_status(status: &str) -> Pledge {
+        PledgeMother::create(
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(status.to_string()),
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_pledge_is_active() {
+        let pledge = pledge_with_status("active");
+        let mut repository = MockPledgeRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(pledge.clone()));
+        repository.expect_save().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let handler = ResumePledgeCommandHandler::new(PledgeResumer::new(
+            Arc::new(repository),
+            Arc::new(event_bus),
+        ));
+
+        let result = handler
+            .handle(Box::new(ResumePledgeCommand {
+                id: pledge.id(),
+                user_id: pledge.sender_id(),
+                updated_at: OffsetDateTime::now_utc(),
+            }))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(
+                ERR_INVALID_PLEDGE_STATUS_TRANSITION.to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_user_is_the_recipient() {
+        let pledge = pledge_with_status("paused");
+        let mut repository = MockPledgeRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(pledge.clone()));
+        repository.expect_save().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let handler = ResumePledgeCommandHandler::new(PledgeResumer::new(
+            Arc::new(repository),
+            Arc::new(event_bus),
+        ));
+
+        let result = handler
+            .handle(Box::new(ResumePledgeCommand {
+                id: pledge.id(),
+                user_id: pledge.user_id(),
+                updated_at: OffsetDateTime::now_utc(),
+            }))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_PLEDGE_NOT_FOUND.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_resume_pledge() {
+        let pledge = pledge_with_status("paused");
+        let mut repository = MockPledgeRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(pledge.clone()));
+        repository
+            .expect_save()
+            .withf(|pledge| {
+                pledge.status() == "active"
+                    && pledge.next_due_at() > OffsetDateTime::now_utc() - Duration::seconds(5)
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus
+            .expect_publish()
+            .with(predicate::function(|events: &Vec<_>| events.len() == 1))
+            .times(1)
+            .return_const(Ok(()));
+
+        let handler = ResumePledgeCommandHandler::new(PledgeResumer::new(
+            Arc::new(repository),
+            Arc::new(event_bus),
+        ));
+
+        let result = handler
+            .handle(Box::new(ResumePledgeCommand {
+                id: pledge.id(),
+                user_id: pledge.sender_id(),
+                updated_at: OffsetDateTime::now_utc(),
+            }))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+}
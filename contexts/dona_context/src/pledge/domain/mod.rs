@@ -0,0 +1,7 @@
+pub mod pledge;
+pub mod pledge_cancelled_event;
+pub mod pledge_charged_event;
+pub mod pledge_created_event;
+pub mod pledge_paused_event;
+pub mod pledge_repository;
+pub mod pledge_resumed_event;
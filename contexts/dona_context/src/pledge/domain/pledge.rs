@@ -0,0 +1,554 @@
+use std::fmt::Display;
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use shared::domain::{
+    bus::event::Event,
+    utils::is_uuid,
+    value_objects::{money::Money, user_id::UserId},
+};
+use time::{format_description::well_known::Rfc3339, Date, Duration, Month, OffsetDateTime};
+
+use crate::{dona::domain::dona::DonaMsg, shared::domain::dona::DonaOptionMethod};
+
+use super::{
+    pledge_cancelled_event::PledgeCancelledEvent, pledge_charged_event::PledgeChargedEvent,
+    pledge_created_event::PledgeCreatedEvent, pledge_paused_event::PledgePausedEvent,
+    pledge_resumed_event::PledgeResumedEvent,
+};
+
+pub const ERR_PLEDGE_NOT_FOUND: &str = "Pledge not found";
+
+pub const ERR_INVALID_PLEDGE_ID: &str = "Invalid Pledge ID";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct PledgeId(String);
+
+impl PledgeId {
+    pub fn new(value: String) -> Result<Self, String> {
+        if is_uuid(&value) {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_PLEDGE_ID.to_string())
+        }
+    }
+}
+
+impl Display for PledgeId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub const ERR_INVALID_PLEDGE_AMOUNT: &str = "Invalid Pledge Amount";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct PledgeAmount(Money);
+
+impl PledgeAmount {
+    pub fn new(value: Money) -> Result<Self, String> {
+        if value.amount() > Decimal::ZERO {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_PLEDGE_AMOUNT.to_string())
+        }
+    }
+}
+
+impl Display for PledgeAmount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub const ERR_INVALID_PLEDGE_INTERVAL: &str = "Invalid Pledge Interval";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum PledgeInterval {
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl PledgeInterval {
+    pub fn new(value: String) -> Result<Self, String> {
+        match value.as_str() {
+            "weekly" => Ok(Self::Weekly),
+            "monthly" => Ok(Self::Monthly),
+            "yearly" => Ok(Self::Yearly),
+            _ => Err(ERR_INVALID_PLEDGE_INTERVAL.to_string()),
+        }
+    }
+
+    /// The due date that follows `from`. Months are added on the calendar, so a pledge due on
+    /// the 31st falls on the last day of shorter months.
+    pub fn advance(&self, from: OffsetDateTime) -> OffsetDateTime {
+        match self {
+            Self::Weekly => from + Duration::weeks(1),
+            Self::Monthly => from.replace_date(add_months(from.date(), 1)),
+            Self::Yearly => from.replace_date(add_months(from.date(), 12)),
+        }
+    }
+}
+
+fn add_months(date: Date, months: i32) -> Date {
+    let total = date.year() * 12 + (date.month() as i32 - 1) + months;
+    let year = total.div_euclid(12);
+    let month = Month::try_from((total.rem_euclid(12) + 1) as u8).unwrap();
+    let day = date.day().min(month.length(year));
+
+    Date::from_calendar_date(year, month, day).unwrap()
+}
+
+impl Display for PledgeInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Weekly => write!(f, "weekly"),
+            Self::Monthly => write!(f, "monthly"),
+            Self::Yearly => write!(f, "yearly"),
+        }
+    }
+}
+
+pub const ERR_INVALID_PLEDGE_STATUS: &str = "Invalid Pledge Status";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum PledgeStatus {
+    Active,
+    Paused,
+    Cancelled,
+}
+
+impl PledgeStatus {
+    pub fn new(value: String) -> Result<Self, String> {
+        match value.as_str() {
+            "active" => Ok(Self::Active),
+            "paused" => Ok(Self::Paused),
+            "cancelled" => Ok(Self::Cancelled),
+            _ => Err(ERR_INVALID_PLEDGE_STATUS.to_string()),
+        }
+    }
+
+    pub fn can_transition_to(&self, next: &PledgeStatus) -> bool {
+        matches!(
+            (self, next),
+            (Self::Active, Self::Paused)
+                | (Self::Paused, Self::Active)
+                | (Self::Active, Self::Cancelled)
+                | (Self::Paused, Self::Cancelled)
+        )
+    }
+}
+
+impl Display for PledgeStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Active => write!(f, "active"),
+            Self::Paused => write!(f, "paused"),
+            Self::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+pub const ERR_INVALID_PLEDGE_STATUS_TRANSITION: &str = "Invalid Pledge Status Transition";
+pub const ERR_PLEDGE_NOT_DUE: &str = "Pledge is not due yet";
+
+pub const ERR_INVALID_PLEDGE_NEXT_DUE_AT: &str = "Pledge cannot be due before its creation";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct PledgeNextDueAt(OffsetDateTime);
+
+impl PledgeNextDueAt {
+    pub fn new(value: OffsetDateTime, created_at: &PledgeCreatedAt) -> Result<Self, String> {
+        if value >= created_at.0 {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_PLEDGE_NEXT_DUE_AT.to_string())
+        }
+    }
+}
+
+impl Display for PledgeNextDueAt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.format(&Rfc3339).unwrap())
+    }
+}
+
+pub const ERR_INVALID_PLEDGE_CREATED_AT: &str = "Invalid Pledge Created At";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct PledgeCreatedAt(OffsetDateTime);
+
+impl PledgeCreatedAt {
+    pub fn new(value: OffsetDateTime) -> Result<Self, String> {
+        if value < OffsetDateTime::now_utc() {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_PLEDGE_CREATED_AT.to_string())
+        }
+    }
+}
+
+impl Display for PledgeCreatedAt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.format(&Rfc3339).unwrap())
+    }
+}
+
+pub const ERR_INVALID_PLEDGE_UPDATED_AT: &str = "Invalid Pledge Updated At";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct PledgeUpdatedAt(OffsetDateTime);
+
+impl PledgeUpdatedAt {
+    pub fn new(value: OffsetDateTime) -> Result<Self, String> {
+        if value < OffsetDateTime::now_utc() {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_PLEDGE_UPDATED_AT.to_string())
+        }
+    }
+}
+
+impl Display for PledgeUpdatedAt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.format(&Rfc3339).unwrap())
+    }
+}
+
+/// A recurring dona: every time it falls due the scheduler turns it into a pending dona from
+/// `sender_id` to `user_id`.
+#[derive(Debug, Clone)]
+pub struct Pledge {
+    id: PledgeId,
+    msg: DonaMsg,
+    amount: PledgeAmount,
+    interval: PledgeInterval,
+    method: DonaOptionMethod,
+    user_id: UserId,
+    sender_id: UserId,
+    status: PledgeStatus,
+    next_due_at: PledgeNextDueAt,
+    created_at: PledgeCreatedAt,
+    updated_at: PledgeUpdatedAt,
+
+    events: Vec<Arc<dyn Event>>,
+}
+
+impl PartialEq for Pledge {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.msg == other.msg
+            && self.amount == other.amount
+            && self.interval == other.interval
+            && self.method == other.method
+            && self.user_id == other.user_id
+            && self.sender_id == other.sender_id
+            && self.status == other.status
+            && self.next_due_at == other.next_due_at
+            && self.created_at == other.created_at
+            && self.updated_at == other.updated_at
+    }
+}
+
+impl Eq for Pledge {}
+
+impl Pledge {
+    pub(crate) fn new(
+        id: String,
+        msg: String,
+        amount: Decimal,
+        currency: String,
+        interval: String,
+        method: String,
+        user_id: String,
+        sender_id: String,
+        status: String,
+        next_due_at: OffsetDateTime,
+        created_at: OffsetDateTime,
+        updated_at: OffsetDateTime,
+    ) -> Result<Self, String> {
+        let created_at = PledgeCreatedAt::new(created_at)?;
+
+        Ok(Self {
+            id: PledgeId::new(id)?,
+            msg: DonaMsg::new(msg)?,
+            amount: PledgeAmount::new(Money::new(amount, currency)?)?,
+            interval: PledgeInterval::new(interval)?,
+            method: DonaOptionMethod::new(method)?,
+            user_id: UserId::new(user_id)?,
+            sender_id: UserId::new(sender_id)?,
+            status: PledgeStatus::new(status)?,
+            next_due_at: PledgeNextDueAt::new(next_due_at, &created_at)?,
+            created_at,
+            updated_at: PledgeUpdatedAt::new(updated_at)?,
+            events: vec![],
+        })
+    }
+
+    pub fn create(
+        id: String,
+        msg: String,
+        amount: Decimal,
+        currency: String,
+        interval: String,
+        method: String,
+        user_id: String,
+        sender_id: String,
+        next_due_at: OffsetDateTime,
+        created_at: OffsetDateTime,
+        updated_at: OffsetDateTime,
+    ) -> Result<Self, String> {
+        let mut pledge = Self::new(
+            id,
+            msg,
+            amount,
+            currency,
+            interval,
+            method,
+            user_id,
+            sender_id,
+            PledgeStatus::Active.to_string(),
+            next_due_at,
+            created_at,
+            updated_at,
+        )?;
+
+        let event = PledgeCreatedEvent::new(
+            pledge.id(),
+            pledge.msg(),
+            pledge.amount().to_string(),
+            pledge.currency(),
+            pledge.interval(),
+            pledge.method(),
+            pledge.user_id(),
+            pledge.sender_id(),
+            pledge.next_due_at.to_string(),
+            pledge.created_at.to_string(),
+            pledge.updated_at.to_string(),
+        );
+        pledge.record(Arc::new(event));
+
+        Ok(pledge)
+    }
+
+    fn transition(&mut self, next: PledgeStatus, ocurred_at: OffsetDateTime) -> Result<(), String> {
+        if !self.status.can_transition_to(&next) {
+            return Err(ERR_INVALID_PLEDGE_STATUS_TRANSITION.to_string());
+        }
+
+        self.updated_at = PledgeUpdatedAt::new(ocurred_at)?;
+        self.status = next;
+
+        Ok(())
+    }
+
+    pub fn pause(&mut self, ocurred_at: OffsetDateTime) -> Result<(), String> {
+        self.transition(PledgeStatus::Paused, ocurred_at)?;
+
+        let event = PledgePausedEvent::new(self.id(), self.updated_at.to_string());
+        self.record(Arc::new(event));
+
+        Ok(())
+    }
+
+    /// Periods that fell due while the pledge was paused are skipped, not charged.
+    pub fn resume(&mut self, ocurred_at: OffsetDateTime) -> Result<(), String> {
+        self.transition(PledgeStatus::Active, ocurred_at)?;
+
+        let mut next_due_at = self.next_due_at.0;
+        while next_due_at < ocurred_at {
+            next_due_at = self.interval.advance(next_due_at);
+        }
+        self.next_due_at = PledgeNextDueAt(next_due_at);
+
+        let event = PledgeResumedEvent::new(
+            self.id(),
+            self.next_due_at.to_string(),
+            self.updated_at.to_string(),
+        );
+        self.record(Arc::new(event));
+
+        Ok(())
+    }
+
+    pub fn cancel(&mut self, ocurred_at: OffsetDateTime) -> Result<(), String> {
+        self.transition(PledgeStatus::Cancelled, ocurred_at)?;
+
+        let event = PledgeCancelledEvent::new(self.id(), self.updated_at.to_string());
+        self.record(Arc::new(event));
+
+        Ok(())
+    }
+
+    pub fn is_due(&self, at: OffsetDateTime) -> bool {
+        self.status == PledgeStatus::Active && self.next_due_at.0 <= at
+    }
+
+    /// Records that the dona for the current period was generated and moves on to the next one.
+    pub fn charge(&mut self, dona_id: String, ocurred_at: OffsetDateTime) -> Result<(), String> {
+        if !self.is_due(ocurred_at) {
+            return Err(ERR_PLEDGE_NOT_DUE.to_string());
+        }
+
+        self.next_due_at = PledgeNextDueAt(self.interval.advance(self.next_due_at.0));
+        self.updated_at = PledgeUpdatedAt::new(ocurred_at)?;
+
+        let event = PledgeChargedEvent::new(
+            self.id(),
+            dona_id,
+            self.next_due_at.to_string(),
+            self.updated_at.to_string(),
+        );
+        self.record(Arc::new(event));
+
+        Ok(())
+    }
+
+    pub fn record(&mut self, event: Arc<dyn Event>) {
+        self.events.push(event);
+    }
+
+    pub fn pull_events(&mut self) -> Vec<Arc<dyn Event>> {
+        let events = self.events.clone();
+        self.events = vec![];
+        events
+    }
+
+    pub fn id(&self) -> String {
+        self.id.to_string()
+    }
+
+    pub fn msg(&self) -> String {
+        self.msg.to_string()
+    }
+
+    pub fn amount(&self) -> Decimal {
+        self.amount.0.amount()
+    }
+
+    pub fn currency(&self) -> String {
+        self.amount.0.currency().to_string()
+    }
+
+    pub fn interval(&self) -> String {
+        self.interval.to_string()
+    }
+
+    pub fn method(&self) -> String {
+        self.method.to_string()
+    }
+
+    pub fn user_id(&self) -> String {
+        self.user_id.to_string()
+    }
+
+    pub fn sender_id(&self) -> String {
+        self.sender_id.to_string()
+    }
+
+    pub fn status(&self) -> String {
+        self.status.to_string()
+    }
+
+    pub fn next_due_at(&self) -> OffsetDateTime {
+        self.next_due_at.0
+    }
+
+    pub fn created_at(&self) -> OffsetDateTime {
+        self.created_at.0
+    }
+
+    pub fn updated_at(&self) -> OffsetDateTime {
+        self.updated_at.0
+    }
+}
+
+pub mod tests {
+    use fake::{faker::lorem::en::Sentence, Fake};
+    use shared::domain::{
+        utils::new_uuid,
+        value_objects::{money::tests::MoneyMother, user_id::tests::UserIdMother},
+    };
+
+    use crate::shared::domain::dona::tests::DonaOptionMethodMother;
+
+    use super::*;
+
+    pub struct PledgeIdMother;
+
+    impl PledgeIdMother {
+        pub fn create(value: Option<String>) -> PledgeId {
+            match value {
+                Some(value) => PledgeId::new(value).unwrap(),
+                None => Self::random(),
+            }
+        }
+
+        pub fn random() -> PledgeId {
+            PledgeId::new(new_uuid()).unwrap()
+        }
+    }
+
+    pub struct PledgeIntervalMother;
+
+    impl PledgeIntervalMother {
+        pub fn create(value: Option<String>) -> PledgeInterval {
+            match value {
+                Some(value) => PledgeInterval::new(value).unwrap(),
+                None => PledgeInterval::Monthly,
+            }
+        }
+    }
+
+    pub struct PledgeStatusMother;
+
+    impl PledgeStatusMother {
+        pub fn create(value: Option<String>) -> PledgeStatus {
+            match value {
+                Some(value) => PledgeStatus::new(value).unwrap(),
+                None => PledgeStatus::Active,
+            }
+        }
+    }
+
+    pub struct PledgeMother;
+
+    impl PledgeMother {
+        /// Pledges are created a week ago and fall due `next_due_at`, a day ago by default.
+        pub fn create(
+            id: Option<String>,
+            amount: Option<Decimal>,
+            currency: Option<String>,
+            interval: Option<String>,
+            user_id: Option<String>,
+            sender_id: Option<String>,
+            status: Option<String>,
+            next_due_at: Option<OffsetDateTime>,
+        ) -> Pledge {
+            let now = OffsetDateTime::now_utc();
+            let created_at = PledgeCreatedAt::new(now - Duration::weeks(1)).unwrap();
+            let next_due_at = next_due_at.unwrap_or(now - Duration::days(1));
+
+            Pledge {
+                id: PledgeIdMother::create(id),
+                msg: DonaMsg::new(Sentence(1..10).fake()).unwrap(),
+                amount: PledgeAmount::new(MoneyMother::create(amount, currency)).unwrap(),
+                interval: PledgeIntervalMother::create(interval),
+                method: DonaOptionMethodMother::random(),
+                user_id: UserIdMother::create(user_id),
+                sender_id: UserIdMother::create(sender_id),
+                status: PledgeStatusMother::create(status),
+                next_due_at: PledgeNextDueAt::new(next_due_at, &created_at).unwrap(),
+                created_at: created_at.clone(),
+                updated_at: PledgeUpdatedAt::new(created_at.0).unwrap(),
+
+                events: vec![],
+            }
+        }
+
+        pub fn random() -> Pledge {
+            Self::create(None, None, None, None, None, None, None, None)
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use shared::domain::bus::event::{BaseEvent, Event, EventDeserializeError, EventSerialized};
+
+pub const PLEDGE_CHARGED_EVENT_TYPE: &str = "dona.pledge_charged";
+
+/// Published each time a due pledge generates its pending dona.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct PledgeChargedEvent {
+    id: String,
+    dona_id: String,
+    next_due_at: String,
+    updated_at: String,
+
+    base_event: BaseEvent,
+}
+
+impl PledgeChargedEvent {
+    pub fn new(id: String, dona_id: String, next_due_at: String, updated_at: String) -> Self {
+        Self {
+            id: id.clone(),
+            dona_id,
+            next_due_at,
+            updated_at,
+            base_event: BaseEvent::new(id),
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn dona_id(&self) -> &str {
+        &self.dona_id
+    }
+
+    pub fn next_due_at(&self) -> &str {
+        &self.next_due_at
+    }
+
+    pub fn updated_at(&self) -> &str {
+        &self.updated_at
+    }
+}
+
+impl Event for PledgeChargedEvent {
+    fn event_type(&self) -> &'static str {
+        PLEDGE_CHARGED_EVENT_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn from_primitives(
+        &self,
+        primitives: EventSerialized,
+    ) -> Result<Box<dyn Event>, EventDeserializeError> {
+        let data = primitives.data();
+        let base_event = BaseEvent::from_primitives(
+            primitives.event_id().to_string(),
+            primitives.aggregate_id().to_string(),
+            primitives.occurred_at().to_string(),
+        );
+
+        let id = data
+            .get("id")
+            .ok_or(EventDeserializeError::MissingField("id".to_string()))?;
+        let dona_id = data
+            .get("dona_id")
+            .ok_or(EventDeserializeError::MissingField("dona_id".to_string()))?;
+        let next_due_at = data
+            .get("next_due_at")
+            .ok_or(EventDeserializeError::MissingField(
+                "next_due_at".to_string(),
+            ))?;
+        let updated_at = data
+            .get("updated_at")
+            .ok_or(EventDeserializeError::MissingField(
+                "updated_at".to_string(),
+            ))?;
+
+        Ok(Box::new(Self {
+            id: id.to_string(),
+            dona_id: dona_id.to_string(),
+            next_due_at: next_due_at.to_string(),
+            updated_at: updated_at.to_string(),
+            base_event,
+        }))
+    }
+
+    fn to_primitives(&self) -> EventSerialized {
+        EventSerialized::new(
+            self.base_event.event_id().to_string(),
+            self.base_event.aggregate_id().to_string(),
+            self.base_event.occurred_at().to_string(),
+            vec![
+                ("id".to_string(), self.id.clone()),
+                ("dona_id".to_string(), self.dona_id.clone()),
+                ("next_due_at".to_string(), self.next_due_at.clone()),
+                ("updated_at".to_string(), self.updated_at.clone()),
+            ]
+            .into_iter()
+            .collect(),
+        )
+    }
+}
@@ -0,0 +1,195 @@
+use shared::domain::bus::event::{BaseEvent, Event, EventDeserializeError, EventSerialized};
+
+pub const PLEDGE_CREATED_EVENT_TYPE: &str = "dona.pledge_created";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct PledgeCreatedEvent {
+    id: String,
+    msg: String,
+    amount: String,
+    currency: String,
+    interval: String,
+    method: String,
+    user_id: String,
+    sender_id: String,
+    next_due_at: String,
+    created_at: String,
+    updated_at: String,
+
+    base_event: BaseEvent,
+}
+
+impl PledgeCreatedEvent {
+    pub fn new(
+        id: String,
+        msg: String,
+        amount: String,
+        currency: String,
+        interval: String,
+        method: String,
+        user_id: String,
+        sender_id: String,
+        next_due_at: String,
+        created_at: String,
+        updated_at: String,
+    ) -> Self {
+        Self {
+            id: id.clone(),
+            msg,
+            amount,
+            currency,
+            interval,
+            method,
+            user_id,
+            sender_id,
+            next_due_at,
+            created_at,
+            updated_at,
+            base_event: BaseEvent::new(id),
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn msg(&self) -> &str {
+        &self.msg
+    }
+
+    pub fn amount(&self) -> &str {
+        &self.amount
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    pub fn interval(&self) -> &str {
+        &self.interval
+    }
+
+    pub fn method(&self) -> &str {
+        &self.method
+    }
+
+    pub fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    pub fn sender_id(&self) -> &str {
+        &self.sender_id
+    }
+
+    pub fn next_due_at(&self) -> &str {
+        &self.next_due_at
+    }
+
+    pub fn created_at(&self) -> &str {
+        &self.created_at
+    }
+
+    pub fn updated_at(&self) -> &str {
+        &self.updated_at
+    }
+}
+
+impl Event for PledgeCreatedEvent {
+    fn event_type(&self) -> &'static str {
+        PLEDGE_CREATED_EVENT_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn from_primitives(
+        &self,
+        primitives: EventSerialized,
+    ) -> Result<Box<dyn Event>, EventDeserializeError> {
+        let data = primitives.data();
+        let base_event = BaseEvent::from_primitives(
+            primitives.event_id().to_string(),
+            primitives.aggregate_id().to_string(),
+            primitives.occurred_at().to_string(),
+        );
+
+        let id = data
+            .get("id")
+            .ok_or(EventDeserializeError::MissingField("id".to_string()))?;
+        let msg = data
+            .get("msg")
+            .ok_or(EventDeserializeError::MissingField("msg".to_string()))?;
+        let amount = data
+            .get("amount")
+            .ok_or(EventDeserializeError::MissingField("amount".to_string()))?;
+        let currency = data
+            .get("currency")
+            .ok_or(EventDeserializeError::MissingField("currency".to_string()))?;
+        let interval = data
+            .get("interval")
+            .ok_or(EventDeserializeError::MissingField("interval".to_string()))?;
+        let method = data
+            .get("method")
+            .ok_or(EventDeserializeError::MissingField("method".to_string()))?;
+        let user_id = data
+            .get("user_id")
+            .ok_or(EventDeserializeError::MissingField("user_id".to_string()))?;
+        let sender_id = data
+            .get("sender_id")
+            .ok_or(EventDeserializeError::MissingField("sender_id".to_string()))?;
+        let next_due_at = data
+            .get("next_due_at")
+            .ok_or(EventDeserializeError::MissingField(
+                "next_due_at".to_string(),
+            ))?;
+        let created_at = data
+            .get("created_at")
+            .ok_or(EventDeserializeError::MissingField(
+                "created_at".to_string(),
+            ))?;
+        let updated_at = data
+            .get("updated_at")
+            .ok_or(EventDeserializeError::MissingField(
+                "updated_at".to_string(),
+            ))?;
+
+        Ok(Box::new(Self {
+            id: id.to_string(),
+            msg: msg.to_string(),
+            amount: amount.to_string(),
+            currency: currency.to_string(),
+            interval: interval.to_string(),
+            method: method.to_string(),
+            user_id: user_id.to_string(),
+            sender_id: sender_id.to_string(),
+            next_due_at: next_due_at.to_string(),
+            created_at: created_at.to_string(),
+            updated_at: updated_at.to_string(),
+            base_event,
+        }))
+    }
+
+    fn to_primitives(&self) -> EventSerialized {
+        EventSerialized::new(
+            self.base_event.event_id().to_string(),
+            self.base_event.aggregate_id().to_string(),
+            self.base_event.occurred_at().to_string(),
+            vec![
+                ("id".to_string(), self.id.clone()),
+                ("msg".to_string(), self.msg.clone()),
+                ("amount".to_string(), self.amount.clone()),
+                ("currency".to_string(), self.currency.clone()),
+                ("interval".to_string(), self.interval.clone()),
+                ("method".to_string(), self.method.clone()),
+                ("user_id".to_string(), self.user_id.clone()),
+                ("sender_id".to_string(), self.sender_id.clone()),
+                ("next_due_at".to_string(), self.next_due_at.clone()),
+                ("created_at".to_string(), self.created_at.clone()),
+                ("updated_at".to_string(), self.updated_at.clone()),
+            ]
+            .into_iter()
+            .collect(),
+        )
+    }
+}
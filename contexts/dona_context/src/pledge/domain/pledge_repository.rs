@@ -0,0 +1,39 @@
+use shared::domain::{
+    base_errors::BaseRepositoryError,
+    criteria::{page::CursorPage, Criteria},
+};
+
+use super::pledge::{Pledge, PledgeId};
+
+#[async_trait::async_trait]
+pub trait PledgeRepository: Send + Sync {
+    async fn find_by_id(&self, id: PledgeId) -> Result<Pledge, BaseRepositoryError>;
+    async fn find_by_criteria(
+        &self,
+        criteria: Criteria,
+    ) -> Result<Vec<Pledge>, BaseRepositoryError>;
+    async fn find_page_by_criteria(
+        &self,
+        criteria: Criteria,
+    ) -> Result<CursorPage<Pledge>, BaseRepositoryError>;
+    async fn save(&self, pledge: &Pledge) -> Result<(), BaseRepositoryError>;
+}
+
+#[cfg(test)]
+pub mod tests {
+    use mockall::mock;
+
+    use super::*;
+
+    mock! {
+        pub PledgeRepository {}
+
+        #[async_trait::async_trait]
+        impl PledgeRepository for PledgeRepository {
+            async fn find_by_id(&self, id: PledgeId) -> Result<Pledge, BaseRepositoryError>;
+            async fn find_by_criteria(&self, criteria: Criteria) -> Result<Vec<Pledge>, BaseRepositoryError>;
+            async fn find_page_by_criteria(&self, criteria: Criteria) -> Result<CursorPage<Pledge>, BaseRepositoryError>;
+            async fn save(&self, pledge: &Pledge) -> Result<(), BaseRepositoryError>;
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod sea_pledge_repo;
@@ -0,0 +1,178 @@
+use shared::domain::bus::query::{Query, QueryError, QueryHandler, Response};
+
+use super::service::ReceiptDownloader;
+
+pub const DOWNLOAD_RECEIPT_QUERY_TYPE: &str = "dona.download_receipt.query";
+
+/// `is_admin` is resolved by the caller through the security context, admins may download
+/// any receipt.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DownloadReceiptQuery {
+    pub dona_id: String,
+    pub format: String,
+    pub requester_id: String,
+    pub is_admin: bool,
+}
+
+impl Query for DownloadReceiptQuery {
+    fn query_type(&self) -> &'static str {
+        DOWNLOAD_RECEIPT_QUERY_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct DownloadReceiptQueryHandler {
+    service: ReceiptDownloader,
+}
+
+impl DownloadReceiptQueryHandler {
+    pub fn new(service: ReceiptDownloader) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler for DownloadReceiptQueryHandler {
+    async fn handle(&self, query: Box<dyn Query>) -> Result<Box<dyn Response>, QueryError> {
+        let query = query
+            .as_any()
+            .downcast_ref::<DownloadReceiptQuery>()
+            .ok_or_else(|| QueryError::new("Invalid query".to_string()))?;
+
+        let receipt = self
+            .service
+            .execute(
+                query.dona_id.to_owned(),
+                query.format.to_owned(),
+                query.requester_id.to_owned(),
+                query.is_admin,
+            )
+            .await
+            .map_err(QueryError::new)?;
+
+        Ok(Box::new(receipt))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Seek, SeekFrom, Write};
+    use std::sync::Arc;
+
+    use shared::domain::{
+        base_errors::BaseRepositoryError, storage::tests::MockFileStorageRepository,
+        utils::new_uuid,
+    };
+
+    use super::*;
+
+    use crate::receipt::application::response::ReceiptFileResponse;
+    use crate::receipt::domain::receipt::tests::ReceiptMother;
+    use crate::receipt::domain::receipt::{Receipt, ERR_RECEIPT_FORBIDDEN, ERR_RECEIPT_NOT_FOUND};
+    use crate::receipt::domain::receipt_repository::tests::MockReceiptRepository;
+
+    fn stored_file() -> MockFileStorageRepository {
+        let mut storage_repository = MockFileStorageRepository::new();
+        storage_repository.expect_get().returning(|_, _, _| {
+            let mut file = tempfile::tempfile().unwrap();
+            file.write_all(b"%PDF-1.4").unwrap();
+            file.seek(SeekFrom::Start(0)).unwrap();
+            Ok(file)
+        });
+        storage_repository
+    }
+
+    fn handler(
+        receipt: Option<Receipt>,
+        storage_repository: MockFileStorageRepository,
+    ) -> DownloadReceiptQueryHandler {
+        let mut receipt_repository = MockReceiptRepository::new();
+        receipt_repository
+            .expect_find_by_dona_id()
+            .times(1)
+            .return_const(receipt.ok_or(BaseRepositoryError::NotFound));
+
+        DownloadReceiptQueryHandler::new(ReceiptDownloader::new(
+            Arc::new(receipt_repository),
+            Arc::new(storage_repository),
+        ))
+    }
+
+    fn query(receipt: &Receipt, requester_id: String, is_admin: bool) -> Box<dyn Query> {
+        Box::new(DownloadReceiptQuery {
+            dona_id: receipt.dona_id(),
+            format: "pdf".to_string(),
+            requester_id,
+            is_admin,
+        })
+    }
+
+    #[tokio::test]
+    async fn it_should_let_sender_recipient_and_admin_download() {
+        let sender_id = new_uuid();
+        let receipt = ReceiptMother::create(None, None, None, Some(sender_id.clone()), Some(3));
+
+        for (requester_id, is_admin) in [
+            (receipt.user_id(), false),
+            (sender_id.clone(), false),
+            (new_uuid(), true),
+        ] {
+            let response = handler(Some(receipt.clone()), stored_file())
+                .handle(query(&receipt, requester_id, is_admin))
+                .await
+                .unwrap();
+            let response = response
+                .as_any()
+                .downcast_ref::<ReceiptFileResponse>()
+                .unwrap()
+                .to_owned();
+
+            assert_eq!(
+                response,
+                ReceiptFileResponse {
+                    filename: "receipt-000003.pdf".to_string(),
+                    content_type: "application/pdf".to_string(),
+                    content: b"%PDF-1.4".to_vec(),
+                }
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_forbid_other_users() {
+        let receipt = ReceiptMother::create(None, None, None, Some(new_uuid()), None);
+
+        let mut storage_repository = MockFileStorageRepository::new();
+        storage_repository.expect_get().times(0);
+
+        let result = handler(Some(receipt.clone()), storage_repository)
+            .handle(query(&receipt, new_uuid(), false))
+            .await;
+
+        assert_eq!(
+            result.err(),
+            Some(QueryError::new(ERR_RECEIPT_FORBIDDEN.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_receipt_does_not_exist() {
+        let receipt = ReceiptMother::random();
+
+        let mut storage_repository = MockFileStorageRepository::new();
+        storage_repository.expect_get().times(0);
+
+        let result = handler(None, storage_repository)
+            .handle(query(&receipt, receipt.user_id(), false))
+            .await;
+
+        assert_eq!(
+            result.err(),
+            Some(QueryError::new(ERR_RECEIPT_NOT_FOUND.to_string()))
+        );
+    }
+}
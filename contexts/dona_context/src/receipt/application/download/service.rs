@@ -0,0 +1,69 @@
+use std::{io::Read, sync::Arc};
+
+use shared::{domain::storage::FileStorageRepository, RECEIPT_STORAGE_MODEL};
+
+use crate::{
+    dona::domain::dona::DonaId,
+    receipt::{
+        application::response::ReceiptFileResponse,
+        domain::{
+            receipt::{ReceiptFormat, ERR_RECEIPT_FORBIDDEN, ERR_RECEIPT_NOT_FOUND},
+            receipt_repository::ReceiptRepository,
+        },
+    },
+};
+
+#[derive(Clone)]
+pub struct ReceiptDownloader {
+    receipt_repository: Arc<dyn ReceiptRepository>,
+    storage_repository: Arc<dyn FileStorageRepository>,
+}
+
+impl ReceiptDownloader {
+    pub fn new(
+        receipt_repository: Arc<dyn ReceiptRepository>,
+        storage_repository: Arc<dyn FileStorageRepository>,
+    ) -> Self {
+        Self {
+            receipt_repository,
+            storage_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        dona_id: String,
+        format: String,
+        requester_id: String,
+        is_admin: bool,
+    ) -> Result<ReceiptFileResponse, String> {
+        let format = ReceiptFormat::new(format)?;
+        let receipt = self
+            .receipt_repository
+            .find_by_dona_id(DonaId::new(dona_id)?)
+            .await
+            .map_err(|_| ERR_RECEIPT_NOT_FOUND.to_string())?;
+
+        if !is_admin && !receipt.can_be_downloaded_by(&requester_id) {
+            return Err(ERR_RECEIPT_FORBIDDEN.to_string());
+        }
+
+        let filename = receipt.filename(format);
+        let mut file = self
+            .storage_repository
+            .get(
+                RECEIPT_STORAGE_MODEL.to_string(),
+                receipt.id(),
+                filename.clone(),
+            )
+            .await?;
+        let mut content = Vec::new();
+        file.read_to_end(&mut content).map_err(|e| e.to_string())?;
+
+        Ok(ReceiptFileResponse {
+            filename,
+            content_type: format.content_type().to_string(),
+            content,
+        })
+    }
+}
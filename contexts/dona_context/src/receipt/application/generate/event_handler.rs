@@ -0,0 +1,436 @@
+use std::sync::Arc;
+
+use shared::domain::bus::event::{Event, EventError, EventHandler};
+
+use crate::dona::domain::dona_confirmed_event::{DonaConfirmedEvent, DONA_CONFIRMED_EVENT_TYPE};
+
+use super::service::ReceiptGenerator;
+
+#[derive(Clone)]
+pub struct GenerateReceiptOnDonaConfirmed {
+    service: ReceiptGenerator,
+}
+
+impl GenerateReceiptOnDonaConfirmed {
+    pub fn new(service: ReceiptGenerator) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl EventHandler for GenerateReceiptOnDonaConfirmed {
+    async fn handle(&self, event: Arc<dyn Event>) -> Result<(), EventError> {
+        let event = event
+            .as_any()
+            .downcast_ref::<DonaConfirmedEvent>()
+            .ok_or_else(|| EventError::new("Invalid event".to_string()))?;
+
+        self.service
+            .execute(event.dona_id().to_string())
+            .await
+            .map_err(EventError::new)
+    }
+
+    fn subscribed_to(&self) -> Vec<&'static str> {
+        vec![DONA_CONFIRMED_EVENT_TYPE]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::{predicate, Sequence};
+    use shared::{
+        domain::{
+            base_errors::BaseRepositoryError,
+            bus::event::tests::MockEventBus,
+            storage::tests::MockFileStorageRepository,
+            value_objects::user_id::UserId,
+        },
+        RECEIPT_STORAGE_MODEL,
+    };
+
+    use super::*;
+
+    use crate::dona::domain::dona::tests::DonaMother;
+    use crate::dona::domain::dona::{Dona, DonaId};
+    use crate::dona::domain::dona_repository::tests::MockDonaRepository;
+    use crate::receipt::domain::receipt::tests::ReceiptMother;
+    use crate::receipt::domain::receipt::ERR_RECEIPT_DONA_NOT_CONFIRMED;
+    use crate::receipt::domain::receipt_issued_event::{
+        ReceiptIssuedEvent, RECEIPT_ISSUED_EVENT_TYPE,
+    };
+    use crate::receipt::domain::receipt_repository::tests::MockReceiptRepository;
+    use crate::receipt::infrastructure::rendering::{
+        html_receipt_renderer::HtmlReceiptRenderer, pdf_receipt_renderer::PdfReceiptRenderer,
+    };
+
+    fn dona_with_status(status: &str) -> Dona {
+        DonaMother::create(
+            None,
+            None,
+            None,
+            None,
+            Some(status.to_string()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn confirmed_event(dona: &Dona) -> Arc<dyn Event> {
+        Arc::new(DonaConfirmedEvent::new(
+            dona.id(),
+            dona.updated_at().to_string(),
+        ))
+    }
+
+    fn handler(
+        receipt_repository: MockReceiptRepository,
+        dona_repository: MockDonaRepository,
+        storage_repository: MockFileStorageRepository,
+        event_bus: MockEventBus,
+    ) -> GenerateReceiptOnDonaConfirmed {
+        GenerateReceiptOnDonaConfirmed::new(ReceiptGenerator::new(
+            Arc::new(receipt_repository),
+            Arc::new(dona_repository),
+            Arc::new(storage_repository),
+            vec![
+                Arc::new(HtmlReceiptRenderer::new()),
+                Arc::new(PdfReceiptRenderer::new()),
+            ],
+            Arc::new(event_bus),
+        ))
+    }
+
+    #[tokio::test]
+    async fn it_should_issue_the_next_receipt_of_the_recipient() {
+        let dona = dona_with_status("confirmed");
+
+        let mut receipt_repository = MockReceiptRepository::new();
+        receipt_repository
+            .expect_find_by_dona_id()
+            .with(predicate::eq(DonaId::new(dona.id()).unwrap()))
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        receipt_repository
+            .expect_last_number()
+            .with(predicate::eq(UserId::new(dona.user_id()).unwrap()))
+            .times(1)
+            .return_const(Ok(41));
+        let expected_dona = dona.clone();
+        receipt_repository
+            .expect_save()
+            .withf(move |receipt| {
+                receipt.number() == 42
+                    && receipt.dona_id() == expected_dona.id()
+                    && receipt.user_id() == expected_dona.user_id()
+                    && receipt.sender_id() == expected_dona.sender_id()
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut dona_repository = MockDonaRepository::new();
+        dona_repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(dona.clone()));
+
+        let mut storage_repository = MockFileStorageRepository::new();
+        storage_repository
+            .expect_save()
+            .withf(|model, _, filename, _| {
+                model == RECEIPT_STORAGE_MODEL && filename == "receipt-000042.html"
+            })
+            .times(1)
+            .returning(|_, _, filename, _| Ok(filename));
+        storage_repository
+            .expect_save()
+            .withf(|model, _, filename, _| {
+                model == RECEIPT_STORAGE_MODEL && filename == "receipt-000042.pdf"
+            })
+            .times(1)
+            .returning(|_, _, filename, _| Ok(filename));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus
+            .expect_publish()
+            .withf(|events| {
+                events.len() == 1
+                    && events[0].event_type() == RECEIPT_ISSUED_EVENT_TYPE
+                    && events[0]
+                        .as_any()
+                        .downcast_ref::<ReceiptIssuedEvent>()
+                        .is_some_and(|event| event.number() == "42")
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let result = handler(
+            receipt_repository,
+            dona_repository,
+            storage_repository,
+            event_bus,
+        )
+        .handle(confirmed_event(&dona))
+        .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_skip_donas_that_already_have_a_receipt() {
+        let dona = dona_with_status("confirmed");
+        let receipt =
+            ReceiptMother::create(None, Some(dona.id()), Some(dona.user_id()), None, None);
+
+        let mut receipt_repository = MockReceiptRepository::new();
+        receipt_repository
+            .expect_find_by_dona_id()
+            .times(1)
+            .return_const(Ok(receipt));
+        receipt_repository.expect_last_number().times(0);
+        receipt_repository.expect_save().times(0);
+
+        let mut dona_repository = MockDonaRepository::new();
+        dona_repository.expect_find_by_id().times(0);
+
+        let mut storage_repository = MockFileStorageRepository::new();
+        storage_repository.expect_save().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let result = handler(
+            receipt_repository,
+            dona_repository,
+            storage_repository,
+            event_bus,
+        )
+        .handle(confirmed_event(&dona))
+        .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_dona_is_not_confirmed() {
+        let dona = dona_with_status("pending");
+
+        let mut receipt_repository = MockReceiptRepository::new();
+        receipt_repository
+            .expect_find_by_dona_id()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        receipt_repository.expect_save().times(0);
+
+        let mut dona_repository = MockDonaRepository::new();
+        dona_repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(dona.clone()));
+
+        let mut storage_repository = MockFileStorageRepository::new();
+        storage_repository.expect_save().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let result = handler(
+            receipt_repository,
+            dona_repository,
+            storage_repository,
+            event_bus,
+        )
+        .handle(confirmed_event(&dona))
+        .await;
+
+        assert_eq!(
+            result,
+            Err(EventError::new(ERR_RECEIPT_DONA_NOT_CONFIRMED.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_skip_a_receipt_issued_by_another_delivery_meanwhile() {
+        let dona = dona_with_status("confirmed");
+        let receipt =
+            ReceiptMother::create(None, Some(dona.id()), Some(dona.user_id()), None, None);
+
+        let mut receipt_repository = MockReceiptRepository::new();
+        let mut sequence = Sequence::new();
+        receipt_repository
+            .expect_find_by_dona_id()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        receipt_repository
+            .expect_last_number()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .return_const(Ok(0));
+        receipt_repository
+            .expect_save()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .return_const(Err(BaseRepositoryError::AlreadyExists));
+        receipt_repository
+            .expect_find_by_dona_id()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .return_const(Ok(receipt));
+        receipt_repository.expect_delete().times(0);
+
+        let mut dona_repository = MockDonaRepository::new();
+        dona_repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(dona.clone()));
+
+        let mut storage_repository = MockFileStorageRepository::new();
+        storage_repository.expect_save().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let result = handler(
+            receipt_repository,
+            dona_repository,
+            storage_repository,
+            event_bus,
+        )
+        .handle(confirmed_event(&dona))
+        .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_remove_the_receipt_when_a_document_cannot_be_stored() {
+        let dona = dona_with_status("confirmed");
+
+        let mut receipt_repository = MockReceiptRepository::new();
+        receipt_repository
+            .expect_find_by_dona_id()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        receipt_repository
+            .expect_last_number()
+            .times(1)
+            .return_const(Ok(0));
+        receipt_repository
+            .expect_save()
+            .times(1)
+            .return_const(Ok(()));
+        receipt_repository
+            .expect_delete()
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut dona_repository = MockDonaRepository::new();
+        dona_repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(dona.clone()));
+
+        let mut storage_repository = MockFileStorageRepository::new();
+        storage_repository
+            .expect_save()
+            .withf(|_, _, filename, _| filename == "receipt-000001.html")
+            .times(1)
+            .returning(|_, _, filename, _| Ok(filename));
+        storage_repository
+            .expect_save()
+            .withf(|_, _, filename, _| filename == "receipt-000001.pdf")
+            .times(1)
+            .returning(|_, _, _, _| Err("Storage unavailable".to_string()));
+        storage_repository
+            .expect_delete()
+            .withf(|model, _, filename| {
+                model == RECEIPT_STORAGE_MODEL && filename == "receipt-000001.html"
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let result = handler(
+            receipt_repository,
+            dona_repository,
+            storage_repository,
+            event_bus,
+        )
+        .handle(confirmed_event(&dona))
+        .await;
+
+        assert_eq!(
+            result,
+            Err(EventError::new("Storage unavailable".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_take_the_next_number_when_another_receipt_took_it() {
+        let dona = dona_with_status("confirmed");
+
+        let mut receipt_repository = MockReceiptRepository::new();
+        receipt_repository
+            .expect_find_by_dona_id()
+            .times(2)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        let mut sequence = Sequence::new();
+        receipt_repository
+            .expect_last_number()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .return_const(Ok(6));
+        receipt_repository
+            .expect_save()
+            .withf(|receipt| receipt.number() == 7)
+            .times(1)
+            .in_sequence(&mut sequence)
+            .return_const(Err(BaseRepositoryError::AlreadyExists));
+        receipt_repository
+            .expect_last_number()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .return_const(Ok(7));
+        receipt_repository
+            .expect_save()
+            .withf(|receipt| receipt.number() == 8)
+            .times(1)
+            .in_sequence(&mut sequence)
+            .return_const(Ok(()));
+
+        let mut dona_repository = MockDonaRepository::new();
+        dona_repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(dona.clone()));
+
+        let mut storage_repository = MockFileStorageRepository::new();
+        storage_repository
+            .expect_save()
+            .withf(|_, _, filename, _| filename.starts_with("receipt-000008."))
+            .times(2)
+            .returning(|_, _, filename, _| Ok(filename));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(1).return_const(Ok(()));
+
+        let result = handler(
+            receipt_repository,
+            dona_repository,
+            storage_repository,
+            event_bus,
+        )
+        .handle(confirmed_event(&dona))
+        .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+}
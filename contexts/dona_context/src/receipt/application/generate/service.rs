@@ -0,0 +1,173 @@
+use std::{
+    io::{Seek, SeekFrom, Write},
+    sync::Arc,
+};
+
+use shared::{
+    domain::{
+        base_errors::BaseRepositoryError,
+        bus::event::EventBus,
+        storage::FileStorageRepository,
+        utils::new_uuid,
+        value_objects::user_id::UserId,
+    },
+    RECEIPT_STORAGE_MODEL,
+};
+use time::OffsetDateTime;
+
+use crate::{
+    dona::domain::{
+        dona::{Dona, DonaId},
+        dona_repository::DonaRepository,
+    },
+    receipt::domain::{
+        receipt::{
+            Receipt, ReceiptId, ERR_RECEIPT_DONA_NOT_CONFIRMED, ERR_RECEIPT_NUMBER_UNAVAILABLE,
+        },
+        receipt_renderer::ReceiptRenderer,
+        receipt_repository::ReceiptRepository,
+    },
+};
+
+/// Times the next number is tried when other receipts of the recipient keep taking it.
+const MAX_NUMBER_ATTEMPTS: usize = 5;
+
+#[derive(Clone)]
+pub struct ReceiptGenerator {
+    receipt_repository: Arc<dyn ReceiptRepository>,
+    dona_repository: Arc<dyn DonaRepository>,
+    storage_repository: Arc<dyn FileStorageRepository>,
+    renderers: Vec<Arc<dyn ReceiptRenderer>>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl ReceiptGenerator {
+    pub fn new(
+        receipt_repository: Arc<dyn ReceiptRepository>,
+        dona_repository: Arc<dyn DonaRepository>,
+        storage_repository: Arc<dyn FileStorageRepository>,
+        renderers: Vec<Arc<dyn ReceiptRenderer>>,
+        event_bus: Arc<dyn EventBus>,
+    ) -> Self {
+        Self {
+            receipt_repository,
+            dona_repository,
+            storage_repository,
+            renderers,
+            event_bus,
+        }
+    }
+
+    /// Issues the receipt of a confirmed dona with the next number of its recipient and stores
+    /// one document per renderer. Donas that already have a receipt are skipped, so the event
+    /// can be delivered more than once. When a document cannot be stored the receipt is
+    /// removed again, leaving the number to the next receipt of the recipient.
+    pub async fn execute(&self, dona_id: String) -> Result<(), String> {
+        let dona_id = DonaId::new(dona_id)?;
+        match self.receipt_repository.find_by_dona_id(dona_id.clone()).await {
+            Ok(_) => return Ok(()),
+            Err(BaseRepositoryError::NotFound) => {}
+            Err(e) => return Err(e.to_string()),
+        }
+
+        let dona = self.dona_repository.find_by_id(dona_id).await?;
+        if !dona.is_confirmed() {
+            return Err(ERR_RECEIPT_DONA_NOT_CONFIRMED.to_string());
+        }
+
+        let Some(mut receipt) = self.issue(&dona).await? else {
+            return Ok(());
+        };
+
+        let mut stored = Vec::new();
+        for renderer in &self.renderers {
+            let filename = receipt.filename(renderer.format());
+            if let Err(e) = self
+                .store(&receipt, &dona, renderer.as_ref(), filename.clone())
+                .await
+            {
+                self.discard(&receipt, stored).await;
+                return Err(e);
+            }
+            stored.push(filename);
+        }
+
+        self.event_bus.publish(receipt.pull_events()).await?;
+
+        Ok(())
+    }
+
+    /// The row goes first, so a racing delivery of the event is stopped by the unique dona index
+    /// before any document is written. A number taken meanwhile by another receipt of the
+    /// recipient makes the insert conflict too, and the next one is tried. Empty when the dona
+    /// got its receipt from another delivery.
+    async fn issue(&self, dona: &Dona) -> Result<Option<Receipt>, String> {
+        let dona_id = DonaId::new(dona.id())?;
+        let user_id = UserId::new(dona.user_id())?;
+
+        for _ in 0..MAX_NUMBER_ATTEMPTS {
+            let number = self.receipt_repository.last_number(user_id.clone()).await? + 1;
+            let receipt = Receipt::create(
+                new_uuid(),
+                dona.id(),
+                dona.user_id(),
+                dona.sender_id(),
+                number,
+                OffsetDateTime::now_utc(),
+            )?;
+
+            match self.receipt_repository.save(&receipt).await {
+                Ok(()) => return Ok(Some(receipt)),
+                Err(BaseRepositoryError::AlreadyExists) => {}
+                Err(e) => return Err(e.to_string()),
+            }
+
+            match self.receipt_repository.find_by_dona_id(dona_id.clone()).await {
+                Ok(_) => return Ok(None),
+                Err(BaseRepositoryError::NotFound) => {}
+                Err(e) => return Err(e.to_string()),
+            }
+        }
+
+        Err(ERR_RECEIPT_NUMBER_UNAVAILABLE.to_string())
+    }
+
+    async fn store(
+        &self,
+        receipt: &Receipt,
+        dona: &Dona,
+        renderer: &dyn ReceiptRenderer,
+        filename: String,
+    ) -> Result<(), String> {
+        let content = renderer.render(receipt, dona)?;
+
+        let mut file = tempfile::tempfile().map_err(|e| e.to_string())?;
+        file.write_all(&content).map_err(|e| e.to_string())?;
+        file.seek(SeekFrom::Start(0)).map_err(|e| e.to_string())?;
+
+        self.storage_repository
+            .save(
+                RECEIPT_STORAGE_MODEL.to_string(),
+                receipt.id(),
+                filename,
+                file,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Best effort, the error that stopped the receipt is the one worth reporting.
+    async fn discard(&self, receipt: &Receipt, stored: Vec<String>) {
+        for filename in stored {
+            let _ = self
+                .storage_repository
+                .delete(RECEIPT_STORAGE_MODEL.to_string(), receipt.id(), filename)
+                .await;
+        }
+
+        if let Ok(id) = ReceiptId::new(receipt.id()) {
+            let _ = self.receipt_repository.delete(id).await;
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use shared::domain::bus::query::Response;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ReceiptFileResponse {
+    pub filename: String,
+    pub content_type: String,
+    pub content: Vec<u8>,
+}
+
+impl Response for ReceiptFileResponse {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
@@ -0,0 +1,4 @@
+pub mod receipt;
+pub mod receipt_issued_event;
+pub mod receipt_renderer;
+pub mod receipt_repository;
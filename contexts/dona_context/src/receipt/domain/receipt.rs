@@ -0,0 +1,290 @@
+use std::fmt::Display;
+use std::sync::Arc;
+
+use shared::domain::{bus::event::Event, utils::is_uuid, value_objects::user_id::UserId};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+use crate::dona::domain::dona::DonaId;
+
+use super::receipt_issued_event::ReceiptIssuedEvent;
+
+pub const ERR_RECEIPT_NOT_FOUND: &str = "Receipt not found";
+pub const ERR_RECEIPT_FORBIDDEN: &str = "Receipt can only be downloaded by its sender or recipient";
+pub const ERR_RECEIPT_DONA_NOT_CONFIRMED: &str = "Receipts are only issued for confirmed Donas";
+pub const ERR_RECEIPT_NUMBER_UNAVAILABLE: &str =
+    "No receipt number could be taken for the recipient, try again later";
+
+pub const ERR_INVALID_RECEIPT_ID: &str = "Invalid Receipt ID";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct ReceiptId(String);
+
+impl ReceiptId {
+    pub fn new(value: String) -> Result<Self, String> {
+        if is_uuid(&value) {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_RECEIPT_ID.to_string())
+        }
+    }
+}
+
+impl Display for ReceiptId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub const ERR_INVALID_RECEIPT_NUMBER: &str = "Invalid Receipt Number";
+
+/// Receipts are numbered from 1 for every recipient, without gaps.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ReceiptNumber(u64);
+
+impl ReceiptNumber {
+    pub fn new(value: u64) -> Result<Self, String> {
+        if value > 0 {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_RECEIPT_NUMBER.to_string())
+        }
+    }
+
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Display for ReceiptNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:06}", self.0)
+    }
+}
+
+pub const ERR_INVALID_RECEIPT_FORMAT: &str = "Invalid Receipt Format";
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum ReceiptFormat {
+    Html,
+    Pdf,
+}
+
+impl ReceiptFormat {
+    pub fn new(value: String) -> Result<Self, String> {
+        match value.as_str() {
+            "html" => Ok(Self::Html),
+            "pdf" => Ok(Self::Pdf),
+            _ => Err(ERR_INVALID_RECEIPT_FORMAT.to_string()),
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Html => "text/html; charset=utf-8",
+            Self::Pdf => "application/pdf",
+        }
+    }
+}
+
+impl Display for ReceiptFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Html => write!(f, "html"),
+            Self::Pdf => write!(f, "pdf"),
+        }
+    }
+}
+
+pub const ERR_INVALID_RECEIPT_ISSUED_AT: &str = "Invalid Receipt Issued At";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct ReceiptIssuedAt(OffsetDateTime);
+
+impl ReceiptIssuedAt {
+    pub fn new(value: OffsetDateTime) -> Result<Self, String> {
+        if value < OffsetDateTime::now_utc() {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_RECEIPT_ISSUED_AT.to_string())
+        }
+    }
+}
+
+impl Display for ReceiptIssuedAt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.format(&Rfc3339).unwrap())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Receipt {
+    id: ReceiptId,
+    dona_id: DonaId,
+    user_id: UserId,
+    sender_id: Option<UserId>,
+    number: ReceiptNumber,
+    issued_at: ReceiptIssuedAt,
+
+    events: Vec<Arc<dyn Event>>,
+}
+
+impl PartialEq for Receipt {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.dona_id == other.dona_id
+            && self.user_id == other.user_id
+            && self.sender_id == other.sender_id
+            && self.number == other.number
+            && self.issued_at == other.issued_at
+    }
+}
+
+impl Eq for Receipt {}
+
+impl Receipt {
+    pub(crate) fn new(
+        id: String,
+        dona_id: String,
+        user_id: String,
+        sender_id: Option<String>,
+        number: u64,
+        issued_at: OffsetDateTime,
+    ) -> Result<Self, String> {
+        Ok(Self {
+            id: ReceiptId::new(id)?,
+            dona_id: DonaId::new(dona_id)?,
+            user_id: UserId::new(user_id)?,
+            sender_id: sender_id.map(UserId::new).transpose()?,
+            number: ReceiptNumber::new(number)?,
+            issued_at: ReceiptIssuedAt::new(issued_at)?,
+            events: vec![],
+        })
+    }
+
+    pub fn create(
+        id: String,
+        dona_id: String,
+        user_id: String,
+        sender_id: Option<String>,
+        number: u64,
+        issued_at: OffsetDateTime,
+    ) -> Result<Self, String> {
+        let mut receipt = Self::new(id, dona_id, user_id, sender_id, number, issued_at)?;
+
+        let event = ReceiptIssuedEvent::new(
+            receipt.id(),
+            receipt.dona_id(),
+            receipt.user_id(),
+            receipt.number().to_string(),
+            receipt.issued_at.to_string(),
+        );
+        receipt.record(Arc::new(event));
+
+        Ok(receipt)
+    }
+
+    /// Guests have no account, so only the recipient can download the receipt of a guest dona.
+    pub fn can_be_downloaded_by(&self, user_id: &str) -> bool {
+        self.user_id.to_string() == user_id
+            || self
+                .sender_id
+                .as_ref()
+                .is_some_and(|sender_id| sender_id.to_string() == user_id)
+    }
+
+    pub fn filename(&self, format: ReceiptFormat) -> String {
+        format!("receipt-{}.{}", self.number, format)
+    }
+
+    pub fn record(&mut self, event: Arc<dyn Event>) {
+        self.events.push(event);
+    }
+
+    pub fn pull_events(&mut self) -> Vec<Arc<dyn Event>> {
+        let events = self.events.clone();
+        self.events = vec![];
+        events
+    }
+
+    pub fn id(&self) -> String {
+        self.id.to_string()
+    }
+
+    pub fn dona_id(&self) -> String {
+        self.dona_id.to_string()
+    }
+
+    pub fn user_id(&self) -> String {
+        self.user_id.to_string()
+    }
+
+    pub fn sender_id(&self) -> Option<String> {
+        self.sender_id.as_ref().map(|id| id.to_string())
+    }
+
+    pub fn number(&self) -> u64 {
+        self.number.value()
+    }
+
+    /// The number as printed on the document, zero padded.
+    pub fn display_number(&self) -> String {
+        self.number.to_string()
+    }
+
+    pub fn issued_at(&self) -> OffsetDateTime {
+        self.issued_at.0
+    }
+}
+
+pub mod tests {
+    use shared::domain::{utils::new_uuid, value_objects::user_id::tests::UserIdMother};
+    use time::Duration;
+
+    use crate::dona::domain::dona::tests::DonaIdMother;
+
+    use super::*;
+
+    pub struct ReceiptIdMother;
+
+    impl ReceiptIdMother {
+        pub fn random() -> ReceiptId {
+            ReceiptId::new(new_uuid()).unwrap()
+        }
+
+        pub fn create(value: Option<String>) -> ReceiptId {
+            match value {
+                Some(value) => ReceiptId::new(value).unwrap(),
+                None => Self::random(),
+            }
+        }
+    }
+
+    pub struct ReceiptMother;
+
+    impl ReceiptMother {
+        pub fn random() -> Receipt {
+            Self::create(None, None, None, None, None)
+        }
+
+        /// Receipts are issued a minute ago and numbered 1 unless told otherwise.
+        pub fn create(
+            id: Option<String>,
+            dona_id: Option<String>,
+            user_id: Option<String>,
+            sender_id: Option<String>,
+            number: Option<u64>,
+        ) -> Receipt {
+            Receipt {
+                id: ReceiptIdMother::create(id),
+                dona_id: DonaIdMother::create(dona_id),
+                user_id: UserIdMother::create(user_id),
+                sender_id: sender_id.map(|id| UserId::new(id).unwrap()),
+                number: ReceiptNumber::new(number.unwrap_or(1)).unwrap(),
+                issued_at: ReceiptIssuedAt::new(OffsetDateTime::now_utc() - Duration::minutes(1))
+                    .unwrap(),
+
+                events: vec![],
+            }
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use shared::domain::bus::event::{BaseEvent, Event, EventDeserializeError, EventSerialized};
+
+pub const RECEIPT_ISSUED_EVENT_TYPE: &str = "dona.receipt_issued";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct ReceiptIssuedEvent {
+    id: String,
+    dona_id: String,
+    user_id: String,
+    number: String,
+    issued_at: String,
+
+    base_event: BaseEvent,
+}
+
+impl ReceiptIssuedEvent {
+    pub fn new(id: String, dona_id: String, user_id: String, number: String, issued_at: String) -> Self {
+        Self {
+            id: id.clone(),
+            dona_id,
+            user_id,
+            number,
+            issued_at,
+            base_event: BaseEvent::new(id),
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn dona_id(&self) -> &str {
+        &self.dona_id
+    }
+
+    pub fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    pub fn number(&self) -> &str {
+        &self.number
+    }
+
+    pub fn issued_at(&self) -> &str {
+        &self.issued_at
+    }
+}
+
+impl Event for ReceiptIssuedEvent {
+    fn event_type(&self) -> &'static str {
+        RECEIPT_ISSUED_EVENT_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn from_primitives(
+        &self,
+        primitives: EventSerialized,
+    ) -> Result<Box<dyn Event>, EventDeserializeError> {
+        let data = primitives.data();
+        let base_event = BaseEvent::from_primitives(
+            primitives.event_id().to_string(),
+            primitives.aggregate_id().to_string(),
+            primitives.occurred_at().to_string(),
+        );
+
+        let id = data
+            .get("id")
+            .ok_or(EventDeserializeError::MissingField("id".to_string()))?;
+        let dona_id = data
+            .get("dona_id")
+            .ok_or(EventDeserializeError::MissingField("dona_id".to_string()))?;
+        let user_id = data
+            .get("user_id")
+            .ok_or(EventDeserializeError::MissingField("user_id".to_string()))?;
+        let number = data
+            .get("number")
+            .ok_or(EventDeserializeError::MissingField("number".to_string()))?;
+        let issued_at = data
+            .get("issued_at")
+            .ok_or(EventDeserializeError::MissingField("issued_at".to_string()))?;
+
+        Ok(Box::new(Self {
+            id: id.to_string(),
+            dona_id: dona_id.to_string(),
+            user_id: user_id.to_string(),
+            number: number.to_string(),
+            issued_at: issued_at.to_string(),
+            base_event,
+        }))
+    }
+
+    fn to_primitives(&self) -> EventSerialized {
+        EventSerialized::new(
+            self.base_event.event_id().to_string(),
+            self.base_event.aggregate_id().to_string(),
+            self.base_event.occurred_at().to_string(),
+            vec![
+                ("id".to_string(), self.id.clone()),
+                ("dona_id".to_string(), self.dona_id.clone()),
+                ("user_id".to_string(), self.user_id.clone()),
+                ("number".to_string(), self.number.clone()),
+                ("issued_at".to_string(), self.issued_at.clone()),
+            ]
+            .into_iter()
+            .collect(),
+        )
+    }
+}
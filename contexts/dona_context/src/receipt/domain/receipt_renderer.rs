@@ -0,0 +1,25 @@
+use crate::dona::domain::dona::Dona;
+
+use super::receipt::{Receipt, ReceiptFormat};
+
+/// Turns a receipt and the dona it was issued for into a downloadable document.
+pub trait ReceiptRenderer: Send + Sync {
+    fn format(&self) -> ReceiptFormat;
+    fn render(&self, receipt: &Receipt, dona: &Dona) -> Result<Vec<u8>, String>;
+}
+
+#[cfg(test)]
+pub mod tests {
+    use mockall::mock;
+
+    use super::*;
+
+    mock! {
+        pub ReceiptRenderer {}
+
+        impl ReceiptRenderer for ReceiptRenderer {
+            fn format(&self) -> ReceiptFormat;
+            fn render(&self, receipt: &Receipt, dona: &Dona) -> Result<Vec<u8>, String>;
+        }
+    }
+}
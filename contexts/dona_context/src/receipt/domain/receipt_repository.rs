@@ -0,0 +1,35 @@
+use shared::domain::{base_errors::BaseRepositoryError, value_objects::user_id::UserId};
+
+use crate::dona::domain::dona::DonaId;
+
+use super::receipt::{Receipt, ReceiptId};
+
+#[async_trait::async_trait]
+pub trait ReceiptRepository: Send + Sync {
+    async fn find_by_dona_id(&self, dona_id: DonaId) -> Result<Receipt, BaseRepositoryError>;
+    /// Highest receipt number issued to the recipient so far, 0 when there is none.
+    async fn last_number(&self, user_id: UserId) -> Result<u64, BaseRepositoryError>;
+    /// Fails with [`BaseRepositoryError::AlreadyExists`] when the dona already has a receipt or
+    /// the recipient already has one with the same number.
+    async fn save(&self, receipt: &Receipt) -> Result<(), BaseRepositoryError>;
+    async fn delete(&self, id: ReceiptId) -> Result<(), BaseRepositoryError>;
+}
+
+#[cfg(test)]
+pub mod tests {
+    use mockall::mock;
+
+    use super::*;
+
+    mock! {
+        pub ReceiptRepository {}
+
+        #[async_trait::async_trait]
+        impl ReceiptRepository for ReceiptRepository {
+            async fn find_by_dona_id(&self, dona_id: DonaId) -> Result<Receipt, BaseRepositoryError>;
+            async fn last_number(&self, user_id: UserId) -> Result<u64, BaseRepositoryError>;
+            async fn save(&self, receipt: &Receipt) -> Result<(), BaseRepositoryError>;
+            async fn delete(&self, id: ReceiptId) -> Result<(), BaseRepositoryError>;
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod persistence;
+pub mod rendering;
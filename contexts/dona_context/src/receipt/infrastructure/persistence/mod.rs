@@ -0,0 +1 @@
+pub mod sea_receipt_repo;
@@ -0,0 +1,114 @@
+use crate::{
+    dona::domain::dona::Dona,
+    receipt::domain::{
+        receipt::{Receipt, ReceiptFormat},
+        receipt_renderer::ReceiptRenderer,
+    },
+};
+
+use super::{receipt_fields, RECEIPT_TITLE};
+
+fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct HtmlReceiptRenderer;
+
+impl HtmlReceiptRenderer {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl ReceiptRenderer for HtmlReceiptRenderer {
+    fn format(&self) -> ReceiptFormat {
+        ReceiptFormat::Html
+    }
+
+    fn render(&self, receipt: &Receipt, dona: &Dona) -> Result<Vec<u8>, String> {
+        let rows = receipt_fields(receipt, dona)
+            .into_iter()
+            .map(|(label, value)| {
+                format!(
+                    "      <tr><th>{}</th><td>{}</td></tr>",
+                    label,
+                    escape_html(&value)
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        let html = format!(
+            r#"<!DOCTYPE html>
+<html lang="en">
+  <head>
+    <meta charset="utf-8">
+    <title>{title} {number}</title>
+    <style>
+      body {{ font-family: Helvetica, Arial, sans-serif; margin: 2rem; color: #222; }}
+      table {{ border-collapse: collapse; }}
+      th {{ text-align: left; padding: 0.3rem 1.5rem 0.3rem 0; vertical-align: top; }}
+      td {{ padding: 0.3rem 0; white-space: pre-wrap; }}
+    </style>
+  </head>
+  <body>
+    <h1>{title}</h1>
+    <table>
+{rows}
+    </table>
+  </body>
+</html>
+"#,
+            title = RECEIPT_TITLE,
+            number = receipt.display_number(),
+            rows = rows,
+        );
+
+        Ok(html.into_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        dona::domain::dona::tests::DonaMother, receipt::domain::receipt::tests::ReceiptMother,
+    };
+
+    use super::*;
+
+    #[test]
+    fn it_should_escape_the_dona_fields() {
+        let dona = DonaMother::guest(
+            None,
+            Some("<script>alert(1)</script>".to_string()),
+            None,
+            false,
+        );
+        let receipt = ReceiptMother::create(
+            None,
+            Some(dona.id()),
+            Some(dona.user_id()),
+            None,
+            Some(42),
+        );
+
+        let html = HtmlReceiptRenderer::new().render(&receipt, &dona).unwrap();
+        let html = String::from_utf8(html).unwrap();
+
+        assert!(html.contains("Dona receipt 000042"));
+        assert!(html.contains("&lt;script&gt;alert(1)&lt;/script&gt;"));
+        assert!(!html.contains("<script>"));
+    }
+}
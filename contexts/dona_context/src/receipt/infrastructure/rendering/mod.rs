@@ -0,0 +1,38 @@
+use time::format_description::well_known::Rfc3339;
+
+use crate::{dona::domain::dona::Dona, receipt::domain::receipt::Receipt};
+
+pub mod html_receipt_renderer;
+pub mod pdf_receipt_renderer;
+
+pub(crate) const RECEIPT_TITLE: &str = "Dona receipt";
+
+/// Label and value of every field printed on a receipt, in order. The message goes last since
+/// it is the only field that may span several lines.
+pub(crate) fn receipt_fields(receipt: &Receipt, dona: &Dona) -> Vec<(&'static str, String)> {
+    let donor = if dona.is_anonymous() {
+        "Anonymous".to_string()
+    } else {
+        dona.guest_name()
+            .or(dona.sender_id())
+            .unwrap_or("Guest".to_string())
+    };
+
+    vec![
+        ("Receipt number", receipt.display_number()),
+        (
+            "Issued at",
+            receipt.issued_at().format(&Rfc3339).unwrap_or_default(),
+        ),
+        ("Dona", dona.id()),
+        (
+            "Date",
+            dona.created_at().format(&Rfc3339).unwrap_or_default(),
+        ),
+        ("Donor", donor),
+        ("Recipient", dona.user_id()),
+        ("Amount", format!("{} {}", dona.amount(), dona.currency())),
+        ("Method", dona.method()),
+        ("Message", dona.msg()),
+    ]
+}
@@ -0,0 +1,204 @@
+use printpdf::{
+    BuiltinFont, CustomPdfConformance, IndirectFontRef, Mm, PdfConformance, PdfDocument,
+    PdfLayerReference, Pt,
+};
+
+use crate::{
+    dona::domain::dona::Dona,
+    receipt::domain::{
+        receipt::{Receipt, ReceiptFormat},
+        receipt_renderer::ReceiptRenderer,
+    },
+};
+
+use super::{receipt_fields, RECEIPT_TITLE};
+
+const PAGE_WIDTH: f32 = 595.0;
+const PAGE_HEIGHT: f32 = 842.0;
+const MARGIN: f32 = 56.0;
+const VALUE_OFFSET: f32 = 170.0;
+const LINE_HEIGHT: f32 = 18.0;
+const FONT_SIZE: f32 = 11.0;
+const TITLE_SIZE: f32 = 20.0;
+/// Helvetica at 11pt fits roughly this many characters between the value column and the margin.
+const MAX_LINE_CHARS: usize = 60;
+const LAYER: &str = "Receipt";
+
+fn wrap(value: &str) -> Vec<String> {
+    let mut lines = vec![];
+    for paragraph in value.lines() {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let mut word = word.to_string();
+            while word.chars().count() > MAX_LINE_CHARS {
+                if !line.is_empty() {
+                    lines.push(std::mem::take(&mut line));
+                }
+                let rest = word.split_off(
+                    word.char_indices()
+                        .nth(MAX_LINE_CHARS)
+                        .map(|(i, _)| i)
+                        .unwrap_or(word.len()),
+                );
+                lines.push(word);
+                word = rest;
+            }
+
+            if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > MAX_LINE_CHARS
+            {
+                lines.push(std::mem::take(&mut line));
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(&word);
+        }
+        lines.push(line);
+    }
+
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+
+    lines
+}
+
+fn text(layer: &PdfLayerReference, font: &IndirectFontRef, size: f32, x: f32, y: f32, value: &str) {
+    layer.use_text(value, size, Mm::from(Pt(x)), Mm::from(Pt(y)), font);
+}
+
+/// Text-only A4 documents using the standard Helvetica fonts, which every viewer ships, so
+/// nothing has to be embedded. Characters outside of WinAnsiEncoding are left out.
+#[derive(Debug, Clone, Default)]
+pub struct PdfReceiptRenderer;
+
+impl PdfReceiptRenderer {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl ReceiptRenderer for PdfReceiptRenderer {
+    fn format(&self) -> ReceiptFormat {
+        ReceiptFormat::Pdf
+    }
+
+    fn render(&self, receipt: &Receipt, dona: &Dona) -> Result<Vec<u8>, String> {
+        let page_width = Mm::from(Pt(PAGE_WIDTH));
+        let page_height = Mm::from(Pt(PAGE_HEIGHT));
+        let (doc, page, layer) = PdfDocument::new(RECEIPT_TITLE, page_width, page_height, LAYER);
+        // The print conformance printpdf defaults to embeds a colour profile of several hundred
+        // kilobytes, a screen document of black text needs none of it.
+        let doc = doc.with_conformance(PdfConformance::Custom(CustomPdfConformance {
+            allows_default_fonts: true,
+            ..Default::default()
+        }));
+        let regular = doc
+            .add_builtin_font(BuiltinFont::Helvetica)
+            .map_err(|e| e.to_string())?;
+        let bold = doc
+            .add_builtin_font(BuiltinFont::HelveticaBold)
+            .map_err(|e| e.to_string())?;
+
+        let mut layer = doc.get_page(page).get_layer(layer);
+        text(
+            &layer,
+            &bold,
+            TITLE_SIZE,
+            MARGIN,
+            PAGE_HEIGHT - MARGIN - TITLE_SIZE,
+            RECEIPT_TITLE,
+        );
+        let mut y = PAGE_HEIGHT - MARGIN - TITLE_SIZE - 2.0 * LINE_HEIGHT;
+
+        for (label, value) in receipt_fields(receipt, dona) {
+            for (i, line) in wrap(&value).iter().enumerate() {
+                if y < MARGIN {
+                    let (page, next) = doc.add_page(page_width, page_height, LAYER);
+                    layer = doc.get_page(page).get_layer(next);
+                    y = PAGE_HEIGHT - MARGIN - FONT_SIZE;
+                }
+                if i == 0 {
+                    text(&layer, &bold, FONT_SIZE, MARGIN, y, label);
+                }
+                text(&layer, &regular, FONT_SIZE, MARGIN + VALUE_OFFSET, y, line);
+                y -= LINE_HEIGHT;
+            }
+        }
+
+        doc.save_to_bytes().map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        dona::domain::dona::tests::DonaMother, receipt::domain::receipt::tests::ReceiptMother,
+    };
+
+    use super::*;
+
+    #[test]
+    fn it_should_wrap_long_values() {
+        let lines = wrap(&"word ".repeat(40));
+        assert!(lines.len() > 1);
+        assert!(lines
+            .iter()
+            .all(|line| line.chars().count() <= MAX_LINE_CHARS));
+
+        let lines = wrap(&"x".repeat(MAX_LINE_CHARS * 2 + 1));
+        assert_eq!(3, lines.len());
+    }
+
+    #[test]
+    fn it_should_render_a_pdf_with_the_receipt_fields() {
+        let dona = DonaMother::random();
+        let receipt = ReceiptMother::create(
+            None,
+            Some(dona.id()),
+            Some(dona.user_id()),
+            dona.sender_id(),
+            Some(7),
+        );
+
+        let pdf = PdfReceiptRenderer::new().render(&receipt, &dona).unwrap();
+
+        assert!(pdf.len() < 16 * 1024);
+        let pdf = lopdf::Document::load_mem(&pdf).unwrap();
+        assert_eq!(1, pdf.get_pages().len());
+        let text = pdf.extract_text(&[1]).unwrap();
+        assert!(text.contains(RECEIPT_TITLE));
+        assert!(text.contains("000007"));
+        assert!(text.contains(&dona.id()));
+    }
+
+    #[test]
+    fn it_should_add_pages_for_long_messages() {
+        let dona = DonaMother::create(
+            None,
+            Some("lorem ipsum ".repeat(400)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let receipt = ReceiptMother::create(
+            None,
+            Some(dona.id()),
+            Some(dona.user_id()),
+            dona.sender_id(),
+            None,
+        );
+
+        let pdf = PdfReceiptRenderer::new().render(&receipt, &dona).unwrap();
+        let pdf = lopdf::Document::load_mem(&pdf).unwrap();
+
+        assert!(pdf.get_pages().len() > 1);
+    }
+}
@@ -0,0 +1,145 @@
+use shared::domain::bus::query::{Query, QueryError, QueryHandler, Response};
+
+use super::service::RecipientSettingsFinder;
+
+pub const FIND_RECIPIENT_SETTINGS_QUERY_TYPE: &str = "dona.find_recipient_settings.query";
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FindRecipientSettingsQuery {
+    pub user_id: String,
+}
+
+impl Query for FindRecipientSettingsQuery {
+    fn query_type(&self) -> &'static str {
+        FIND_RECIPIENT_SETTINGS_QUERY_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct FindRecipientSettingsQueryHandler {
+    service: RecipientSettingsFinder,
+}
+
+impl FindRecipientSettingsQueryHandler {
+    pub fn new(service: RecipientSettingsFinder) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler for FindRecipientSettingsQueryHandler {
+    async fn handle(&self, query: Box<dyn Query>) -> Result<Box<dyn Response>, QueryError> {
+        let query = query
+            .as_any()
+            .downcast_ref::<FindRecipientSettingsQuery>()
+            .ok_or_else(|| QueryError::new("Invalid query".to_string()))?;
+
+        let settings = self
+            .service
+            .execute(query.user_id.to_owned())
+            .await
+            .map_err(QueryError::new)?;
+
+        Ok(Box::new(settings))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockall::predicate;
+    use rust_decimal_macros::dec;
+    use shared::domain::{
+        base_errors::BaseRepositoryError, utils::new_uuid, value_objects::user_id::UserId,
+    };
+
+    use super::*;
+
+    use crate::recipient::application::response::RecipientSettingsResponse;
+    use crate::recipient::domain::recipient_settings::tests::RecipientSettingsMother;
+    use crate::recipient::domain::recipient_settings_repository::tests::MockRecipientSettingsRepository;
+
+    async fn find(
+        repository: MockRecipientSettingsRepository,
+        user_id: String,
+    ) -> Result<RecipientSettingsResponse, QueryError> {
+        let handler = FindRecipientSettingsQueryHandler::new(RecipientSettingsFinder::new(
+            Arc::new(repository),
+        ));
+
+        handler
+            .handle(Box::new(FindRecipientSettingsQuery { user_id }))
+            .await
+            .map(|response| {
+                response
+                    .as_any()
+                    .downcast_ref::<RecipientSettingsResponse>()
+                    .unwrap()
+                    .clone()
+            })
+    }
+
+    #[tokio::test]
+    async fn it_should_find_recipient_settings() {
+        let settings = RecipientSettingsMother::create(
+            None,
+            Some(dec!(2.00)),
+            Some(dec!(500.00)),
+            Some("EUR"),
+            vec!["PAYPAL"],
+            false,
+        );
+
+        let mut repository = MockRecipientSettingsRepository::new();
+        repository
+            .expect_find_by_user_id()
+            .with(predicate::eq(UserId::new(settings.user_id()).unwrap()))
+            .times(1)
+            .return_const(Ok(settings.clone()));
+
+        let result = find(repository, settings.user_id()).await;
+
+        assert_eq!(result, Ok(RecipientSettingsResponse::from(settings)));
+    }
+
+    #[tokio::test]
+    async fn it_should_return_defaults_when_recipient_has_no_settings() {
+        let user_id = new_uuid();
+
+        let mut repository = MockRecipientSettingsRepository::new();
+        repository
+            .expect_find_by_user_id()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+
+        let result = find(repository, user_id.clone()).await.unwrap();
+
+        assert_eq!(result.active_methods, vec!["MANUAL", "PAYPAL", "CRYPTO"]);
+        assert!(result.messages_allowed);
+        assert_eq!(result.min_amount, None);
+        assert_eq!(result.updated_at, None);
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_repository_fails() {
+        let mut repository = MockRecipientSettingsRepository::new();
+        repository
+            .expect_find_by_user_id()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::ConnectionError));
+
+        let result = find(repository, new_uuid()).await;
+
+        assert_eq!(
+            result,
+            Err(QueryError::new(
+                BaseRepositoryError::ConnectionError.to_string()
+            ))
+        );
+    }
+}
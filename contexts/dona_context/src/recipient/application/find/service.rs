@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use shared::domain::{base_errors::BaseRepositoryError, value_objects::user_id::UserId};
+
+use crate::recipient::{
+    application::response::RecipientSettingsResponse,
+    domain::recipient_settings_repository::RecipientSettingsRepository,
+};
+
+#[derive(Clone)]
+pub struct RecipientSettingsFinder {
+    repository: Arc<dyn RecipientSettingsRepository>,
+}
+
+impl RecipientSettingsFinder {
+    pub fn new(repository: Arc<dyn RecipientSettingsRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, user_id: String) -> Result<RecipientSettingsResponse, String> {
+        match self
+            .repository
+            .find_by_user_id(UserId::new(user_id.clone())?)
+            .await
+        {
+            Ok(settings) => Ok(RecipientSettingsResponse::from(settings)),
+            Err(BaseRepositoryError::NotFound) => Ok(RecipientSettingsResponse::defaults(user_id)),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod find;
+pub mod response;
+pub mod update;
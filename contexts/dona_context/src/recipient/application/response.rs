@@ -0,0 +1,59 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use shared::domain::bus::query::Response;
+use time::OffsetDateTime;
+
+use crate::{
+    recipient::domain::recipient_settings::RecipientSettings,
+    shared::domain::dona::DonaOptionMethod,
+};
+
+/// `updated_at` is empty while the recipient has not saved any settings.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RecipientSettingsResponse {
+    pub user_id: String,
+    pub min_amount: Option<Decimal>,
+    pub max_amount: Option<Decimal>,
+    pub currency: Option<String>,
+    pub active_methods: Vec<String>,
+    pub messages_allowed: bool,
+    pub updated_at: Option<OffsetDateTime>,
+}
+
+impl RecipientSettingsResponse {
+    /// What applies to recipients without settings.
+    pub fn defaults(user_id: String) -> Self {
+        Self {
+            user_id,
+            min_amount: None,
+            max_amount: None,
+            currency: None,
+            active_methods: DonaOptionMethod::all()
+                .iter()
+                .map(|method| method.to_string())
+                .collect(),
+            messages_allowed: true,
+            updated_at: None,
+        }
+    }
+}
+
+impl From<RecipientSettings> for RecipientSettingsResponse {
+    fn from(settings: RecipientSettings) -> Self {
+        Self {
+            user_id: settings.user_id(),
+            min_amount: settings.min_amount(),
+            max_amount: settings.max_amount(),
+            currency: settings.currency(),
+            active_methods: settings.active_methods(),
+            messages_allowed: settings.messages_allowed(),
+            updated_at: Some(settings.updated_at()),
+        }
+    }
+}
+
+impl Response for RecipientSettingsResponse {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
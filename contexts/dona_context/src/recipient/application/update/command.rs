@@ -0,0 +1,287 @@
+use rust_decimal::Decimal;
+use shared::domain::bus::command::{Command, CommandError, CommandHandler};
+use time::OffsetDateTime;
+
+use super::service::RecipientSettingsUpdater;
+
+pub const UPDATE_RECIPIENT_SETTINGS_COMMAND_TYPE: &str = "dona.update_recipient_settings.command";
+
+/// `currency` is required when a minimum or a maximum is given.
+#[derive(Debug)]
+pub struct UpdateRecipientSettingsCommand {
+    pub user_id: String,
+    pub min_amount: Option<Decimal>,
+    pub max_amount: Option<Decimal>,
+    pub currency: Option<String>,
+    pub active_methods: Vec<String>,
+    pub messages_allowed: bool,
+    pub updated_at: OffsetDateTime,
+}
+
+impl Command for UpdateRecipientSettingsCommand {
+    fn command_type(&self) -> &'static str {
+        UPDATE_RECIPIENT_SETTINGS_COMMAND_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct UpdateRecipientSettingsCommandHandler {
+    service: RecipientSettingsUpdater,
+}
+
+impl UpdateRecipientSettingsCommandHandler {
+    pub fn new(service: RecipientSettingsUpdater) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler for UpdateRecipientSettingsCommandHandler {
+    async fn handle(&self, command: Box<dyn Command>) -> Result<(), CommandError> {
+        let command = command
+            .as_any()
+            .downcast_ref::<UpdateRecipientSettingsCommand>()
+            .ok_or_else(|| CommandError::new("Invalid command".to_string()))?;
+
+        self.service
+            .execute(
+                command.user_id.to_owned(),
+                command.min_amount,
+                command.max_amount,
+                command.currency.to_owned(),
+                command.active_methods.to_owned(),
+                command.messages_allowed,
+                command.updated_at,
+            )
+            .await
+            .map_err(CommandError::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockall::predicate;
+    use rust_decimal_macros::dec;
+    use shared::domain::{
+        base_errors::BaseRepositoryError, bus::event::tests::MockEventBus, utils::new_uuid,
+        value_objects::user_id::UserId,
+    };
+
+    use super::*;
+
+    use crate::recipient::domain::recipient_settings::tests::RecipientSettingsMother;
+    use crate::recipient::domain::recipient_settings::{
+        ERR_INVALID_RECIPIENT_ACTIVE_METHODS, ERR_INVALID_RECIPIENT_DONA_LIMITS,
+    };
+    use crate::recipient::domain::recipient_settings_repository::tests::MockRecipientSettingsRepository;
+    use crate::recipient::domain::recipient_settings_updated_event::{
+        RecipientSettingsUpdatedEvent, RECIPIENT_SETTINGS_UPDATED_EVENT_TYPE,
+    };
+
+    fn handler(
+        repository: MockRecipientSettingsRepository,
+        event_bus: MockEventBus,
+    ) -> UpdateRecipientSettingsCommandHandler {
+        UpdateRecipientSettingsCommandHandler::new(RecipientSettingsUpdater::new(
+            Arc::new(repository),
+            Arc::new(event_bus),
+        ))
+    }
+
+    fn updated_event_bus(min_amount: &'static str, active_methods: &'static str) -> MockEventBus {
+        let mut event_bus = MockEventBus::new();
+        event_bus
+            .expect_publish()
+            .withf(move |events| {
+                events.len() == 1
+                    && events[0].event_type() == RECIPIENT_SETTINGS_UPDATED_EVENT_TYPE
+                    && events[0]
+                        .as_any()
+                        .downcast_ref::<RecipientSettingsUpdatedEvent>()
+                        .is_some_and(|event| {
+                            event.min_amount() == min_amount
+                                && event.active_methods() == active_methods
+                        })
+            })
+            .times(1)
+            .return_const(Ok(()));
+        event_bus
+    }
+
+    fn command(
+        user_id: String,
+        min_amount: Option<Decimal>,
+        max_amount: Option<Decimal>,
+        currency: Option<&str>,
+        active_methods: Vec<&str>,
+    ) -> Box<UpdateRecipientSettingsCommand> {
+        Box::new(UpdateRecipientSettingsCommand {
+            user_id,
+            min_amount,
+            max_amount,
+            currency: currency.map(str::to_string),
+            active_methods: active_methods.into_iter().map(str::to_string).collect(),
+            messages_allowed: true,
+            updated_at: OffsetDateTime::now_utc(),
+        })
+    }
+
+    #[tokio::test]
+    async fn it_should_create_settings_on_first_update() {
+        let user_id = new_uuid();
+
+        let mut repository = MockRecipientSettingsRepository::new();
+        repository
+            .expect_find_by_user_id()
+            .with(predicate::eq(UserId::new(user_id.clone()).unwrap()))
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        let expected_user_id = user_id.clone();
+        repository
+            .expect_save()
+            .withf(move |settings| {
+                settings.user_id() == expected_user_id
+                    && settings.min_amount() == Some(dec!(3.00))
+                    && settings.currency() == Some("USD".to_string())
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let result = handler(repository, updated_event_bus("3.00", "PAYPAL"))
+            .handle(command(
+                user_id,
+                Some(dec!(3.00)),
+                None,
+                Some("USD"),
+                vec!["PAYPAL", "PAYPAL"],
+            ))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_update_existing_settings() {
+        let settings = RecipientSettingsMother::create(
+            None,
+            Some(dec!(1.00)),
+            Some(dec!(100.00)),
+            Some("EUR"),
+            vec!["MANUAL"],
+            false,
+        );
+
+        let mut repository = MockRecipientSettingsRepository::new();
+        repository
+            .expect_find_by_user_id()
+            .times(1)
+            .return_const(Ok(settings.clone()));
+        repository
+            .expect_save()
+            .withf(|settings| {
+                settings.limits().is_none()
+                    && settings.messages_allowed()
+                    && settings.active_methods() == vec!["MANUAL", "CRYPTO"]
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let result = handler(repository, updated_event_bus("", "MANUAL,CRYPTO"))
+            .handle(command(
+                settings.user_id(),
+                None,
+                None,
+                None,
+                vec!["MANUAL", "CRYPTO"],
+            ))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_settings_are_invalid() {
+        for (min_amount, max_amount, currency, active_methods, error) in [
+            (
+                Some(dec!(10.00)),
+                Some(dec!(5.00)),
+                Some("USD"),
+                vec!["PAYPAL"],
+                ERR_INVALID_RECIPIENT_DONA_LIMITS,
+            ),
+            (
+                Some(dec!(0)),
+                None,
+                Some("USD"),
+                vec!["PAYPAL"],
+                ERR_INVALID_RECIPIENT_DONA_LIMITS,
+            ),
+            (
+                Some(dec!(5.00)),
+                None,
+                None,
+                vec!["PAYPAL"],
+                ERR_INVALID_RECIPIENT_DONA_LIMITS,
+            ),
+            (
+                None,
+                None,
+                None,
+                vec![],
+                ERR_INVALID_RECIPIENT_ACTIVE_METHODS,
+            ),
+        ] {
+            let mut repository = MockRecipientSettingsRepository::new();
+            repository
+                .expect_find_by_user_id()
+                .times(1)
+                .return_const(Err(BaseRepositoryError::NotFound));
+            repository.expect_save().times(0);
+
+            let mut event_bus = MockEventBus::new();
+            event_bus.expect_publish().times(0);
+
+            let result = handler(repository, event_bus)
+                .handle(command(
+                    new_uuid(),
+                    min_amount,
+                    max_amount,
+                    currency,
+                    active_methods,
+                ))
+                .await;
+
+            assert_eq!(result, Err(CommandError::new(error.to_string())));
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_repository_fails() {
+        let mut repository = MockRecipientSettingsRepository::new();
+        repository
+            .expect_find_by_user_id()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::ConnectionError));
+        repository.expect_save().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let result = handler(repository, event_bus)
+            .handle(command(new_uuid(), None, None, None, vec!["PAYPAL"]))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(
+                BaseRepositoryError::ConnectionError.to_string()
+            ))
+        );
+    }
+}
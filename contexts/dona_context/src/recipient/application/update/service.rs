@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use shared::domain::{
+    base_errors::BaseRepositoryError, bus::event::EventBus, value_objects::user_id::UserId,
+};
+use time::OffsetDateTime;
+
+use crate::recipient::domain::{
+    recipient_settings::RecipientSettings,
+    recipient_settings_repository::RecipientSettingsRepository,
+};
+
+#[derive(Clone)]
+pub struct RecipientSettingsUpdater {
+    repository: Arc<dyn RecipientSettingsRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl RecipientSettingsUpdater {
+    pub fn new(
+        repository: Arc<dyn RecipientSettingsRepository>,
+        event_bus: Arc<dyn EventBus>,
+    ) -> Self {
+        Self {
+            repository,
+            event_bus,
+        }
+    }
+
+    /// Settings are created on the first update, until then the defaults apply.
+    pub async fn execute(
+        &self,
+        user_id: String,
+        min_amount: Option<Decimal>,
+        max_amount: Option<Decimal>,
+        currency: Option<String>,
+        active_methods: Vec<String>,
+        messages_allowed: bool,
+        updated_at: OffsetDateTime,
+    ) -> Result<(), String> {
+        let mut settings = match self
+            .repository
+            .find_by_user_id(UserId::new(user_id.clone())?)
+            .await
+        {
+            Ok(mut settings) => {
+                settings.update(
+                    min_amount,
+                    max_amount,
+                    currency,
+                    active_methods,
+                    messages_allowed,
+                    updated_at,
+                )?;
+                settings
+            }
+            Err(BaseRepositoryError::NotFound) => RecipientSettings::create(
+                user_id,
+                min_amount,
+                max_amount,
+                currency,
+                active_methods,
+                messages_allowed,
+                updated_at,
+            )?,
+            Err(e) => return Err(e.to_string()),
+        };
+
+        self.repository.save(&settings).await?;
+
+        self.event_bus.publish(settings.pull_events()).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,3 @@
+pub mod recipient_settings;
+pub mod recipient_settings_repository;
+pub mod recipient_settings_updated_event;
@@ -0,0 +1,362 @@
+use std::fmt::Display;
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use shared::domain::{
+    bus::event::Event,
+    value_objects::{
+        money::{Currency, Money},
+        user_id::UserId,
+    },
+};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+use crate::shared::domain::dona::DonaOptionMethod;
+
+use super::recipient_settings_updated_event::RecipientSettingsUpdatedEvent;
+
+pub const ERR_DONA_AMOUNT_BELOW_RECIPIENT_MINIMUM: &str =
+    "The amount of the dona is below the minimum accepted by the recipient";
+pub const ERR_DONA_AMOUNT_ABOVE_RECIPIENT_MAXIMUM: &str =
+    "The amount of the dona is above the maximum accepted by the recipient";
+pub const ERR_RECIPIENT_METHOD_NOT_ACTIVE: &str =
+    "The recipient is not taking donas through this payment method";
+
+pub const ERR_INVALID_RECIPIENT_DONA_LIMITS: &str = "Invalid Recipient Dona Limits";
+
+/// Smallest and largest dona a recipient takes, in a single currency. Donas in other
+/// currencies are converted before being compared.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct RecipientDonaLimits {
+    min_amount: Option<Money>,
+    max_amount: Option<Money>,
+    currency: Currency,
+}
+
+impl RecipientDonaLimits {
+    pub fn new(
+        min_amount: Option<Decimal>,
+        max_amount: Option<Decimal>,
+        currency: String,
+    ) -> Result<Self, String> {
+        let money = |amount: Option<Decimal>| -> Result<Option<Money>, String> {
+            match amount {
+                Some(amount) if amount <= Decimal::ZERO => {
+                    Err(ERR_INVALID_RECIPIENT_DONA_LIMITS.to_string())
+                }
+                Some(amount) => Ok(Some(Money::new(amount, currency.clone())?)),
+                None => Ok(None),
+            }
+        };
+        let min_amount = money(min_amount)?;
+        let max_amount = money(max_amount)?;
+
+        match (&min_amount, &max_amount) {
+            (None, None) => return Err(ERR_INVALID_RECIPIENT_DONA_LIMITS.to_string()),
+            (Some(min), Some(max)) if min.amount() > max.amount() => {
+                return Err(ERR_INVALID_RECIPIENT_DONA_LIMITS.to_string())
+            }
+            _ => {}
+        }
+
+        Ok(Self {
+            min_amount,
+            max_amount,
+            currency: Currency::new(currency)?,
+        })
+    }
+
+    /// `amount` must already be in the currency of the limits.
+    pub fn check(&self, amount: Decimal) -> Result<(), String> {
+        if self
+            .min_amount
+            .as_ref()
+            .is_some_and(|min| amount < min.amount())
+        {
+            return Err(ERR_DONA_AMOUNT_BELOW_RECIPIENT_MINIMUM.to_string());
+        }
+        if self
+            .max_amount
+            .as_ref()
+            .is_some_and(|max| amount > max.amount())
+        {
+            return Err(ERR_DONA_AMOUNT_ABOVE_RECIPIENT_MAXIMUM.to_string());
+        }
+
+        Ok(())
+    }
+
+    pub fn min_amount(&self) -> Option<Decimal> {
+        self.min_amount.as_ref().map(Money::amount)
+    }
+
+    pub fn max_amount(&self) -> Option<Decimal> {
+        self.max_amount.as_ref().map(Money::amount)
+    }
+
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+}
+
+pub const ERR_INVALID_RECIPIENT_ACTIVE_METHODS: &str = "Invalid Recipient Active Methods";
+
+/// At least one method stays active, a recipient that wants no donas can remove their
+/// payment methods instead.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct RecipientActiveMethods(Vec<DonaOptionMethod>);
+
+impl RecipientActiveMethods {
+    pub fn new(value: Vec<String>) -> Result<Self, String> {
+        let mut methods: Vec<DonaOptionMethod> = vec![];
+        for method in value {
+            let method = DonaOptionMethod::new(method)?;
+            if !methods.contains(&method) {
+                methods.push(method);
+            }
+        }
+
+        if methods.is_empty() {
+            Err(ERR_INVALID_RECIPIENT_ACTIVE_METHODS.to_string())
+        } else {
+            Ok(Self(methods))
+        }
+    }
+}
+
+pub const ERR_INVALID_RECIPIENT_SETTINGS_UPDATED_AT: &str = "Invalid Recipient Settings Updated At";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct RecipientSettingsUpdatedAt(OffsetDateTime);
+
+impl RecipientSettingsUpdatedAt {
+    pub fn new(value: OffsetDateTime) -> Result<Self, String> {
+        if value < OffsetDateTime::now_utc() {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_RECIPIENT_SETTINGS_UPDATED_AT.to_string())
+        }
+    }
+}
+
+impl Display for RecipientSettingsUpdatedAt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.format(&Rfc3339).unwrap())
+    }
+}
+
+/// How a user takes donas as a recipient. Users that never saved any take donas of any
+/// amount, through every method they set up, with messages.
+#[derive(Debug, Clone)]
+pub struct RecipientSettings {
+    user_id: UserId,
+    limits: Option<RecipientDonaLimits>,
+    active_methods: RecipientActiveMethods,
+    messages_allowed: bool,
+    updated_at: RecipientSettingsUpdatedAt,
+
+    events: Vec<Arc<dyn Event>>,
+}
+
+impl PartialEq for RecipientSettings {
+    fn eq(&self, other: &Self) -> bool {
+        self.user_id == other.user_id
+            && self.limits == other.limits
+            && self.active_methods == other.active_methods
+            && self.messages_allowed == other.messages_allowed
+            && self.updated_at == other.updated_at
+    }
+}
+
+impl Eq for RecipientSettings {}
+
+/// Limits are only kept when a minimum or a maximum is given, and then need a currency.
+fn limits(
+    min_amount: Option<Decimal>,
+    max_amount: Option<Decimal>,
+    currency: Option<String>,
+) -> Result<Option<RecipientDonaLimits>, String> {
+    if min_amount.is_none() && max_amount.is_none() {
+        return Ok(None);
+    }
+    let currency = currency.ok_or(ERR_INVALID_RECIPIENT_DONA_LIMITS.to_string())?;
+
+    RecipientDonaLimits::new(min_amount, max_amount, currency).map(Some)
+}
+
+impl RecipientSettings {
+    pub(crate) fn new(
+        user_id: String,
+        min_amount: Option<Decimal>,
+        max_amount: Option<Decimal>,
+        currency: Option<String>,
+        active_methods: Vec<String>,
+        messages_allowed: bool,
+        updated_at: OffsetDateTime,
+    ) -> Result<Self, String> {
+        Ok(Self {
+            user_id: UserId::new(user_id)?,
+            limits: limits(min_amount, max_amount, currency)?,
+            active_methods: RecipientActiveMethods::new(active_methods)?,
+            messages_allowed,
+            updated_at: RecipientSettingsUpdatedAt::new(updated_at)?,
+            events: vec![],
+        })
+    }
+
+    pub fn create(
+        user_id: String,
+        min_amount: Option<Decimal>,
+        max_amount: Option<Decimal>,
+        currency: Option<String>,
+        active_methods: Vec<String>,
+        messages_allowed: bool,
+        updated_at: OffsetDateTime,
+    ) -> Result<Self, String> {
+        let mut settings = Self::new(
+            user_id,
+            min_amount,
+            max_amount,
+            currency,
+            active_methods,
+            messages_allowed,
+            updated_at,
+        )?;
+        settings.record_updated();
+
+        Ok(settings)
+    }
+
+    pub fn update(
+        &mut self,
+        min_amount: Option<Decimal>,
+        max_amount: Option<Decimal>,
+        currency: Option<String>,
+        active_methods: Vec<String>,
+        messages_allowed: bool,
+        updated_at: OffsetDateTime,
+    ) -> Result<(), String> {
+        self.limits = limits(min_amount, max_amount, currency)?;
+        self.active_methods = RecipientActiveMethods::new(active_methods)?;
+        self.messages_allowed = messages_allowed;
+        self.updated_at = RecipientSettingsUpdatedAt::new(updated_at)?;
+        self.record_updated();
+
+        Ok(())
+    }
+
+    fn record_updated(&mut self) {
+        let event = RecipientSettingsUpdatedEvent::new(
+            self.user_id(),
+            self.min_amount().map(|a| a.to_string()).unwrap_or_default(),
+            self.max_amount().map(|a| a.to_string()).unwrap_or_default(),
+            self.currency().unwrap_or_default(),
+            self.active_methods().join(","),
+            self.messages_allowed.to_string(),
+            self.updated_at.to_string(),
+        );
+        self.record(Arc::new(event));
+    }
+
+    pub fn check_method(&self, method: &DonaOptionMethod) -> Result<(), String> {
+        if self.active_methods.0.contains(method) {
+            Ok(())
+        } else {
+            Err(ERR_RECIPIENT_METHOD_NOT_ACTIVE.to_string())
+        }
+    }
+
+    pub fn record(&mut self, event: Arc<dyn Event>) {
+        self.events.push(event);
+    }
+
+    pub fn pull_events(&mut self) -> Vec<Arc<dyn Event>> {
+        let events = self.events.clone();
+        self.events = vec![];
+        events
+    }
+
+    pub fn user_id(&self) -> String {
+        self.user_id.to_string()
+    }
+
+    pub fn limits(&self) -> Option<&RecipientDonaLimits> {
+        self.limits.as_ref()
+    }
+
+    pub fn min_amount(&self) -> Option<Decimal> {
+        self.limits.as_ref().and_then(|l| l.min_amount())
+    }
+
+    pub fn max_amount(&self) -> Option<Decimal> {
+        self.limits.as_ref().and_then(|l| l.max_amount())
+    }
+
+    pub fn currency(&self) -> Option<String> {
+        self.limits.as_ref().map(|l| l.currency().to_string())
+    }
+
+    pub fn active_methods(&self) -> Vec<String> {
+        self.active_methods
+            .0
+            .iter()
+            .map(|method| method.to_string())
+            .collect()
+    }
+
+    pub fn messages_allowed(&self) -> bool {
+        self.messages_allowed
+    }
+
+    pub fn updated_at(&self) -> OffsetDateTime {
+        self.updated_at.0
+    }
+}
+
+pub mod tests {
+    use shared::domain::value_objects::user_id::tests::UserIdMother;
+    use time::Duration;
+
+    use super::*;
+
+    pub struct RecipientSettingsMother;
+
+    impl RecipientSettingsMother {
+        pub fn random() -> RecipientSettings {
+            Self::create(
+                None,
+                None,
+                None,
+                None,
+                vec!["MANUAL", "PAYPAL", "CRYPTO"],
+                true,
+            )
+        }
+
+        /// Settings last updated a day ago.
+        pub fn create(
+            user_id: Option<String>,
+            min_amount: Option<Decimal>,
+            max_amount: Option<Decimal>,
+            currency: Option<&str>,
+            active_methods: Vec<&str>,
+            messages_allowed: bool,
+        ) -> RecipientSettings {
+            RecipientSettings {
+                user_id: UserIdMother::create(user_id),
+                limits: limits(min_amount, max_amount, currency.map(str::to_string)).unwrap(),
+                active_methods: RecipientActiveMethods::new(
+                    active_methods.into_iter().map(str::to_string).collect(),
+                )
+                .unwrap(),
+                messages_allowed,
+                updated_at: RecipientSettingsUpdatedAt::new(
+                    OffsetDateTime::now_utc() - Duration::days(1),
+                )
+                .unwrap(),
+
+                events: vec![],
+            }
+        }
+    }
+}
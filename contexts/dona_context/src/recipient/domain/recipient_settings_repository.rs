@@ -0,0 +1,29 @@
+use shared::domain::{base_errors::BaseRepositoryError, value_objects::user_id::UserId};
+
+use super::recipient_settings::RecipientSettings;
+
+#[async_trait::async_trait]
+pub trait RecipientSettingsRepository: Send + Sync {
+    async fn find_by_user_id(
+        &self,
+        user_id: UserId,
+    ) -> Result<RecipientSettings, BaseRepositoryError>;
+    async fn save(&self, settings: &RecipientSettings) -> Result<(), BaseRepositoryError>;
+}
+
+#[cfg(test)]
+pub mod tests {
+    use mockall::mock;
+
+    use super::*;
+
+    mock! {
+        pub RecipientSettingsRepository {}
+
+        #[async_trait::async_trait]
+        impl RecipientSettingsRepository for RecipientSettingsRepository {
+            async fn find_by_user_id(&self, user_id: UserId) -> Result<RecipientSettings, BaseRepositoryError>;
+            async fn save(&self, settings: &RecipientSettings) -> Result<(), BaseRepositoryError>;
+        }
+    }
+}
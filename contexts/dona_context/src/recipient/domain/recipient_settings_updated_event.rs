@@ -0,0 +1,155 @@
+use shared::domain::bus::event::{BaseEvent, Event, EventDeserializeError, EventSerialized};
+
+pub const RECIPIENT_SETTINGS_UPDATED_EVENT_TYPE: &str = "dona.recipient_settings_updated";
+
+/// Amounts and the currency are empty when the recipient has no limits.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct RecipientSettingsUpdatedEvent {
+    user_id: String,
+    min_amount: String,
+    max_amount: String,
+    currency: String,
+    active_methods: String,
+    messages_allowed: String,
+    updated_at: String,
+
+    base_event: BaseEvent,
+}
+
+impl RecipientSettingsUpdatedEvent {
+    pub fn new(
+        user_id: String,
+        min_amount: String,
+        max_amount: String,
+        currency: String,
+        active_methods: String,
+        messages_allowed: String,
+        updated_at: String,
+    ) -> Self {
+        Self {
+            user_id: user_id.clone(),
+            min_amount,
+            max_amount,
+            currency,
+            active_methods,
+            messages_allowed,
+            updated_at,
+            base_event: BaseEvent::new(user_id),
+        }
+    }
+
+    pub fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    pub fn min_amount(&self) -> &str {
+        &self.min_amount
+    }
+
+    pub fn max_amount(&self) -> &str {
+        &self.max_amount
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    pub fn active_methods(&self) -> &str {
+        &self.active_methods
+    }
+
+    pub fn messages_allowed(&self) -> &str {
+        &self.messages_allowed
+    }
+
+    pub fn updated_at(&self) -> &str {
+        &self.updated_at
+    }
+}
+
+impl Event for RecipientSettingsUpdatedEvent {
+    fn event_type(&self) -> &'static str {
+        RECIPIENT_SETTINGS_UPDATED_EVENT_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn from_primitives(
+        &self,
+        primitives: EventSerialized,
+    ) -> Result<Box<dyn Event>, EventDeserializeError> {
+        let data = primitives.data();
+        let base_event = BaseEvent::from_primitives(
+            primitives.event_id().to_string(),
+            primitives.aggregate_id().to_string(),
+            primitives.occurred_at().to_string(),
+        );
+
+        let user_id = data
+            .get("user_id")
+            .ok_or(EventDeserializeError::MissingField("user_id".to_string()))?;
+        let min_amount = data
+            .get("min_amount")
+            .ok_or(EventDeserializeError::MissingField(
+                "min_amount".to_string(),
+            ))?;
+        let max_amount = data
+            .get("max_amount")
+            .ok_or(EventDeserializeError::MissingField(
+                "max_amount".to_string(),
+            ))?;
+        let currency = data
+            .get("currency")
+            .ok_or(EventDeserializeError::MissingField("currency".to_string()))?;
+        let active_methods =
+            data.get("active_methods")
+                .ok_or(EventDeserializeError::MissingField(
+                    "active_methods".to_string(),
+                ))?;
+        let messages_allowed =
+            data.get("messages_allowed")
+                .ok_or(EventDeserializeError::MissingField(
+                    "messages_allowed".to_string(),
+                ))?;
+        let updated_at = data
+            .get("updated_at")
+            .ok_or(EventDeserializeError::MissingField(
+                "updated_at".to_string(),
+            ))?;
+
+        Ok(Box::new(Self {
+            user_id: user_id.to_string(),
+            min_amount: min_amount.to_string(),
+            max_amount: max_amount.to_string(),
+            currency: currency.to_string(),
+            active_methods: active_methods.to_string(),
+            messages_allowed: messages_allowed.to_string(),
+            updated_at: updated_at.to_string(),
+            base_event,
+        }))
+    }
+
+    fn to_primitives(&self) -> EventSerialized {
+        EventSerialized::new(
+            self.base_event.event_id().to_string(),
+            self.base_event.aggregate_id().to_string(),
+            self.base_event.occurred_at().to_string(),
+            vec![
+                ("user_id".to_string(), self.user_id.clone()),
+                ("min_amount".to_string(), self.min_amount.clone()),
+                ("max_amount".to_string(), self.max_amount.clone()),
+                ("currency".to_string(), self.currency.clone()),
+                ("active_methods".to_string(), self.active_methods.clone()),
+                (
+                    "messages_allowed".to_string(),
+                    self.messages_allowed.clone(),
+                ),
+                ("updated_at".to_string(), self.updated_at.clone()),
+            ]
+            .into_iter()
+            .collect(),
+        )
+    }
+}
@@ -0,0 +1 @@
+pub mod sea_recipient_settings_repo;
@@ -6,6 +6,7 @@ pub const ERR_INVALID_DONA_OPTION_METHOD: &str = "Invalid Dona Option Method";
 pub enum DonaOptionMethod {
     Manual,
     Paypal,
+    Crypto,
 }
 
 impl DonaOptionMethod {
@@ -13,9 +14,14 @@ impl DonaOptionMethod {
         match value.as_str() {
             "MANUAL" => Ok(Self::Manual),
             "PAYPAL" => Ok(Self::Paypal),
+            "CRYPTO" => Ok(Self::Crypto),
             _ => Err(ERR_INVALID_DONA_OPTION_METHOD.to_string()),
         }
     }
+
+    pub fn all() -> [Self; 3] {
+        [Self::Manual, Self::Paypal, Self::Crypto]
+    }
 }
 
 impl Display for DonaOptionMethod {
@@ -23,6 +29,7 @@ impl Display for DonaOptionMethod {
         match self {
             Self::Manual => write!(f, "MANUAL"),
             Self::Paypal => write!(f, "PAYPAL"),
+            Self::Crypto => write!(f, "CRYPTO"),
         }
     }
 }
@@ -40,7 +47,11 @@ pub mod tests {
             _config: &DonaOptionMethodFaker,
             rng: &mut R,
         ) -> Self {
-            let values = vec![DonaOptionMethod::Manual, DonaOptionMethod::Paypal];
+            let values = vec![
+                DonaOptionMethod::Manual,
+                DonaOptionMethod::Paypal,
+                DonaOptionMethod::Crypto,
+            ];
             values.choose(rng).unwrap().clone()
         }
     }
@@ -0,0 +1,3 @@
+pub mod response;
+pub mod top;
+pub mod update_preferences;
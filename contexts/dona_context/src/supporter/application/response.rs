@@ -0,0 +1,51 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use shared::domain::bus::query::Response;
+
+use crate::supporter::domain::{leaderboard::SupporterTotal, supporter_profile::SupporterProfile};
+
+/// Profile fields are empty when the supporter no longer has a backoffice user.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TopSupporterResponse {
+    pub rank: u64,
+    pub sender_id: String,
+    pub username: Option<String>,
+    pub full_name: Option<String>,
+    pub profile_picture: Option<String>,
+    pub amount: Decimal,
+    pub currency: String,
+    pub count: u64,
+}
+
+impl TopSupporterResponse {
+    pub fn new(
+        rank: u64,
+        total: SupporterTotal,
+        currency: String,
+        profile: Option<SupporterProfile>,
+    ) -> Self {
+        Self {
+            rank,
+            sender_id: total.sender_id,
+            username: profile.as_ref().map(|p| p.username.clone()),
+            full_name: profile.as_ref().map(|p| p.full_name.clone()),
+            profile_picture: profile.and_then(|p| p.profile_picture),
+            amount: total.amount,
+            currency,
+            count: total.count,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TopSupportersResponse {
+    pub supporters: Vec<TopSupporterResponse>,
+    pub has_previous_page: bool,
+    pub has_next_page: bool,
+}
+
+impl Response for TopSupportersResponse {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
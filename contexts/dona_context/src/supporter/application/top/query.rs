@@ -0,0 +1,251 @@
+use shared::domain::bus::query::{Query, QueryError, QueryHandler, Response};
+use time::OffsetDateTime;
+
+use super::service::TopSupportersFinder;
+
+pub const GET_TOP_SUPPORTERS_QUERY_TYPE: &str = "dona.get_top_supporters.query";
+
+/// `period` is one of `all_time`, `last_30_days` or `custom`, the last one reading `from`
+/// and `to`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GetTopSupportersQuery {
+    pub user_id: String,
+    pub currency: String,
+    pub period: String,
+    pub from: Option<OffsetDateTime>,
+    pub to: Option<OffsetDateTime>,
+    pub first: u64,
+    pub offset: u64,
+}
+
+impl Query for GetTopSupportersQuery {
+    fn query_type(&self) -> &'static str {
+        GET_TOP_SUPPORTERS_QUERY_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct GetTopSupportersQueryHandler {
+    service: TopSupportersFinder,
+}
+
+impl GetTopSupportersQueryHandler {
+    pub fn new(service: TopSupportersFinder) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler for GetTopSupportersQueryHandler {
+    async fn handle(&self, query: Box<dyn Query>) -> Result<Box<dyn Response>, QueryError> {
+        let query = query
+            .as_any()
+            .downcast_ref::<GetTopSupportersQuery>()
+            .ok_or_else(|| QueryError::new("Invalid query".to_string()))?;
+
+        let supporters = self
+            .service
+            .execute(
+                query.user_id.to_owned(),
+                query.currency.to_owned(),
+                query.period.to_owned(),
+                query.from,
+                query.to,
+                query.first,
+                query.offset,
+            )
+            .await
+            .map_err(QueryError::new)?;
+
+        Ok(Box::new(supporters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockall::predicate;
+    use rust_decimal_macros::dec;
+    use shared::domain::utils::new_uuid;
+    use shared::domain::value_objects::{money::Currency, user_id::UserId};
+    use time::{macros::datetime, Duration};
+
+    use super::*;
+
+    use crate::dona::domain::dona_stats::DonaStatsRange;
+    use crate::supporter::application::response::{TopSupporterResponse, TopSupportersResponse};
+    use crate::supporter::domain::leaderboard::{
+        LeaderboardPage, SupporterTotal, ERR_INVALID_LEADERBOARD_PAGE_SIZE,
+        ERR_INVALID_LEADERBOARD_PERIOD, LEADERBOARD_RECENT_DAYS,
+    };
+    use crate::supporter::domain::supporter_profile::tests::MockSupporterProfileRepository;
+    use crate::supporter::domain::supporter_profile::SupporterProfile;
+    use crate::supporter::domain::supporter_repository::tests::MockSupporterRepository;
+
+    fn handler(
+        repository: MockSupporterRepository,
+        profile_repository: MockSupporterProfileRepository,
+    ) -> GetTopSupportersQueryHandler {
+        GetTopSupportersQueryHandler::new(TopSupportersFinder::new(
+            Arc::new(repository),
+            Arc::new(profile_repository),
+        ))
+    }
+
+    fn query(period: &str, from: Option<OffsetDateTime>, first: u64) -> GetTopSupportersQuery {
+        GetTopSupportersQuery {
+            user_id: new_uuid(),
+            currency: "USD".to_string(),
+            period: period.to_string(),
+            from,
+            to: from.map(|from| from + Duration::days(7)),
+            first,
+            offset: 2,
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_rank_supporters_and_load_profiles_at_once() {
+        let query = query("custom", Some(datetime!(2024-03-01 00:00 UTC)), 2);
+        let (first, second, third) = (new_uuid(), new_uuid(), new_uuid());
+        let total = |sender_id: &String, amount| SupporterTotal {
+            sender_id: sender_id.clone(),
+            amount,
+            count: 1,
+        };
+
+        let mut repository = MockSupporterRepository::new();
+        repository
+            .expect_top_supporters()
+            .with(
+                predicate::eq(UserId::new(query.user_id.clone()).unwrap()),
+                predicate::eq(Currency::new("USD".to_string()).unwrap()),
+                predicate::eq(Some(
+                    DonaStatsRange::new(query.from.unwrap(), query.to.unwrap()).unwrap(),
+                )),
+                predicate::eq(LeaderboardPage::new(2, 2).unwrap()),
+            )
+            .times(1)
+            .return_const(Ok(vec![
+                total(&first, dec!(30.00)),
+                total(&second, dec!(20.00)),
+                total(&third, dec!(10.00)),
+            ]));
+
+        let profile = SupporterProfile {
+            user_id: first.clone(),
+            username: "first".to_string(),
+            full_name: "First Supporter".to_string(),
+            profile_picture: None,
+        };
+        let mut profile_repository = MockSupporterProfileRepository::new();
+        profile_repository
+            .expect_find_by_ids()
+            .with(predicate::eq(vec![
+                UserId::new(first.clone()).unwrap(),
+                UserId::new(second.clone()).unwrap(),
+            ]))
+            .times(1)
+            .return_const(Ok(vec![profile]));
+
+        let response = handler(repository, profile_repository)
+            .handle(Box::new(query))
+            .await
+            .unwrap();
+        let response = response
+            .as_any()
+            .downcast_ref::<TopSupportersResponse>()
+            .unwrap()
+            .to_owned();
+
+        assert_eq!(
+            response,
+            TopSupportersResponse {
+                supporters: vec![
+                    TopSupporterResponse {
+                        rank: 3,
+                        sender_id: first,
+                        username: Some("first".to_string()),
+                        full_name: Some("First Supporter".to_string()),
+                        profile_picture: None,
+                        amount: dec!(30.00),
+                        currency: "USD".to_string(),
+                        count: 1,
+                    },
+                    TopSupporterResponse {
+                        rank: 4,
+                        sender_id: second,
+                        username: None,
+                        full_name: None,
+                        profile_picture: None,
+                        amount: dec!(20.00),
+                        currency: "USD".to_string(),
+                        count: 1,
+                    },
+                ],
+                has_previous_page: true,
+                has_next_page: true,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_cover_the_last_days() {
+        let mut repository = MockSupporterRepository::new();
+        repository
+            .expect_top_supporters()
+            .withf(|_, _, range, _| {
+                range.is_some_and(|range| {
+                    range.to() - range.from() == Duration::days(LEADERBOARD_RECENT_DAYS)
+                })
+            })
+            .times(1)
+            .return_const(Ok(vec![]));
+
+        let mut profile_repository = MockSupporterProfileRepository::new();
+        profile_repository
+            .expect_find_by_ids()
+            .times(1)
+            .return_const(Ok(vec![]));
+
+        let response = handler(repository, profile_repository)
+            .handle(Box::new(query("last_30_days", None, 10)))
+            .await
+            .unwrap();
+        let response = response
+            .as_any()
+            .downcast_ref::<TopSupportersResponse>()
+            .unwrap()
+            .to_owned();
+
+        assert!(response.supporters.is_empty());
+        assert!(!response.has_next_page);
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_with_an_invalid_period_or_page() {
+        for (period, first, error) in [
+            ("custom", 10, ERR_INVALID_LEADERBOARD_PERIOD),
+            ("forever", 10, ERR_INVALID_LEADERBOARD_PERIOD),
+            ("all_time", 0, ERR_INVALID_LEADERBOARD_PAGE_SIZE),
+            ("all_time", 101, ERR_INVALID_LEADERBOARD_PAGE_SIZE),
+        ] {
+            let mut repository = MockSupporterRepository::new();
+            repository.expect_top_supporters().times(0);
+
+            let mut profile_repository = MockSupporterProfileRepository::new();
+            profile_repository.expect_find_by_ids().times(0);
+
+            let result = handler(repository, profile_repository)
+                .handle(Box::new(query(period, None, first)))
+                .await;
+
+            assert_eq!(result.err(), Some(QueryError::new(error.to_string())));
+        }
+    }
+}
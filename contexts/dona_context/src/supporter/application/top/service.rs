@@ -0,0 +1,82 @@
+use std::{collections::HashMap, sync::Arc};
+
+use shared::domain::value_objects::{money::Currency, user_id::UserId};
+use time::OffsetDateTime;
+
+use crate::supporter::{
+    application::response::{TopSupporterResponse, TopSupportersResponse},
+    domain::{
+        leaderboard::{LeaderboardPage, LeaderboardPeriod},
+        supporter_profile::{SupporterProfile, SupporterProfileRepository},
+        supporter_repository::SupporterRepository,
+    },
+};
+
+#[derive(Clone)]
+pub struct TopSupportersFinder {
+    repository: Arc<dyn SupporterRepository>,
+    profile_repository: Arc<dyn SupporterProfileRepository>,
+}
+
+impl TopSupportersFinder {
+    pub fn new(
+        repository: Arc<dyn SupporterRepository>,
+        profile_repository: Arc<dyn SupporterProfileRepository>,
+    ) -> Self {
+        Self {
+            repository,
+            profile_repository,
+        }
+    }
+
+    /// Ranks the page of supporters and loads all their profiles with a single lookup.
+    pub async fn execute(
+        &self,
+        user_id: String,
+        currency: String,
+        period: String,
+        from: Option<OffsetDateTime>,
+        to: Option<OffsetDateTime>,
+        first: u64,
+        offset: u64,
+    ) -> Result<TopSupportersResponse, String> {
+        let user_id = UserId::new(user_id)?;
+        let currency = Currency::new(currency)?;
+        let range = LeaderboardPeriod::new(period, from, to)?.range_at(OffsetDateTime::now_utc())?;
+        let page = LeaderboardPage::new(offset, first)?;
+
+        let mut totals = self
+            .repository
+            .top_supporters(user_id, currency.clone(), range, page)
+            .await?;
+        let has_next_page = totals.len() as u64 > page.size();
+        totals.truncate(page.size() as usize);
+
+        let ids = totals
+            .iter()
+            .map(|total| UserId::new(total.sender_id.clone()))
+            .collect::<Result<Vec<UserId>, String>>()?;
+        let mut profiles = self
+            .profile_repository
+            .find_by_ids(ids)
+            .await?
+            .into_iter()
+            .map(|profile| (profile.user_id.clone(), profile))
+            .collect::<HashMap<String, SupporterProfile>>();
+
+        let supporters = totals
+            .into_iter()
+            .zip(page.offset() + 1..)
+            .map(|(total, rank)| {
+                let profile = profiles.remove(&total.sender_id);
+                TopSupporterResponse::new(rank, total, currency.to_string(), profile)
+            })
+            .collect();
+
+        Ok(TopSupportersResponse {
+            supporters,
+            has_previous_page: page.offset() > 0,
+            has_next_page,
+        })
+    }
+}
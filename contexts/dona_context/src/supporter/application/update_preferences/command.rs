@@ -0,0 +1,186 @@
+use shared::domain::bus::command::{Command, CommandError, CommandHandler};
+use time::OffsetDateTime;
+
+use super::service::SupporterPreferencesUpdater;
+
+pub const UPDATE_SUPPORTER_PREFERENCES_COMMAND_TYPE: &str =
+    "dona.update_supporter_preferences.command";
+
+#[derive(Debug)]
+pub struct UpdateSupporterPreferencesCommand {
+    pub user_id: String,
+    pub hidden_from_leaderboards: bool,
+    pub updated_at: OffsetDateTime,
+}
+
+impl Command for UpdateSupporterPreferencesCommand {
+    fn command_type(&self) -> &'static str {
+        UPDATE_SUPPORTER_PREFERENCES_COMMAND_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct UpdateSupporterPreferencesCommandHandler {
+    service: SupporterPreferencesUpdater,
+}
+
+impl UpdateSupporterPreferencesCommandHandler {
+    pub fn new(service: SupporterPreferencesUpdater) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler for UpdateSupporterPreferencesCommandHandler {
+    async fn handle(&self, command: Box<dyn Command>) -> Result<(), CommandError> {
+        let command = command
+            .as_any()
+            .downcast_ref::<UpdateSupporterPreferencesCommand>()
+            .ok_or_else(|| CommandError::new("Invalid command".to_string()))?;
+
+        self.service
+            .execute(
+                command.user_id.to_owned(),
+                command.hidden_from_leaderboards,
+                command.updated_at,
+            )
+            .await
+            .map_err(CommandError::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockall::predicate;
+    use shared::domain::{
+        base_errors::BaseRepositoryError, bus::event::tests::MockEventBus, utils::new_uuid,
+        value_objects::user_id::UserId,
+    };
+
+    use super::*;
+
+    use crate::supporter::domain::supporter_preferences::tests::SupporterPreferencesMother;
+    use crate::supporter::domain::supporter_preferences_updated_event::{
+        SupporterPreferencesUpdatedEvent, SUPPORTER_PREFERENCES_UPDATED_EVENT_TYPE,
+    };
+    use crate::supporter::domain::supporter_repository::tests::MockSupporterRepository;
+
+    fn handler(
+        repository: MockSupporterRepository,
+        event_bus: MockEventBus,
+    ) -> UpdateSupporterPreferencesCommandHandler {
+        UpdateSupporterPreferencesCommandHandler::new(SupporterPreferencesUpdater::new(
+            Arc::new(repository),
+            Arc::new(event_bus),
+        ))
+    }
+
+    fn updated_event_bus(hidden_from_leaderboards: &'static str) -> MockEventBus {
+        let mut event_bus = MockEventBus::new();
+        event_bus
+            .expect_publish()
+            .withf(move |events| {
+                events.len() == 1
+                    && events[0].event_type() == SUPPORTER_PREFERENCES_UPDATED_EVENT_TYPE
+                    && events[0]
+                        .as_any()
+                        .downcast_ref::<SupporterPreferencesUpdatedEvent>()
+                        .is_some_and(|event| {
+                            event.hidden_from_leaderboards() == hidden_from_leaderboards
+                        })
+            })
+            .times(1)
+            .return_const(Ok(()));
+        event_bus
+    }
+
+    #[tokio::test]
+    async fn it_should_create_preferences_on_first_update() {
+        let user_id = new_uuid();
+
+        let mut repository = MockSupporterRepository::new();
+        repository
+            .expect_find_preferences()
+            .with(predicate::eq(UserId::new(user_id.clone()).unwrap()))
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        let expected_user_id = user_id.clone();
+        repository
+            .expect_save_preferences()
+            .withf(move |preferences| {
+                preferences.user_id() == expected_user_id && preferences.hidden_from_leaderboards()
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let result = handler(repository, updated_event_bus("true"))
+            .handle(Box::new(UpdateSupporterPreferencesCommand {
+                user_id,
+                hidden_from_leaderboards: true,
+                updated_at: OffsetDateTime::now_utc(),
+            }))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_update_existing_preferences() {
+        let preferences = SupporterPreferencesMother::create(None, true);
+
+        let mut repository = MockSupporterRepository::new();
+        repository
+            .expect_find_preferences()
+            .times(1)
+            .return_const(Ok(preferences.clone()));
+        repository
+            .expect_save_preferences()
+            .withf(|preferences| !preferences.hidden_from_leaderboards())
+            .times(1)
+            .return_const(Ok(()));
+
+        let result = handler(repository, updated_event_bus("false"))
+            .handle(Box::new(UpdateSupporterPreferencesCommand {
+                user_id: preferences.user_id(),
+                hidden_from_leaderboards: false,
+                updated_at: OffsetDateTime::now_utc(),
+            }))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_repository_fails() {
+        let mut repository = MockSupporterRepository::new();
+        repository
+            .expect_find_preferences()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::ConnectionError));
+        repository.expect_save_preferences().times(0);
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(0);
+
+        let result = handler(repository, event_bus)
+            .handle(Box::new(UpdateSupporterPreferencesCommand {
+                user_id: new_uuid(),
+                hidden_from_leaderboards: true,
+                updated_at: OffsetDateTime::now_utc(),
+            }))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(
+                BaseRepositoryError::ConnectionError.to_string()
+            ))
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use shared::domain::{
+    base_errors::BaseRepositoryError, bus::event::EventBus, value_objects::user_id::UserId,
+};
+use time::OffsetDateTime;
+
+use crate::supporter::domain::{
+    supporter_preferences::SupporterPreferences, supporter_repository::SupporterRepository,
+};
+
+#[derive(Clone)]
+pub struct SupporterPreferencesUpdater {
+    repository: Arc<dyn SupporterRepository>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl SupporterPreferencesUpdater {
+    pub fn new(repository: Arc<dyn SupporterRepository>, event_bus: Arc<dyn EventBus>) -> Self {
+        Self {
+            repository,
+            event_bus,
+        }
+    }
+
+    /// Preferences are created on the first update, until then the defaults apply.
+    pub async fn execute(
+        &self,
+        user_id: String,
+        hidden_from_leaderboards: bool,
+        updated_at: OffsetDateTime,
+    ) -> Result<(), String> {
+        let mut preferences = match self
+            .repository
+            .find_preferences(UserId::new(user_id.clone())?)
+            .await
+        {
+            Ok(mut preferences) => {
+                preferences.update(hidden_from_leaderboards, updated_at)?;
+                preferences
+            }
+            Err(BaseRepositoryError::NotFound) => {
+                SupporterPreferences::create(user_id, hidden_from_leaderboards, updated_at)?
+            }
+            Err(e) => return Err(e.to_string()),
+        };
+
+        self.repository.save_preferences(&preferences).await?;
+
+        self.event_bus.publish(preferences.pull_events()).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use rust_decimal::Decimal;
+use time::{Duration, OffsetDateTime};
+
+use crate::dona::domain::dona_stats::DonaStatsRange;
+
+pub const LEADERBOARD_RECENT_DAYS: i64 = 30;
+pub const LEADERBOARD_MAX_PAGE_SIZE: u64 = 100;
+
+pub const ERR_INVALID_LEADERBOARD_PERIOD: &str = "Invalid Leaderboard Period";
+pub const ERR_INVALID_LEADERBOARD_PAGE_SIZE: &str = "Invalid Leaderboard Page Size";
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum LeaderboardPeriod {
+    AllTime,
+    Recent,
+    Custom(DonaStatsRange),
+}
+
+impl LeaderboardPeriod {
+    /// `from` and `to` are only read, and required, for the `custom` period.
+    pub fn new(
+        value: String,
+        from: Option<OffsetDateTime>,
+        to: Option<OffsetDateTime>,
+    ) -> Result<Self, String> {
+        match (value.as_str(), from, to) {
+            ("all_time", _, _) => Ok(Self::AllTime),
+            ("last_30_days", _, _) => Ok(Self::Recent),
+            ("custom", Some(from), Some(to)) => Ok(Self::Custom(DonaStatsRange::new(from, to)?)),
+            _ => Err(ERR_INVALID_LEADERBOARD_PERIOD.to_string()),
+        }
+    }
+
+    /// Range of creation dates the period covers at `now`, `None` when it is unbounded.
+    pub fn range_at(&self, now: OffsetDateTime) -> Result<Option<DonaStatsRange>, String> {
+        match self {
+            Self::AllTime => Ok(None),
+            Self::Recent => Ok(Some(DonaStatsRange::new(
+                now - Duration::days(LEADERBOARD_RECENT_DAYS),
+                now,
+            )?)),
+            Self::Custom(range) => Ok(Some(*range)),
+        }
+    }
+}
+
+/// A page of the leaderboard, `offset` being the number of supporters ranked above it.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct LeaderboardPage {
+    offset: u64,
+    size: u64,
+}
+
+impl LeaderboardPage {
+    pub fn new(offset: u64, size: u64) -> Result<Self, String> {
+        if size == 0 || size > LEADERBOARD_MAX_PAGE_SIZE {
+            return Err(ERR_INVALID_LEADERBOARD_PAGE_SIZE.to_string());
+        }
+
+        Ok(Self { offset, size })
+    }
+
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+}
+
+/// Confirmed total a registered sender gave to a recipient, in a single currency.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SupporterTotal {
+    pub sender_id: String,
+    pub amount: Decimal,
+    pub count: u64,
+}
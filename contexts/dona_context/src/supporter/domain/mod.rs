@@ -0,0 +1,5 @@
+pub mod leaderboard;
+pub mod supporter_preferences;
+pub mod supporter_preferences_updated_event;
+pub mod supporter_profile;
+pub mod supporter_repository;
@@ -0,0 +1,150 @@
+use std::fmt::Display;
+use std::sync::Arc;
+
+use shared::domain::{bus::event::Event, value_objects::user_id::UserId};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+use super::supporter_preferences_updated_event::SupporterPreferencesUpdatedEvent;
+
+pub const ERR_INVALID_SUPPORTER_PREFERENCES_UPDATED_AT: &str =
+    "Invalid Supporter Preferences Updated At";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct SupporterPreferencesUpdatedAt(OffsetDateTime);
+
+impl SupporterPreferencesUpdatedAt {
+    pub fn new(value: OffsetDateTime) -> Result<Self, String> {
+        if value < OffsetDateTime::now_utc() {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_SUPPORTER_PREFERENCES_UPDATED_AT.to_string())
+        }
+    }
+}
+
+impl Display for SupporterPreferencesUpdatedAt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.format(&Rfc3339).unwrap())
+    }
+}
+
+/// Choices a user makes as a donor. Users that never saved any are shown on leaderboards.
+#[derive(Debug, Clone)]
+pub struct SupporterPreferences {
+    user_id: UserId,
+    hidden_from_leaderboards: bool,
+    updated_at: SupporterPreferencesUpdatedAt,
+
+    events: Vec<Arc<dyn Event>>,
+}
+
+impl PartialEq for SupporterPreferences {
+    fn eq(&self, other: &Self) -> bool {
+        self.user_id == other.user_id
+            && self.hidden_from_leaderboards == other.hidden_from_leaderboards
+            && self.updated_at == other.updated_at
+    }
+}
+
+impl Eq for SupporterPreferences {}
+
+impl SupporterPreferences {
+    pub(crate) fn new(
+        user_id: String,
+        hidden_from_leaderboards: bool,
+        updated_at: OffsetDateTime,
+    ) -> Result<Self, String> {
+        Ok(Self {
+            user_id: UserId::new(user_id)?,
+            hidden_from_leaderboards,
+            updated_at: SupporterPreferencesUpdatedAt::new(updated_at)?,
+            events: vec![],
+        })
+    }
+
+    pub fn create(
+        user_id: String,
+        hidden_from_leaderboards: bool,
+        updated_at: OffsetDateTime,
+    ) -> Result<Self, String> {
+        let mut preferences = Self::new(user_id, hidden_from_leaderboards, updated_at)?;
+        preferences.record_updated();
+
+        Ok(preferences)
+    }
+
+    pub fn update(
+        &mut self,
+        hidden_from_leaderboards: bool,
+        updated_at: OffsetDateTime,
+    ) -> Result<(), String> {
+        self.hidden_from_leaderboards = hidden_from_leaderboards;
+        self.updated_at = SupporterPreferencesUpdatedAt::new(updated_at)?;
+        self.record_updated();
+
+        Ok(())
+    }
+
+    fn record_updated(&mut self) {
+        let event = SupporterPreferencesUpdatedEvent::new(
+            self.user_id(),
+            self.hidden_from_leaderboards.to_string(),
+            self.updated_at.to_string(),
+        );
+        self.record(Arc::new(event));
+    }
+
+    pub fn record(&mut self, event: Arc<dyn Event>) {
+        self.events.push(event);
+    }
+
+    pub fn pull_events(&mut self) -> Vec<Arc<dyn Event>> {
+        let events = self.events.clone();
+        self.events = vec![];
+        events
+    }
+
+    pub fn user_id(&self) -> String {
+        self.user_id.to_string()
+    }
+
+    pub fn hidden_from_leaderboards(&self) -> bool {
+        self.hidden_from_leaderboards
+    }
+
+    pub fn updated_at(&self) -> OffsetDateTime {
+        self.updated_at.0
+    }
+}
+
+pub mod tests {
+    use shared::domain::value_objects::user_id::tests::UserIdMother;
+    use time::Duration;
+
+    use super::*;
+
+    pub struct SupporterPreferencesMother;
+
+    impl SupporterPreferencesMother {
+        pub fn random() -> SupporterPreferences {
+            Self::create(None, false)
+        }
+
+        /// Preferences last updated a day ago.
+        pub fn create(
+            user_id: Option<String>,
+            hidden_from_leaderboards: bool,
+        ) -> SupporterPreferences {
+            SupporterPreferences {
+                user_id: UserIdMother::create(user_id),
+                hidden_from_leaderboards,
+                updated_at: SupporterPreferencesUpdatedAt::new(
+                    OffsetDateTime::now_utc() - Duration::days(1),
+                )
+                .unwrap(),
+
+                events: vec![],
+            }
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use shared::domain::bus::event::{BaseEvent, Event, EventDeserializeError, EventSerialized};
+
+pub const SUPPORTER_PREFERENCES_UPDATED_EVENT_TYPE: &str = "dona.supporter_preferences_updated";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct SupporterPreferencesUpdatedEvent {
+    user_id: String,
+    hidden_from_leaderboards: String,
+    updated_at: String,
+
+    base_event: BaseEvent,
+}
+
+impl SupporterPreferencesUpdatedEvent {
+    pub fn new(user_id: String, hidden_from_leaderboards: String, updated_at: String) -> Self {
+        Self {
+            user_id: user_id.clone(),
+            hidden_from_leaderboards,
+            updated_at,
+            base_event: BaseEvent::new(user_id),
+        }
+    }
+
+    pub fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    pub fn hidden_from_leaderboards(&self) -> &str {
+        &self.hidden_from_leaderboards
+    }
+
+    pub fn updated_at(&self) -> &str {
+        &self.updated_at
+    }
+}
+
+impl Event for SupporterPreferencesUpdatedEvent {
+    fn event_type(&self) -> &'static str {
+        SUPPORTER_PREFERENCES_UPDATED_EVENT_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn from_primitives(
+        &self,
+        primitives: EventSerialized,
+    ) -> Result<Box<dyn Event>, EventDeserializeError> {
+        let data = primitives.data();
+        let base_event = BaseEvent::from_primitives(
+            primitives.event_id().to_string(),
+            primitives.aggregate_id().to_string(),
+            primitives.occurred_at().to_string(),
+        );
+
+        let user_id = data
+            .get("user_id")
+            .ok_or(EventDeserializeError::MissingField("user_id".to_string()))?;
+        let hidden_from_leaderboards =
+            data.get("hidden_from_leaderboards")
+                .ok_or(EventDeserializeError::MissingField(
+                    "hidden_from_leaderboards".to_string(),
+                ))?;
+        let updated_at = data
+            .get("updated_at")
+            .ok_or(EventDeserializeError::MissingField(
+                "updated_at".to_string(),
+            ))?;
+
+        Ok(Box::new(Self {
+            user_id: user_id.to_string(),
+            hidden_from_leaderboards: hidden_from_leaderboards.to_string(),
+            updated_at: updated_at.to_string(),
+            base_event,
+        }))
+    }
+
+    fn to_primitives(&self) -> EventSerialized {
+        EventSerialized::new(
+            self.base_event.event_id().to_string(),
+            self.base_event.aggregate_id().to_string(),
+            self.base_event.occurred_at().to_string(),
+            vec![
+                ("user_id".to_string(), self.user_id.clone()),
+                (
+                    "hidden_from_leaderboards".to_string(),
+                    self.hidden_from_leaderboards.clone(),
+                ),
+                ("updated_at".to_string(), self.updated_at.clone()),
+            ]
+            .into_iter()
+            .collect(),
+        )
+    }
+}
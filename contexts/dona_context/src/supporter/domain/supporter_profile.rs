@@ -0,0 +1,35 @@
+use shared::domain::{base_errors::BaseRepositoryError, value_objects::user_id::UserId};
+
+/// Public display data of a supporter, owned by the backoffice context.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SupporterProfile {
+    pub user_id: String,
+    pub username: String,
+    pub full_name: String,
+    pub profile_picture: Option<String>,
+}
+
+#[async_trait::async_trait]
+pub trait SupporterProfileRepository: Send + Sync {
+    /// Fetches every profile in one lookup. Unknown ids are left out of the result.
+    async fn find_by_ids(
+        &self,
+        ids: Vec<UserId>,
+    ) -> Result<Vec<SupporterProfile>, BaseRepositoryError>;
+}
+
+#[cfg(test)]
+pub mod tests {
+    use mockall::mock;
+
+    use super::*;
+
+    mock! {
+        pub SupporterProfileRepository {}
+
+        #[async_trait::async_trait]
+        impl SupporterProfileRepository for SupporterProfileRepository {
+            async fn find_by_ids(&self, ids: Vec<UserId>) -> Result<Vec<SupporterProfile>, BaseRepositoryError>;
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use shared::domain::{
+    base_errors::BaseRepositoryError,
+    value_objects::{money::Currency, user_id::UserId},
+};
+
+use crate::dona::domain::dona_stats::DonaStatsRange;
+
+use super::{
+    leaderboard::{LeaderboardPage, SupporterTotal},
+    supporter_preferences::SupporterPreferences,
+};
+
+#[async_trait::async_trait]
+pub trait SupporterRepository: Send + Sync {
+    async fn find_preferences(
+        &self,
+        user_id: UserId,
+    ) -> Result<SupporterPreferences, BaseRepositoryError>;
+    async fn save_preferences(
+        &self,
+        preferences: &SupporterPreferences,
+    ) -> Result<(), BaseRepositoryError>;
+    /// Registered senders ranked by what they gave the recipient in `currency`, highest first.
+    /// Anonymous donas and supporters hidden from leaderboards are left out. Fetches one row
+    /// past the page so callers can tell whether there is a next one.
+    async fn top_supporters(
+        &self,
+        user_id: UserId,
+        currency: Currency,
+        range: Option<DonaStatsRange>,
+        page: LeaderboardPage,
+    ) -> Result<Vec<SupporterTotal>, BaseRepositoryError>;
+}
+
+#[cfg(test)]
+pub mod tests {
+    use mockall::mock;
+
+    use super::*;
+
+    mock! {
+        pub SupporterRepository {}
+
+        #[async_trait::async_trait]
+        impl SupporterRepository for SupporterRepository {
+            async fn find_preferences(&self, user_id: UserId) -> Result<SupporterPreferences, BaseRepositoryError>;
+            async fn save_preferences(&self, preferences: &SupporterPreferences) -> Result<(), BaseRepositoryError>;
+            async fn top_supporters(&self, user_id: UserId, currency: Currency, range: Option<DonaStatsRange>, page: LeaderboardPage) -> Result<Vec<SupporterTotal>, BaseRepositoryError>;
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use backoffice::auth::domain::user_repository::UserRepository;
+use shared::domain::{
+    base_errors::BaseRepositoryError,
+    criteria::{
+        cursor::{Cursor, FirstField},
+        filter::{Filter, FilterField, FilterOperator, FilterValue},
+        Criteria,
+    },
+    value_objects::user_id::UserId,
+};
+
+use crate::supporter::domain::supporter_profile::{SupporterProfile, SupporterProfileRepository};
+
+/// Reads the profiles straight from the backoffice users, with a single `IN` lookup per call.
+pub struct BackofficeSupporterProfileRepo {
+    user_repository: Arc<dyn UserRepository>,
+}
+
+impl BackofficeSupporterProfileRepo {
+    pub fn new(user_repository: Arc<dyn UserRepository>) -> Self {
+        Self { user_repository }
+    }
+}
+
+#[async_trait::async_trait]
+impl SupporterProfileRepository for BackofficeSupporterProfileRepo {
+    async fn find_by_ids(
+        &self,
+        ids: Vec<UserId>,
+    ) -> Result<Vec<SupporterProfile>, BaseRepositoryError> {
+        if ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let ids_len = ids.len();
+        let ids = ids
+            .into_iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+        let criteria = Criteria::new(
+            vec![Filter::new(
+                FilterField::try_from("id".to_string()).unwrap(),
+                FilterOperator::In,
+                FilterValue::try_from(ids).map_err(BaseRepositoryError::CriteriaCoverterError)?,
+            )],
+            None,
+            Some(Cursor::new(
+                None,
+                None,
+                Some(FirstField::new(ids_len).unwrap()),
+                None,
+            )),
+        );
+
+        let users = self.user_repository.find_by_criteria(criteria).await?;
+
+        Ok(users
+            .into_iter()
+            .map(|user| SupporterProfile {
+                user_id: user.id(),
+                username: user.username(),
+                full_name: user.full_name(),
+                profile_picture: user.profile_picture(),
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,2 @@
+pub mod backoffice_supporter_profile_repo;
+pub mod persistence;
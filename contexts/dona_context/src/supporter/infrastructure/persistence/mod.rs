@@ -0,0 +1 @@
+pub mod sea_supporter_repo;
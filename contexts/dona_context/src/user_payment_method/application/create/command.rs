@@ -11,6 +11,12 @@ pub struct CreateUserPaymentMethodCommand {
     pub user_id: String,
     pub payment_method: String,
     pub instructions: String,
+    /// Required for crypto methods, rejected for the others.
+    pub crypto_network: Option<String>,
+    pub crypto_address: Option<String>,
+    /// Required for PayPal methods, rejected for the others.
+    pub paypal_email: Option<String>,
+    pub currencies: Vec<String>,
     pub created_at: OffsetDateTime,
     pub updated_at: OffsetDateTime,
 }
@@ -50,6 +56,10 @@ impl CommandHandler for CreateUserPaymentMethodCommandHandler {
                 command.user_id.to_owned(),
                 command.payment_method.to_owned(),
                 command.instructions.to_owned(),
+                command.crypto_network.to_owned(),
+                command.crypto_address.to_owned(),
+                command.paypal_email.to_owned(),
+                command.currencies.to_owned(),
                 command.created_at,
                 command.updated_at,
             )
@@ -60,8 +70,15 @@ impl CommandHandler for CreateUserPaymentMethodCommandHandler {
 
 #[cfg(test)]
 mod tests {
+    use crate::user_payment_method::domain::crypto_wallet::{
+        ERR_INVALID_CRYPTO_ADDRESS, ERR_INVALID_CRYPTO_NETWORK,
+    };
     use crate::user_payment_method::domain::user_payment_method::tests::UserPaymentMethodMother;
-    use crate::user_payment_method::domain::user_payment_method::UserPaymentMethodId;
+    use crate::user_payment_method::domain::user_payment_method::{
+        UserPaymentMethodId, ERR_CRYPTO_WALLET_NOT_ALLOWED, ERR_CRYPTO_WALLET_REQUIRED,
+        ERR_INVALID_USER_PAYMENT_METHOD_PAYPAL_EMAIL, ERR_PAYPAL_EMAIL_NOT_ALLOWED,
+        ERR_PAYPAL_EMAIL_REQUIRED,
+    };
     use crate::user_payment_method::domain::user_payment_method_repository::tests::MockUserPaymentMethodRepository;
     use std::sync::Arc;
 
@@ -94,6 +111,10 @@ mod tests {
             user_id: method.user_id(),
             payment_method: method.payment_method(),
             instructions: method.instructions(),
+            crypto_network: method.crypto_network(),
+            crypto_address: method.crypto_address(),
+            paypal_email: method.paypal_email(),
+            currencies: method.currencies(),
             created_at: method.created_at(),
             updated_at: method.updated_at(),
         };
@@ -133,6 +154,10 @@ mod tests {
             user_id: method.user_id(),
             payment_method: method.payment_method(),
             instructions: method.instructions(),
+            crypto_network: method.crypto_network(),
+            crypto_address: method.crypto_address(),
+            paypal_email: method.paypal_email(),
+            currencies: method.currencies(),
             created_at: method.created_at(),
             updated_at: method.updated_at(),
         };
@@ -170,6 +195,10 @@ mod tests {
             user_id: method.user_id(),
             payment_method: method.payment_method(),
             instructions: method.instructions(),
+            crypto_network: method.crypto_network(),
+            crypto_address: method.crypto_address(),
+            paypal_email: method.paypal_email(),
+            currencies: method.currencies(),
             created_at: method.created_at(),
             updated_at: method.updated_at(),
         };
@@ -177,4 +206,179 @@ mod tests {
 
         assert!(result.is_ok(), "Result should be Ok");
     }
+
+    fn crypto_command(
+        payment_method: &str,
+        crypto_network: Option<&str>,
+        crypto_address: Option<&str>,
+    ) -> CreateUserPaymentMethodCommand {
+        let method = UserPaymentMethodMother::random();
+
+        CreateUserPaymentMethodCommand {
+            id: method.id(),
+            user_id: method.user_id(),
+            payment_method: payment_method.to_string(),
+            instructions: method.instructions(),
+            crypto_network: crypto_network.map(str::to_string),
+            crypto_address: crypto_address.map(str::to_string),
+            paypal_email: None,
+            currencies: method.currencies(),
+            created_at: method.created_at(),
+            updated_at: method.updated_at(),
+        }
+    }
+
+    fn handler_saving(saves: usize) -> CreateUserPaymentMethodCommandHandler {
+        let mut repository = MockUserPaymentMethodRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+        repository.expect_save().times(saves).return_const(Ok(()));
+
+        let mut event_bus = MockEventBus::new();
+        event_bus.expect_publish().times(saves).return_const(Ok(()));
+
+        CreateUserPaymentMethodCommandHandler::new(UserPaymentMethodCreator::new(
+            Arc::new(repository),
+            Arc::new(event_bus),
+        ))
+    }
+
+    #[tokio::test]
+    async fn it_should_create_crypto_method_with_a_valid_address() {
+        for (network, address) in [
+            ("BTC", "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa"),
+            ("BTC", "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy"),
+            ("BTC", "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq"),
+            (
+                "BTC",
+                "bc1p5d7rjq7g6rdk2yhzks9smlaqtedr4dekq08ge8ztwac72sfr9rusxg3297",
+            ),
+            ("ETH", "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+            ("ETH", "0xfb6916095ca1df60bb79ce92ce3ea74c37c5d359"),
+            ("USDT-ERC20", "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb"),
+            ("TRX", "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t"),
+            ("USDT-TRC20", "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t"),
+        ] {
+            let result = handler_saving(1)
+                .handle(Box::new(crypto_command(
+                    "CRYPTO",
+                    Some(network),
+                    Some(address),
+                )))
+                .await;
+
+            assert!(result.is_ok(), "{} {} should be valid", network, address);
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_crypto_address_is_invalid_for_its_network() {
+        for (network, address) in [
+            // Last character changed, the checksum no longer matches.
+            ("BTC", "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNb"),
+            ("BTC", "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdz"),
+            // Testnet.
+            ("BTC", "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx"),
+            ("BTC", "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+            // Mixed case that does not follow EIP-55.
+            ("ETH", "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"),
+            ("ETH", "5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+            ("USDT-ERC20", "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeA"),
+            ("TRX", "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6u"),
+            ("USDT-TRC20", "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa"),
+        ] {
+            let result = handler_saving(0)
+                .handle(Box::new(crypto_command(
+                    "CRYPTO",
+                    Some(network),
+                    Some(address),
+                )))
+                .await;
+
+            assert_eq!(
+                result,
+                Err(CommandError::new(ERR_INVALID_CRYPTO_ADDRESS.to_string())),
+                "{} {} should be invalid",
+                network,
+                address
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_wallet_does_not_match_the_method() {
+        for (command, error) in [
+            (
+                crypto_command("CRYPTO", None, None),
+                ERR_CRYPTO_WALLET_REQUIRED,
+            ),
+            (
+                crypto_command("CRYPTO", Some("BTC"), None),
+                ERR_CRYPTO_WALLET_REQUIRED,
+            ),
+            (
+                crypto_command(
+                    "CRYPTO",
+                    Some("DOGE"),
+                    Some("DH5yaieqoZN36fDVciNyRueRGvGLR3mr7L"),
+                ),
+                ERR_INVALID_CRYPTO_NETWORK,
+            ),
+            (
+                crypto_command(
+                    "PAYPAL",
+                    Some("BTC"),
+                    Some("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa"),
+                ),
+                ERR_CRYPTO_WALLET_NOT_ALLOWED,
+            ),
+        ] {
+            let result = handler_saving(0).handle(Box::new(command)).await;
+
+            assert_eq!(result, Err(CommandError::new(error.to_string())));
+        }
+    }
+
+    fn paypal_command(
+        payment_method: &str,
+        paypal_email: Option<&str>,
+    ) -> CreateUserPaymentMethodCommand {
+        CreateUserPaymentMethodCommand {
+            paypal_email: paypal_email.map(str::to_string),
+            ..crypto_command(payment_method, None, None)
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_create_paypal_method_with_its_account_email() {
+        let result = handler_saving(1)
+            .handle(Box::new(paypal_command(
+                "PAYPAL",
+                Some("recipient@example.com"),
+            )))
+            .await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_paypal_email_does_not_match_the_method() {
+        for (command, error) in [
+            (paypal_command("PAYPAL", None), ERR_PAYPAL_EMAIL_REQUIRED),
+            (
+                paypal_command("PAYPAL", Some("recipient.example.com")),
+                ERR_INVALID_USER_PAYMENT_METHOD_PAYPAL_EMAIL,
+            ),
+            (
+                paypal_command("MANUAL", Some("recipient@example.com")),
+                ERR_PAYPAL_EMAIL_NOT_ALLOWED,
+            ),
+        ] {
+            let result = handler_saving(0).handle(Box::new(command)).await;
+
+            assert_eq!(result, Err(CommandError::new(error.to_string())));
+        }
+    }
 }
@@ -44,6 +44,10 @@ impl UserPaymentMethodCreator {
         user_id: String,
         payment_method: String,
         instructions: String,
+        crypto_network: Option<String>,
+        crypto_address: Option<String>,
+        paypal_email: Option<String>,
+        currencies: Vec<String>,
         created_at: OffsetDateTime,
         updated_at: OffsetDateTime,
     ) -> Result<(), String> {
@@ -54,6 +58,10 @@ impl UserPaymentMethodCreator {
             user_id,
             payment_method,
             instructions,
+            crypto_network,
+            crypto_address,
+            paypal_email,
+            currencies,
             created_at,
             updated_at,
         )?;
@@ -32,6 +32,10 @@ impl UserPaymentMethodFinder {
             user_id: user_payment_method.user_id().to_string(),
             payment_method: user_payment_method.payment_method().to_string(),
             instructions: user_payment_method.instructions().to_string(),
+            crypto_network: user_payment_method.crypto_network(),
+            crypto_address: user_payment_method.crypto_address(),
+            paypal_email: user_payment_method.paypal_email(),
+            currencies: user_payment_method.currencies(),
             created_at: user_payment_method.created_at(),
             updated_at: user_payment_method.updated_at(),
         })
@@ -0,0 +1,357 @@
+use shared::domain::bus::query::{Query, QueryError, QueryHandler, Response};
+
+use super::service::UserPaymentMethodQrCodeGetter;
+
+pub const GET_USER_PAYMENT_METHOD_QR_CODE_QUERY_TYPE: &str =
+    "dona.get_user_payment_method_qr_code.query";
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GetUserPaymentMethodQrCodeQuery {
+    pub id: String,
+    pub format: String,
+}
+
+impl Query for GetUserPaymentMethodQrCodeQuery {
+    fn query_type(&self) -> &'static str {
+        GET_USER_PAYMENT_METHOD_QR_CODE_QUERY_TYPE
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct GetUserPaymentMethodQrCodeQueryHandler {
+    service: UserPaymentMethodQrCodeGetter,
+}
+
+impl GetUserPaymentMethodQrCodeQueryHandler {
+    pub fn new(service: UserPaymentMethodQrCodeGetter) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler for GetUserPaymentMethodQrCodeQueryHandler {
+    async fn handle(&self, query: Box<dyn Query>) -> Result<Box<dyn Response>, QueryError> {
+        let query = query
+            .as_any()
+            .downcast_ref::<GetUserPaymentMethodQrCodeQuery>()
+            .ok_or_else(|| QueryError::new("Invalid query".to_string()))?;
+
+        let qr_code = self
+            .service
+            .execute(query.id.to_owned(), query.format.to_owned())
+            .await
+            .map_err(QueryError::new)?;
+
+        Ok(Box::new(qr_code))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockall::predicate;
+    use shared::{
+        domain::{
+            base_errors::BaseRepositoryError, storage::tests::MockFileStorageRepository,
+            utils::new_uuid,
+        },
+        USER_PAYMENT_METHOD_STORAGE_MODEL,
+    };
+    use time::OffsetDateTime;
+
+    use super::*;
+
+    use crate::user_payment_method::application::response::UserPaymentMethodQrCodeResponse;
+    use crate::user_payment_method::domain::payment_qr_code::{
+        QrCodeFormat, ERR_INVALID_QR_CODE_FORMAT,
+    };
+    use crate::user_payment_method::domain::qr_code_renderer::{
+        tests::MockQrCodeRenderer, QrCodeRenderer,
+    };
+    use crate::user_payment_method::domain::user_payment_method::tests::UserPaymentMethodMother;
+    use crate::user_payment_method::domain::user_payment_method::UserPaymentMethod;
+    use crate::user_payment_method::domain::user_payment_method_repository::tests::MockUserPaymentMethodRepository;
+
+    fn method(
+        payment_method: &str,
+        instructions: &str,
+        crypto_wallet: Option<(&str, &str)>,
+        currencies: Vec<&str>,
+    ) -> UserPaymentMethod {
+        UserPaymentMethod::new(
+            new_uuid(),
+            new_uuid(),
+            payment_method.to_string(),
+            instructions.to_string(),
+            crypto_wallet.map(|(network, _)| network.to_string()),
+            crypto_wallet.map(|(_, address)| address.to_string()),
+            None,
+            currencies.into_iter().map(str::to_string).collect(),
+            OffsetDateTime::now_utc(),
+            OffsetDateTime::now_utc(),
+        )
+        .unwrap()
+    }
+
+    fn handler(
+        method: &UserPaymentMethod,
+        storage_repository: MockFileStorageRepository,
+        renderers: Vec<MockQrCodeRenderer>,
+    ) -> GetUserPaymentMethodQrCodeQueryHandler {
+        let mut repository = MockUserPaymentMethodRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Ok(method.clone()));
+
+        GetUserPaymentMethodQrCodeQueryHandler::new(UserPaymentMethodQrCodeGetter::new(
+            Arc::new(repository),
+            Arc::new(storage_repository),
+            renderers
+                .into_iter()
+                .map(|renderer| Arc::new(renderer) as Arc<dyn QrCodeRenderer>)
+                .collect(),
+        ))
+    }
+
+    fn renderer_of(format: QrCodeFormat, content: String, times: usize) -> MockQrCodeRenderer {
+        let mut renderer = MockQrCodeRenderer::new();
+        renderer.expect_format().return_const(format);
+        renderer
+            .expect_render()
+            .with(predicate::eq(content))
+            .times(times)
+            .returning(|_| Ok(b"qr".to_vec()));
+        renderer
+    }
+
+    fn filename(method: &UserPaymentMethod, extension: &str) -> String {
+        format!("qr-{}.{}", method.updated_at().unix_timestamp(), extension)
+    }
+
+    fn empty_storage(method: &UserPaymentMethod, filename: String) -> MockFileStorageRepository {
+        let mut storage_repository = MockFileStorageRepository::new();
+        storage_repository
+            .expect_get()
+            .times(1)
+            .returning(|_, _, _| Err("File not found".to_string()));
+        storage_repository
+            .expect_save()
+            .with(
+                predicate::eq(USER_PAYMENT_METHOD_STORAGE_MODEL.to_string()),
+                predicate::eq(method.id()),
+                predicate::eq(filename),
+                predicate::always(),
+            )
+            .times(1)
+            .returning(|_, _, filename, _| Ok(filename));
+        storage_repository
+    }
+
+    async fn ask(
+        handler: GetUserPaymentMethodQrCodeQueryHandler,
+        method: &UserPaymentMethod,
+        format: &str,
+    ) -> Result<UserPaymentMethodQrCodeResponse, QueryError> {
+        handler
+            .handle(Box::new(GetUserPaymentMethodQrCodeQuery {
+                id: method.id(),
+                format: format.to_string(),
+            }))
+            .await
+            .map(|response| {
+                response
+                    .as_any()
+                    .downcast_ref::<UserPaymentMethodQrCodeResponse>()
+                    .unwrap()
+                    .to_owned()
+            })
+    }
+
+    #[tokio::test]
+    async fn it_should_render_and_store_the_code_on_first_request() {
+        let method = method(
+            "CRYPTO",
+            "Send BTC only",
+            Some(("BTC", "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa")),
+            vec!["USD"],
+        );
+
+        let handler = handler(
+            &method,
+            empty_storage(&method, filename(&method, "png")),
+            vec![
+                renderer_of(
+                    QrCodeFormat::Png,
+                    "bitcoin:1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa".to_string(),
+                    1,
+                ),
+                renderer_of(QrCodeFormat::Svg, String::new(), 0),
+            ],
+        );
+
+        let response = ask(handler, &method, "png").await.unwrap();
+
+        assert_eq!(
+            response,
+            UserPaymentMethodQrCodeResponse {
+                path: format!(
+                    "user_payment_method/{}/{}",
+                    method.id(),
+                    filename(&method, "png")
+                ),
+                content_type: "image/png".to_string(),
+                updated_at: method.updated_at(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_encode_sepa_transfers_as_epc_payload() {
+        let method = method(
+            "MANUAL",
+            "Bank transfer\nIBAN: DE89 3704 0044 0532 0130 00\nBIC: cobadeffxxx\nName: Jane Doe",
+            None,
+            vec!["EUR", "USD"],
+        );
+
+        let handler = handler(
+            &method,
+            empty_storage(&method, filename(&method, "svg")),
+            vec![renderer_of(
+                QrCodeFormat::Svg,
+                "BCD\n002\n1\nSCT\nCOBADEFFXXX\nJane Doe\nDE89370400440532013000".to_string(),
+                1,
+            )],
+        );
+
+        let response = ask(handler, &method, "SVG").await.unwrap();
+
+        assert_eq!(response.content_type, "image/svg+xml");
+    }
+
+    #[tokio::test]
+    async fn it_should_encode_the_raw_instructions_otherwise() {
+        for method in [
+            // Not in EUR.
+            method(
+                "MANUAL",
+                "IBAN: DE89 3704 0044 0532 0130 00\nName: Jane Doe",
+                None,
+                vec!["USD"],
+            ),
+            // Wrong check digits.
+            method(
+                "MANUAL",
+                "IBAN: DE88 3704 0044 0532 0130 00\nName: Jane Doe",
+                None,
+                vec!["EUR"],
+            ),
+            // No beneficiary.
+            method(
+                "MANUAL",
+                "IBAN: DE89 3704 0044 0532 0130 00",
+                None,
+                vec!["EUR"],
+            ),
+            method("PAYPAL", "paypal.me/janedoe", None, vec!["EUR"]),
+        ] {
+            let handler = handler(
+                &method,
+                empty_storage(&method, filename(&method, "png")),
+                vec![renderer_of(QrCodeFormat::Png, method.instructions(), 1)],
+            );
+
+            let result = ask(handler, &method, "png").await;
+
+            assert!(result.is_ok(), "Result should be Ok");
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_not_render_a_stored_code() {
+        let method = UserPaymentMethodMother::random();
+
+        let mut storage_repository = MockFileStorageRepository::new();
+        storage_repository
+            .expect_get()
+            .times(1)
+            .returning(|_, _, _| Ok(tempfile::tempfile().unwrap()));
+        storage_repository.expect_save().times(0);
+
+        let handler = handler(
+            &method,
+            storage_repository,
+            vec![renderer_of(QrCodeFormat::Png, String::new(), 0)],
+        );
+
+        let response = ask(handler, &method, "png").await.unwrap();
+
+        assert_eq!(
+            response.path,
+            format!(
+                "user_payment_method/{}/{}",
+                method.id(),
+                filename(&method, "png")
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_format_is_unknown() {
+        let mut repository = MockUserPaymentMethodRepository::new();
+        repository.expect_find_by_id().times(0);
+
+        let handler =
+            GetUserPaymentMethodQrCodeQueryHandler::new(UserPaymentMethodQrCodeGetter::new(
+                Arc::new(repository),
+                Arc::new(MockFileStorageRepository::new()),
+                vec![],
+            ));
+
+        let result = handler
+            .handle(Box::new(GetUserPaymentMethodQrCodeQuery {
+                id: new_uuid(),
+                format: "gif".to_string(),
+            }))
+            .await;
+
+        assert_eq!(
+            result.err(),
+            Some(QueryError::new(ERR_INVALID_QR_CODE_FORMAT.to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_method_does_not_exist() {
+        let mut repository = MockUserPaymentMethodRepository::new();
+        repository
+            .expect_find_by_id()
+            .times(1)
+            .return_const(Err(BaseRepositoryError::NotFound));
+
+        let mut storage_repository = MockFileStorageRepository::new();
+        storage_repository.expect_get().times(0);
+
+        let handler =
+            GetUserPaymentMethodQrCodeQueryHandler::new(UserPaymentMethodQrCodeGetter::new(
+                Arc::new(repository),
+                Arc::new(storage_repository),
+                vec![],
+            ));
+
+        let result = handler
+            .handle(Box::new(GetUserPaymentMethodQrCodeQuery {
+                id: new_uuid(),
+                format: "png".to_string(),
+            }))
+            .await;
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,96 @@
+use std::{
+    io::{Seek, SeekFrom, Write},
+    sync::Arc,
+};
+
+use shared::{domain::storage::FileStorageRepository, USER_PAYMENT_METHOD_STORAGE_MODEL};
+
+use crate::user_payment_method::{
+    application::response::UserPaymentMethodQrCodeResponse,
+    domain::{
+        payment_qr_code::{PaymentQrCode, QrCodeFormat, ERR_INVALID_QR_CODE_FORMAT},
+        qr_code_renderer::QrCodeRenderer,
+        user_payment_method::UserPaymentMethodId,
+        user_payment_method_repository::UserPaymentMethodRepository,
+    },
+};
+
+#[derive(Clone)]
+pub struct UserPaymentMethodQrCodeGetter {
+    user_payment_method_repository: Arc<dyn UserPaymentMethodRepository>,
+    storage_repository: Arc<dyn FileStorageRepository>,
+    renderers: Vec<Arc<dyn QrCodeRenderer>>,
+}
+
+impl UserPaymentMethodQrCodeGetter {
+    pub fn new(
+        user_payment_method_repository: Arc<dyn UserPaymentMethodRepository>,
+        storage_repository: Arc<dyn FileStorageRepository>,
+        renderers: Vec<Arc<dyn QrCodeRenderer>>,
+    ) -> Self {
+        Self {
+            user_payment_method_repository,
+            storage_repository,
+            renderers,
+        }
+    }
+
+    /// Codes are rendered on the first request and kept in the storage until the instructions
+    /// of the method change, a new update renders them under a new name.
+    pub async fn execute(
+        &self,
+        id: String,
+        format: String,
+    ) -> Result<UserPaymentMethodQrCodeResponse, String> {
+        let format = QrCodeFormat::new(format)?;
+        let user_payment_method = self
+            .user_payment_method_repository
+            .find_by_id(UserPaymentMethodId::new(id)?)
+            .await
+            .map_err(|e| e.to_string())?;
+        let filename = PaymentQrCode::filename(format, user_payment_method.updated_at());
+
+        let is_cached = self
+            .storage_repository
+            .get(
+                USER_PAYMENT_METHOD_STORAGE_MODEL.to_string(),
+                user_payment_method.id(),
+                filename.clone(),
+            )
+            .await
+            .is_ok();
+        if !is_cached {
+            let renderer = self
+                .renderers
+                .iter()
+                .find(|renderer| renderer.format() == format)
+                .ok_or(ERR_INVALID_QR_CODE_FORMAT.to_string())?;
+            let content =
+                renderer.render(&PaymentQrCode::from_method(&user_payment_method).content())?;
+
+            let mut file = tempfile::tempfile().map_err(|e| e.to_string())?;
+            file.write_all(&content).map_err(|e| e.to_string())?;
+            file.seek(SeekFrom::Start(0)).map_err(|e| e.to_string())?;
+
+            self.storage_repository
+                .save(
+                    USER_PAYMENT_METHOD_STORAGE_MODEL.to_string(),
+                    user_payment_method.id(),
+                    filename.clone(),
+                    file,
+                )
+                .await?;
+        }
+
+        Ok(UserPaymentMethodQrCodeResponse {
+            path: format!(
+                "{}/{}/{}",
+                USER_PAYMENT_METHOD_STORAGE_MODEL,
+                user_payment_method.id(),
+                filename
+            ),
+            content_type: format.content_type().to_string(),
+            updated_at: user_payment_method.updated_at(),
+        })
+    }
+}
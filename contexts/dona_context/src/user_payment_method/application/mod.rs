@@ -2,6 +2,8 @@ pub mod create;
 pub mod delete;
 pub mod find;
 pub mod find_by_criteria;
+pub mod get_qr_code;
 pub mod get_user_payment_methods;
+pub mod remove_qr_codes_on_instructions_updated;
 pub mod response;
 pub mod update_instructions;
@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use shared::domain::bus::event::{Event, EventError, EventHandler};
+
+use crate::user_payment_method::domain::user_payment_method_update_instructions_event::{
+    UserPaymentMethodInstructionsUpdatedEvent, USER_PAYMENT_METHOD_INST_UPDATED,
+};
+
+use super::service::UserPaymentMethodQrCodesRemover;
+
+#[derive(Clone)]
+pub struct RemoveQrCodesOnInstructionsUpdated {
+    service: UserPaymentMethodQrCodesRemover,
+}
+
+impl RemoveQrCodesOnInstructionsUpdated {
+    pub fn new(service: UserPaymentMethodQrCodesRemover) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl EventHandler for RemoveQrCodesOnInstructionsUpdated {
+    async fn handle(&self, event: Arc<dyn Event>) -> Result<(), EventError> {
+        let event = event
+            .as_any()
+            .downcast_ref::<UserPaymentMethodInstructionsUpdatedEvent>()
+            .ok_or_else(|| EventError::new("Invalid event".to_string()))?;
+
+        self.service
+            .execute(
+                event.id().to_string(),
+                event.previous_updated_at().to_string(),
+            )
+            .await
+            .map_err(EventError::new)
+    }
+
+    fn subscribed_to(&self) -> Vec<&'static str> {
+        vec![USER_PAYMENT_METHOD_INST_UPDATED]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate;
+    use shared::{
+        domain::{storage::tests::MockFileStorageRepository, utils::new_uuid},
+        USER_PAYMENT_METHOD_STORAGE_MODEL,
+    };
+
+    use super::*;
+
+    fn event(id: String) -> Arc<dyn Event> {
+        Arc::new(UserPaymentMethodInstructionsUpdatedEvent::new(
+            id,
+            new_uuid(),
+            "MANUAL".to_string(),
+            "IBAN: DE89 3704 0044 0532 0130 00".to_string(),
+            String::new(),
+            String::new(),
+            String::new(),
+            "2024-04-10T10:00:00Z".to_string(),
+            "2024-04-10T11:00:00Z".to_string(),
+            "2024-04-10T10:00:00Z".to_string(),
+        ))
+    }
+
+    #[tokio::test]
+    async fn it_should_remove_the_stored_codes() {
+        let id = new_uuid();
+
+        let mut storage_repository = MockFileStorageRepository::new();
+        storage_repository
+            .expect_get()
+            .with(
+                predicate::eq(USER_PAYMENT_METHOD_STORAGE_MODEL.to_string()),
+                predicate::eq(id.clone()),
+                predicate::eq("qr-1712743200.png".to_string()),
+            )
+            .times(1)
+            .returning(|_, _, _| Ok(tempfile::tempfile().unwrap()));
+        storage_repository
+            .expect_get()
+            .with(
+                predicate::eq(USER_PAYMENT_METHOD_STORAGE_MODEL.to_string()),
+                predicate::eq(id.clone()),
+                predicate::eq("qr-1712743200.svg".to_string()),
+            )
+            .times(1)
+            .returning(|_, _, _| Err("File not found".to_string()));
+        storage_repository
+            .expect_delete()
+            .with(
+                predicate::eq(USER_PAYMENT_METHOD_STORAGE_MODEL.to_string()),
+                predicate::eq(id.clone()),
+                predicate::eq("qr-1712743200.png".to_string()),
+            )
+            .times(1)
+            .return_const(Ok(()));
+
+        let handler = RemoveQrCodesOnInstructionsUpdated::new(
+            UserPaymentMethodQrCodesRemover::new(Arc::new(storage_repository)),
+        );
+
+        let result = handler.handle(event(id)).await;
+
+        assert!(result.is_ok(), "Result should be Ok");
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_a_code_cannot_be_removed() {
+        let mut storage_repository = MockFileStorageRepository::new();
+        storage_repository
+            .expect_get()
+            .returning(|_, _, _| Ok(tempfile::tempfile().unwrap()));
+        storage_repository
+            .expect_delete()
+            .times(1)
+            .return_const(Err("Permission denied".to_string()));
+
+        let handler = RemoveQrCodesOnInstructionsUpdated::new(
+            UserPaymentMethodQrCodesRemover::new(Arc::new(storage_repository)),
+        );
+
+        let result = handler.handle(event(new_uuid())).await;
+
+        assert_eq!(
+            result,
+            Err(EventError::new("Permission denied".to_string()))
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use shared::{domain::storage::FileStorageRepository, USER_PAYMENT_METHOD_STORAGE_MODEL};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+use crate::user_payment_method::domain::{
+    payment_qr_code::{PaymentQrCode, QrCodeFormat},
+    user_payment_method::UserPaymentMethodId,
+};
+
+#[derive(Clone)]
+pub struct UserPaymentMethodQrCodesRemover {
+    storage_repository: Arc<dyn FileStorageRepository>,
+}
+
+impl UserPaymentMethodQrCodesRemover {
+    pub fn new(storage_repository: Arc<dyn FileStorageRepository>) -> Self {
+        Self { storage_repository }
+    }
+
+    /// Only the codes rendered so far are in the storage, the next request renders them
+    /// again from the new instructions.
+    pub async fn execute(&self, id: String, previous_updated_at: String) -> Result<(), String> {
+        let id = UserPaymentMethodId::new(id)?;
+        let previous_updated_at =
+            OffsetDateTime::parse(&previous_updated_at, &Rfc3339).map_err(|e| e.to_string())?;
+
+        for format in QrCodeFormat::all() {
+            let filename = PaymentQrCode::filename(format, previous_updated_at);
+            let is_cached = self
+                .storage_repository
+                .get(
+                    USER_PAYMENT_METHOD_STORAGE_MODEL.to_string(),
+                    id.to_string(),
+                    filename.clone(),
+                )
+                .await
+                .is_ok();
+            if !is_cached {
+                continue;
+            }
+
+            self.storage_repository
+                .delete(
+                    USER_PAYMENT_METHOD_STORAGE_MODEL.to_string(),
+                    id.to_string(),
+                    filename,
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
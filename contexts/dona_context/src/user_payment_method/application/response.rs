@@ -8,6 +8,10 @@ pub struct UserPaymentMethodResponse {
     pub user_id: String,
     pub payment_method: String,
     pub instructions: String,
+    pub crypto_network: Option<String>,
+    pub crypto_address: Option<String>,
+    pub paypal_email: Option<String>,
+    pub currencies: Vec<String>,
     pub created_at: OffsetDateTime,
     pub updated_at: OffsetDateTime,
 }
@@ -28,3 +32,19 @@ impl Response for UserPaymentMethodsResponse {
         self
     }
 }
+
+/// Where the rendered QR code of a payment method is stored, relative to the public storage.
+/// `updated_at` is the one of the method, which moves whenever the code has to be rendered
+/// again, so it can bust client caches.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct UserPaymentMethodQrCodeResponse {
+    pub path: String,
+    pub content_type: String,
+    pub updated_at: OffsetDateTime,
+}
+
+impl Response for UserPaymentMethodQrCodeResponse {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
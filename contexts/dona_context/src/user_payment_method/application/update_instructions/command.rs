@@ -11,6 +11,11 @@ pub struct UpdateUserPaymentMethodInstructionsCommand {
     pub id: String,
     pub user_id: String,
     pub instructions: String,
+    /// Replaces the wallet of a crypto method, both are left out to keep it.
+    pub crypto_network: Option<String>,
+    pub crypto_address: Option<String>,
+    /// Replaces the account email of a PayPal method, left out to keep it.
+    pub paypal_email: Option<String>,
     pub updated_at: OffsetDateTime,
 }
 
@@ -48,6 +53,9 @@ impl CommandHandler for UpdateUserPaymentMethodInstructionsCommandHandler {
                 command.id.to_owned(),
                 command.user_id.to_owned(),
                 command.instructions.to_owned(),
+                command.crypto_network.to_owned(),
+                command.crypto_address.to_owned(),
+                command.paypal_email.to_owned(),
                 command.updated_at,
             )
             .await
@@ -103,6 +111,9 @@ mod tests {
             id: "id".to_string(),
             user_id: "user_id".to_string(),
             instructions: "instructions".to_string(),
+            crypto_network: None,
+            crypto_address: None,
+            paypal_email: None,
             updated_at: OffsetDateTime::now_utc(),
         };
 
@@ -158,6 +169,9 @@ mod tests {
             id: method.id().to_string(),
             user_id: method.user_id().to_string(),
             instructions: "instructions".to_string(),
+            crypto_network: None,
+            crypto_address: None,
+            paypal_email: None,
             updated_at: OffsetDateTime::now_utc(),
         };
 
@@ -198,7 +212,7 @@ mod tests {
 
         let mut method_clone = method.clone();
         method_clone
-            .update_instructions("instructions".to_string(), updated_at)
+            .update_instructions("instructions".to_string(), None, None, None, updated_at)
             .unwrap();
         repository
             .expect_save()
@@ -217,6 +231,9 @@ mod tests {
             id: method.id().to_string(),
             user_id: method.user_id().to_string(),
             instructions: "instructions".to_string(),
+            crypto_network: None,
+            crypto_address: None,
+            paypal_email: None,
             updated_at,
         };
 
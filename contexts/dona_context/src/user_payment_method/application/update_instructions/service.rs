@@ -64,10 +64,19 @@ impl UserPaymentMethodInstructionsUpdater {
         id: String,
         user_id: String,
         instructions: String,
+        crypto_network: Option<String>,
+        crypto_address: Option<String>,
+        paypal_email: Option<String>,
         updated_at: OffsetDateTime,
     ) -> Result<(), String> {
         let mut user_payment_method = self.method_finder(id.clone(), user_id.clone()).await?;
-        user_payment_method.update_instructions(instructions, updated_at)?;
+        user_payment_method.update_instructions(
+            instructions,
+            crypto_network,
+            crypto_address,
+            paypal_email,
+            updated_at,
+        )?;
 
         self.repository.save(&user_payment_method).await?;
 
@@ -0,0 +1,186 @@
+use std::fmt::Display;
+
+use bech32::{hrp, segwit};
+use sha3::{Digest, Keccak256};
+
+pub const ERR_INVALID_CRYPTO_NETWORK: &str = "Invalid crypto network";
+
+/// Networks a crypto payment method can receive on. Tokens are named after the chain that
+/// carries them, since the same token has different addresses on each chain.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum CryptoNetwork {
+    Btc,
+    Eth,
+    UsdtErc20,
+    Trx,
+    UsdtTrc20,
+}
+
+impl CryptoNetwork {
+    pub fn new(value: String) -> Result<Self, String> {
+        match value.as_str() {
+            "BTC" => Ok(Self::Btc),
+            "ETH" => Ok(Self::Eth),
+            "USDT-ERC20" => Ok(Self::UsdtErc20),
+            "TRX" => Ok(Self::Trx),
+            "USDT-TRC20" => Ok(Self::UsdtTrc20),
+            _ => Err(ERR_INVALID_CRYPTO_NETWORK.to_string()),
+        }
+    }
+
+    fn is_valid_address(&self, address: &str) -> bool {
+        match self {
+            Self::Btc => is_bitcoin_address(address),
+            Self::Eth | Self::UsdtErc20 => is_ethereum_address(address),
+            Self::Trx | Self::UsdtTrc20 => is_tron_address(address),
+        }
+    }
+
+    /// Every supported network identifies transactions by a 32 byte hash in hex, which
+    /// Ethereum tools print with a `0x` prefix.
+    pub fn is_valid_tx_hash(&self, tx_hash: &str) -> bool {
+        let tx_hash = match self {
+            Self::Eth | Self::UsdtErc20 => tx_hash.strip_prefix("0x").unwrap_or(tx_hash),
+            Self::Btc | Self::Trx | Self::UsdtTrc20 => tx_hash,
+        };
+
+        tx_hash.len() == 64 && tx_hash.chars().all(|c| c.is_ascii_hexdigit())
+    }
+}
+
+impl Display for CryptoNetwork {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Btc => write!(f, "BTC"),
+            Self::Eth => write!(f, "ETH"),
+            Self::UsdtErc20 => write!(f, "USDT-ERC20"),
+            Self::Trx => write!(f, "TRX"),
+            Self::UsdtTrc20 => write!(f, "USDT-TRC20"),
+        }
+    }
+}
+
+/// Mainnet only: legacy and script addresses in Base58Check, or segwit and taproot in bech32.
+fn is_bitcoin_address(address: &str) -> bool {
+    if address.to_lowercase().starts_with("bc1") {
+        return segwit::decode(address).is_ok_and(|(address_hrp, _, _)| address_hrp == hrp::BC);
+    }
+
+    bs58::decode(address)
+        .with_check(None)
+        .into_vec()
+        .is_ok_and(|payload| payload.len() == 21 && matches!(payload[0], 0x00 | 0x05))
+}
+
+/// All lower or all upper case addresses carry no checksum, mixed case ones must match
+/// EIP-55.
+fn is_ethereum_address(address: &str) -> bool {
+    let Some(hex) = address.strip_prefix("0x") else {
+        return false;
+    };
+    if hex.len() != 40 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return false;
+    }
+    if !hex.chars().any(|c| c.is_ascii_lowercase()) || !hex.chars().any(|c| c.is_ascii_uppercase())
+    {
+        return true;
+    }
+
+    let hash = Keccak256::digest(hex.to_lowercase().as_bytes());
+    hex.chars().enumerate().all(|(i, c)| {
+        let nibble = (hash[i / 2] >> if i % 2 == 0 { 4 } else { 0 }) & 0x0f;
+        !c.is_ascii_alphabetic() || c.is_ascii_uppercase() == (nibble >= 8)
+    })
+}
+
+/// Base58Check with the 0x41 prefix, which makes every address start with `T`.
+fn is_tron_address(address: &str) -> bool {
+    bs58::decode(address)
+        .with_check(Some(0x41))
+        .into_vec()
+        .is_ok_and(|payload| payload.len() == 21)
+}
+
+pub const ERR_INVALID_CRYPTO_ADDRESS: &str = "Invalid crypto address for the network";
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct CryptoAddress(String);
+
+impl CryptoAddress {
+    pub fn new(network: &CryptoNetwork, value: String) -> Result<Self, String> {
+        let value = value.trim().to_string();
+
+        if network.is_valid_address(&value) {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_CRYPTO_ADDRESS.to_string())
+        }
+    }
+}
+
+impl Display for CryptoAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Where a crypto payment method receives donas. The address is only valid for its network.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct CryptoWallet {
+    network: CryptoNetwork,
+    address: CryptoAddress,
+}
+
+impl CryptoWallet {
+    pub fn new(network: String, address: String) -> Result<Self, String> {
+        let network = CryptoNetwork::new(network)?;
+        let address = CryptoAddress::new(&network, address)?;
+
+        Ok(Self { network, address })
+    }
+
+    pub fn network(&self) -> CryptoNetwork {
+        self.network
+    }
+
+    pub fn address(&self) -> String {
+        self.address.to_string()
+    }
+}
+
+pub mod tests {
+    use rand::seq::SliceRandom;
+
+    use super::*;
+
+    /// Well known mainnet addresses, one per address format.
+    pub const CRYPTO_WALLETS: [(&str, &str); 8] = [
+        ("BTC", "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa"),
+        ("BTC", "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy"),
+        ("BTC", "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq"),
+        (
+            "BTC",
+            "bc1p5d7rjq7g6rdk2yhzks9smlaqtedr4dekq08ge8ztwac72sfr9rusxg3297",
+        ),
+        ("ETH", "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+        ("USDT-ERC20", "0xdbf03b407c01e7cd3cbea99509d93f8dddc8c6fb"),
+        ("TRX", "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t"),
+        ("USDT-TRC20", "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t"),
+    ];
+
+    pub struct CryptoWalletMother;
+
+    impl CryptoWalletMother {
+        pub fn create(network: Option<String>, address: Option<String>) -> CryptoWallet {
+            match (network, address) {
+                (Some(network), Some(address)) => CryptoWallet::new(network, address).unwrap(),
+                _ => Self::random(),
+            }
+        }
+
+        pub fn random() -> CryptoWallet {
+            let (network, address) = CRYPTO_WALLETS.choose(&mut rand::thread_rng()).unwrap();
+            CryptoWallet::new(network.to_string(), address.to_string()).unwrap()
+        }
+    }
+}
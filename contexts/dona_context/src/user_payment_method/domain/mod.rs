@@ -1,3 +1,6 @@
+pub mod crypto_wallet;
+pub mod payment_qr_code;
+pub mod qr_code_renderer;
 pub mod user_payment_method;
 pub mod user_payment_method_created_event;
 pub mod user_payment_method_repository;
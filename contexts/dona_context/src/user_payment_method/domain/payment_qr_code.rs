@@ -0,0 +1,144 @@
+use std::fmt::Display;
+
+use time::OffsetDateTime;
+
+use super::{crypto_wallet::CryptoNetwork, user_payment_method::UserPaymentMethod};
+
+pub const ERR_INVALID_QR_CODE_FORMAT: &str = "Invalid QR code format";
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum QrCodeFormat {
+    Png,
+    Svg,
+}
+
+impl QrCodeFormat {
+    pub fn new(value: String) -> Result<Self, String> {
+        match value.to_lowercase().as_str() {
+            "png" => Ok(Self::Png),
+            "svg" => Ok(Self::Svg),
+            _ => Err(ERR_INVALID_QR_CODE_FORMAT.to_string()),
+        }
+    }
+
+    pub fn all() -> [Self; 2] {
+        [Self::Png, Self::Svg]
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Png => "image/png",
+            Self::Svg => "image/svg+xml",
+        }
+    }
+}
+
+impl Display for QrCodeFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Png => write!(f, "png"),
+            Self::Svg => write!(f, "svg"),
+        }
+    }
+}
+
+/// Labels a recipient may use in the instructions of a bank transfer, compared without case.
+const IBAN_LABELS: [&str; 1] = ["iban"];
+const BIC_LABELS: [&str; 2] = ["bic", "swift"];
+const BENEFICIARY_LABELS: [&str; 4] = ["name", "beneficiary", "account holder", "holder"];
+
+/// Value of the first `label: value` line of the instructions with one of the labels.
+fn labelled_value(instructions: &str, labels: &[&str]) -> Option<String> {
+    instructions.lines().find_map(|line| {
+        let (label, value) = line.split_once(':')?;
+        let value = value.trim();
+
+        (labels.contains(&label.trim().to_lowercase().as_str()) && !value.is_empty())
+            .then(|| value.to_string())
+    })
+}
+
+/// ISO 13616: country code, check digits and up to 30 characters, validated with mod 97.
+fn iban(value: &str) -> Option<String> {
+    let iban = value.replace(' ', "").to_uppercase();
+    if !(15..=34).contains(&iban.len())
+        || !iban.chars().all(|c| c.is_ascii_alphanumeric())
+        || !iban[..2].chars().all(|c| c.is_ascii_alphabetic())
+        || !iban[2..4].chars().all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let remainder = iban[4..]
+        .chars()
+        .chain(iban[..4].chars())
+        .fold(0, |remainder, c| {
+            let digits = c.to_digit(36).unwrap_or_default();
+            let shift = if digits < 10 { 10 } else { 100 };
+            (remainder * shift + digits) % 97
+        });
+
+    (remainder == 1).then_some(iban)
+}
+
+fn bic(value: &str) -> Option<String> {
+    let bic = value.replace(' ', "").to_uppercase();
+
+    (matches!(bic.len(), 8 | 11) && bic.chars().all(|c| c.is_ascii_alphanumeric())).then_some(bic)
+}
+
+/// EPC069-12 "SEPA credit transfer" payload, read by most European banking apps. Version 002
+/// makes the BIC optional, and trailing empty fields can be left out.
+fn epc_payload(instructions: &str) -> Option<String> {
+    let iban = iban(&labelled_value(instructions, &IBAN_LABELS)?)?;
+    let beneficiary = labelled_value(instructions, &BENEFICIARY_LABELS)?;
+    if beneficiary.chars().count() > 70 {
+        return None;
+    }
+    let bic = labelled_value(instructions, &BIC_LABELS)
+        .and_then(|value| bic(&value))
+        .unwrap_or_default();
+
+    Some(["BCD", "002", "1", "SCT", &bic, &beneficiary, &iban].join("\n"))
+}
+
+/// What a donor's phone reads when scanning the code of a payment method. Bitcoin and Ether
+/// wallets get a payment URI, EUR bank transfers with an IBAN and a beneficiary an EPC payload,
+/// and anything else the instructions as they were written.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct PaymentQrCode {
+    content: String,
+}
+
+impl PaymentQrCode {
+    pub fn from_method(method: &UserPaymentMethod) -> Self {
+        let content = match method.crypto_wallet() {
+            Some(wallet) => match wallet.network() {
+                CryptoNetwork::Btc => format!("bitcoin:{}", wallet.address()),
+                CryptoNetwork::Eth => format!("ethereum:{}", wallet.address()),
+                // Token transfers need the contract in the URI, the bare address is safer.
+                CryptoNetwork::UsdtErc20 | CryptoNetwork::Trx | CryptoNetwork::UsdtTrc20 => {
+                    wallet.address()
+                }
+            },
+            None => method
+                .currencies()
+                .contains(&"EUR".to_string())
+                .then(|| epc_payload(&method.instructions()))
+                .flatten()
+                .unwrap_or(method.instructions()),
+        };
+
+        Self { content }
+    }
+
+    pub fn content(&self) -> String {
+        self.content.clone()
+    }
+
+    /// Named after `updated_at` of the method, so a code rendered before its instructions
+    /// changed is never served again, even if it could not be removed.
+    pub fn filename(format: QrCodeFormat, updated_at: OffsetDateTime) -> String {
+        format!("qr-{}.{}", updated_at.unix_timestamp(), format)
+    }
+}
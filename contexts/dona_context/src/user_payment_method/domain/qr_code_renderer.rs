@@ -0,0 +1,23 @@
+use super::payment_qr_code::QrCodeFormat;
+
+/// Draws the content of a payment QR code as an image.
+pub trait QrCodeRenderer: Send + Sync {
+    fn format(&self) -> QrCodeFormat;
+    fn render(&self, content: &str) -> Result<Vec<u8>, String>;
+}
+
+#[cfg(test)]
+pub mod tests {
+    use mockall::mock;
+
+    use super::*;
+
+    mock! {
+        pub QrCodeRenderer {}
+
+        impl QrCodeRenderer for QrCodeRenderer {
+            fn format(&self) -> QrCodeFormat;
+            fn render(&self, content: &str) -> Result<Vec<u8>, String>;
+        }
+    }
+}
@@ -1,13 +1,17 @@
 use std::fmt::Display;
 use std::sync::Arc;
 
-use shared::domain::{bus::event::Event, utils::is_uuid, value_objects::user_id::UserId};
+use shared::domain::{
+    bus::event::Event,
+    utils::is_uuid,
+    value_objects::{money::Currency, user_id::UserId},
+};
 use time::{format_description::well_known::Rfc3339, OffsetDateTime};
 
 use crate::shared::domain::dona::DonaOptionMethod;
 
 use super::{
-    user_payment_method_created_event::UserPaymentMethodCreatedEvent,
+    crypto_wallet::CryptoWallet, user_payment_method_created_event::UserPaymentMethodCreatedEvent,
     user_payment_method_update_instructions_event::UserPaymentMethodInstructionsUpdatedEvent,
 };
 
@@ -54,6 +58,124 @@ impl Display for UserPaymentMethodInstructions {
     }
 }
 
+pub const ERR_CRYPTO_WALLET_REQUIRED: &str =
+    "Crypto payment methods need the network and the address of a wallet";
+pub const ERR_CRYPTO_WALLET_NOT_ALLOWED: &str =
+    "Only crypto payment methods take a wallet network and address";
+
+/// Crypto methods must name the wallet donas are sent to, other methods must not have one.
+fn crypto_wallet(
+    payment_method: &DonaOptionMethod,
+    crypto_network: Option<String>,
+    crypto_address: Option<String>,
+) -> Result<Option<CryptoWallet>, String> {
+    match (payment_method, crypto_network, crypto_address) {
+        (DonaOptionMethod::Crypto, Some(network), Some(address)) => {
+            Ok(Some(CryptoWallet::new(network, address)?))
+        }
+        (DonaOptionMethod::Crypto, _, _) => Err(ERR_CRYPTO_WALLET_REQUIRED.to_string()),
+        (_, None, None) => Ok(None),
+        (_, _, _) => Err(ERR_CRYPTO_WALLET_NOT_ALLOWED.to_string()),
+    }
+}
+
+pub const ERR_INVALID_USER_PAYMENT_METHOD_PAYPAL_EMAIL: &str =
+    "Invalid user payment method PayPal email";
+
+/// Email of the PayPal account donas are sent to, the one PayPal reports as the receiver.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserPaymentMethodPaypalEmail(String);
+
+impl UserPaymentMethodPaypalEmail {
+    pub fn new(value: String) -> Result<Self, String> {
+        let value = value.trim().to_string();
+        let is_valid = match value.split_once('@') {
+            Some((local, domain)) => {
+                !local.is_empty()
+                    && domain.contains('.')
+                    && !domain.starts_with('.')
+                    && !domain.ends_with('.')
+                    && !value.contains(char::is_whitespace)
+            }
+            None => false,
+        };
+
+        if is_valid {
+            Ok(Self(value))
+        } else {
+            Err(ERR_INVALID_USER_PAYMENT_METHOD_PAYPAL_EMAIL.to_string())
+        }
+    }
+
+    /// Emails are compared ignoring case, as PayPal does not keep the case they were typed in.
+    pub fn matches(&self, email: &str) -> bool {
+        self.0.eq_ignore_ascii_case(email.trim())
+    }
+}
+
+impl Display for UserPaymentMethodPaypalEmail {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub const ERR_PAYPAL_EMAIL_REQUIRED: &str =
+    "PayPal payment methods need the email of the account donas are sent to";
+pub const ERR_PAYPAL_EMAIL_NOT_ALLOWED: &str = "Only PayPal payment methods take an account email";
+
+/// Only PayPal methods have an account email. Methods saved before it was asked for may lack
+/// it, so only [`UserPaymentMethod::create`] requires it.
+fn paypal_email(
+    payment_method: &DonaOptionMethod,
+    paypal_email: Option<String>,
+) -> Result<Option<UserPaymentMethodPaypalEmail>, String> {
+    match (payment_method, paypal_email) {
+        (DonaOptionMethod::Paypal, Some(email)) => {
+            Ok(Some(UserPaymentMethodPaypalEmail::new(email)?))
+        }
+        (_, None) => Ok(None),
+        (_, Some(_)) => Err(ERR_PAYPAL_EMAIL_NOT_ALLOWED.to_string()),
+    }
+}
+
+pub const ERR_INVALID_USER_PAYMENT_METHOD_CURRENCIES: &str =
+    "Invalid user payment method currencies";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserPaymentMethodCurrencies(Vec<Currency>);
+
+impl UserPaymentMethodCurrencies {
+    pub fn new(values: Vec<String>) -> Result<Self, String> {
+        let mut currencies: Vec<Currency> = vec![];
+        for value in values {
+            let currency = Currency::new(value)?;
+            if !currencies.contains(&currency) {
+                currencies.push(currency);
+            }
+        }
+
+        if currencies.is_empty() {
+            return Err(ERR_INVALID_USER_PAYMENT_METHOD_CURRENCIES.to_string());
+        }
+
+        Ok(Self(currencies))
+    }
+
+    pub fn accepts(&self, currency: &Currency) -> bool {
+        self.0.contains(currency)
+    }
+
+    pub fn values(&self) -> Vec<String> {
+        self.0.iter().map(|currency| currency.to_string()).collect()
+    }
+}
+
+impl Display for UserPaymentMethodCurrencies {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.values().join(","))
+    }
+}
+
 pub const ERR_INVALID_USER_PAYMENT_METHOD_CREATED_AT: &str =
     "Invalid user payment method created at";
 
@@ -106,6 +228,9 @@ pub struct UserPaymentMethod {
     user_id: UserId,
     payment_method: DonaOptionMethod,
     instructions: UserPaymentMethodInstructions,
+    crypto_wallet: Option<CryptoWallet>,
+    paypal_email: Option<UserPaymentMethodPaypalEmail>,
+    currencies: UserPaymentMethodCurrencies,
     created_at: UserPaymentMethodCreatedAt,
     updated_at: UserPaymentMethodUpdatedAt,
 
@@ -118,6 +243,9 @@ impl PartialEq for UserPaymentMethod {
             && self.user_id == other.user_id
             && self.payment_method == other.payment_method
             && self.instructions == other.instructions
+            && self.crypto_wallet == other.crypto_wallet
+            && self.paypal_email == other.paypal_email
+            && self.currencies == other.currencies
             && self.created_at == other.created_at
             && self.updated_at == other.updated_at
     }
@@ -131,14 +259,23 @@ impl UserPaymentMethod {
         user_id: String,
         payment_method: String,
         instructions: String,
+        crypto_network: Option<String>,
+        crypto_address: Option<String>,
+        paypal_email: Option<String>,
+        currencies: Vec<String>,
         created_at: OffsetDateTime,
         updated_at: OffsetDateTime,
     ) -> Result<Self, String> {
+        let payment_method = DonaOptionMethod::new(payment_method)?;
+
         Ok(Self {
             id: UserPaymentMethodId::new(id)?,
             user_id: UserId::new(user_id)?,
-            payment_method: DonaOptionMethod::new(payment_method)?,
+            crypto_wallet: crypto_wallet(&payment_method, crypto_network, crypto_address)?,
+            paypal_email: self::paypal_email(&payment_method, paypal_email)?,
+            payment_method,
             instructions: UserPaymentMethodInstructions::new(instructions)?,
+            currencies: UserPaymentMethodCurrencies::new(currencies)?,
             created_at: UserPaymentMethodCreatedAt::new(created_at)?,
             updated_at: UserPaymentMethodUpdatedAt::new(updated_at)?,
 
@@ -151,6 +288,10 @@ impl UserPaymentMethod {
         user_id: String,
         payment_method: String,
         instructions: String,
+        crypto_network: Option<String>,
+        crypto_address: Option<String>,
+        paypal_email: Option<String>,
+        currencies: Vec<String>,
         created_at: OffsetDateTime,
         updated_at: OffsetDateTime,
     ) -> Result<Self, String> {
@@ -159,15 +300,26 @@ impl UserPaymentMethod {
             user_id,
             payment_method,
             instructions,
+            crypto_network,
+            crypto_address,
+            paypal_email,
+            currencies,
             created_at,
             updated_at,
         )?;
+        if method.payment_method == DonaOptionMethod::Paypal && method.paypal_email.is_none() {
+            return Err(ERR_PAYPAL_EMAIL_REQUIRED.to_string());
+        }
 
         method.record(Arc::new(UserPaymentMethodCreatedEvent::new(
             method.id().to_string(),
             method.user_id().to_string(),
             method.payment_method().to_string(),
             method.instructions().to_string(),
+            method.crypto_network().unwrap_or_default(),
+            method.crypto_address().unwrap_or_default(),
+            method.paypal_email().unwrap_or_default(),
+            method.currencies.to_string(),
             method.created_at().to_string(),
             method.updated_at().to_string(),
         )));
@@ -175,21 +327,45 @@ impl UserPaymentMethod {
         Ok(method)
     }
 
+    /// The wallet of a crypto method is kept unless a new network and address are given, and
+    /// so is the account email of a PayPal method.
     pub fn update_instructions(
         &mut self,
         instructions: String,
+        crypto_network: Option<String>,
+        crypto_address: Option<String>,
+        paypal_email: Option<String>,
         updated_at: OffsetDateTime,
     ) -> Result<(), String> {
-        self.instructions = UserPaymentMethodInstructions::new(instructions)?;
-        self.updated_at = UserPaymentMethodUpdatedAt::new(updated_at)?;
+        let instructions = UserPaymentMethodInstructions::new(instructions)?;
+        let crypto_wallet = if crypto_network.is_some() || crypto_address.is_some() {
+            crypto_wallet(&self.payment_method, crypto_network, crypto_address)?
+        } else {
+            self.crypto_wallet.clone()
+        };
+        let paypal_email = match paypal_email {
+            Some(email) => self::paypal_email(&self.payment_method, Some(email))?,
+            None => self.paypal_email.clone(),
+        };
+        let updated_at = UserPaymentMethodUpdatedAt::new(updated_at)?;
+        let previous_updated_at = self.updated_at;
+
+        self.instructions = instructions;
+        self.crypto_wallet = crypto_wallet;
+        self.paypal_email = paypal_email;
+        self.updated_at = updated_at;
 
         self.record(Arc::new(UserPaymentMethodInstructionsUpdatedEvent::new(
             self.id().to_string(),
             self.user_id().to_string(),
             self.payment_method().to_string(),
             self.instructions().to_string(),
+            self.crypto_network().unwrap_or_default(),
+            self.crypto_address().unwrap_or_default(),
+            self.paypal_email().unwrap_or_default(),
             self.created_at().to_string(),
             self.updated_at().to_string(),
+            previous_updated_at.value().format(&Rfc3339).unwrap(),
         )));
 
         Ok(())
@@ -221,6 +397,40 @@ impl UserPaymentMethod {
         self.instructions.to_string()
     }
 
+    pub fn crypto_wallet(&self) -> Option<&CryptoWallet> {
+        self.crypto_wallet.as_ref()
+    }
+
+    pub fn crypto_network(&self) -> Option<String> {
+        self.crypto_wallet
+            .as_ref()
+            .map(|wallet| wallet.network().to_string())
+    }
+
+    pub fn crypto_address(&self) -> Option<String> {
+        self.crypto_wallet.as_ref().map(|wallet| wallet.address())
+    }
+
+    pub fn paypal_email(&self) -> Option<String> {
+        self.paypal_email.as_ref().map(|email| email.to_string())
+    }
+
+    pub fn currencies(&self) -> Vec<String> {
+        self.currencies.values()
+    }
+
+    pub fn accepts_currency(&self, currency: &Currency) -> bool {
+        self.currencies.accepts(currency)
+    }
+
+    /// Whether `account`, as a provider reports it, is the one donas are sent to. Only the
+    /// email of a PayPal account can be checked, any other account is refused.
+    pub fn receives_at(&self, account: &str) -> bool {
+        self.paypal_email
+            .as_ref()
+            .is_some_and(|email| email.matches(account))
+    }
+
     pub fn created_at(&self) -> OffsetDateTime {
         self.created_at.value()
     }
@@ -232,16 +442,17 @@ impl UserPaymentMethod {
 
 pub mod tests {
     use crate::shared::domain::dona::tests::DonaOptionMethodMother;
+    use crate::user_payment_method::domain::crypto_wallet::tests::CryptoWalletMother;
 
     use super::*;
 
     use fake::{
-        faker::{lorem::en::Sentence, time::en::DateTimeAfter},
+        faker::{internet::en::SafeEmail, lorem::en::Sentence, time::en::DateTimeAfter},
         Fake,
     };
     use shared::domain::{
         utils::{new_uuid, MINIMUM_DATE_PERMITTED},
-        value_objects::user_id::tests::UserIdMother,
+        value_objects::{money::tests::CurrencyMother, user_id::tests::UserIdMother},
     };
 
     pub struct UserPaymentMethodIdMother;
@@ -274,6 +485,36 @@ pub mod tests {
         }
     }
 
+    pub struct UserPaymentMethodPaypalEmailMother;
+
+    impl UserPaymentMethodPaypalEmailMother {
+        pub fn random() -> UserPaymentMethodPaypalEmail {
+            UserPaymentMethodPaypalEmail::new(SafeEmail().fake()).unwrap()
+        }
+
+        pub fn create(value: Option<String>) -> UserPaymentMethodPaypalEmail {
+            match value {
+                Some(value) => UserPaymentMethodPaypalEmail::new(value).unwrap(),
+                None => Self::random(),
+            }
+        }
+    }
+
+    pub struct UserPaymentMethodCurrenciesMother;
+
+    impl UserPaymentMethodCurrenciesMother {
+        pub fn random() -> UserPaymentMethodCurrencies {
+            UserPaymentMethodCurrencies::new(vec![CurrencyMother::random().to_string()]).unwrap()
+        }
+
+        pub fn create(value: Option<Vec<String>>) -> UserPaymentMethodCurrencies {
+            match value {
+                Some(value) => UserPaymentMethodCurrencies::new(value).unwrap(),
+                None => Self::random(),
+            }
+        }
+    }
+
     pub struct UserPaymentMethodCreatedAtMother;
 
     impl UserPaymentMethodCreatedAtMother {
@@ -312,7 +553,7 @@ pub mod tests {
 
     impl UserPaymentMethodMother {
         pub fn random() -> UserPaymentMethod {
-            Self::create(None, None, None, None, None, None)
+            Self::create(None, None, None, None, None, None, None)
         }
 
         pub fn create(
@@ -320,19 +561,42 @@ pub mod tests {
             user_id: Option<String>,
             payment_method: Option<String>,
             instructions: Option<String>,
+            currencies: Option<Vec<String>>,
             created_at: Option<OffsetDateTime>,
             updated_at: Option<OffsetDateTime>,
         ) -> UserPaymentMethod {
+            let payment_method = DonaOptionMethodMother::create(payment_method);
+
             UserPaymentMethod {
                 id: UserPaymentMethodIdMother::create(id),
                 user_id: UserIdMother::create(user_id),
-                payment_method: DonaOptionMethodMother::create(payment_method),
+                crypto_wallet: (payment_method == DonaOptionMethod::Crypto)
+                    .then(CryptoWalletMother::random),
+                paypal_email: (payment_method == DonaOptionMethod::Paypal)
+                    .then(UserPaymentMethodPaypalEmailMother::random),
+                payment_method,
                 instructions: UserPaymentMethodInstructionsMother::create(instructions),
+                currencies: UserPaymentMethodCurrenciesMother::create(currencies),
                 created_at: UserPaymentMethodCreatedAtMother::create(created_at),
                 updated_at: UserPaymentMethodUpdatedAtMother::create(updated_at),
 
                 events: vec![],
             }
         }
+
+        /// A PayPal method taking USD, sending donas to the account `paypal_email`.
+        pub fn paypal(user_id: Option<String>, paypal_email: Option<String>) -> UserPaymentMethod {
+            let mut method = Self::create(
+                None,
+                user_id,
+                Some(DonaOptionMethod::Paypal.to_string()),
+                None,
+                Some(vec!["USD".to_string()]),
+                None,
+                None,
+            );
+            method.paypal_email = Some(UserPaymentMethodPaypalEmailMother::create(paypal_email));
+            method
+        }
     }
 }
@@ -8,6 +8,12 @@ pub struct UserPaymentMethodCreatedEvent {
     user_id: String,
     payment_method: String,
     instructions: String,
+    /// Empty unless the method is a crypto one.
+    crypto_network: String,
+    crypto_address: String,
+    /// Empty unless the method is a PayPal one.
+    paypal_email: String,
+    currencies: String,
     created_at: String,
     updated_at: String,
 
@@ -20,6 +26,10 @@ impl UserPaymentMethodCreatedEvent {
         user_id: String,
         payment_method: String,
         instructions: String,
+        crypto_network: String,
+        crypto_address: String,
+        paypal_email: String,
+        currencies: String,
         created_at: String,
         updated_at: String,
     ) -> Self {
@@ -28,6 +38,10 @@ impl UserPaymentMethodCreatedEvent {
             user_id,
             payment_method,
             instructions,
+            crypto_network,
+            crypto_address,
+            paypal_email,
+            currencies,
             created_at,
             updated_at,
             base_event: BaseEvent::new(id),
@@ -50,6 +64,22 @@ impl UserPaymentMethodCreatedEvent {
         &self.instructions
     }
 
+    pub fn crypto_network(&self) -> &str {
+        &self.crypto_network
+    }
+
+    pub fn crypto_address(&self) -> &str {
+        &self.crypto_address
+    }
+
+    pub fn paypal_email(&self) -> &str {
+        &self.paypal_email
+    }
+
+    pub fn currencies(&self) -> &str {
+        &self.currencies
+    }
+
     pub fn created_at(&self) -> &str {
         &self.created_at
     }
@@ -95,6 +125,26 @@ impl Event for UserPaymentMethodCreatedEvent {
             .ok_or(EventDeserializeError::MissingField(
                 "instructions".to_string(),
             ))?;
+        let crypto_network =
+            data.get("crypto_network")
+                .ok_or(EventDeserializeError::MissingField(
+                    "crypto_network".to_string(),
+                ))?;
+        let crypto_address =
+            data.get("crypto_address")
+                .ok_or(EventDeserializeError::MissingField(
+                    "crypto_address".to_string(),
+                ))?;
+        let paypal_email = data
+            .get("paypal_email")
+            .ok_or(EventDeserializeError::MissingField(
+                "paypal_email".to_string(),
+            ))?;
+        let currencies = data
+            .get("currencies")
+            .ok_or(EventDeserializeError::MissingField(
+                "currencies".to_string(),
+            ))?;
         let created_at = data
             .get("created_at")
             .ok_or(EventDeserializeError::MissingField(
@@ -111,6 +161,10 @@ impl Event for UserPaymentMethodCreatedEvent {
             user_id: user_id.to_string(),
             payment_method: payment_method.to_string(),
             instructions: instructions.to_string(),
+            crypto_network: crypto_network.to_string(),
+            crypto_address: crypto_address.to_string(),
+            paypal_email: paypal_email.to_string(),
+            currencies: currencies.to_string(),
             created_at: created_at.to_string(),
             updated_at: updated_at.to_string(),
             base_event,
@@ -130,6 +184,16 @@ impl Event for UserPaymentMethodCreatedEvent {
                     self.payment_method.to_string(),
                 ),
                 ("instructions".to_string(), self.instructions.to_string()),
+                (
+                    "crypto_network".to_string(),
+                    self.crypto_network.to_string(),
+                ),
+                (
+                    "crypto_address".to_string(),
+                    self.crypto_address.to_string(),
+                ),
+                ("paypal_email".to_string(), self.paypal_email.to_string()),
+                ("currencies".to_string(), self.currencies.to_string()),
                 ("created_at".to_string(), self.created_at.to_string()),
                 ("updated_at".to_string(), self.updated_at.to_string()),
             ]
@@ -8,8 +8,16 @@ pub struct UserPaymentMethodInstructionsUpdatedEvent {
     user_id: String,
     payment_method: String,
     instructions: String,
+    /// Empty unless the method is a crypto one.
+    crypto_network: String,
+    crypto_address: String,
+    /// Empty unless the method is a PayPal one.
+    paypal_email: String,
     created_at: String,
     updated_at: String,
+    /// RFC 3339 date of the update before this one, the codes rendered until now are named
+    /// after it.
+    previous_updated_at: String,
 
     base_event: BaseEvent,
 }
@@ -20,16 +28,24 @@ impl UserPaymentMethodInstructionsUpdatedEvent {
         user_id: String,
         payment_method: String,
         instructions: String,
+        crypto_network: String,
+        crypto_address: String,
+        paypal_email: String,
         created_at: String,
         updated_at: String,
+        previous_updated_at: String,
     ) -> Self {
         Self {
             id: id.clone(),
             user_id,
             payment_method,
             instructions,
+            crypto_network,
+            crypto_address,
+            paypal_email,
             created_at,
             updated_at,
+            previous_updated_at,
             base_event: BaseEvent::new(id),
         }
     }
@@ -50,6 +66,18 @@ impl UserPaymentMethodInstructionsUpdatedEvent {
         &self.instructions
     }
 
+    pub fn crypto_network(&self) -> &str {
+        &self.crypto_network
+    }
+
+    pub fn crypto_address(&self) -> &str {
+        &self.crypto_address
+    }
+
+    pub fn paypal_email(&self) -> &str {
+        &self.paypal_email
+    }
+
     pub fn created_at(&self) -> &str {
         &self.created_at
     }
@@ -57,6 +85,10 @@ impl UserPaymentMethodInstructionsUpdatedEvent {
     pub fn updated_at(&self) -> &str {
         &self.updated_at
     }
+
+    pub fn previous_updated_at(&self) -> &str {
+        &self.previous_updated_at
+    }
 }
 
 impl Event for UserPaymentMethodInstructionsUpdatedEvent {
@@ -95,6 +127,21 @@ impl Event for UserPaymentMethodInstructionsUpdatedEvent {
             .ok_or(EventDeserializeError::MissingField(
                 "instructions".to_string(),
             ))?;
+        let crypto_network =
+            data.get("crypto_network")
+                .ok_or(EventDeserializeError::MissingField(
+                    "crypto_network".to_string(),
+                ))?;
+        let crypto_address =
+            data.get("crypto_address")
+                .ok_or(EventDeserializeError::MissingField(
+                    "crypto_address".to_string(),
+                ))?;
+        let paypal_email = data
+            .get("paypal_email")
+            .ok_or(EventDeserializeError::MissingField(
+                "paypal_email".to_string(),
+            ))?;
         let created_at = data
             .get("created_at")
             .ok_or(EventDeserializeError::MissingField(
@@ -105,14 +152,23 @@ impl Event for UserPaymentMethodInstructionsUpdatedEvent {
             .ok_or(EventDeserializeError::MissingField(
                 "updated_at".to_string(),
             ))?;
+        let previous_updated_at =
+            data.get("previous_updated_at")
+                .ok_or(EventDeserializeError::MissingField(
+                    "previous_updated_at".to_string(),
+                ))?;
 
         Ok(Box::new(UserPaymentMethodInstructionsUpdatedEvent {
             id: id.to_string(),
             user_id: user_id.to_string(),
             payment_method: payment_method.to_string(),
             instructions: instructions.to_string(),
+            crypto_network: crypto_network.to_string(),
+            crypto_address: crypto_address.to_string(),
+            paypal_email: paypal_email.to_string(),
             created_at: created_at.to_string(),
             updated_at: updated_at.to_string(),
+            previous_updated_at: previous_updated_at.to_string(),
             base_event,
         }))
     }
@@ -130,8 +186,21 @@ impl Event for UserPaymentMethodInstructionsUpdatedEvent {
                     self.payment_method.to_string(),
                 ),
                 ("instructions".to_string(), self.instructions.to_string()),
+                (
+                    "crypto_network".to_string(),
+                    self.crypto_network.to_string(),
+                ),
+                (
+                    "crypto_address".to_string(),
+                    self.crypto_address.to_string(),
+                ),
+                ("paypal_email".to_string(), self.paypal_email.to_string()),
                 ("created_at".to_string(), self.created_at.to_string()),
                 ("updated_at".to_string(), self.updated_at.to_string()),
+                (
+                    "previous_updated_at".to_string(),
+                    self.previous_updated_at.to_string(),
+                ),
             ]
             .into_iter()
             .collect(),
@@ -19,6 +19,10 @@ pub struct Model {
     pub user_id: Uuid,
     pub payment_method: String,
     pub instructions: String,
+    pub crypto_network: Option<String>,
+    pub crypto_address: Option<String>,
+    pub paypal_email: Option<String>,
+    pub currencies: String,
     pub created_at: TimeDateTimeWithTimeZone,
     pub updated_at: TimeDateTimeWithTimeZone,
 }
@@ -34,6 +38,10 @@ fn from_model(model: Model) -> UserPaymentMethod {
         model.user_id.to_string(),
         model.payment_method,
         model.instructions,
+        model.crypto_network,
+        model.crypto_address,
+        model.paypal_email,
+        model.currencies.split(',').map(str::to_string).collect(),
         model.created_at,
         model.updated_at,
     )
@@ -110,6 +118,10 @@ impl UserPaymentMethodRepository for SeaUserPaymentMethodRepo {
                 Column::UserId,
                 Column::PaymentMethod,
                 Column::Instructions,
+                Column::CryptoNetwork,
+                Column::CryptoAddress,
+                Column::PaypalEmail,
+                Column::Currencies,
                 Column::CreatedAt,
                 Column::UpdatedAt,
             ])
@@ -120,6 +132,10 @@ impl UserPaymentMethodRepository for SeaUserPaymentMethodRepo {
             user_id: Set(Uuid::parse_str(&user_payment_method.user_id()).unwrap()),
             payment_method: Set(user_payment_method.payment_method()),
             instructions: Set(user_payment_method.instructions()),
+            crypto_network: Set(user_payment_method.crypto_network()),
+            crypto_address: Set(user_payment_method.crypto_address()),
+            paypal_email: Set(user_payment_method.paypal_email()),
+            currencies: Set(user_payment_method.currencies().join(",")),
             created_at: Set(user_payment_method.created_at()),
             updated_at: Set(user_payment_method.updated_at()),
         };
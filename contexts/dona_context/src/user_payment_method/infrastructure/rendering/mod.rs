@@ -0,0 +1,5 @@
+pub mod png_qr_code_renderer;
+pub mod svg_qr_code_renderer;
+
+/// Side of the rendered codes in pixels, large enough to be scanned from a screen.
+pub(crate) const QR_CODE_MIN_SIZE: u32 = 256;
@@ -0,0 +1,56 @@
+use std::io::Cursor;
+
+use image::{DynamicImage, ImageFormat, Luma};
+use qrcode::QrCode;
+
+use crate::user_payment_method::domain::{
+    payment_qr_code::QrCodeFormat, qr_code_renderer::QrCodeRenderer,
+};
+
+use super::QR_CODE_MIN_SIZE;
+
+#[derive(Debug, Clone, Default)]
+pub struct PngQrCodeRenderer;
+
+impl PngQrCodeRenderer {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl QrCodeRenderer for PngQrCodeRenderer {
+    fn format(&self) -> QrCodeFormat {
+        QrCodeFormat::Png
+    }
+
+    fn render(&self, content: &str) -> Result<Vec<u8>, String> {
+        let code = QrCode::new(content.as_bytes()).map_err(|e| e.to_string())?;
+        let image = code
+            .render::<Luma<u8>>()
+            .min_dimensions(QR_CODE_MIN_SIZE, QR_CODE_MIN_SIZE)
+            .build();
+
+        let mut png = Cursor::new(Vec::new());
+        DynamicImage::ImageLuma8(image)
+            .write_to(&mut png, ImageFormat::Png)
+            .map_err(|e| e.to_string())?;
+
+        Ok(png.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_render_a_png() {
+        let png = PngQrCodeRenderer::new()
+            .render("bitcoin:1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa")
+            .unwrap();
+        let image = image::load_from_memory_with_format(&png, ImageFormat::Png).unwrap();
+
+        assert!(image.width() >= QR_CODE_MIN_SIZE);
+        assert_eq!(image.width(), image.height());
+    }
+}
@@ -0,0 +1,55 @@
+use qrcode::{render::svg, QrCode};
+
+use crate::user_payment_method::domain::{
+    payment_qr_code::QrCodeFormat, qr_code_renderer::QrCodeRenderer,
+};
+
+use super::QR_CODE_MIN_SIZE;
+
+#[derive(Debug, Clone, Default)]
+pub struct SvgQrCodeRenderer;
+
+impl SvgQrCodeRenderer {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl QrCodeRenderer for SvgQrCodeRenderer {
+    fn format(&self) -> QrCodeFormat {
+        QrCodeFormat::Svg
+    }
+
+    fn render(&self, content: &str) -> Result<Vec<u8>, String> {
+        let code = QrCode::new(content.as_bytes()).map_err(|e| e.to_string())?;
+        let image = code
+            .render::<svg::Color>()
+            .min_dimensions(QR_CODE_MIN_SIZE, QR_CODE_MIN_SIZE)
+            .build();
+
+        Ok(image.into_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_render_an_svg() {
+        let svg = SvgQrCodeRenderer::new()
+            .render("bitcoin:1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa")
+            .unwrap();
+        let svg = String::from_utf8(svg).unwrap();
+
+        assert!(svg.starts_with("<?xml"));
+        assert!(svg.contains("<svg"));
+    }
+
+    #[test]
+    fn it_should_fail_when_the_content_does_not_fit() {
+        let result = SvgQrCodeRenderer::new().render(&"a".repeat(5000));
+
+        assert!(result.is_err());
+    }
+}
@@ -18,6 +18,11 @@ impl Criteria {
         }
     }
 
+    pub fn with_filter(mut self, filter: Filter) -> Self {
+        self.filters.push(filter);
+        self
+    }
+
     pub fn filters(&self) -> &[Filter] {
         &self.filters
     }
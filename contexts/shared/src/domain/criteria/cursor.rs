@@ -2,21 +2,45 @@ use std::{collections::HashMap, fmt::Display};
 
 use time::{format_description::well_known::Iso8601, OffsetDateTime};
 
+use crate::domain::utils::is_uuid;
+
+/// Separates the date from the id in the text form of an [`AfterCursor`].
+const AFTER_CURSOR_ID_SEPARATOR: char = '_';
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AfterCursor {
     value: OffsetDateTime,
+    id: Option<String>,
 }
 
 impl AfterCursor {
     pub fn new(value: OffsetDateTime) -> Self {
-        Self { value }
+        Self { value, id: None }
+    }
+
+    /// Resumes right after the row with `id`, so rows sharing its date are not skipped. A
+    /// cursor without an id skips every row created at `value`.
+    pub fn with_id(value: OffsetDateTime, id: String) -> Result<Self, String> {
+        if !is_uuid(&id) {
+            return Err("AfterCursor id is not a valid uuid".to_string());
+        }
+
+        Ok(Self {
+            value,
+            id: Some(id),
+        })
     }
 
     pub fn value(&self) -> &OffsetDateTime {
         &self.value
     }
+
+    pub fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
 }
 
+/// Reads either a bare date or the `<date>_<id>` written by its `Display`.
 impl TryFrom<String> for AfterCursor {
     type Error = String;
 
@@ -25,20 +49,33 @@ impl TryFrom<String> for AfterCursor {
             return Err("AfterCursor is empty".to_string());
         }
 
-        let date = OffsetDateTime::parse(value.as_str(), &Iso8601::DEFAULT);
+        let (date, id) = match value.split_once(AFTER_CURSOR_ID_SEPARATOR) {
+            Some((date, id)) => (date, Some(id)),
+            None => (value.as_str(), None),
+        };
+        let date = OffsetDateTime::parse(date, &Iso8601::DEFAULT);
         if date.is_err() {
             return Err("AfterCursor is not a valid date".to_string());
         }
 
-        Ok(Self {
-            value: date.unwrap(),
-        })
+        match id {
+            Some(id) => Self::with_id(date.unwrap(), id.to_string()),
+            None => Ok(Self {
+                value: date.unwrap(),
+                id: None,
+            }),
+        }
     }
 }
 
 impl Display for AfterCursor {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.value.format(&Iso8601::DEFAULT).unwrap())
+        write!(f, "{}", self.value.format(&Iso8601::DEFAULT).unwrap())?;
+        if let Some(id) = &self.id {
+            write!(f, "{}{}", AFTER_CURSOR_ID_SEPARATOR, id)?;
+        }
+
+        Ok(())
     }
 }
 
@@ -277,3 +314,30 @@ impl Display for Cursor {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn it_should_keep_the_id_of_an_after_cursor_through_its_text() {
+        let created_at = datetime!(2024-01-01 00:00 UTC);
+        let id = "0b6a0a0e-7d1a-4a4c-9a51-3c1f9e0b2f6d".to_string();
+
+        let cursor = AfterCursor::with_id(created_at, id.clone()).unwrap();
+        let parsed = AfterCursor::try_from(cursor.to_string()).unwrap();
+
+        assert_eq!(cursor, parsed);
+        assert_eq!(Some(&id), parsed.id());
+    }
+
+    #[test]
+    fn it_should_read_an_after_cursor_without_id() {
+        let cursor = AfterCursor::new(datetime!(2024-01-01 00:00 UTC));
+
+        assert_eq!(cursor, AfterCursor::try_from(cursor.to_string()).unwrap());
+        assert!(AfterCursor::try_from("2024-01-01T00:00:00Z_not-an-id".to_string()).is_err());
+    }
+}
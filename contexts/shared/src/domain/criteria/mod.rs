@@ -3,5 +3,6 @@ mod criteria;
 pub mod cursor;
 pub mod filter;
 pub mod order;
+pub mod page;
 
 pub use criteria::Criteria;
@@ -0,0 +1,42 @@
+/// A slice of results fetched through a [`Cursor`](super::cursor::Cursor), along
+/// with whether more results exist on either side of it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CursorPage<T> {
+    items: Vec<T>,
+    has_previous_page: bool,
+    has_next_page: bool,
+}
+
+impl<T> CursorPage<T> {
+    pub fn new(items: Vec<T>, has_previous_page: bool, has_next_page: bool) -> Self {
+        Self {
+            items,
+            has_previous_page,
+            has_next_page,
+        }
+    }
+
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+
+    pub fn has_previous_page(&self) -> bool {
+        self.has_previous_page
+    }
+
+    pub fn has_next_page(&self) -> bool {
+        self.has_next_page
+    }
+
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> CursorPage<U> {
+        CursorPage {
+            items: self.items.into_iter().map(f).collect(),
+            has_previous_page: self.has_previous_page,
+            has_next_page: self.has_next_page,
+        }
+    }
+}
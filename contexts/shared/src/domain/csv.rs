@@ -0,0 +1,144 @@
+pub const ERR_UNTERMINATED_CSV_QUOTE: &str = "Unterminated quoted field";
+
+/// Row of a CSV file along with the line it starts on, counting from 1. Quoted fields may
+/// span several lines, so it is not the position of the row in the file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvRecord {
+    pub line: usize,
+    pub fields: Vec<String>,
+}
+
+/// Parses RFC 4180 CSV: comma separated fields, optionally wrapped in double quotes, where a
+/// doubled quote inside a quoted field stands for a literal one. Blank lines are skipped.
+pub fn parse_csv(input: &str) -> Result<Vec<Vec<String>>, String> {
+    Ok(parse_csv_records(input)?
+        .into_iter()
+        .map(|record| record.fields)
+        .collect())
+}
+
+/// Like [`parse_csv`], keeping the line every row starts on.
+pub fn parse_csv_records(input: &str) -> Result<Vec<CsvRecord>, String> {
+    let mut records = vec![];
+    let mut row = vec![];
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut row_line = line;
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '\n' {
+            line += 1;
+        }
+
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' => in_quotes = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+
+        match c {
+            '"' => in_quotes = true,
+            ',' => row.push(std::mem::take(&mut field)),
+            '\r' => {}
+            '\n' => {
+                row.push(std::mem::take(&mut field));
+                push_record(&mut records, row_line, std::mem::take(&mut row));
+                row_line = line;
+            }
+            _ => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err(ERR_UNTERMINATED_CSV_QUOTE.to_string());
+    }
+
+    row.push(field);
+    push_record(&mut records, row_line, row);
+
+    Ok(records)
+}
+
+fn push_record(records: &mut Vec<CsvRecord>, line: usize, fields: Vec<String>) {
+    if fields.len() == 1 && fields[0].trim().is_empty() {
+        return;
+    }
+
+    records.push(CsvRecord { line, fields });
+}
+
+/// Serializes one CSV line, quoting only the fields that need it.
+pub fn to_csv_row(fields: &[String]) -> String {
+    fields
+        .iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.to_string()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_csv() {
+        let rows =
+            parse_csv("a,b,c\r\n1,\"two, \"\"quoted\"\"\",3\n\n4,\"multi\nline\",\n").unwrap();
+
+        assert_eq!(
+            rows,
+            vec![
+                vec!["a", "b", "c"],
+                vec!["1", "two, \"quoted\"", "3"],
+                vec!["4", "multi\nline", ""],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_csv_records_lines() {
+        let records = parse_csv_records("a,b\n\"multi\nline\",1\n\n2,\"x\"\r\n3,y").unwrap();
+
+        assert_eq!(
+            records
+                .iter()
+                .map(|record| record.line)
+                .collect::<Vec<usize>>(),
+            vec![1, 2, 5, 6]
+        );
+        assert_eq!(records[1].fields, vec!["multi\nline", "1"]);
+    }
+
+    #[test]
+    fn test_parse_csv_unterminated_quote() {
+        assert_eq!(
+            parse_csv("a,\"b\n"),
+            Err(ERR_UNTERMINATED_CSV_QUOTE.to_string())
+        );
+    }
+
+    #[test]
+    fn test_to_csv_row() {
+        let row = to_csv_row(&[
+            "plain".to_string(),
+            "with, comma".to_string(),
+            "with \"quotes\"".to_string(),
+        ]);
+
+        assert_eq!(row, "plain,\"with, comma\",\"with \"\"quotes\"\"\"");
+    }
+}
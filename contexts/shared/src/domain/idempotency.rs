@@ -0,0 +1,113 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use super::base_errors::BaseRepositoryError;
+
+pub const ERR_INVALID_IDEMPOTENCY_KEY: &str = "Invalid idempotency key";
+pub const ERR_IDEMPOTENCY_KEY_REUSED: &str =
+    "Idempotency key already used with a different request";
+pub const ERR_IDEMPOTENT_REQUEST_IN_PROGRESS: &str =
+    "A request with the same idempotency key is still in progress";
+
+const IDEMPOTENCY_KEY_MAX_LENGTH: usize = 255;
+
+/// Key chosen by the client so a retried request is not applied twice.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IdempotencyKey(String);
+
+impl IdempotencyKey {
+    pub fn new(key: String) -> Result<Self, String> {
+        if key.is_empty()
+            || key.len() > IDEMPOTENCY_KEY_MAX_LENGTH
+            || !key.chars().all(|c| c.is_ascii_graphic())
+        {
+            return Err(ERR_INVALID_IDEMPOTENCY_KEY.to_string());
+        }
+
+        Ok(Self(key))
+    }
+
+    pub fn value(&self) -> String {
+        self.0.clone()
+    }
+}
+
+/// What is stored under an idempotency key: the fingerprint of the request that took the key
+/// and whether its command already went through.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdempotencyRecord {
+    fingerprint: String,
+    completed: bool,
+}
+
+impl IdempotencyRecord {
+    pub fn pending(fingerprint: String) -> Self {
+        Self {
+            fingerprint,
+            completed: false,
+        }
+    }
+
+    pub fn completed(fingerprint: String) -> Self {
+        Self {
+            fingerprint,
+            completed: true,
+        }
+    }
+
+    pub fn fingerprint(&self) -> String {
+        self.fingerprint.clone()
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.completed
+    }
+}
+
+#[async_trait::async_trait]
+pub trait IdempotencyRepository: Send + Sync {
+    /// Stores the record only if nothing is stored under the key yet, telling whether it did.
+    async fn reserve(
+        &self,
+        key: &str,
+        record: &IdempotencyRecord,
+        ttl: Duration,
+    ) -> Result<bool, BaseRepositoryError>;
+    async fn find(&self, key: &str) -> Result<IdempotencyRecord, BaseRepositoryError>;
+    async fn save(
+        &self,
+        key: &str,
+        record: &IdempotencyRecord,
+        ttl: Duration,
+    ) -> Result<(), BaseRepositoryError>;
+    async fn delete(&self, key: &str) -> Result<(), BaseRepositoryError>;
+}
+
+pub mod tests {
+    use mockall::mock;
+
+    use super::*;
+
+    mock! {
+        pub IdempotencyRepository {}
+
+        #[async_trait::async_trait]
+        impl IdempotencyRepository for IdempotencyRepository {
+            async fn reserve(
+                &self,
+                key: &str,
+                record: &IdempotencyRecord,
+                ttl: Duration,
+            ) -> Result<bool, BaseRepositoryError>;
+            async fn find(&self, key: &str) -> Result<IdempotencyRecord, BaseRepositoryError>;
+            async fn save(
+                &self,
+                key: &str,
+                record: &IdempotencyRecord,
+                ttl: Duration,
+            ) -> Result<(), BaseRepositoryError>;
+            async fn delete(&self, key: &str) -> Result<(), BaseRepositoryError>;
+        }
+    }
+}
@@ -1,6 +1,8 @@
 pub mod base_errors;
 pub mod bus;
 pub mod criteria;
+pub mod csv;
+pub mod idempotency;
 pub mod storage;
 pub mod utils;
 pub mod value_objects;
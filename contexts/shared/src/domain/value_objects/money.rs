@@ -0,0 +1,215 @@
+use std::fmt::Display;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+pub const ERR_INVALID_CURRENCY: &str = "Invalid currency";
+pub const ERR_INVALID_MONEY_PRECISION: &str = "Amount has more decimals than its currency allows";
+
+/// ISO-4217 codes we accept, with the number of minor units (decimal places) of each one.
+const CURRENCIES: [(&str, u32); 18] = [
+    ("ARS", 2),
+    ("AUD", 2),
+    ("BRL", 2),
+    ("CAD", 2),
+    ("CHF", 2),
+    ("CLP", 0),
+    ("COP", 2),
+    ("EUR", 2),
+    ("GBP", 2),
+    ("JPY", 0),
+    ("KRW", 0),
+    ("KWD", 3),
+    ("MXN", 2),
+    ("PEN", 2),
+    ("PYG", 0),
+    ("USD", 2),
+    ("UYU", 2),
+    ("VES", 2),
+];
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Currency {
+    code: String,
+    precision: u32,
+}
+
+impl Currency {
+    pub fn new(code: String) -> Result<Self, String> {
+        CURRENCIES
+            .iter()
+            .find(|(currency, _)| *currency == code)
+            .map(|(_, precision)| Self {
+                code,
+                precision: *precision,
+            })
+            .ok_or_else(|| ERR_INVALID_CURRENCY.to_string())
+    }
+
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    pub fn precision(&self) -> u32 {
+        self.precision
+    }
+}
+
+impl Display for Currency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code)
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Money {
+    amount: Decimal,
+    currency: Currency,
+}
+
+impl Money {
+    pub fn new(amount: Decimal, currency: String) -> Result<Self, String> {
+        let currency = Currency::new(currency)?;
+
+        if amount.normalize().scale() > currency.precision() {
+            return Err(ERR_INVALID_MONEY_PRECISION.to_string());
+        }
+
+        Ok(Self { amount, currency })
+    }
+
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+}
+
+impl Display for Money {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            self.amount.round_dp(self.currency.precision()),
+            self.currency
+        )
+    }
+}
+
+pub mod tests {
+    use fake::Fake;
+
+    use super::*;
+
+    pub struct CurrencyMother;
+
+    impl CurrencyMother {
+        pub fn create(value: Option<String>) -> Currency {
+            match value {
+                Some(value) => Currency::new(value).unwrap(),
+                None => Self::random(),
+            }
+        }
+
+        pub fn random() -> Currency {
+            let (code, _) = CURRENCIES[(0..CURRENCIES.len()).fake::<usize>()];
+            Currency::new(code.to_string()).unwrap()
+        }
+
+        /// A random currency precise enough to hold `amount`.
+        pub fn random_for(amount: Decimal) -> Currency {
+            let scale = amount.normalize().scale();
+            let candidates = CURRENCIES
+                .iter()
+                .filter(|(_, precision)| *precision >= scale)
+                .collect::<Vec<_>>();
+            let (code, _) = candidates[(0..candidates.len()).fake::<usize>()];
+            Currency::new(code.to_string()).unwrap()
+        }
+    }
+
+    pub struct MoneyMother;
+
+    impl MoneyMother {
+        pub fn create(amount: Option<Decimal>, currency: Option<String>) -> Money {
+            let (amount, currency) = match (amount, currency) {
+                (Some(amount), Some(currency)) => (amount, Currency::new(currency).unwrap()),
+                (Some(amount), None) => (amount, CurrencyMother::random_for(amount)),
+                (None, currency) => {
+                    let currency = CurrencyMother::create(currency);
+                    (Self::random_amount(&currency), currency)
+                }
+            };
+
+            Money::new(amount, currency.to_string()).unwrap()
+        }
+
+        pub fn random() -> Money {
+            Self::create(None, None)
+        }
+
+        /// A positive amount that fits the precision of the given currency.
+        pub fn random_amount(currency: &Currency) -> Decimal {
+            let minor_units = (1..10_000_000i64).fake::<i64>();
+            Decimal::new(minor_units, currency.precision())
+        }
+    }
+
+    #[test]
+    fn it_should_create_known_currency() {
+        let currency = Currency::new("KWD".to_string()).unwrap();
+
+        assert_eq!(currency.code(), "KWD");
+        assert_eq!(currency.precision(), 3);
+    }
+
+    #[test]
+    fn it_should_reject_unknown_currency() {
+        assert_eq!(
+            Currency::new("XXX".to_string()),
+            Err(ERR_INVALID_CURRENCY.to_string())
+        );
+        assert_eq!(
+            Currency::new("usd".to_string()),
+            Err(ERR_INVALID_CURRENCY.to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_reject_money_with_unknown_currency() {
+        assert_eq!(
+            Money::new(Decimal::new(100, 2), "XXX".to_string()),
+            Err(ERR_INVALID_CURRENCY.to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_reject_amount_more_precise_than_its_currency() {
+        assert_eq!(
+            Money::new(Decimal::new(1001, 3), "USD".to_string()),
+            Err(ERR_INVALID_MONEY_PRECISION.to_string())
+        );
+        assert_eq!(
+            Money::new(Decimal::new(15, 1), "JPY".to_string()),
+            Err(ERR_INVALID_MONEY_PRECISION.to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_accept_trailing_zeros_beyond_precision() {
+        let money = Money::new(Decimal::new(1000, 3), "USD".to_string()).unwrap();
+        assert_eq!(money.amount(), Decimal::new(1, 0));
+
+        let money = Money::new(Decimal::new(150, 1), "JPY".to_string()).unwrap();
+        assert_eq!(money.to_string(), "15 JPY");
+    }
+
+    #[test]
+    fn it_should_display_amount_with_currency() {
+        let money = Money::new(Decimal::new(1050, 2), "EUR".to_string()).unwrap();
+
+        assert_eq!(money.to_string(), "10.50 EUR");
+    }
+}
@@ -1,27 +1,55 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock, Weak},
+};
 
 use crate::domain::bus::event::{Event, EventBus, EventError, EventHandler};
 
-#[derive(Clone, Default)]
+type EventHandlers = HashMap<&'static str, Vec<Arc<dyn EventHandler>>>;
+
+/// Handlers sit behind a lock so a [`WeakEventBus`] can register them on a shared bus too.
+#[derive(Default)]
 pub struct InMemoryEventBus {
-    handlers: HashMap<&'static str, Vec<Arc<dyn EventHandler>>>,
+    handlers: RwLock<EventHandlers>,
 }
 
 impl InMemoryEventBus {
     pub fn new() -> Self {
         Self::default()
     }
+
+    fn add_handler(&self, handler: Arc<dyn EventHandler>) {
+        let mut handlers = self.handlers.write().unwrap();
+        for event_type in handler.subscribed_to() {
+            handlers
+                .entry(event_type)
+                .or_default()
+                .push(handler.clone());
+        }
+    }
+}
+
+impl Clone for InMemoryEventBus {
+    fn clone(&self) -> Self {
+        Self {
+            handlers: RwLock::new(self.handlers.read().unwrap().clone()),
+        }
+    }
 }
 
 #[async_trait::async_trait]
 impl EventBus for InMemoryEventBus {
     async fn publish(&self, event: Vec<Arc<dyn Event>>) -> Result<(), EventError> {
         for e in event {
-            let event_type = e.event_type();
-            if let Some(handlers) = self.handlers.get(event_type) {
-                for handler in handlers {
-                    handler.handle(e.clone()).await?;
-                }
+            let handlers = self
+                .handlers
+                .read()
+                .unwrap()
+                .get(e.event_type())
+                .cloned()
+                .unwrap_or_default();
+            for handler in handlers {
+                handler.handle(e.clone()).await?;
             }
         }
 
@@ -29,11 +57,39 @@ impl EventBus for InMemoryEventBus {
     }
 
     fn register_handler(&mut self, handler: Arc<dyn EventHandler>) {
-        for event_type in handler.subscribed_to() {
-            self.handlers
-                .entry(event_type)
-                .and_modify(|handlers| handlers.push(handler.clone()))
-                .or_default();
+        self.add_handler(handler);
+    }
+}
+
+/// Publishes to an [`InMemoryEventBus`] without keeping it alive. Handlers that publish events
+/// of their own get one pointing at the bus they are registered in, so their events reach
+/// every handler of that bus, including the ones registered after them.
+#[derive(Clone)]
+pub struct WeakEventBus {
+    bus: Weak<InMemoryEventBus>,
+}
+
+impl WeakEventBus {
+    pub fn new(bus: Weak<InMemoryEventBus>) -> Self {
+        Self { bus }
+    }
+}
+
+#[async_trait::async_trait]
+impl EventBus for WeakEventBus {
+    async fn publish(&self, event: Vec<Arc<dyn Event>>) -> Result<(), EventError> {
+        let bus = self
+            .bus
+            .upgrade()
+            .ok_or_else(|| EventError::new("Event bus is no longer available".to_string()))?;
+
+        bus.publish(event).await
+    }
+
+    /// Registers on the bus it points at, the handler is dropped if that bus is gone.
+    fn register_handler(&mut self, handler: Arc<dyn EventHandler>) {
+        if let Some(bus) = self.bus.upgrade() {
+            bus.add_handler(handler);
         }
     }
 }
@@ -85,6 +141,139 @@ mod tests {
         }
     }
 
+    #[derive(Debug, Default)]
+    struct CountingEventHandler {
+        handled: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl EventHandler for CountingEventHandler {
+        async fn handle(&self, _event: Arc<dyn Event>) -> Result<(), EventError> {
+            self.handled
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+
+            Ok(())
+        }
+
+        fn subscribed_to(&self) -> Vec<&'static str> {
+            vec!["TestEvent"]
+        }
+    }
+
+    #[tokio::test]
+    async fn test_publish_reaches_every_registered_handler() {
+        let mut bus = InMemoryEventBus::new();
+        let first = Arc::new(CountingEventHandler::default());
+        let second = Arc::new(CountingEventHandler::default());
+        bus.register_handler(first.clone());
+        bus.register_handler(second.clone());
+
+        bus.publish(vec![Arc::new(TestEvent)]).await.unwrap();
+
+        assert_eq!(first.handled.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert_eq!(second.handled.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    struct RelayingEventHandler {
+        event_bus: Arc<dyn EventBus>,
+    }
+
+    #[async_trait::async_trait]
+    impl EventHandler for RelayingEventHandler {
+        async fn handle(&self, _event: Arc<dyn Event>) -> Result<(), EventError> {
+            self.event_bus.publish(vec![Arc::new(RelayedEvent)]).await
+        }
+
+        fn subscribed_to(&self) -> Vec<&'static str> {
+            vec!["TestEvent"]
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct RelayedEvent;
+
+    impl Event for RelayedEvent {
+        fn event_type(&self) -> &'static str {
+            "RelayedEvent"
+        }
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn from_primitives(
+            &self,
+            _primitives: EventSerialized,
+        ) -> Result<Box<dyn Event>, EventDeserializeError> {
+            Ok(Box::new(RelayedEvent))
+        }
+
+        fn to_primitives(&self) -> EventSerialized {
+            EventSerialized::default()
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct RelayedEventHandler {
+        handled: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl EventHandler for RelayedEventHandler {
+        async fn handle(&self, _event: Arc<dyn Event>) -> Result<(), EventError> {
+            self.handled
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+
+            Ok(())
+        }
+
+        fn subscribed_to(&self) -> Vec<&'static str> {
+            vec!["RelayedEvent"]
+        }
+    }
+
+    #[tokio::test]
+    async fn test_weak_bus_reaches_handlers_registered_later() {
+        let relayed = Arc::new(RelayedEventHandler::default());
+
+        let bus = Arc::new_cyclic(|bus| {
+            let mut event_bus = InMemoryEventBus::new();
+            event_bus.register_handler(Arc::new(RelayingEventHandler {
+                event_bus: Arc::new(WeakEventBus::new(bus.clone())),
+            }));
+            event_bus.register_handler(relayed.clone());
+            event_bus
+        });
+
+        bus.publish(vec![Arc::new(TestEvent)]).await.unwrap();
+
+        assert_eq!(relayed.handled.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_weak_bus_fails_once_the_bus_is_dropped() {
+        let bus = Arc::new(InMemoryEventBus::new());
+        let weak_bus = WeakEventBus::new(Arc::downgrade(&bus));
+        drop(bus);
+
+        assert!(weak_bus.publish(vec![Arc::new(TestEvent)]).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_weak_bus_registers_handlers_on_the_bus_while_it_lives() {
+        let bus = Arc::new(InMemoryEventBus::new());
+        let mut weak_bus = WeakEventBus::new(Arc::downgrade(&bus));
+        let handler = Arc::new(CountingEventHandler::default());
+        weak_bus.register_handler(handler.clone());
+
+        bus.publish(vec![Arc::new(TestEvent)]).await.unwrap();
+
+        assert_eq!(handler.handled.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        drop(bus);
+        weak_bus.register_handler(Arc::new(CountingEventHandler::default()));
+    }
+
     #[tokio::test]
     async fn test_publish() {
         let mut bus = InMemoryEventBus::new();
@@ -0,0 +1,401 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use crate::domain::{
+    bus::command::{Command, CommandBus, CommandError, CommandHandler},
+    idempotency::{
+        IdempotencyRecord, IdempotencyRepository, ERR_IDEMPOTENCY_KEY_REUSED,
+        ERR_IDEMPOTENT_REQUEST_IN_PROGRESS,
+    },
+};
+
+/// Sits in front of another bus so a retried request does not apply its commands twice.
+///
+/// Only the given command types are guarded, and only when the request carried a key. A
+/// request may dispatch the same command type more than once, so each dispatch is stored
+/// under the key, the command type and its position among the dispatches of that type.
+pub struct IdempotentCommandBus<B: CommandBus> {
+    inner: B,
+    repository: Arc<dyn IdempotencyRepository>,
+    command_types: HashSet<&'static str>,
+    ttl: Duration,
+    request: Option<(String, String)>,
+    dispatched: Mutex<HashMap<&'static str, usize>>,
+}
+
+impl<B: CommandBus> IdempotentCommandBus<B> {
+    pub fn new(
+        inner: B,
+        repository: Arc<dyn IdempotencyRepository>,
+        command_types: &[&'static str],
+        ttl: Duration,
+    ) -> Self {
+        Self {
+            inner,
+            repository,
+            command_types: command_types.iter().copied().collect(),
+            ttl,
+            request: None,
+            dispatched: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Guards the commands of the request identified by `key`, `fingerprint` telling apart
+    /// a retry from a different request reusing the key.
+    pub fn with_key(mut self, key: String, fingerprint: String) -> Self {
+        self.request = Some((key, fingerprint));
+        self
+    }
+
+    fn next_key(&self, key: &str, command_type: &'static str) -> String {
+        let mut dispatched = self.dispatched.lock().unwrap();
+        let position = dispatched.entry(command_type).or_insert(0);
+        *position += 1;
+
+        format!("{}:{}:{}", key, command_type, position)
+    }
+}
+
+#[async_trait::async_trait]
+impl<B: CommandBus> CommandBus for IdempotentCommandBus<B> {
+    async fn dispatch(&self, command: Box<dyn Command>) -> Result<(), CommandError> {
+        let command_type = command.command_type();
+        let Some((key, fingerprint)) = self
+            .request
+            .as_ref()
+            .filter(|_| self.command_types.contains(command_type))
+        else {
+            return self.inner.dispatch(command).await;
+        };
+        let key = self.next_key(key, command_type);
+
+        let reserved = self
+            .repository
+            .reserve(
+                &key,
+                &IdempotencyRecord::pending(fingerprint.clone()),
+                self.ttl,
+            )
+            .await
+            .map_err(|e| CommandError::new(e.message()))?;
+
+        if !reserved {
+            let record = self
+                .repository
+                .find(&key)
+                .await
+                .map_err(|e| CommandError::new(e.message()))?;
+
+            if record.fingerprint() != *fingerprint {
+                return Err(CommandError::new(ERR_IDEMPOTENCY_KEY_REUSED.to_string()));
+            }
+            if !record.is_completed() {
+                return Err(CommandError::new(
+                    ERR_IDEMPOTENT_REQUEST_IN_PROGRESS.to_string(),
+                ));
+            }
+
+            return Ok(());
+        }
+
+        // A failed command releases the key, so the client can retry once the cause is fixed.
+        let result = self.inner.dispatch(command).await;
+        let stored = match result {
+            Ok(_) => {
+                self.repository
+                    .save(
+                        &key,
+                        &IdempotencyRecord::completed(fingerprint.clone()),
+                        self.ttl,
+                    )
+                    .await
+            }
+            Err(_) => self.repository.delete(&key).await,
+        };
+        if let Err(e) = stored {
+            eprintln!(
+                "Failed to store idempotency record {}: {}",
+                key,
+                e.message()
+            );
+        }
+
+        result
+    }
+
+    fn register_handler(&mut self, command_type: &'static str, handler: Arc<dyn CommandHandler>) {
+        self.inner.register_handler(command_type, handler);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use mockall::predicate::{always, eq};
+
+    use super::*;
+    use crate::{
+        domain::{base_errors::BaseRepositoryError, idempotency::tests::MockIdempotencyRepository},
+        infrastructure::bus::command::InMemoryCommandBus,
+    };
+
+    const GUARDED_COMMAND_TYPE: &str = "GuardedCommand";
+    const TTL: Duration = Duration::from_secs(60);
+
+    struct TestCommand(&'static str);
+
+    impl Command for TestCommand {
+        fn command_type(&self) -> &'static str {
+            self.0
+        }
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+    }
+
+    struct CountingHandler {
+        calls: Arc<AtomicUsize>,
+        result: Result<(), CommandError>,
+    }
+
+    #[async_trait::async_trait]
+    impl CommandHandler for CountingHandler {
+        async fn handle(&self, _command: Box<dyn Command>) -> Result<(), CommandError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            self.result.clone()
+        }
+    }
+
+    fn bus(
+        repository: MockIdempotencyRepository,
+        result: Result<(), CommandError>,
+    ) -> (IdempotentCommandBus<InMemoryCommandBus>, Arc<AtomicUsize>) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut bus = IdempotentCommandBus::new(
+            InMemoryCommandBus::new(),
+            Arc::new(repository),
+            &[GUARDED_COMMAND_TYPE],
+            TTL,
+        );
+        for command_type in [GUARDED_COMMAND_TYPE, "OtherCommand"] {
+            bus.register_handler(
+                command_type,
+                Arc::new(CountingHandler {
+                    calls: calls.clone(),
+                    result: result.clone(),
+                }),
+            );
+        }
+
+        (bus, calls)
+    }
+
+    #[tokio::test]
+    async fn should_dispatch_and_store_the_command_the_first_time() {
+        let mut repository = MockIdempotencyRepository::new();
+        repository
+            .expect_reserve()
+            .with(
+                eq("user:key:GuardedCommand:1"),
+                eq(IdempotencyRecord::pending("fingerprint".to_string())),
+                eq(TTL),
+            )
+            .times(1)
+            .returning(|_, _, _| Ok(true));
+        repository
+            .expect_save()
+            .with(
+                eq("user:key:GuardedCommand:1"),
+                eq(IdempotencyRecord::completed("fingerprint".to_string())),
+                eq(TTL),
+            )
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let (bus, calls) = bus(repository, Ok(()));
+        let bus = bus.with_key("user:key".to_string(), "fingerprint".to_string());
+
+        let result = bus
+            .dispatch(Box::new(TestCommand(GUARDED_COMMAND_TYPE)))
+            .await;
+
+        assert!(result.is_ok());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn should_not_dispatch_a_retried_command_again() {
+        let mut repository = MockIdempotencyRepository::new();
+        repository
+            .expect_reserve()
+            .times(1)
+            .returning(|_, _, _| Ok(false));
+        repository
+            .expect_find()
+            .with(eq("user:key:GuardedCommand:1"))
+            .times(1)
+            .returning(|_| Ok(IdempotencyRecord::completed("fingerprint".to_string())));
+        repository.expect_save().never();
+
+        let (bus, calls) = bus(repository, Ok(()));
+        let bus = bus.with_key("user:key".to_string(), "fingerprint".to_string());
+
+        let result = bus
+            .dispatch(Box::new(TestCommand(GUARDED_COMMAND_TYPE)))
+            .await;
+
+        assert!(result.is_ok());
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn should_reject_a_reused_key_with_a_different_request() {
+        let mut repository = MockIdempotencyRepository::new();
+        repository
+            .expect_reserve()
+            .times(1)
+            .returning(|_, _, _| Ok(false));
+        repository
+            .expect_find()
+            .times(1)
+            .returning(|_| Ok(IdempotencyRecord::completed("other".to_string())));
+
+        let (bus, calls) = bus(repository, Ok(()));
+        let bus = bus.with_key("user:key".to_string(), "fingerprint".to_string());
+
+        let result = bus
+            .dispatch(Box::new(TestCommand(GUARDED_COMMAND_TYPE)))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(ERR_IDEMPOTENCY_KEY_REUSED.to_string()))
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn should_reject_a_retry_while_the_first_request_is_in_progress() {
+        let mut repository = MockIdempotencyRepository::new();
+        repository
+            .expect_reserve()
+            .times(1)
+            .returning(|_, _, _| Ok(false));
+        repository
+            .expect_find()
+            .times(1)
+            .returning(|_| Ok(IdempotencyRecord::pending("fingerprint".to_string())));
+
+        let (bus, calls) = bus(repository, Ok(()));
+        let bus = bus.with_key("user:key".to_string(), "fingerprint".to_string());
+
+        let result = bus
+            .dispatch(Box::new(TestCommand(GUARDED_COMMAND_TYPE)))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommandError::new(
+                ERR_IDEMPOTENT_REQUEST_IN_PROGRESS.to_string()
+            ))
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn should_release_the_key_when_the_command_fails() {
+        let mut repository = MockIdempotencyRepository::new();
+        repository
+            .expect_reserve()
+            .times(1)
+            .returning(|_, _, _| Ok(true));
+        repository
+            .expect_delete()
+            .with(eq("user:key:GuardedCommand:1"))
+            .times(1)
+            .returning(|_| Ok(()));
+        repository.expect_save().never();
+
+        let error = CommandError::new("boom".to_string());
+        let (bus, calls) = bus(repository, Err(error.clone()));
+        let bus = bus.with_key("user:key".to_string(), "fingerprint".to_string());
+
+        let result = bus
+            .dispatch(Box::new(TestCommand(GUARDED_COMMAND_TYPE)))
+            .await;
+
+        assert_eq!(result, Err(error));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn should_not_dispatch_when_the_key_cannot_be_reserved() {
+        let mut repository = MockIdempotencyRepository::new();
+        repository
+            .expect_reserve()
+            .times(1)
+            .returning(|_, _, _| Err(BaseRepositoryError::ConnectionError));
+
+        let (bus, calls) = bus(repository, Ok(()));
+        let bus = bus.with_key("user:key".to_string(), "fingerprint".to_string());
+
+        let result = bus
+            .dispatch(Box::new(TestCommand(GUARDED_COMMAND_TYPE)))
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn should_tell_apart_repeated_commands_of_the_same_request() {
+        let mut repository = MockIdempotencyRepository::new();
+        for key in ["user:key:GuardedCommand:1", "user:key:GuardedCommand:2"] {
+            repository
+                .expect_reserve()
+                .with(eq(key), always(), always())
+                .times(1)
+                .returning(|_, _, _| Ok(true));
+            repository
+                .expect_save()
+                .with(eq(key), always(), always())
+                .times(1)
+                .returning(|_, _, _| Ok(()));
+        }
+
+        let (bus, calls) = bus(repository, Ok(()));
+        let bus = bus.with_key("user:key".to_string(), "fingerprint".to_string());
+
+        bus.dispatch(Box::new(TestCommand(GUARDED_COMMAND_TYPE)))
+            .await
+            .unwrap();
+        bus.dispatch(Box::new(TestCommand(GUARDED_COMMAND_TYPE)))
+            .await
+            .unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn should_pass_through_unguarded_commands_and_requests_without_key() {
+        let mut repository = MockIdempotencyRepository::new();
+        repository.expect_reserve().never();
+
+        let (bus, calls) = bus(repository, Ok(()));
+        bus.dispatch(Box::new(TestCommand(GUARDED_COMMAND_TYPE)))
+            .await
+            .unwrap();
+
+        let bus = bus.with_key("user:key".to_string(), "fingerprint".to_string());
+        bus.dispatch(Box::new(TestCommand("OtherCommand")))
+            .await
+            .unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+}
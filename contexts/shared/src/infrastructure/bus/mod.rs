@@ -1,3 +1,4 @@
 pub mod command;
 pub mod event;
+pub mod idempotent_command;
 pub mod query;
@@ -95,7 +95,8 @@ pub enum OrderTypeGql {
 #[derive(Debug, Clone, PartialEq, Eq, Hash, InputObject)]
 #[graphql(name = "Cursor")]
 pub struct CursorGql {
-    pub after: Option<OffsetDateTime>,
+    /// The cursor of the last edge seen, or a date to start after.
+    pub after: Option<String>,
     pub before: Option<OffsetDateTime>,
     pub first: Option<usize>,
     pub last: Option<usize>,
@@ -106,7 +107,7 @@ impl TryInto<Cursor> for CursorGql {
 
     fn try_into(self) -> Result<Cursor, Self::Error> {
         Cursor::new_validated(
-            self.after.map(AfterCursor::new),
+            self.after.map(AfterCursor::try_from).transpose()?,
             self.before.map(BeforeCursor::new),
             self.first.map(FirstField::new).transpose()?,
             self.last.map(LastField::new).transpose()?,
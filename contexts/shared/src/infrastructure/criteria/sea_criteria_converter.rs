@@ -10,6 +10,7 @@ use crate::domain::criteria::{
     cursor::Cursor,
     filter::{Filter, FilterOperator},
     order::{Order, OrderType},
+    page::CursorPage,
     Criteria,
 };
 
@@ -151,3 +152,128 @@ pub fn convert_criteria_cursor<
 
     query
 }
+
+/// Returns the page size requested by the cursor and whether it is paginating backwards.
+fn cursor_page_size(cursor: Option<&Cursor>) -> (Option<usize>, bool) {
+    match cursor {
+        None => (Some(DEFAULT_LIMIT), false),
+        Some(cursor) => match (cursor.first(), cursor.last()) {
+            (Some(first), _) => (Some(first.value()), false),
+            (None, Some(last)) => (Some(last.value()), true),
+            (None, None) => (None, false),
+        },
+    }
+}
+
+/// Like [`convert_criteria_cursor`], but fetches one row past the requested page size so
+/// [`into_cursor_page`] can tell whether another page exists. The query must be a cursor by
+/// `(created_at, id)`, which keeps rows created at the same instant in a stable order.
+pub fn convert_criteria_cursor_page<
+    'a,
+    Columns: ColumnTrait<Err = ColumnFromStrErr>,
+    E: FromQueryResult,
+>(
+    cursor: Option<&Cursor>,
+    query: &'a mut SeaCursor<SelectModel<E>>,
+) -> &'a mut SeaCursor<SelectModel<E>> {
+    let mut query = query;
+
+    if let Some(cursor) = cursor {
+        if let Some(after) = cursor.after() {
+            // Without an id every row created at the cursor date is skipped
+            let id = after
+                .id()
+                .and_then(|id| Uuid::parse_str(id).ok())
+                .unwrap_or(Uuid::max());
+            query = query.after((after.value().to_owned(), id));
+        }
+
+        if let Some(before) = cursor.before() {
+            query = query.before((before.value().to_owned(), Uuid::nil()));
+        }
+    }
+
+    match cursor_page_size(cursor) {
+        (Some(size), false) => query.first(size as u64 + 1),
+        (Some(size), true) => query.last(size as u64 + 1),
+        (None, _) => query,
+    }
+}
+
+/// Trims the extra row fetched by [`convert_criteria_cursor_page`] and works out the page info.
+pub fn into_cursor_page<T>(cursor: Option<&Cursor>, mut items: Vec<T>) -> CursorPage<T> {
+    let (size, backwards) = cursor_page_size(cursor);
+    let has_more = size.is_some_and(|size| items.len() > size);
+
+    if has_more {
+        if backwards {
+            items.remove(0);
+        } else {
+            items.pop();
+        }
+    }
+
+    let has_after = cursor.is_some_and(|cursor| cursor.after().is_some());
+    let has_before = cursor.is_some_and(|cursor| cursor.before().is_some());
+
+    if backwards {
+        CursorPage::new(items, has_more, has_before)
+    } else {
+        CursorPage::new(items, has_after, has_more)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::OffsetDateTime;
+
+    use super::*;
+    use crate::domain::criteria::cursor::{AfterCursor, BeforeCursor, FirstField, LastField};
+
+    #[test]
+    fn it_should_use_default_limit_without_cursor() {
+        let page = into_cursor_page(None, (0..DEFAULT_LIMIT + 1).collect());
+
+        assert_eq!(page.items().len(), DEFAULT_LIMIT);
+        assert_eq!(page.items().last(), Some(&(DEFAULT_LIMIT - 1)));
+        assert!(page.has_next_page());
+        assert!(!page.has_previous_page());
+    }
+
+    #[test]
+    fn it_should_detect_next_page_when_paginating_forward() {
+        let cursor = Cursor::new(
+            Some(AfterCursor::new(OffsetDateTime::now_utc())),
+            None,
+            Some(FirstField::new(2).unwrap()),
+            None,
+        );
+
+        let page = into_cursor_page(Some(&cursor), vec![1, 2, 3]);
+        assert_eq!(page.items(), &[1, 2]);
+        assert!(page.has_next_page());
+        assert!(page.has_previous_page());
+
+        let page = into_cursor_page(Some(&cursor), vec![1, 2]);
+        assert_eq!(page.items(), &[1, 2]);
+        assert!(!page.has_next_page());
+    }
+
+    #[test]
+    fn it_should_detect_previous_page_when_paginating_backward() {
+        let cursor = Cursor::new(
+            None,
+            Some(BeforeCursor::new(OffsetDateTime::now_utc())),
+            None,
+            Some(LastField::new(2).unwrap()),
+        );
+
+        let page = into_cursor_page(Some(&cursor), vec![1, 2, 3]);
+        assert_eq!(page.items(), &[2, 3]);
+        assert!(page.has_previous_page());
+        assert!(page.has_next_page());
+
+        let page = into_cursor_page(Some(&cursor), vec![2, 3]);
+        assert!(!page.has_previous_page());
+    }
+}
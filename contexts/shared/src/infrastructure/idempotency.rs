@@ -0,0 +1,159 @@
+use std::{sync::Arc, time::Duration};
+
+use redis::Client;
+
+use crate::domain::{
+    base_errors::BaseRepositoryError,
+    idempotency::{IdempotencyRecord, IdempotencyRepository},
+};
+
+#[derive(Clone)]
+pub struct RedisIdempotencyRepository {
+    pub conn: Arc<Client>,
+}
+
+impl RedisIdempotencyRepository {
+    pub fn new(conn: Arc<Client>) -> Self {
+        Self { conn }
+    }
+
+    async fn get_conn(&self) -> Result<redis::aio::MultiplexedConnection, BaseRepositoryError> {
+        self.conn
+            .get_multiplexed_tokio_connection()
+            .await
+            .map_err(|e| BaseRepositoryError::UnexpectedError(e.to_string()))
+    }
+
+    fn serialize(record: &IdempotencyRecord) -> Result<String, BaseRepositoryError> {
+        serde_json::to_string(record)
+            .map_err(|e| BaseRepositoryError::UnexpectedError(e.to_string()))
+    }
+}
+
+#[async_trait::async_trait]
+impl IdempotencyRepository for RedisIdempotencyRepository {
+    async fn reserve(
+        &self,
+        key: &str,
+        record: &IdempotencyRecord,
+        ttl: Duration,
+    ) -> Result<bool, BaseRepositoryError> {
+        let mut conn = self.get_conn().await?;
+        let reserved: Option<String> = redis::cmd("SET")
+            .arg(format!("idempotency:{}", key))
+            .arg(Self::serialize(record)?)
+            .arg("NX")
+            .arg("EX")
+            .arg(ttl.as_secs())
+            .query_async(&mut conn)
+            .await
+            .map_err(|e| BaseRepositoryError::UnexpectedError(e.to_string()))?;
+
+        Ok(reserved.is_some())
+    }
+
+    async fn find(&self, key: &str) -> Result<IdempotencyRecord, BaseRepositoryError> {
+        let mut conn = self.get_conn().await?;
+        let record: Option<String> = redis::cmd("GET")
+            .arg(format!("idempotency:{}", key))
+            .query_async(&mut conn)
+            .await
+            .map_err(|e| BaseRepositoryError::UnexpectedError(e.to_string()))?;
+        let record = record.ok_or(BaseRepositoryError::NotFound)?;
+
+        serde_json::from_str(&record)
+            .map_err(|e| BaseRepositoryError::UnexpectedError(e.to_string()))
+    }
+
+    async fn save(
+        &self,
+        key: &str,
+        record: &IdempotencyRecord,
+        ttl: Duration,
+    ) -> Result<(), BaseRepositoryError> {
+        let mut conn = self.get_conn().await?;
+        redis::cmd("SET")
+            .arg(format!("idempotency:{}", key))
+            .arg(Self::serialize(record)?)
+            .arg("EX")
+            .arg(ttl.as_secs())
+            .query_async::<_, ()>(&mut conn)
+            .await
+            .map_err(|e| BaseRepositoryError::UnexpectedError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), BaseRepositoryError> {
+        let mut conn = self.get_conn().await?;
+        redis::cmd("DEL")
+            .arg(format!("idempotency:{}", key))
+            .query_async::<_, ()>(&mut conn)
+            .await
+            .map_err(|e| BaseRepositoryError::UnexpectedError(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use testcontainers::{clients::Cli as Docker, GenericImage};
+
+    const TTL: Duration = Duration::from_secs(60);
+
+    fn get_redis_image() -> GenericImage {
+        GenericImage::new("redis", "7-alpine")
+            .with_exposed_port(6379)
+            .to_owned()
+    }
+
+    #[tokio::test]
+    async fn should_reserve_a_key_only_once() {
+        let docker = Docker::default();
+        let node = docker.run(get_redis_image());
+
+        let conn = redis::Client::open(format!(
+            "redis://localhost:{}",
+            node.get_host_port_ipv4(6379)
+        ))
+        .unwrap();
+        let repository = RedisIdempotencyRepository::new(Arc::new(conn));
+
+        let first = IdempotencyRecord::pending("first".to_string());
+        let second = IdempotencyRecord::pending("second".to_string());
+
+        assert!(repository.reserve("key", &first, TTL).await.unwrap());
+        assert!(!repository.reserve("key", &second, TTL).await.unwrap());
+        assert_eq!(repository.find("key").await.unwrap(), first);
+    }
+
+    #[tokio::test]
+    async fn should_save_and_delete_a_record() {
+        let docker = Docker::default();
+        let node = docker.run(get_redis_image());
+
+        let conn = redis::Client::open(format!(
+            "redis://localhost:{}",
+            node.get_host_port_ipv4(6379)
+        ))
+        .unwrap();
+        let repository = RedisIdempotencyRepository::new(Arc::new(conn));
+
+        let pending = IdempotencyRecord::pending("fingerprint".to_string());
+        let completed = IdempotencyRecord::completed("fingerprint".to_string());
+
+        repository.reserve("key", &pending, TTL).await.unwrap();
+        repository.save("key", &completed, TTL).await.unwrap();
+        assert_eq!(repository.find("key").await.unwrap(), completed);
+
+        repository.delete("key").await.unwrap();
+        assert_eq!(
+            repository.find("key").await,
+            Err(BaseRepositoryError::NotFound)
+        );
+        assert!(repository.reserve("key", &pending, TTL).await.unwrap());
+    }
+}
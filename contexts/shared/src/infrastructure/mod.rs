@@ -1,3 +1,4 @@
 pub mod bus;
 pub mod criteria;
+pub mod idempotency;
 pub mod storage;
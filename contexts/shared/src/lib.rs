@@ -2,6 +2,10 @@ pub mod domain;
 pub mod infrastructure;
 
 pub const USER_STORAGE_MODEL: &str = "user";
+pub const RECEIPT_STORAGE_MODEL: &str = "receipt";
+pub const DONA_EXPORT_STORAGE_MODEL: &str = "dona_export";
+pub const DONA_STORAGE_MODEL: &str = "dona";
+pub const USER_PAYMENT_METHOD_STORAGE_MODEL: &str = "user_payment_method";
 
 pub const FILE_EXTENSIONS: [&str; 4] = ["jpg", "jpeg", "png", "gif"];
 
@@ -1,12 +1,46 @@
 pub use sea_orm_migration::prelude::*;
 
 mod m20220101_000001_create_table;
+mod m20240401_000001_add_currencies;
+mod m20240402_000001_create_exchange_rates;
+mod m20240403_000001_create_campaigns;
+mod m20240404_000001_create_pledges;
+mod m20240405_000001_add_guest_donors;
+mod m20240406_000001_create_receipts;
+mod m20240407_000001_create_supporter_preferences;
+mod m20240408_000001_create_dona_exports;
+mod m20240409_000001_create_dona_attachments;
+mod m20240410_000001_add_crypto_payments;
+mod m20240411_000001_add_dona_msg_review;
+mod m20240412_000001_create_recipient_settings;
+mod m20240413_000001_create_fee_rules;
+mod m20240414_000001_create_dona_replies;
+mod m20240415_000001_create_notifications;
+mod m20240416_000001_add_paypal_emails;
 
 pub struct Migrator;
 
 #[async_trait::async_trait]
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(m20220101_000001_create_table::Migration)]
+        vec![
+            Box::new(m20220101_000001_create_table::Migration),
+            Box::new(m20240401_000001_add_currencies::Migration),
+            Box::new(m20240402_000001_create_exchange_rates::Migration),
+            Box::new(m20240403_000001_create_campaigns::Migration),
+            Box::new(m20240404_000001_create_pledges::Migration),
+            Box::new(m20240405_000001_add_guest_donors::Migration),
+            Box::new(m20240406_000001_create_receipts::Migration),
+            Box::new(m20240407_000001_create_supporter_preferences::Migration),
+            Box::new(m20240408_000001_create_dona_exports::Migration),
+            Box::new(m20240409_000001_create_dona_attachments::Migration),
+            Box::new(m20240410_000001_add_crypto_payments::Migration),
+            Box::new(m20240411_000001_add_dona_msg_review::Migration),
+            Box::new(m20240412_000001_create_recipient_settings::Migration),
+            Box::new(m20240413_000001_create_fee_rules::Migration),
+            Box::new(m20240414_000001_create_dona_replies::Migration),
+            Box::new(m20240415_000001_create_notifications::Migration),
+            Box::new(m20240416_000001_add_paypal_emails::Migration),
+        ]
     }
 }
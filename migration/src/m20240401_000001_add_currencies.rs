@@ -0,0 +1,73 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Donas::Table)
+                    .add_column(
+                        ColumnDef::new(Donas::Currency)
+                            .string_len(3)
+                            .not_null()
+                            .default("USD"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UserPaymentMethods::Table)
+                    .add_column(
+                        ColumnDef::new(UserPaymentMethods::Currencies)
+                            .string()
+                            .not_null()
+                            .default("USD"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UserPaymentMethods::Table)
+                    .drop_column(UserPaymentMethods::Currencies)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Donas::Table)
+                    .drop_column(Donas::Currency)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Donas {
+    Table,
+    Currency,
+}
+
+#[derive(DeriveIden)]
+enum UserPaymentMethods {
+    Table,
+    Currencies,
+}
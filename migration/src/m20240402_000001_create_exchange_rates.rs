@@ -0,0 +1,81 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ExchangeRates::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(ExchangeRates::Id).uuid().primary_key())
+                    .col(
+                        ColumnDef::new(ExchangeRates::BaseCurrency)
+                            .string_len(3)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ExchangeRates::QuoteCurrency)
+                            .string_len(3)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ExchangeRates::Rate).decimal().not_null())
+                    .col(ColumnDef::new(ExchangeRates::Date).date().not_null())
+                    .col(
+                        ColumnDef::new(ExchangeRates::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ExchangeRates::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_exchange_rates_pair_date")
+                    .table(ExchangeRates::Table)
+                    .col(ExchangeRates::BaseCurrency)
+                    .col(ExchangeRates::QuoteCurrency)
+                    .col(ExchangeRates::Date)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .if_exists()
+                    .table(ExchangeRates::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum ExchangeRates {
+    Table,
+    Id,
+    BaseCurrency,
+    QuoteCurrency,
+    Rate,
+    Date,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,116 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Campaigns::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(Campaigns::Id).uuid().primary_key())
+                    .col(ColumnDef::new(Campaigns::UserId).uuid().not_null())
+                    .col(ColumnDef::new(Campaigns::Title).string_len(150).not_null())
+                    .col(ColumnDef::new(Campaigns::Target).decimal().not_null())
+                    .col(ColumnDef::new(Campaigns::Currency).string_len(3).not_null())
+                    .col(
+                        ColumnDef::new(Campaigns::Deadline)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(Campaigns::Status).string().not_null())
+                    .col(
+                        ColumnDef::new(Campaigns::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(Campaigns::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_campaigns_user_id")
+                    .table(Campaigns::Table)
+                    .col(Campaigns::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Donas::Table)
+                    .add_column(ColumnDef::new(Donas::CampaignId).uuid().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_donas_campaign_id")
+                    .table(Donas::Table)
+                    .col(Donas::CampaignId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_donas_campaign_id")
+                    .table(Donas::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Donas::Table)
+                    .drop_column(Donas::CampaignId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().if_exists().table(Campaigns::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Campaigns {
+    Table,
+    Id,
+    UserId,
+    Title,
+    Target,
+    Currency,
+    Deadline,
+    Status,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Donas {
+    Table,
+    CampaignId,
+}
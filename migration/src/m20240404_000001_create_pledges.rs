@@ -0,0 +1,143 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Pledges::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(Pledges::Id).uuid().primary_key())
+                    .col(ColumnDef::new(Pledges::Msg).string().not_null())
+                    .col(ColumnDef::new(Pledges::Amount).decimal().not_null())
+                    .col(ColumnDef::new(Pledges::Currency).string_len(3).not_null())
+                    .col(ColumnDef::new(Pledges::Interval).string().not_null())
+                    .col(ColumnDef::new(Pledges::OptionMethod).string().not_null())
+                    .col(ColumnDef::new(Pledges::UserId).uuid().not_null())
+                    .col(ColumnDef::new(Pledges::SenderId).uuid().not_null())
+                    .col(ColumnDef::new(Pledges::Status).string().not_null())
+                    .col(
+                        ColumnDef::new(Pledges::NextDueAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(Pledges::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(Pledges::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_pledges_user_id")
+                    .table(Pledges::Table)
+                    .col(Pledges::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_pledges_sender_id")
+                    .table(Pledges::Table)
+                    .col(Pledges::SenderId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_pledges_status_next_due_at")
+                    .table(Pledges::Table)
+                    .col(Pledges::Status)
+                    .col(Pledges::NextDueAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Donas::Table)
+                    .add_column(ColumnDef::new(Donas::PledgeId).uuid().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_donas_pledge_id")
+                    .table(Donas::Table)
+                    .col(Donas::PledgeId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_donas_pledge_id")
+                    .table(Donas::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Donas::Table)
+                    .drop_column(Donas::PledgeId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().if_exists().table(Pledges::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Pledges {
+    Table,
+    Id,
+    Msg,
+    Amount,
+    Currency,
+    Interval,
+    OptionMethod,
+    UserId,
+    SenderId,
+    Status,
+    NextDueAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Donas {
+    Table,
+    PledgeId,
+}
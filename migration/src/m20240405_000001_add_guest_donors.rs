@@ -0,0 +1,54 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Donas::Table)
+                    .modify_column(ColumnDef::new(Donas::SenderId).uuid().null())
+                    .add_column(ColumnDef::new(Donas::GuestName).string_len(100).null())
+                    .add_column(ColumnDef::new(Donas::GuestEmail).string().null())
+                    .add_column(
+                        ColumnDef::new(Donas::IsAnonymous)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rolling back fails while guest donas exist, as they have no sender to restore.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Donas::Table)
+                    .drop_column(Donas::IsAnonymous)
+                    .drop_column(Donas::GuestEmail)
+                    .drop_column(Donas::GuestName)
+                    .modify_column(ColumnDef::new(Donas::SenderId).uuid().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Donas {
+    Table,
+    SenderId,
+    GuestName,
+    GuestEmail,
+    IsAnonymous,
+}
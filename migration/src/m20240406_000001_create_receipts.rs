@@ -0,0 +1,74 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Receipts::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(Receipts::Id).uuid().primary_key())
+                    .col(ColumnDef::new(Receipts::DonaId).uuid().not_null())
+                    .col(ColumnDef::new(Receipts::UserId).uuid().not_null())
+                    .col(ColumnDef::new(Receipts::SenderId).uuid().null())
+                    .col(ColumnDef::new(Receipts::Number).big_integer().not_null())
+                    .col(
+                        ColumnDef::new(Receipts::IssuedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_receipts_dona_id")
+                    .table(Receipts::Table)
+                    .col(Receipts::DonaId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // Keeps the numbering gapless and unique when two confirmations race for the same
+        // recipient, the second insert fails instead of reusing the number.
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_receipts_user_id_number")
+                    .table(Receipts::Table)
+                    .col(Receipts::UserId)
+                    .col(Receipts::Number)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().if_exists().table(Receipts::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Receipts {
+    Table,
+    Id,
+    DonaId,
+    UserId,
+    SenderId,
+    Number,
+    IssuedAt,
+}
@@ -0,0 +1,85 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SupporterPreferences::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SupporterPreferences::UserId)
+                            .uuid()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SupporterPreferences::HiddenFromLeaderboards)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(
+                        ColumnDef::new(SupporterPreferences::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // The leaderboard groups the confirmed donas of a recipient by sender.
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_donas_user_id_sender_id")
+                    .table(Donas::Table)
+                    .col(Donas::UserId)
+                    .col(Donas::SenderId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_donas_user_id_sender_id")
+                    .table(Donas::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .if_exists()
+                    .table(SupporterPreferences::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum SupporterPreferences {
+    Table,
+    UserId,
+    HiddenFromLeaderboards,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Donas {
+    Table,
+    UserId,
+    SenderId,
+}
@@ -0,0 +1,94 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(DonaExports::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(DonaExports::Id).uuid().primary_key())
+                    .col(ColumnDef::new(DonaExports::UserId).uuid().not_null())
+                    .col(ColumnDef::new(DonaExports::Format).string().not_null())
+                    .col(ColumnDef::new(DonaExports::Columns).string().not_null())
+                    .col(ColumnDef::new(DonaExports::Filters).text().not_null())
+                    .col(ColumnDef::new(DonaExports::Status).string().not_null())
+                    .col(
+                        ColumnDef::new(DonaExports::RowCount)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(ColumnDef::new(DonaExports::Error).text().null())
+                    .col(
+                        ColumnDef::new(DonaExports::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(DonaExports::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_dona_exports_user_id_created_at")
+                    .table(DonaExports::Table)
+                    .col(DonaExports::UserId)
+                    .col(DonaExports::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        // The export worker picks the oldest pending exports on every run.
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_dona_exports_status_created_at")
+                    .table(DonaExports::Table)
+                    .col(DonaExports::Status)
+                    .col(DonaExports::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .if_exists()
+                    .table(DonaExports::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum DonaExports {
+    Table,
+    Id,
+    UserId,
+    Format,
+    Columns,
+    Filters,
+    Status,
+    RowCount,
+    Error,
+    CreatedAt,
+    UpdatedAt,
+}
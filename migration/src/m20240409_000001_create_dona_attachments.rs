@@ -0,0 +1,84 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(DonaAttachments::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(DonaAttachments::Id).uuid().primary_key())
+                    .col(ColumnDef::new(DonaAttachments::DonaId).uuid().not_null())
+                    .col(ColumnDef::new(DonaAttachments::UserId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(DonaAttachments::UploaderId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(DonaAttachments::Filename)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(DonaAttachments::ContentType)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(DonaAttachments::Size)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(DonaAttachments::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_dona_attachments_dona_id")
+                    .table(DonaAttachments::Table)
+                    .col(DonaAttachments::DonaId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .if_exists()
+                    .table(DonaAttachments::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum DonaAttachments {
+    Table,
+    Id,
+    DonaId,
+    UserId,
+    UploaderId,
+    Filename,
+    ContentType,
+    Size,
+    CreatedAt,
+}
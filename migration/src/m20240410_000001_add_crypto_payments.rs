@@ -0,0 +1,74 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UserPaymentMethods::Table)
+                    .add_column(
+                        ColumnDef::new(UserPaymentMethods::CryptoNetwork)
+                            .string_len(20)
+                            .null(),
+                    )
+                    .add_column(
+                        ColumnDef::new(UserPaymentMethods::CryptoAddress)
+                            .string_len(100)
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Donas::Table)
+                    .add_column(ColumnDef::new(Donas::TxHash).string_len(100).null())
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Donas::Table)
+                    .drop_column(Donas::TxHash)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UserPaymentMethods::Table)
+                    .drop_column(UserPaymentMethods::CryptoAddress)
+                    .drop_column(UserPaymentMethods::CryptoNetwork)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum UserPaymentMethods {
+    Table,
+    CryptoNetwork,
+    CryptoAddress,
+}
+
+#[derive(DeriveIden)]
+enum Donas {
+    Table,
+    TxHash,
+}
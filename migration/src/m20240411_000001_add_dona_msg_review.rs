@@ -0,0 +1,73 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Donas::Table)
+                    .add_column(
+                        ColumnDef::new(Donas::MsgStatus)
+                            .string_len(20)
+                            .not_null()
+                            .default("published"),
+                    )
+                    .add_column(
+                        ColumnDef::new(Donas::MsgFlags)
+                            .string_len(50)
+                            .not_null()
+                            .default(""),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_donas_user_id_msg_status")
+                    .table(Donas::Table)
+                    .col(Donas::UserId)
+                    .col(Donas::MsgStatus)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_donas_user_id_msg_status")
+                    .table(Donas::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Donas::Table)
+                    .drop_column(Donas::MsgFlags)
+                    .drop_column(Donas::MsgStatus)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Donas {
+    Table,
+    UserId,
+    MsgStatus,
+    MsgFlags,
+}
@@ -0,0 +1,81 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(RecipientSettings::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(RecipientSettings::UserId)
+                            .uuid()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(RecipientSettings::MinAmount)
+                            .decimal()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(RecipientSettings::MaxAmount)
+                            .decimal()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(RecipientSettings::Currency)
+                            .string_len(3)
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(RecipientSettings::ActiveMethods)
+                            .string_len(50)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RecipientSettings::MessagesAllowed)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .col(
+                        ColumnDef::new(RecipientSettings::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .if_exists()
+                    .table(RecipientSettings::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum RecipientSettings {
+    Table,
+    UserId,
+    MinAmount,
+    MaxAmount,
+    Currency,
+    ActiveMethods,
+    MessagesAllowed,
+    UpdatedAt,
+}
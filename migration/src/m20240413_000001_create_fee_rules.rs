@@ -0,0 +1,90 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(FeeRules::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(FeeRules::Id).uuid().primary_key())
+                    .col(ColumnDef::new(FeeRules::OptionMethod).string().not_null())
+                    .col(ColumnDef::new(FeeRules::Percentage).decimal().not_null())
+                    .col(ColumnDef::new(FeeRules::FixedAmount).decimal().null())
+                    .col(ColumnDef::new(FeeRules::FixedCurrency).string_len(3).null())
+                    .col(ColumnDef::new(FeeRules::EffectiveFrom).date().not_null())
+                    .col(
+                        ColumnDef::new(FeeRules::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_fee_rules_option_method_effective_from")
+                    .table(FeeRules::Table)
+                    .col(FeeRules::OptionMethod)
+                    .col(FeeRules::EffectiveFrom)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Donas::Table)
+                    .add_column(ColumnDef::new(Donas::FeeAmount).decimal().null())
+                    .add_column(ColumnDef::new(Donas::NetAmount).decimal().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Donas::Table)
+                    .drop_column(Donas::NetAmount)
+                    .drop_column(Donas::FeeAmount)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().if_exists().table(FeeRules::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum FeeRules {
+    Table,
+    Id,
+    OptionMethod,
+    Percentage,
+    FixedAmount,
+    FixedCurrency,
+    EffectiveFrom,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Donas {
+    Table,
+    FeeAmount,
+    NetAmount,
+}
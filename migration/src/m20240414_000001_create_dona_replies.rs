@@ -0,0 +1,71 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(DonaReplies::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(DonaReplies::Id).uuid().primary_key())
+                    .col(ColumnDef::new(DonaReplies::DonaId).uuid().not_null())
+                    .col(ColumnDef::new(DonaReplies::UserId).uuid().not_null())
+                    .col(ColumnDef::new(DonaReplies::Msg).string().not_null())
+                    .col(ColumnDef::new(DonaReplies::Visibility).string().not_null())
+                    .col(
+                        ColumnDef::new(DonaReplies::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(DonaReplies::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_dona_replies_dona_id")
+                    .table(DonaReplies::Table)
+                    .col(DonaReplies::DonaId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .if_exists()
+                    .table(DonaReplies::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum DonaReplies {
+    Table,
+    Id,
+    DonaId,
+    UserId,
+    Msg,
+    Visibility,
+    CreatedAt,
+    UpdatedAt,
+}
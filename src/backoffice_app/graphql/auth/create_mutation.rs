@@ -5,7 +5,7 @@ use time::{format_description::well_known::Rfc3339, OffsetDateTime};
 use uuid::Uuid;
 
 use crate::{
-    gql_validators::{check_admin, check_upload, is_authenticated},
+    gql_validators::{check_admin, check_upload, is_authenticated, IMAGE_TYPES},
     CommandBusType,
 };
 
@@ -43,7 +43,7 @@ impl CreateUserMutation {
             .clone()
             .map(|p| p.value(ctx))
             .transpose()?;
-        check_upload(&upload_value)?;
+        check_upload(upload_value.as_ref(), &IMAGE_TYPES)?;
 
         let (profile_name, profile_file) = if let Some(upload_value) = &upload_value {
             (
@@ -5,7 +5,7 @@ use time::{format_description::well_known::Rfc3339, OffsetDateTime};
 use uuid::Uuid;
 
 use crate::{
-    gql_validators::{check_admin, check_permission, check_upload},
+    gql_validators::{check_admin, check_permission, check_upload, IMAGE_TYPES},
     CommandBusType,
 };
 
@@ -43,7 +43,7 @@ impl UpdateUserMutation {
             .map(|p| p.map(|p| p.value(ctx)).transpose())
             .transpose()?;
         if let Some(value) = &upload_value {
-            check_upload(value)?;
+            check_upload(value.as_ref(), &IMAGE_TYPES)?;
         }
 
         let (profile_name, profile_file) = if let Some(upload_value) = &upload_value {
@@ -1,8 +1,326 @@
 use std::sync::Arc;
 
+use backoffice::auth::infrastructure::persistence::sea_user_repo::SeaUserRepository;
 use dona_context::{
-    dona::infrastructure::persistence::sea_dona_repo::SeaDonaRepo,
+    attachment::{
+        application::{
+            add::{
+                command::{AddDonaAttachmentCommandHandler, ADD_DONA_ATTACHMENT_COMMAND_TYPE},
+                service::DonaAttachmentAdder,
+            },
+            download::{
+                query::{DownloadDonaAttachmentQueryHandler, DOWNLOAD_DONA_ATTACHMENT_QUERY_TYPE},
+                service::DonaAttachmentDownloader,
+            },
+            find_by_dona::{
+                query::{FindDonaAttachmentsQueryHandler, FIND_DONA_ATTACHMENTS_QUERY_TYPE},
+                service::DonaAttachmentsFinder,
+            },
+            remove_on_dona_deleted::{
+                event_handler::RemoveDonaAttachmentsOnDonaDeleted, service::DonaAttachmentsRemover,
+            },
+        },
+        infrastructure::persistence::sea_dona_attachment_repo::SeaDonaAttachmentRepo,
+    },
+    campaign::{
+        application::{
+            check_goal::{
+                event_handler::CheckCampaignGoalOnDonaConfirmed, service::CampaignGoalChecker,
+            },
+            close::{
+                command::{CloseCampaignCommandHandler, CLOSE_CAMPAIGN_COMMAND_TYPE},
+                service::CampaignCloser,
+            },
+            create::{
+                command::{CreateCampaignCommandHandler, CREATE_CAMPAIGN_COMMAND_TYPE},
+                service::CampaignCreator,
+            },
+            delete::{
+                command::{DeleteCampaignCommandHandler, DELETE_CAMPAIGN_COMMAND_TYPE},
+                service::CampaignDeleter,
+            },
+            find::{
+                query::{FindCampaignQueryHandler, FIND_CAMPAIGN_QUERY_TYPE},
+                service::CampaignFinder,
+            },
+            find_by_criteria::{
+                query::{
+                    FindCampaignsByCriteriaQueryHandler, FIND_CAMPAIGNS_BY_CRITERIA_QUERY_TYPE,
+                },
+                service::CampaignsFinder,
+            },
+            update::{
+                command::{UpdateCampaignCommandHandler, UPDATE_CAMPAIGN_COMMAND_TYPE},
+                service::CampaignUpdater,
+            },
+        },
+        infrastructure::persistence::sea_campaign_repo::SeaCampaignRepo,
+    },
+    dona::{
+        application::{
+            cancel::{
+                command::{CancelDonaCommandHandler, CANCEL_DONA_COMMAND_TYPE},
+                service::DonaCanceller,
+            },
+            checkout::{
+                query::{StartDonaCheckoutQueryHandler, START_DONA_CHECKOUT_QUERY_TYPE},
+                service::DonaCheckoutStarter,
+            },
+            confirm::{
+                command::{ConfirmDonaCommandHandler, CONFIRM_DONA_COMMAND_TYPE},
+                service::DonaConfirmer,
+            },
+            confirm_payment::{
+                command::{ConfirmDonaPaymentCommandHandler, CONFIRM_DONA_PAYMENT_COMMAND_TYPE},
+                service::DonaPaymentConfirmer,
+            },
+            create::{
+                command::{CreateDonaCommandHandler, CREATE_DONA_COMMAND_TYPE},
+                service::DonaCreator,
+            },
+            create_reply::{
+                command::{CreateDonaReplyCommandHandler, CREATE_DONA_REPLY_COMMAND_TYPE},
+                service::DonaReplyCreator,
+            },
+            delete::{
+                command::{DeleteDonaCommandHandler, DELETE_DONA_COMMAND_TYPE},
+                service::DonaDeleter,
+            },
+            delete_reply::{
+                command::{DeleteDonaReplyCommandHandler, DELETE_DONA_REPLY_COMMAND_TYPE},
+                service::DonaReplyDeleter,
+            },
+            delete_reply_on_dona_deleted::{
+                event_handler::DeleteDonaReplyOnDonaDeleted, service::DonaReplyRemover,
+            },
+            expire::{
+                command::{ExpireDonaCommandHandler, EXPIRE_DONA_COMMAND_TYPE},
+                service::DonaExpirer,
+            },
+            expire_stale::{
+                command::{ExpireStaleDonasCommandHandler, EXPIRE_STALE_DONAS_COMMAND_TYPE},
+                service::StaleDonasExpirer,
+            },
+            find::{
+                query::{FindDonaQueryHandler, FIND_DONA_QUERY_TYPE},
+                service::DonaFinder,
+            },
+            find_by_criteria::{
+                query::{FindDonasByCriteriaQueryHandler, FIND_DONAS_BY_CRITERIA_QUERY_TYPE},
+                service::DonasFinder,
+            },
+            find_public_replies::{
+                query::{FindPublicDonaRepliesQueryHandler, FIND_PUBLIC_DONA_REPLIES_QUERY_TYPE},
+                service::PublicDonaRepliesFinder,
+            },
+            import::{
+                command::{ImportDonasCommandHandler, IMPORT_DONAS_COMMAND_TYPE},
+                service::DonasImporter,
+            },
+            received_total::{
+                query::{GetReceivedTotalQueryHandler, GET_RECEIVED_TOTAL_QUERY_TYPE},
+                service::ReceivedTotalCalculator,
+            },
+            refresh_payment::{
+                command::{RefreshDonaPaymentCommandHandler, REFRESH_DONA_PAYMENT_COMMAND_TYPE},
+                service::DonaPaymentRefresher,
+            },
+            refund::{
+                command::{RefundDonaCommandHandler, REFUND_DONA_COMMAND_TYPE},
+                service::DonaRefunder,
+            },
+            reject::{
+                command::{RejectDonaCommandHandler, REJECT_DONA_COMMAND_TYPE},
+                service::DonaRejecter,
+            },
+            review_msg::{
+                command::{ReviewDonaMsgCommandHandler, REVIEW_DONA_MSG_COMMAND_TYPE},
+                service::DonaMsgReviewer,
+            },
+            stats::{
+                query::{GetDonaStatsQueryHandler, GET_DONA_STATS_QUERY_TYPE},
+                service::DonaStatsCalculator,
+            },
+            update_reply::{
+                command::{UpdateDonaReplyCommandHandler, UPDATE_DONA_REPLY_COMMAND_TYPE},
+                service::DonaReplyUpdater,
+            },
+            validate_import::{
+                query::{ValidateDonaImportQueryHandler, VALIDATE_DONA_IMPORT_QUERY_TYPE},
+                service::DonaImportValidator,
+            },
+        },
+        domain::{
+            dona::DONA_MSG_MAX_LENGTH,
+            dona_msg_policy::{DonaMsgLinkPolicy, DonaMsgPolicy},
+            payment_gateway::PaymentGatewayRegistry,
+        },
+        infrastructure::{
+            paypal::{
+                http_paypal_notification_verifier::{
+                    HttpPaypalNotificationVerifier, PAYPAL_IPN_VERIFY_URL,
+                },
+                paypal_payment_gateway::PaypalPaymentGateway,
+            },
+            persistence::{sea_dona_reply_repo::SeaDonaReplyRepo, sea_dona_repo::SeaDonaRepo},
+        },
+    },
+    exchange_rate::{
+        application::{
+            convert::{
+                query::{ConvertAmountQueryHandler, CONVERT_AMOUNT_QUERY_TYPE},
+                service::CurrencyConverter,
+            },
+            import::{
+                command::{ImportExchangeRatesCommandHandler, IMPORT_EXCHANGE_RATES_COMMAND_TYPE},
+                service::ExchangeRatesImporter,
+            },
+            upsert::{
+                command::{UpsertExchangeRateCommandHandler, UPSERT_EXCHANGE_RATE_COMMAND_TYPE},
+                service::ExchangeRateUpserter,
+            },
+        },
+        infrastructure::persistence::sea_exchange_rate_repo::SeaExchangeRateRepo,
+    },
+    export::{
+        application::{
+            download::{
+                query::{DownloadDonaExportQueryHandler, DOWNLOAD_DONA_EXPORT_QUERY_TYPE},
+                service::DonaExportDownloader,
+            },
+            find_by_user::{
+                query::{FindDonaExportsQueryHandler, FIND_DONA_EXPORTS_QUERY_TYPE},
+                service::DonaExportsFinder,
+            },
+            process_pending::{
+                command::{
+                    ProcessPendingDonaExportsCommandHandler,
+                    PROCESS_PENDING_DONA_EXPORTS_COMMAND_TYPE,
+                },
+                service::{DonaExportsProcessor, DONA_EXPORT_PAGE_SIZE},
+            },
+            request::{
+                command::{RequestDonaExportCommandHandler, REQUEST_DONA_EXPORT_COMMAND_TYPE},
+                service::DonaExportRequester,
+            },
+        },
+        infrastructure::{
+            persistence::sea_dona_export_repo::SeaDonaExportRepo,
+            writing::{
+                csv_dona_export_writer::CsvDonaExportWriter,
+                xlsx_dona_export_writer::XlsxDonaExportWriter,
+            },
+        },
+    },
+    fee::{
+        application::{
+            apply_on_dona_confirmed::{
+                event_handler::ApplyFeeOnDonaConfirmed, service::DonaFeeApplier,
+            },
+            calculate::service::FeeCalculator,
+            create::{
+                command::{CreateFeeRuleCommandHandler, CREATE_FEE_RULE_COMMAND_TYPE},
+                service::FeeRuleCreator,
+            },
+            find_by_method::{
+                query::{FindFeeRulesByMethodQueryHandler, FIND_FEE_RULES_BY_METHOD_QUERY_TYPE},
+                service::FeeRulesByMethodFinder,
+            },
+        },
+        infrastructure::persistence::sea_fee_rule_repo::SeaFeeRuleRepo,
+    },
+    notification::{
+        application::{
+            find_by_user::{
+                query::{FindNotificationsQueryHandler, FIND_NOTIFICATIONS_QUERY_TYPE},
+                service::NotificationsFinder,
+            },
+            notify_on_dona_export_completed::{
+                event_handler::NotifyOnDonaExportCompleted, service::DonaExportCompletedNotifier,
+            },
+        },
+        infrastructure::persistence::sea_notification_repo::SeaNotificationRepo,
+    },
+    pledge::{
+        application::{
+            cancel::{
+                command::{CancelPledgeCommandHandler, CANCEL_PLEDGE_COMMAND_TYPE},
+                service::PledgeCanceller,
+            },
+            charge_due::{
+                command::{ChargeDuePledgesCommandHandler, CHARGE_DUE_PLEDGES_COMMAND_TYPE},
+                service::DuePledgesCharger,
+            },
+            create::{
+                command::{CreatePledgeCommandHandler, CREATE_PLEDGE_COMMAND_TYPE},
+                service::PledgeCreator,
+            },
+            find_by_criteria::{
+                query::{FindPledgesByCriteriaQueryHandler, FIND_PLEDGES_BY_CRITERIA_QUERY_TYPE},
+                service::PledgesFinder,
+            },
+            pause::{
+                command::{PausePledgeCommandHandler, PAUSE_PLEDGE_COMMAND_TYPE},
+                service::PledgePauser,
+            },
+            resume::{
+                command::{ResumePledgeCommandHandler, RESUME_PLEDGE_COMMAND_TYPE},
+                service::PledgeResumer,
+            },
+        },
+        infrastructure::persistence::sea_pledge_repo::SeaPledgeRepo,
+    },
     posts::infrastructure::persistence::sea_post_repo::SeaPostRepo,
+    receipt::{
+        application::{
+            download::{
+                query::{DownloadReceiptQueryHandler, DOWNLOAD_RECEIPT_QUERY_TYPE},
+                service::ReceiptDownloader,
+            },
+            generate::{event_handler::GenerateReceiptOnDonaConfirmed, service::ReceiptGenerator},
+        },
+        infrastructure::{
+            persistence::sea_receipt_repo::SeaReceiptRepo,
+            rendering::{
+                html_receipt_renderer::HtmlReceiptRenderer,
+                pdf_receipt_renderer::PdfReceiptRenderer,
+            },
+        },
+    },
+    recipient::{
+        application::{
+            find::{
+                query::{FindRecipientSettingsQueryHandler, FIND_RECIPIENT_SETTINGS_QUERY_TYPE},
+                service::RecipientSettingsFinder,
+            },
+            update::{
+                command::{
+                    UpdateRecipientSettingsCommandHandler, UPDATE_RECIPIENT_SETTINGS_COMMAND_TYPE,
+                },
+                service::RecipientSettingsUpdater,
+            },
+        },
+        infrastructure::persistence::sea_recipient_settings_repo::SeaRecipientSettingsRepo,
+    },
+    supporter::{
+        application::{
+            top::{
+                query::{GetTopSupportersQueryHandler, GET_TOP_SUPPORTERS_QUERY_TYPE},
+                service::TopSupportersFinder,
+            },
+            update_preferences::{
+                command::{
+                    UpdateSupporterPreferencesCommandHandler,
+                    UPDATE_SUPPORTER_PREFERENCES_COMMAND_TYPE,
+                },
+                service::SupporterPreferencesUpdater,
+            },
+        },
+        infrastructure::{
+            backoffice_supporter_profile_repo::BackofficeSupporterProfileRepo,
+            persistence::sea_supporter_repo::SeaSupporterRepo,
+        },
+    },
     user_payment_method::{
         application::{
             create::{
@@ -28,10 +346,21 @@ use dona_context::{
                 },
                 service::UserPaymentMethodsFinder,
             },
+            get_qr_code::{
+                query::{
+                    GetUserPaymentMethodQrCodeQueryHandler,
+                    GET_USER_PAYMENT_METHOD_QR_CODE_QUERY_TYPE,
+                },
+                service::UserPaymentMethodQrCodeGetter,
+            },
             get_user_payment_methods::{
                 query::{GetUserPaymentMethodsQueryHandler, GET_USER_PAYMENT_METHODS_QUERY_TYPE},
                 service::GetPaymentMethodsByUser,
             },
+            remove_qr_codes_on_instructions_updated::{
+                event_handler::RemoveQrCodesOnInstructionsUpdated,
+                service::UserPaymentMethodQrCodesRemover,
+            },
             update_instructions::{
                 command::{
                     UpdateUserPaymentMethodInstructionsCommandHandler,
@@ -40,17 +369,67 @@ use dona_context::{
                 service::UserPaymentMethodInstructionsUpdater,
             },
         },
-        infrastructure::persistence::sea_user_payment_method_repo::SeaUserPaymentMethodRepo,
+        infrastructure::{
+            persistence::sea_user_payment_method_repo::SeaUserPaymentMethodRepo,
+            rendering::{
+                png_qr_code_renderer::PngQrCodeRenderer, svg_qr_code_renderer::SvgQrCodeRenderer,
+            },
+        },
     },
 };
 use sea_orm::DatabaseConnection;
 use shared::{
-    domain::bus::{command::CommandBus, query::QueryBus},
-    infrastructure::bus::{
-        command::InMemoryCommandBus, event::InMemoryEventBus, query::InMemoryQueryBus,
+    domain::bus::{command::CommandBus, event::EventBus, query::QueryBus},
+    infrastructure::{
+        bus::{
+            command::InMemoryCommandBus,
+            event::{InMemoryEventBus, WeakEventBus},
+            query::InMemoryQueryBus,
+        },
+        storage::DiskFileStorageRepository,
     },
 };
 
+/// Storage for documents that must not be reachable through `/media`, which serves
+/// `storage_files` to anyone.
+fn private_file_storage() -> DiskFileStorageRepository {
+    let pwd = std::env::current_dir().unwrap();
+    DiskFileStorageRepository::new(format!("{}/storage_private", pwd.display()), false)
+}
+
+/// Reads `PAYPAL_IPN_VERIFY_URL`, so the sandbox or a local fake can stand in for PayPal.
+fn paypal_notification_verifier() -> HttpPaypalNotificationVerifier {
+    HttpPaypalNotificationVerifier::new(
+        std::env::var("PAYPAL_IPN_VERIFY_URL").unwrap_or(PAYPAL_IPN_VERIFY_URL.to_string()),
+    )
+}
+
+/// Reads `DONA_MSG_MAX_LENGTH`, `DONA_MSG_BANNED_WORDS` (comma separated) and
+/// `DONA_MSG_LINK_POLICY` (`ALLOW`, `REVIEW` or `REJECT`). Unset or invalid values keep the
+/// defaults: the full length, no banned words and links sent to review.
+fn dona_msg_policy() -> DonaMsgPolicy {
+    let max_length = std::env::var("DONA_MSG_MAX_LENGTH")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(DONA_MSG_MAX_LENGTH);
+    let banned_words = std::env::var("DONA_MSG_BANNED_WORDS")
+        .map(|value| value.split(',').map(str::to_string).collect())
+        .unwrap_or_default();
+    let link_policy = std::env::var("DONA_MSG_LINK_POLICY")
+        .ok()
+        .and_then(|value| DonaMsgLinkPolicy::new(value).ok())
+        .unwrap_or(DonaMsgLinkPolicy::Review);
+
+    DonaMsgPolicy::new(max_length, banned_words, link_policy)
+}
+
+/// Every method settled by a provider needs its gateway here, the others stay manual.
+fn payment_gateways() -> PaymentGatewayRegistry {
+    PaymentGatewayRegistry::new(vec![Arc::new(PaypalPaymentGateway::new(Arc::new(
+        paypal_notification_verifier(),
+    )))])
+}
+
 /// This function is used to register all the dependencies of the dona app.
 ///
 /// The event bus must be injected as an Arc because it is shared between the services
@@ -123,9 +502,608 @@ pub fn dona_app_di(
         Arc::new(get_payment_methods_by_user_query_handler),
     );
 
+    // QR codes only carry what the method already shows to donors, so they are public media.
+    let get_user_payment_method_qr_code = UserPaymentMethodQrCodeGetter::new(
+        user_payment_method_repository.clone(),
+        Arc::new(DiskFileStorageRepository::default()),
+        vec![
+            Arc::new(PngQrCodeRenderer::new()),
+            Arc::new(SvgQrCodeRenderer::new()),
+        ],
+    );
+    let get_user_payment_method_qr_code_query_handler =
+        GetUserPaymentMethodQrCodeQueryHandler::new(get_user_payment_method_qr_code);
+
+    query_bus.register_handler(
+        GET_USER_PAYMENT_METHOD_QR_CODE_QUERY_TYPE,
+        Arc::new(get_user_payment_method_qr_code_query_handler),
+    );
+
+    // Exchange Rate
+    let exchange_rate_repository = Arc::new(SeaExchangeRateRepo::new(db.clone()));
+
+    let upsert_exchange_rate =
+        ExchangeRateUpserter::new(exchange_rate_repository.clone(), event_bus.clone());
+    let upsert_exchange_rate_command_handler =
+        UpsertExchangeRateCommandHandler::new(upsert_exchange_rate.clone());
+
+    let import_exchange_rates = ExchangeRatesImporter::new(upsert_exchange_rate);
+    let import_exchange_rates_command_handler =
+        ImportExchangeRatesCommandHandler::new(import_exchange_rates);
+
+    command_bus.register_handler(
+        UPSERT_EXCHANGE_RATE_COMMAND_TYPE,
+        Arc::new(upsert_exchange_rate_command_handler),
+    );
+    command_bus.register_handler(
+        IMPORT_EXCHANGE_RATES_COMMAND_TYPE,
+        Arc::new(import_exchange_rates_command_handler),
+    );
+
+    let currency_converter = CurrencyConverter::new(exchange_rate_repository.clone());
+    let convert_amount_query_handler = ConvertAmountQueryHandler::new(currency_converter.clone());
+
+    query_bus.register_handler(
+        CONVERT_AMOUNT_QUERY_TYPE,
+        Arc::new(convert_amount_query_handler),
+    );
+
     // Dona
-    let _dona_repository = Arc::new(SeaDonaRepo::new(db.clone()));
+    let dona_repository = Arc::new(SeaDonaRepo::new(db.clone()));
+    let dona_reply_repository = Arc::new(SeaDonaReplyRepo::new(db.clone()));
+    let campaign_repository = Arc::new(SeaCampaignRepo::new(db.clone()));
+    let recipient_settings_repository = Arc::new(SeaRecipientSettingsRepo::new(db.clone()));
+
+    let create_dona = DonaCreator::new(
+        dona_repository.clone(),
+        user_payment_method_repository.clone(),
+        campaign_repository.clone(),
+        recipient_settings_repository.clone(),
+        currency_converter.clone(),
+        dona_msg_policy(),
+        event_bus.clone(),
+    );
+    let create_dona_command_handler = CreateDonaCommandHandler::new(create_dona);
+
+    let confirm_dona = DonaConfirmer::new(dona_repository.clone(), event_bus.clone());
+    let confirm_dona_command_handler = ConfirmDonaCommandHandler::new(confirm_dona);
+
+    let reject_dona = DonaRejecter::new(dona_repository.clone(), event_bus.clone());
+    let reject_dona_command_handler = RejectDonaCommandHandler::new(reject_dona);
+
+    let review_dona_msg = DonaMsgReviewer::new(dona_repository.clone(), event_bus.clone());
+    let review_dona_msg_command_handler = ReviewDonaMsgCommandHandler::new(review_dona_msg);
+
+    let cancel_dona = DonaCanceller::new(dona_repository.clone(), event_bus.clone());
+    let cancel_dona_command_handler = CancelDonaCommandHandler::new(cancel_dona);
+
+    let refund_dona = DonaRefunder::new(dona_repository.clone(), event_bus.clone());
+    let refund_dona_command_handler = RefundDonaCommandHandler::new(refund_dona);
+
+    let expire_dona = DonaExpirer::new(dona_repository.clone(), event_bus.clone());
+    let expire_dona_command_handler = ExpireDonaCommandHandler::new(expire_dona);
+
+    let expire_stale_donas = StaleDonasExpirer::new(dona_repository.clone(), event_bus.clone());
+    let expire_stale_donas_command_handler =
+        ExpireStaleDonasCommandHandler::new(expire_stale_donas);
+
+    let import_donas = DonasImporter::new(
+        dona_repository.clone(),
+        recipient_settings_repository.clone(),
+        dona_msg_policy(),
+        event_bus.clone(),
+    );
+    let import_donas_command_handler = ImportDonasCommandHandler::new(import_donas);
+
+    let delete_dona = DonaDeleter::new(dona_repository.clone(), event_bus.clone());
+    let delete_dona_command_handler = DeleteDonaCommandHandler::new(delete_dona);
+
+    let payment_gateways = payment_gateways();
+
+    let confirm_dona_payment = DonaPaymentConfirmer::new(
+        dona_repository.clone(),
+        user_payment_method_repository.clone(),
+        payment_gateways.clone(),
+        event_bus.clone(),
+    );
+    let confirm_dona_payment_command_handler =
+        ConfirmDonaPaymentCommandHandler::new(confirm_dona_payment.clone());
+
+    let refresh_dona_payment =
+        DonaPaymentRefresher::new(payment_gateways.clone(), confirm_dona_payment);
+    let refresh_dona_payment_command_handler =
+        RefreshDonaPaymentCommandHandler::new(refresh_dona_payment);
+
+    let create_dona_reply = DonaReplyCreator::new(
+        dona_repository.clone(),
+        dona_reply_repository.clone(),
+        event_bus.clone(),
+    );
+    let create_dona_reply_command_handler = CreateDonaReplyCommandHandler::new(create_dona_reply);
+
+    let update_dona_reply = DonaReplyUpdater::new(dona_reply_repository.clone(), event_bus.clone());
+    let update_dona_reply_command_handler = UpdateDonaReplyCommandHandler::new(update_dona_reply);
+
+    let delete_dona_reply = DonaReplyDeleter::new(dona_reply_repository.clone(), event_bus.clone());
+    let delete_dona_reply_command_handler = DeleteDonaReplyCommandHandler::new(delete_dona_reply);
+
+    command_bus.register_handler(
+        CREATE_DONA_COMMAND_TYPE,
+        Arc::new(create_dona_command_handler),
+    );
+    command_bus.register_handler(
+        CONFIRM_DONA_COMMAND_TYPE,
+        Arc::new(confirm_dona_command_handler),
+    );
+    command_bus.register_handler(
+        REJECT_DONA_COMMAND_TYPE,
+        Arc::new(reject_dona_command_handler),
+    );
+    command_bus.register_handler(
+        REVIEW_DONA_MSG_COMMAND_TYPE,
+        Arc::new(review_dona_msg_command_handler),
+    );
+    command_bus.register_handler(
+        CANCEL_DONA_COMMAND_TYPE,
+        Arc::new(cancel_dona_command_handler),
+    );
+    command_bus.register_handler(
+        REFUND_DONA_COMMAND_TYPE,
+        Arc::new(refund_dona_command_handler),
+    );
+    command_bus.register_handler(
+        EXPIRE_DONA_COMMAND_TYPE,
+        Arc::new(expire_dona_command_handler),
+    );
+    command_bus.register_handler(
+        EXPIRE_STALE_DONAS_COMMAND_TYPE,
+        Arc::new(expire_stale_donas_command_handler),
+    );
+    command_bus.register_handler(
+        IMPORT_DONAS_COMMAND_TYPE,
+        Arc::new(import_donas_command_handler),
+    );
+    command_bus.register_handler(
+        DELETE_DONA_COMMAND_TYPE,
+        Arc::new(delete_dona_command_handler),
+    );
+    command_bus.register_handler(
+        CONFIRM_DONA_PAYMENT_COMMAND_TYPE,
+        Arc::new(confirm_dona_payment_command_handler),
+    );
+    command_bus.register_handler(
+        REFRESH_DONA_PAYMENT_COMMAND_TYPE,
+        Arc::new(refresh_dona_payment_command_handler),
+    );
+    command_bus.register_handler(
+        CREATE_DONA_REPLY_COMMAND_TYPE,
+        Arc::new(create_dona_reply_command_handler),
+    );
+    command_bus.register_handler(
+        UPDATE_DONA_REPLY_COMMAND_TYPE,
+        Arc::new(update_dona_reply_command_handler),
+    );
+    command_bus.register_handler(
+        DELETE_DONA_REPLY_COMMAND_TYPE,
+        Arc::new(delete_dona_reply_command_handler),
+    );
+
+    let find_dona = DonaFinder::new(dona_repository.clone());
+    let find_dona_query_handler = FindDonaQueryHandler::new(find_dona);
+
+    let find_donas = DonasFinder::new(dona_repository.clone(), dona_reply_repository.clone());
+    let find_donas_query_handler = FindDonasByCriteriaQueryHandler::new(find_donas);
+
+    let find_public_dona_replies = PublicDonaRepliesFinder::new(dona_reply_repository);
+    let find_public_dona_replies_query_handler =
+        FindPublicDonaRepliesQueryHandler::new(find_public_dona_replies);
+
+    let received_total =
+        ReceivedTotalCalculator::new(dona_repository.clone(), currency_converter.clone());
+    let received_total_query_handler = GetReceivedTotalQueryHandler::new(received_total);
+
+    let dona_stats = DonaStatsCalculator::new(dona_repository.clone());
+    let dona_stats_query_handler = GetDonaStatsQueryHandler::new(dona_stats);
+
+    let validate_dona_import = DonaImportValidator::new(dona_msg_policy());
+    let validate_dona_import_query_handler =
+        ValidateDonaImportQueryHandler::new(validate_dona_import);
+
+    let start_dona_checkout = DonaCheckoutStarter::new(dona_repository.clone(), payment_gateways);
+    let start_dona_checkout_query_handler = StartDonaCheckoutQueryHandler::new(start_dona_checkout);
+
+    query_bus.register_handler(FIND_DONA_QUERY_TYPE, Arc::new(find_dona_query_handler));
+    query_bus.register_handler(
+        FIND_DONAS_BY_CRITERIA_QUERY_TYPE,
+        Arc::new(find_donas_query_handler),
+    );
+    query_bus.register_handler(
+        FIND_PUBLIC_DONA_REPLIES_QUERY_TYPE,
+        Arc::new(find_public_dona_replies_query_handler),
+    );
+    query_bus.register_handler(
+        GET_RECEIVED_TOTAL_QUERY_TYPE,
+        Arc::new(received_total_query_handler),
+    );
+    query_bus.register_handler(
+        GET_DONA_STATS_QUERY_TYPE,
+        Arc::new(dona_stats_query_handler),
+    );
+    query_bus.register_handler(
+        VALIDATE_DONA_IMPORT_QUERY_TYPE,
+        Arc::new(validate_dona_import_query_handler),
+    );
+    query_bus.register_handler(
+        START_DONA_CHECKOUT_QUERY_TYPE,
+        Arc::new(start_dona_checkout_query_handler),
+    );
+
+    // Campaign
+    let create_campaign = CampaignCreator::new(campaign_repository.clone(), event_bus.clone());
+    let create_campaign_command_handler = CreateCampaignCommandHandler::new(create_campaign);
+
+    let update_campaign = CampaignUpdater::new(campaign_repository.clone(), event_bus.clone());
+    let update_campaign_command_handler = UpdateCampaignCommandHandler::new(update_campaign);
+
+    let close_campaign = CampaignCloser::new(campaign_repository.clone(), event_bus.clone());
+    let close_campaign_command_handler = CloseCampaignCommandHandler::new(close_campaign);
+
+    let delete_campaign = CampaignDeleter::new(
+        campaign_repository.clone(),
+        dona_repository.clone(),
+        event_bus.clone(),
+    );
+    let delete_campaign_command_handler = DeleteCampaignCommandHandler::new(delete_campaign);
+
+    command_bus.register_handler(
+        CREATE_CAMPAIGN_COMMAND_TYPE,
+        Arc::new(create_campaign_command_handler),
+    );
+    command_bus.register_handler(
+        UPDATE_CAMPAIGN_COMMAND_TYPE,
+        Arc::new(update_campaign_command_handler),
+    );
+    command_bus.register_handler(
+        CLOSE_CAMPAIGN_COMMAND_TYPE,
+        Arc::new(close_campaign_command_handler),
+    );
+    command_bus.register_handler(
+        DELETE_CAMPAIGN_COMMAND_TYPE,
+        Arc::new(delete_campaign_command_handler),
+    );
+
+    let find_campaign = CampaignFinder::new(campaign_repository.clone(), dona_repository.clone());
+    let find_campaign_query_handler = FindCampaignQueryHandler::new(find_campaign);
+
+    let find_campaigns = CampaignsFinder::new(campaign_repository.clone(), dona_repository.clone());
+    let find_campaigns_query_handler = FindCampaignsByCriteriaQueryHandler::new(find_campaigns);
+
+    query_bus.register_handler(
+        FIND_CAMPAIGN_QUERY_TYPE,
+        Arc::new(find_campaign_query_handler),
+    );
+    query_bus.register_handler(
+        FIND_CAMPAIGNS_BY_CRITERIA_QUERY_TYPE,
+        Arc::new(find_campaigns_query_handler),
+    );
+
+    // Pledge
+    let pledge_repository = Arc::new(SeaPledgeRepo::new(db.clone()));
+
+    let create_pledge = PledgeCreator::new(
+        pledge_repository.clone(),
+        user_payment_method_repository.clone(),
+        recipient_settings_repository.clone(),
+        currency_converter.clone(),
+        dona_msg_policy(),
+        event_bus.clone(),
+    );
+    let create_pledge_command_handler = CreatePledgeCommandHandler::new(create_pledge);
+
+    let pause_pledge = PledgePauser::new(pledge_repository.clone(), event_bus.clone());
+    let pause_pledge_command_handler = PausePledgeCommandHandler::new(pause_pledge);
+
+    let resume_pledge = PledgeResumer::new(pledge_repository.clone(), event_bus.clone());
+    let resume_pledge_command_handler = ResumePledgeCommandHandler::new(resume_pledge);
+
+    let cancel_pledge = PledgeCanceller::new(pledge_repository.clone(), event_bus.clone());
+    let cancel_pledge_command_handler = CancelPledgeCommandHandler::new(cancel_pledge);
+
+    let charge_due_pledges = DuePledgesCharger::new(
+        pledge_repository.clone(),
+        dona_repository.clone(),
+        recipient_settings_repository.clone(),
+        currency_converter.clone(),
+        dona_msg_policy(),
+        event_bus.clone(),
+    );
+    let charge_due_pledges_command_handler =
+        ChargeDuePledgesCommandHandler::new(charge_due_pledges);
+
+    command_bus.register_handler(
+        CREATE_PLEDGE_COMMAND_TYPE,
+        Arc::new(create_pledge_command_handler),
+    );
+    command_bus.register_handler(
+        PAUSE_PLEDGE_COMMAND_TYPE,
+        Arc::new(pause_pledge_command_handler),
+    );
+    command_bus.register_handler(
+        RESUME_PLEDGE_COMMAND_TYPE,
+        Arc::new(resume_pledge_command_handler),
+    );
+    command_bus.register_handler(
+        CANCEL_PLEDGE_COMMAND_TYPE,
+        Arc::new(cancel_pledge_command_handler),
+    );
+    command_bus.register_handler(
+        CHARGE_DUE_PLEDGES_COMMAND_TYPE,
+        Arc::new(charge_due_pledges_command_handler),
+    );
+
+    let find_pledges = PledgesFinder::new(pledge_repository.clone());
+    let find_pledges_query_handler = FindPledgesByCriteriaQueryHandler::new(find_pledges);
+
+    query_bus.register_handler(
+        FIND_PLEDGES_BY_CRITERIA_QUERY_TYPE,
+        Arc::new(find_pledges_query_handler),
+    );
+
+    // Receipt
+    let receipt_repository = Arc::new(SeaReceiptRepo::new(db.clone()));
+
+    let download_receipt =
+        ReceiptDownloader::new(receipt_repository, Arc::new(private_file_storage()));
+    let download_receipt_query_handler = DownloadReceiptQueryHandler::new(download_receipt);
+
+    query_bus.register_handler(
+        DOWNLOAD_RECEIPT_QUERY_TYPE,
+        Arc::new(download_receipt_query_handler),
+    );
+
+    // Supporter
+    let supporter_repository = Arc::new(SeaSupporterRepo::new(db.clone()));
+    let supporter_profile_repository = Arc::new(BackofficeSupporterProfileRepo::new(Arc::new(
+        SeaUserRepository::new(db.clone()),
+    )));
+
+    let update_supporter_preferences =
+        SupporterPreferencesUpdater::new(supporter_repository.clone(), event_bus.clone());
+    let update_supporter_preferences_command_handler =
+        UpdateSupporterPreferencesCommandHandler::new(update_supporter_preferences);
+
+    command_bus.register_handler(
+        UPDATE_SUPPORTER_PREFERENCES_COMMAND_TYPE,
+        Arc::new(update_supporter_preferences_command_handler),
+    );
+
+    let top_supporters =
+        TopSupportersFinder::new(supporter_repository, supporter_profile_repository.clone());
+    let top_supporters_query_handler = GetTopSupportersQueryHandler::new(top_supporters);
+
+    query_bus.register_handler(
+        GET_TOP_SUPPORTERS_QUERY_TYPE,
+        Arc::new(top_supporters_query_handler),
+    );
+
+    // Recipient
+    let update_recipient_settings =
+        RecipientSettingsUpdater::new(recipient_settings_repository.clone(), event_bus.clone());
+    let update_recipient_settings_command_handler =
+        UpdateRecipientSettingsCommandHandler::new(update_recipient_settings);
+
+    command_bus.register_handler(
+        UPDATE_RECIPIENT_SETTINGS_COMMAND_TYPE,
+        Arc::new(update_recipient_settings_command_handler),
+    );
+
+    let find_recipient_settings = RecipientSettingsFinder::new(recipient_settings_repository);
+    let find_recipient_settings_query_handler =
+        FindRecipientSettingsQueryHandler::new(find_recipient_settings);
+
+    query_bus.register_handler(
+        FIND_RECIPIENT_SETTINGS_QUERY_TYPE,
+        Arc::new(find_recipient_settings_query_handler),
+    );
+
+    // Fee
+    let fee_rule_repository = Arc::new(SeaFeeRuleRepo::new(db.clone()));
+
+    let create_fee_rule = FeeRuleCreator::new(fee_rule_repository.clone(), event_bus.clone());
+    let create_fee_rule_command_handler = CreateFeeRuleCommandHandler::new(create_fee_rule);
+
+    command_bus.register_handler(
+        CREATE_FEE_RULE_COMMAND_TYPE,
+        Arc::new(create_fee_rule_command_handler),
+    );
+
+    let find_fee_rules = FeeRulesByMethodFinder::new(fee_rule_repository);
+    let find_fee_rules_query_handler = FindFeeRulesByMethodQueryHandler::new(find_fee_rules);
+
+    query_bus.register_handler(
+        FIND_FEE_RULES_BY_METHOD_QUERY_TYPE,
+        Arc::new(find_fee_rules_query_handler),
+    );
+
+    // Export
+    let dona_export_repository = Arc::new(SeaDonaExportRepo::new(db.clone()));
+    let dona_export_storage = Arc::new(private_file_storage());
+
+    let request_dona_export =
+        DonaExportRequester::new(dona_export_repository.clone(), event_bus.clone());
+    let request_dona_export_command_handler =
+        RequestDonaExportCommandHandler::new(request_dona_export);
+
+    let process_dona_exports = DonaExportsProcessor::new(
+        dona_export_repository.clone(),
+        dona_repository.clone(),
+        supporter_profile_repository,
+        dona_export_storage.clone(),
+        vec![
+            Arc::new(CsvDonaExportWriter::new()),
+            Arc::new(XlsxDonaExportWriter::new()),
+        ],
+        event_bus.clone(),
+        DONA_EXPORT_PAGE_SIZE,
+    );
+    let process_dona_exports_command_handler =
+        ProcessPendingDonaExportsCommandHandler::new(process_dona_exports);
+
+    command_bus.register_handler(
+        REQUEST_DONA_EXPORT_COMMAND_TYPE,
+        Arc::new(request_dona_export_command_handler),
+    );
+    command_bus.register_handler(
+        PROCESS_PENDING_DONA_EXPORTS_COMMAND_TYPE,
+        Arc::new(process_dona_exports_command_handler),
+    );
+
+    let find_dona_exports = DonaExportsFinder::new(dona_export_repository.clone());
+    let find_dona_exports_query_handler = FindDonaExportsQueryHandler::new(find_dona_exports);
+
+    let download_dona_export =
+        DonaExportDownloader::new(dona_export_repository, dona_export_storage);
+    let download_dona_export_query_handler =
+        DownloadDonaExportQueryHandler::new(download_dona_export);
+
+    query_bus.register_handler(
+        FIND_DONA_EXPORTS_QUERY_TYPE,
+        Arc::new(find_dona_exports_query_handler),
+    );
+    query_bus.register_handler(
+        DOWNLOAD_DONA_EXPORT_QUERY_TYPE,
+        Arc::new(download_dona_export_query_handler),
+    );
+
+    // Notification
+    let find_notifications =
+        NotificationsFinder::new(Arc::new(SeaNotificationRepo::new(db.clone())));
+    let find_notifications_query_handler = FindNotificationsQueryHandler::new(find_notifications);
+
+    query_bus.register_handler(
+        FIND_NOTIFICATIONS_QUERY_TYPE,
+        Arc::new(find_notifications_query_handler),
+    );
+
+    // Attachment
+    let dona_attachment_repository = Arc::new(SeaDonaAttachmentRepo::new(db.clone()));
+    let dona_attachment_storage = Arc::new(private_file_storage());
+
+    let add_dona_attachment = DonaAttachmentAdder::new(
+        dona_attachment_repository.clone(),
+        dona_repository.clone(),
+        dona_attachment_storage.clone(),
+        event_bus.clone(),
+    );
+    let add_dona_attachment_command_handler =
+        AddDonaAttachmentCommandHandler::new(add_dona_attachment);
+
+    command_bus.register_handler(
+        ADD_DONA_ATTACHMENT_COMMAND_TYPE,
+        Arc::new(add_dona_attachment_command_handler),
+    );
+
+    let find_dona_attachments =
+        DonaAttachmentsFinder::new(dona_attachment_repository.clone(), dona_repository.clone());
+    let find_dona_attachments_query_handler =
+        FindDonaAttachmentsQueryHandler::new(find_dona_attachments);
+
+    let download_dona_attachment =
+        DonaAttachmentDownloader::new(dona_attachment_repository, dona_attachment_storage);
+    let download_dona_attachment_query_handler =
+        DownloadDonaAttachmentQueryHandler::new(download_dona_attachment);
+
+    query_bus.register_handler(
+        FIND_DONA_ATTACHMENTS_QUERY_TYPE,
+        Arc::new(find_dona_attachments_query_handler),
+    );
+    query_bus.register_handler(
+        DOWNLOAD_DONA_ATTACHMENT_QUERY_TYPE,
+        Arc::new(download_dona_attachment_query_handler),
+    );
 
     // Posts
     let _posts_repository = Arc::new(SeaPostRepo::new(db.clone()));
 }
+
+/// Builds the event bus of the dona app with its event handlers registered.
+///
+/// Handlers that publish events of their own do it through a [`WeakEventBus`] pointing at the
+/// returned bus, so their events reach every handler registered here, whatever the order.
+pub fn dona_app_event_handlers_di(db: &DatabaseConnection) -> Arc<InMemoryEventBus> {
+    Arc::new_cyclic(|bus| {
+        let mut event_bus = InMemoryEventBus::default();
+        let publisher = Arc::new(WeakEventBus::new(bus.clone()));
+
+        // Campaign
+        let campaign_repository = Arc::new(SeaCampaignRepo::new(db.clone()));
+        let dona_repository = Arc::new(SeaDonaRepo::new(db.clone()));
+
+        let check_campaign_goal = CampaignGoalChecker::new(
+            campaign_repository,
+            dona_repository.clone(),
+            publisher.clone(),
+        );
+        event_bus.register_handler(Arc::new(CheckCampaignGoalOnDonaConfirmed::new(
+            check_campaign_goal,
+        )));
+
+        // Receipt
+        let receipt_repository = Arc::new(SeaReceiptRepo::new(db.clone()));
+
+        let generate_receipt = ReceiptGenerator::new(
+            receipt_repository,
+            dona_repository.clone(),
+            Arc::new(private_file_storage()),
+            vec![
+                Arc::new(HtmlReceiptRenderer::new()),
+                Arc::new(PdfReceiptRenderer::new()),
+            ],
+            publisher.clone(),
+        );
+        event_bus.register_handler(Arc::new(GenerateReceiptOnDonaConfirmed::new(
+            generate_receipt,
+        )));
+
+        // Fee
+        let apply_fee = DonaFeeApplier::new(
+            dona_repository,
+            FeeCalculator::new(
+                Arc::new(SeaFeeRuleRepo::new(db.clone())),
+                CurrencyConverter::new(Arc::new(SeaExchangeRateRepo::new(db.clone()))),
+            ),
+        );
+        event_bus.register_handler(Arc::new(ApplyFeeOnDonaConfirmed::new(apply_fee)));
+
+        // Attachment
+        let remove_dona_attachments = DonaAttachmentsRemover::new(
+            Arc::new(SeaDonaAttachmentRepo::new(db.clone())),
+            Arc::new(private_file_storage()),
+        );
+        event_bus.register_handler(Arc::new(RemoveDonaAttachmentsOnDonaDeleted::new(
+            remove_dona_attachments,
+        )));
+
+        // Reply
+        let remove_dona_reply = DonaReplyRemover::new(Arc::new(SeaDonaReplyRepo::new(db.clone())));
+        event_bus.register_handler(Arc::new(DeleteDonaReplyOnDonaDeleted::new(
+            remove_dona_reply,
+        )));
+
+        // Notification
+        let notify_dona_export_completed =
+            DonaExportCompletedNotifier::new(Arc::new(SeaNotificationRepo::new(db.clone())));
+        event_bus.register_handler(Arc::new(NotifyOnDonaExportCompleted::new(
+            notify_dona_export_completed,
+        )));
+
+        // User Payment Method
+        let remove_qr_codes =
+            UserPaymentMethodQrCodesRemover::new(Arc::new(DiskFileStorageRepository::default()));
+        event_bus.register_handler(Arc::new(RemoveQrCodesOnInstructionsUpdated::new(
+            remove_qr_codes,
+        )));
+
+        event_bus
+    })
+}
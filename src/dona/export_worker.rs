@@ -0,0 +1,119 @@
+use std::time::Duration;
+
+use dona_context::export::application::process_pending::command::ProcessPendingDonaExportsCommand;
+use redis::Client as RedisClient;
+use sea_orm::DatabaseConnection;
+use shared::{
+    domain::{bus::command::CommandBus, utils::new_uuid},
+    infrastructure::bus::{command::InMemoryCommandBus, query::InMemoryQueryBus},
+};
+use tokio::task::JoinHandle;
+
+use super::{
+    di::{dona_app_di, dona_app_event_handlers_di},
+    sweeper::{acquire_lock, release_lock},
+};
+
+const EXPORT_WORKER_LOCK_KEY: &str = "lock:dona_export_worker";
+const DEFAULT_EXPORT_INTERVAL_SECONDS: u64 = 30;
+const DEFAULT_EXPORT_BATCH_SIZE: u64 = 5;
+const DEFAULT_EXPORT_LOCK_SECONDS: u64 = 60 * 10;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DonaExportWorkerConfig {
+    /// How often the worker looks for pending exports.
+    pub interval: Duration,
+    /// How many exports are built on a single run.
+    pub batch_size: u64,
+    /// How long a run may hold the lock. A batch of large exports easily outlasts the interval,
+    /// and another instance taking the lock meanwhile would build the same exports again.
+    pub lock_ttl: Duration,
+}
+
+impl Default for DonaExportWorkerConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(DEFAULT_EXPORT_INTERVAL_SECONDS),
+            batch_size: DEFAULT_EXPORT_BATCH_SIZE,
+            lock_ttl: Duration::from_secs(DEFAULT_EXPORT_LOCK_SECONDS),
+        }
+    }
+}
+
+impl DonaExportWorkerConfig {
+    /// Reads `DONA_EXPORT_INTERVAL_SECONDS`, `DONA_EXPORT_BATCH_SIZE` and
+    /// `DONA_EXPORT_LOCK_SECONDS`, falling back to the defaults when they are missing or invalid.
+    /// The lock is never shorter than the interval.
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        let number = |name: &str| {
+            std::env::var(name)
+                .ok()
+                .and_then(|value| value.parse::<u64>().ok())
+                .filter(|value| *value > 0)
+        };
+
+        Self {
+            interval: number("DONA_EXPORT_INTERVAL_SECONDS")
+                .map(Duration::from_secs)
+                .unwrap_or(default.interval),
+            batch_size: number("DONA_EXPORT_BATCH_SIZE").unwrap_or(default.batch_size),
+            lock_ttl: number("DONA_EXPORT_LOCK_SECONDS")
+                .map(Duration::from_secs)
+                .unwrap_or(default.lock_ttl),
+        }
+    }
+}
+
+async fn process(
+    db: &DatabaseConnection,
+    redis: &RedisClient,
+    config: &DonaExportWorkerConfig,
+) -> Result<(), String> {
+    let token = new_uuid();
+    let ttl = config.lock_ttl.max(config.interval);
+    if !acquire_lock(redis, EXPORT_WORKER_LOCK_KEY, &token, ttl).await? {
+        return Ok(());
+    }
+
+    let mut command_bus = InMemoryCommandBus::default();
+    let mut query_bus = InMemoryQueryBus::default();
+    dona_app_di(
+        &mut command_bus,
+        &mut query_bus,
+        dona_app_event_handlers_di(db),
+        db,
+    );
+
+    let result = command_bus
+        .dispatch(Box::new(ProcessPendingDonaExportsCommand {
+            limit: config.batch_size,
+        }))
+        .await
+        .map_err(|e| e.to_string());
+
+    release_lock(redis, EXPORT_WORKER_LOCK_KEY, &token).await?;
+
+    result
+}
+
+/// Periodically builds the requested dona exports and stores them for download. Like the
+/// sweeper, it runs on every instance but only builds on the instance holding the Redis lock.
+/// Owners get a notification with the download url once their export is done.
+pub fn spawn_dona_export_worker(
+    db: DatabaseConnection,
+    redis: RedisClient,
+    config: DonaExportWorkerConfig,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(config.interval);
+
+        loop {
+            interval.tick().await;
+
+            if let Err(e) = process(&db, &redis, &config).await {
+                eprintln!("Failed to build dona exports: {}", e);
+            }
+        }
+    })
+}
@@ -0,0 +1,59 @@
+use async_graphql::{Context, Error, InputObject, Object, Result, Upload};
+use dona_context::attachment::application::add::command::AddDonaAttachmentCommand;
+use poem::session::Session;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+    gql_validators::{check_upload, is_authenticated_with_err, ATTACHMENT_TYPES},
+    CommandBusType,
+};
+
+#[derive(InputObject)]
+pub struct AddDonaAttachmentInput {
+    pub id: Uuid,
+    pub dona_id: Uuid,
+    pub file: Upload,
+    pub created_at: OffsetDateTime,
+}
+
+#[derive(Debug, Default)]
+pub struct AddDonaAttachmentMutation;
+
+#[Object]
+impl AddDonaAttachmentMutation {
+    /// Attaches a proof of payment, an image or a PDF, to a pending manual dona sent by the
+    /// session user.
+    async fn add_dona_attachment(
+        &self,
+        ctx: &Context<'_>,
+        input: AddDonaAttachmentInput,
+    ) -> Result<bool> {
+        let command_bus = ctx.data::<CommandBusType>()?;
+        let session = ctx.data::<Session>()?;
+        is_authenticated_with_err(session)?;
+
+        let user_id = session
+            .get::<String>("user_id")
+            .ok_or(Error::new("UNAUTHORIZED"))?;
+
+        let upload_value = input.file.value(ctx)?;
+        check_upload(Some(&upload_value), &ATTACHMENT_TYPES)?;
+
+        let command = AddDonaAttachmentCommand {
+            id: input.id.to_string(),
+            dona_id: input.dona_id.to_string(),
+            uploader_id: user_id,
+            filename: upload_value.filename.clone(),
+            content_type: upload_value.content_type.clone().unwrap_or_default(),
+            file: upload_value.content.try_clone()?,
+            created_at: input.created_at,
+        };
+        command_bus
+            .dispatch(Box::new(command))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,97 @@
+use async_graphql::{Context, Error, Object, Result};
+use dona_context::attachment::application::{
+    download::query::DownloadDonaAttachmentQuery,
+    find_by_dona::query::FindDonaAttachmentsQuery,
+    response::{DonaAttachmentFileResponse, DonaAttachmentsResponse},
+};
+use poem::session::Session;
+use uuid::Uuid;
+
+use crate::{
+    gql_validators::{check_admin, is_authenticated_with_err},
+    CommandBusType, QueryBusType,
+};
+
+use super::types::{DonaAttachment, DonaAttachmentFile};
+
+#[derive(Debug, Default)]
+pub struct DonaAttachmentsQuery;
+
+#[Object]
+impl DonaAttachmentsQuery {
+    /// Proofs of payment attached to a dona. Only its recipient or an admin may list them.
+    async fn dona_attachments(
+        &self,
+        ctx: &Context<'_>,
+        dona_id: Uuid,
+    ) -> Result<Vec<DonaAttachment>> {
+        let session = ctx.data::<Session>()?;
+        is_authenticated_with_err(session)?;
+
+        let user_id = session
+            .get::<String>("user_id")
+            .ok_or(Error::new("UNAUTHORIZED"))?;
+        let command_bus = ctx.data::<CommandBusType>()?;
+        let is_admin = check_admin(command_bus, session).await.is_ok();
+
+        let query_bus = ctx.data::<QueryBusType>()?;
+        let attachments = query_bus
+            .ask(Box::new(FindDonaAttachmentsQuery {
+                dona_id: dona_id.to_string(),
+                requester_id: user_id,
+                is_admin,
+            }))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+        let attachments: DonaAttachmentsResponse = attachments
+            .as_any()
+            .downcast_ref::<DonaAttachmentsResponse>()
+            .unwrap()
+            .clone();
+
+        Ok(attachments
+            .attachments
+            .into_iter()
+            .map(DonaAttachment::from)
+            .collect())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct DonaAttachmentFileQuery;
+
+#[Object]
+impl DonaAttachmentFileQuery {
+    /// Content of an attachment, for its recipient or an admin.
+    async fn dona_attachment_file(
+        &self,
+        ctx: &Context<'_>,
+        id: Uuid,
+    ) -> Result<DonaAttachmentFile> {
+        let session = ctx.data::<Session>()?;
+        is_authenticated_with_err(session)?;
+
+        let user_id = session
+            .get::<String>("user_id")
+            .ok_or(Error::new("UNAUTHORIZED"))?;
+        let command_bus = ctx.data::<CommandBusType>()?;
+        let is_admin = check_admin(command_bus, session).await.is_ok();
+
+        let query_bus = ctx.data::<QueryBusType>()?;
+        let file = query_bus
+            .ask(Box::new(DownloadDonaAttachmentQuery {
+                id: id.to_string(),
+                requester_id: user_id,
+                is_admin,
+            }))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+        let file: DonaAttachmentFileResponse = file
+            .as_any()
+            .downcast_ref::<DonaAttachmentFileResponse>()
+            .unwrap()
+            .clone();
+
+        Ok(file.into())
+    }
+}
@@ -0,0 +1,16 @@
+use async_graphql::MergedObject;
+
+use self::{
+    add_mutation::AddDonaAttachmentMutation,
+    attachments_query::{DonaAttachmentFileQuery, DonaAttachmentsQuery},
+};
+
+mod add_mutation;
+mod attachments_query;
+pub mod types;
+
+#[derive(MergedObject, Default)]
+pub struct AttachmentQuery(DonaAttachmentsQuery, DonaAttachmentFileQuery);
+
+#[derive(MergedObject, Default)]
+pub struct AttachmentMutation(AddDonaAttachmentMutation);
@@ -0,0 +1,48 @@
+use async_graphql::SimpleObject;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use dona_context::attachment::application::response::{
+    DonaAttachmentFileResponse, DonaAttachmentResponse,
+};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+#[derive(SimpleObject)]
+pub struct DonaAttachment {
+    pub id: Uuid,
+    pub dona_id: Uuid,
+    pub filename: String,
+    pub content_type: String,
+    pub size: u64,
+    pub created_at: OffsetDateTime,
+}
+
+impl From<DonaAttachmentResponse> for DonaAttachment {
+    fn from(value: DonaAttachmentResponse) -> Self {
+        Self {
+            id: Uuid::parse_str(&value.id).unwrap(),
+            dona_id: Uuid::parse_str(&value.dona_id).unwrap(),
+            filename: value.filename,
+            content_type: value.content_type,
+            size: value.size,
+            created_at: value.created_at,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct DonaAttachmentFile {
+    pub filename: String,
+    pub content_type: String,
+    /// The file encoded as standard base64.
+    pub content: String,
+}
+
+impl From<DonaAttachmentFileResponse> for DonaAttachmentFile {
+    fn from(value: DonaAttachmentFileResponse) -> Self {
+        Self {
+            filename: value.filename,
+            content_type: value.content_type,
+            content: STANDARD.encode(value.content),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use async_graphql::{Context, Error, Object, Result};
+use dona_context::campaign::application::{
+    find::query::FindCampaignQuery, response::CampaignResponse,
+};
+use uuid::Uuid;
+
+use crate::{dona::graphql::campaign::types::Campaign, QueryBusType};
+
+#[derive(Debug, Default)]
+pub struct CampaignGqlQuery;
+
+#[Object]
+impl CampaignGqlQuery {
+    /// Campaigns are public so donors can follow their progress before giving.
+    async fn campaign(&self, ctx: &Context<'_>, id: Uuid) -> Result<Campaign> {
+        let query_bus = ctx.data::<QueryBusType>()?;
+        let campaign = query_bus
+            .ask(Box::new(FindCampaignQuery { id: id.to_string() }))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+        let campaign: CampaignResponse = campaign
+            .as_any()
+            .downcast_ref::<CampaignResponse>()
+            .unwrap()
+            .clone();
+
+        Ok(campaign.into())
+    }
+}
@@ -0,0 +1,54 @@
+use async_graphql::{Context, Error, InputObject, Object, Result};
+use dona_context::campaign::application::create::command::CreateCampaignCommand;
+use poem::session::Session;
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{gql_validators::is_authenticated_with_err, CommandBusType};
+
+#[derive(InputObject)]
+pub struct CreateCampaignInput {
+    pub id: Uuid,
+    #[graphql(validator(chars_min_length = 1, chars_max_length = 150))]
+    pub title: String,
+    pub target: Decimal,
+    #[graphql(validator(chars_min_length = 3, chars_max_length = 3))]
+    pub currency: String,
+    pub deadline: OffsetDateTime,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(Debug, Default)]
+pub struct CreateCampaignMutation;
+
+#[Object]
+impl CreateCampaignMutation {
+    async fn create_campaign(&self, ctx: &Context<'_>, input: CreateCampaignInput) -> Result<bool> {
+        let command_bus = ctx.data::<CommandBusType>()?;
+        let session = ctx.data::<Session>()?;
+        is_authenticated_with_err(session)?;
+
+        let user_id = session
+            .get::<String>("user_id")
+            .ok_or(Error::new("UNAUTHORIZED"))?;
+
+        let command = CreateCampaignCommand {
+            id: input.id.to_string(),
+            user_id,
+            title: input.title,
+            target: input.target,
+            currency: input.currency,
+            deadline: input.deadline,
+            created_at: input.created_at,
+            updated_at: input.updated_at,
+        };
+        command_bus
+            .dispatch(Box::new(command))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+
+        Ok(true)
+    }
+}
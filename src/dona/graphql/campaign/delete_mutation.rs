@@ -0,0 +1,33 @@
+use async_graphql::{Context, Error, Object, Result};
+use dona_context::campaign::application::delete::command::DeleteCampaignCommand;
+use poem::session::Session;
+use uuid::Uuid;
+
+use crate::{gql_validators::is_authenticated_with_err, CommandBusType};
+
+#[derive(Debug, Default)]
+pub struct DeleteCampaignMutation;
+
+#[Object]
+impl DeleteCampaignMutation {
+    async fn delete_campaign(&self, ctx: &Context<'_>, id: Uuid) -> Result<bool> {
+        let command_bus = ctx.data::<CommandBusType>()?;
+        let session = ctx.data::<Session>()?;
+        is_authenticated_with_err(session)?;
+
+        let user_id = session
+            .get::<String>("user_id")
+            .ok_or(Error::new("UNAUTHORIZED"))?;
+
+        let command = DeleteCampaignCommand {
+            id: id.to_string(),
+            user_id,
+        };
+        command_bus
+            .dispatch(Box::new(command))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,26 @@
+use async_graphql::MergedObject;
+
+use self::{
+    campaign_query::CampaignGqlQuery, close_mutation::CloseCampaignMutation,
+    create_mutation::CreateCampaignMutation, delete_mutation::DeleteCampaignMutation,
+    my_campaigns_query::MyCampaignsQuery, update_mutation::UpdateCampaignMutation,
+};
+
+mod campaign_query;
+mod close_mutation;
+mod create_mutation;
+mod delete_mutation;
+mod my_campaigns_query;
+pub mod types;
+mod update_mutation;
+
+#[derive(MergedObject, Default)]
+pub struct CampaignQuery(CampaignGqlQuery, MyCampaignsQuery);
+
+#[derive(MergedObject, Default)]
+pub struct CampaignMutation(
+    CreateCampaignMutation,
+    UpdateCampaignMutation,
+    CloseCampaignMutation,
+    DeleteCampaignMutation,
+);
@@ -0,0 +1,57 @@
+use async_graphql::{Context, Error, Object, Result};
+use dona_context::campaign::application::{
+    find_by_criteria::query::FindCampaignsByCriteriaQuery, response::CampaignsResponse,
+};
+use poem::session::Session;
+use shared::{
+    domain::criteria::{
+        filter::{Filter, FilterField, FilterOperator, FilterValue},
+        Criteria,
+    },
+    infrastructure::criteria::async_graphql::CriteriaGql,
+};
+
+use crate::{
+    dona::graphql::campaign::types::{into_campaign_connection, CampaignConnection},
+    gql_validators::is_authenticated_with_err,
+    QueryBusType,
+};
+
+#[derive(Debug, Default)]
+pub struct MyCampaignsQuery;
+
+#[Object]
+impl MyCampaignsQuery {
+    async fn my_campaigns(
+        &self,
+        ctx: &Context<'_>,
+        criteria: CriteriaGql,
+    ) -> Result<CampaignConnection> {
+        let session = ctx.data::<Session>()?;
+        is_authenticated_with_err(session)?;
+
+        let user_id = session
+            .get::<String>("user_id")
+            .ok_or(Error::new("UNAUTHORIZED"))?;
+
+        let criteria: Criteria = criteria.try_into()?;
+        let criteria = criteria.with_filter(Filter::new(
+            FilterField::try_from("user_id".to_string())?,
+            FilterOperator::Equal,
+            FilterValue::try_from(user_id)?,
+        ));
+
+        let query_bus = ctx.data::<QueryBusType>()?;
+        let campaigns = query_bus
+            .ask(Box::new(FindCampaignsByCriteriaQuery { criteria }))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+        let campaigns: CampaignsResponse = campaigns
+            .as_any()
+            .downcast_ref::<CampaignsResponse>()
+            .unwrap()
+            .clone();
+
+        Ok(into_campaign_connection(campaigns))
+    }
+}
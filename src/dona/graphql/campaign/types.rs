@@ -0,0 +1,54 @@
+use async_graphql::{connection::Connection, connection::Edge, SimpleObject};
+use dona_context::campaign::application::response::{CampaignResponse, CampaignsResponse};
+use rust_decimal::Decimal;
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+#[derive(SimpleObject)]
+pub struct Campaign {
+    pub id: String,
+    pub user_id: String,
+    pub title: String,
+    pub target: Decimal,
+    pub currency: String,
+    pub deadline: OffsetDateTime,
+    pub status: String,
+    /// Sum of the confirmed donas made to the campaign, in the campaign currency.
+    pub raised: Decimal,
+    pub dona_count: u64,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl From<CampaignResponse> for Campaign {
+    fn from(value: CampaignResponse) -> Self {
+        Self {
+            id: value.id,
+            user_id: value.user_id,
+            title: value.title,
+            target: value.target,
+            currency: value.currency,
+            deadline: value.deadline,
+            status: value.status,
+            raised: value.raised,
+            dona_count: value.dona_count,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+pub type CampaignConnection = Connection<String, Campaign>;
+
+pub fn into_campaign_connection(value: CampaignsResponse) -> CampaignConnection {
+    let mut connection = Connection::new(value.has_previous_page, value.has_next_page);
+    connection
+        .edges
+        .extend(value.campaigns.into_iter().map(|campaign| {
+            Edge::new(
+                campaign.created_at.format(&Rfc3339).unwrap_or_default(),
+                campaign.into(),
+            )
+        }));
+
+    connection
+}
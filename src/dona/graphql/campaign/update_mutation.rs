@@ -0,0 +1,49 @@
+use async_graphql::{Context, Error, InputObject, Object, Result};
+use dona_context::campaign::application::update::command::UpdateCampaignCommand;
+use poem::session::Session;
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{gql_validators::is_authenticated_with_err, CommandBusType};
+
+#[derive(InputObject)]
+pub struct UpdateCampaignInput {
+    pub id: Uuid,
+    #[graphql(validator(chars_min_length = 1, chars_max_length = 150))]
+    pub title: String,
+    pub target: Decimal,
+    pub deadline: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(Debug, Default)]
+pub struct UpdateCampaignMutation;
+
+#[Object]
+impl UpdateCampaignMutation {
+    async fn update_campaign(&self, ctx: &Context<'_>, input: UpdateCampaignInput) -> Result<bool> {
+        let command_bus = ctx.data::<CommandBusType>()?;
+        let session = ctx.data::<Session>()?;
+        is_authenticated_with_err(session)?;
+
+        let user_id = session
+            .get::<String>("user_id")
+            .ok_or(Error::new("UNAUTHORIZED"))?;
+
+        let command = UpdateCampaignCommand {
+            id: input.id.to_string(),
+            user_id,
+            title: input.title,
+            target: input.target,
+            deadline: input.deadline,
+            updated_at: input.updated_at,
+        };
+        command_bus
+            .dispatch(Box::new(command))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+
+        Ok(true)
+    }
+}
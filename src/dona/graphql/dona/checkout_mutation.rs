@@ -0,0 +1,43 @@
+use async_graphql::{Context, Error, Object, Result};
+use dona_context::dona::application::{
+    checkout::query::StartDonaCheckoutQuery, response::DonaCheckoutResponse,
+};
+use poem::session::Session;
+use uuid::Uuid;
+
+use crate::{gql_validators::is_authenticated, QueryBusType};
+
+use super::types::DonaCheckout;
+
+#[derive(Debug, Default)]
+pub struct StartDonaCheckoutMutation;
+
+#[Object]
+impl StartDonaCheckoutMutation {
+    /// Starts paying a pending dona with the provider of its method. Donas sent from an account
+    /// can only be paid by their sender, guest donas by whoever holds their id.
+    async fn start_dona_checkout(&self, ctx: &Context<'_>, dona_id: Uuid) -> Result<DonaCheckout> {
+        let session = ctx.data::<Session>()?;
+        let requester_id = if is_authenticated(session) {
+            session.get::<String>("user_id")
+        } else {
+            None
+        };
+
+        let query_bus = ctx.data::<QueryBusType>()?;
+        let checkout = query_bus
+            .ask(Box::new(StartDonaCheckoutQuery {
+                dona_id: dona_id.to_string(),
+                requester_id,
+            }))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+        let checkout: DonaCheckoutResponse = checkout
+            .as_any()
+            .downcast_ref::<DonaCheckoutResponse>()
+            .unwrap()
+            .clone();
+
+        Ok(checkout.into())
+    }
+}
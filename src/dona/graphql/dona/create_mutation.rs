@@ -0,0 +1,89 @@
+use async_graphql::{Context, Error, InputObject, Object, Result};
+use dona_context::dona::application::create::command::CreateDonaCommand;
+use poem::session::Session;
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{gql_validators::is_authenticated, CommandBusType};
+
+/// Identifies a donor without an account.
+#[derive(InputObject)]
+pub struct GuestDonorInput {
+    #[graphql(validator(chars_min_length = 1, chars_max_length = 100))]
+    pub name: String,
+    #[graphql(validator(email))]
+    pub email: Option<String>,
+}
+
+#[derive(InputObject)]
+pub struct CreateDonaInput {
+    pub id: Uuid,
+    #[graphql(validator(chars_min_length = 1, chars_max_length = 500))]
+    pub msg: String,
+    pub amount: Decimal,
+    #[graphql(validator(chars_min_length = 3, chars_max_length = 3))]
+    pub currency: String,
+    #[graphql(validator(chars_min_length = 1))]
+    pub method: String,
+    pub user_id: Uuid,
+    pub campaign_id: Option<Uuid>,
+    /// Required when donating without being logged in, rejected otherwise.
+    pub guest: Option<GuestDonorInput>,
+    /// Hides the sender from the recipient.
+    #[graphql(default)]
+    pub is_anonymous: bool,
+    /// Required for crypto donas, rejected otherwise.
+    #[graphql(validator(chars_max_length = 100))]
+    pub tx_hash: Option<String>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(Debug, Default)]
+pub struct CreateDonaMutation;
+
+#[Object]
+impl CreateDonaMutation {
+    async fn create_dona(&self, ctx: &Context<'_>, input: CreateDonaInput) -> Result<bool> {
+        let command_bus = ctx.data::<CommandBusType>()?;
+        let session = ctx.data::<Session>()?;
+
+        let sender_id = if is_authenticated(session) {
+            Some(
+                session
+                    .get::<String>("user_id")
+                    .ok_or(Error::new("UNAUTHORIZED"))?,
+            )
+        } else {
+            None
+        };
+        let (guest_name, guest_email) = match input.guest {
+            Some(guest) => (Some(guest.name), guest.email),
+            None => (None, None),
+        };
+
+        let command = CreateDonaCommand {
+            id: input.id.to_string(),
+            msg: input.msg,
+            amount: input.amount,
+            currency: input.currency,
+            method: input.method,
+            user_id: input.user_id.to_string(),
+            sender_id,
+            guest_name,
+            guest_email,
+            is_anonymous: input.is_anonymous,
+            campaign_id: input.campaign_id.map(|id| id.to_string()),
+            tx_hash: input.tx_hash,
+            created_at: input.created_at,
+            updated_at: input.updated_at,
+        };
+        command_bus
+            .dispatch(Box::new(command))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+
+        Ok(true)
+    }
+}
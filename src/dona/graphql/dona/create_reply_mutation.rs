@@ -0,0 +1,65 @@
+use async_graphql::{Context, Enum, Error, InputObject, Object, Result};
+use dona_context::dona::application::create_reply::command::CreateDonaReplyCommand;
+use poem::session::Session;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{gql_validators::check_permission_for_user, CommandBusType};
+
+/// Private replies are only shown to the donor.
+#[derive(Enum, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DonaReplyVisibility {
+    Private,
+    Public,
+}
+
+impl From<DonaReplyVisibility> for String {
+    fn from(value: DonaReplyVisibility) -> Self {
+        match value {
+            DonaReplyVisibility::Private => "PRIVATE".to_string(),
+            DonaReplyVisibility::Public => "PUBLIC".to_string(),
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct CreateDonaReplyInput {
+    pub id: Uuid,
+    pub dona_id: Uuid,
+    pub user_id: Uuid,
+    #[graphql(validator(chars_min_length = 1, chars_max_length = 500))]
+    pub msg: String,
+    pub visibility: DonaReplyVisibility,
+    pub created_at: OffsetDateTime,
+}
+
+#[derive(Debug, Default)]
+pub struct CreateDonaReplyMutation;
+
+#[Object]
+impl CreateDonaReplyMutation {
+    async fn create_dona_reply(
+        &self,
+        ctx: &Context<'_>,
+        input: CreateDonaReplyInput,
+    ) -> Result<bool> {
+        let command_bus = ctx.data::<CommandBusType>()?;
+        let session = ctx.data::<Session>()?;
+        check_permission_for_user(command_bus, session, input.user_id.to_string()).await?;
+
+        let command = CreateDonaReplyCommand {
+            id: input.id.to_string(),
+            dona_id: input.dona_id.to_string(),
+            user_id: input.user_id.to_string(),
+            msg: input.msg,
+            visibility: input.visibility.into(),
+            created_at: input.created_at,
+        };
+        command_bus
+            .dispatch(Box::new(command))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+
+        Ok(true)
+    }
+}
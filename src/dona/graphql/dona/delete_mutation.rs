@@ -0,0 +1,31 @@
+use async_graphql::{Context, Error, Object, Result};
+use dona_context::dona::application::delete::command::DeleteDonaCommand;
+use poem::session::Session;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{gql_validators::check_admin, CommandBusType};
+
+#[derive(Debug, Default)]
+pub struct DeleteDonaMutation;
+
+#[Object]
+impl DeleteDonaMutation {
+    /// Removes a dona along with its attachments. Admin only.
+    async fn delete_dona(&self, ctx: &Context<'_>, id: Uuid) -> Result<bool> {
+        let command_bus = ctx.data::<CommandBusType>()?;
+        let session = ctx.data::<Session>()?;
+        check_admin(command_bus, session).await?;
+
+        let command = DeleteDonaCommand {
+            id: id.to_string(),
+            deleted_at: OffsetDateTime::now_utc(),
+        };
+        command_bus
+            .dispatch(Box::new(command))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,42 @@
+use async_graphql::{Context, Error, InputObject, Object, Result};
+use dona_context::dona::application::delete_reply::command::DeleteDonaReplyCommand;
+use poem::session::Session;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{gql_validators::check_permission_for_user, CommandBusType};
+
+#[derive(InputObject)]
+pub struct DeleteDonaReplyInput {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub deleted_at: OffsetDateTime,
+}
+
+#[derive(Debug, Default)]
+pub struct DeleteDonaReplyMutation;
+
+#[Object]
+impl DeleteDonaReplyMutation {
+    async fn delete_dona_reply(
+        &self,
+        ctx: &Context<'_>,
+        input: DeleteDonaReplyInput,
+    ) -> Result<bool> {
+        let command_bus = ctx.data::<CommandBusType>()?;
+        let session = ctx.data::<Session>()?;
+        check_permission_for_user(command_bus, session, input.user_id.to_string()).await?;
+
+        let command = DeleteDonaReplyCommand {
+            id: input.id.to_string(),
+            user_id: input.user_id.to_string(),
+            deleted_at: input.deleted_at,
+        };
+        command_bus
+            .dispatch(Box::new(command))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+
+        Ok(true)
+    }
+}
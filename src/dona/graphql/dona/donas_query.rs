@@ -0,0 +1,40 @@
+use async_graphql::{Context, Error, Object, Result};
+use dona_context::dona::application::{
+    find_by_criteria::query::FindDonasByCriteriaQuery, response::DonasResponse,
+};
+use poem::session::Session;
+use shared::{domain::criteria::Criteria, infrastructure::criteria::async_graphql::CriteriaGql};
+
+use crate::{
+    dona::graphql::dona::types::{into_dona_connection, DonaConnection},
+    gql_validators::check_admin,
+    CommandBusType, QueryBusType,
+};
+
+#[derive(Debug, Default)]
+pub struct DonasQuery;
+
+#[Object]
+impl DonasQuery {
+    /// Every dona matching the criteria, senders of anonymous donas included. Admins only.
+    async fn donas(&self, ctx: &Context<'_>, criteria: CriteriaGql) -> Result<DonaConnection> {
+        let command_bus = ctx.data::<CommandBusType>()?;
+        let session = ctx.data::<Session>()?;
+        check_admin(command_bus, session).await?;
+
+        let criteria: Criteria = criteria.try_into()?;
+
+        let query_bus = ctx.data::<QueryBusType>()?;
+        let donas = query_bus
+            .ask(Box::new(FindDonasByCriteriaQuery { criteria }))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+        let donas: DonasResponse = donas
+            .as_any()
+            .downcast_ref::<DonasResponse>()
+            .unwrap()
+            .clone();
+
+        Ok(into_dona_connection(donas))
+    }
+}
@@ -0,0 +1,72 @@
+use std::io::Read;
+
+use async_graphql::{Context, Error, Object, Result, Upload};
+use dona_context::dona::application::{
+    import::command::ImportDonasCommand, response::DonaImportReportResponse,
+    validate_import::query::ValidateDonaImportQuery,
+};
+use poem::session::Session;
+use time::OffsetDateTime;
+
+use crate::{
+    dona::graphql::dona::types::DonaImportReport,
+    gql_validators::{check_upload, is_authenticated_with_err, CSV_TYPES},
+    CommandBusType, QueryBusType,
+};
+
+#[derive(Debug, Default)]
+pub struct ImportDonasMutation;
+
+#[Object]
+impl ImportDonasMutation {
+    /// Imports donas received offline by the session user from a CSV file with the columns
+    /// `date,amount,currency,donor_name,donor_email,message`. Valid rows become confirmed
+    /// manual donas and invalid ones are reported by line. A dry run only returns the report.
+    async fn import_donas(
+        &self,
+        ctx: &Context<'_>,
+        file: Upload,
+        #[graphql(default)] dry_run: bool,
+    ) -> Result<DonaImportReport> {
+        let session = ctx.data::<Session>()?;
+        is_authenticated_with_err(session)?;
+
+        let user_id = session
+            .get::<String>("user_id")
+            .ok_or(Error::new("UNAUTHORIZED"))?;
+
+        let upload_value = file.value(ctx)?;
+        check_upload(Some(&upload_value), &CSV_TYPES)?;
+
+        let mut csv = String::new();
+        upload_value.into_read().read_to_string(&mut csv)?;
+
+        let query_bus = ctx.data::<QueryBusType>()?;
+        let report = query_bus
+            .ask(Box::new(ValidateDonaImportQuery {
+                user_id: user_id.clone(),
+                csv: csv.clone(),
+            }))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+        let report: DonaImportReportResponse = report
+            .as_any()
+            .downcast_ref::<DonaImportReportResponse>()
+            .unwrap()
+            .clone();
+
+        if !dry_run && report.valid_rows > 0 {
+            let command_bus = ctx.data::<CommandBusType>()?;
+            command_bus
+                .dispatch(Box::new(ImportDonasCommand {
+                    user_id,
+                    csv,
+                    imported_at: OffsetDateTime::now_utc(),
+                }))
+                .await
+                .map_err(|e| Error::new(e.to_string()))?;
+        }
+
+        Ok(DonaImportReport::new(report, dry_run))
+    }
+}
@@ -0,0 +1,62 @@
+use async_graphql::MergedObject;
+
+use self::{
+    cancel_mutation::CancelDonaMutation, checkout_mutation::StartDonaCheckoutMutation,
+    confirm_mutation::ConfirmDonaMutation, create_mutation::CreateDonaMutation,
+    create_reply_mutation::CreateDonaReplyMutation, delete_mutation::DeleteDonaMutation,
+    delete_reply_mutation::DeleteDonaReplyMutation, donas_query::DonasQuery,
+    import_mutation::ImportDonasMutation, msg_review_queue_query::DonaMsgReviewQueueQuery,
+    public_replies_query::PublicDonaRepliesQuery, received_donas_query::MyReceivedDonasQuery,
+    received_total_query::MyReceivedTotalQuery, refund_mutation::RefundDonaMutation,
+    reject_mutation::RejectDonaMutation, review_msg_mutation::ReviewDonaMsgMutation,
+    sent_donas_query::MySentDonasQuery, stats_query::DonaStatsQuery,
+    update_reply_mutation::UpdateDonaReplyMutation,
+};
+
+mod cancel_mutation;
+mod checkout_mutation;
+mod confirm_mutation;
+mod create_mutation;
+mod create_reply_mutation;
+mod delete_mutation;
+mod delete_reply_mutation;
+mod donas_query;
+mod import_mutation;
+mod msg_review_queue_query;
+mod public_replies_query;
+mod received_donas_query;
+mod received_total_query;
+mod refund_mutation;
+mod reject_mutation;
+mod review_msg_mutation;
+mod sent_donas_query;
+mod stats_query;
+pub mod types;
+mod update_reply_mutation;
+
+#[derive(MergedObject, Default)]
+pub struct DonaQuery(
+    MyReceivedDonasQuery,
+    MySentDonasQuery,
+    MyReceivedTotalQuery,
+    DonasQuery,
+    DonaStatsQuery,
+    DonaMsgReviewQueueQuery,
+    PublicDonaRepliesQuery,
+);
+
+#[derive(MergedObject, Default)]
+pub struct DonaMutation(
+    CreateDonaMutation,
+    ConfirmDonaMutation,
+    RejectDonaMutation,
+    CancelDonaMutation,
+    RefundDonaMutation,
+    ImportDonasMutation,
+    DeleteDonaMutation,
+    StartDonaCheckoutMutation,
+    ReviewDonaMsgMutation,
+    CreateDonaReplyMutation,
+    UpdateDonaReplyMutation,
+    DeleteDonaReplyMutation,
+);
@@ -0,0 +1,64 @@
+use async_graphql::{Context, Error, Object, Result};
+use dona_context::dona::{
+    application::{find_by_criteria::query::FindDonasByCriteriaQuery, response::DonasResponse},
+    domain::dona::DonaMsgStatus,
+};
+use poem::session::Session;
+use shared::{
+    domain::criteria::{
+        filter::{Filter, FilterField, FilterOperator, FilterValue},
+        Criteria,
+    },
+    infrastructure::criteria::async_graphql::CriteriaGql,
+};
+use uuid::Uuid;
+
+use crate::{
+    dona::graphql::dona::types::{into_received_dona_connection, DonaConnection},
+    gql_validators::check_permission_for_user,
+    CommandBusType, QueryBusType,
+};
+
+#[derive(Debug, Default)]
+pub struct DonaMsgReviewQueueQuery;
+
+#[Object]
+impl DonaMsgReviewQueueQuery {
+    /// Donas of the user whose message was flagged and waits for review.
+    async fn dona_msg_review_queue(
+        &self,
+        ctx: &Context<'_>,
+        user_id: Uuid,
+        criteria: CriteriaGql,
+    ) -> Result<DonaConnection> {
+        let command_bus = ctx.data::<CommandBusType>()?;
+        let session = ctx.data::<Session>()?;
+        check_permission_for_user(command_bus, session, user_id.to_string()).await?;
+
+        let criteria: Criteria = criteria.try_into()?;
+        let criteria = criteria
+            .with_filter(Filter::new(
+                FilterField::try_from("user_id".to_string())?,
+                FilterOperator::Equal,
+                FilterValue::try_from(user_id.to_string())?,
+            ))
+            .with_filter(Filter::new(
+                FilterField::try_from("msg_status".to_string())?,
+                FilterOperator::Equal,
+                FilterValue::try_from(DonaMsgStatus::InReview.to_string())?,
+            ));
+
+        let query_bus = ctx.data::<QueryBusType>()?;
+        let donas = query_bus
+            .ask(Box::new(FindDonasByCriteriaQuery { criteria }))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+        let donas: DonasResponse = donas
+            .as_any()
+            .downcast_ref::<DonasResponse>()
+            .unwrap()
+            .clone();
+
+        Ok(into_received_dona_connection(donas))
+    }
+}
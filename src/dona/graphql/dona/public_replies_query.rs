@@ -0,0 +1,44 @@
+use async_graphql::{Context, Error, Object, Result};
+use dona_context::dona::application::{
+    find_public_replies::query::FindPublicDonaRepliesQuery, response::DonaRepliesResponse,
+};
+use shared::{domain::criteria::Criteria, infrastructure::criteria::async_graphql::CriteriaGql};
+use uuid::Uuid;
+
+use crate::{
+    dona::graphql::dona::types::{into_dona_reply_connection, DonaReplyConnection},
+    QueryBusType,
+};
+
+#[derive(Debug, Default)]
+pub struct PublicDonaRepliesQuery;
+
+#[Object]
+impl PublicDonaRepliesQuery {
+    /// Thank-you replies a recipient made public, so anyone can read them. Private replies
+    /// are never listed here.
+    async fn public_dona_replies(
+        &self,
+        ctx: &Context<'_>,
+        user_id: Uuid,
+        criteria: CriteriaGql,
+    ) -> Result<DonaReplyConnection> {
+        let criteria: Criteria = criteria.try_into()?;
+
+        let query_bus = ctx.data::<QueryBusType>()?;
+        let replies = query_bus
+            .ask(Box::new(FindPublicDonaRepliesQuery {
+                user_id: user_id.to_string(),
+                criteria,
+            }))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+        let replies: DonaRepliesResponse = replies
+            .as_any()
+            .downcast_ref::<DonaRepliesResponse>()
+            .unwrap()
+            .clone();
+
+        Ok(into_dona_reply_connection(replies))
+    }
+}
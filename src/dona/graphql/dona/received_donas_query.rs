@@ -0,0 +1,57 @@
+use async_graphql::{Context, Error, Object, Result};
+use dona_context::dona::application::{
+    find_by_criteria::query::FindDonasByCriteriaQuery, response::DonasResponse,
+};
+use poem::session::Session;
+use shared::{
+    domain::criteria::{
+        filter::{Filter, FilterField, FilterOperator, FilterValue},
+        Criteria,
+    },
+    infrastructure::criteria::async_graphql::CriteriaGql,
+};
+
+use crate::{
+    dona::graphql::dona::types::{into_received_dona_connection, DonaConnection},
+    gql_validators::is_authenticated_with_err,
+    QueryBusType,
+};
+
+#[derive(Debug, Default)]
+pub struct MyReceivedDonasQuery;
+
+#[Object]
+impl MyReceivedDonasQuery {
+    async fn my_received_donas(
+        &self,
+        ctx: &Context<'_>,
+        criteria: CriteriaGql,
+    ) -> Result<DonaConnection> {
+        let session = ctx.data::<Session>()?;
+        is_authenticated_with_err(session)?;
+
+        let user_id = session
+            .get::<String>("user_id")
+            .ok_or(Error::new("UNAUTHORIZED"))?;
+
+        let criteria: Criteria = criteria.try_into()?;
+        let criteria = criteria.with_filter(Filter::new(
+            FilterField::try_from("user_id".to_string())?,
+            FilterOperator::Equal,
+            FilterValue::try_from(user_id)?,
+        ));
+
+        let query_bus = ctx.data::<QueryBusType>()?;
+        let donas = query_bus
+            .ask(Box::new(FindDonasByCriteriaQuery { criteria }))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+        let donas: DonasResponse = donas
+            .as_any()
+            .downcast_ref::<DonasResponse>()
+            .unwrap()
+            .clone();
+
+        Ok(into_received_dona_connection(donas))
+    }
+}
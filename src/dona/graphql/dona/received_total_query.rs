@@ -0,0 +1,58 @@
+use async_graphql::{Context, Error, Object, Result, SimpleObject};
+use dona_context::dona::application::{
+    received_total::query::GetReceivedTotalQuery, response::ReceivedTotalResponse,
+};
+use poem::session::Session;
+use rust_decimal::Decimal;
+
+use crate::{gql_validators::is_authenticated_with_err, QueryBusType};
+
+#[derive(SimpleObject)]
+pub struct ReceivedTotal {
+    pub total: Decimal,
+    pub currency: String,
+    pub count: u64,
+}
+
+impl From<ReceivedTotalResponse> for ReceivedTotal {
+    fn from(value: ReceivedTotalResponse) -> Self {
+        Self {
+            total: value.total,
+            currency: value.currency,
+            count: value.count,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct MyReceivedTotalQuery;
+
+#[Object]
+impl MyReceivedTotalQuery {
+    /// Confirmed donas received by the session user, normalized to `currency`.
+    async fn my_received_total(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(validator(chars_min_length = 3, chars_max_length = 3))] currency: String,
+    ) -> Result<ReceivedTotal> {
+        let session = ctx.data::<Session>()?;
+        is_authenticated_with_err(session)?;
+
+        let user_id = session
+            .get::<String>("user_id")
+            .ok_or(Error::new("UNAUTHORIZED"))?;
+
+        let query_bus = ctx.data::<QueryBusType>()?;
+        let total = query_bus
+            .ask(Box::new(GetReceivedTotalQuery { user_id, currency }))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+        let total: ReceivedTotalResponse = total
+            .as_any()
+            .downcast_ref::<ReceivedTotalResponse>()
+            .unwrap()
+            .clone();
+
+        Ok(total.into())
+    }
+}
@@ -0,0 +1,38 @@
+use async_graphql::{Context, Error, InputObject, Object, Result};
+use dona_context::dona::application::reject::command::RejectDonaCommand;
+use poem::session::Session;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{gql_validators::check_permission_for_user, CommandBusType};
+
+#[derive(InputObject)]
+pub struct RejectDonaInput {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(Debug, Default)]
+pub struct RejectDonaMutation;
+
+#[Object]
+impl RejectDonaMutation {
+    async fn reject_dona(&self, ctx: &Context<'_>, input: RejectDonaInput) -> Result<bool> {
+        let command_bus = ctx.data::<CommandBusType>()?;
+        let session = ctx.data::<Session>()?;
+        check_permission_for_user(command_bus, session, input.user_id.to_string()).await?;
+
+        let command = RejectDonaCommand {
+            id: input.id.to_string(),
+            user_id: input.user_id.to_string(),
+            updated_at: input.updated_at,
+        };
+        command_bus
+            .dispatch(Box::new(command))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+
+        Ok(true)
+    }
+}
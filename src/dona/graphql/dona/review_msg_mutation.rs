@@ -0,0 +1,55 @@
+use async_graphql::{Context, Enum, Error, InputObject, Object, Result};
+use dona_context::dona::application::review_msg::command::ReviewDonaMsgCommand;
+use poem::session::Session;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{gql_validators::check_permission_for_user, CommandBusType};
+
+#[derive(Enum, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DonaMsgReviewDecision {
+    Approve,
+    Redact,
+}
+
+impl From<DonaMsgReviewDecision> for String {
+    fn from(value: DonaMsgReviewDecision) -> Self {
+        match value {
+            DonaMsgReviewDecision::Approve => "approve".to_string(),
+            DonaMsgReviewDecision::Redact => "redact".to_string(),
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct ReviewDonaMsgInput {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub decision: DonaMsgReviewDecision,
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(Debug, Default)]
+pub struct ReviewDonaMsgMutation;
+
+#[Object]
+impl ReviewDonaMsgMutation {
+    async fn review_dona_msg(&self, ctx: &Context<'_>, input: ReviewDonaMsgInput) -> Result<bool> {
+        let command_bus = ctx.data::<CommandBusType>()?;
+        let session = ctx.data::<Session>()?;
+        check_permission_for_user(command_bus, session, input.user_id.to_string()).await?;
+
+        let command = ReviewDonaMsgCommand {
+            id: input.id.to_string(),
+            user_id: input.user_id.to_string(),
+            decision: input.decision.into(),
+            updated_at: input.updated_at,
+        };
+        command_bus
+            .dispatch(Box::new(command))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+
+        Ok(true)
+    }
+}
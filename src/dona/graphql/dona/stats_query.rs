@@ -0,0 +1,158 @@
+use async_graphql::{Context, Enum, Error, Object, Result, SimpleObject};
+use dona_context::dona::application::{
+    response::{DonaStatsBucketResponse, DonaStatsResponse},
+    stats::query::GetDonaStatsQuery,
+};
+use poem::session::Session;
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+    gql_validators::{check_admin, is_authenticated_with_err},
+    CommandBusType, QueryBusType,
+};
+
+#[derive(Enum, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum DonaStatsGranularity {
+    #[default]
+    Day,
+    Week,
+    Month,
+}
+
+impl From<DonaStatsGranularity> for String {
+    fn from(value: DonaStatsGranularity) -> Self {
+        match value {
+            DonaStatsGranularity::Day => "day".to_string(),
+            DonaStatsGranularity::Week => "week".to_string(),
+            DonaStatsGranularity::Month => "month".to_string(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct DonaStatsBucket {
+    pub key: String,
+    pub currency: String,
+    pub amount: Decimal,
+    pub fees: Decimal,
+    /// Donas still without a fee count in full.
+    pub net_amount: Decimal,
+    pub count: u64,
+    pub average: Decimal,
+}
+
+impl From<DonaStatsBucketResponse> for DonaStatsBucket {
+    fn from(value: DonaStatsBucketResponse) -> Self {
+        Self {
+            key: value.key,
+            currency: value.currency,
+            amount: value.amount,
+            fees: value.fees,
+            net_amount: value.net_amount,
+            count: value.count,
+            average: value.average,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct DonaStats {
+    pub from: OffsetDateTime,
+    pub to: OffsetDateTime,
+    pub granularity: String,
+    /// Confirmed donas per currency.
+    pub totals: Vec<DonaStatsBucket>,
+    /// Confirmed donas per period, keyed by the first day of the period.
+    pub by_period: Vec<DonaStatsBucket>,
+    /// Confirmed donas per payment method.
+    pub by_method: Vec<DonaStatsBucket>,
+    /// Donas in any status.
+    pub by_status: Vec<DonaStatsBucket>,
+}
+
+impl From<DonaStatsResponse> for DonaStats {
+    fn from(value: DonaStatsResponse) -> Self {
+        let into_buckets = |buckets: Vec<DonaStatsBucketResponse>| {
+            buckets.into_iter().map(DonaStatsBucket::from).collect()
+        };
+
+        Self {
+            from: value.from,
+            to: value.to,
+            granularity: value.granularity,
+            totals: into_buckets(value.totals),
+            by_period: into_buckets(value.by_period),
+            by_method: into_buckets(value.by_method),
+            by_status: into_buckets(value.by_status),
+        }
+    }
+}
+
+async fn ask_dona_stats(
+    ctx: &Context<'_>,
+    user_id: String,
+    from: Option<OffsetDateTime>,
+    to: Option<OffsetDateTime>,
+    granularity: DonaStatsGranularity,
+) -> Result<DonaStats> {
+    let query_bus = ctx.data::<QueryBusType>()?;
+    let stats = query_bus
+        .ask(Box::new(GetDonaStatsQuery {
+            user_id,
+            from,
+            to,
+            granularity: granularity.into(),
+        }))
+        .await
+        .map_err(|e| Error::new(e.to_string()))?;
+    let stats: DonaStatsResponse = stats
+        .as_any()
+        .downcast_ref::<DonaStatsResponse>()
+        .unwrap()
+        .clone();
+
+    Ok(stats.into())
+}
+
+#[derive(Debug, Default)]
+pub struct DonaStatsQuery;
+
+#[Object]
+impl DonaStatsQuery {
+    /// Statistics of the donas received by the session user, over the last 30 days unless a
+    /// range is given.
+    async fn dona_stats(
+        &self,
+        ctx: &Context<'_>,
+        from: Option<OffsetDateTime>,
+        to: Option<OffsetDateTime>,
+        #[graphql(default)] granularity: DonaStatsGranularity,
+    ) -> Result<DonaStats> {
+        let session = ctx.data::<Session>()?;
+        is_authenticated_with_err(session)?;
+
+        let user_id = session
+            .get::<String>("user_id")
+            .ok_or(Error::new("UNAUTHORIZED"))?;
+
+        ask_dona_stats(ctx, user_id, from, to, granularity).await
+    }
+
+    /// Same as `donaStats` for any user. Admins only.
+    async fn user_dona_stats(
+        &self,
+        ctx: &Context<'_>,
+        user_id: Uuid,
+        from: Option<OffsetDateTime>,
+        to: Option<OffsetDateTime>,
+        #[graphql(default)] granularity: DonaStatsGranularity,
+    ) -> Result<DonaStats> {
+        let command_bus = ctx.data::<CommandBusType>()?;
+        let session = ctx.data::<Session>()?;
+        check_admin(command_bus, session).await?;
+
+        ask_dona_stats(ctx, user_id.to_string(), from, to, granularity).await
+    }
+}
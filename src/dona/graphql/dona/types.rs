@@ -0,0 +1,212 @@
+use async_graphql::{connection::Connection, connection::Edge, SimpleObject};
+use dona_context::dona::application::response::{
+    DonaCheckoutResponse, DonaImportReportResponse, DonaImportRowErrorResponse,
+    DonaRepliesResponse, DonaReplyResponse, DonaResponse, DonasResponse,
+};
+use rust_decimal::Decimal;
+use shared::domain::criteria::cursor::AfterCursor;
+use time::OffsetDateTime;
+
+#[derive(SimpleObject)]
+pub struct Dona {
+    pub id: String,
+    pub msg: String,
+    /// `in_review` while a flagged message waits for the recipient, `redacted` once removed.
+    pub msg_status: String,
+    pub msg_flags: Vec<String>,
+    /// Gross amount, fees included.
+    pub amount: Decimal,
+    pub currency: String,
+    /// Platform fee, empty until the dona is confirmed.
+    pub fee_amount: Option<Decimal>,
+    /// What the recipient gets once the fee is taken, empty until the dona is confirmed.
+    pub net_amount: Option<Decimal>,
+    pub status: String,
+    pub method: String,
+    pub user_id: String,
+    /// Empty for guest donas, and for anonymous donas when seen by the recipient.
+    pub sender_id: Option<String>,
+    pub guest_name: Option<String>,
+    /// Only shown to admins, recipients never see the email of a guest donor.
+    pub guest_email: Option<String>,
+    pub is_anonymous: bool,
+    pub campaign_id: Option<String>,
+    pub pledge_id: Option<String>,
+    /// Transaction hash reported by the donor for crypto donas.
+    pub tx_hash: Option<String>,
+    /// Thank-you reply of the recipient, only filled in when donas are listed.
+    pub reply: Option<DonaReply>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl From<DonaResponse> for Dona {
+    fn from(value: DonaResponse) -> Self {
+        Self {
+            id: value.id,
+            msg: value.msg,
+            msg_status: value.msg_status,
+            msg_flags: value.msg_flags,
+            amount: value.amount,
+            currency: value.currency,
+            fee_amount: value.fee_amount,
+            net_amount: value.net_amount,
+            status: value.status,
+            method: value.method,
+            user_id: value.user_id,
+            sender_id: value.sender_id,
+            guest_name: value.guest_name,
+            guest_email: value.guest_email,
+            is_anonymous: value.is_anonymous,
+            campaign_id: value.campaign_id,
+            pledge_id: value.pledge_id,
+            tx_hash: value.tx_hash,
+            reply: value.reply.map(DonaReply::from),
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+impl Dona {
+    /// The dona as its recipient sees it: anonymous donas do not reveal who sent them, and
+    /// guest donors never have their email shown.
+    pub fn for_recipient(value: DonaResponse) -> Self {
+        let is_anonymous = value.is_anonymous;
+        let mut dona = Self::from(value);
+        dona.guest_email = None;
+
+        if is_anonymous {
+            dona.sender_id = None;
+            dona.guest_name = None;
+        }
+
+        dona
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct DonaReply {
+    pub id: String,
+    pub msg: String,
+    /// `PRIVATE` replies are only shown to the donor, `PUBLIC` ones are also listed by
+    /// `publicDonaReplies`.
+    pub visibility: String,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl From<DonaReplyResponse> for DonaReply {
+    fn from(value: DonaReplyResponse) -> Self {
+        Self {
+            id: value.id,
+            msg: value.msg,
+            visibility: value.visibility,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+pub type DonaReplyConnection = Connection<String, DonaReply>;
+
+/// Replies are paginated like donas, see [`edge_cursor`].
+pub fn into_dona_reply_connection(value: DonaRepliesResponse) -> DonaReplyConnection {
+    let mut connection = Connection::new(value.has_previous_page, value.has_next_page);
+    connection
+        .edges
+        .extend(value.replies.into_iter().map(|reply| {
+            Edge::new(
+                edge_cursor(reply.created_at, reply.id.clone()),
+                DonaReply::from(reply),
+            )
+        }));
+
+    connection
+}
+
+pub type DonaConnection = Connection<String, Dona>;
+
+/// Donas are paginated by `created_at` and then `id`, so each edge exposes both as its cursor
+/// and the next page does not skip the donas created at the same instant.
+fn edge_cursor(created_at: OffsetDateTime, id: String) -> String {
+    AfterCursor::with_id(created_at, id)
+        .map(|cursor| cursor.to_string())
+        .unwrap_or_default()
+}
+
+fn into_connection(value: DonasResponse, into_dona: fn(DonaResponse) -> Dona) -> DonaConnection {
+    let mut connection = Connection::new(value.has_previous_page, value.has_next_page);
+    connection.edges.extend(value.donas.into_iter().map(|dona| {
+        Edge::new(
+            edge_cursor(dona.created_at, dona.id.clone()),
+            into_dona(dona),
+        )
+    }));
+
+    connection
+}
+
+pub fn into_dona_connection(value: DonasResponse) -> DonaConnection {
+    into_connection(value, Dona::from)
+}
+
+pub fn into_received_dona_connection(value: DonasResponse) -> DonaConnection {
+    into_connection(value, Dona::for_recipient)
+}
+
+#[derive(SimpleObject)]
+pub struct DonaImportRowError {
+    /// Line of the file, the header being line 1.
+    pub line: u64,
+    pub error: String,
+}
+
+impl From<DonaImportRowErrorResponse> for DonaImportRowError {
+    fn from(value: DonaImportRowErrorResponse) -> Self {
+        Self {
+            line: value.line,
+            error: value.error,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct DonaImportReport {
+    pub total_rows: u64,
+    pub valid_rows: u64,
+    /// Zero on a dry run.
+    pub imported_rows: u64,
+    pub errors: Vec<DonaImportRowError>,
+}
+
+impl DonaImportReport {
+    pub fn new(value: DonaImportReportResponse, dry_run: bool) -> Self {
+        Self {
+            total_rows: value.total_rows,
+            valid_rows: value.valid_rows,
+            imported_rows: if dry_run { 0 } else { value.valid_rows },
+            errors: value
+                .errors
+                .into_iter()
+                .map(DonaImportRowError::from)
+                .collect(),
+        }
+    }
+}
+
+/// Page of the payment provider where the donor completes the payment.
+#[derive(SimpleObject)]
+pub struct DonaCheckout {
+    pub reference: String,
+    pub url: String,
+}
+
+impl From<DonaCheckoutResponse> for DonaCheckout {
+    fn from(value: DonaCheckoutResponse) -> Self {
+        Self {
+            reference: value.reference,
+            url: value.url,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use async_graphql::{Context, Error, InputObject, Object, Result};
+use dona_context::dona::application::update_reply::command::UpdateDonaReplyCommand;
+use poem::session::Session;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{gql_validators::check_permission_for_user, CommandBusType};
+
+use super::create_reply_mutation::DonaReplyVisibility;
+
+#[derive(InputObject)]
+pub struct UpdateDonaReplyInput {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    #[graphql(validator(chars_min_length = 1, chars_max_length = 500))]
+    pub msg: String,
+    pub visibility: DonaReplyVisibility,
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(Debug, Default)]
+pub struct UpdateDonaReplyMutation;
+
+#[Object]
+impl UpdateDonaReplyMutation {
+    async fn update_dona_reply(
+        &self,
+        ctx: &Context<'_>,
+        input: UpdateDonaReplyInput,
+    ) -> Result<bool> {
+        let command_bus = ctx.data::<CommandBusType>()?;
+        let session = ctx.data::<Session>()?;
+        check_permission_for_user(command_bus, session, input.user_id.to_string()).await?;
+
+        let command = UpdateDonaReplyCommand {
+            id: input.id.to_string(),
+            user_id: input.user_id.to_string(),
+            msg: input.msg,
+            visibility: input.visibility.into(),
+            updated_at: input.updated_at,
+        };
+        command_bus
+            .dispatch(Box::new(command))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+
+        Ok(true)
+    }
+}
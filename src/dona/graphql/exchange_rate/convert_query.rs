@@ -0,0 +1,61 @@
+use async_graphql::{Context, Error, Object, Result, SimpleObject};
+use dona_context::exchange_rate::application::{
+    convert::query::ConvertAmountQuery, response::ConvertedAmountResponse,
+};
+use poem::session::Session;
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
+
+use crate::{gql_validators::is_authenticated_with_err, QueryBusType};
+
+#[derive(SimpleObject)]
+pub struct ConvertedAmount {
+    pub amount: Decimal,
+    pub currency: String,
+}
+
+impl From<ConvertedAmountResponse> for ConvertedAmount {
+    fn from(value: ConvertedAmountResponse) -> Self {
+        Self {
+            amount: value.amount,
+            currency: value.currency,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ConvertAmountGqlQuery;
+
+#[Object]
+impl ConvertAmountGqlQuery {
+    /// Converts an amount with the exchange rate in effect at `at`, or today when omitted.
+    async fn convert_amount(
+        &self,
+        ctx: &Context<'_>,
+        amount: Decimal,
+        #[graphql(validator(chars_min_length = 3, chars_max_length = 3))] from: String,
+        #[graphql(validator(chars_min_length = 3, chars_max_length = 3))] to: String,
+        at: Option<OffsetDateTime>,
+    ) -> Result<ConvertedAmount> {
+        let session = ctx.data::<Session>()?;
+        is_authenticated_with_err(session)?;
+
+        let query_bus = ctx.data::<QueryBusType>()?;
+        let converted = query_bus
+            .ask(Box::new(ConvertAmountQuery {
+                amount,
+                from,
+                to,
+                at: at.unwrap_or_else(OffsetDateTime::now_utc),
+            }))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+        let converted: ConvertedAmountResponse = converted
+            .as_any()
+            .downcast_ref::<ConvertedAmountResponse>()
+            .unwrap()
+            .clone();
+
+        Ok(converted.into())
+    }
+}
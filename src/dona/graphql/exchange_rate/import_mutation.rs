@@ -0,0 +1,42 @@
+use std::io::Read;
+
+use async_graphql::{Context, Error, Object, Result, Upload};
+use dona_context::exchange_rate::application::import::command::ImportExchangeRatesCommand;
+use poem::session::Session;
+use time::OffsetDateTime;
+
+use crate::{
+    gql_validators::{check_admin, check_upload, CSV_TYPES},
+    CommandBusType,
+};
+
+#[derive(Debug, Default)]
+pub struct ImportExchangeRatesMutation;
+
+#[Object]
+impl ImportExchangeRatesMutation {
+    /// Upserts the rates of a CSV file with the columns
+    /// `base_currency,quote_currency,rate,date`.
+    async fn import_exchange_rates(&self, ctx: &Context<'_>, file: Upload) -> Result<bool> {
+        let command_bus = ctx.data::<CommandBusType>()?;
+        let session = ctx.data::<Session>()?;
+        check_admin(command_bus, session).await?;
+
+        let upload_value = file.value(ctx)?;
+        check_upload(Some(&upload_value), &CSV_TYPES)?;
+
+        let mut csv = String::new();
+        upload_value.into_read().read_to_string(&mut csv)?;
+
+        let command = ImportExchangeRatesCommand {
+            csv,
+            updated_at: OffsetDateTime::now_utc(),
+        };
+        command_bus
+            .dispatch(Box::new(command))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,16 @@
+use async_graphql::MergedObject;
+
+use self::{
+    convert_query::ConvertAmountGqlQuery, import_mutation::ImportExchangeRatesMutation,
+    upsert_mutation::UpsertExchangeRateMutation,
+};
+
+mod convert_query;
+mod import_mutation;
+mod upsert_mutation;
+
+#[derive(MergedObject, Default)]
+pub struct ExchangeRateQuery(ConvertAmountGqlQuery);
+
+#[derive(MergedObject, Default)]
+pub struct ExchangeRateMutation(UpsertExchangeRateMutation, ImportExchangeRatesMutation);
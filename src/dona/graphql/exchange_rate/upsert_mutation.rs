@@ -0,0 +1,51 @@
+use async_graphql::{Context, Error, InputObject, Object, Result};
+use dona_context::exchange_rate::application::upsert::command::UpsertExchangeRateCommand;
+use poem::session::Session;
+use rust_decimal::Decimal;
+use time::{Date, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::{gql_validators::check_admin, CommandBusType};
+
+#[derive(InputObject)]
+pub struct UpsertExchangeRateInput {
+    pub id: Uuid,
+    #[graphql(validator(chars_min_length = 3, chars_max_length = 3))]
+    pub base_currency: String,
+    #[graphql(validator(chars_min_length = 3, chars_max_length = 3))]
+    pub quote_currency: String,
+    pub rate: Decimal,
+    pub date: Date,
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(Debug, Default)]
+pub struct UpsertExchangeRateMutation;
+
+#[Object]
+impl UpsertExchangeRateMutation {
+    async fn upsert_exchange_rate(
+        &self,
+        ctx: &Context<'_>,
+        input: UpsertExchangeRateInput,
+    ) -> Result<bool> {
+        let command_bus = ctx.data::<CommandBusType>()?;
+        let session = ctx.data::<Session>()?;
+        check_admin(command_bus, session).await?;
+
+        let command = UpsertExchangeRateCommand {
+            id: input.id.to_string(),
+            base_currency: input.base_currency,
+            quote_currency: input.quote_currency,
+            rate: input.rate,
+            date: input.date,
+            updated_at: input.updated_at,
+        };
+        command_bus
+            .dispatch(Box::new(command))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,38 @@
+use async_graphql::{Context, Error, Object, Result};
+use dona_context::export::application::{
+    find_by_user::query::FindDonaExportsQuery, response::DonaExportsResponse,
+};
+use poem::session::Session;
+
+use crate::{gql_validators::is_authenticated_with_err, QueryBusType};
+
+use super::types::DonaExport;
+
+#[derive(Debug, Default)]
+pub struct MyDonaExportsQuery;
+
+#[Object]
+impl MyDonaExportsQuery {
+    /// Latest exports requested by the session user, newest first.
+    async fn my_dona_exports(&self, ctx: &Context<'_>) -> Result<Vec<DonaExport>> {
+        let session = ctx.data::<Session>()?;
+        is_authenticated_with_err(session)?;
+
+        let user_id = session
+            .get::<String>("user_id")
+            .ok_or(Error::new("UNAUTHORIZED"))?;
+
+        let query_bus = ctx.data::<QueryBusType>()?;
+        let exports = query_bus
+            .ask(Box::new(FindDonaExportsQuery { user_id }))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+        let exports: DonaExportsResponse = exports
+            .as_any()
+            .downcast_ref::<DonaExportsResponse>()
+            .unwrap()
+            .clone();
+
+        Ok(exports.exports.into_iter().map(DonaExport::from).collect())
+    }
+}
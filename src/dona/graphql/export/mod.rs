@@ -0,0 +1,13 @@
+use async_graphql::MergedObject;
+
+use self::{exports_query::MyDonaExportsQuery, request_mutation::ExportDonasMutation};
+
+mod exports_query;
+mod request_mutation;
+pub mod types;
+
+#[derive(MergedObject, Default)]
+pub struct ExportQuery(MyDonaExportsQuery);
+
+#[derive(MergedObject, Default)]
+pub struct ExportMutation(ExportDonasMutation);
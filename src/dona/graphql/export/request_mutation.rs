@@ -0,0 +1,56 @@
+use async_graphql::{Context, Error, InputObject, Object, Result};
+use dona_context::export::application::request::command::RequestDonaExportCommand;
+use poem::session::Session;
+use shared::infrastructure::criteria::async_graphql::CriteriaGql;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{gql_validators::is_authenticated_with_err, CommandBusType};
+
+use super::types::{DonaExportColumn, DonaExportFormat};
+
+#[derive(InputObject)]
+pub struct ExportDonasInput {
+    pub id: Uuid,
+    /// Only the filters are kept, the export always holds every matching dona.
+    pub criteria: CriteriaGql,
+    #[graphql(default)]
+    pub format: DonaExportFormat,
+    /// Written in the given order, every column when empty.
+    #[graphql(default)]
+    pub columns: Vec<DonaExportColumn>,
+    pub created_at: OffsetDateTime,
+}
+
+#[derive(Debug, Default)]
+pub struct ExportDonasMutation;
+
+#[Object]
+impl ExportDonasMutation {
+    /// Queues an export of the donas received by the session user. It is built in the
+    /// background, `myDonaExports` shows its download url once it is completed.
+    async fn export_donas(&self, ctx: &Context<'_>, input: ExportDonasInput) -> Result<bool> {
+        let command_bus = ctx.data::<CommandBusType>()?;
+        let session = ctx.data::<Session>()?;
+        is_authenticated_with_err(session)?;
+
+        let user_id = session
+            .get::<String>("user_id")
+            .ok_or(Error::new("UNAUTHORIZED"))?;
+
+        let command = RequestDonaExportCommand {
+            id: input.id.to_string(),
+            user_id,
+            format: input.format.into(),
+            columns: input.columns.into_iter().map(String::from).collect(),
+            criteria: input.criteria.try_into()?,
+            created_at: input.created_at,
+        };
+        command_bus
+            .dispatch(Box::new(command))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+
+        Ok(true)
+    }
+}
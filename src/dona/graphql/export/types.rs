@@ -0,0 +1,81 @@
+use async_graphql::{Enum, SimpleObject};
+use dona_context::export::application::response::DonaExportResponse;
+use time::OffsetDateTime;
+
+#[derive(Enum, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum DonaExportFormat {
+    #[default]
+    Csv,
+    Xlsx,
+}
+
+impl From<DonaExportFormat> for String {
+    fn from(value: DonaExportFormat) -> Self {
+        match value {
+            DonaExportFormat::Csv => "csv".to_string(),
+            DonaExportFormat::Xlsx => "xlsx".to_string(),
+        }
+    }
+}
+
+#[derive(Enum, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum DonaExportColumn {
+    #[default]
+    Id,
+    Date,
+    Amount,
+    Fees,
+    NetAmount,
+    Currency,
+    Method,
+    Status,
+    Sender,
+    Message,
+}
+
+impl From<DonaExportColumn> for String {
+    fn from(value: DonaExportColumn) -> Self {
+        match value {
+            DonaExportColumn::Id => "id".to_string(),
+            DonaExportColumn::Date => "date".to_string(),
+            DonaExportColumn::Amount => "amount".to_string(),
+            DonaExportColumn::Fees => "fees".to_string(),
+            DonaExportColumn::NetAmount => "net_amount".to_string(),
+            DonaExportColumn::Currency => "currency".to_string(),
+            DonaExportColumn::Method => "method".to_string(),
+            DonaExportColumn::Status => "status".to_string(),
+            DonaExportColumn::Sender => "sender".to_string(),
+            DonaExportColumn::Message => "message".to_string(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct DonaExport {
+    pub id: String,
+    pub format: String,
+    pub columns: Vec<String>,
+    pub status: String,
+    pub row_count: u64,
+    pub error: Option<String>,
+    /// Path of the file, relative to the server. Only set once the export is completed.
+    pub download_url: Option<String>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl From<DonaExportResponse> for DonaExport {
+    fn from(value: DonaExportResponse) -> Self {
+        Self {
+            id: value.id,
+            format: value.format,
+            columns: value.columns,
+            status: value.status,
+            row_count: value.row_count,
+            error: value.error,
+            download_url: value.download_url,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use async_graphql::{Context, Error, InputObject, Object, Result};
+use dona_context::fee::application::create::command::CreateFeeRuleCommand;
+use poem::session::Session;
+use rust_decimal::Decimal;
+use time::{Date, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::{gql_validators::check_admin, CommandBusType};
+
+#[derive(InputObject)]
+pub struct CreateFeeRuleInput {
+    pub id: Uuid,
+    pub method: String,
+    pub percentage: Decimal,
+    /// Required together with `fixed_currency`.
+    pub fixed_amount: Option<Decimal>,
+    #[graphql(validator(chars_min_length = 3, chars_max_length = 3))]
+    pub fixed_currency: Option<String>,
+    pub effective_from: Date,
+    pub created_at: OffsetDateTime,
+}
+
+#[derive(Debug, Default)]
+pub struct CreateFeeRuleMutation;
+
+#[Object]
+impl CreateFeeRuleMutation {
+    /// Rules cannot be edited, a new rule replaces the current one from its effective day.
+    async fn create_fee_rule(&self, ctx: &Context<'_>, input: CreateFeeRuleInput) -> Result<bool> {
+        let command_bus = ctx.data::<CommandBusType>()?;
+        let session = ctx.data::<Session>()?;
+        check_admin(command_bus, session).await?;
+
+        let command = CreateFeeRuleCommand {
+            id: input.id.to_string(),
+            method: input.method,
+            percentage: input.percentage,
+            fixed_amount: input.fixed_amount,
+            fixed_currency: input.fixed_currency,
+            effective_from: input.effective_from,
+            created_at: input.created_at,
+        };
+        command_bus
+            .dispatch(Box::new(command))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,35 @@
+use async_graphql::{Context, Error, Object, Result};
+use dona_context::fee::application::{
+    find_by_method::query::FindFeeRulesByMethodQuery, response::FeeRulesResponse,
+};
+use poem::session::Session;
+
+use crate::{
+    dona::graphql::fee::types::FeeRule, gql_validators::is_authenticated_with_err, QueryBusType,
+};
+
+#[derive(Debug, Default)]
+pub struct FeeRulesQuery;
+
+#[Object]
+impl FeeRulesQuery {
+    /// Every rule the method has had, newest first, so recipients can tell how each of their
+    /// donas was charged.
+    async fn fee_rules(&self, ctx: &Context<'_>, method: String) -> Result<Vec<FeeRule>> {
+        let query_bus = ctx.data::<QueryBusType>()?;
+        let session = ctx.data::<Session>()?;
+        is_authenticated_with_err(session)?;
+
+        let fee_rules = query_bus
+            .ask(Box::new(FindFeeRulesByMethodQuery { method }))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+        let fee_rules: FeeRulesResponse = fee_rules
+            .as_any()
+            .downcast_ref::<FeeRulesResponse>()
+            .unwrap()
+            .clone();
+
+        Ok(fee_rules.fee_rules.into_iter().map(FeeRule::from).collect())
+    }
+}
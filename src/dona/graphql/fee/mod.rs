@@ -0,0 +1,13 @@
+use async_graphql::MergedObject;
+
+use self::{create_mutation::CreateFeeRuleMutation, fee_rules_query::FeeRulesQuery};
+
+mod create_mutation;
+mod fee_rules_query;
+pub mod types;
+
+#[derive(MergedObject, Default)]
+pub struct FeeQuery(FeeRulesQuery);
+
+#[derive(MergedObject, Default)]
+pub struct FeeMutation(CreateFeeRuleMutation);
@@ -0,0 +1,31 @@
+use async_graphql::SimpleObject;
+use dona_context::fee::application::response::FeeRuleResponse;
+use rust_decimal::Decimal;
+use time::{Date, OffsetDateTime};
+
+#[derive(SimpleObject)]
+pub struct FeeRule {
+    pub id: String,
+    pub method: String,
+    pub percentage: Decimal,
+    /// Charged on top of the percentage, converted to the currency of each dona.
+    pub fixed_amount: Option<Decimal>,
+    pub fixed_currency: Option<String>,
+    /// Applies to donas created from this day until the next rule of the method.
+    pub effective_from: Date,
+    pub created_at: OffsetDateTime,
+}
+
+impl From<FeeRuleResponse> for FeeRule {
+    fn from(value: FeeRuleResponse) -> Self {
+        Self {
+            id: value.id,
+            method: value.method,
+            percentage: value.percentage,
+            fixed_amount: value.fixed_amount,
+            fixed_currency: value.fixed_currency,
+            effective_from: value.effective_from,
+            created_at: value.created_at,
+        }
+    }
+}
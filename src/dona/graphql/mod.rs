@@ -0,0 +1,58 @@
+use async_graphql::MergedObject;
+
+use self::{
+    attachment::{AttachmentMutation, AttachmentQuery},
+    campaign::{CampaignMutation, CampaignQuery},
+    dona::{DonaMutation, DonaQuery},
+    exchange_rate::{ExchangeRateMutation, ExchangeRateQuery},
+    export::{ExportMutation, ExportQuery},
+    fee::{FeeMutation, FeeQuery},
+    notification::NotificationQuery,
+    pledge::{PledgeMutation, PledgeQuery},
+    receipt::ReceiptQuery,
+    recipient::{RecipientMutation, RecipientQuery},
+    supporter::{SupporterMutation, SupporterQuery},
+    user_payment_method::UserPaymentMethodQuery,
+};
+
+mod attachment;
+mod campaign;
+mod dona;
+mod exchange_rate;
+mod export;
+mod fee;
+mod notification;
+mod pledge;
+mod receipt;
+mod recipient;
+mod supporter;
+mod user_payment_method;
+
+#[derive(MergedObject, Default)]
+pub struct DonaAppQuery(
+    DonaQuery,
+    ExchangeRateQuery,
+    CampaignQuery,
+    PledgeQuery,
+    ReceiptQuery,
+    SupporterQuery,
+    ExportQuery,
+    AttachmentQuery,
+    UserPaymentMethodQuery,
+    RecipientQuery,
+    FeeQuery,
+    NotificationQuery,
+);
+
+#[derive(MergedObject, Default)]
+pub struct DonaAppMutation(
+    DonaMutation,
+    ExchangeRateMutation,
+    CampaignMutation,
+    PledgeMutation,
+    SupporterMutation,
+    ExportMutation,
+    AttachmentMutation,
+    RecipientMutation,
+    FeeMutation,
+);
@@ -0,0 +1,9 @@
+use async_graphql::MergedObject;
+
+use self::notifications_query::MyNotificationsQuery;
+
+mod notifications_query;
+pub mod types;
+
+#[derive(MergedObject, Default)]
+pub struct NotificationQuery(MyNotificationsQuery);
@@ -0,0 +1,42 @@
+use async_graphql::{Context, Error, Object, Result};
+use dona_context::notification::application::{
+    find_by_user::query::FindNotificationsQuery, response::NotificationsResponse,
+};
+use poem::session::Session;
+
+use crate::{gql_validators::is_authenticated_with_err, QueryBusType};
+
+use super::types::Notification;
+
+#[derive(Debug, Default)]
+pub struct MyNotificationsQuery;
+
+#[Object]
+impl MyNotificationsQuery {
+    /// Latest notifications of the session user, newest first.
+    async fn my_notifications(&self, ctx: &Context<'_>) -> Result<Vec<Notification>> {
+        let session = ctx.data::<Session>()?;
+        is_authenticated_with_err(session)?;
+
+        let user_id = session
+            .get::<String>("user_id")
+            .ok_or(Error::new("UNAUTHORIZED"))?;
+
+        let query_bus = ctx.data::<QueryBusType>()?;
+        let notifications = query_bus
+            .ask(Box::new(FindNotificationsQuery { user_id }))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+        let notifications: NotificationsResponse = notifications
+            .as_any()
+            .downcast_ref::<NotificationsResponse>()
+            .unwrap()
+            .clone();
+
+        Ok(notifications
+            .notifications
+            .into_iter()
+            .map(Notification::from)
+            .collect())
+    }
+}
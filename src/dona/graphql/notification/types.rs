@@ -0,0 +1,26 @@
+use async_graphql::SimpleObject;
+use dona_context::notification::application::response::NotificationResponse;
+use time::OffsetDateTime;
+
+#[derive(SimpleObject)]
+pub struct Notification {
+    pub id: String,
+    /// What happened, `dona_export_completed` once an export can be downloaded.
+    pub kind: String,
+    pub msg: String,
+    /// Path relative to the server, such as the download url of the export.
+    pub link: Option<String>,
+    pub created_at: OffsetDateTime,
+}
+
+impl From<NotificationResponse> for Notification {
+    fn from(value: NotificationResponse) -> Self {
+        Self {
+            id: value.id,
+            kind: value.kind,
+            msg: value.msg,
+            link: value.link,
+            created_at: value.created_at,
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use async_graphql::{Context, Error, InputObject, Object, Result};
+use dona_context::pledge::application::cancel::command::CancelPledgeCommand;
+use poem::session::Session;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{gql_validators::is_authenticated_with_err, CommandBusType};
+
+#[derive(InputObject)]
+pub struct CancelPledgeInput {
+    pub id: Uuid,
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(Debug, Default)]
+pub struct CancelPledgeMutation;
+
+#[Object]
+impl CancelPledgeMutation {
+    async fn cancel_pledge(&self, ctx: &Context<'_>, input: CancelPledgeInput) -> Result<bool> {
+        let command_bus = ctx.data::<CommandBusType>()?;
+        let session = ctx.data::<Session>()?;
+        is_authenticated_with_err(session)?;
+
+        let user_id = session
+            .get::<String>("user_id")
+            .ok_or(Error::new("UNAUTHORIZED"))?;
+
+        let command = CancelPledgeCommand {
+            id: input.id.to_string(),
+            user_id,
+            updated_at: input.updated_at,
+        };
+        command_bus
+            .dispatch(Box::new(command))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+
+        Ok(true)
+    }
+}
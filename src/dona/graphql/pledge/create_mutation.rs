@@ -0,0 +1,63 @@
+use async_graphql::{Context, Error, InputObject, Object, Result};
+use dona_context::pledge::application::create::command::CreatePledgeCommand;
+use poem::session::Session;
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{gql_validators::is_authenticated_with_err, CommandBusType};
+
+#[derive(InputObject)]
+pub struct CreatePledgeInput {
+    pub id: Uuid,
+    #[graphql(validator(chars_min_length = 1, chars_max_length = 500))]
+    pub msg: String,
+    pub amount: Decimal,
+    #[graphql(validator(chars_min_length = 3, chars_max_length = 3))]
+    pub currency: String,
+    /// One of `weekly`, `monthly` or `yearly`.
+    pub interval: String,
+    #[graphql(validator(chars_min_length = 1))]
+    pub method: String,
+    pub user_id: Uuid,
+    /// When the first dona is generated. Defaults to `created_at`.
+    pub first_due_at: Option<OffsetDateTime>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(Debug, Default)]
+pub struct CreatePledgeMutation;
+
+#[Object]
+impl CreatePledgeMutation {
+    async fn create_pledge(&self, ctx: &Context<'_>, input: CreatePledgeInput) -> Result<bool> {
+        let command_bus = ctx.data::<CommandBusType>()?;
+        let session = ctx.data::<Session>()?;
+        is_authenticated_with_err(session)?;
+
+        let sender_id = session
+            .get::<String>("user_id")
+            .ok_or(Error::new("UNAUTHORIZED"))?;
+
+        let command = CreatePledgeCommand {
+            id: input.id.to_string(),
+            msg: input.msg,
+            amount: input.amount,
+            currency: input.currency,
+            interval: input.interval,
+            method: input.method,
+            user_id: input.user_id.to_string(),
+            sender_id,
+            next_due_at: input.first_due_at.unwrap_or(input.created_at),
+            created_at: input.created_at,
+            updated_at: input.updated_at,
+        };
+        command_bus
+            .dispatch(Box::new(command))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+
+        Ok(true)
+    }
+}
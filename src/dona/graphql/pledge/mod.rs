@@ -0,0 +1,26 @@
+use async_graphql::MergedObject;
+
+use self::{
+    cancel_mutation::CancelPledgeMutation, create_mutation::CreatePledgeMutation,
+    pause_mutation::PausePledgeMutation, received_pledges_query::ReceivedPledgesQuery,
+    resume_mutation::ResumePledgeMutation, sent_pledges_query::SentPledgesQuery,
+};
+
+mod cancel_mutation;
+mod create_mutation;
+mod pause_mutation;
+mod received_pledges_query;
+mod resume_mutation;
+mod sent_pledges_query;
+pub mod types;
+
+#[derive(MergedObject, Default)]
+pub struct PledgeQuery(SentPledgesQuery, ReceivedPledgesQuery);
+
+#[derive(MergedObject, Default)]
+pub struct PledgeMutation(
+    CreatePledgeMutation,
+    PausePledgeMutation,
+    ResumePledgeMutation,
+    CancelPledgeMutation,
+);
@@ -0,0 +1,41 @@
+use async_graphql::{Context, Error, InputObject, Object, Result};
+use dona_context::pledge::application::resume::command::ResumePledgeCommand;
+use poem::session::Session;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{gql_validators::is_authenticated_with_err, CommandBusType};
+
+#[derive(InputObject)]
+pub struct ResumePledgeInput {
+    pub id: Uuid,
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(Debug, Default)]
+pub struct ResumePledgeMutation;
+
+#[Object]
+impl ResumePledgeMutation {
+    async fn resume_pledge(&self, ctx: &Context<'_>, input: ResumePledgeInput) -> Result<bool> {
+        let command_bus = ctx.data::<CommandBusType>()?;
+        let session = ctx.data::<Session>()?;
+        is_authenticated_with_err(session)?;
+
+        let user_id = session
+            .get::<String>("user_id")
+            .ok_or(Error::new("UNAUTHORIZED"))?;
+
+        let command = ResumePledgeCommand {
+            id: input.id.to_string(),
+            user_id,
+            updated_at: input.updated_at,
+        };
+        command_bus
+            .dispatch(Box::new(command))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+
+        Ok(true)
+    }
+}
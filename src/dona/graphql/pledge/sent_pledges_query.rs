@@ -0,0 +1,58 @@
+use async_graphql::{Context, Error, Object, Result};
+use dona_context::pledge::application::{
+    find_by_criteria::query::FindPledgesByCriteriaQuery, response::PledgesResponse,
+};
+use poem::session::Session;
+use shared::{
+    domain::criteria::{
+        filter::{Filter, FilterField, FilterOperator, FilterValue},
+        Criteria,
+    },
+    infrastructure::criteria::async_graphql::CriteriaGql,
+};
+
+use crate::{
+    dona::graphql::pledge::types::{into_pledge_connection, PledgeConnection},
+    gql_validators::is_authenticated_with_err,
+    QueryBusType,
+};
+
+#[derive(Debug, Default)]
+pub struct SentPledgesQuery;
+
+#[Object]
+impl SentPledgesQuery {
+    /// Pledges the session user makes to others.
+    async fn my_sent_pledges(
+        &self,
+        ctx: &Context<'_>,
+        criteria: CriteriaGql,
+    ) -> Result<PledgeConnection> {
+        let session = ctx.data::<Session>()?;
+        is_authenticated_with_err(session)?;
+
+        let user_id = session
+            .get::<String>("user_id")
+            .ok_or(Error::new("UNAUTHORIZED"))?;
+
+        let criteria: Criteria = criteria.try_into()?;
+        let criteria = criteria.with_filter(Filter::new(
+            FilterField::try_from("sender_id".to_string())?,
+            FilterOperator::Equal,
+            FilterValue::try_from(user_id)?,
+        ));
+
+        let query_bus = ctx.data::<QueryBusType>()?;
+        let pledges = query_bus
+            .ask(Box::new(FindPledgesByCriteriaQuery { criteria }))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+        let pledges: PledgesResponse = pledges
+            .as_any()
+            .downcast_ref::<PledgesResponse>()
+            .unwrap()
+            .clone();
+
+        Ok(into_pledge_connection(pledges))
+    }
+}
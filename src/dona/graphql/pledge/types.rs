@@ -0,0 +1,56 @@
+use async_graphql::{connection::Connection, connection::Edge, SimpleObject};
+use dona_context::pledge::application::response::{PledgeResponse, PledgesResponse};
+use rust_decimal::Decimal;
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+#[derive(SimpleObject)]
+pub struct Pledge {
+    pub id: String,
+    pub msg: String,
+    pub amount: Decimal,
+    pub currency: String,
+    pub interval: String,
+    pub method: String,
+    pub user_id: String,
+    pub sender_id: String,
+    pub status: String,
+    /// When the scheduler will generate the next pending dona for this pledge.
+    pub next_due_at: OffsetDateTime,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl From<PledgeResponse> for Pledge {
+    fn from(value: PledgeResponse) -> Self {
+        Self {
+            id: value.id,
+            msg: value.msg,
+            amount: value.amount,
+            currency: value.currency,
+            interval: value.interval,
+            method: value.method,
+            user_id: value.user_id,
+            sender_id: value.sender_id,
+            status: value.status,
+            next_due_at: value.next_due_at,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+pub type PledgeConnection = Connection<String, Pledge>;
+
+pub fn into_pledge_connection(value: PledgesResponse) -> PledgeConnection {
+    let mut connection = Connection::new(value.has_previous_page, value.has_next_page);
+    connection
+        .edges
+        .extend(value.pledges.into_iter().map(|pledge| {
+            Edge::new(
+                pledge.created_at.format(&Rfc3339).unwrap_or_default(),
+                pledge.into(),
+            )
+        }));
+
+    connection
+}
@@ -0,0 +1,8 @@
+use async_graphql::MergedObject;
+
+use self::receipt_query::DonaReceiptQuery;
+
+mod receipt_query;
+
+#[derive(MergedObject, Default)]
+pub struct ReceiptQuery(DonaReceiptQuery);
@@ -0,0 +1,87 @@
+use async_graphql::{Context, Enum, Error, Object, Result, SimpleObject};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use dona_context::receipt::application::{
+    download::query::DownloadReceiptQuery, response::ReceiptFileResponse,
+};
+use poem::session::Session;
+use uuid::Uuid;
+
+use crate::{
+    gql_validators::{check_admin, is_authenticated_with_err},
+    CommandBusType, QueryBusType,
+};
+
+#[derive(Enum, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ReceiptFormat {
+    Html,
+    #[default]
+    Pdf,
+}
+
+impl From<ReceiptFormat> for String {
+    fn from(value: ReceiptFormat) -> Self {
+        match value {
+            ReceiptFormat::Html => "html".to_string(),
+            ReceiptFormat::Pdf => "pdf".to_string(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct ReceiptFile {
+    pub filename: String,
+    pub content_type: String,
+    /// The document encoded as standard base64.
+    pub content: String,
+}
+
+impl From<ReceiptFileResponse> for ReceiptFile {
+    fn from(value: ReceiptFileResponse) -> Self {
+        Self {
+            filename: value.filename,
+            content_type: value.content_type,
+            content: STANDARD.encode(value.content),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct DonaReceiptQuery;
+
+#[Object]
+impl DonaReceiptQuery {
+    /// Receipt of a confirmed dona. Only its sender, its recipient or an admin may download it.
+    async fn dona_receipt(
+        &self,
+        ctx: &Context<'_>,
+        dona_id: Uuid,
+        #[graphql(default)] format: ReceiptFormat,
+    ) -> Result<ReceiptFile> {
+        let session = ctx.data::<Session>()?;
+        is_authenticated_with_err(session)?;
+
+        let user_id = session
+            .get::<String>("user_id")
+            .ok_or(Error::new("UNAUTHORIZED"))?;
+        let command_bus = ctx.data::<CommandBusType>()?;
+        let is_admin = check_admin(command_bus, session).await.is_ok();
+
+        let query_bus = ctx.data::<QueryBusType>()?;
+        let receipt = query_bus
+            .ask(Box::new(DownloadReceiptQuery {
+                dona_id: dona_id.to_string(),
+                format: format.into(),
+                requester_id: user_id,
+                is_admin,
+            }))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+        let receipt: ReceiptFileResponse = receipt
+            .as_any()
+            .downcast_ref::<ReceiptFileResponse>()
+            .unwrap()
+            .clone();
+
+        Ok(receipt.into())
+    }
+}
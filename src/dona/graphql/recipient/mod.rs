@@ -0,0 +1,13 @@
+use async_graphql::MergedObject;
+
+use self::{settings_mutation::RecipientSettingsMutation, settings_query::RecipientSettingsQuery};
+
+mod settings_mutation;
+mod settings_query;
+pub mod types;
+
+#[derive(MergedObject, Default)]
+pub struct RecipientQuery(RecipientSettingsQuery);
+
+#[derive(MergedObject, Default)]
+pub struct RecipientMutation(RecipientSettingsMutation);
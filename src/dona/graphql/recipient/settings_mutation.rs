@@ -0,0 +1,57 @@
+use async_graphql::{Context, Error, InputObject, Object, Result};
+use dona_context::recipient::application::update::command::UpdateRecipientSettingsCommand;
+use poem::session::Session;
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
+
+use crate::{gql_validators::is_authenticated_with_err, CommandBusType};
+
+#[derive(InputObject)]
+pub struct UpdateRecipientSettingsInput {
+    pub min_amount: Option<Decimal>,
+    pub max_amount: Option<Decimal>,
+    /// Required when a minimum or a maximum is given.
+    #[graphql(validator(chars_min_length = 3, chars_max_length = 3))]
+    pub currency: Option<String>,
+    #[graphql(validator(min_items = 1))]
+    pub active_methods: Vec<String>,
+    pub messages_allowed: bool,
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(Debug, Default)]
+pub struct RecipientSettingsMutation;
+
+#[Object]
+impl RecipientSettingsMutation {
+    /// Settings of the session user as a recipient.
+    async fn update_recipient_settings(
+        &self,
+        ctx: &Context<'_>,
+        input: UpdateRecipientSettingsInput,
+    ) -> Result<bool> {
+        let command_bus = ctx.data::<CommandBusType>()?;
+        let session = ctx.data::<Session>()?;
+        is_authenticated_with_err(session)?;
+
+        let user_id = session
+            .get::<String>("user_id")
+            .ok_or(Error::new("UNAUTHORIZED"))?;
+
+        let command = UpdateRecipientSettingsCommand {
+            user_id,
+            min_amount: input.min_amount,
+            max_amount: input.max_amount,
+            currency: input.currency,
+            active_methods: input.active_methods,
+            messages_allowed: input.messages_allowed,
+            updated_at: input.updated_at,
+        };
+        command_bus
+            .dispatch(Box::new(command))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,35 @@
+use async_graphql::{Context, Error, Object, Result};
+use dona_context::recipient::application::{
+    find::query::FindRecipientSettingsQuery, response::RecipientSettingsResponse,
+};
+use uuid::Uuid;
+
+use crate::{dona::graphql::recipient::types::RecipientSettings, QueryBusType};
+
+#[derive(Debug, Default)]
+pub struct RecipientSettingsQuery;
+
+#[Object]
+impl RecipientSettingsQuery {
+    /// Public, so donation forms can show the limits and methods before a dona is sent.
+    async fn recipient_settings(
+        &self,
+        ctx: &Context<'_>,
+        user_id: Uuid,
+    ) -> Result<RecipientSettings> {
+        let query_bus = ctx.data::<QueryBusType>()?;
+        let settings = query_bus
+            .ask(Box::new(FindRecipientSettingsQuery {
+                user_id: user_id.to_string(),
+            }))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+        let settings: RecipientSettingsResponse = settings
+            .as_any()
+            .downcast_ref::<RecipientSettingsResponse>()
+            .unwrap()
+            .clone();
+
+        Ok(settings.into())
+    }
+}
@@ -0,0 +1,31 @@
+use async_graphql::SimpleObject;
+use dona_context::recipient::application::response::RecipientSettingsResponse;
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
+
+#[derive(SimpleObject)]
+pub struct RecipientSettings {
+    pub user_id: String,
+    /// Limits are in `currency`, donas in other currencies are converted to compare them.
+    pub min_amount: Option<Decimal>,
+    pub max_amount: Option<Decimal>,
+    pub currency: Option<String>,
+    pub active_methods: Vec<String>,
+    pub messages_allowed: bool,
+    /// Empty while the recipient uses the defaults.
+    pub updated_at: Option<OffsetDateTime>,
+}
+
+impl From<RecipientSettingsResponse> for RecipientSettings {
+    fn from(value: RecipientSettingsResponse) -> Self {
+        Self {
+            user_id: value.user_id,
+            min_amount: value.min_amount,
+            max_amount: value.max_amount,
+            currency: value.currency,
+            active_methods: value.active_methods,
+            messages_allowed: value.messages_allowed,
+            updated_at: value.updated_at,
+        }
+    }
+}
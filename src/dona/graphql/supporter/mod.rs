@@ -0,0 +1,15 @@
+use async_graphql::MergedObject;
+
+use self::{
+    preferences_mutation::SupporterPreferencesMutation, top_supporters_query::TopSupportersQuery,
+};
+
+mod preferences_mutation;
+mod top_supporters_query;
+pub mod types;
+
+#[derive(MergedObject, Default)]
+pub struct SupporterQuery(TopSupportersQuery);
+
+#[derive(MergedObject, Default)]
+pub struct SupporterMutation(SupporterPreferencesMutation);
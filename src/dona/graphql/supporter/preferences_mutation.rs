@@ -0,0 +1,45 @@
+use async_graphql::{Context, Error, InputObject, Object, Result};
+use dona_context::supporter::application::update_preferences::command::UpdateSupporterPreferencesCommand;
+use poem::session::Session;
+use time::OffsetDateTime;
+
+use crate::{gql_validators::is_authenticated_with_err, CommandBusType};
+
+#[derive(InputObject)]
+pub struct UpdateSupporterPreferencesInput {
+    pub hidden_from_leaderboards: bool,
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(Debug, Default)]
+pub struct SupporterPreferencesMutation;
+
+#[Object]
+impl SupporterPreferencesMutation {
+    /// Preferences of the session user as a donor.
+    async fn update_supporter_preferences(
+        &self,
+        ctx: &Context<'_>,
+        input: UpdateSupporterPreferencesInput,
+    ) -> Result<bool> {
+        let command_bus = ctx.data::<CommandBusType>()?;
+        let session = ctx.data::<Session>()?;
+        is_authenticated_with_err(session)?;
+
+        let user_id = session
+            .get::<String>("user_id")
+            .ok_or(Error::new("UNAUTHORIZED"))?;
+
+        let command = UpdateSupporterPreferencesCommand {
+            user_id,
+            hidden_from_leaderboards: input.hidden_from_leaderboards,
+            updated_at: input.updated_at,
+        };
+        command_bus
+            .dispatch(Box::new(command))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,62 @@
+use async_graphql::{Context, Error, Object, Result};
+use dona_context::supporter::application::{
+    response::TopSupportersResponse, top::query::GetTopSupportersQuery,
+};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+    dona::graphql::supporter::types::{
+        into_top_supporter_connection, LeaderboardPeriod, TopSupporterConnection,
+    },
+    QueryBusType,
+};
+
+#[derive(Debug, Default)]
+pub struct TopSupportersQuery;
+
+#[Object]
+impl TopSupportersQuery {
+    /// Public leaderboard of the registered users that gave the most to `userId` in
+    /// `currency`. Anonymous donas and users hidden from leaderboards are not listed. Pass the
+    /// cursor of the last edge as `after` to get the next page.
+    async fn top_supporters(
+        &self,
+        ctx: &Context<'_>,
+        user_id: Uuid,
+        currency: String,
+        #[graphql(default)] period: LeaderboardPeriod,
+        from: Option<OffsetDateTime>,
+        to: Option<OffsetDateTime>,
+        #[graphql(default = 10)] first: u64,
+        after: Option<String>,
+    ) -> Result<TopSupporterConnection> {
+        let offset = match after {
+            Some(after) => after
+                .parse::<u64>()
+                .map_err(|_| Error::new("Invalid cursor"))?,
+            None => 0,
+        };
+
+        let query_bus = ctx.data::<QueryBusType>()?;
+        let supporters = query_bus
+            .ask(Box::new(GetTopSupportersQuery {
+                user_id: user_id.to_string(),
+                currency,
+                period: period.into(),
+                from,
+                to,
+                first,
+                offset,
+            }))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+        let supporters: TopSupportersResponse = supporters
+            .as_any()
+            .downcast_ref::<TopSupportersResponse>()
+            .unwrap()
+            .clone();
+
+        Ok(into_top_supporter_connection(supporters))
+    }
+}
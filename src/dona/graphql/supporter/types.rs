@@ -0,0 +1,65 @@
+use async_graphql::{connection::Connection, connection::Edge, Enum, SimpleObject};
+use dona_context::supporter::application::response::{TopSupporterResponse, TopSupportersResponse};
+use rust_decimal::Decimal;
+
+#[derive(Enum, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum LeaderboardPeriod {
+    #[default]
+    AllTime,
+    Last30Days,
+    /// Reads the `from` and `to` arguments.
+    Custom,
+}
+
+impl From<LeaderboardPeriod> for String {
+    fn from(value: LeaderboardPeriod) -> Self {
+        match value {
+            LeaderboardPeriod::AllTime => "all_time".to_string(),
+            LeaderboardPeriod::Last30Days => "last_30_days".to_string(),
+            LeaderboardPeriod::Custom => "custom".to_string(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct TopSupporter {
+    pub rank: u64,
+    pub sender_id: String,
+    pub username: Option<String>,
+    pub full_name: Option<String>,
+    pub profile_picture: Option<String>,
+    /// Confirmed total given to the recipient in the requested currency.
+    pub amount: Decimal,
+    pub currency: String,
+    pub count: u64,
+}
+
+impl From<TopSupporterResponse> for TopSupporter {
+    fn from(value: TopSupporterResponse) -> Self {
+        Self {
+            rank: value.rank,
+            sender_id: value.sender_id,
+            username: value.username,
+            full_name: value.full_name,
+            profile_picture: value.profile_picture,
+            amount: value.amount,
+            currency: value.currency,
+            count: value.count,
+        }
+    }
+}
+
+pub type TopSupporterConnection = Connection<String, TopSupporter>;
+
+/// The cursor of an edge is the rank of the supporter.
+pub fn into_top_supporter_connection(value: TopSupportersResponse) -> TopSupporterConnection {
+    let mut connection = Connection::new(value.has_previous_page, value.has_next_page);
+    connection.edges.extend(
+        value
+            .supporters
+            .into_iter()
+            .map(|supporter| Edge::new(supporter.rank.to_string(), supporter.into())),
+    );
+
+    connection
+}
@@ -0,0 +1,8 @@
+use async_graphql::MergedObject;
+
+use self::qr_code_query::UserPaymentMethodQrCodeQuery;
+
+mod qr_code_query;
+
+#[derive(MergedObject, Default)]
+pub struct UserPaymentMethodQuery(UserPaymentMethodQrCodeQuery);
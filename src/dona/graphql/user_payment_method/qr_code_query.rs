@@ -0,0 +1,73 @@
+use async_graphql::{Context, Enum, Error, Object, Result, SimpleObject};
+use dona_context::user_payment_method::application::{
+    get_qr_code::query::GetUserPaymentMethodQrCodeQuery, response::UserPaymentMethodQrCodeResponse,
+};
+use uuid::Uuid;
+
+use crate::QueryBusType;
+
+#[derive(Enum, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum QrCodeFormat {
+    #[default]
+    Png,
+    Svg,
+}
+
+impl From<QrCodeFormat> for String {
+    fn from(value: QrCodeFormat) -> Self {
+        match value {
+            QrCodeFormat::Png => "png".to_string(),
+            QrCodeFormat::Svg => "svg".to_string(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct PaymentQrCode {
+    /// Served from `/media`. The query string changes with the instructions of the method.
+    pub url: String,
+    pub content_type: String,
+}
+
+impl From<UserPaymentMethodQrCodeResponse> for PaymentQrCode {
+    fn from(value: UserPaymentMethodQrCodeResponse) -> Self {
+        Self {
+            url: format!(
+                "/media/{}?v={}",
+                value.path,
+                value.updated_at.unix_timestamp()
+            ),
+            content_type: value.content_type,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct UserPaymentMethodQrCodeQuery;
+
+#[Object]
+impl UserPaymentMethodQrCodeQuery {
+    /// QR code to scan the instructions of a payment method, open to donors without an account.
+    async fn user_payment_method_qr_code(
+        &self,
+        ctx: &Context<'_>,
+        id: Uuid,
+        #[graphql(default)] format: QrCodeFormat,
+    ) -> Result<PaymentQrCode> {
+        let query_bus = ctx.data::<QueryBusType>()?;
+        let qr_code = query_bus
+            .ask(Box::new(GetUserPaymentMethodQrCodeQuery {
+                id: id.to_string(),
+                format: format.into(),
+            }))
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+        let qr_code: UserPaymentMethodQrCodeResponse = qr_code
+            .as_any()
+            .downcast_ref::<UserPaymentMethodQrCodeResponse>()
+            .unwrap()
+            .clone();
+
+        Ok(qr_code.into())
+    }
+}
@@ -1 +1,5 @@
 pub mod di;
+pub mod export_worker;
+pub mod graphql;
+pub mod pledge_scheduler;
+pub mod sweeper;
@@ -0,0 +1,100 @@
+use std::time::Duration;
+
+use dona_context::pledge::application::charge_due::command::ChargeDuePledgesCommand;
+use redis::Client as RedisClient;
+use sea_orm::DatabaseConnection;
+use shared::{
+    domain::{bus::command::CommandBus, utils::new_uuid},
+    infrastructure::bus::{command::InMemoryCommandBus, query::InMemoryQueryBus},
+};
+use time::OffsetDateTime;
+use tokio::task::JoinHandle;
+
+use super::{
+    di::{dona_app_di, dona_app_event_handlers_di},
+    sweeper::{acquire_lock, release_lock},
+};
+
+const PLEDGE_SCHEDULER_LOCK_KEY: &str = "lock:dona_pledge_scheduler";
+const DEFAULT_PLEDGE_INTERVAL_SECONDS: u64 = 60 * 15;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PledgeSchedulerConfig {
+    /// How often the scheduler looks for pledges that are due.
+    pub interval: Duration,
+}
+
+impl Default for PledgeSchedulerConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(DEFAULT_PLEDGE_INTERVAL_SECONDS),
+        }
+    }
+}
+
+impl PledgeSchedulerConfig {
+    /// Reads `DONA_PLEDGE_INTERVAL_SECONDS`, falling back to the default when it is missing or
+    /// invalid.
+    pub fn from_env() -> Self {
+        let interval = std::env::var("DONA_PLEDGE_INTERVAL_SECONDS")
+            .ok()
+            .and_then(|value| value.parse::<u64>().ok())
+            .filter(|value| *value > 0)
+            .map(Duration::from_secs);
+
+        Self {
+            interval: interval.unwrap_or(Self::default().interval),
+        }
+    }
+}
+
+async fn charge(
+    db: &DatabaseConnection,
+    redis: &RedisClient,
+    config: &PledgeSchedulerConfig,
+) -> Result<(), String> {
+    let token = new_uuid();
+    if !acquire_lock(redis, PLEDGE_SCHEDULER_LOCK_KEY, &token, config.interval).await? {
+        return Ok(());
+    }
+
+    let mut command_bus = InMemoryCommandBus::default();
+    let mut query_bus = InMemoryQueryBus::default();
+    dona_app_di(
+        &mut command_bus,
+        &mut query_bus,
+        dona_app_event_handlers_di(db),
+        db,
+    );
+
+    let result = command_bus
+        .dispatch(Box::new(ChargeDuePledgesCommand {
+            now: OffsetDateTime::now_utc(),
+        }))
+        .await
+        .map_err(|e| e.to_string());
+
+    release_lock(redis, PLEDGE_SCHEDULER_LOCK_KEY, &token).await?;
+
+    result
+}
+
+/// Periodically turns every due pledge into a pending dona. Like the sweeper, it runs on every
+/// instance but only charges on the instance holding the Redis lock.
+pub fn spawn_pledge_scheduler(
+    db: DatabaseConnection,
+    redis: RedisClient,
+    config: PledgeSchedulerConfig,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(config.interval);
+
+        loop {
+            interval.tick().await;
+
+            if let Err(e) = charge(&db, &redis, &config).await {
+                eprintln!("Failed to charge due pledges: {}", e);
+            }
+        }
+    })
+}
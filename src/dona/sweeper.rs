@@ -0,0 +1,164 @@
+use std::time::Duration;
+
+use dona_context::dona::application::expire_stale::command::ExpireStaleDonasCommand;
+use redis::Client as RedisClient;
+use sea_orm::DatabaseConnection;
+use shared::{
+    domain::{bus::command::CommandBus, utils::new_uuid},
+    infrastructure::bus::{command::InMemoryCommandBus, query::InMemoryQueryBus},
+};
+use time::OffsetDateTime;
+use tokio::task::JoinHandle;
+
+use super::di::{dona_app_di, dona_app_event_handlers_di};
+
+const SWEEPER_LOCK_KEY: &str = "lock:dona_sweeper";
+const DEFAULT_PENDING_TTL_SECONDS: u64 = 60 * 60 * 24 * 7;
+const DEFAULT_SWEEP_INTERVAL_SECONDS: u64 = 60 * 10;
+
+/// Only deletes the lock if it still holds our token, so an instance whose sweep outlived the
+/// lock cannot release a lock that another instance has acquired since.
+const RELEASE_LOCK_SCRIPT: &str = r#"
+if redis.call("GET", KEYS[1]) == ARGV[1] then
+    return redis.call("DEL", KEYS[1])
+else
+    return 0
+end
+"#;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DonaSweeperConfig {
+    /// How long a dona may stay pending before it is expired.
+    pub pending_ttl: Duration,
+    /// How often the sweeper looks for stale donas.
+    pub interval: Duration,
+}
+
+impl Default for DonaSweeperConfig {
+    fn default() -> Self {
+        Self {
+            pending_ttl: Duration::from_secs(DEFAULT_PENDING_TTL_SECONDS),
+            interval: Duration::from_secs(DEFAULT_SWEEP_INTERVAL_SECONDS),
+        }
+    }
+}
+
+impl DonaSweeperConfig {
+    /// Reads `DONA_PENDING_TTL_SECONDS` and `DONA_SWEEP_INTERVAL_SECONDS`, falling back to the
+    /// defaults when they are missing or invalid.
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        let seconds = |name: &str| {
+            std::env::var(name)
+                .ok()
+                .and_then(|value| value.parse::<u64>().ok())
+                .filter(|value| *value > 0)
+                .map(Duration::from_secs)
+        };
+
+        Self {
+            pending_ttl: seconds("DONA_PENDING_TTL_SECONDS").unwrap_or(default.pending_ttl),
+            interval: seconds("DONA_SWEEP_INTERVAL_SECONDS").unwrap_or(default.interval),
+        }
+    }
+}
+
+/// Takes the lock at `key` for `ttl` unless another instance already holds it.
+pub(super) async fn acquire_lock(
+    redis: &RedisClient,
+    key: &str,
+    token: &str,
+    ttl: Duration,
+) -> Result<bool, String> {
+    let mut conn = redis
+        .get_multiplexed_tokio_connection()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let acquired: Option<String> = redis::cmd("SET")
+        .arg(key)
+        .arg(token)
+        .arg("NX")
+        .arg("PX")
+        .arg(ttl.as_millis() as u64)
+        .query_async(&mut conn)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(acquired.is_some())
+}
+
+pub(super) async fn release_lock(
+    redis: &RedisClient,
+    key: &str,
+    token: &str,
+) -> Result<(), String> {
+    let mut conn = redis
+        .get_multiplexed_tokio_connection()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    redis::Script::new(RELEASE_LOCK_SCRIPT)
+        .key(key)
+        .arg(token)
+        .invoke_async::<_, i32>(&mut conn)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+async fn sweep(
+    db: &DatabaseConnection,
+    redis: &RedisClient,
+    config: &DonaSweeperConfig,
+) -> Result<(), String> {
+    let token = new_uuid();
+    if !acquire_lock(redis, SWEEPER_LOCK_KEY, &token, config.interval).await? {
+        return Ok(());
+    }
+
+    let mut command_bus = InMemoryCommandBus::default();
+    let mut query_bus = InMemoryQueryBus::default();
+    dona_app_di(
+        &mut command_bus,
+        &mut query_bus,
+        dona_app_event_handlers_di(db),
+        db,
+    );
+
+    let now = OffsetDateTime::now_utc();
+    let result = command_bus
+        .dispatch(Box::new(ExpireStaleDonasCommand {
+            older_than: now - config.pending_ttl,
+            updated_at: now,
+        }))
+        .await
+        .map_err(|e| e.to_string());
+
+    release_lock(redis, SWEEPER_LOCK_KEY, &token).await?;
+
+    result
+}
+
+/// Periodically expires donas that have been pending for longer than the configured TTL.
+///
+/// Every instance runs its own sweeper, but only the one holding the Redis lock sweeps on a
+/// given tick.
+pub fn spawn_dona_sweeper(
+    db: DatabaseConnection,
+    redis: RedisClient,
+    config: DonaSweeperConfig,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(config.interval);
+
+        loop {
+            interval.tick().await;
+
+            if let Err(e) = sweep(&db, &redis, &config).await {
+                eprintln!("Failed to expire stale donas: {}", e);
+            }
+        }
+    })
+}
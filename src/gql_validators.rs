@@ -4,7 +4,7 @@ use security::session::application::check_permission::command::CheckPermissionCo
 
 use crate::{CommandBusType, MAX_UPLOAD_SIZE};
 
-const IMAGE_TYPES: [&str; 8] = [
+pub const IMAGE_TYPES: [&str; 8] = [
     "image/jpg",
     "image/jpeg",
     "image/png",
@@ -15,17 +15,29 @@ const IMAGE_TYPES: [&str; 8] = [
     "image/heic",
 ];
 
-pub fn check_upload(value: &Option<UploadValue>) -> Result<(), Error> {
+pub const CSV_TYPES: [&str; 3] = ["text/csv", "text/plain", "application/vnd.ms-excel"];
+
+/// Proofs of payment, either a picture of the receipt or the PDF sent by the bank.
+pub const ATTACHMENT_TYPES: [&str; 5] = [
+    "image/jpeg",
+    "image/png",
+    "image/gif",
+    "image/webp",
+    "application/pdf",
+];
+
+/// Checks the size of an optional upload and that its content type is one of `allowed_types`.
+pub fn check_upload(value: Option<&UploadValue>, allowed_types: &[&str]) -> Result<(), Error> {
     match value {
         Some(value) => {
             if value.size().map_err(|e| Error::new(e.to_string()))? > MAX_UPLOAD_SIZE {
                 return Err(Error::new("File too large"));
             }
 
-            if IMAGE_TYPES.contains(&value.content_type.clone().unwrap_or_default().as_str()) {
+            if allowed_types.contains(&value.content_type.clone().unwrap_or_default().as_str()) {
                 Ok(())
             } else {
-                Err(Error::new("Invalid image type"))
+                Err(Error::new("Invalid file type"))
             }
         }
         None => Ok(()),
@@ -72,3 +84,21 @@ pub async fn check_permission(bus: &CommandBusType, session: &Session) -> Result
         .await
         .map_err(|e| Error::new(e.to_string()))
 }
+
+pub async fn check_permission_for_user(
+    bus: &CommandBusType,
+    session: &Session,
+    user_id: String,
+) -> Result<(), Error> {
+    let session_id = session
+        .get::<String>("session_id")
+        .ok_or(Error::new("UNAUTHORIZED"))?;
+    let check_perm_command = CheckPermissionCommand {
+        session_id,
+        user_id: Some(user_id),
+    };
+
+    bus.dispatch(Box::new(check_perm_command))
+        .await
+        .map_err(|e| Error::new(e.to_string()))
+}
@@ -1,6 +1,9 @@
 use async_graphql::{EmptySubscription, MergedObject, Object, Schema};
 
-use crate::backoffice_app::graphql::{BackofficeMutation, BackofficeQuery};
+use crate::{
+    backoffice_app::graphql::{BackofficeMutation, BackofficeQuery},
+    dona::graphql::{DonaAppMutation, DonaAppQuery},
+};
 
 #[derive(Default)]
 pub struct BaseQuery;
@@ -13,9 +16,9 @@ impl BaseQuery {
 }
 
 #[derive(MergedObject, Default)]
-pub struct Query(BaseQuery, BackofficeQuery);
+pub struct Query(BaseQuery, BackofficeQuery, DonaAppQuery);
 
 #[derive(MergedObject, Default)]
-pub struct Mutation(BackofficeMutation);
+pub struct Mutation(BackofficeMutation, DonaAppMutation);
 
 pub type DonaSchema = Schema<Query, Mutation, EmptySubscription>;
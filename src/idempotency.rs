@@ -0,0 +1,131 @@
+use std::{sync::Arc, time::Duration};
+
+use backoffice::auth::application::create_user::command::CREATE_USER_COMMAND_TYPE;
+use dona_context::{
+    campaign::application::create::command::CREATE_CAMPAIGN_COMMAND_TYPE,
+    dona::application::{
+        create::command::CREATE_DONA_COMMAND_TYPE,
+        create_reply::command::CREATE_DONA_REPLY_COMMAND_TYPE,
+    },
+    fee::application::create::command::CREATE_FEE_RULE_COMMAND_TYPE,
+    pledge::application::create::command::CREATE_PLEDGE_COMMAND_TYPE,
+    user_payment_method::application::create::command::CREATE_USER_PAYMENT_METHOD_COMMAND_TYPE,
+};
+use poem::{http::HeaderMap, session::Session};
+use redis::Client as RedisClient;
+use sha3::{Digest, Sha3_256};
+use shared::{
+    domain::{
+        bus::command::CommandBus,
+        idempotency::{IdempotencyKey, ERR_INVALID_IDEMPOTENCY_KEY},
+        utils::new_uuid,
+    },
+    infrastructure::{
+        bus::idempotent_command::IdempotentCommandBus, idempotency::RedisIdempotencyRepository,
+    },
+};
+
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+const GUEST_SESSION_ID: &str = "guest_session_id";
+const DEFAULT_IDEMPOTENCY_KEY_TTL_SECONDS: u64 = 60 * 60 * 24;
+
+/// Commands of the create mutations, which mobile clients retry when a response gets lost.
+const IDEMPOTENT_COMMAND_TYPES: [&str; 7] = [
+    CREATE_USER_COMMAND_TYPE,
+    CREATE_USER_PAYMENT_METHOD_COMMAND_TYPE,
+    CREATE_DONA_COMMAND_TYPE,
+    CREATE_CAMPAIGN_COMMAND_TYPE,
+    CREATE_PLEDGE_COMMAND_TYPE,
+    CREATE_FEE_RULE_COMMAND_TYPE,
+    CREATE_DONA_REPLY_COMMAND_TYPE,
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdempotencyConfig {
+    /// How long a key is remembered after its request.
+    pub ttl: Duration,
+}
+
+impl Default for IdempotencyConfig {
+    fn default() -> Self {
+        Self {
+            ttl: Duration::from_secs(DEFAULT_IDEMPOTENCY_KEY_TTL_SECONDS),
+        }
+    }
+}
+
+impl IdempotencyConfig {
+    /// Reads `IDEMPOTENCY_KEY_TTL_SECONDS`, falling back to the default when it is missing or
+    /// invalid.
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        let seconds = |name: &str| {
+            std::env::var(name)
+                .ok()
+                .and_then(|value| value.parse::<u64>().ok())
+                .filter(|value| *value > 0)
+                .map(Duration::from_secs)
+        };
+
+        Self {
+            ttl: seconds("IDEMPOTENCY_KEY_TTL_SECONDS").unwrap_or(default.ttl),
+        }
+    }
+}
+
+/// Wraps the command bus of a GraphQL request so its create commands are applied only once
+/// per `Idempotency-Key`. Keys are scoped to the user sending them, so two users picking the
+/// same key do not collide. Guests are scoped to their session, so their retries are only
+/// recognised when they keep the session cookie.
+pub fn idempotent_command_bus<B: CommandBus>(
+    command_bus: B,
+    redis: &RedisClient,
+    config: &IdempotencyConfig,
+    headers: &HeaderMap,
+    session: &Session,
+    request: &async_graphql::Request,
+) -> Result<IdempotentCommandBus<B>, String> {
+    let command_bus = IdempotentCommandBus::new(
+        command_bus,
+        Arc::new(RedisIdempotencyRepository::new(Arc::new(redis.clone()))),
+        &IDEMPOTENT_COMMAND_TYPES,
+        config.ttl,
+    );
+
+    let Some(key) = headers.get(IDEMPOTENCY_KEY_HEADER) else {
+        return Ok(command_bus);
+    };
+    let key = key
+        .to_str()
+        .map_err(|_| ERR_INVALID_IDEMPOTENCY_KEY.to_string())?;
+    let key = IdempotencyKey::new(key.to_string())?;
+
+    let scope = match session.get::<String>("user_id") {
+        Some(user_id) => user_id,
+        None => format!("guest:{}", guest_session_id(session)),
+    };
+
+    Ok(command_bus.with_key(format!("{}:{}", scope, key.value()), fingerprint(request)))
+}
+
+/// Guests have no user id, so their session gets an id of its own the first time they send a
+/// key.
+fn guest_session_id(session: &Session) -> String {
+    session.get::<String>(GUEST_SESSION_ID).unwrap_or_else(|| {
+        let id = new_uuid();
+        session.set(GUEST_SESSION_ID, id.clone());
+        id
+    })
+}
+
+/// Tells a retry apart from a different request reusing the key.
+fn fingerprint(request: &async_graphql::Request) -> String {
+    let payload =
+        serde_json::to_string(&(&request.query, &request.operation_name, &request.variables))
+            .unwrap_or_default();
+
+    Sha3_256::digest(payload.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
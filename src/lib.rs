@@ -6,6 +6,7 @@ pub mod backoffice_app;
 pub mod dona;
 pub mod gql_validators;
 pub mod graphql;
+pub mod idempotency;
 pub mod security;
 pub mod server;
 
@@ -1,24 +1,40 @@
 use std::sync::Arc;
 
 use crate::backoffice_app::di::backoffice_app_di;
-use crate::dona::di::dona_app_di;
+use crate::dona::di::{dona_app_di, dona_app_event_handlers_di};
+use crate::dona::export_worker::{spawn_dona_export_worker, DonaExportWorkerConfig};
+use crate::dona::pledge_scheduler::{spawn_pledge_scheduler, PledgeSchedulerConfig};
+use crate::dona::sweeper::{spawn_dona_sweeper, DonaSweeperConfig};
+use crate::gql_validators::is_authenticated;
 use crate::graphql::{DonaSchema, Mutation, Query};
+use crate::idempotency::{idempotent_command_bus, IdempotencyConfig};
 use crate::security::di::security_app_di;
 use crate::{CommandBusType, QueryBusType};
-use async_graphql::{http::GraphiQLSource, EmptySubscription, Schema};
+use async_graphql::{http::GraphiQLSource, EmptySubscription, Schema, ServerError};
 use async_graphql_poem::{GraphQLRequest, GraphQLResponse};
+use dona_context::dona::application::confirm_payment::command::ConfirmDonaPaymentCommand;
+use dona_context::export::application::{
+    download::query::DownloadDonaExportQuery, response::DonaExportFileResponse,
+};
+use dona_context::export::domain::dona_export::{
+    DONA_EXPORT_DOWNLOAD_PATH, ERR_DONA_EXPORT_NOT_READY,
+};
 use poem::endpoint::StaticFilesEndpoint;
+use poem::http::{header, HeaderMap, StatusCode};
 use poem::listener::TcpListener;
 use poem::middleware::{AddDataEndpoint, CatchPanic, Cors};
 use poem::session::{CookieConfig, RedisStorage, ServerSession, Session};
 use poem::web::cookie::SameSite;
-use poem::web::{Data, Html};
-use poem::{get, handler, EndpointExt, IntoResponse, Route, Server};
+use poem::web::{Data, Html, Path};
+use poem::{get, handler, post, EndpointExt, IntoResponse, Response, Route, Server};
 use redis::Client as RedisClient;
 use sea_orm::prelude::*;
+use shared::domain::bus::command::CommandBus;
+use shared::domain::bus::query::{QueryBus, QueryError};
 use shared::infrastructure::bus::command::InMemoryCommandBus;
 use shared::infrastructure::bus::event::InMemoryEventBus;
 use shared::infrastructure::bus::query::InMemoryQueryBus;
+use time::OffsetDateTime;
 
 #[handler]
 async fn health_check() -> impl IntoResponse {
@@ -40,6 +56,8 @@ async fn index(
     schema: Data<&DonaSchema>,
     db: Data<&DatabaseConnection>,
     redis: Data<&RedisClient>,
+    idempotency: Data<&IdempotencyConfig>,
+    headers: &HeaderMap,
     session: &Session,
     req: GraphQLRequest,
 ) -> GraphQLResponse {
@@ -47,14 +65,20 @@ async fn index(
 
     let mut command_bus = InMemoryCommandBus::default();
     let mut query_bus = InMemoryQueryBus::default();
-    let event_bus = InMemoryEventBus::default();
-    // When we have the event handlers do the di here separately
-    let event_bus = Arc::new(event_bus);
+    let event_bus = dona_app_event_handlers_di(&db);
 
     backoffice_app_di(&mut command_bus, &mut query_bus, event_bus.clone(), &db);
     security_app_di(&mut command_bus, &redis);
     dona_app_di(&mut command_bus, &mut query_bus, event_bus, &db);
 
+    let command_bus =
+        match idempotent_command_bus(command_bus, &redis, &idempotency, headers, session, &req) {
+            Ok(command_bus) => command_bus,
+            Err(e) => {
+                return async_graphql::Response::from_errors(vec![ServerError::new(e, None)]).into()
+            }
+        };
+
     let command_bus: CommandBusType = Arc::new(command_bus);
     let query_bus: QueryBusType = Arc::new(query_bus);
 
@@ -66,22 +90,107 @@ async fn index(
     schema.execute(req).await.into()
 }
 
+/// Serves a completed dona export to its owner as an attachment.
+#[handler]
+async fn download_dona_export(
+    Path(id): Path<String>,
+    db: Data<&DatabaseConnection>,
+    session: &Session,
+) -> poem::Result<Response> {
+    if !is_authenticated(session) {
+        return Err(StatusCode::UNAUTHORIZED.into());
+    }
+    let user_id = session
+        .get::<String>("user_id")
+        .ok_or(StatusCode::UNAUTHORIZED)?;
+
+    let mut command_bus = InMemoryCommandBus::default();
+    let mut query_bus = InMemoryQueryBus::default();
+    let event_bus = Arc::new(InMemoryEventBus::default());
+    dona_app_di(&mut command_bus, &mut query_bus, event_bus, &db);
+
+    let export = query_bus
+        .ask(Box::new(DownloadDonaExportQuery {
+            id,
+            requester_id: user_id,
+        }))
+        .await
+        .map_err(|e| {
+            if e == QueryError::new(ERR_DONA_EXPORT_NOT_READY.to_string()) {
+                StatusCode::CONFLICT
+            } else {
+                StatusCode::NOT_FOUND
+            }
+        })?;
+    let export = export
+        .as_any()
+        .downcast_ref::<DonaExportFileResponse>()
+        .unwrap()
+        .clone();
+
+    Ok(Response::builder()
+        .content_type(export.content_type)
+        .header(
+            header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"{}\"", export.filename),
+        )
+        .body(export.content))
+}
+
+/// Receives PayPal Instant Payment Notifications. PayPal keeps sending a notification until it
+/// is answered with a 200, so anything that cannot be verified or applied gets a 400.
+#[handler]
+async fn paypal_ipn(payload: String, db: Data<&DatabaseConnection>) -> StatusCode {
+    let mut command_bus = InMemoryCommandBus::default();
+    let mut query_bus = InMemoryQueryBus::default();
+    let event_bus = dona_app_event_handlers_di(&db);
+    dona_app_di(&mut command_bus, &mut query_bus, event_bus, &db);
+
+    let result = command_bus
+        .dispatch(Box::new(ConfirmDonaPaymentCommand {
+            method: "PAYPAL".to_string(),
+            payload,
+            received_at: OffsetDateTime::now_utc(),
+        }))
+        .await;
+
+    match result {
+        Ok(_) => StatusCode::OK,
+        Err(e) => {
+            eprintln!("Failed to apply PayPal notification: {}", e);
+            StatusCode::BAD_REQUEST
+        }
+    }
+}
+
+pub type AppEndpoint = AddDataEndpoint<
+    AddDataEndpoint<
+        AddDataEndpoint<AddDataEndpoint<Route, DatabaseConnection>, RedisClient>,
+        DonaSchema,
+    >,
+    IdempotencyConfig,
+>;
+
 pub fn create_app(
     db: DatabaseConnection,
     redis: RedisClient,
     schema: DonaSchema,
-) -> AddDataEndpoint<
-    AddDataEndpoint<AddDataEndpoint<Route, DatabaseConnection>, RedisClient>,
-    DonaSchema,
-> {
+    idempotency: IdempotencyConfig,
+) -> AppEndpoint {
     Route::new()
         .at("/graphql", get(index).post(index).options(index))
         .at("/", get(graphiql))
         .at("/health", get(health_check))
+        .at("/paypal/ipn", post(paypal_ipn))
+        .at(
+            format!("{}/:id", DONA_EXPORT_DOWNLOAD_PATH),
+            get(download_dona_export),
+        )
         .nest("/media", StaticFilesEndpoint::new("storage_files"))
         .data(db.clone())
         .data(redis.clone())
         .data(schema)
+        .data(idempotency)
 }
 
 pub async fn run(db: &DatabaseConnection, redis: &RedisClient) -> Result<(), std::io::Error> {
@@ -96,9 +205,17 @@ pub async fn run(db: &DatabaseConnection, redis: &RedisClient) -> Result<(), std
         RedisStorage::new(redis.get_connection_manager().await.unwrap()),
     );
 
+    spawn_dona_sweeper(db.clone(), redis.clone(), DonaSweeperConfig::from_env());
+    spawn_pledge_scheduler(db.clone(), redis.clone(), PledgeSchedulerConfig::from_env());
+    spawn_dona_export_worker(
+        db.clone(),
+        redis.clone(),
+        DonaExportWorkerConfig::from_env(),
+    );
+
     Server::new(TcpListener::bind("127.0.0.1:8080"))
         .run(
-            create_app(db_clone, redis_clone, schema)
+            create_app(db_clone, redis_clone, schema, IdempotencyConfig::from_env())
                 .with(CatchPanic::new())
                 .with(Cors::new().allow_credentials(true).allow_origin_regex("*"))
                 .with(session_storage),
@@ -2,14 +2,14 @@ use std::{collections::BTreeMap, time::Duration};
 
 use async_graphql::{EmptySubscription, Schema};
 use dona::{
-    graphql::{DonaSchema, Mutation, Query},
-    server::create_app,
+    graphql::{Mutation, Query},
+    idempotency::IdempotencyConfig,
+    server::{create_app, AppEndpoint},
 };
 use mockall::mock;
 use poem::{
-    middleware::AddDataEndpoint,
     session::{CookieConfig, ServerSession, SessionStorage},
-    Result, Route,
+    Result,
 };
 use redis::{aio::MultiplexedConnection, Client as RedisClient};
 use sea_orm::DatabaseConnection;
@@ -19,17 +19,12 @@ use time::OffsetDateTime;
 
 pub const TEST_SESSION_ID: &str = "poem-session=BATz_xth_nsSbYDj5mTUJHfpVEOCiZefaKjJEUgTh14";
 
-pub fn configure_app(
-    db: DatabaseConnection,
-    redis: RedisClient,
-) -> AddDataEndpoint<
-    AddDataEndpoint<AddDataEndpoint<Route, DatabaseConnection>, RedisClient>,
-    DonaSchema,
-> {
+pub fn configure_app(db: DatabaseConnection, redis: RedisClient) -> AppEndpoint {
     create_app(
         db.clone(),
         redis.clone(),
         Schema::build(Query::default(), Mutation::default(), EmptySubscription).finish(),
+        IdempotencyConfig::default(),
     )
 }
 